@@ -1,3 +1,6 @@
+// The `Trace` derive of the `gc` crate generates its impls inside of a constant.
+#![allow(non_local_definitions)]
+
 use boa::{
     class::{Class, ClassBuilder},
     gc::{Finalize, Trace},
@@ -61,7 +64,7 @@ impl Class for Person {
         // we get the first arguemnt of undefined if the first one is unavalable and call `to_string`.
         //
         // This is equivalent to `String(arg)`.
-        let name = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+        let name = args.first().cloned().unwrap_or_default().to_string(ctx)?;
         // we get the second arguemnt of undefined if the first one is unavalable and call `to_u32`.
         //
         // This is equivalent to `arg | 0`.
@@ -87,7 +90,7 @@ impl Class for Person {
         //
         // This function is added to `Person.is()`
         class.static_method("is", 1, |_this, args, _ctx| {
-            if let Some(arg) = args.get(0) {
+            if let Some(arg) = args.first() {
                 if let Some(object) = arg.as_object() {
                     if object.is::<Person>() {
                         // we check if the object type is `Person`
@@ -98,6 +101,28 @@ impl Class for Person {
             Ok(false.into()) // otherwise `false`.
         });
 
+        // we add a method bound to the well known symbol `Symbol.toPrimitive`, which is called
+        // when a `Person` is converted to a primitive, like in `'' + person`.
+        //
+        // This function is added to `Person.prototype[Symbol.toPrimitive]()`
+        let to_primitive = class.context().well_known_symbols().to_primitive_symbol();
+        class.method(
+            (to_primitive, "[Symbol.toPrimitive]"),
+            1,
+            |this, _args, _ctx| {
+                if let Some(object) = this.as_object() {
+                    if let Some(person) = object.downcast_ref::<Person>() {
+                        return Ok(person.name.clone().into());
+                    }
+                }
+                Ok(Value::undefined())
+            },
+        );
+
+        // we add a `Symbol.toStringTag` property, which is used by `Object.prototype.toString`.
+        let to_string_tag = class.context().well_known_symbols().to_string_tag_symbol();
+        class.property(to_string_tag, "Person", Attribute::CONFIGURABLE);
+
         // Add a inherited property with the value `10`, with deafault attribute.
         // (`READONLY, NON_ENUMERABLE, PERMANENT).
         class.property("inheritedProperty", 10, Attribute::default());
@@ -136,6 +161,9 @@ fn main() {
         console.log(Person.staticProperty);
         console.log(person.inheritedProperty);
 	    console.log(Person.prototype.inheritedProperty === person.inheritedProperty);
+
+        console.log(Object.prototype.toString.call(person));
+        console.log('name: ' + person);
     ",
         )
         .unwrap();
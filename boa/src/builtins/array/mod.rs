@@ -15,14 +15,14 @@ mod tests;
 
 use crate::{
     builtins::array::array_iterator::{ArrayIterationKind, ArrayIterator},
-    builtins::BuiltIn,
+    builtins::{iterable::get_iterator, BuiltIn, Number},
     gc::GcObject,
-    object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
+    object::{ConstructorBuilder, FunctionBuilder, Object, ObjectData},
     property::{Attribute, DataDescriptor},
     value::{same_value_zero, Value},
     BoaProfiler, Context, Result,
 };
-use std::cmp::{max, min};
+use std::cmp::{max, min, Ordering};
 
 /// JavaScript `Array` built-in implementation.
 #[derive(Debug, Clone, Copy)]
@@ -40,12 +40,22 @@ impl BuiltIn for Array {
 
         let symbol_iterator = context.well_known_symbols().iterator_symbol();
 
+        let species_symbol = context.well_known_symbols().species_symbol();
+        let get_species = FunctionBuilder::new(context, Self::species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+
+        let symbol_unscopables = context.well_known_symbols().unscopables_symbol();
+        let unscopables = Self::unscopables_object();
+
         let values_function = FunctionBuilder::new(context, Self::values)
             .name("values")
             .length(0)
             .callable(true)
             .constructable(false)
             .build();
+        context.realm_mut().array_prototype_values = values_function.clone();
 
         let array = ConstructorBuilder::with_standard_object(
             context,
@@ -65,6 +75,11 @@ impl BuiltIn for Array {
             values_function,
             Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
         )
+        .property(
+            symbol_unscopables,
+            unscopables,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .method(Self::concat, "concat", 1)
         .method(Self::push, "push", 1)
         .method(Self::index_of, "indexOf", 1)
@@ -89,8 +104,25 @@ impl BuiltIn for Array {
         .method(Self::reduce_right, "reduceRight", 2)
         .method(Self::keys, "keys", 0)
         .method(Self::entries, "entries", 0)
+        .method(Self::at, "at", 1)
+        .method(Self::copy_within, "copyWithin", 2)
+        .method(Self::find_last, "findLast", 1)
+        .method(Self::find_last_index, "findLastIndex", 1)
+        .method(Self::flat, "flat", 0)
+        .method(Self::flat_map, "flatMap", 1)
+        .method(Self::sort, "sort", 1)
+        .method(Self::splice, "splice", 2)
+        .method(Self::to_locale_string, "toLocaleString", 0)
         // Static Methods
         .static_method(Self::is_array, "isArray", 1)
+        .static_method(Self::from, "from", 1)
+        .static_method(Self::of, "of", 0)
+        .static_accessor(
+            species_symbol,
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
         .build();
 
         (Self::NAME, array.into(), Self::attribute())
@@ -101,11 +133,18 @@ impl Array {
     const LENGTH: usize = 1;
 
     fn constructor(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // Calling `Array` as a function creates a new array, like `new Array` does.
+        let this = if this.is_object() && !this.is_global() {
+            this.clone()
+        } else {
+            Value::from(Object::default())
+        };
+
         // Delegate to the appropriate constructor based on the number of arguments
         match args.len() {
-            0 => Array::construct_array_empty(this, context),
-            1 => Array::construct_array_length(this, &args[0], context),
-            _ => Array::construct_array_values(this, args, context),
+            0 => Array::construct_array_empty(&this, context),
+            1 => Array::construct_array_length(&this, &args[0], context),
+            _ => Array::construct_array_values(&this, args, context),
         }
     }
 
@@ -136,13 +175,17 @@ impl Array {
         let array = Array::array_create(this, 0, Some(prototype), context)?;
 
         if !length.is_number() {
-            array.set_field(0, Value::from(length));
-            array.set_field("length", Value::from(1));
+            array.set_field(0, Value::from(length), context)?;
+            array.set_field("length", Value::from(1), context)?;
         } else {
             if length.is_double() {
                 return context.throw_range_error("Invalid array length");
             }
-            array.set_field("length", Value::from(length.to_u32(context).unwrap()));
+            array.set_field(
+                "length",
+                Value::from(length.to_u32(context).unwrap()),
+                context,
+            )?;
         }
 
         Ok(array)
@@ -163,7 +206,7 @@ impl Array {
         let array = Array::array_create(this, items.len() as u32, Some(prototype), context)?;
 
         for (k, item) in items.iter().enumerate() {
-            array.set_field(k, item.clone());
+            array.set_field(k, item.clone(), context)?;
         }
 
         Ok(array)
@@ -216,7 +259,11 @@ impl Array {
             .as_object()
             .expect("array object")
             .set_prototype_instance(context.standard_objects().array_object().prototype().into());
-        array.set_field("length", Value::from(0));
+        let length = DataDescriptor::new(
+            Value::from(0),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        array.set_property("length", length);
         Ok(array)
     }
 
@@ -224,11 +271,15 @@ impl Array {
     ///
     /// `array_obj` can be any array with prototype already set (it will be wiped and
     /// recreated from `array_contents`)
-    pub(crate) fn construct_array(array_obj: &Value, array_contents: &[Value]) -> Result<Value> {
+    pub(crate) fn construct_array(
+        array_obj: &Value,
+        array_contents: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
         let array_obj_ptr = array_obj.clone();
 
         // Wipe existing contents of the array object
-        let orig_length = array_obj.get_field("length").as_number().unwrap() as i32;
+        let orig_length = array_obj.get_field("length", context)?.as_number().unwrap() as i32;
         for n in 0..orig_length {
             array_obj_ptr.remove_property(n);
         }
@@ -241,29 +292,145 @@ impl Array {
         array_obj_ptr.set_property("length".to_string(), length);
 
         for (n, value) in array_contents.iter().enumerate() {
-            array_obj_ptr.set_field(n, value);
+            array_obj_ptr.set_field(n, value, context)?;
         }
         Ok(array_obj_ptr)
     }
 
     /// Utility function which takes an existing array object and puts additional
     /// values on the end, correctly rewriting the length
-    pub(crate) fn add_to_array_object(array_ptr: &Value, add_values: &[Value]) -> Result<Value> {
-        let orig_length = array_ptr.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn add_to_array_object(
+        array_ptr: &Value,
+        add_values: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let orig_length = array_ptr.get_field("length", context)?.as_number().unwrap() as i32;
 
         for (n, value) in add_values.iter().enumerate() {
             let new_index = orig_length.wrapping_add(n as i32);
-            array_ptr.set_field(new_index, value);
+            array_ptr.set_field(new_index, value, context)?;
         }
 
         array_ptr.set_field(
             "length",
             Value::from(orig_length.wrapping_add(add_values.len() as i32)),
-        );
+            context,
+        )?;
 
         Ok(array_ptr.clone())
     }
 
+    /// Creates a new `Array` instance with the given length.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraycreate
+    fn new_array_with_length(length: usize, context: &mut Context) -> Result<Value> {
+        if length > u32::MAX as usize {
+            return context.throw_range_error("Invalid array length");
+        }
+        let array = Self::new_array(context)?;
+        array.set_field("length", length, context)?;
+        Ok(array)
+    }
+
+    /// Creates a new array, with the constructor of `original` if it is an array, to hold the
+    /// elements derived from it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arrayspeciescreate
+    fn array_species_create(
+        original: &GcObject,
+        length: usize,
+        method: &str,
+        context: &mut Context,
+    ) -> Result<Value> {
+        if !original.is_array() {
+            return Self::new_array_with_length(length, context);
+        }
+
+        let mut constructor =
+            original.get(&"constructor".into(), original.clone().into(), context)?;
+        if constructor.is_object() {
+            let species = context.well_known_symbols().species_symbol();
+            constructor = constructor.get_field_with_receiver(species, &constructor, context)?;
+            if constructor.is_null() {
+                constructor = Value::undefined();
+            }
+        }
+
+        match constructor {
+            Value::Undefined => Self::new_array_with_length(length, context),
+            Value::Object(ref object) if object.is_constructable() => {
+                object.construct(&[length.into()], &constructor, context)
+            }
+            _ => context.throw_type_error(format!(
+                "Array.prototype.{}: species constructor is not a constructor",
+                method
+            )),
+        }
+    }
+
+    /// Abstract operation `LengthOfArrayLike ( obj )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-lengthofarraylike
+    fn length_of_array_like(object: &GcObject, context: &mut Context) -> Result<usize> {
+        object
+            .get(&"length".into(), object.clone().into(), context)?
+            .to_length(context)
+    }
+
+    /// Abstract operation `IsConcatSpreadable ( O )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isconcatspreadable
+    fn is_concat_spreadable(value: &Value, context: &mut Context) -> Result<bool> {
+        let object = match value {
+            Value::Object(ref object) => object,
+            _ => return Ok(false),
+        };
+        let symbol = context.well_known_symbols().is_concat_spreadable_symbol();
+        let spreadable = object.get(&symbol.into(), value.clone(), context)?;
+        if spreadable.is_undefined() {
+            Ok(object.is_array())
+        } else {
+            Ok(spreadable.to_boolean())
+        }
+    }
+
+    /// Returns the callback function of an array method, which is its first argument.
+    fn callback(args: &[Value], method: &str, context: &mut Context) -> Result<GcObject> {
+        match args.first() {
+            Some(Value::Object(ref object)) if object.is_callable() => Ok(object.clone()),
+            _ => Err(context.construct_type_error(format!(
+                "Array.prototype.{}: callback is not a function",
+                method
+            ))),
+        }
+    }
+
+    /// Abstract operation `DeletePropertyOrThrow ( O, P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-deletepropertyorthrow
+    fn delete_property_or_throw(object: &Value, index: usize, context: &mut Context) -> Result<()> {
+        if object.to_object(context)?.delete(&index.into(), context)? {
+            Ok(())
+        } else {
+            Err(context.construct_type_error(format!("cannot delete property: {}", index)))
+        }
+    }
+
     /// `Array.isArray( arg )`
     ///
     /// The isArray function takes one argument arg, and returns the Boolean value true
@@ -280,7 +447,7 @@ impl Array {
         args: &[Value],
         _interpreter: &mut Context,
     ) -> Result<Value> {
-        match args.get(0).and_then(|x| x.as_object()) {
+        match args.first().and_then(|x| x.as_object()) {
             Some(object) => Ok(Value::from(object.borrow().is_array())),
             None => Ok(Value::from(false)),
         }
@@ -298,29 +465,38 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.concat
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/concat
-    pub(crate) fn concat(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        if args.is_empty() {
-            // If concat is called with no arguments, it returns the original array
-            return Ok(this.clone());
-        }
-
-        // Make a new array (using this object as the prototype basis for the new
-        // one)
-        let mut new_values: Vec<Value> = Vec::new();
-
-        let this_length = this.get_field("length").as_number().unwrap() as i32;
-        for n in 0..this_length {
-            new_values.push(this.get_field(n));
-        }
-
-        for concat_array in args {
-            let concat_length = concat_array.get_field("length").as_number().unwrap() as i32;
-            for n in 0..concat_length {
-                new_values.push(concat_array.get_field(n));
+    pub(crate) fn concat(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = this.to_object(context)?;
+        let array = Self::array_species_create(&object, 0, "concat", context)?;
+
+        let mut n = 0;
+        for item in std::iter::once(Value::from(object)).chain(args.iter().cloned()) {
+            if Self::is_concat_spreadable(&item, context)? {
+                let item = item.as_object().expect("spreadable values are objects");
+                let length = Self::length_of_array_like(&item, context)?;
+                if n + length > Number::MAX_SAFE_INTEGER as usize {
+                    return context
+                        .throw_type_error("Array.prototype.concat: array length is too large");
+                }
+                for k in 0..length {
+                    if item.has_property(&k.into(), context)? {
+                        let value = item.get(&k.into(), item.clone().into(), context)?;
+                        array.set_field(n, value, context)?;
+                    }
+                    n += 1;
+                }
+            } else {
+                if n >= Number::MAX_SAFE_INTEGER as usize {
+                    return context
+                        .throw_type_error("Array.prototype.concat: array length is too large");
+                }
+                array.set_field(n, item, context)?;
+                n += 1;
             }
         }
+        array.set_field("length", n, context)?;
 
-        Self::construct_array(this, &new_values)
+        Ok(array)
     }
 
     /// `Array.prototype.push( ...items )`
@@ -335,9 +511,9 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.push
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push
-    pub(crate) fn push(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let new_array = Self::add_to_array_object(this, args)?;
-        Ok(new_array.get_field("length"))
+    pub(crate) fn push(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let new_array = Self::add_to_array_object(this, args, context)?;
+        new_array.get_field("length", context)
     }
 
     /// `Array.prototype.pop()`
@@ -350,16 +526,16 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.pop
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/pop
-    pub(crate) fn pop(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let curr_length = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn pop(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let curr_length = this.get_field("length", context)?.as_number().unwrap() as i32;
 
         if curr_length < 1 {
             return Ok(Value::undefined());
         }
         let pop_index = curr_length.wrapping_sub(1);
-        let pop_value: Value = this.get_field(pop_index.to_string());
-        this.remove_property(pop_index);
-        this.set_field("length", Value::from(pop_index));
+        let pop_value: Value = this.get_field(pop_index.to_string(), context)?;
+        Self::delete_property_or_throw(this, pop_index as usize, context)?;
+        this.set_field("length", Value::from(pop_index), context)?;
         Ok(pop_value)
     }
 
@@ -378,13 +554,13 @@ impl Array {
             return Err(Value::from("Missing argument for Array.prototype.forEach"));
         }
 
-        let callback_arg = args.get(0).expect("Could not get `callbackFn` argument.");
+        let callback_arg = args.first().expect("Could not get `callbackFn` argument.");
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         for i in 0..length {
-            let element = this.get_field(i);
+            let element = this.get_field(i, ctx)?;
             let arguments = [element, Value::from(i), this.clone()];
 
             ctx.call(callback_arg, &this_arg, &arguments)?;
@@ -409,16 +585,16 @@ impl Array {
         let separator = if args.is_empty() {
             String::from(",")
         } else {
-            args.get(0)
+            args.first()
                 .expect("Could not get argument")
                 .to_string(ctx)?
                 .to_string()
         };
 
         let mut elem_strs = Vec::new();
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", ctx)?.as_number().unwrap() as i32;
         for n in 0..length {
-            let elem_str = this.get_field(n).to_string(ctx)?.to_string();
+            let elem_str = this.get_field(n, ctx)?.to_string(ctx)?.to_string();
             elem_strs.push(elem_str);
         }
 
@@ -442,14 +618,11 @@ impl Array {
         let method_name = "join";
         let mut arguments = vec![Value::from(",")];
         // 2.
-        let mut method = this.get_field(method_name);
+        let mut method = this.get_field(method_name, ctx)?;
         // 3.
         if !method.is_function() {
-            method = ctx
-                .global_object()
-                .get_field("Object")
-                .get_field(PROTOTYPE)
-                .get_field("toString");
+            let object_prototype: Value = ctx.standard_objects().object_object().prototype().into();
+            method = object_prototype.get_field("toString", ctx)?;
 
             arguments = Vec::new();
         }
@@ -477,8 +650,8 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.reverse
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/reverse
     #[allow(clippy::else_if_without_else)]
-    pub(crate) fn reverse(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn reverse(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let len = this.get_field("length", context)?.as_number().unwrap() as i32;
 
         let middle: i32 = len.wrapping_div(2);
 
@@ -488,18 +661,18 @@ impl Array {
             let upper_exists = this.has_field(upper);
             let lower_exists = this.has_field(lower);
 
-            let upper_value = this.get_field(upper);
-            let lower_value = this.get_field(lower);
+            let upper_value = this.get_field(upper, context)?;
+            let lower_value = this.get_field(lower, context)?;
 
             if upper_exists && lower_exists {
-                this.set_field(upper, lower_value);
-                this.set_field(lower, upper_value);
+                this.set_field(upper, lower_value, context)?;
+                this.set_field(lower, upper_value, context)?;
             } else if upper_exists {
-                this.set_field(lower, upper_value);
-                this.remove_property(upper);
+                this.set_field(lower, upper_value, context)?;
+                Self::delete_property_or_throw(this, upper as usize, context)?;
             } else if lower_exists {
-                this.set_field(upper, lower_value);
-                this.remove_property(lower);
+                this.set_field(upper, lower_value, context)?;
+                Self::delete_property_or_throw(this, lower as usize, context)?;
             }
         }
 
@@ -516,31 +689,31 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.shift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/shift
-    pub(crate) fn shift(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn shift(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let len = this.get_field("length", context)?.as_number().unwrap() as i32;
 
         if len == 0 {
-            this.set_field("length", 0);
+            this.set_field("length", 0, context)?;
             return Ok(Value::undefined());
         }
 
-        let first: Value = this.get_field(0);
+        let first: Value = this.get_field(0, context)?;
 
         for k in 1..len {
             let from = k;
             let to = k.wrapping_sub(1);
 
-            let from_value = this.get_field(from);
+            let from_value = this.get_field(from, context)?;
             if from_value.is_undefined() {
-                this.remove_property(to);
+                Self::delete_property_or_throw(this, to as usize, context)?;
             } else {
-                this.set_field(to, from_value);
+                this.set_field(to, from_value, context)?;
             }
         }
 
         let final_index = len.wrapping_sub(1);
-        this.remove_property(final_index);
-        this.set_field("length", Value::from(final_index));
+        Self::delete_property_or_throw(this, final_index as usize, context)?;
+        this.set_field("length", Value::from(final_index), context)?;
 
         Ok(first)
     }
@@ -557,8 +730,8 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.unshift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/unshift
-    pub(crate) fn unshift(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn unshift(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let len = this.get_field("length", context)?.as_number().unwrap() as i32;
 
         let arg_c: i32 = args.len() as i32;
 
@@ -567,11 +740,11 @@ impl Array {
                 let from = k.wrapping_sub(1);
                 let to = k.wrapping_add(arg_c).wrapping_sub(1);
 
-                let from_value = this.get_field(from);
+                let from_value = this.get_field(from, context)?;
                 if from_value.is_undefined() {
-                    this.remove_property(to);
+                    Self::delete_property_or_throw(this, to as usize, context)?;
                 } else {
-                    this.set_field(to, from_value);
+                    this.set_field(to, from_value, context)?;
                 }
             }
             for j in 0..arg_c {
@@ -580,12 +753,13 @@ impl Array {
                     args.get(j as usize)
                         .expect("Could not get argument")
                         .clone(),
-                );
+                    context,
+                )?;
             }
         }
 
         let temp = len.wrapping_add(arg_c);
-        this.set_field("length", Value::from(temp));
+        this.set_field("length", Value::from(temp), context)?;
         Ok(Value::from(temp))
     }
 
@@ -615,10 +789,10 @@ impl Array {
             Value::undefined()
         };
         let mut i = 0;
-        let max_len = this.get_field("length").as_number().unwrap() as i32;
+        let max_len = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
         let mut len = max_len;
         while i < len {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element, Value::from(i), this.clone()];
             let result = interpreter.call(callback, &this_arg, &arguments)?;
             if !result.to_boolean() {
@@ -626,7 +800,7 @@ impl Array {
             }
             len = min(
                 max_len,
-                this.get_field("length").as_number().unwrap() as i32,
+                this.get_field("length", interpreter)?.as_number().unwrap() as i32,
             );
             i += 1;
         }
@@ -645,35 +819,25 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.map
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/map
     pub(crate) fn map(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        if args.is_empty() {
-            return Err(Value::from(
-                "missing argument 0 when calling function Array.prototype.map",
-            ));
-        }
-
-        let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
-        let this_val = args.get(1).cloned().unwrap_or_else(Value::undefined);
-
-        let length = this.get_field("length").to_length(context)?;
-
-        if length > 2usize.pow(32) - 1 {
-            return context.throw_range_error("Invalid array length");
+        let object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+        let callback = Self::callback(args, "map", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        let array = Self::array_species_create(&object, length, "map", context)?;
+        for k in 0..length {
+            if object.has_property(&k.into(), context)? {
+                let value = object.get(&k.into(), object.clone().into(), context)?;
+                let mapped = callback.call(
+                    &this_arg,
+                    &[value, k.into(), object.clone().into()],
+                    context,
+                )?;
+                array.set_field(k, mapped, context)?;
+            }
         }
 
-        let new = Self::new_array(context)?;
-
-        let values: Vec<Value> = (0..length)
-            .map(|idx| {
-                let element = this.get_field(idx);
-                let args = [element, Value::from(idx), new.clone()];
-
-                context
-                    .call(&callback, &this_val, &args)
-                    .unwrap_or_else(|_| Value::undefined())
-            })
-            .collect();
-
-        Self::construct_array(&new, &values)
+        Ok(array)
     }
 
     /// `Array.prototype.indexOf( searchElement[, fromIndex ] )`
@@ -695,14 +859,14 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.indexof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/indexOf
-    pub(crate) fn index_of(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn index_of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         // If no arguments, return -1. Not described in spec, but is what chrome does.
         if args.is_empty() {
             return Ok(Value::from(-1));
         }
 
         let search_element = args[0].clone();
-        let len = this.get_field("length").as_number().unwrap() as i32;
+        let len = this.get_field("length", context)?.as_number().unwrap() as i32;
 
         let mut idx = match args.get(1) {
             Some(from_idx_ptr) => {
@@ -718,7 +882,7 @@ impl Array {
         };
 
         while idx < len {
-            let check_element = this.get_field(idx).clone();
+            let check_element = this.get_field(idx, context)?.clone();
 
             if check_element.strict_equals(&search_element) {
                 return Ok(Value::from(idx));
@@ -748,7 +912,11 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.lastindexof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/lastIndexOf
-    pub(crate) fn last_index_of(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn last_index_of(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
         // If no arguments, return -1. Not described in spec, but is what chrome does.
         if args.is_empty() {
             return Ok(Value::from(-1));
@@ -756,7 +924,7 @@ impl Array {
 
         let search_element = args[0].clone();
         let len = this
-            .get_field("length")
+            .get_field("length", context)?
             .as_number()
             .expect("length was not a number") as i32;
 
@@ -774,7 +942,7 @@ impl Array {
         };
 
         while idx >= 0 {
-            let check_element = this.get_field(idx).clone();
+            let check_element = this.get_field(idx, context)?.clone();
 
             if check_element.strict_equals(&search_element) {
                 return Ok(Value::from(idx));
@@ -806,9 +974,9 @@ impl Array {
         }
         let callback = &args[0];
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let len = this.get_field("length").as_number().unwrap() as i32;
+        let len = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
         for i in 0..len {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element.clone(), Value::from(i), this.clone()];
             let result = interpreter.call(callback, &this_arg, &arguments)?;
             if result.to_boolean() {
@@ -841,14 +1009,14 @@ impl Array {
             ));
         }
 
-        let predicate_arg = args.get(0).expect("Could not get `predicate` argument.");
+        let predicate_arg = args.first().expect("Could not get `predicate` argument.");
 
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
 
         for i in 0..length {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element, Value::from(i), this.clone()];
 
             let result = interpreter.call(predicate_arg, &this_arg, &arguments)?;
@@ -873,10 +1041,10 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.fill
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fill
     pub(crate) fn fill(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let len: i32 = this.get_field("length").as_number().unwrap() as i32;
+        let len: i32 = this.get_field("length", ctx)?.as_number().unwrap() as i32;
 
         let default_value = Value::undefined();
-        let value = args.get(0).unwrap_or(&default_value);
+        let value = args.first().unwrap_or(&default_value);
         let relative_start = args.get(1).unwrap_or(&default_value).to_number(ctx)? as i32;
         let relative_end_val = args.get(2).unwrap_or(&default_value);
         let relative_end = if relative_end_val.is_undefined() {
@@ -896,7 +1064,7 @@ impl Array {
         };
 
         for i in start..fin {
-            this.set_field(i, value.clone());
+            this.set_field(i, value.clone(), ctx)?;
         }
 
         Ok(this.clone())
@@ -912,13 +1080,17 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.includes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/includes
-    pub(crate) fn includes_value(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let search_element = args.get(0).cloned().unwrap_or_else(Value::undefined);
+    pub(crate) fn includes_value(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let search_element = args.first().cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this.get_field("length", context)?.as_number().unwrap() as i32;
 
         for idx in 0..length {
-            let check_element = this.get_field(idx).clone();
+            let check_element = this.get_field(idx, context)?.clone();
 
             if same_value_zero(&check_element, &search_element) {
                 return Ok(Value::from(true));
@@ -942,38 +1114,30 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.slice
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/slice
-    pub(crate) fn slice(this: &Value, args: &[Value], interpreter: &mut Context) -> Result<Value> {
-        let new_array = Self::new_array(interpreter)?;
-        let len = this.get_field("length").as_number().unwrap() as i32;
-
-        let start = match args.get(0) {
-            Some(v) => v.as_number().unwrap() as i32,
-            None => 0,
-        };
-        let end = match args.get(1) {
-            Some(v) => v.as_number().unwrap() as i32,
-            None => len,
+    pub(crate) fn slice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+        let from = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        let to = match args.get(1) {
+            None | Some(Value::Undefined) => length,
+            Some(end) => end.to_relative_index(length, context)?,
         };
 
-        let from = if start < 0 {
-            max(len.wrapping_add(start), 0)
-        } else {
-            min(start, len)
-        };
-        let to = if end < 0 {
-            max(len.wrapping_add(end), 0)
-        } else {
-            min(end, len)
-        };
-
-        let span = max(to.wrapping_sub(from), 0);
-        let mut new_array_len: i32 = 0;
-        for i in from..from.wrapping_add(span) {
-            new_array.set_field(new_array_len, this.get_field(i));
-            new_array_len = new_array_len.wrapping_add(1);
+        let count = to.saturating_sub(from);
+        let array = Self::array_species_create(&object, count, "slice", context)?;
+        for (n, k) in (from..to).enumerate() {
+            if object.has_property(&k.into(), context)? {
+                let value = object.get(&k.into(), object.clone().into(), context)?;
+                array.set_field(n, value, context)?;
+            }
         }
-        new_array.set_field("length", Value::from(new_array_len));
-        Ok(new_array)
+        array.set_field("length", count, context)?;
+
+        Ok(array)
     }
 
     /// `Array.prototype.filter( callback, [ thisArg ] )`
@@ -987,39 +1151,32 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.filter
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/filter
-    pub(crate) fn filter(this: &Value, args: &[Value], interpreter: &mut Context) -> Result<Value> {
-        if args.is_empty() {
-            return Err(Value::from(
-                "missing argument 0 when calling function Array.prototype.filter",
-            ));
-        }
-
-        let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
-        let this_val = args.get(1).cloned().unwrap_or_else(Value::undefined);
-
-        let length = this.get_field("length").as_number().unwrap() as i32;
-
-        let new = Self::new_array(interpreter)?;
-
-        let values = (0..length)
-            .filter_map(|idx| {
-                let element = this.get_field(idx);
-
-                let args = [element.clone(), Value::from(idx), new.clone()];
-
-                let callback_result = interpreter
-                    .call(&callback, &this_val, &args)
-                    .unwrap_or_else(|_| Value::undefined());
-
-                if callback_result.to_boolean() {
-                    Some(element)
-                } else {
-                    None
+    pub(crate) fn filter(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+        let callback = Self::callback(args, "filter", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        let array = Self::array_species_create(&object, 0, "filter", context)?;
+        let mut n = 0;
+        for k in 0..length {
+            if object.has_property(&k.into(), context)? {
+                let value = object.get(&k.into(), object.clone().into(), context)?;
+                let selected = callback
+                    .call(
+                        &this_arg,
+                        &[value.clone(), k.into(), object.clone().into()],
+                        context,
+                    )?
+                    .to_boolean();
+                if selected {
+                    array.set_field(n, value, context)?;
+                    n += 1;
                 }
-            })
-            .collect::<Vec<Value>>();
+            }
+        }
 
-        Self::construct_array(&new, &values)
+        Ok(array)
     }
 
     /// Array.prototype.some ( callbackfn [ , thisArg ] )
@@ -1050,10 +1207,10 @@ impl Array {
             Value::undefined()
         };
         let mut i = 0;
-        let max_len = this.get_field("length").as_number().unwrap() as i32;
+        let max_len = this.get_field("length", interpreter)?.as_number().unwrap() as i32;
         let mut len = max_len;
         while i < len {
-            let element = this.get_field(i);
+            let element = this.get_field(i, interpreter)?;
             let arguments = [element, Value::from(i), this.clone()];
             let result = interpreter.call(callback, &this_arg, &arguments)?;
             if result.to_boolean() {
@@ -1062,7 +1219,7 @@ impl Array {
             // the length of the array must be updated because the callback can mutate it.
             len = min(
                 max_len,
-                this.get_field("length").as_number().unwrap() as i32,
+                this.get_field("length", interpreter)?.as_number().unwrap() as i32,
             );
             i += 1;
         }
@@ -1082,12 +1239,14 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/reduce
     pub(crate) fn reduce(this: &Value, args: &[Value], interpreter: &mut Context) -> Result<Value> {
         let this: Value = this.to_object(interpreter)?.into();
-        let callback = match args.get(0) {
+        let callback = match args.first() {
             Some(value) if value.is_function() => value,
             _ => return interpreter.throw_type_error("Reduce was called without a callback"),
         };
         let initial_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let mut length = this.get_field("length").to_length(interpreter)?;
+        let mut length = this
+            .get_field("length", interpreter)?
+            .to_length(interpreter)?;
         if length == 0 && initial_value.is_undefined() {
             return interpreter
                 .throw_type_error("Reduce was called on an empty array and with no initial value");
@@ -1107,7 +1266,7 @@ impl Array {
                     "Reduce was called on an empty array and with no initial value",
                 );
             }
-            let result = this.get_field(k);
+            let result = this.get_field(k, interpreter)?;
             k += 1;
             result
         } else {
@@ -1115,12 +1274,21 @@ impl Array {
         };
         while k < length {
             if this.has_field(k) {
-                let arguments = [accumulator, this.get_field(k), Value::from(k), this.clone()];
-                accumulator = interpreter.call(&callback, &Value::undefined(), &arguments)?;
+                let arguments = [
+                    accumulator,
+                    this.get_field(k, interpreter)?,
+                    Value::from(k),
+                    this.clone(),
+                ];
+                accumulator = interpreter.call(callback, &Value::undefined(), &arguments)?;
                 /* We keep track of possibly shortened length in order to prevent unnecessary iteration.
                 It may also be necessary to do this since shortening the array length does not
                 delete array elements. See: https://github.com/boa-dev/boa/issues/557 */
-                length = min(length, this.get_field("length").to_length(interpreter)?);
+                length = min(
+                    length,
+                    this.get_field("length", interpreter)?
+                        .to_length(interpreter)?,
+                );
             }
             k += 1;
         }
@@ -1144,12 +1312,14 @@ impl Array {
         interpreter: &mut Context,
     ) -> Result<Value> {
         let this: Value = this.to_object(interpreter)?.into();
-        let callback = match args.get(0) {
+        let callback = match args.first() {
             Some(value) if value.is_function() => value,
             _ => return interpreter.throw_type_error("reduceRight was called without a callback"),
         };
         let initial_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let mut length = this.get_field("length").to_length(interpreter)?;
+        let mut length = this
+            .get_field("length", interpreter)?
+            .to_length(interpreter)?;
         if length == 0 {
             return if initial_value.is_undefined() {
                 interpreter.throw_type_error(
@@ -1179,7 +1349,7 @@ impl Array {
                     "reduceRight was called on an empty array and with no initial value",
                 );
             }
-            let result = this.get_field(k);
+            let result = this.get_field(k, interpreter)?;
             k = k.overflowing_sub(1).0;
             result
         } else {
@@ -1188,12 +1358,21 @@ impl Array {
         // usize::MAX is bigger than the maximum array size so we can use it check for integer undeflow
         while k != usize::MAX {
             if this.has_field(k) {
-                let arguments = [accumulator, this.get_field(k), Value::from(k), this.clone()];
-                accumulator = interpreter.call(&callback, &Value::undefined(), &arguments)?;
+                let arguments = [
+                    accumulator,
+                    this.get_field(k, interpreter)?,
+                    Value::from(k),
+                    this.clone(),
+                ];
+                accumulator = interpreter.call(callback, &Value::undefined(), &arguments)?;
                 /* We keep track of possibly shortened length in order to prevent unnecessary iteration.
                 It may also be necessary to do this since shortening the array length does not
                 delete array elements. See: https://github.com/boa-dev/boa/issues/557 */
-                length = min(length, this.get_field("length").to_length(interpreter)?);
+                length = min(
+                    length,
+                    this.get_field("length", interpreter)?
+                        .to_length(interpreter)?,
+                );
 
                 // move k to the last defined element if necessary or return if the length was set to 0
                 if k >= length {
@@ -1254,4 +1433,594 @@ impl Array {
     pub(crate) fn entries(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
         ArrayIterator::create_array_iterator(ctx, this.clone(), ArrayIterationKind::KeyAndValue)
     }
+
+    /// `Array.from( items [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// Creates an array, with `this` as the constructor if it is one, from the elements of an
+    /// iterable or array-like object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/from
+    pub(crate) fn from(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let items = args.first().cloned().unwrap_or_default();
+        let map_fn = match args.get(1) {
+            None | Some(Value::Undefined) => None,
+            Some(Value::Object(ref object)) if object.is_callable() => Some(object.clone()),
+            Some(_) => return context.throw_type_error("Array.from: mapFn is not a function"),
+        };
+        let this_arg = args.get(2).cloned().unwrap_or_default();
+        let constructor = this.as_object().filter(|object| object.is_constructable());
+
+        let iterator = context.well_known_symbols().iterator_symbol();
+        // Primitives, like strings, are iterable through their prototype.
+        let using_iterator = Value::from(items.to_object(context)?)
+            .get_field_with_receiver(iterator, &items, context)?;
+        if !using_iterator.is_null_or_undefined() {
+            if !using_iterator.is_function() {
+                return context.throw_type_error("Array.from: @@iterator is not a function");
+            }
+            let array = match constructor {
+                Some(ref constructor) => constructor.construct(&[], this, context)?,
+                None => Self::new_array(context)?,
+            };
+
+            let iterator = get_iterator(context, items)?;
+            let mut k = 0;
+            loop {
+                let next = iterator.next(context)?;
+                if next.is_done() {
+                    array.set_field("length", k, context)?;
+                    return Ok(array);
+                }
+                let value = match map_fn {
+                    Some(ref map_fn) => {
+                        match map_fn.call(&this_arg, &[next.value(), k.into()], context) {
+                            Ok(value) => value,
+                            Err(error) => return iterator.close(Err(error), context),
+                        }
+                    }
+                    None => next.value(),
+                };
+                if let Err(error) = array.set_field(k, value, context) {
+                    return iterator.close(Err(error), context);
+                }
+                k += 1;
+            }
+        }
+
+        let array_like = items.to_object(context)?;
+        let length = Self::length_of_array_like(&array_like, context)?;
+        let array = match constructor {
+            Some(ref constructor) => constructor.construct(&[length.into()], this, context)?,
+            None => Self::new_array_with_length(length, context)?,
+        };
+        for k in 0..length {
+            let value = array_like.get(&k.into(), array_like.clone().into(), context)?;
+            let value = match map_fn {
+                Some(ref map_fn) => map_fn.call(&this_arg, &[value, k.into()], context)?,
+                None => value,
+            };
+            array.set_field(k, value, context)?;
+        }
+        array.set_field("length", length, context)?;
+        Ok(array)
+    }
+
+    /// `Array.of( ...items )`
+    ///
+    /// Creates an array, with `this` as the constructor if it is one, with the arguments as
+    /// elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/of
+    pub(crate) fn of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let array = match this.as_object() {
+            Some(constructor) if constructor.is_constructable() => {
+                constructor.construct(&[args.len().into()], this, context)?
+            }
+            _ => Self::new_array_with_length(args.len(), context)?,
+        };
+        for (k, value) in args.iter().enumerate() {
+            array.set_field(k, value.clone(), context)?;
+        }
+        array.set_field("length", args.len(), context)?;
+        Ok(array)
+    }
+
+    /// Creates the `Array.prototype [ @@unscopables ]` object, whose properties are the names of
+    /// the array methods that are not bound by `with` statements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype-@@unscopables
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/@@unscopables
+    fn unscopables_object() -> GcObject {
+        let mut unscopables = GcObject::new(Object::create(Value::null()));
+        for name in &[
+            "at",
+            "copyWithin",
+            "entries",
+            "fill",
+            "find",
+            "findIndex",
+            "findLast",
+            "findLastIndex",
+            "flat",
+            "flatMap",
+            "includes",
+            "keys",
+            "values",
+        ] {
+            unscopables.insert_property(*name, true, Attribute::all());
+        }
+        unscopables
+    }
+
+    /// `get Array [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-array-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/@@species
+    pub(crate) fn species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `Array.prototype.at( index )`
+    ///
+    /// Returns the element at `index`, counting from the end if it is negative.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/at
+    pub(crate) fn at(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+        let relative = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_integer(context)?;
+        let index = if relative >= 0.0 {
+            relative
+        } else {
+            length as f64 + relative
+        };
+        if index < 0.0 || index >= length as f64 {
+            return Ok(Value::undefined());
+        }
+        object.get(&(index as usize).into(), object.clone().into(), context)
+    }
+
+    /// `Array.prototype.copyWithin( target, start [ , end ] )`
+    ///
+    /// Copies the elements from `start` to `end` to the position `target`, keeping the holes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.copywithin
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/copyWithin
+    pub(crate) fn copy_within(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+        let to = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        let from = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        let last = match args.get(2) {
+            None | Some(Value::Undefined) => length,
+            Some(end) => end.to_relative_index(length, context)?,
+        };
+
+        let count = last.saturating_sub(from).min(length - to);
+        // Copies from the end when the ranges overlap and the target is after the source.
+        let offsets: Box<dyn Iterator<Item = usize>> = if from < to && to < from + count {
+            Box::new((0..count).rev())
+        } else {
+            Box::new(0..count)
+        };
+        let target: Value = object.clone().into();
+        for offset in offsets {
+            let (from, to) = (from + offset, to + offset);
+            if object.has_property(&from.into(), context)? {
+                let value = object.get(&from.into(), target.clone(), context)?;
+                target.set_field(to, value, context)?;
+            } else {
+                Self::delete_property_or_throw(&target, to, context)?;
+            }
+        }
+        Ok(target)
+    }
+
+    /// Searches the elements from the end for the first one for which the predicate returns a
+    /// truthy value, returning its index and value.
+    fn find_last_element(
+        this: &Value,
+        args: &[Value],
+        method: &str,
+        context: &mut Context,
+    ) -> Result<Option<(usize, Value)>> {
+        let object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+        let predicate = Self::callback(args, method, context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        for k in (0..length).rev() {
+            let value = object.get(&k.into(), object.clone().into(), context)?;
+            let found = predicate
+                .call(
+                    &this_arg,
+                    &[value.clone(), k.into(), object.clone().into()],
+                    context,
+                )?
+                .to_boolean();
+            if found {
+                return Ok(Some((k, value)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// `Array.prototype.findLast( predicate [ , thisArg ] )`
+    ///
+    /// Returns the last element for which the predicate returns a truthy value, or `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.findlast
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLast
+    pub(crate) fn find_last(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let found = Self::find_last_element(this, args, "findLast", context)?;
+        Ok(found.map(|(_, value)| value).unwrap_or_default())
+    }
+
+    /// `Array.prototype.findLastIndex( predicate [ , thisArg ] )`
+    ///
+    /// Returns the index of the last element for which the predicate returns a truthy value, or
+    /// `-1`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.findlastindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLastIndex
+    pub(crate) fn find_last_index(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let found = Self::find_last_element(this, args, "findLastIndex", context)?;
+        Ok(found.map_or(Value::from(-1), |(index, _)| index.into()))
+    }
+
+    /// Abstract operation `FlattenIntoArray`
+    ///
+    /// Appends the elements of `source` to `target` from `start`, flattening the arrays up to
+    /// `depth` levels deep. If there is a mapper, it is applied to the elements of `source`
+    /// before they are flattened. Returns the next index of `target`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-flattenintoarray
+    fn flatten_into_array(
+        target: &Value,
+        source: &GcObject,
+        start: usize,
+        depth: f64,
+        mapper: Option<(&GcObject, &Value)>,
+        context: &mut Context,
+    ) -> Result<usize> {
+        let length = Self::length_of_array_like(source, context)?;
+        let mut target_index = start;
+        for k in 0..length {
+            if !source.has_property(&k.into(), context)? {
+                continue;
+            }
+            let mut element = source.get(&k.into(), source.clone().into(), context)?;
+            if let Some((mapper, this_arg)) = mapper {
+                element = mapper.call(
+                    this_arg,
+                    &[element, k.into(), source.clone().into()],
+                    context,
+                )?;
+            }
+
+            match element {
+                Value::Object(ref element) if depth > 0.0 && element.is_array() => {
+                    target_index = Self::flatten_into_array(
+                        target,
+                        element,
+                        target_index,
+                        depth - 1.0,
+                        None,
+                        context,
+                    )?;
+                }
+                _ => {
+                    if target_index >= Number::MAX_SAFE_INTEGER as usize {
+                        return Err(context.construct_type_error("array length is too large"));
+                    }
+                    target.set_field(target_index, element, context)?;
+                    target_index += 1;
+                }
+            }
+        }
+        Ok(target_index)
+    }
+
+    /// `Array.prototype.flat( [ depth ] )`
+    ///
+    /// Returns a new array with the elements of the sub-arrays concatenated into it, up to
+    /// `depth` levels deep.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.flat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/flat
+    pub(crate) fn flat(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = this.to_object(context)?;
+        let depth = match args.first() {
+            None | Some(Value::Undefined) => 1.0,
+            Some(depth) => depth.to_integer(context)?.max(0.0),
+        };
+        let array = Self::array_species_create(&object, 0, "flat", context)?;
+        Self::flatten_into_array(&array, &object, 0, depth, None, context)?;
+        Ok(array)
+    }
+
+    /// `Array.prototype.flatMap( mapperFunction [ , thisArg ] )`
+    ///
+    /// Returns a new array with the results of calling the mapper on the elements, where the
+    /// returned arrays are flattened one level deep.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.flatmap
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/flatMap
+    pub(crate) fn flat_map(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = this.to_object(context)?;
+        let mapper = Self::callback(args, "flatMap", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let array = Self::array_species_create(&object, 0, "flatMap", context)?;
+        Self::flatten_into_array(&array, &object, 0, 1.0, Some((&mapper, &this_arg)), context)?;
+        Ok(array)
+    }
+
+    /// `Array.prototype.sort( comparefn )`
+    ///
+    /// Sorts the elements in place with a stable sort. The elements are compared as strings,
+    /// unless there is a comparison function. The `undefined` elements are moved after the
+    /// other elements, and the holes after them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.sort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/sort
+    pub(crate) fn sort(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let compare_fn = match args.first() {
+            None | Some(Value::Undefined) => None,
+            Some(Value::Object(ref object)) if object.is_callable() => Some(object.clone()),
+            Some(_) => {
+                return context
+                    .throw_type_error("Array.prototype.sort: comparefn is not a function")
+            }
+        };
+        let object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+
+        let mut items = Vec::new();
+        let mut undefined_count = 0;
+        for k in 0..length {
+            if object.has_property(&k.into(), context)? {
+                let value = object.get(&k.into(), object.clone().into(), context)?;
+                if value.is_undefined() {
+                    undefined_count += 1;
+                } else {
+                    items.push(value);
+                }
+            }
+        }
+
+        merge_sort(&mut items, &mut |x, y| {
+            if let Some(ref compare_fn) = compare_fn {
+                let order = compare_fn
+                    .call(&Value::undefined(), &[x.clone(), y.clone()], context)?
+                    .to_number(context)?;
+                Ok(order.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+            } else {
+                let x = x.to_string(context)?;
+                let y = y.to_string(context)?;
+                Ok(x.cmp(&y))
+            }
+        })?;
+
+        let target: Value = object.clone().into();
+        let sorted_count = items.len() + undefined_count;
+        let values = items
+            .into_iter()
+            .chain(std::iter::repeat_with(Value::undefined).take(undefined_count));
+        for (k, value) in values.enumerate() {
+            target.set_field(k, value, context)?;
+        }
+        for k in sorted_count..length {
+            Self::delete_property_or_throw(&target, k, context)?;
+        }
+        Ok(target)
+    }
+
+    /// `Array.prototype.splice( start, deleteCount, ...items )`
+    ///
+    /// Removes `deleteCount` elements from `start`, and inserts the items in their place.
+    /// Returns a new array with the removed elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.splice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/splice
+    pub(crate) fn splice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+        let start = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        let items = args.get(2..).unwrap_or_default();
+        let delete_count = match args.len() {
+            0 => 0,
+            1 => length - start,
+            _ => args[1]
+                .to_integer(context)?
+                .max(0.0)
+                .min((length - start) as f64) as usize,
+        };
+        if (length + items.len() - delete_count) as f64 > Number::MAX_SAFE_INTEGER {
+            return context.throw_type_error("Array.prototype.splice: array length is too large");
+        }
+
+        let removed = Self::array_species_create(&object, delete_count, "splice", context)?;
+        for k in 0..delete_count {
+            let from = start + k;
+            if object.has_property(&from.into(), context)? {
+                let value = object.get(&from.into(), object.clone().into(), context)?;
+                removed.set_field(k, value, context)?;
+            }
+        }
+        removed.set_field("length", delete_count, context)?;
+
+        let target: Value = object.clone().into();
+        let move_element = |from: usize, to: usize, context: &mut Context| -> Result<()> {
+            if object.has_property(&from.into(), context)? {
+                let value = object.get(&from.into(), target.clone(), context)?;
+                target.set_field(to, value, context)?;
+            } else {
+                Self::delete_property_or_throw(&target, to, context)?;
+            }
+            Ok(())
+        };
+        if items.len() < delete_count {
+            for k in start..length - delete_count {
+                move_element(k + delete_count, k + items.len(), context)?;
+            }
+            for k in (length - delete_count + items.len()..length).rev() {
+                Self::delete_property_or_throw(&target, k, context)?;
+            }
+        } else if items.len() > delete_count {
+            for k in (start..length - delete_count).rev() {
+                move_element(k + delete_count, k + items.len(), context)?;
+            }
+        }
+        for (k, item) in items.iter().enumerate() {
+            target.set_field(start + k, item.clone(), context)?;
+        }
+        target.set_field("length", length - delete_count + items.len(), context)?;
+
+        Ok(removed)
+    }
+
+    /// `Array.prototype.toLocaleString( )`
+    ///
+    /// Returns the `toLocaleString` of the elements, separated by commas.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toLocaleString
+    pub(crate) fn to_locale_string(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = this.to_object(context)?;
+        let length = Self::length_of_array_like(&object, context)?;
+        let mut result = String::new();
+        for k in 0..length {
+            if k > 0 {
+                result.push(',');
+            }
+            let element = object.get(&k.into(), object.clone().into(), context)?;
+            if !element.is_null_or_undefined() {
+                let method = Value::from(element.to_object(context)?).get_field_with_receiver(
+                    "toLocaleString",
+                    &element,
+                    context,
+                )?;
+                let string = context.call(&method, &element, &[])?;
+                result.push_str(&string.to_string(context)?);
+            }
+        }
+        Ok(result.into())
+    }
+}
+
+/// Sorts `items` with a stable merge sort, where the comparison can fail.
+pub(crate) fn merge_sort<F>(items: &mut Vec<Value>, compare: &mut F) -> Result<()>
+where
+    F: FnMut(&Value, &Value) -> Result<Ordering>,
+{
+    if items.len() <= 1 {
+        return Ok(());
+    }
+
+    let mut right = items.split_off(items.len() / 2);
+    merge_sort(items, compare)?;
+    merge_sort(&mut right, compare)?;
+
+    let left = std::mem::take(items);
+    items.reserve(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(x), Some(y)) = (left.peek(), right.peek()) {
+        // Takes the element on the left when they are equal, which keeps the sort stable.
+        let next = if compare(y, x)? == Ordering::Less {
+            right.next()
+        } else {
+            left.next()
+        };
+        items.extend(next);
+    }
+    items.extend(left);
+    items.extend(right);
+    Ok(())
 }
@@ -1234,3 +1234,326 @@ fn array_spread_non_iterable() {
     "#;
     assert_eq!(forward(&mut engine, init), "true");
 }
+
+#[test]
+fn sort() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "[3, 1, 10, 2].sort().join()"),
+        "\"1,10,2,3\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[3, 1, 2].sort((a, b) => b - a).join()"),
+        "\"3,2,1\""
+    );
+    let init = r#"
+        var sparse = [3, 0, 1, undefined];
+        delete sparse[1];
+        sparse.sort();
+        var people = [{ n: 'a', a: 2 }, { n: 'b', a: 1 }, { n: 'c', a: 2 }, { n: 'd', a: 1 }];
+        var stable = people.sort((x, y) => x.a - y.a).map(p => p.n).join('');
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "sparse.length"), "4");
+    assert_eq!(forward(&mut engine, "sparse[2]"), "undefined");
+    assert_eq!(forward(&mut engine, "2 in sparse"), "true");
+    assert_eq!(forward(&mut engine, "3 in sparse"), "false");
+    assert_eq!(forward(&mut engine, "stable"), "\"bdac\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Array.prototype.sort.call({ length: 2, 0: 'b', 1: 'a' })[0]"
+        ),
+        "\"a\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[2, 1].sort(() => { throw 'boom'; })"),
+        "Uncaught \"boom\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[].sort(1)"),
+        "Uncaught \"TypeError\": \"Array.prototype.sort: comparefn is not a function\""
+    );
+}
+
+#[test]
+fn splice() {
+    let mut engine = Context::new();
+    let init = r#"
+        var grown = [1, 2, 3, 4, 5];
+        var replaced = grown.splice(1, 2, 'x', 'y', 'z');
+        var shrunk = [1, 2, 3, 4, 5];
+        var removed = shrunk.splice(-4, 3);
+        var tail = [1, 2, 3];
+        var rest = tail.splice(1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "grown.join()"), "\"1,x,y,z,4,5\"");
+    assert_eq!(forward(&mut engine, "replaced.join()"), "\"2,3\"");
+    assert_eq!(forward(&mut engine, "shrunk.join()"), "\"1,5\"");
+    assert_eq!(forward(&mut engine, "shrunk.length"), "2");
+    assert_eq!(forward(&mut engine, "removed.join()"), "\"2,3,4\"");
+    assert_eq!(forward(&mut engine, "tail.length"), "1");
+    assert_eq!(forward(&mut engine, "rest.join()"), "\"2,3\"");
+    assert_eq!(forward(&mut engine, "[1, 2].splice().length"), "0");
+}
+
+#[test]
+fn flat() {
+    let mut engine = Context::new();
+    forward(
+        &mut engine,
+        "var nested = [1, [2, [3, [4]]], 0, 5]; delete nested[2];",
+    );
+    assert_eq!(forward(&mut engine, "nested.flat().length"), "4");
+    assert_eq!(forward(&mut engine, "nested.flat(2).length"), "5");
+    assert_eq!(
+        forward(&mut engine, "nested.flat(Infinity).join()"),
+        "\"1,2,3,4,5\""
+    );
+    assert_eq!(forward(&mut engine, "nested.flat(0).length"), "3");
+    assert_eq!(
+        forward(&mut engine, "[1, 2].flatMap(x => [x, [x * 2]]).length"),
+        "4"
+    );
+    assert_eq!(
+        forward(&mut engine, "[1, 2].flatMap(x => [x, x * 2]).join()"),
+        "\"1,2,2,4\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[].flatMap()"),
+        "Uncaught \"TypeError\": \"Array.prototype.flatMap: callback is not a function\""
+    );
+}
+
+#[test]
+fn copy_within() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "[1, 2, 3, 4, 5].copyWithin(0, 3).join()"),
+        "\"4,5,3,4,5\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[1, 2, 3, 4, 5].copyWithin(1, 0, 3).join()"),
+        "\"1,1,2,3,5\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[1, 2, 3, 4, 5].copyWithin(-2, -4, -3).join()"),
+        "\"1,2,3,2,5\""
+    );
+    forward(
+        &mut engine,
+        "var holes = [1, 2, 3]; delete holes[1]; holes.copyWithin(0, 1);",
+    );
+    assert_eq!(forward(&mut engine, "0 in holes"), "false");
+    assert_eq!(forward(&mut engine, "holes[1]"), "3");
+}
+
+#[test]
+fn at() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "[1, 2, 3].at(0)"), "1");
+    assert_eq!(forward(&mut engine, "[1, 2, 3].at(-1)"), "3");
+    assert_eq!(forward(&mut engine, "[1, 2, 3].at(3)"), "undefined");
+    assert_eq!(forward(&mut engine, "[1, 2, 3].at(-4)"), "undefined");
+    assert_eq!(
+        forward(&mut engine, "Array.prototype.at.call('abc', -1)"),
+        "\"c\""
+    );
+}
+
+#[test]
+fn find_last() {
+    let mut engine = Context::new();
+    forward(&mut engine, "var values = [1, 2, 3, 4];");
+    assert_eq!(
+        forward(&mut engine, "values.findLast(x => x % 2 == 1)"),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut engine, "values.findLast(x => x > 4)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut engine, "values.findLastIndex(x => x % 2 == 1)"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut engine, "values.findLastIndex(x => x > 4)"),
+        "-1"
+    );
+    assert_eq!(
+        forward(&mut engine, "values.findLast()"),
+        "Uncaught \"TypeError\": \"Array.prototype.findLast: callback is not a function\""
+    );
+}
+
+#[test]
+fn to_locale_string() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "[1, null, 2, undefined].toLocaleString()"),
+        "\"1,,2,\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "[{ toLocaleString() { return 'x'; } }, 2].toLocaleString()"
+        ),
+        "\"x,2\""
+    );
+}
+
+#[test]
+fn from() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "Array.from('abc').join()"),
+        "\"a,b,c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Array.from(new Set([1, 2, 2])).join()"),
+        "\"1,2\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Array.from({ length: 2, 0: 'x', 1: 'y' }, (v, i) => v + i).join()"
+        ),
+        "\"x0,y1\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Array.from({ length: 2 }).length"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut engine, "Array.from([], 1)"),
+        "Uncaught \"TypeError\": \"Array.from: mapFn is not a function\""
+    );
+
+    let init = r#"
+        function Custom() {}
+        var custom = Array.from.call(Custom, [1, 2]);
+        var closed = false;
+        var iterable = {};
+        iterable[Symbol.iterator] = function() {
+            return {
+                next() { return { value: 1, done: false }; },
+                return() { closed = true; return {}; },
+            };
+        };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(custom) === Custom.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "custom.length"), "2");
+    assert_eq!(
+        forward(&mut engine, "Array.from(iterable, () => { throw 'stop'; })"),
+        "Uncaught \"stop\""
+    );
+    assert_eq!(forward(&mut engine, "closed"), "true");
+}
+
+#[test]
+fn of() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "Array.of(7).length"), "1");
+    assert_eq!(
+        forward(&mut engine, "Array.of(7, 8, 9).join()"),
+        "\"7,8,9\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Array.of.call(undefined, 1, 2).length"),
+        "2"
+    );
+}
+
+#[test]
+fn species() {
+    let mut engine = Context::new();
+    let init = r#"
+        var array = [1, 2, 3];
+        array.constructor = {};
+        array.constructor[Symbol.species] = function(length) {
+            this.created = length;
+        };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Array[Symbol.species] === Array"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "array.map(x => x).created"), "3");
+    assert_eq!(forward(&mut engine, "array.filter(x => x).created"), "0");
+    assert_eq!(forward(&mut engine, "array.slice(1).created"), "2");
+    assert_eq!(forward(&mut engine, "array.splice(0, 1).created"), "1");
+    assert_eq!(forward(&mut engine, "array.concat([4]).created"), "0");
+    assert_eq!(forward(&mut engine, "array.flat().created"), "0");
+
+    forward(&mut engine, "array.constructor[Symbol.species] = 1;");
+    assert_eq!(
+        forward(&mut engine, "array.map(x => x)"),
+        "Uncaught \"TypeError\": \"Array.prototype.map: species constructor is not a constructor\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Array.isArray(Array.prototype.map.call({ length: 1, 0: 1 }, x => x))"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn concat_spreadable() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "[1, [2]].concat([3, [4]], 5).length"),
+        "5"
+    );
+    let init = r#"
+        var base = [1];
+        var joined = base.concat();
+        var spreadable = { length: 2, 0: 'a', 1: 'b' };
+        spreadable[Symbol.isConcatSpreadable] = true;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "joined === base"), "false");
+    assert_eq!(
+        forward(&mut engine, "[].concat(spreadable).join()"),
+        "\"a,b\""
+    );
+}
+
+#[test]
+fn values_is_iterator() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Array.prototype.values === Array.prototype[Symbol.iterator]"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn unscopables() {
+    let mut engine = Context::new();
+    forward(
+        &mut engine,
+        "var unscopables = Array.prototype[Symbol.unscopables];",
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.getPrototypeOf(unscopables)"),
+        "null"
+    );
+    assert_eq!(forward(&mut engine, "unscopables.includes"), "true");
+    assert_eq!(forward(&mut engine, "unscopables.flatMap"), "true");
+    assert_eq!(forward(&mut engine, "unscopables.concat"), "undefined");
+}
@@ -0,0 +1,445 @@
+//! This module implements the global `ArrayBuffer` object.
+//!
+//! An `ArrayBuffer` holds raw binary data, which is read and written through typed arrays and
+//! `DataView` objects.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer
+
+use crate::{
+    builtins::BuiltIn,
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The data of an `ArrayBuffer` object.
+#[derive(Debug, Clone, Finalize)]
+pub struct ArrayBuffer {
+    data: Vec<u8>,
+    /// The maximum length the buffer can be resized to, or `None` if it is not resizable.
+    max_byte_length: Option<usize>,
+}
+
+unsafe impl Trace for ArrayBuffer {
+    unsafe_empty_trace!();
+}
+
+impl ArrayBuffer {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Creates a fixed-length buffer holding `data`.
+    #[inline]
+    pub fn new(data: Vec<u8>) -> Self {
+        Self {
+            data,
+            max_byte_length: None,
+        }
+    }
+
+    /// Returns the bytes of the buffer.
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the bytes of the buffer, which can be modified but not resized.
+    #[inline]
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// Returns the length of the buffer, in bytes.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the buffer can be resized.
+    #[inline]
+    pub fn is_resizable(&self) -> bool {
+        self.max_byte_length.is_some()
+    }
+
+    /// Abstract operation `AllocateArrayBuffer ( constructor, byteLength [ , maxByteLength ] )`
+    ///
+    /// Allocates a buffer of `byte_length` zeroed bytes, throwing a `RangeError` if it can't.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatearraybuffer
+    pub(crate) fn allocate(
+        byte_length: usize,
+        max_byte_length: Option<usize>,
+        context: &mut Context,
+    ) -> Result<Self> {
+        if max_byte_length.is_some_and(|max| byte_length > max) {
+            return Err(
+                context.construct_range_error("ArrayBuffer: length exceeds the maximum length")
+            );
+        }
+
+        let mut data = Vec::new();
+        if data.try_reserve_exact(byte_length).is_err() {
+            return Err(context.construct_range_error("ArrayBuffer: allocation failed"));
+        }
+        data.resize(byte_length, 0);
+
+        Ok(Self {
+            data,
+            max_byte_length,
+        })
+    }
+
+    /// Returns the `ArrayBuffer` object of `this`, throwing a `TypeError` if it isn't one.
+    fn this_array_buffer(this: &Value, method: &str, context: &mut Context) -> Result<GcObject> {
+        match this {
+            Value::Object(ref object) if object.borrow().as_array_buffer().is_some() => {
+                Ok(object.clone())
+            }
+            _ => Err(context.construct_type_error(format!(
+                "ArrayBuffer.prototype.{}: 'this' is not an ArrayBuffer",
+                method
+            ))),
+        }
+    }
+
+    /// `ArrayBuffer( length [ , options ] )`
+    ///
+    /// Creates a buffer of `length` bytes, which can be resized up to the `maxByteLength` of
+    /// `options` if it is given.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer-length
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/ArrayBuffer
+    pub(crate) fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let byte_length = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_index(context)?;
+
+        // GetArrayBufferMaxByteLengthOption ( options )
+        let max_byte_length = match args.get(1) {
+            Some(Value::Object(ref options)) => {
+                let max = options.get(&"maxByteLength".into(), options.clone().into(), context)?;
+                if max.is_undefined() {
+                    None
+                } else {
+                    Some(max.to_index(context)?)
+                }
+            }
+            _ => None,
+        };
+
+        let buffer = Self::allocate(byte_length, max_byte_length, context)?;
+        this.set_data(ObjectData::ArrayBuffer(buffer));
+        Ok(this.clone())
+    }
+
+    /// `ArrayBuffer.isView( arg )`
+    ///
+    /// Returns `true` if the argument is a typed array or a `DataView`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.isview
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/isView
+    pub(crate) fn is_view(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(match args.first() {
+            Some(Value::Object(ref object)) => {
+                let object = object.borrow();
+                object.is_typed_array() || object.as_data_view().is_some()
+            }
+            _ => false,
+        }
+        .into())
+    }
+
+    /// `get ArrayBuffer [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/@@species
+    pub(crate) fn species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `get ArrayBuffer.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/byteLength
+    pub(crate) fn byte_length_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let buffer = Self::this_array_buffer(this, "byteLength", context)?;
+        let buffer = buffer.borrow();
+        let buffer = buffer
+            .as_array_buffer()
+            .expect("checked to be an ArrayBuffer");
+        Ok(buffer.byte_length().into())
+    }
+
+    /// `get ArrayBuffer.prototype.maxByteLength`
+    ///
+    /// Returns the maximum length of a resizable buffer, or the length of a fixed-length buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.maxbytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/maxByteLength
+    pub(crate) fn max_byte_length_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let buffer = Self::this_array_buffer(this, "maxByteLength", context)?;
+        let buffer = buffer.borrow();
+        let buffer = buffer
+            .as_array_buffer()
+            .expect("checked to be an ArrayBuffer");
+        Ok(buffer
+            .max_byte_length
+            .unwrap_or_else(|| buffer.byte_length())
+            .into())
+    }
+
+    /// `get ArrayBuffer.prototype.resizable`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.resizable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resizable
+    pub(crate) fn resizable_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let buffer = Self::this_array_buffer(this, "resizable", context)?;
+        let buffer = buffer.borrow();
+        let buffer = buffer
+            .as_array_buffer()
+            .expect("checked to be an ArrayBuffer");
+        Ok(buffer.is_resizable().into())
+    }
+
+    /// `ArrayBuffer.prototype.resize( newLength )`
+    ///
+    /// Resizes a resizable buffer, zeroing the new bytes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.resize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resize
+    pub(crate) fn resize(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let buffer = Self::this_array_buffer(this, "resize", context)?;
+        let max_byte_length = match buffer
+            .borrow()
+            .as_array_buffer()
+            .expect("checked to be an ArrayBuffer")
+            .max_byte_length
+        {
+            Some(max) => max,
+            None => {
+                return context
+                    .throw_type_error("ArrayBuffer.prototype.resize: buffer is not resizable")
+            }
+        };
+
+        let new_byte_length = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_index(context)?;
+        if new_byte_length > max_byte_length {
+            return context.throw_range_error(
+                "ArrayBuffer.prototype.resize: length exceeds the maximum length",
+            );
+        }
+
+        buffer
+            .borrow_mut()
+            .as_array_buffer_mut()
+            .expect("checked to be an ArrayBuffer")
+            .data
+            .resize(new_byte_length, 0);
+        Ok(Value::undefined())
+    }
+
+    /// `ArrayBuffer.prototype.slice( start, end )`
+    ///
+    /// Returns a new buffer, created by the species constructor of this buffer, with a copy of
+    /// the bytes from `start` to `end`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice
+    pub(crate) fn slice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let buffer = Self::this_array_buffer(this, "slice", context)?;
+        let len = buffer
+            .borrow()
+            .as_array_buffer()
+            .expect("checked to be an ArrayBuffer")
+            .byte_length();
+
+        let first = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(len, context)?;
+        let end = args.get(1).cloned().unwrap_or_default();
+        let last = if end.is_undefined() {
+            len
+        } else {
+            end.to_relative_index(len, context)?
+        };
+        let new_len = last.saturating_sub(first);
+
+        let default_constructor = context
+            .standard_objects()
+            .array_buffer_object()
+            .constructor();
+        let constructor = buffer.species_constructor(default_constructor.into(), context)?;
+        let new = match constructor {
+            Value::Object(ref constructor) => {
+                constructor.construct(&[new_len.into()], &constructor.clone().into(), context)?
+            }
+            _ => unreachable!("species constructors are objects"),
+        };
+
+        let new = match new {
+            Value::Object(ref object) if object.borrow().as_array_buffer().is_some() => {
+                object.clone()
+            }
+            _ => return context.throw_type_error(
+                "ArrayBuffer.prototype.slice: species constructor did not return an ArrayBuffer",
+            ),
+        };
+        if GcObject::equals(&new, &buffer) {
+            return context.throw_type_error(
+                "ArrayBuffer.prototype.slice: species constructor returned the same buffer",
+            );
+        }
+
+        let mut new_buffer = new.borrow_mut();
+        let new_buffer = new_buffer
+            .as_array_buffer_mut()
+            .expect("checked to be an ArrayBuffer");
+        if new_buffer.byte_length() < new_len {
+            return context.throw_type_error(
+                "ArrayBuffer.prototype.slice: species constructor returned a buffer that is too small",
+            );
+        }
+
+        // The species constructor may have resized this buffer.
+        let buffer = buffer.borrow();
+        let data = buffer
+            .as_array_buffer()
+            .expect("checked to be an ArrayBuffer")
+            .data();
+        if first < data.len() {
+            let count = new_len.min(data.len() - first);
+            new_buffer.data[..count].copy_from_slice(&data[first..first + count]);
+        }
+
+        Ok(new.clone().into())
+    }
+}
+
+impl BuiltIn for ArrayBuffer {
+    const NAME: &'static str = "ArrayBuffer";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let species_symbol = context.well_known_symbols().species_symbol();
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+
+        let species = FunctionBuilder::new(context, Self::species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+        let byte_length = FunctionBuilder::new(context, Self::byte_length_getter)
+            .name("get byteLength")
+            .constructable(false)
+            .build();
+        let max_byte_length = FunctionBuilder::new(context, Self::max_byte_length_getter)
+            .name("get maxByteLength")
+            .constructable(false)
+            .build();
+        let resizable = FunctionBuilder::new(context, Self::resizable_getter)
+            .name("get resizable")
+            .constructable(false)
+            .build();
+
+        let array_buffer_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().array_buffer_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::is_view, "isView", 1)
+        .static_accessor(species_symbol, Some(species), None, Attribute::CONFIGURABLE)
+        .accessor(
+            "byteLength",
+            Some(byte_length),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "maxByteLength",
+            Some(max_byte_length),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor("resizable", Some(resizable), None, Attribute::CONFIGURABLE)
+        .method(Self::resize, "resize", 1)
+        .method(Self::slice, "slice", 2)
+        .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+        .callable(false)
+        .build();
+
+        (Self::NAME, array_buffer_object.into(), Self::attribute())
+    }
+}
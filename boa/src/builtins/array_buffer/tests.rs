@@ -0,0 +1,136 @@
+use crate::{forward, Context, Value};
+
+#[test]
+fn constructor() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "new ArrayBuffer(8).byteLength"), "8");
+    assert_eq!(forward(&mut engine, "new ArrayBuffer().byteLength"), "0");
+    assert_eq!(
+        forward(&mut engine, "new ArrayBuffer(-1)"),
+        "Uncaught \"RangeError\": \"Integer index must be >= 0\""
+    );
+    assert_eq!(
+        forward(&mut engine, "ArrayBuffer(8)"),
+        "Uncaught \"TypeError\": \"function object is not callable\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.prototype.toString.call(new ArrayBuffer(1))"
+        ),
+        "\"[object ArrayBuffer]\""
+    );
+}
+
+#[test]
+fn is_view() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "ArrayBuffer.isView(new Uint8Array(1))"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "ArrayBuffer.isView(new DataView(new ArrayBuffer(1)))"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "ArrayBuffer.isView(new ArrayBuffer(1))"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "ArrayBuffer.isView([])"), "false");
+}
+
+#[test]
+fn slice() {
+    let mut engine = Context::new();
+    let init = r#"
+        let bytes = new Uint8Array([1, 2, 3, 4, 5]);
+        let sliced = new Uint8Array(bytes.buffer.slice(1, -1));
+        let empty = bytes.buffer.slice(4, 2);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "sliced.join()"), "\"2,3,4\"");
+    assert_eq!(forward(&mut engine, "empty.byteLength"), "0");
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array(bytes.buffer.slice(-2)).join()"),
+        "\"4,5\""
+    );
+
+    let init = r#"
+        let same = new ArrayBuffer(4);
+        same.constructor = {};
+        same.constructor[Symbol.species] = function() { return same; };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "same.slice(0)"),
+        "Uncaught \"TypeError\": \"ArrayBuffer.prototype.slice: species constructor returned the same buffer\""
+    );
+}
+
+#[test]
+fn resizable() {
+    let mut engine = Context::new();
+    let init = r#"
+        let buffer = new ArrayBuffer(2, { maxByteLength: 8 });
+        let tracking = new Uint8Array(buffer);
+        let fixed = new Uint8Array(buffer, 0, 2);
+        tracking[1] = 7;
+        buffer.resize(6);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "buffer.resizable"), "true");
+    assert_eq!(forward(&mut engine, "buffer.maxByteLength"), "8");
+    assert_eq!(forward(&mut engine, "buffer.byteLength"), "6");
+    assert_eq!(forward(&mut engine, "tracking.length"), "6");
+    assert_eq!(forward(&mut engine, "tracking.join()"), "\"0,7,0,0,0,0\"");
+
+    forward(&mut engine, "buffer.resize(1)");
+    assert_eq!(forward(&mut engine, "tracking.length"), "1");
+    assert_eq!(forward(&mut engine, "fixed.length"), "0");
+    assert_eq!(
+        forward(&mut engine, "fixed.fill(1)"),
+        "Uncaught \"TypeError\": \"TypedArray.prototype.fill: typed array is out of bounds\""
+    );
+
+    assert_eq!(
+        forward(&mut engine, "buffer.resize(9)"),
+        "Uncaught \"RangeError\": \"ArrayBuffer.prototype.resize: length exceeds the maximum length\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new ArrayBuffer(1).resize(1)"),
+        "Uncaught \"TypeError\": \"ArrayBuffer.prototype.resize: buffer is not resizable\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new ArrayBuffer(4, { maxByteLength: 2 })"),
+        "Uncaught \"RangeError\": \"ArrayBuffer: length exceeds the maximum length\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new ArrayBuffer(4).maxByteLength"),
+        "4"
+    );
+}
+
+#[test]
+fn construct_from_host() {
+    let mut engine = Context::new();
+    let buffer = engine.construct_array_buffer(vec![1, 2, 3]);
+    let global = engine.global_object().clone();
+    global
+        .set_field("hostBuffer", Value::from(buffer), &mut engine)
+        .unwrap();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(hostBuffer) === ArrayBuffer.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array(hostBuffer).join()"),
+        "\"1,2,3\""
+    );
+}
@@ -0,0 +1,202 @@
+//! This module implements the execution of async functions.
+//!
+//! Calling an async function returns a promise, and runs its body until the first `await`
+//! expression. The body is then suspended until the awaited value is settled, and resumed as
+//! a promise job. The returned promise is resolved with the value returned by the body, or
+//! rejected with the error it throws.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-async-function-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncFunction
+
+use crate::{
+    builtins::{
+        function::Captures,
+        promise::{Promise, PromiseCapability},
+    },
+    environment::lexical_environment::Environment,
+    exec::coroutine::{Coroutine, CoroutineState, ResumeKind},
+    object::FunctionBuilder,
+    property::Attribute,
+    syntax::ast::node::RcStatementList,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a running async function, shared by the functions that resume it.
+#[derive(Debug, Trace, Finalize)]
+struct AsyncFunctionState {
+    /// The body of the function, which is taken out of the state while it runs.
+    coroutine: Option<Coroutine>,
+    /// The promise returned by the call to the function.
+    capability: PromiseCapability,
+}
+
+/// The execution of async function bodies.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncFunction;
+
+impl AsyncFunction {
+    pub(crate) const NAME: &'static str = "AsyncFunction";
+
+    /// Initializes `%AsyncFunction.prototype%`, the prototype of all async functions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-async-function-prototype-properties
+    pub(crate) fn init_prototype(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let function_prototype = context.standard_objects().function_object().prototype();
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+
+        let mut prototype = context.standard_objects().async_function_prototype();
+        prototype.set_prototype_instance(function_prototype.into());
+        prototype.insert_property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE);
+    }
+
+    /// Starts running the body of an async function, and returns the promise that is settled
+    /// with its result.
+    ///
+    /// `environment` is the function environment of the call, with the arguments already bound.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-async-functions-abstract-operations-async-function-start
+    pub(crate) fn start(
+        body: RcStatementList,
+        environment: Environment,
+        strict: bool,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let constructor = context.standard_objects().promise_object().constructor();
+        let capability = PromiseCapability::new(&constructor.into(), context)?;
+        let promise = capability.promise().clone();
+
+        let coroutine = Coroutine::new(body, environment, strict);
+
+        let state = Captures::new(AsyncFunctionState {
+            coroutine: Some(coroutine),
+            capability,
+        });
+        Self::resume(&state, ResumeKind::Next, Value::undefined(), context)?;
+        Ok(promise)
+    }
+
+    /// Returns a promise rejected with `error`, for an async function whose parameters could
+    /// not be bound to its arguments.
+    pub(crate) fn reject(error: Value, context: &mut Context) -> Result<Value> {
+        let constructor = context.standard_objects().promise_object().constructor();
+        let capability = PromiseCapability::new(&constructor.into(), context)?;
+        capability.reject(error, context)?;
+        Ok(capability.promise().clone())
+    }
+
+    /// Runs the body of the async function until it awaits a value or completes.
+    fn resume(
+        state: &Captures,
+        mut kind: ResumeKind,
+        mut value: Value,
+        context: &mut Context,
+    ) -> Result<()> {
+        loop {
+            let mut coroutine = state
+                .downcast_mut::<AsyncFunctionState>()
+                .expect("captures of the async function changed type")
+                .coroutine
+                .take()
+                .expect("async function resumed while running");
+
+            match coroutine.resume(kind, value, context) {
+                CoroutineState::Suspended(awaited) => {
+                    state
+                        .downcast_mut::<AsyncFunctionState>()
+                        .expect("captures of the async function changed type")
+                        .coroutine = Some(coroutine);
+
+                    // An error while resolving the awaited value is thrown from the `await`.
+                    match Self::r#await(state, awaited, context) {
+                        Ok(()) => return Ok(()),
+                        Err(error) => {
+                            kind = ResumeKind::Throw;
+                            value = error;
+                        }
+                    }
+                }
+                CoroutineState::Completed(result) => {
+                    let capability = state
+                        .downcast_ref::<AsyncFunctionState>()
+                        .expect("captures of the async function changed type")
+                        .capability
+                        .clone();
+                    match result {
+                        Ok(value) => capability.resolve(value, context)?,
+                        Err(reason) => capability.reject(reason, context)?,
+                    };
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    /// Resumes the async function once `value` is settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#await
+    fn r#await(state: &Captures, value: Value, context: &mut Context) -> Result<()> {
+        let constructor = context.standard_objects().promise_object().constructor();
+        let promise = Promise::promise_resolve(&constructor.into(), value, context)?;
+        let promise = promise
+            .as_object()
+            .expect("resolving a value with %Promise% returned a non-object");
+
+        let on_fulfilled = FunctionBuilder::closure(context, Self::await_fulfilled, state.clone())
+            .length(1)
+            .build();
+        let on_rejected = FunctionBuilder::closure(context, Self::await_rejected, state.clone())
+            .length(1)
+            .build();
+        Promise::perform_then(
+            &promise,
+            &on_fulfilled.into(),
+            &on_rejected.into(),
+            None,
+            context,
+        );
+        Ok(())
+    }
+
+    /// Resumes the async function with the value the awaited promise was fulfilled with.
+    fn await_fulfilled(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let value = args.first().cloned().unwrap_or_default();
+        Self::resume(captures, ResumeKind::Next, value, context)?;
+        Ok(Value::undefined())
+    }
+
+    /// Throws the reason the awaited promise was rejected with from the `await` expression.
+    fn await_rejected(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let reason = args.first().cloned().unwrap_or_default();
+        Self::resume(captures, ResumeKind::Throw, reason, context)?;
+        Ok(Value::undefined())
+    }
+}
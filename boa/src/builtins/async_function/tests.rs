@@ -0,0 +1,168 @@
+use crate::{forward, Context};
+
+#[test]
+fn returns_promise() {
+    let mut engine = Context::new();
+    let init = r#"
+        async function f() { return 1; }
+        var p = f();
+        var result;
+        p.then(v => { result = v; });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(p) === Promise.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "result"), "undefined");
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "1");
+}
+
+#[test]
+fn runs_synchronously_until_await() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        async function f() {
+            log.push('start');
+            var v = await 1;
+            log.push('resumed ' + v);
+        }
+        f();
+        log.push('sync');
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "log.join()"), "\"start,sync\"");
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"start,sync,resumed 1\""
+    );
+}
+
+#[test]
+fn await_promises() {
+    let mut engine = Context::new();
+    let init = r#"
+        var resolve;
+        var pending = new Promise(r => { resolve = r; });
+        async function f() {
+            var a = await Promise.resolve(1);
+            var b = await pending;
+            var c = await { then(r) { r(3); } };
+            return a + b + c;
+        }
+        var result;
+        f().then(v => { result = v; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "undefined");
+    forward(&mut engine, "resolve(2)");
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "6");
+}
+
+#[test]
+fn rejections() {
+    let mut engine = Context::new();
+    let init = r#"
+        async function thrower() {
+            await null;
+            throw 'thrown';
+        }
+        async function catcher() {
+            try {
+                await Promise.reject('rejected');
+            } catch (e) {
+                return 'caught ' + e;
+            }
+        }
+        var thrown;
+        thrower().catch(e => { thrown = e; });
+        var caught;
+        catcher().then(v => { caught = v; });
+        var sync_error;
+        (async function() { throw 'sync'; })().catch(e => { sync_error = e; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "thrown"), "\"thrown\"");
+    assert_eq!(forward(&mut engine, "caught"), "\"caught rejected\"");
+    assert_eq!(forward(&mut engine, "sync_error"), "\"sync\"");
+}
+
+#[test]
+fn async_arrow_functions() {
+    let mut engine = Context::new();
+    let init = r#"
+        var obj = {
+            value: 5,
+            method() {
+                var f = async () => this.value + await 1;
+                return f();
+            },
+        };
+        var result;
+        obj.method().then(v => { result = v; });
+        var single;
+        var double = async x => (await x) * 2;
+        double(Promise.resolve(21)).then(v => { single = v; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "6");
+    assert_eq!(forward(&mut engine, "single"), "42");
+}
+
+#[test]
+fn interleaving() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        async function task(name) {
+            for (let i = 0; i < 2; i++) {
+                log.push(name + i);
+                await undefined;
+            }
+        }
+        task('a');
+        task('b');
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "log.join()"), "\"a0,b0,a1,b1\"");
+}
+
+#[test]
+fn async_function_objects() {
+    let mut engine = Context::new();
+    let init = r#"
+        async function f() {}
+        var proto = Object.getPrototypeOf(f);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "proto[Symbol.toStringTag]"),
+        "\"AsyncFunction\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(proto) === Function.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "f.hasOwnProperty('prototype')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut engine, "try { new f(); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
@@ -1,6 +1,7 @@
 use super::BigInt;
 
 use crate::{builtins::Number, Context, Value};
+use num_bigint::Sign;
 use num_traits::cast::{FromPrimitive, ToPrimitive};
 
 use std::convert::TryFrom;
@@ -53,10 +54,10 @@ impl BigInt {
 
     /// Converts the BigInt to a f64 type.
     ///
-    /// Returns `std::f64::INFINITY` if the BigInt is too big.
+    /// Returns `f64::INFINITY` if the BigInt is too big.
     #[inline]
     pub fn to_f64(&self) -> f64 {
-        self.0.to_f64().unwrap_or(std::f64::INFINITY)
+        self.0.to_f64().unwrap_or(f64::INFINITY)
     }
 
     #[inline]
@@ -66,6 +67,29 @@ impl BigInt {
             Err(_) => None,
         }
     }
+
+    /// Converts the BigInt to a `u64`, wrapping it modulo 2^64.
+    ///
+    /// The result can be reinterpreted as an `i64` to get the signed conversion.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-tobiguint64
+    #[inline]
+    pub(crate) fn to_u64_wrapping(&self) -> u64 {
+        let (sign, bytes) = self.0.to_bytes_le();
+        let mut low = [0; 8];
+        let len = bytes.len().min(8);
+        low[..len].copy_from_slice(&bytes[..len]);
+
+        let magnitude = u64::from_le_bytes(low);
+        if sign == Sign::Minus {
+            magnitude.wrapping_neg()
+        } else {
+            magnitude
+        }
+    }
 }
 
 impl From<i64> for BigInt {
@@ -74,6 +98,12 @@ impl From<i64> for BigInt {
     }
 }
 
+impl From<u64> for BigInt {
+    fn from(n: u64) -> BigInt {
+        BigInt(num_bigint::BigInt::from(n))
+    }
+}
+
 impl From<i32> for BigInt {
     fn from(n: i32) -> BigInt {
         BigInt(num_bigint::BigInt::from(n))
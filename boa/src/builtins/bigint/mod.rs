@@ -30,8 +30,6 @@ pub mod equality;
 pub mod operations;
 
 pub use conversions::*;
-pub use equality::*;
-pub use operations::*;
 
 #[cfg(test)]
 mod tests;
@@ -85,8 +83,8 @@ impl BigInt {
     /// [spec]: https://tc39.es/ecma262/#sec-bigint-objects
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/BigInt
     fn constructor(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let data = match args.get(0) {
-            Some(ref value) => value.to_bigint(context)?,
+        let data = match args.first() {
+            Some(value) => value.to_bigint(context)?,
             None => RcBigInt::from(Self::from(0)),
         };
         Ok(Value::from(data))
@@ -140,7 +138,7 @@ impl BigInt {
         } else {
             10
         };
-        if radix < 2 || radix > 36 {
+        if !(2..=36).contains(&radix) {
             return ctx
                 .throw_range_error("radix must be an integer at least 2 and no greater than 36");
         }
@@ -213,7 +211,7 @@ impl BigInt {
 
         let undefined_value = Value::undefined();
 
-        let bits_arg = args.get(0).unwrap_or(&undefined_value);
+        let bits_arg = args.first().unwrap_or(&undefined_value);
         let bigint_arg = args.get(1).unwrap_or(&undefined_value);
 
         let bits = bits_arg.to_index(ctx)?;
@@ -22,7 +22,7 @@ impl BigInt {
             if n > 0 {
                 self.0.shr_assign(n as usize)
             } else {
-                self.0.shl_assign(n.abs() as usize)
+                self.0.shl_assign(n.unsigned_abs() as usize)
             }
 
             Ok(self)
@@ -40,7 +40,7 @@ impl BigInt {
             if n > 0 {
                 self.0.shl_assign(n as usize)
             } else {
-                self.0.shr_assign(n.abs() as usize)
+                self.0.shr_assign(n.unsigned_abs() as usize)
             }
 
             Ok(self)
@@ -62,6 +62,13 @@ impl BigInt {
         use num_integer::Integer;
         Self(self.0.mod_floor(&other.0))
     }
+
+    /// Checks if the `BigInt` is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        use num_traits::Zero;
+        self.0.is_zero()
+    }
 }
 
 macro_rules! impl_bigint_operator {
@@ -58,7 +58,7 @@ impl Boolean {
     /// `[[Call]]` Creates a new boolean primitive
     pub(crate) fn constructor(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
         // Get the argument, if any
-        let data = args.get(0).map(|x| x.to_boolean()).unwrap_or(false);
+        let data = args.first().map(|x| x.to_boolean()).unwrap_or(false);
         this.set_data(ObjectData::Boolean(data));
 
         Ok(Value::from(data))
@@ -59,7 +59,7 @@ pub(crate) fn logger(msg: LogMessage, console_state: &Console) {
 
 /// This represents the `console` formatter.
 pub fn formatter(data: &[Value], ctx: &mut Context) -> Result<String> {
-    let target = data.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+    let target = data.first().cloned().unwrap_or_default().to_string(ctx)?;
 
     match data.len() {
         0 => Ok(String::new()),
@@ -334,7 +334,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#count
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/count
     pub(crate) fn count(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let label = match args.get(0) {
+        let label = match args.first() {
             Some(value) => value.to_string(ctx)?,
             None => "default".into(),
         };
@@ -358,7 +358,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#countreset
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/countReset
     pub(crate) fn count_reset(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let label = match args.get(0) {
+        let label = match args.first() {
             Some(value) => value.to_string(ctx)?,
             None => "default".into(),
         };
@@ -392,12 +392,12 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#time
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/time
     pub(crate) fn time(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let label = match args.get(0) {
+        let label = match args.first() {
             Some(value) => value.to_string(ctx)?,
             None => "default".into(),
         };
 
-        if ctx.console().timer_map.get(&label).is_some() {
+        if ctx.console().timer_map.contains_key(&label) {
             logger(
                 LogMessage::Warn(format!("Timer '{}' already exist", label)),
                 ctx.console(),
@@ -421,7 +421,7 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#timelog
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/timeLog
     pub(crate) fn time_log(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let label = match args.get(0) {
+        let label = match args.first() {
             Some(value) => value.to_string(ctx)?,
             None => "default".into(),
         };
@@ -454,12 +454,12 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#timeend
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/timeEnd
     pub(crate) fn time_end(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let label = match args.get(0) {
+        let label = match args.first() {
             Some(value) => value.to_string(ctx)?,
             None => "default".into(),
         };
 
-        if let Some(t) = ctx.console_mut().timer_map.remove(label.as_str()) {
+        if let Some(t) = ctx.console_mut().timer_map.remove(&label) {
             let time = Self::system_time_in_ms();
             logger(
                 LogMessage::Info(format!("{}: {} ms - timer removed", label, time - t)),
@@ -526,7 +526,7 @@ impl Console {
     pub(crate) fn dir(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let undefined = Value::undefined();
         logger(
-            LogMessage::Info(display_obj(args.get(0).unwrap_or(&undefined), true)),
+            LogMessage::Info(display_obj(args.first().unwrap_or(&undefined), true)),
             ctx.console(),
         );
 
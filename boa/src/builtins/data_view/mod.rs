@@ -0,0 +1,398 @@
+//! This module implements the global `DataView` object.
+//!
+//! A `DataView` reads and writes numbers of any type, with any endianness, at any offset of an
+//! `ArrayBuffer`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-dataview-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView
+
+use crate::{
+    builtins::{typed_array::TypedArrayKind, BuiltIn},
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The data of a `DataView` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct DataView {
+    /// The `ArrayBuffer` object that is viewed.
+    buffer: GcObject,
+    byte_offset: usize,
+    /// The length of the view, or `None` if it tracks the length of a resizable buffer.
+    byte_length: Option<usize>,
+}
+
+/// Defines a `get` and a `set` method of `DataView.prototype` for elements of type `$kind`.
+macro_rules! view_accessors {
+    ($get:ident, $set:ident, $kind:expr) => {
+        pub(crate) fn $get(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+            Self::get_view_value(this, args, $kind, context)
+        }
+
+        pub(crate) fn $set(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+            Self::set_view_value(this, args, $kind, context)
+        }
+    };
+}
+
+impl DataView {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Returns the `ArrayBuffer` object that is viewed.
+    #[inline]
+    pub fn buffer(&self) -> &GcObject {
+        &self.buffer
+    }
+
+    /// Returns the length of the view, in bytes, or `None` if it is out of the bounds of its
+    /// buffer, which can happen after the buffer is shrunk.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewbytelength
+    pub fn byte_length(&self) -> Option<usize> {
+        let buffer_byte_length = self
+            .buffer
+            .borrow()
+            .as_array_buffer()
+            .expect("the buffer of a DataView is an ArrayBuffer")
+            .byte_length();
+        match self.byte_length {
+            _ if self.byte_offset > buffer_byte_length => None,
+            Some(length) if self.byte_offset + length > buffer_byte_length => None,
+            Some(length) => Some(length),
+            None => Some(buffer_byte_length - self.byte_offset),
+        }
+    }
+
+    /// Returns the `DataView` of `this` with its length, throwing a `TypeError` if it isn't one
+    /// or it is out of bounds.
+    fn this_data_view(this: &Value, method: &str, context: &mut Context) -> Result<(Self, usize)> {
+        let view = match this {
+            Value::Object(ref object) => object.borrow().as_data_view().cloned(),
+            _ => None,
+        };
+        let view = match view {
+            Some(view) => view,
+            None => {
+                return Err(context.construct_type_error(format!(
+                    "DataView.prototype.{}: 'this' is not a DataView",
+                    method
+                )))
+            }
+        };
+        match view.byte_length() {
+            Some(length) => Ok((view, length)),
+            None => Err(context.construct_type_error(format!(
+                "DataView.prototype.{}: view is out of bounds",
+                method
+            ))),
+        }
+    }
+
+    /// `DataView( buffer [ , byteOffset [ , byteLength ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview-buffer-byteoffset-bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/DataView
+    pub(crate) fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let buffer = match args.first() {
+            Some(Value::Object(ref object)) if object.borrow().as_array_buffer().is_some() => {
+                object.clone()
+            }
+            _ => return context.throw_type_error("DataView: buffer must be an ArrayBuffer"),
+        };
+
+        let offset = args.get(1).cloned().unwrap_or_default().to_index(context)?;
+        let (buffer_byte_length, resizable) = {
+            let buffer = buffer.borrow();
+            let buffer = buffer
+                .as_array_buffer()
+                .expect("checked to be an ArrayBuffer");
+            (buffer.byte_length(), buffer.is_resizable())
+        };
+        if offset > buffer_byte_length {
+            return context
+                .throw_range_error("DataView: start offset is outside the bounds of the buffer");
+        }
+
+        let byte_length = match args.get(2) {
+            Some(length) if !length.is_undefined() => {
+                let length = length.to_index(context)?;
+                if offset + length > buffer_byte_length {
+                    return context
+                        .throw_range_error("DataView: length is outside the bounds of the buffer");
+                }
+                Some(length)
+            }
+            _ if resizable => None,
+            _ => Some(buffer_byte_length - offset),
+        };
+
+        let view = Self {
+            buffer,
+            byte_offset: offset,
+            byte_length,
+        };
+        // The conversions of the arguments may have shrunk the buffer.
+        if view.byte_length().is_none() {
+            return context
+                .throw_range_error("DataView: length is outside the bounds of the buffer");
+        }
+
+        this.set_data(ObjectData::DataView(view));
+        Ok(this.clone())
+    }
+
+    /// `get DataView.prototype.buffer`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.buffer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/buffer
+    pub(crate) fn buffer_getter(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        if let Value::Object(ref object) = this {
+            if let Some(view) = object.borrow().as_data_view() {
+                return Ok(view.buffer.clone().into());
+            }
+        }
+        context.throw_type_error("DataView.prototype.buffer: 'this' is not a DataView")
+    }
+
+    /// `get DataView.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteLength
+    pub(crate) fn byte_length_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (_, length) = Self::this_data_view(this, "byteLength", context)?;
+        Ok(length.into())
+    }
+
+    /// `get DataView.prototype.byteOffset`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-dataview.prototype.byteoffset
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteOffset
+    pub(crate) fn byte_offset_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (view, _) = Self::this_data_view(this, "byteOffset", context)?;
+        Ok(view.byte_offset.into())
+    }
+
+    /// Abstract operation `GetViewValue ( view, requestIndex, isLittleEndian, type )`
+    ///
+    /// Reads an element of type `kind` at the offset given by the first argument, in big endian
+    /// order unless the second argument is `true`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewvalue
+    fn get_view_value(
+        this: &Value,
+        args: &[Value],
+        kind: TypedArrayKind,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let method = format!("get{}", kind.name().trim_end_matches("Array"));
+        if !matches!(this, Value::Object(ref object) if object.borrow().as_data_view().is_some()) {
+            return context.throw_type_error(format!(
+                "DataView.prototype.{}: 'this' is not a DataView",
+                method
+            ));
+        }
+        let index = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_index(context)?;
+        let little_endian = args.get(1).is_some_and(Value::to_boolean);
+
+        let (view, length) = Self::this_data_view(this, &method, context)?;
+        let element_size = kind.element_size();
+        if length < element_size || index > length - element_size {
+            return context.throw_range_error(format!(
+                "DataView.prototype.{}: offset is outside the bounds of the view",
+                method
+            ));
+        }
+
+        let start = view.byte_offset + index;
+        let buffer = view.buffer.borrow();
+        let data = buffer
+            .as_array_buffer()
+            .expect("the buffer of a DataView is an ArrayBuffer")
+            .data();
+        Ok(kind.read(&data[start..start + element_size], little_endian))
+    }
+
+    /// Abstract operation `SetViewValue ( view, requestIndex, isLittleEndian, type, value )`
+    ///
+    /// Stores the third argument as an element of type `kind` at the offset given by the first
+    /// argument, in big endian order unless the third argument is `true`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setviewvalue
+    fn set_view_value(
+        this: &Value,
+        args: &[Value],
+        kind: TypedArrayKind,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let method = format!("set{}", kind.name().trim_end_matches("Array"));
+        if !matches!(this, Value::Object(ref object) if object.borrow().as_data_view().is_some()) {
+            return context.throw_type_error(format!(
+                "DataView.prototype.{}: 'this' is not a DataView",
+                method
+            ));
+        }
+        let index = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_index(context)?;
+        let value = kind.to_element(&args.get(1).cloned().unwrap_or_default(), context)?;
+        let little_endian = args.get(2).is_some_and(Value::to_boolean);
+
+        let (view, length) = Self::this_data_view(this, &method, context)?;
+        let element_size = kind.element_size();
+        if length < element_size || index > length - element_size {
+            return context.throw_range_error(format!(
+                "DataView.prototype.{}: offset is outside the bounds of the view",
+                method
+            ));
+        }
+
+        let start = view.byte_offset + index;
+        let mut buffer = view.buffer.borrow_mut();
+        let data = buffer
+            .as_array_buffer_mut()
+            .expect("the buffer of a DataView is an ArrayBuffer")
+            .data_mut();
+        kind.write(
+            &value,
+            &mut data[start..start + element_size],
+            little_endian,
+        );
+        Ok(Value::undefined())
+    }
+
+    view_accessors!(get_int8, set_int8, TypedArrayKind::Int8);
+    view_accessors!(get_uint8, set_uint8, TypedArrayKind::Uint8);
+    view_accessors!(get_int16, set_int16, TypedArrayKind::Int16);
+    view_accessors!(get_uint16, set_uint16, TypedArrayKind::Uint16);
+    view_accessors!(get_int32, set_int32, TypedArrayKind::Int32);
+    view_accessors!(get_uint32, set_uint32, TypedArrayKind::Uint32);
+    view_accessors!(get_float32, set_float32, TypedArrayKind::Float32);
+    view_accessors!(get_float64, set_float64, TypedArrayKind::Float64);
+    view_accessors!(get_big_int64, set_big_int64, TypedArrayKind::BigInt64);
+    view_accessors!(get_big_uint64, set_big_uint64, TypedArrayKind::BigUint64);
+}
+
+impl BuiltIn for DataView {
+    const NAME: &'static str = "DataView";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+
+        let buffer = FunctionBuilder::new(context, Self::buffer_getter)
+            .name("get buffer")
+            .constructable(false)
+            .build();
+        let byte_length = FunctionBuilder::new(context, Self::byte_length_getter)
+            .name("get byteLength")
+            .constructable(false)
+            .build();
+        let byte_offset = FunctionBuilder::new(context, Self::byte_offset_getter)
+            .name("get byteOffset")
+            .constructable(false)
+            .build();
+
+        let data_view_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().data_view_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor("buffer", Some(buffer), None, Attribute::CONFIGURABLE)
+        .accessor(
+            "byteLength",
+            Some(byte_length),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "byteOffset",
+            Some(byte_offset),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::get_int8, "getInt8", 1)
+        .method(Self::set_int8, "setInt8", 2)
+        .method(Self::get_uint8, "getUint8", 1)
+        .method(Self::set_uint8, "setUint8", 2)
+        .method(Self::get_int16, "getInt16", 1)
+        .method(Self::set_int16, "setInt16", 2)
+        .method(Self::get_uint16, "getUint16", 1)
+        .method(Self::set_uint16, "setUint16", 2)
+        .method(Self::get_int32, "getInt32", 1)
+        .method(Self::set_int32, "setInt32", 2)
+        .method(Self::get_uint32, "getUint32", 1)
+        .method(Self::set_uint32, "setUint32", 2)
+        .method(Self::get_float32, "getFloat32", 1)
+        .method(Self::set_float32, "setFloat32", 2)
+        .method(Self::get_float64, "getFloat64", 1)
+        .method(Self::set_float64, "setFloat64", 2)
+        .method(Self::get_big_int64, "getBigInt64", 1)
+        .method(Self::set_big_int64, "setBigInt64", 2)
+        .method(Self::get_big_uint64, "getBigUint64", 1)
+        .method(Self::set_big_uint64, "setBigUint64", 2)
+        .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+        .callable(false)
+        .build();
+
+        (Self::NAME, data_view_object.into(), Self::attribute())
+    }
+}
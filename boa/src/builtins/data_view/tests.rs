@@ -0,0 +1,112 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut engine = Context::new();
+    let init = r#"
+        let buffer = new ArrayBuffer(8);
+        let view = new DataView(buffer, 2, 4);
+        let rest = new DataView(buffer, 3);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "view.buffer === buffer"), "true");
+    assert_eq!(forward(&mut engine, "view.byteOffset"), "2");
+    assert_eq!(forward(&mut engine, "view.byteLength"), "4");
+    assert_eq!(forward(&mut engine, "rest.byteLength"), "5");
+    assert_eq!(
+        forward(&mut engine, "new DataView({})"),
+        "Uncaught \"TypeError\": \"DataView: buffer must be an ArrayBuffer\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new DataView(buffer, 9)"),
+        "Uncaught \"RangeError\": \"DataView: start offset is outside the bounds of the buffer\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new DataView(buffer, 4, 5)"),
+        "Uncaught \"RangeError\": \"DataView: length is outside the bounds of the buffer\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(view)"),
+        "\"[object DataView]\""
+    );
+}
+
+#[test]
+fn endianness() {
+    let mut engine = Context::new();
+    let init = r#"
+        let view = new DataView(new ArrayBuffer(8));
+        view.setUint16(0, 0x1234);
+        view.setUint16(2, 0x1234, true);
+        let bytes = new Uint8Array(view.buffer);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "bytes.join()"),
+        "\"18,52,52,18,0,0,0,0\""
+    );
+    assert_eq!(forward(&mut engine, "view.getUint16(0)"), "4660");
+    assert_eq!(forward(&mut engine, "view.getUint16(0, true)"), "13330");
+    assert_eq!(forward(&mut engine, "view.getInt8(0)"), "18");
+
+    forward(&mut engine, "view.setFloat64(0, Math.PI, true);");
+    assert_eq!(
+        forward(&mut engine, "view.getFloat64(0, true)"),
+        "3.141592653589793"
+    );
+    forward(&mut engine, "view.setInt32(4, -2);");
+    assert_eq!(forward(&mut engine, "view.getInt32(4)"), "-2");
+    assert_eq!(forward(&mut engine, "view.getUint32(4)"), "4294967294");
+}
+
+#[test]
+fn bigint_accessors() {
+    let mut engine = Context::new();
+    let init = r#"
+        let view = new DataView(new ArrayBuffer(8));
+        view.setBigInt64(0, -2n, true);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "view.getBigInt64(0, true)"), "-2n");
+    assert_eq!(
+        forward(&mut engine, "view.getBigUint64(0, true)"),
+        "18446744073709551614n"
+    );
+    assert_eq!(forward(&mut engine, "view.getUint8(0)"), "254");
+}
+
+#[test]
+fn out_of_bounds() {
+    let mut engine = Context::new();
+    forward(
+        &mut engine,
+        "let view = new DataView(new ArrayBuffer(4), 1);",
+    );
+    assert_eq!(
+        forward(&mut engine, "view.getUint32(0)"),
+        "Uncaught \"RangeError\": \"DataView.prototype.getUint32: offset is outside the bounds of the view\""
+    );
+    assert_eq!(
+        forward(&mut engine, "view.setInt16(2, 1)"),
+        "Uncaught \"RangeError\": \"DataView.prototype.setInt16: offset is outside the bounds of the view\""
+    );
+    assert_eq!(
+        forward(&mut engine, "view.getInt8(-1)"),
+        "Uncaught \"RangeError\": \"Integer index must be >= 0\""
+    );
+    assert_eq!(
+        forward(&mut engine, "DataView.prototype.getInt8.call({}, 0)"),
+        "Uncaught \"TypeError\": \"DataView.prototype.getInt8: 'this' is not a DataView\""
+    );
+
+    let init = r#"
+        let buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+        let fixed = new DataView(buffer, 0, 4);
+        buffer.resize(2);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "fixed.byteLength"),
+        "Uncaught \"TypeError\": \"DataView.prototype.byteLength: view is out of bounds\""
+    );
+}
@@ -3,7 +3,7 @@ mod tests;
 
 use crate::{
     builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData},
+    object::{ConstructorBuilder, FunctionBuilder, ObjectData},
     property::Attribute,
     value::{PreferredType, Value},
     BoaProfiler, Context, Result,
@@ -115,6 +115,13 @@ impl BuiltIn for Date {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        let symbol_to_primitive = context.well_known_symbols().to_primitive_symbol();
+        let to_primitive = FunctionBuilder::new(context, Self::to_primitive)
+            .name("[Symbol.toPrimitive]")
+            .length(1)
+            .constructable(false)
+            .build();
+
         let date_object = ConstructorBuilder::new(context, Self::constructor)
             .name(Self::NAME)
             .length(Self::LENGTH)
@@ -178,6 +185,11 @@ impl BuiltIn for Date {
             .method(getter_method!(to_time_string), "toTimeString", 0)
             .method(getter_method!(to_utc_string), "toUTCString", 0)
             .method(getter_method!(value_of), "valueOf", 0)
+            .property(
+                symbol_to_primitive,
+                to_primitive,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .static_method(Self::now, "now", 0)
             .static_method(Self::parse, "parse", 1)
             .static_method(Self::utc, "UTC", 7)
@@ -226,7 +238,8 @@ impl Date {
         #[inline]
         fn num_days_in(year: i32, month: u32) -> i32 {
             let month = month + 1; // zero-based for calculations
-            NaiveDate::from_ymd(
+            let first = NaiveDate::from_ymd_opt(year, month, 1).expect("invalid month");
+            let next = NaiveDate::from_ymd_opt(
                 match month {
                     12 => year + 1,
                     _ => year,
@@ -237,8 +250,8 @@ impl Date {
                 },
                 1,
             )
-            .signed_duration_since(NaiveDate::from_ymd(year, month, 1))
-            .num_days() as i32
+            .expect("invalid month");
+            next.signed_duration_since(first).num_days() as i32
         }
 
         #[inline]
@@ -302,7 +315,8 @@ impl Date {
                 + Duration::seconds(second)
                 + Duration::milliseconds(millisecond);
             NaiveDate::from_ymd_opt(year, month as u32 + 1, day as u32 + 1)
-                .and_then(|dt| dt.and_hms(0, 0, 0).checked_add_signed(duration))
+                .and_then(|dt| dt.and_hms_opt(0, 0, 0))
+                .and_then(|dt| dt.checked_add_signed(duration))
                 .and_then(|dt| {
                     if utc {
                         Some(Utc.from_utc_datetime(&dt).naive_utc())
@@ -324,7 +338,8 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date-constructor
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/Date
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        if this.is_global() {
+        // When `Date` is called as a function, `this` is not the new object created by `new`.
+        if !this.is_object() || this.is_global() {
             Self::make_date_string()
         } else if args.is_empty() {
             Self::make_date_now(this)
@@ -384,7 +399,7 @@ impl Date {
         let tv = match this_time_value(value, ctx) {
             Ok(dt) => dt.0,
             _ => match value.to_primitive(ctx, PreferredType::Default)? {
-                Value::String(ref str) => match chrono::DateTime::parse_from_rfc3339(&str) {
+                Value::String(ref str) => match DateTime::parse_from_rfc3339(str) {
                     Ok(dt) => Some(dt.naive_utc()),
                     _ => None,
                 },
@@ -392,7 +407,7 @@ impl Date {
                     let tv = tv.to_number(ctx)?;
                     let secs = (tv / 1_000f64) as i64;
                     let nsecs = ((tv % 1_000f64) * 1_000_000f64) as u32;
-                    NaiveDateTime::from_timestamp_opt(secs, nsecs)
+                    DateTime::from_timestamp(secs, nsecs).map(|dt| dt.naive_utc())
                 }
             },
         };
@@ -440,7 +455,7 @@ impl Date {
         let sec = sec as u32;
         let milli = milli as u32;
 
-        let year = if 0 <= year && year <= 99 {
+        let year = if (0..=99).contains(&year) {
             1900 + year
         } else {
             year
@@ -619,7 +634,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTimezoneOffset
     #[inline]
     pub fn get_timezone_offset() -> f64 {
-        let offset_seconds = chrono::Local::now().offset().local_minus_utc() as f64;
+        let offset_seconds = Local::now().offset().local_minus_utc() as f64;
         offset_seconds / 60f64
     }
 
@@ -922,7 +937,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setYear
     pub fn set_year(&mut self, year: Option<f64>, month: Option<f64>, day: Option<f64>) {
         if let Some(mut year) = year {
-            year += if 0f64 <= year && year < 100f64 {
+            year += if (0f64..100f64).contains(&year) {
                 1900f64
             } else {
                 0f64
@@ -1231,6 +1246,37 @@ impl Date {
         self.get_time()
     }
 
+    /// `Date.prototype [ @@toPrimitive ] ( hint )`
+    ///
+    /// Converts a `Date` object to a primitive value. Unlike other objects, dates are converted
+    /// to strings when no type is preferred.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype-@@toprimitive
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/@@toPrimitive
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_primitive(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = match this {
+            Value::Object(ref object) => object,
+            _ => {
+                return ctx.throw_type_error(
+                    "Date.prototype[Symbol.toPrimitive]: 'this' is not an object",
+                )
+            }
+        };
+        let hint = match args.first() {
+            Some(Value::String(ref hint)) if hint == "string" || hint == "default" => {
+                PreferredType::String
+            }
+            Some(Value::String(ref hint)) if hint == "number" => PreferredType::Number,
+            _ => return ctx.throw_type_error("Date.prototype[Symbol.toPrimitive]: invalid hint"),
+        };
+        object.ordinary_to_primitive(ctx, hint)
+    }
+
     /// `Date.now()`
     ///
     /// The static `Date.now()` method returns the number of milliseconds elapsed since January 1, 1970 00:00:00 UTC.
@@ -1266,7 +1312,7 @@ impl Date {
         }
 
         match DateTime::parse_from_rfc3339(&args[0].to_string(ctx)?) {
-            Ok(v) => Ok(Value::number(v.naive_utc().timestamp_millis() as f64)),
+            Ok(v) => Ok(Value::number(v.timestamp_millis() as f64)),
             _ => Ok(Value::number(f64::NAN)),
         }
     }
@@ -1283,7 +1329,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/UTC
     pub(crate) fn utc(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let year = args
-            .get(0)
+            .first()
             .map_or(Ok(f64::NAN), |value| value.to_number(ctx))?;
         let month = args.get(1).map_or(Ok(1f64), |value| value.to_number(ctx))?;
         let day = args.get(2).map_or(Ok(1f64), |value| value.to_number(ctx))?;
@@ -1304,7 +1350,7 @@ impl Date {
         let sec = sec as u32;
         let milli = milli as u32;
 
-        let year = if 0 <= year && year <= 99 {
+        let year = if (0..=99).contains(&year) {
             1900 + year
         } else {
             year
@@ -1313,7 +1359,7 @@ impl Date {
         NaiveDate::from_ymd_opt(year, month + 1, day)
             .and_then(|f| f.and_hms_milli_opt(hour, min, sec, milli))
             .map_or(Ok(Value::number(f64::NAN)), |f| {
-                Ok(Value::number(f.timestamp_millis() as f64))
+                Ok(Value::number(f.and_utc().timestamp_millis() as f64))
             })
     }
 }
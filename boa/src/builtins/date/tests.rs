@@ -106,7 +106,8 @@ fn date_ctor_call_string() -> Result<(), Box<dyn std::error::Error>> {
 
     // Internal date is expressed as UTC
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 06, 08).and_hms_milli(15, 46, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 06, 08)
+            .and_then(|date| date.and_hms_milli_opt(15, 46, 15, 779)),
         date_time
     );
     Ok(())
@@ -127,7 +128,8 @@ fn date_ctor_call_number() -> Result<(), Box<dyn std::error::Error>> {
 
     let date_time = forward_dt_utc(&mut engine, "new Date(1594199775779)");
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         date_time
     );
     Ok(())
@@ -140,7 +142,8 @@ fn date_ctor_call_date() -> Result<(), Box<dyn std::error::Error>> {
     let date_time = forward_dt_utc(&mut engine, "new Date(new Date(1594199775779))");
 
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         date_time
     );
     Ok(())
@@ -153,7 +156,8 @@ fn date_ctor_call_multiple() -> Result<(), Box<dyn std::error::Error>> {
     let date_time = forward_dt_local(&mut engine, "new Date(2020, 06, 08, 09, 16, 15, 779)");
 
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         date_time
     );
     Ok(())
@@ -166,7 +170,8 @@ fn date_ctor_call_multiple_90s() -> Result<(), Box<dyn std::error::Error>> {
     let date_time = forward_dt_local(&mut engine, "new Date(99, 06, 08, 09, 16, 15, 779)");
 
     assert_eq!(
-        Some(NaiveDate::from_ymd(1999, 07, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(1999, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         date_time
     );
     Ok(())
@@ -196,12 +201,12 @@ fn date_ctor_now_call() -> Result<(), Box<dyn std::error::Error>> {
     let mut engine = Context::new();
 
     let date_time = forward(&mut engine, "Date.now()");
-    let dt1 = u64::from_str_radix(&date_time, 10)?;
+    let dt1 = date_time.parse::<u64>()?;
 
     std::thread::sleep(std::time::Duration::from_millis(1));
 
     let date_time = forward(&mut engine, "Date.now()");
-    let dt2 = u64::from_str_radix(&date_time, 10)?;
+    let dt2 = date_time.parse::<u64>()?;
 
     assert_ne!(dt1, dt2);
     Ok(())
@@ -378,9 +383,10 @@ fn date_proto_get_time() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     let ts = Local
-        .ymd(2020, 07, 08)
-        .and_hms_milli(09, 16, 15, 779)
-        .timestamp_millis() as f64;
+        .with_ymd_and_hms(2020, 07, 08, 09, 16, 15)
+        .unwrap()
+        .timestamp_millis() as f64
+        + 779.0;
     assert_eq!(Ok(Value::Rational(ts)), actual);
 
     let actual = forward_val(&mut engine, "new Date(1/0).getTime()");
@@ -421,7 +427,7 @@ fn date_proto_get_timezone_offset() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     // The value of now().offset() depends on the host machine, so we have to replicate the method code here.
-    let offset_seconds = chrono::Local::now().offset().local_minus_utc() as f64;
+    let offset_seconds = Local::now().offset().local_minus_utc() as f64;
     let offset_minutes = offset_seconds / 60f64;
     assert_eq!(Ok(Value::Rational(offset_minutes)), actual);
 
@@ -564,7 +570,8 @@ fn date_proto_set_date() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setDate(21); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 21).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 21)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -574,7 +581,8 @@ fn date_proto_set_date() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setDate(0); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 06, 30).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 06, 30)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -596,7 +604,8 @@ fn date_proto_set_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setFullYear(2012); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2012, 07, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2012, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -605,7 +614,8 @@ fn date_proto_set_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setFullYear(2012, 8); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2012, 09, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2012, 09, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -614,7 +624,8 @@ fn date_proto_set_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setFullYear(2012, 8, 10); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2012, 09, 10).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2012, 09, 10)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -625,7 +636,8 @@ fn date_proto_set_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 07, 08, 09, 16, 15, 779); dt.setFullYear(2012, 35); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2014, 12, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2014, 12, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -634,7 +646,8 @@ fn date_proto_set_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 07, 08, 09, 16, 15, 779); dt.setFullYear(2012, -35); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2009, 02, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2009, 02, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -643,7 +656,8 @@ fn date_proto_set_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 07, 08, 09, 16, 15, 779); dt.setFullYear(2012, 9, 950); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2015, 05, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2015, 05, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -652,7 +666,8 @@ fn date_proto_set_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 07, 08, 09, 16, 15, 779); dt.setFullYear(2012, 9, -950); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2010, 02, 23).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2010, 02, 23)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -668,7 +683,8 @@ fn date_proto_set_hours() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setHours(11); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(11, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(11, 16, 15, 779)),
         actual
     );
 
@@ -677,7 +693,8 @@ fn date_proto_set_hours() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setHours(11, 35); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(11, 35, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(11, 35, 15, 779)),
         actual
     );
 
@@ -686,7 +703,8 @@ fn date_proto_set_hours() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setHours(11, 35, 23); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(11, 35, 23, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(11, 35, 23, 779)),
         actual
     );
 
@@ -695,7 +713,8 @@ fn date_proto_set_hours() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setHours(11, 35, 23, 537); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(11, 35, 23, 537)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(11, 35, 23, 537)),
         actual
     );
 
@@ -706,7 +725,8 @@ fn date_proto_set_hours() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setHours(10000, 20000, 30000, 40123); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2021, 09, 11).and_hms_milli(21, 40, 40, 123)),
+        NaiveDate::from_ymd_opt(2021, 09, 11)
+            .and_then(|date| date.and_hms_milli_opt(21, 40, 40, 123)),
         actual
     );
 
@@ -722,7 +742,8 @@ fn date_proto_set_milliseconds() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setMilliseconds(597); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 15, 597)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 597)),
         actual
     );
 
@@ -734,7 +755,8 @@ fn date_proto_set_milliseconds() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setMilliseconds(40123); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 55, 123)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 55, 123)),
         actual
     );
 
@@ -750,7 +772,8 @@ fn date_proto_set_minutes() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setMinutes(11); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 11, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 11, 15, 779)),
         actual
     );
 
@@ -759,7 +782,8 @@ fn date_proto_set_minutes() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setMinutes(11, 35); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 11, 35, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 11, 35, 779)),
         actual
     );
 
@@ -768,7 +792,8 @@ fn date_proto_set_minutes() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setMinutes(11, 35, 537); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 11, 35, 537)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 11, 35, 537)),
         actual
     );
 
@@ -780,7 +805,8 @@ fn date_proto_set_minutes() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setMinutes(600000, 30000, 40123); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2021, 08, 29).and_hms_milli(09, 20, 40, 123)),
+        NaiveDate::from_ymd_opt(2021, 08, 29)
+            .and_then(|date| date.and_hms_milli_opt(09, 20, 40, 123)),
         actual
     );
 
@@ -796,7 +822,8 @@ fn date_proto_set_month() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setMonth(11); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 12, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 12, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -805,7 +832,8 @@ fn date_proto_set_month() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setMonth(11, 16); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 12, 16).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 12, 16)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -817,7 +845,8 @@ fn date_proto_set_month() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 07, 08, 09, 16, 15, 779); dt.setMonth(40, 83); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2023, 07, 22).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2023, 07, 22)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -833,7 +862,8 @@ fn date_proto_set_seconds() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setSeconds(11); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 11, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 11, 779)),
         actual
     );
 
@@ -842,7 +872,8 @@ fn date_proto_set_seconds() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setSeconds(11, 487); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 11, 487)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 11, 487)),
         actual
     );
 
@@ -854,7 +885,8 @@ fn date_proto_set_seconds() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 07, 08, 09, 16, 15, 779); dt.setSeconds(40000000, 40123); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2021, 11, 14).and_hms_milli(08, 23, 20, 123)),
+        NaiveDate::from_ymd_opt(2021, 11, 14)
+            .and_then(|date| date.and_hms_milli_opt(08, 23, 20, 123)),
         actual
     );
 
@@ -870,7 +902,8 @@ fn set_year() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setYear(98); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(1998, 07, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(1998, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -879,7 +912,8 @@ fn set_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(2020, 06, 08, 09, 16, 15, 779); dt.setYear(2001); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2001, 07, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2001, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -895,7 +929,8 @@ fn date_proto_set_time() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(); dt.setTime(new Date(2020, 06, 08, 09, 16, 15, 779).getTime()); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -911,7 +946,8 @@ fn date_proto_set_utc_date() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCDate(21); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 21).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 21)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -921,7 +957,8 @@ fn date_proto_set_utc_date() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCDate(0); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 06, 30).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 06, 30)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -943,7 +980,8 @@ fn date_proto_set_utc_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCFullYear(2012); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2012, 07, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2012, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -952,7 +990,8 @@ fn date_proto_set_utc_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCFullYear(2012, 8); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2012, 09, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2012, 09, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -961,7 +1000,8 @@ fn date_proto_set_utc_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCFullYear(2012, 8, 10); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2012, 09, 10).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2012, 09, 10)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -972,7 +1012,8 @@ fn date_proto_set_utc_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 07, 08, 09, 16, 15, 779)); dt.setUTCFullYear(2012, 35); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2014, 12, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2014, 12, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -981,7 +1022,8 @@ fn date_proto_set_utc_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 07, 08, 09, 16, 15, 779)); dt.setUTCFullYear(2012, -35); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2009, 02, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2009, 02, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -990,7 +1032,8 @@ fn date_proto_set_utc_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 07, 08, 09, 16, 15, 779)); dt.setUTCFullYear(2012, 9, 950); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2015, 05, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2015, 05, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -999,7 +1042,8 @@ fn date_proto_set_utc_full_year() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 07, 08, 09, 16, 15, 779)); dt.setUTCFullYear(2012, 9, -950); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2010, 02, 23).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2010, 02, 23)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -1015,7 +1059,8 @@ fn date_proto_set_utc_hours() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCHours(11); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(11, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(11, 16, 15, 779)),
         actual
     );
 
@@ -1024,7 +1069,8 @@ fn date_proto_set_utc_hours() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCHours(11, 35); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(11, 35, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(11, 35, 15, 779)),
         actual
     );
 
@@ -1033,7 +1079,8 @@ fn date_proto_set_utc_hours() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCHours(11, 35, 23); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(11, 35, 23, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(11, 35, 23, 779)),
         actual
     );
 
@@ -1042,7 +1089,8 @@ fn date_proto_set_utc_hours() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCHours(11, 35, 23, 537); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(11, 35, 23, 537)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(11, 35, 23, 537)),
         actual
     );
 
@@ -1053,7 +1101,8 @@ fn date_proto_set_utc_hours() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCHours(10000, 20000, 30000, 40123); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2021, 09, 11).and_hms_milli(21, 40, 40, 123)),
+        NaiveDate::from_ymd_opt(2021, 09, 11)
+            .and_then(|date| date.and_hms_milli_opt(21, 40, 40, 123)),
         actual
     );
 
@@ -1069,7 +1118,8 @@ fn date_proto_set_utc_milliseconds() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCMilliseconds(597); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 15, 597)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 597)),
         actual
     );
 
@@ -1081,7 +1131,8 @@ fn date_proto_set_utc_milliseconds() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCMilliseconds(40123); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 55, 123)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 55, 123)),
         actual
     );
 
@@ -1097,7 +1148,8 @@ fn date_proto_set_utc_minutes() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCMinutes(11); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 11, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 11, 15, 779)),
         actual
     );
 
@@ -1106,7 +1158,8 @@ fn date_proto_set_utc_minutes() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCMinutes(11, 35); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 11, 35, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 11, 35, 779)),
         actual
     );
 
@@ -1115,7 +1168,8 @@ fn date_proto_set_utc_minutes() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCMinutes(11, 35, 537); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 11, 35, 537)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 11, 35, 537)),
         actual
     );
 
@@ -1127,7 +1181,8 @@ fn date_proto_set_utc_minutes() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCMinutes(600000, 30000, 40123); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2021, 08, 29).and_hms_milli(09, 20, 40, 123)),
+        NaiveDate::from_ymd_opt(2021, 08, 29)
+            .and_then(|date| date.and_hms_milli_opt(09, 20, 40, 123)),
         actual
     );
 
@@ -1143,7 +1198,8 @@ fn date_proto_set_utc_month() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCMonth(11); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 12, 08).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 12, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -1152,7 +1208,8 @@ fn date_proto_set_utc_month() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCMonth(11, 16); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 12, 16).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2020, 12, 16)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -1164,7 +1221,8 @@ fn date_proto_set_utc_month() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 07, 08, 09, 16, 15, 779)); dt.setUTCMonth(40, 83); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2023, 07, 22).and_hms_milli(09, 16, 15, 779)),
+        NaiveDate::from_ymd_opt(2023, 07, 22)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 15, 779)),
         actual
     );
 
@@ -1180,7 +1238,8 @@ fn date_proto_set_utc_seconds() -> Result<(), Box<dyn std::error::Error>> {
         "let dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCSeconds(11); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 11, 779)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 11, 779)),
         actual
     );
 
@@ -1189,7 +1248,8 @@ fn date_proto_set_utc_seconds() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779)); dt.setUTCSeconds(11, 487); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2020, 07, 08).and_hms_milli(09, 16, 11, 487)),
+        NaiveDate::from_ymd_opt(2020, 07, 08)
+            .and_then(|date| date.and_hms_milli_opt(09, 16, 11, 487)),
         actual
     );
 
@@ -1201,7 +1261,8 @@ fn date_proto_set_utc_seconds() -> Result<(), Box<dyn std::error::Error>> {
         "dt = new Date(Date.UTC(2020, 07, 08, 09, 16, 15, 779)); dt.setUTCSeconds(40000000, 40123); dt",
     );
     assert_eq!(
-        Some(NaiveDate::from_ymd(2021, 11, 14).and_hms_milli(08, 23, 20, 123)),
+        NaiveDate::from_ymd_opt(2021, 11, 14)
+            .and_then(|date| date.and_hms_milli_opt(08, 23, 20, 123)),
         actual
     );
 
@@ -1364,3 +1425,34 @@ fn date_json() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn date_proto_to_primitive() {
+    let mut engine = Context::new();
+    forward(&mut engine, "let date = new Date(Date.UTC(2020, 06, 08));");
+
+    assert_eq!(forward(&mut engine, "typeof (date + 1)"), "\"string\"");
+    assert_eq!(
+        forward(&mut engine, "date + 1 === date.toString() + 1"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "date - 0"), "1594166400000");
+    assert_eq!(
+        forward(&mut engine, "date[Symbol.toPrimitive]('number')"),
+        "1594166400000"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "date[Symbol.toPrimitive]('default') === date.toString()"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { date[Symbol.toPrimitive]('other') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
@@ -0,0 +1,102 @@
+//! This module implements the global `AggregateError` object.
+//!
+//! Represents several errors wrapped in a single error, for example when all the promises
+//! given to `Promise.any()` are rejected.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
+
+use crate::{
+    builtins::{iterable::iterable_to_list, Array, BuiltIn},
+    object::{ConstructorBuilder, Object, ObjectData},
+    profiler::BoaProfiler,
+    property::{Attribute, DataDescriptor},
+    Context, Result, Value,
+};
+
+/// JavaScript `AggregateError` implementation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AggregateError;
+
+impl BuiltIn for AggregateError {
+    const NAME: &'static str = "AggregateError";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let error_prototype = context.standard_objects().error_object().prototype();
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let aggregate_error_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().aggregate_error_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .inherit(error_prototype.into())
+        .property("name", Self::NAME, attribute)
+        .property("message", "", attribute)
+        .build();
+
+        (Self::NAME, aggregate_error_object.into(), Self::attribute())
+    }
+}
+
+impl AggregateError {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// `AggregateError( errors, message )`
+    ///
+    /// Create a new error object.
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let this = &super::new_error_target(
+            this,
+            ctx.standard_objects().aggregate_error_object().prototype(),
+        );
+        if let Some(message) = args.get(1) {
+            if !message.is_undefined() {
+                this.set_field("message", message.to_string(ctx)?, ctx)?;
+            }
+        }
+
+        let errors = iterable_to_list(ctx, args.first().cloned().unwrap_or_default())?;
+        Self::set_errors(this, &errors, ctx)?;
+
+        // This value is used by console.log and other routines to match Object type
+        // to its Javascript Identifier (global constructor method name)
+        this.set_data(ObjectData::Error);
+        Ok(this.clone())
+    }
+
+    /// Creates a new `AggregateError` with the given errors and message.
+    pub(crate) fn create(errors: &[Value], message: &str, ctx: &mut Context) -> Result<Value> {
+        let prototype = ctx.standard_objects().aggregate_error_object().prototype();
+        let error: Value = Object::with_prototype(prototype.into(), ObjectData::Error).into();
+        error.set_field("message", message, ctx)?;
+        Self::set_errors(&error, errors, ctx)?;
+        Ok(error)
+    }
+
+    /// Defines the `errors` property of an `AggregateError` object.
+    fn set_errors(error: &Value, errors: &[Value], ctx: &mut Context) -> Result<()> {
+        let array = Array::new_array(ctx)?;
+        Array::add_to_array_object(&array, errors, ctx)?;
+        error.set_property(
+            "errors",
+            DataDescriptor::new(
+                array,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+        Ok(())
+    }
+}
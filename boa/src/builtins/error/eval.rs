@@ -57,8 +57,10 @@ impl EvalError {
 
     /// Create a new error object.
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        if let Some(message) = args.get(0) {
-            this.set_field("message", message.to_string(ctx)?);
+        let this =
+            &super::new_error_target(this, ctx.standard_objects().eval_error_object().prototype());
+        if let Some(message) = args.first() {
+            this.set_field("message", message.to_string(ctx)?, ctx)?;
         }
 
         // This value is used by console.log and other routines to match Object type
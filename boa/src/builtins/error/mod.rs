@@ -12,12 +12,13 @@
 
 use crate::{
     builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData},
+    object::{ConstructorBuilder, GcObject, Object, ObjectData},
     profiler::BoaProfiler,
     property::Attribute,
     Context, Result, Value,
 };
 
+pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
@@ -28,6 +29,7 @@ pub(crate) mod uri;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use self::aggregate::AggregateError;
 pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
@@ -39,6 +41,16 @@ pub(crate) use self::uri::UriError;
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Error;
 
+/// Returns the object initialized by an error constructor, which is a new error object with
+/// `prototype` if the constructor was called as a function rather than with `new`.
+fn new_error_target(this: &Value, prototype: GcObject) -> Value {
+    if this.is_object() && !this.is_global() {
+        this.clone()
+    } else {
+        Object::create(prototype.into()).into()
+    }
+}
+
 impl BuiltIn for Error {
     const NAME: &'static str = "Error";
 
@@ -74,8 +86,9 @@ impl Error {
     ///
     /// Create a new error object.
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        if let Some(message) = args.get(0) {
-            this.set_field("message", message.to_string(ctx)?);
+        let this = &new_error_target(this, ctx.standard_objects().error_object().prototype());
+        if let Some(message) = args.first() {
+            this.set_field("message", message.to_string(ctx)?, ctx)?;
         }
 
         // This value is used by console.log and other routines to match Object type
@@ -99,7 +112,7 @@ impl Error {
         if !this.is_object() {
             return context.throw_type_error("'this' is not an Object");
         }
-        let name = this.get_field("name");
+        let name = this.get_field("name", context)?;
         let name_to_string;
         let name = if name.is_undefined() {
             "Error"
@@ -108,7 +121,7 @@ impl Error {
             name_to_string.as_str()
         };
 
-        let message = this.get_field("message");
+        let message = this.get_field("message", context)?;
         let message_to_string;
         let message = if message.is_undefined() {
             ""
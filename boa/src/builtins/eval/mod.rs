@@ -0,0 +1,141 @@
+//! This module implements the global `eval` function.
+//!
+//! The `eval()` function evaluates JavaScript code represented as a string.
+//!
+//! A call to `eval` through its name is a direct eval, which evaluates the code in the scope of
+//! the call. Any other call, such as `(0, eval)(code)` or `globalThis.eval(code)`, is an
+//! indirect eval, which evaluates the code in the global scope.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-eval-x
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+
+use crate::{
+    builtins::BuiltIn,
+    environment::{
+        function_environment_record::BindingStatus,
+        lexical_environment::{new_declarative_environment, new_function_environment},
+    },
+    exec::Executable,
+    object::{FunctionBuilder, GcObject},
+    property::Attribute,
+    syntax::Parser,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `eval` global function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Eval;
+
+impl BuiltIn for Eval {
+    const NAME: &'static str = "eval";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let eval = FunctionBuilder::new(context, Self::eval)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+        context.realm_mut().eval_function = eval.clone();
+
+        (Self::NAME, eval.into(), Self::attribute())
+    }
+}
+
+impl Eval {
+    /// `eval( x )`
+    ///
+    /// This is only reached by an indirect eval, as a direct eval is performed by the call.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-eval-x
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
+    fn eval(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let x = args.first().cloned().unwrap_or_default();
+        Self::perform_eval(x, false, context)
+    }
+
+    /// Returns `true` if `function` is the `%eval%` intrinsic, in which case calling it through
+    /// the name `eval` is a direct eval.
+    pub(crate) fn is_eval_function(function: &Value, context: &Context) -> bool {
+        match function {
+            Value::Object(function) => GcObject::equals(function, &context.realm().eval_function),
+            _ => false,
+        }
+    }
+
+    /// The abstract operation `PerformEval`, which evaluates `x` if it is a string, or returns
+    /// it otherwise.
+    ///
+    /// A direct eval runs the code in the scope of its caller, and is strict mode code if the
+    /// caller is. Non-strict code declares its variables and functions in the function or the
+    /// script that performs the direct eval, or in the global scope for an indirect eval,
+    /// while strict mode code declares them in a scope of its own.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn perform_eval(x: Value, direct: bool, context: &mut Context) -> Result<Value> {
+        let source = match x {
+            Value::String(ref source) => source.clone(),
+            _ => return Ok(x),
+        };
+        context.ensure_can_compile_strings()?;
+
+        let strict_caller = direct && context.executor().strict();
+        let statement_list = Parser::new(source.as_str().as_bytes())
+            .parse_eval(strict_caller)
+            .map_err(|error| context.construct_syntax_error(error.to_string()))?;
+        let strict = strict_caller || statement_list.strict();
+
+        // An indirect eval only sees the global environment, so the environments of the caller
+        // are set aside while it runs.
+        let caller_environments = if direct {
+            Vec::new()
+        } else {
+            context.realm_mut().environment.split_off(1)
+        };
+        let depth = context.realm().environment.depth();
+
+        // Like the environment of an arrow function, the variable environment of strict mode
+        // code has no `this` binding, so `this` is still the one of the caller.
+        let environment = if strict {
+            let eval_function = context.realm().eval_function.clone();
+            new_function_environment(
+                eval_function,
+                None,
+                None,
+                BindingStatus::Lexical,
+                Value::undefined(),
+            )
+        } else {
+            new_declarative_environment(None)
+        };
+        context.realm_mut().environment.push(environment);
+
+        let result = context.run_with_strict_mode(strict, |context| statement_list.run(context));
+
+        context.realm_mut().environment.split_off(depth);
+        for environment in caller_environments {
+            context.realm_mut().environment.push(environment);
+        }
+        result
+    }
+}
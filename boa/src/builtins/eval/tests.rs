@@ -0,0 +1,203 @@
+use crate::{forward, Context};
+
+#[test]
+fn eval_returns_completion_value() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "eval('1 + 2')"), "3");
+    assert_eq!(forward(&mut engine, "eval('({ a: 1 })').a"), "1");
+    assert_eq!(forward(&mut engine, "eval('')"), "undefined");
+}
+
+#[test]
+fn eval_non_string_argument() {
+    let mut engine = Context::new();
+    let init = r#"
+        var object = {};
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "eval(object) === object"), "true");
+    assert_eq!(forward(&mut engine, "eval(42)"), "42");
+    assert_eq!(forward(&mut engine, "eval()"), "undefined");
+}
+
+#[test]
+fn eval_syntax_error() {
+    let mut engine = Context::new();
+    let init = r#"
+        var error;
+        try {
+            eval('1 +');
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "error instanceof SyntaxError"), "true");
+}
+
+#[test]
+fn direct_eval_sees_caller_scope() {
+    let mut engine = Context::new();
+    let init = r#"
+        var x = 'global';
+        function f() {
+            var x = 'local';
+            let y = 'block';
+            return eval('x + " " + y');
+        }
+        function g() {
+            var x = 'local';
+            return (0, eval)('x');
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "f()"), "\"local block\"");
+    assert_eq!(forward(&mut engine, "g()"), "\"global\"");
+    assert_eq!(forward(&mut engine, "globalThis.eval('x')"), "\"global\"");
+}
+
+#[test]
+fn direct_eval_declares_in_enclosing_function() {
+    let mut engine = Context::new();
+    let init = r#"
+        function f() {
+            eval('var declared = 1; function inner() { return 2; }');
+            return declared + inner();
+        }
+        function g() {
+            eval('let scoped = 1; const constant = 2;');
+            return isUndeclared(() => scoped) && isUndeclared(() => constant);
+        }
+        function isUndeclared(f) {
+            try {
+                f();
+            } catch (e) {
+                return e instanceof ReferenceError;
+            }
+        }
+        var result = f();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result"), "3");
+    assert_eq!(forward(&mut engine, "'declared' in globalThis"), "false");
+    assert_eq!(forward(&mut engine, "g()"), "true");
+}
+
+#[test]
+fn indirect_eval_declares_globally() {
+    let mut engine = Context::new();
+    let init = r#"
+        function f() {
+            var indirect = eval;
+            indirect('var fromIndirect = 1; function fromIndirectFn() { return 2; }');
+        }
+        f();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "fromIndirect"), "1");
+    assert_eq!(forward(&mut engine, "fromIndirectFn()"), "2");
+}
+
+#[test]
+fn eval_twice_redeclares() {
+    let mut engine = Context::new();
+    let init = r#"
+        eval('var twice = 1; function twiceFn() { return 1; }');
+        eval('var twice = 2; function twiceFn() { return 2; }');
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "twice + twiceFn()"), "4");
+}
+
+#[test]
+fn strict_eval_isolates_declarations() {
+    let mut engine = Context::new();
+    let init = r#"
+        function strictCaller() {
+            'use strict';
+            eval('var fromStrictCaller = 1;');
+            return isUndeclared(() => fromStrictCaller);
+        }
+        function strictCode() {
+            eval('"use strict"; var fromStrictCode = 1; function fromStrictFn() {}');
+            return isUndeclared(() => fromStrictCode) && isUndeclared(() => fromStrictFn);
+        }
+        function isUndeclared(f) {
+            try {
+                f();
+            } catch (e) {
+                return e instanceof ReferenceError;
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "strictCaller()"), "true");
+    assert_eq!(forward(&mut engine, "strictCode()"), "true");
+    assert_eq!(
+        forward(&mut engine, "eval('\"use strict\"; var x = 1; x')"),
+        "1"
+    );
+    assert_eq!(forward(&mut engine, "'x' in globalThis"), "false");
+}
+
+#[test]
+fn eval_strictness() {
+    let mut engine = Context::new();
+    let init = r#"
+        function strictCaller() {
+            'use strict';
+            try {
+                eval('undeclaredInStrictEval = 1');
+            } catch (e) {
+                return e instanceof ReferenceError;
+            }
+        }
+        function sloppyIndirect() {
+            'use strict';
+            (0, eval)('sloppyFromIndirect = 1');
+            return sloppyFromIndirect;
+        }
+        function thisValue() {
+            'use strict';
+            return eval('this');
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "strictCaller()"), "true");
+    assert_eq!(forward(&mut engine, "sloppyIndirect()"), "1");
+    assert_eq!(forward(&mut engine, "thisValue()"), "undefined");
+    assert_eq!(forward(&mut engine, "thisValue.call(1)"), "1");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "(function () { return eval('this'); }).call(globalThis) === globalThis"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn eval_disallowed_by_host() {
+    let mut engine = Context::new();
+    engine.set_allow_code_generation_from_strings(false);
+    let init = r#"
+        function attempt(f) {
+            try {
+                f();
+            } catch (e) {
+                return e instanceof EvalError;
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "attempt(() => eval('1'))"), "true");
+    assert_eq!(
+        forward(&mut engine, "attempt(() => (0, eval)('1'))"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "attempt(() => Function('return 1'))"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "eval(1)"), "1");
+}
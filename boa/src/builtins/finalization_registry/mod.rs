@@ -0,0 +1,240 @@
+//! This module implements the global `FinalizationRegistry` object.
+//!
+//! A finalization registry calls a cleanup callback after the objects registered on it are
+//! garbage collected. The callbacks are run as jobs, which are queued on the [`Context`] after
+//! a garbage collection, and run by [`Context::run_jobs`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+
+use crate::gc::{Finalize, Trace};
+use crate::{
+    builtins::BuiltIn,
+    object::{ConstructorBuilder, GcObject, ObjectData, WeakGcObject, PROTOTYPE},
+    property::Attribute,
+    value::same_value,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// An object registered on a finalization registry.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-finalization-registry-instances
+#[derive(Debug, Trace, Finalize)]
+struct Cell {
+    target: WeakGcObject,
+    held_value: Value,
+    unregister_token: Option<WeakGcObject>,
+}
+
+#[derive(Debug, Trace, Finalize)]
+pub struct FinalizationRegistry {
+    cleanup_callback: Value,
+    cells: Vec<Cell>,
+}
+
+impl BuiltIn for FinalizationRegistry {
+    const NAME: &'static str = "FinalizationRegistry";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+
+        let finalization_registry_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .method(Self::register, "register", 2)
+            .method(Self::unregister, "unregister", 1)
+            .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+            .callable(false)
+            .build();
+
+        (
+            Self::NAME,
+            finalization_registry_object.into(),
+            Self::attribute(),
+        )
+    }
+}
+
+impl FinalizationRegistry {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Create a new finalization registry
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let cleanup_callback = args.first().cloned().unwrap_or_else(Value::undefined);
+        if !cleanup_callback.is_function() {
+            return ctx.throw_type_error("FinalizationRegistry: cleanup must be callable");
+        }
+
+        let prototype = ctx
+            .global_object()
+            .clone()
+            .get_field("FinalizationRegistry", ctx)?
+            .get_field(PROTOTYPE, ctx)?;
+
+        let mut registry = this
+            .as_object()
+            .expect("this is finalization registry object");
+        registry.set_prototype_instance(prototype);
+
+        this.set_data(ObjectData::FinalizationRegistry(Self {
+            cleanup_callback,
+            cells: Vec::new(),
+        }));
+        ctx.add_finalization_registry(&registry);
+
+        Ok(this.clone())
+    }
+
+    /// `FinalizationRegistry.prototype.register( target, heldValue [ , unregisterToken ] )`
+    ///
+    /// This method registers the target object, so that the cleanup callback is called with
+    /// the held value once the target is garbage collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/register
+    pub(crate) fn register(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let undefined = Value::undefined();
+        let target_value = args.first().unwrap_or(&undefined);
+        let held_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
+        let unregister_token = args.get(2).unwrap_or(&undefined);
+
+        let target = match target_value {
+            Value::Object(ref target) => target.downgrade(),
+            _ => {
+                return ctx.throw_type_error(
+                    "FinalizationRegistry.prototype.register: target must be an object",
+                )
+            }
+        };
+        if same_value(target_value, &held_value) {
+            return ctx.throw_type_error(
+                "FinalizationRegistry.prototype.register: target and held value must not be the same",
+            );
+        }
+        let unregister_token =
+            match unregister_token {
+                Value::Object(ref token) => Some(token.downgrade()),
+                Value::Undefined => None,
+                _ => return ctx.throw_type_error(
+                    "FinalizationRegistry.prototype.register: unregister token must be an object",
+                ),
+            };
+
+        if let Value::Object(ref object) = this {
+            if let Some(registry) = object.borrow_mut().as_finalization_registry_mut() {
+                registry.cells.push(Cell {
+                    target,
+                    held_value,
+                    unregister_token,
+                });
+                return Ok(Value::undefined());
+            }
+        }
+
+        Err(ctx.construct_type_error("'this' is not a FinalizationRegistry"))
+    }
+
+    /// `FinalizationRegistry.prototype.unregister( unregisterToken )`
+    ///
+    /// This method unregisters the targets that were registered with the unregister token.
+    /// Returns true if any target was unregistered, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/unregister
+    pub(crate) fn unregister(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = match this {
+            Value::Object(ref object) if object.borrow().as_finalization_registry().is_some() => {
+                object
+            }
+            _ => return ctx.throw_type_error("'this' is not a FinalizationRegistry"),
+        };
+
+        let unregister_token =
+            match args.first() {
+                Some(Value::Object(ref token)) => token,
+                _ => return ctx.throw_type_error(
+                    "FinalizationRegistry.prototype.unregister: unregister token must be an object",
+                ),
+            };
+
+        let mut object = object.borrow_mut();
+        let registry = object
+            .as_finalization_registry_mut()
+            .expect("this is finalization registry object");
+        let count = registry.cells.len();
+        registry.cells.retain(|cell| {
+            !cell
+                .unregister_token
+                .as_ref()
+                .and_then(WeakGcObject::upgrade)
+                .is_some_and(|token| GcObject::equals(&token, unregister_token))
+        });
+        Ok((registry.cells.len() != count).into())
+    }
+
+    /// Returns `true` if some of the registered targets were garbage collected.
+    pub(crate) fn needs_cleanup(&self) -> bool {
+        self.cells.iter().any(|cell| !cell.target.is_alive())
+    }
+
+    /// Abstract operation `CleanupFinalizationRegistry ( finalizationRegistry )`
+    ///
+    /// Calls the cleanup callback of the registry with the held value of each registered
+    /// target that was garbage collected, and unregisters them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-cleanup-finalization-registry
+    pub(crate) fn cleanup(registry: &GcObject, ctx: &mut Context) -> Result<Value> {
+        loop {
+            // The callback may register or unregister targets, so the registry isn't borrowed
+            // while it runs.
+            let cleanup = {
+                let mut registry = registry.borrow_mut();
+                let registry = registry
+                    .as_finalization_registry_mut()
+                    .expect("registry is finalization registry object");
+                registry
+                    .cells
+                    .iter()
+                    .position(|cell| !cell.target.is_alive())
+                    .map(|index| {
+                        let cell = registry.cells.remove(index);
+                        (registry.cleanup_callback.clone(), cell.held_value.clone())
+                    })
+            };
+
+            match cleanup {
+                Some((callback, held_value)) => {
+                    ctx.call(&callback, &Value::undefined(), &[held_value])?;
+                }
+                None => return Ok(Value::undefined()),
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{forward, gc::force_collect, Context};
+
+#[test]
+fn cleanup_callback_is_called_after_collection() {
+    let mut engine = Context::new();
+    let init = r#"
+        let cleaned = [];
+        let registry = new FinalizationRegistry(function (held) { cleaned.push(held); });
+        let kept = {};
+        registry.register({}, "collected");
+        registry.register(kept, "kept");
+        "#;
+    engine.eval(init).unwrap();
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "cleaned.length"), "0");
+
+    force_collect();
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "cleaned.join()"), "\"collected\"");
+
+    force_collect();
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "cleaned.join()"), "\"collected\"");
+}
+
+#[test]
+fn unregister() {
+    let mut engine = Context::new();
+    let init = r#"
+        let cleaned = [];
+        let registry = new FinalizationRegistry(function (held) { cleaned.push(held); });
+        let token = {};
+        registry.register({}, "unregistered", token);
+        "#;
+    engine.eval(init).unwrap();
+    assert_eq!(forward(&mut engine, "registry.unregister(token)"), "true");
+    assert_eq!(forward(&mut engine, "registry.unregister(token)"), "false");
+
+    force_collect();
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "cleaned.length"), "0");
+}
+
+#[test]
+fn register_errors() {
+    let mut engine = Context::new();
+    forward(
+        &mut engine,
+        "let registry = new FinalizationRegistry(function () {}); let target = {};",
+    );
+    assert_eq!(
+        forward(&mut engine, "new FinalizationRegistry(1)"),
+        "Uncaught \"TypeError\": \"FinalizationRegistry: cleanup must be callable\""
+    );
+    assert_eq!(
+        forward(&mut engine, "registry.register(1, 2)"),
+        "Uncaught \"TypeError\": \"FinalizationRegistry.prototype.register: target must be an object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "registry.register(target, target)"),
+        "Uncaught \"TypeError\": \"FinalizationRegistry.prototype.register: target and held value must not be the same\""
+    );
+    assert_eq!(
+        forward(&mut engine, "registry.register(target, 1, 2)"),
+        "Uncaught \"TypeError\": \"FinalizationRegistry.prototype.register: unregister token must be an object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "registry.unregister(1)"),
+        "Uncaught \"TypeError\": \"FinalizationRegistry.prototype.unregister: unregister token must be an object\""
+    );
+}
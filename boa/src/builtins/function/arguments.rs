@@ -0,0 +1,183 @@
+//! This module implements the `arguments` object of ordinary functions.
+//!
+//! The arguments object of a strict mode function, or of a function whose parameters are not
+//! simple, is an ordinary object holding a copy of the arguments. The arguments object of
+//! other functions is mapped: its elements are aliased to the parameter bindings, so that
+//! changing one changes the other.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/arguments
+
+use crate::{
+    environment::lexical_environment::Environment,
+    object::{GcObject, Object, ObjectData},
+    property::{AccessorDescriptor, Attribute, DataDescriptor},
+    syntax::ast::node::{pattern::Target, FormalParameter},
+    Context, Value,
+};
+use gc::{Finalize, Trace};
+
+/// The data of an arguments object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum Arguments {
+    /// The arguments object of a strict mode function, or of a function with non-simple
+    /// parameters.
+    Unmapped,
+    /// The arguments object of a non-strict function with simple parameters.
+    Mapped(ParameterMap),
+}
+
+/// The `[[ParameterMap]]` of a mapped arguments object, which maps the indices of the
+/// arguments to the parameter bindings in the environment of the call.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ParameterMap {
+    names: Vec<Option<String>>,
+    environment: Environment,
+}
+
+impl ParameterMap {
+    /// Returns the name of the parameter bound to the argument at `index`, if it is mapped.
+    fn name(&self, index: u32) -> Option<&str> {
+        self.names.get(index as usize)?.as_deref()
+    }
+
+    /// Returns `true` if the argument at `index` is mapped to a parameter.
+    #[inline]
+    pub(crate) fn is_mapped(&self, index: u32) -> bool {
+        self.name(index).is_some()
+    }
+
+    /// Returns the value of the parameter bound to the argument at `index`, if it is mapped.
+    pub(crate) fn get(&self, index: u32) -> Option<Value> {
+        let name = self.name(index)?;
+        Some(self.environment.borrow().get_binding_value(name, false))
+    }
+
+    /// Sets the value of the parameter bound to the argument at `index`, if it is mapped.
+    pub(crate) fn set(&self, index: u32, value: Value) {
+        if let Some(name) = self.name(index) {
+            self.environment
+                .borrow_mut()
+                .set_mutable_binding(name, value, false)
+                .expect("parameters are mutable bindings");
+        }
+    }
+
+    /// Removes the mapping of the argument at `index`, which no longer changes with its
+    /// parameter.
+    pub(crate) fn unmap(&mut self, index: u32) {
+        if let Some(name) = self.names.get_mut(index as usize) {
+            *name = None;
+        }
+    }
+}
+
+/// Creates an arguments object with the length and the elements of `arguments_list`, and
+/// the `@@iterator` method of arrays.
+fn create_arguments_object(
+    arguments_list: &[Value],
+    arguments: Arguments,
+    context: &Context,
+) -> GcObject {
+    let prototype = context.standard_objects().object_object().prototype();
+    let mut obj = GcObject::new(Object::with_prototype(
+        prototype.into(),
+        ObjectData::Arguments(arguments),
+    ));
+
+    obj.insert(
+        "length",
+        DataDescriptor::new(
+            arguments_list.len(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+    for (index, value) in arguments_list.iter().enumerate() {
+        obj.insert(
+            index,
+            DataDescriptor::new(
+                value.clone(),
+                Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+            ),
+        );
+    }
+    obj.insert(
+        context.well_known_symbols().iterator_symbol(),
+        DataDescriptor::new(
+            context.realm().array_prototype_values.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+
+    obj
+}
+
+/// Creates the arguments object of a strict mode function, whose `callee` property throws a
+/// `TypeError` when it is accessed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createunmappedargumentsobject
+pub fn create_unmapped_arguments_object(arguments_list: &[Value], context: &Context) -> Value {
+    let mut obj = create_arguments_object(arguments_list, Arguments::Unmapped, context);
+    let thrower = context.throw_type_error_function();
+    obj.insert(
+        "callee",
+        AccessorDescriptor::new(
+            Some(thrower.clone()),
+            Some(thrower),
+            Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        ),
+    );
+
+    Value::from(obj)
+}
+
+/// Creates the arguments object of a non-strict function with simple parameters, whose
+/// `callee` property is the function.
+///
+/// Each argument that has a parameter is mapped to the binding of the parameter in
+/// `environment`. When several parameters have the same name, the binding is mapped to the
+/// last of them.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createmappedargumentsobject
+pub(crate) fn create_mapped_arguments_object(
+    function: &GcObject,
+    formals: &[FormalParameter],
+    arguments_list: &[Value],
+    environment: &Environment,
+    context: &Context,
+) -> Value {
+    let mut names: Vec<Option<String>> = vec![None; formals.len().min(arguments_list.len())];
+    for (index, formal) in formals.iter().enumerate().take(names.len()).rev() {
+        if let Target::Identifier(ident) = formal.target() {
+            let name = ident.as_ref();
+            if !names.iter().flatten().any(|mapped| mapped == name) {
+                names[index] = Some(name.to_owned());
+            }
+        }
+    }
+    let map = ParameterMap {
+        names,
+        environment: environment.clone(),
+    };
+
+    let mut obj = create_arguments_object(arguments_list, Arguments::Mapped(map), context);
+    obj.insert(
+        "callee",
+        DataDescriptor::new(
+            function.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+
+    Value::from(obj)
+}
@@ -14,15 +14,30 @@
 use crate::{
     builtins::{Array, BuiltIn},
     environment::lexical_environment::Environment,
-    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
+    object::{
+        ConstructorBuilder, FunctionBuilder, GcObject, NativeObject, Object, ObjectData, Ref,
+        RefMut,
+    },
     property::{Attribute, DataDescriptor},
-    syntax::ast::node::{FormalParameter, RcStatementList},
-    BoaProfiler, Context, Result, Value,
+    syntax::{
+        ast::node::{
+            pattern::{BindingKind, Target},
+            FormalParameter, FunctionDecl, Node, RcFormalParameterList, RcStatementList,
+        },
+        Parser,
+    },
+    value::RcString,
+    BoaProfiler, Context, Executable, Result, Value,
 };
 use bitflags::bitflags;
-use gc::{unsafe_empty_trace, Finalize, Trace};
+use gc::{unsafe_empty_trace, Finalize, Gc, GcCell, Trace};
 use std::fmt::{self, Debug};
 
+mod arguments;
+
+pub(crate) use arguments::create_mapped_arguments_object;
+pub use arguments::{create_unmapped_arguments_object, Arguments, ParameterMap};
+
 #[cfg(test)]
 mod tests;
 
@@ -48,12 +63,94 @@ impl Debug for BuiltInFunction {
     }
 }
 
+/// _fn(this, arguments, captures, ctx) -> ResultValue_ - The signature of a built-in closure
+pub type NativeClosure = fn(&Value, &[Value], &Captures, &mut Context) -> Result<Value>;
+
+#[derive(Clone, Copy, Finalize)]
+pub struct BuiltInClosure(pub(crate) NativeClosure);
+
+unsafe impl Trace for BuiltInClosure {
+    unsafe_empty_trace!();
+}
+
+impl From<NativeClosure> for BuiltInClosure {
+    fn from(function: NativeClosure) -> Self {
+        Self(function)
+    }
+}
+
+impl Debug for BuiltInClosure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[native closure]")
+    }
+}
+
+/// The state captured by a built-in closure.
+///
+/// The captured values are garbage collected, and can be shared by several closures by
+/// cloning the `Captures`.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Captures(Gc<GcCell<Box<dyn NativeObject>>>);
+
+impl Captures {
+    /// Create new captures from the given native object.
+    pub fn new<T>(captures: T) -> Self
+    where
+        T: NativeObject,
+    {
+        Self(Gc::new(GcCell::new(Box::new(captures))))
+    }
+
+    /// Downcast a reference to the captures, if they are of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the captures are currently mutably borrowed.
+    pub fn downcast_ref<T>(&self) -> Option<Ref<'_, T>>
+    where
+        T: NativeObject,
+    {
+        let captures = self.0.borrow();
+        if (**captures).as_any().is::<T>() {
+            Some(Ref::map(captures, |x| {
+                (**x).as_any().downcast_ref::<T>().unwrap()
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Downcast a mutable reference to the captures, if they are of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the captures are currently borrowed.
+    pub fn downcast_mut<T>(&self) -> Option<RefMut<'_, T>>
+    where
+        T: NativeObject,
+    {
+        let captures = self.0.borrow_mut();
+        if (**captures).as_any().is::<T>() {
+            Some(RefMut::map(captures, |x| {
+                (**x).as_mut_any().downcast_mut::<T>().unwrap()
+            }))
+        } else {
+            None
+        }
+    }
+}
+
 bitflags! {
     #[derive(Finalize, Default)]
     pub struct FunctionFlags: u8 {
         const CALLABLE = 0b0000_0001;
         const CONSTRUCTABLE = 0b0000_0010;
         const LEXICAL_THIS_MODE = 0b0000_0100;
+        const CLASS_CONSTRUCTOR = 0b0000_1000;
+        const DERIVED_CONSTRUCTOR = 0b0001_0000;
+        const GENERATOR = 0b0010_0000;
+        const ASYNC = 0b0100_0000;
+        const STRICT = 0b1000_0000;
     }
 }
 
@@ -85,6 +182,31 @@ impl FunctionFlags {
     pub(crate) fn is_lexical_this_mode(&self) -> bool {
         self.contains(Self::LEXICAL_THIS_MODE)
     }
+
+    #[inline]
+    pub(crate) fn is_class_constructor(&self) -> bool {
+        self.contains(Self::CLASS_CONSTRUCTOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_derived_constructor(&self) -> bool {
+        self.contains(Self::DERIVED_CONSTRUCTOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_generator(&self) -> bool {
+        self.contains(Self::GENERATOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_async(&self) -> bool {
+        self.contains(Self::ASYNC)
+    }
+
+    #[inline]
+    pub(crate) fn is_strict(&self) -> bool {
+        self.contains(Self::STRICT)
+    }
 }
 
 unsafe impl Trace for FunctionFlags {
@@ -99,61 +221,31 @@ unsafe impl Trace for FunctionFlags {
 #[derive(Debug, Clone, Finalize, Trace)]
 pub enum Function {
     BuiltIn(BuiltInFunction, FunctionFlags),
+    /// A built-in function that carries some state with it.
+    Closure {
+        function: BuiltInClosure,
+        captures: Captures,
+        flags: FunctionFlags,
+    },
     Ordinary {
         flags: FunctionFlags,
         body: RcStatementList,
-        params: Box<[FormalParameter]>,
+        params: RcFormalParameterList,
         environment: Environment,
+        /// The object whose prototype `super` property accesses are resolved against.
+        home_object: Option<GcObject>,
+        /// The source text of the function, if it was created from a function declaration or
+        /// expression.
+        source_text: Option<RcString>,
     },
 }
 
 impl Function {
-    // Adds the final rest parameters to the Environment as an array
-    pub(crate) fn add_rest_param(
-        &self,
-        param: &FormalParameter,
-        index: usize,
-        args_list: &[Value],
-        interpreter: &mut Context,
-        local_env: &Environment,
-    ) {
-        // Create array of values
-        let array = Array::new_array(interpreter).unwrap();
-        Array::add_to_array_object(&array, &args_list[index..]).unwrap();
-
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false);
-
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), array);
-    }
-
-    // Adds an argument to the environment
-    pub(crate) fn add_arguments_to_environment(
-        &self,
-        param: &FormalParameter,
-        value: Value,
-        local_env: &Environment,
-    ) {
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false);
-
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), value);
-    }
-
     /// Returns true if the function object is callable.
     pub fn is_callable(&self) -> bool {
         match self {
             Self::BuiltIn(_, flags) => flags.is_callable(),
+            Self::Closure { flags, .. } => flags.is_callable(),
             Self::Ordinary { flags, .. } => flags.is_callable(),
         }
     }
@@ -162,37 +254,163 @@ impl Function {
     pub fn is_constructable(&self) -> bool {
         match self {
             Self::BuiltIn(_, flags) => flags.is_constructable(),
+            Self::Closure { flags, .. } => flags.is_constructable(),
             Self::Ordinary { flags, .. } => flags.is_constructable(),
         }
     }
+
+    /// Returns the `[[HomeObject]]` of the function, if any.
+    pub fn home_object(&self) -> Option<&GcObject> {
+        match self {
+            Self::BuiltIn(_, _) | Self::Closure { .. } => None,
+            Self::Ordinary { home_object, .. } => home_object.as_ref(),
+        }
+    }
+
+    /// Returns the source text of the function, if any.
+    pub fn source_text(&self) -> Option<&RcString> {
+        match self {
+            Self::BuiltIn(_, _) | Self::Closure { .. } => None,
+            Self::Ordinary { source_text, .. } => source_text.as_ref(),
+        }
+    }
+
+    /// Sets the source text of an ordinary function.
+    pub(crate) fn set_source_text(&mut self, text: RcString) {
+        if let Self::Ordinary { source_text, .. } = self {
+            *source_text = Some(text);
+        }
+    }
 }
 
-/// Arguments.
+/// The internal slots of a bound function exotic object, which wraps a target function.
 ///
-/// <https://tc39.es/ecma262/#sec-createunmappedargumentsobject>
-pub fn create_unmapped_arguments_object(arguments_list: &[Value]) -> Value {
-    let len = arguments_list.len();
-    let mut obj = GcObject::new(Object::default());
-    // Set length
-    let length = DataDescriptor::new(
-        len,
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
-    );
-    // Define length as a property
-    obj.define_own_property("length", length.into());
-    let mut index: usize = 0;
-    while index < len {
-        let val = arguments_list.get(index).expect("Could not get argument");
-        let prop = DataDescriptor::new(
-            val.clone(),
-            Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
-        );
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct BoundFunction {
+    target_function: GcObject,
+    bound_this: Value,
+    bound_args: Vec<Value>,
+}
+
+impl BoundFunction {
+    /// Abstract operation `BoundFunctionCreate ( targetFunction, boundThis, boundArgs )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-boundfunctioncreate
+    pub(crate) fn create(
+        target_function: GcObject,
+        bound_this: Value,
+        bound_args: Vec<Value>,
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        let prototype = target_function.get_prototype_of(context)?;
+        Ok(GcObject::new(Object::with_prototype(
+            prototype,
+            ObjectData::BoundFunction(Self {
+                target_function,
+                bound_this,
+                bound_args,
+            }),
+        )))
+    }
 
-        obj.insert(index, prop);
-        index += 1;
+    /// Returns the function that is called by the bound function.
+    #[inline]
+    pub fn target_function(&self) -> &GcObject {
+        &self.target_function
+    }
+
+    /// Returns the `this` value the target function is called with.
+    #[inline]
+    pub fn bound_this(&self) -> &Value {
+        &self.bound_this
+    }
+
+    /// Returns the arguments that are passed to the target function before the arguments of
+    /// the call.
+    #[inline]
+    pub fn bound_args(&self) -> &[Value] {
+        &self.bound_args
+    }
+}
+
+/// Binds the parameters of a function to the arguments it is called with, in the environment of
+/// the call, evaluating the default value of the parameters whose argument is `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
+pub(crate) fn bind_parameters(
+    params: &[FormalParameter],
+    args: &[Value],
+    context: &mut Context,
+) -> Result<()> {
+    for (i, param) in params.iter().enumerate() {
+        // Rest Parameters
+        if param.is_rest_param() {
+            let array = Array::new_array(context)?;
+            Array::add_to_array_object(&array, args.get(i..).unwrap_or_default(), context)?;
+            param
+                .target()
+                .bind(array, BindingKind::Parameter, context)?;
+            break;
+        }
+
+        let mut value = args.get(i).cloned().unwrap_or_else(Value::undefined);
+        if value.is_undefined() {
+            if let Some(init) = param.init() {
+                value = init.run(context)?;
+            }
+        }
+        param
+            .target()
+            .bind(value, BindingKind::Parameter, context)?;
     }
+    Ok(())
+}
 
-    Value::from(obj)
+/// Returns `true` if the parameters are plain identifiers, without default values, rest
+/// parameters or destructuring patterns.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-issimpleparameterlist
+pub(crate) fn is_simple_parameter_list(params: &[FormalParameter]) -> bool {
+    params.iter().all(|param| {
+        !param.is_rest_param()
+            && param.init().is_none()
+            && matches!(param.target(), Target::Identifier(_))
+    })
+}
+
+/// Creates the `%ThrowTypeError%` intrinsic, the function that throws a `TypeError` when the
+/// `callee` property of the arguments object of a strict mode function is accessed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-%throwtypeerror%
+pub(crate) fn create_throw_type_error(context: &mut Context) -> GcObject {
+    let mut function = FunctionBuilder::new(context, |_, _, context| {
+        context.throw_type_error(
+            "'caller', 'callee', and 'arguments' properties may not be accessed in strict mode",
+        )
+    })
+    .name("")
+    .length(0)
+    .build();
+    function
+        .prevent_extensions(context)
+        .expect("preventing the extensions of a function object cannot fail");
+    function
 }
 
 /// Creates a new member function of a `Object` or `prototype`.
@@ -228,9 +446,10 @@ pub fn make_builtin_fn<N>(
     let mut function = Object::function(
         Function::BuiltIn(function.into(), FunctionFlags::CALLABLE),
         interpreter
-            .global_object()
-            .get_field("Function")
-            .get_field("prototype"),
+            .standard_objects()
+            .function_object()
+            .prototype()
+            .into(),
     );
     function.insert_property("length", length, Attribute::all());
 
@@ -246,12 +465,69 @@ pub struct BuiltInFunctionObject;
 impl BuiltInFunctionObject {
     pub const LENGTH: usize = 1;
 
-    fn constructor(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        this.set_data(ObjectData::Function(Function::BuiltIn(
-            BuiltInFunction(|_, _, _| Ok(Value::undefined())),
+    /// `Function ( p1, p2, … , pn, body )`
+    ///
+    /// The `Function` constructor creates a function from the source text of its parameters
+    /// and of its body. The function is created in the global scope.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createdynamicfunction
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/Function
+    fn constructor(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        context.ensure_can_compile_strings()?;
+
+        let (params, body) = match args.split_last() {
+            Some((body, params)) => (params, Some(body)),
+            None => (args, None),
+        };
+
+        let mut parameters = Vec::with_capacity(params.len());
+        for param in params {
+            parameters.push(param.to_string(context)?.to_string());
+        }
+        let parameters = parameters.join(",");
+        let body = match body {
+            Some(body) => body.to_string(context)?,
+            None => RcString::default(),
+        };
+
+        // The parameters and the body are parsed on their own, so that the source text of one
+        // of them cannot end the other, as in `Function("/*", "*/){")`.
+        let params_function = parse_function_declaration(
+            &format!("function anonymous({}\n) {{\n}}", parameters),
+            context,
+        )?;
+        let body_function = parse_function_declaration(
+            &format!("function anonymous(\n) {{\n{}\n}}", body),
+            context,
+        )?;
+
+        let environment = context.realm().environment.get_global_environment().clone();
+        let function = context.create_function_in_environment(
+            params_function.parameters().to_vec(),
+            body_function.statement_list().clone(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
-        )));
-        Ok(this.clone())
+            environment,
+        );
+        function.set_property(
+            "name",
+            DataDescriptor::new("anonymous", Attribute::CONFIGURABLE),
+        );
+
+        let source_text = format!("function anonymous({}\n) {{\n{}\n}}", parameters, body);
+        if let Some(function) = function
+            .as_object()
+            .expect("functions are objects")
+            .borrow_mut()
+            .as_function_mut()
+        {
+            function.set_source_text(source_text.into());
+        }
+
+        Ok(function)
     }
 
     fn prototype(_: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
@@ -272,7 +548,7 @@ impl BuiltInFunctionObject {
         if !this.is_function() {
             return context.throw_type_error(format!("{} is not a function", this.display()));
         }
-        let this_arg: Value = args.get(0).cloned().unwrap_or_default();
+        let this_arg: Value = args.first().cloned().unwrap_or_default();
         // TODO?: 3. Perform PrepareForTailCall
         let start = if !args.is_empty() { 1 } else { 0 };
         context.call(this, &this_arg, &args[start..])
@@ -293,18 +569,153 @@ impl BuiltInFunctionObject {
         if !this.is_function() {
             return context.throw_type_error(format!("{} is not a function", this.display()));
         }
-        let this_arg = args.get(0).cloned().unwrap_or_default();
+        let this_arg = args.first().cloned().unwrap_or_default();
         let arg_array = args.get(1).cloned().unwrap_or_default();
         if arg_array.is_null_or_undefined() {
             // TODO?: 3.a. PrepareForTailCall
             return context.call(this, &this_arg, &[]);
         }
         let arg_list = context
-            .extract_array_properties(&arg_array)
-            .map_err(|()| arg_array)?;
+            .extract_array_properties(&arg_array)?
+            .ok_or(arg_array)?;
         // TODO?: 5. PrepareForTailCall
         context.call(this, &this_arg, &arg_list)
     }
+
+    /// `Function.prototype.bind`
+    ///
+    /// The bind() method creates a bound function, which calls self with the given `this` value,
+    /// and with the given arguments followed by the arguments it is called with.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.bind
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind
+    fn bind(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let Target be the this value.
+        // 2. If IsCallable(Target) is false, throw a TypeError exception.
+        let target = match this {
+            Value::Object(ref object) if object.is_callable() => object.clone(),
+            _ => {
+                return context
+                    .throw_type_error("Function.prototype.bind: 'this' is not a function")
+            }
+        };
+        let this_arg = args.first().cloned().unwrap_or_default();
+        let bound_args = args.get(1..).unwrap_or_default().to_vec();
+        let arg_count = bound_args.len();
+
+        // 3. Let F be ? BoundFunctionCreate(Target, thisArg, args).
+        let mut function = BoundFunction::create(target.clone(), this_arg, bound_args, context)?;
+
+        // 4. Let L be 0.
+        let mut length = 0.0;
+
+        // 5. Let targetHasLength be ? HasOwnProperty(Target, "length").
+        // 6. If targetHasLength is true, then
+        if target
+            .get_own_property(&"length".into(), context)?
+            .is_some()
+        {
+            // a. Let targetLen be ? Get(Target, "length").
+            let target_length = target.get(&"length".into(), target.clone().into(), context)?;
+
+            // b. If Type(targetLen) is Number, then
+            //    i. If targetLen is +∞, set L to +∞.
+            //    ii. Else if targetLen is -∞, set L to 0.
+            //    iii. Else, set L to max(ToIntegerOrInfinity(targetLen) - argCount, 0).
+            if target_length.is_number() {
+                length = (target_length.to_integer(context)? - arg_count as f64).max(0.0);
+            }
+        }
+
+        // 7. Perform ! SetFunctionLength(F, L).
+        function.insert_property("length", length, Attribute::CONFIGURABLE);
+
+        // 8. Let targetName be ? Get(Target, "name").
+        // 9. If Type(targetName) is not String, set targetName to the empty String.
+        let target_name = target.get(&"name".into(), target.clone().into(), context)?;
+        let target_name = target_name.as_string().map_or("", RcString::as_str);
+
+        // 10. Perform SetFunctionName(F, targetName, "bound").
+        function.insert_property(
+            "name",
+            format!("bound {}", target_name),
+            Attribute::CONFIGURABLE,
+        );
+
+        // 11. Return F.
+        Ok(function.into())
+    }
+
+    /// `Function.prototype.toString`
+    ///
+    /// The toString() method returns the source text of self, if it was created from source
+    /// code, and a native function string otherwise.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/toString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_string(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let object = match this {
+            Value::Object(ref object) if object.is_callable() => object.clone(),
+            _ => {
+                return context
+                    .throw_type_error("Function.prototype.toString: 'this' is not a function")
+            }
+        };
+
+        let source_text = object
+            .borrow()
+            .as_function()
+            .and_then(Function::source_text)
+            .cloned();
+        if let Some(source_text) = source_text {
+            return Ok(source_text.into());
+        }
+
+        let name = object.get(&"name".into(), object.clone().into(), context)?;
+        let name = name.as_string().map_or("", RcString::as_str);
+        Ok(format!("function {}() {{ [native code] }}", name).into())
+    }
+
+    /// `Function.prototype [ @@hasInstance ] ( V )`
+    ///
+    /// This method is the default behaviour of `instanceof`, which checks if the prototype of
+    /// self is in the prototype chain of `V`.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype-@@hasinstance
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/@@hasInstance
+    fn has_instance(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args.first().cloned().unwrap_or_default();
+        match this {
+            Value::Object(ref constructor) => {
+                Ok(constructor.ordinary_has_instance(&object, context)?.into())
+            }
+            _ => Ok(false.into()),
+        }
+    }
+}
+
+/// Parses `source_text` as a single function declaration, or throws a `SyntaxError`.
+fn parse_function_declaration(source_text: &str, context: &mut Context) -> Result<FunctionDecl> {
+    let statements = Parser::new(source_text.as_bytes())
+        .parse_all()
+        .map_err(|error| context.construct_syntax_error(error.to_string()))?;
+    match statements.statements() {
+        [Node::FunctionDecl(function)] => Ok(function.clone()),
+        _ => Err(context.construct_syntax_error("Function: invalid function source text")),
+    }
 }
 
 impl BuiltIn for BuiltInFunctionObject {
@@ -318,6 +729,13 @@ impl BuiltIn for BuiltInFunctionObject {
         let _timer = BoaProfiler::global().start_event("function", "init");
 
         let function_prototype = context.standard_objects().function_object().prototype();
+        let symbol_has_instance = context.well_known_symbols().has_instance_symbol();
+        let has_instance = FunctionBuilder::new(context, Self::has_instance)
+            .name("[Symbol.hasInstance]")
+            .length(1)
+            .constructable(false)
+            .build();
+
         FunctionBuilder::new(context, Self::prototype)
             .name("")
             .length(0)
@@ -334,6 +752,13 @@ impl BuiltIn for BuiltInFunctionObject {
         .length(Self::LENGTH)
         .method(Self::call, "call", 1)
         .method(Self::apply, "apply", 1)
+        .method(Self::bind, "bind", 1)
+        .method(Self::to_string, "toString", 0)
+        .property(
+            symbol_has_instance,
+            has_instance,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        )
         .build();
 
         (Self::NAME, function_object.into(), Self::attribute())
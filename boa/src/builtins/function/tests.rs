@@ -15,7 +15,7 @@ fn arguments_object() {
     eprintln!("{}", forward(&mut engine, init));
 
     let return_val = forward_val(&mut engine, "val").expect("value expected");
-    assert_eq!(return_val.is_integer(), true);
+    assert!(return_val.is_integer());
     assert_eq!(
         return_val
             .to_i32(&mut engine)
@@ -24,6 +24,116 @@ fn arguments_object() {
     );
 }
 
+#[test]
+fn mapped_arguments_object() {
+    let mut engine = Context::new();
+    let init = r#"
+        function aliased(a, b) {
+            arguments[0] = 10;
+            b = 20;
+            return [a, arguments[1], arguments.length].join();
+        }
+        function deleted(a) {
+            delete arguments[0];
+            arguments[0] = 3;
+            return a;
+        }
+        function readOnly(a) {
+            Object.defineProperty(arguments, '0', { value: 3, writable: false });
+            a = 4;
+            return arguments[0] + ',' + a;
+        }
+        function duplicate(a, a) {
+            arguments[1] = 7;
+            return a + ',' + arguments[0];
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "aliased(1, 2)"), "\"10,20,2\"");
+    assert_eq!(forward(&mut engine, "aliased(1)"), "\"10,,1\"");
+    assert_eq!(forward(&mut engine, "deleted(1)"), "1");
+    assert_eq!(forward(&mut engine, "readOnly(1)"), "\"3,4\"");
+    assert_eq!(forward(&mut engine, "duplicate(1, 2)"), "\"7,1\"");
+}
+
+#[test]
+fn unmapped_arguments_object() {
+    let mut engine = Context::new();
+    let init = r#"
+        function strict(a) {
+            'use strict';
+            arguments[0] = 10;
+            a = 5;
+            return [a, arguments[0]].join();
+        }
+        function withDefault(a = 0) {
+            arguments[0] = 10;
+            return a;
+        }
+        function withRest(a, ...rest) {
+            a = 10;
+            return arguments[0];
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "strict(1)"), "\"5,10\"");
+    assert_eq!(forward(&mut engine, "withDefault(1)"), "1");
+    assert_eq!(forward(&mut engine, "withRest(1, 2)"), "1");
+}
+
+#[test]
+fn arguments_object_properties() {
+    let mut engine = Context::new();
+    let init = r#"
+        function args() {
+            return arguments;
+        }
+        function variadic() {
+            var total = 0;
+            for (var i = 0; i < arguments.length; i++) {
+                total += arguments[i];
+            }
+            return total;
+        }
+        var sloppy = args(1, 2);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(sloppy)"),
+        "\"[object Arguments]\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.keys(sloppy).join()"),
+        "\"0,1\""
+    );
+    assert_eq!(forward(&mut engine, "sloppy.callee === args"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "sloppy[Symbol.iterator] === Array.prototype.values"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "[...args(1, 2, 3)].join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(forward(&mut engine, "variadic(1, 2, 3, 4)"), "10");
+}
+
+#[test]
+fn arrow_function_arguments() {
+    let mut engine = Context::new();
+    let init = r#"
+        function outer() {
+            var arrow = () => arguments[0];
+            return arrow('inner');
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "outer('outer')"), "\"outer\"");
+}
+
 #[test]
 fn self_mutating_function_when_calling() {
     let mut engine = Context::new();
@@ -35,7 +145,7 @@ fn self_mutating_function_when_calling() {
         "#;
     eprintln!("{}", forward(&mut engine, func));
     let y = forward_val(&mut engine, "x.y").expect("value expected");
-    assert_eq!(y.is_integer(), true);
+    assert!(y.is_integer());
     assert_eq!(
         y.to_i32(&mut engine)
             .expect("Could not convert value to i32"),
@@ -54,7 +164,7 @@ fn self_mutating_function_when_constructing() {
         "#;
     eprintln!("{}", forward(&mut engine, func));
     let y = forward_val(&mut engine, "x.y").expect("value expected");
-    assert_eq!(y.is_integer(), true);
+    assert!(y.is_integer());
     assert_eq!(
         y.to_i32(&mut engine)
             .expect("Could not convert value to i32"),
@@ -212,3 +322,197 @@ fn function_prototype_apply_on_object() {
         .unwrap();
     assert!(boolean);
 }
+
+#[test]
+fn function_prototype_bind() {
+    let mut engine = Context::new();
+    let init = r#"
+        function add(a, b, c) {
+            return this.base + a + b + c;
+        }
+        let bound = add.bind({ base: 10 }, 1);
+        let twice = bound.bind(null, 2);
+    "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "bound(2, 3)"), "16");
+    assert_eq!(forward(&mut engine, "twice(3)"), "16");
+    assert_eq!(forward(&mut engine, "bound.name"), "\"bound add\"");
+    assert_eq!(forward(&mut engine, "twice.name"), "\"bound bound add\"");
+    assert_eq!(forward(&mut engine, "bound.length"), "2");
+    assert_eq!(forward(&mut engine, "twice.length"), "1");
+    assert_eq!(
+        forward(&mut engine, "add.bind(null, 1, 2, 3, 4).length"),
+        "0"
+    );
+    assert_eq!(forward(&mut engine, "typeof bound"), "\"function\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(bound) === Function.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Function.prototype.bind.call({})"),
+        "Uncaught \"TypeError\": \"Function.prototype.bind: 'this' is not a function\""
+    );
+}
+
+#[test]
+fn function_prototype_bind_construct() {
+    let mut engine = Context::new();
+    let init = r#"
+        function Point(x, y) {
+            this.x = x;
+            this.y = y;
+        }
+        let OnAxis = Point.bind({ x: 0 }, 0);
+        let point = new OnAxis(5);
+    "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "point.x"), "0");
+    assert_eq!(forward(&mut engine, "point.y"), "5");
+    assert_eq!(forward(&mut engine, "point instanceof Point"), "true");
+    assert_eq!(
+        forward(&mut engine, "Promise.resolve(1) instanceof Promise"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "new Map() instanceof Map"), "true");
+    assert_eq!(forward(&mut engine, "point instanceof OnAxis"), "true");
+    assert_eq!(
+        forward(&mut engine, "new (Math.max.bind(null))()"),
+        "Uncaught \"TypeError\": \"bound function is not a constructor\""
+    );
+}
+
+#[test]
+fn function_constructor() {
+    let mut engine = Context::new();
+    let init = r#"
+        var x = "global";
+        function scoped(x) {
+            return Function("return x")();
+        }
+        let add = Function("a", "b", "return a + b");
+        let sum = new Function("...values", "return values.length");
+    "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "add(2, 3)"), "5");
+    assert_eq!(forward(&mut engine, "add.name"), "\"anonymous\"");
+    assert_eq!(forward(&mut engine, "add.length"), "2");
+    assert_eq!(forward(&mut engine, "sum(1, 2, 3)"), "3");
+    assert_eq!(forward(&mut engine, "Function()()"), "undefined");
+    assert_eq!(forward(&mut engine, "scoped(\"local\")"), "\"global\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(add) === Function.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn function_constructor_syntax_error() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Function(\"return (\") } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Function(\"/*\", \"*/){\") } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Function(\"}, function() {\") } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+}
+
+#[test]
+fn function_prototype_to_string() {
+    let mut engine = Context::new();
+    let init = r#"
+        function add(a, b) { return a + b; }
+        let expression = function  named ( x ) { /* comment */ return x; };
+        let created = Function("a", "b", "return a");
+    "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "add.toString()"),
+        "\"function add(a, b) { return a + b; }\""
+    );
+    assert_eq!(
+        forward(&mut engine, "expression.toString()"),
+        "\"function  named ( x ) { /* comment */ return x; }\""
+    );
+    assert_eq!(
+        forward(&mut engine, "created.toString()"),
+        "\"function anonymous(a,b\n) {\nreturn a\n}\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Math.max.toString()"),
+        "\"function max() { [native code] }\""
+    );
+    assert_eq!(
+        forward(&mut engine, "add.bind(null).toString()"),
+        "\"function bound add() { [native code] }\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Function.prototype.toString.call({})"),
+        "Uncaught \"TypeError\": \"Function.prototype.toString: 'this' is not a function\""
+    );
+}
+
+#[test]
+fn instanceof_uses_has_instance() {
+    let mut engine = Context::new();
+    let init = r#"
+        var Even = {};
+        Even[Symbol.hasInstance] = function(value) { return value % 2 === 0; };
+        function Point() {}
+        var point = new Point();
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "2 instanceof Even"), "true");
+    assert_eq!(forward(&mut engine, "3 instanceof Even"), "false");
+    assert_eq!(forward(&mut engine, "point instanceof Point"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Function.prototype[Symbol.hasInstance].call(Point, point)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Function.prototype[Symbol.hasInstance].call({}, point)"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(Function.prototype, Symbol.hasInstance).writable"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { point instanceof {} } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
@@ -0,0 +1,263 @@
+//! This module implements the `Generator` objects returned by generator functions.
+//!
+//! A generator object runs the body of the generator function that created it as its `next()`
+//! method is called, and it stops at each `yield` expression, returning the yielded value.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-generator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator
+
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    environment::lexical_environment::Environment,
+    exec::coroutine::{Coroutine, CoroutineState, ResumeKind},
+    object::{GcObject, Object, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor},
+    syntax::ast::node::RcStatementList,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The execution state of a generator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-internal-slots-of-generator-instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub enum GeneratorState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+unsafe impl Trace for GeneratorState {
+    unsafe_empty_trace!();
+}
+
+/// The internal representation of a `Generator` object.
+#[derive(Debug, Finalize, Trace)]
+pub struct Generator {
+    state: GeneratorState,
+    /// The body of the generator function, which is taken out of the generator while it runs.
+    coroutine: Option<Coroutine>,
+}
+
+impl Generator {
+    pub(crate) const NAME: &'static str = "Generator";
+
+    /// Creates the generator object returned by a call to a generator function.
+    ///
+    /// `environment` is the function environment of the call, with the arguments already bound.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluatebody
+    pub(crate) fn create(
+        function: &GcObject,
+        body: RcStatementList,
+        environment: Environment,
+        strict: bool,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let prototype = match function.get(&PROTOTYPE.into(), function.clone().into(), context)? {
+            prototype @ Value::Object(_) => prototype,
+            _ => context.iterator_prototypes().generator().into(),
+        };
+
+        let coroutine = Coroutine::new(body, environment, strict);
+
+        let mut object = Object::create(prototype);
+        object.data = ObjectData::Generator(Self {
+            state: GeneratorState::SuspendedStart,
+            coroutine: Some(coroutine),
+        });
+        Ok(GcObject::new(object).into())
+    }
+
+    /// Returns the current state of the generator.
+    pub fn state(&self) -> GeneratorState {
+        self.state
+    }
+
+    /// `Generator.prototype.next( value )`
+    ///
+    /// Resumes the generator, passing `value` as the result of the `yield` expression it is
+    /// suspended at.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next
+    pub(crate) fn next(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.first().cloned().unwrap_or_default();
+        Self::resume(this, ResumeKind::Next, value, context)
+    }
+
+    /// `Generator.prototype.return( value )`
+    ///
+    /// Makes the generator return `value` from the point it is suspended at, running any
+    /// `finally` blocks on the way out.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return
+    pub(crate) fn r#return(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.first().cloned().unwrap_or_default();
+        Self::resume(this, ResumeKind::Return, value, context)
+    }
+
+    /// `Generator.prototype.throw( exception )`
+    ///
+    /// Throws `exception` from the point the generator is suspended at.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw
+    pub(crate) fn throw(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.first().cloned().unwrap_or_default();
+        Self::resume(this, ResumeKind::Throw, value, context)
+    }
+
+    /// Resumes the execution of the generator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generatorresume
+    fn resume(
+        this: &Value,
+        kind: ResumeKind,
+        value: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = match this {
+            Value::Object(ref object) if object.borrow().is_generator() => object.clone(),
+            _ => return context.throw_type_error("`this` is not a generator"),
+        };
+
+        let coroutine = {
+            let mut object = object.borrow_mut();
+            let generator = object
+                .as_generator_mut()
+                .expect("checked to be a generator");
+            match generator.state {
+                GeneratorState::Executing => {
+                    drop(object);
+                    return context.throw_type_error("generator is already running");
+                }
+                GeneratorState::Completed => {
+                    drop(object);
+                    return match kind {
+                        ResumeKind::Next => {
+                            Ok(create_iter_result_object(context, Value::undefined(), true))
+                        }
+                        ResumeKind::Return => Ok(create_iter_result_object(context, value, true)),
+                        ResumeKind::Throw => Err(value),
+                    };
+                }
+                GeneratorState::SuspendedStart | GeneratorState::SuspendedYield => {}
+            }
+            generator.state = GeneratorState::Executing;
+            generator
+                .coroutine
+                .take()
+                .expect("suspended generator without a body")
+        };
+
+        let mut coroutine = coroutine;
+        let result = coroutine.resume(kind, value, context);
+
+        let mut object = object.borrow_mut();
+        let generator = object
+            .as_generator_mut()
+            .expect("checked to be a generator");
+        match result {
+            CoroutineState::Suspended(result) => {
+                generator.state = GeneratorState::SuspendedYield;
+                generator.coroutine = Some(coroutine);
+                Ok(result)
+            }
+            CoroutineState::Completed(result) => {
+                generator.state = GeneratorState::Completed;
+                drop(object);
+                let value = result?;
+                Ok(create_iter_result_object(context, value, true))
+            }
+        }
+    }
+
+    /// Creates the `%GeneratorFunction.prototype%` and `%GeneratorFunction.prototype.prototype%`
+    /// objects.
+    ///
+    /// The first one is the prototype of all generator functions, and the second one is the
+    /// prototype of the `prototype` property of generator functions, which is inherited by
+    /// the generator objects they create.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-prototype
+    pub(crate) fn create_prototype(
+        context: &mut Context,
+        iterator_prototype: Value,
+    ) -> (Value, Value) {
+        let global = context.global_object();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+
+        // Create the prototype of generator objects
+        let generator = Value::new_object(Some(global));
+        make_builtin_fn(Self::next, "next", &generator, 1, context);
+        make_builtin_fn(Self::r#return, "return", &generator, 1, context);
+        make_builtin_fn(Self::throw, "throw", &generator, 1, context);
+        generator
+            .as_object()
+            .expect("generator prototype object")
+            .set_prototype_instance(iterator_prototype);
+        generator.set_property(
+            to_string_tag.clone(),
+            DataDescriptor::new("Generator", Attribute::CONFIGURABLE),
+        );
+
+        // Create the prototype of generator functions
+        let generator_function: Value = Object::create(
+            context
+                .standard_objects()
+                .function_object()
+                .prototype()
+                .into(),
+        )
+        .into();
+        generator_function.set_property(
+            PROTOTYPE,
+            DataDescriptor::new(generator.clone(), Attribute::CONFIGURABLE),
+        );
+        generator_function.set_property(
+            to_string_tag,
+            DataDescriptor::new("GeneratorFunction", Attribute::CONFIGURABLE),
+        );
+        generator.set_property(
+            "constructor",
+            DataDescriptor::new(generator_function.clone(), Attribute::CONFIGURABLE),
+        );
+
+        (generator_function, generator)
+    }
+}
@@ -0,0 +1,420 @@
+use crate::{forward, Context};
+
+#[test]
+fn next_sequence() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() {
+            yield 1;
+            yield 2;
+            return 3;
+        }
+        var g = gen();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "g.next().value"), "1");
+    assert_eq!(forward(&mut engine, "g.next().value"), "2");
+    let result = forward(
+        &mut engine,
+        "var last = g.next(); last.value + ' ' + last.done",
+    );
+    assert_eq!(result, "\"3 true\"");
+    let result = forward(
+        &mut engine,
+        "var end = g.next(); end.value + ' ' + end.done",
+    );
+    assert_eq!(result, "\"undefined true\"");
+}
+
+#[test]
+fn body_is_lazy() {
+    let mut engine = Context::new();
+    let init = r#"
+        var started = false;
+        function* gen() {
+            started = true;
+            yield 1;
+        }
+        var g = gen();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "started"), "false");
+    forward(&mut engine, "g.next()");
+    assert_eq!(forward(&mut engine, "started"), "true");
+}
+
+#[test]
+fn next_passes_values() {
+    let scenario = r#"
+        function* gen(a) {
+            const b = yield a;
+            const c = yield a + b;
+            return a + b + c;
+        }
+        var g = gen(1);
+        g.next(100).value + ',' + g.next(2).value + ',' + g.next(3).value
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"1,3,6\"");
+}
+
+#[test]
+fn locals_survive_suspension() {
+    let scenario = r#"
+        function* counter() {
+            let i = 0;
+            while (true) {
+                yield i;
+                i++;
+            }
+        }
+        var a = counter();
+        var b = counter();
+        a.next(); a.next(); b.next();
+        a.next().value + ',' + b.next().value
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"2,1\"");
+}
+
+#[test]
+fn return_runs_finally() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        function* gen() {
+            try {
+                yield 1;
+                log.push('unreachable');
+            } catch (e) {
+                log.push('catch');
+            } finally {
+                log.push('finally');
+            }
+        }
+        var g = gen();
+        g.next();
+        var result = g.return(42);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "result.value"), "42");
+    assert_eq!(forward(&mut engine, "result.done"), "true");
+    assert_eq!(forward(&mut engine, "log.join()"), "\"finally\"");
+    assert_eq!(forward(&mut engine, "g.next().done"), "true");
+}
+
+#[test]
+fn return_overridden_by_finally() {
+    let scenario = r#"
+        function* gen() {
+            try {
+                yield 1;
+            } finally {
+                return 5;
+            }
+        }
+        const it = gen();
+        it.next();
+        const result = it.return(9);
+        result.value + ',' + result.done
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"5,true\"");
+}
+
+#[test]
+fn return_before_start() {
+    let scenario = r#"
+        var started = false;
+        function* gen() {
+            started = true;
+            yield 1;
+        }
+        var g = gen();
+        var result = g.return(5);
+        result.value + ',' + result.done + ',' + started + ',' + g.next().done
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"5,true,false,true\"");
+}
+
+#[test]
+fn throw_into_generator() {
+    let scenario = r#"
+        function* gen() {
+            try {
+                yield 1;
+            } catch (e) {
+                yield 'caught ' + e;
+            }
+        }
+        var g = gen();
+        g.next();
+        g.throw('error').value
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"caught error\"");
+
+    let scenario = r#"
+        function* gen() {
+            yield 1;
+        }
+        var g = gen();
+        g.next();
+        var message;
+        try {
+            g.throw('uncaught');
+        } catch (e) {
+            message = e;
+        }
+        message + ',' + g.next().done
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"uncaught,true\"");
+}
+
+#[test]
+fn for_of_and_spread() {
+    let scenario = r#"
+        function* gen() {
+            yield 1;
+            yield 2;
+            yield 3;
+        }
+        var sum = 0;
+        for (const x of gen()) {
+            sum += x;
+        }
+        sum + ',' + [...gen()].join() + ',' + Math.max(...gen())
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"6,1,2,3,3\"");
+}
+
+#[test]
+fn for_of_break_closes_generator() {
+    let scenario = r#"
+        var closed = false;
+        function* gen() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                closed = true;
+            }
+        }
+        for (const x of gen()) {
+            break;
+        }
+        closed
+    "#;
+    assert_eq!(&crate::exec(scenario), "true");
+}
+
+#[test]
+fn yield_delegate() {
+    let scenario = r#"
+        function* inner() {
+            yield 'a';
+            yield 'b';
+            return 'inner done';
+        }
+        function* outer() {
+            const result = yield* inner();
+            yield result;
+            yield* [1, 2];
+        }
+        [...outer()].join()
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"a,b,inner done,1,2\"");
+}
+
+#[test]
+fn generator_methods() {
+    let scenario = r#"
+        var obj = {
+            *values() {
+                yield 1;
+                yield 2;
+            }
+        };
+        class Range {
+            constructor(end) {
+                this.end = end;
+            }
+            *[Symbol.iterator]() {
+                for (let i = 0; i < this.end; i++) {
+                    yield i;
+                }
+            }
+        }
+        [...obj.values()].join() + ';' + [...new Range(3)].join()
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"1,2;0,1,2\"");
+}
+
+#[test]
+fn generator_expression_and_prototypes() {
+    let scenario = r#"
+        var gen = function*() {};
+        var g = gen();
+        var GeneratorFunctionPrototype = Object.getPrototypeOf(gen);
+        var GeneratorPrototype = Object.getPrototypeOf(gen.prototype);
+        [
+            Object.getPrototypeOf(g) === gen.prototype,
+            GeneratorFunctionPrototype.prototype === GeneratorPrototype,
+            g[Symbol.iterator]() === g,
+            Object.prototype.toString.call(g),
+            gen.prototype.hasOwnProperty('constructor'),
+        ].join()
+    "#;
+    assert_eq!(
+        &crate::exec(scenario),
+        "\"true,true,true,[object Generator],false\""
+    );
+}
+
+#[test]
+fn already_running() {
+    let scenario = r#"
+        var g;
+        function* gen() {
+            g.next();
+        }
+        g = gen();
+        try {
+            g.next();
+        } catch (e) {
+            e instanceof TypeError
+        }
+    "#;
+    assert_eq!(&crate::exec(scenario), "true");
+}
+
+#[test]
+fn not_a_constructor() {
+    let scenario = r#"
+        function* gen() {}
+        try {
+            new gen();
+        } catch (e) {
+            e instanceof TypeError
+        }
+    "#;
+    assert_eq!(&crate::exec(scenario), "true");
+}
+
+#[test]
+fn yield_in_loops() {
+    let scenario = r#"
+        function* gen() {
+            for (let i = 0; i < 2; i++) {
+                let j = 0;
+                while (j < 2) {
+                    yield i * 10 + j;
+                    j++;
+                }
+            }
+            do {
+                yield 'done';
+            } while (false);
+        }
+        var values = [];
+        for (const value of gen()) {
+            values.push(value);
+        }
+        values.join()
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"0,1,10,11,done\"");
+}
+
+#[test]
+fn yield_in_expressions() {
+    let scenario = r#"
+        function* gen() {
+            var a = [1, (yield 'a'), 3];
+            var o = { x: yield 'b', y: 2 };
+            var s = (yield 'c') + (yield 'd');
+            return a.join() + ' ' + o.x + o.y + ' ' + s + ' ' + Math.max(1, yield 'e');
+        }
+        var g = gen();
+        g.next();
+        g.next(2);
+        g.next(1);
+        g.next(3);
+        g.next(4);
+        g.next(5).value
+    "#;
+    assert_eq!(&crate::exec(scenario), "\"1,2,3 12 7 5\"");
+}
+
+#[test]
+fn yield_in_switch_and_try() {
+    let scenario = r#"
+        var log = [];
+        function* gen(x) {
+            switch (x) {
+                case yield 'case':
+                    log.push('matched');
+                case 2:
+                    log.push(yield 'body');
+                    break;
+                default:
+                    log.push('default');
+            }
+            try {
+                yield 'try';
+                throw 'error';
+            } catch (e) {
+                log.push(yield e);
+            } finally {
+                log.push(yield 'finally');
+            }
+        }
+        var g = gen(1);
+        var yielded = [];
+        var result = g.next();
+        var input = [1, 'b', undefined, 'c', 'f'];
+        while (!result.done) {
+            yielded.push(result.value);
+            result = g.next(input.shift());
+        }
+        yielded.join() + ' ' + log.join()
+    "#;
+    assert_eq!(
+        &crate::exec(scenario),
+        "\"case,body,try,error,finally matched,b,c,f\""
+    );
+}
+
+#[test]
+fn return_in_finally_while_suspended() {
+    let scenario = r#"
+        var log = [];
+        function* gen() {
+            try {
+                yield 1;
+            } finally {
+                log.push(yield 2);
+                log.push('cleanup');
+            }
+        }
+        var g = gen();
+        g.next();
+        var first = g.return('early');
+        var second = g.next('resumed');
+        first.value + ' ' + first.done + ' ' + second.value + ' ' + second.done + ' ' + log.join()
+    "#;
+    assert_eq!(
+        &crate::exec(scenario),
+        "\"2 false early true resumed,cleanup\""
+    );
+}
+
+#[test]
+fn update_expressions_evaluate_target_once() {
+    let scenario = r#"
+        function* gen() {
+            var o = { a: 1 };
+            o[yield 'key']++;
+            return o.a;
+        }
+        var g = gen();
+        g.next();
+        g.next('a').value
+    "#;
+    assert_eq!(&crate::exec(scenario), "2");
+}
@@ -1,7 +1,10 @@
 use crate::{
     builtins::string::string_iterator::StringIterator,
     builtins::ArrayIterator,
+    builtins::ForInIterator,
+    builtins::Generator,
     builtins::MapIterator,
+    builtins::SetIterator,
     object::{GcObject, ObjectInitializer},
     property::{Attribute, DataDescriptor},
     BoaProfiler, Context, Result, Value,
@@ -13,11 +16,17 @@ pub struct IteratorPrototypes {
     array_iterator: GcObject,
     string_iterator: GcObject,
     map_iterator: GcObject,
+    set_iterator: GcObject,
+    for_in_iterator: GcObject,
+    generator: GcObject,
+    generator_function: GcObject,
 }
 
 impl IteratorPrototypes {
     pub fn init(ctx: &mut Context) -> Self {
         let iterator_prototype = create_iterator_prototype(ctx);
+        let (generator_function, generator) =
+            Generator::create_prototype(ctx, iterator_prototype.clone());
         Self {
             iterator_prototype: iterator_prototype
                 .as_object()
@@ -28,9 +37,21 @@ impl IteratorPrototypes {
             string_iterator: StringIterator::create_prototype(ctx, iterator_prototype.clone())
                 .as_object()
                 .expect("String Iterator Prototype is not an object"),
-            map_iterator: MapIterator::create_prototype(ctx, iterator_prototype)
+            map_iterator: MapIterator::create_prototype(ctx, iterator_prototype.clone())
                 .as_object()
                 .expect("Map Iterator Prototype is not an object"),
+            set_iterator: SetIterator::create_prototype(ctx, iterator_prototype.clone())
+                .as_object()
+                .expect("Set Iterator Prototype is not an object"),
+            for_in_iterator: ForInIterator::create_prototype(ctx, iterator_prototype)
+                .as_object()
+                .expect("For In Iterator Prototype is not an object"),
+            generator: generator
+                .as_object()
+                .expect("Generator Prototype is not an object"),
+            generator_function: generator_function
+                .as_object()
+                .expect("Generator Function Prototype is not an object"),
         }
     }
 
@@ -49,6 +70,24 @@ impl IteratorPrototypes {
     pub fn map_iterator(&self) -> GcObject {
         self.map_iterator.clone()
     }
+
+    pub fn set_iterator(&self) -> GcObject {
+        self.set_iterator.clone()
+    }
+
+    pub fn for_in_iterator(&self) -> GcObject {
+        self.for_in_iterator.clone()
+    }
+
+    /// Returns `%GeneratorFunction.prototype.prototype%`, the prototype of generator objects.
+    pub fn generator(&self) -> GcObject {
+        self.generator.clone()
+    }
+
+    /// Returns `%GeneratorFunction.prototype%`, the prototype of generator functions.
+    pub fn generator_function(&self) -> GcObject {
+        self.generator_function.clone()
+    }
 }
 
 /// CreateIterResultObject( value, done )
@@ -66,16 +105,23 @@ pub fn create_iter_result_object(ctx: &mut Context, value: Value, done: bool) ->
 
 /// Get an iterator record
 pub fn get_iterator(ctx: &mut Context, iterable: Value) -> Result<IteratorRecord> {
-    // TODO: Fix the accessor handling
-    let iterator_function = iterable
-        .get_property(ctx.well_known_symbols().iterator_symbol())
-        .map(|p| p.as_data_descriptor().unwrap().value())
-        .ok_or_else(|| ctx.construct_type_error("Not an iterable"))?;
+    if iterable.is_null_or_undefined() {
+        return Err(ctx.construct_type_error("Not an iterable"));
+    }
+    // Primitives, like strings, are iterable through their prototype.
+    let iterator_function = Value::from(iterable.to_object(ctx)?).get_field_with_receiver(
+        ctx.well_known_symbols().iterator_symbol(),
+        &iterable,
+        ctx,
+    )?;
+    if !iterator_function.is_function() {
+        return Err(ctx.construct_type_error("Not an iterable"));
+    }
     let iterator_object = ctx.call(&iterator_function, &iterable, &[])?;
-    let next_function = iterator_object
-        .get_property("next")
-        .map(|p| p.as_data_descriptor().unwrap().value())
-        .ok_or_else(|| ctx.construct_type_error("Could not find property `next`"))?;
+    if iterator_object.get_property("next").is_none() {
+        return Err(ctx.construct_type_error("Could not find property `next`"));
+    }
+    let next_function = iterator_object.get_field("next", ctx)?;
     Ok(IteratorRecord::new(iterator_object, next_function))
 }
 
@@ -107,13 +153,23 @@ pub struct IteratorRecord {
 }
 
 impl IteratorRecord {
-    fn new(iterator_object: Value, next_function: Value) -> Self {
+    pub(crate) fn new(iterator_object: Value, next_function: Value) -> Self {
         Self {
             iterator_object,
             next_function,
         }
     }
 
+    /// Gets the iterator object.
+    pub(crate) fn iterator_object(&self) -> &Value {
+        &self.iterator_object
+    }
+
+    /// Gets the `next` method of the iterator.
+    pub(crate) fn next_function(&self) -> &Value {
+        &self.next_function
+    }
+
     /// Get the next value in the iterator
     ///
     /// More information:
@@ -122,20 +178,67 @@ impl IteratorRecord {
     /// [spec]: https://tc39.es/ecma262/#sec-iteratornext
     pub(crate) fn next(&self, ctx: &mut Context) -> Result<IteratorResult> {
         let next = ctx.call(&self.next_function, &self.iterator_object, &[])?;
-        // FIXME: handle accessor descriptors
         let done = next
-            .get_property("done")
-            .map(|p| p.as_data_descriptor().unwrap().value())
-            .and_then(|v| v.as_boolean())
+            .get_field("done", ctx)?
+            .as_boolean()
             .ok_or_else(|| ctx.construct_type_error("Could not find property `done`"))?;
 
-        // FIXME: handle accessor descriptors
-        let next_result = next
-            .get_property("value")
-            .map(|p| p.as_data_descriptor().unwrap().value())
-            .unwrap_or_default();
+        let next_result = next.get_field("value", ctx)?;
         Ok(IteratorResult::new(next_result, done))
     }
+
+    /// Cleanup the iterator
+    ///
+    /// Calls the `return` method of the iterator, if it has one, when the iteration is stopped
+    /// before the iterator is done. The given completion is returned, unless it is normal and
+    /// the `return` method fails.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iteratorclose
+    pub(crate) fn close(&self, completion: Result<Value>, ctx: &mut Context) -> Result<Value> {
+        let return_method =
+            self.iterator_object
+                .get_field_with_receiver("return", &self.iterator_object, ctx);
+        let return_method = match return_method {
+            Ok(return_method) if return_method.is_null_or_undefined() => return completion,
+            Ok(return_method) => return_method,
+            Err(error) => return completion.and(Err(error)),
+        };
+
+        // The iterator may be closed while a generator is returning, which must not affect
+        // the code of the `return` method.
+        let state = ctx.executor().get_current_state().clone();
+        let pending_return = ctx.take_coroutine_return();
+        let inner_result = ctx.call(&return_method, &self.iterator_object, &[]);
+        ctx.restore_coroutine_return(pending_return);
+        ctx.executor().set_current_state(state);
+
+        let completion = completion?;
+        if !inner_result?.is_object() {
+            return ctx.throw_type_error("iterator result is not an object");
+        }
+        Ok(completion)
+    }
+}
+
+/// Creates a list with all the values of an iterable.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iterabletolist
+pub(crate) fn iterable_to_list(ctx: &mut Context, iterable: Value) -> Result<Vec<Value>> {
+    let iterator = get_iterator(ctx, iterable)?;
+    let mut values = Vec::new();
+    loop {
+        let next = iterator.next(ctx)?;
+        if next.is_done() {
+            return Ok(values);
+        }
+        values.push(next.value());
+    }
 }
 
 #[derive(Debug)]
@@ -62,7 +62,7 @@ impl Json {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse
     pub(crate) fn parse(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let arg = args
-            .get(0)
+            .first()
             .cloned()
             .unwrap_or_else(Value::undefined)
             .to_string(ctx)?;
@@ -73,7 +73,7 @@ impl Json {
                 match args.get(1) {
                     Some(reviver) if reviver.is_function() => {
                         let mut holder = Value::new_object(None);
-                        holder.set_field("", j);
+                        holder.set_field("", j, ctx)?;
                         Self::walk(reviver, ctx, &mut holder, &PropertyKey::from(""))
                     }
                     _ => Ok(j),
@@ -95,7 +95,7 @@ impl Json {
         holder: &mut Value,
         key: &PropertyKey,
     ) -> Result<Value> {
-        let value = holder.get_field(key.clone());
+        let value = holder.get_field(key.clone(), ctx)?;
 
         if let Value::Object(ref object) = value {
             let keys: Vec<_> = object.borrow().keys().collect();
@@ -104,7 +104,7 @@ impl Json {
                 let v = Self::walk(reviver, ctx, &mut value.clone(), &key);
                 match v {
                     Ok(v) if !v.is_undefined() => {
-                        value.set_field(key, v);
+                        value.set_field(key, v, ctx)?;
                     }
                     Ok(_) => {
                         value.remove_property(key);
@@ -133,7 +133,7 @@ impl Json {
     /// [spec]: https://tc39.es/ecma262/#sec-json.stringify
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify
     pub(crate) fn stringify(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let object = match args.get(0) {
+        let object = match args.first() {
             Some(obj) if obj.is_symbol() || obj.is_function() || obj.is_undefined() => {
                 return Ok(Value::undefined())
             }
@@ -153,21 +153,14 @@ impl Json {
                 .as_object()
                 .map(|obj| {
                     let object_to_return = Value::new_object(None);
-                    for (key, val) in obj
-                        .borrow()
-                        .iter()
-                        // FIXME: handle accessor descriptors
-                        .map(|(k, v)| (k, v.as_data_descriptor().unwrap().value()))
-                    {
+                    let keys: Vec<PropertyKey> = obj.borrow().keys().collect();
+                    for key in keys {
+                        let val = object.get_field(key.clone(), ctx)?;
                         let this_arg = object.clone();
                         object_to_return.set_property(
-                            key.to_owned(),
+                            key.clone(),
                             DataDescriptor::new(
-                                ctx.call(
-                                    replacer,
-                                    &this_arg,
-                                    &[Value::from(key.clone()), val.clone()],
-                                )?,
+                                ctx.call(replacer, &this_arg, &[Value::from(&key), val])?,
                                 Attribute::all(),
                             ),
                         );
@@ -178,21 +171,16 @@ impl Json {
         } else if replacer_as_object.is_array() {
             let mut obj_to_return = serde_json::Map::new();
             let replacer_as_object = replacer_as_object.borrow();
-            let fields = replacer_as_object.keys().filter_map(|key| {
-                if key == "length" {
-                    None
-                } else {
-                    Some(replacer.get_field(key))
-                }
-            });
-            for field in fields {
-                if let Some(value) = object
-                    .get_property(field.to_string(ctx)?)
-                    // FIXME: handle accessor descriptors
-                    .map(|prop| prop.as_data_descriptor().unwrap().value().to_json(ctx))
-                    .transpose()?
-                {
-                    obj_to_return.insert(field.to_string(ctx)?.to_string(), value);
+            let keys: Vec<PropertyKey> = replacer_as_object
+                .keys()
+                .filter(|key| *key != "length")
+                .collect();
+            drop(replacer_as_object);
+            for key in keys {
+                let field = replacer.get_field(key, ctx)?.to_string(ctx)?;
+                if object.get_property(field.clone()).is_some() {
+                    let value = object.get_field(field.clone(), ctx)?.to_json(ctx)?;
+                    obj_to_return.insert(field.to_string(), value);
                 }
             }
             Ok(Value::from(JSONValue::Object(obj_to_return).to_string()))
@@ -221,19 +221,35 @@ fn json_parse_array_with_reviver() {
     )
     .unwrap();
     assert_eq!(
-        result.get_field("0").to_number(&mut engine).unwrap() as u8,
+        result
+            .get_field("0", &mut engine)
+            .unwrap()
+            .to_number(&mut engine)
+            .unwrap() as u8,
         2u8
     );
     assert_eq!(
-        result.get_field("1").to_number(&mut engine).unwrap() as u8,
+        result
+            .get_field("1", &mut engine)
+            .unwrap()
+            .to_number(&mut engine)
+            .unwrap() as u8,
         4u8
     );
     assert_eq!(
-        result.get_field("2").to_number(&mut engine).unwrap() as u8,
+        result
+            .get_field("2", &mut engine)
+            .unwrap()
+            .to_number(&mut engine)
+            .unwrap() as u8,
         6u8
     );
     assert_eq!(
-        result.get_field("3").to_number(&mut engine).unwrap() as u8,
+        result
+            .get_field("3", &mut engine)
+            .unwrap()
+            .to_number(&mut engine)
+            .unwrap() as u8,
         8u8
     );
 }
@@ -285,19 +301,19 @@ fn json_parse_sets_prototypes() {
         .as_object()
         .unwrap()
         .prototype_instance();
-    let global_object_prototype = engine
-        .global_object()
-        .get_field("Object")
-        .get_field(PROTOTYPE);
-    let global_array_prototype = engine
-        .global_object()
-        .get_field("Array")
-        .get_field(PROTOTYPE);
-    assert_eq!(
-        same_value(&object_prototype, &global_object_prototype),
-        true
-    );
-    assert_eq!(same_value(&array_prototype, &global_array_prototype), true);
+    let global = engine.global_object().clone();
+    let global_object_prototype = global
+        .get_field("Object", &mut engine)
+        .unwrap()
+        .get_field(PROTOTYPE, &mut engine)
+        .unwrap();
+    let global_array_prototype = global
+        .get_field("Array", &mut engine)
+        .unwrap()
+        .get_field(PROTOTYPE, &mut engine)
+        .unwrap();
+    assert!(same_value(&object_prototype, &global_object_prototype));
+    assert!(same_value(&array_prototype, &global_array_prototype));
 }
 
 #[test]
@@ -0,0 +1,99 @@
+use crate::{
+    gc::{Finalize, Trace},
+    object::{GcObject, Object},
+};
+use gc::{Ephemeron, GcCell};
+use rustc_hash::FxHashMap;
+use std::fmt::{self, Debug};
+
+/// A table of values keyed by objects, which are held weakly.
+///
+/// Each value is kept alive only as long as its key is alive, even if the value references the
+/// key, and the entries of the keys that were garbage collected are removed from the table.
+/// This is the storage of `WeakMap` and `WeakSet`.
+#[derive(Trace, Finalize)]
+pub struct EphemeronTable<V>
+where
+    V: Trace + Clone + 'static,
+{
+    entries: FxHashMap<usize, Ephemeron<GcCell<Object>, V>>,
+    // The number of garbage collections when the entries of collected keys were last removed.
+    collections: usize,
+}
+
+impl<V> EphemeronTable<V>
+where
+    V: Trace + Clone + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            collections: gc::collections(),
+        }
+    }
+
+    /// The entries are keyed by the address of their key, which is unique among the live
+    /// objects. An entry whose key was collected may have the address of a newer object, but
+    /// it is ignored, as it is dead.
+    fn address(key: &GcObject) -> usize {
+        key.as_ref() as *const GcCell<Object> as usize
+    }
+
+    /// Return the value associated to `key`, if any.
+    pub fn get(&self, key: &GcObject) -> Option<V> {
+        self.entries
+            .get(&Self::address(key))
+            .and_then(Ephemeron::value)
+    }
+
+    /// Return `true` if a value is associated to `key`.
+    pub fn contains_key(&self, key: &GcObject) -> bool {
+        self.entries
+            .get(&Self::address(key))
+            .is_some_and(Ephemeron::is_alive)
+    }
+
+    /// Associate `value` to `key`, replacing the previous value, if any.
+    pub fn insert(&mut self, key: &GcObject, value: V) {
+        self.remove_collected();
+        let ephemeron = key.ephemeron(value);
+        self.entries.insert(Self::address(key), ephemeron);
+    }
+
+    /// Remove the value associated to `key`, and return `true` if there was one.
+    pub fn remove(&mut self, key: &GcObject) -> bool {
+        self.entries
+            .remove(&Self::address(key))
+            .is_some_and(|ephemeron| ephemeron.is_alive())
+    }
+
+    /// Remove the entries of the keys that were garbage collected, if there was a garbage
+    /// collection since they were last removed.
+    fn remove_collected(&mut self) {
+        let collections = gc::collections();
+        if collections != self.collections {
+            self.entries.retain(|_, ephemeron| ephemeron.is_alive());
+            self.collections = collections;
+        }
+    }
+}
+
+impl<V> Default for EphemeronTable<V>
+where
+    V: Trace + Clone + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Debug for EphemeronTable<V>
+where
+    V: Trace + Clone + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EphemeronTable")
+            .field("entries", &self.entries.len())
+            .finish()
+    }
+}
@@ -106,6 +106,7 @@ impl MapIterator {
                                         let result = Array::construct_array(
                                             &Array::new_array(ctx)?,
                                             &[key.clone(), value.clone()],
+                                            ctx,
                                         )?;
                                         return Ok(create_iter_result_object(ctx, result, false));
                                     }
@@ -11,11 +11,14 @@ use ordered_map::OrderedMap;
 pub mod map_iterator;
 use map_iterator::{MapIterationKind, MapIterator};
 
+pub mod ephemeron_table;
 pub mod ordered_map;
 #[cfg(test)]
 mod tests;
+pub mod weak_map;
 
 #[derive(Debug, Clone)]
+#[allow(dead_code)]
 pub(crate) struct Map(OrderedMap<Value, Value>);
 
 impl BuiltIn for Map {
@@ -37,6 +40,12 @@ impl BuiltIn for Map {
             .constructable(false)
             .build();
 
+        let species_symbol = context.well_known_symbols().species_symbol();
+        let get_species = FunctionBuilder::new(context, Self::species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+
         let map_object = ConstructorBuilder::new(context, Self::constructor)
             .name(Self::NAME)
             .length(Self::LENGTH)
@@ -58,6 +67,12 @@ impl BuiltIn for Map {
             .method(Self::has, "has", 1)
             .method(Self::for_each, "forEach", 1)
             .method(Self::values, "values", 0)
+            .static_accessor(
+                species_symbol,
+                Some(get_species),
+                None,
+                Attribute::CONFIGURABLE,
+            )
             .callable(false)
             .build();
 
@@ -68,10 +83,26 @@ impl BuiltIn for Map {
 impl Map {
     pub(crate) const LENGTH: usize = 1;
 
+    /// `get Map [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-map-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/@@species
+    pub(crate) fn species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
     /// Create a new map
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         // Set Prototype
-        let prototype = ctx.global_object().get_field("Map").get_field(PROTOTYPE);
+        let prototype = ctx
+            .global_object()
+            .clone()
+            .get_field("Map", ctx)?
+            .get_field(PROTOTYPE, ctx)?;
 
         this.as_object()
             .expect("this is map object")
@@ -84,15 +115,15 @@ impl Map {
             0 => OrderedMap::new(),
             _ => match &args[0] {
                 Value::Object(object) => {
-                    let object = object.borrow();
-                    if let Some(map) = object.as_map_ref().cloned() {
+                    let map = object.borrow().as_map_ref().cloned();
+                    if let Some(map) = map {
                         map
                     } else if object.is_array() {
                         let mut map = OrderedMap::new();
-                        let len = args[0].get_field("length").to_integer(ctx)? as i32;
+                        let len = args[0].get_field("length", ctx)?.to_integer(ctx)? as i32;
                         for i in 0..len {
-                            let val = &args[0].get_field(i.to_string());
-                            let (key, value) = Self::get_key_value(val).ok_or_else(|| {
+                            let val = &args[0].get_field(i.to_string(), ctx)?;
+                            let (key, value) = Self::get_key_value(val, ctx)?.ok_or_else(|| {
                                 ctx.construct_type_error(
                                     "iterable for Map should have array-like objects",
                                 )
@@ -352,17 +383,18 @@ impl Map {
     }
 
     /// Helper function to get a key-value pair from an array.
-    fn get_key_value(value: &Value) -> Option<(Value, Value)> {
+    fn get_key_value(value: &Value, ctx: &mut Context) -> Result<Option<(Value, Value)>> {
         if let Value::Object(object) = value {
             if object.is_array() {
-                let (key, value) = match value.get_field("length").as_number().unwrap() as i32 {
+                let (key, value) = match value.get_field("length", ctx)?.as_number().unwrap() as i32
+                {
                     0 => (Value::Undefined, Value::Undefined),
-                    1 => (value.get_field("0"), Value::Undefined),
-                    _ => (value.get_field("0"), value.get_field("1")),
+                    1 => (value.get_field("0", ctx)?, Value::Undefined),
+                    _ => (value.get_field("0", ctx)?, value.get_field("1", ctx)?),
                 };
-                return Some((key, value));
+                return Ok(Some((key, value)));
             }
         }
-        None
+        Ok(None)
     }
 }
@@ -1,4 +1,4 @@
-use crate::{forward, Context};
+use crate::{forward, gc::force_collect, Context};
 
 #[test]
 fn construct_empty() {
@@ -354,3 +354,70 @@ fn not_a_function() {
         "\"TypeError: function object is not callable\""
     );
 }
+
+#[test]
+fn weak_map() {
+    let mut engine = Context::new();
+    let init = r#"
+        let a = {};
+        let b = {};
+        let map = new WeakMap([[a, 1]]);
+        let returned = map.set(b, "b");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "returned === map"), "true");
+    assert_eq!(forward(&mut engine, "map.get(a)"), "1");
+    assert_eq!(forward(&mut engine, "map.get(b)"), "\"b\"");
+    assert_eq!(forward(&mut engine, "map.get({})"), "undefined");
+    assert_eq!(forward(&mut engine, "map.get(1)"), "undefined");
+    assert_eq!(forward(&mut engine, "map.has(a)"), "true");
+    assert_eq!(forward(&mut engine, "map.has(1)"), "false");
+    assert_eq!(forward(&mut engine, "map.set(a, 2).get(a)"), "2");
+    assert_eq!(forward(&mut engine, "map.delete(a)"), "true");
+    assert_eq!(forward(&mut engine, "map.delete(a)"), "false");
+    assert_eq!(forward(&mut engine, "map.has(a)"), "false");
+    assert_eq!(forward(&mut engine, "map.size"), "undefined");
+    assert_eq!(
+        forward(&mut engine, "map.set(1, 1)"),
+        "Uncaught \"TypeError\": \"WeakMap.prototype.set: key is not an object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "WeakMap.prototype.get.call(new Map(), a)"),
+        "Uncaught \"TypeError\": \"'this' is not a WeakMap\""
+    );
+}
+
+#[test]
+fn weak_map_collects_unreachable_keys() {
+    let mut engine = Context::new();
+    let init = r#"
+        let map = new WeakMap();
+        let key = {};
+        let kept = {};
+        let keyRef = new WeakRef(key);
+        map.set(key, { key: key });
+        map.set(kept, {});
+        let valueRef = new WeakRef(map.get(kept));
+        key = null;
+        "#;
+    engine.eval(init).unwrap();
+    force_collect();
+    assert_eq!(forward(&mut engine, "keyRef.deref()"), "undefined");
+    assert_eq!(
+        forward(&mut engine, "valueRef.deref() === map.get(kept)"),
+        "true"
+    );
+}
+
+#[test]
+fn species() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "Map[Symbol.species] === Map"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(Map, Symbol.species).get.name"
+        ),
+        "\"get [Symbol.species]\""
+    );
+}
@@ -0,0 +1,207 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! A `WeakMap` associates values to objects, without keeping the objects alive: once a key is
+//! garbage collected, its entry disappears, along with its value if it isn't referenced
+//! elsewhere.
+
+use super::ephemeron_table::EphemeronTable;
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn},
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_map_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .method(Self::delete, "delete", 1)
+            .method(Self::get, "get", 1)
+            .method(Self::has, "has", 1)
+            .method(Self::set, "set", 2)
+            .callable(false)
+            .build();
+
+        (Self::NAME, weak_map_object.into(), Self::attribute())
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Create a new weak map
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let prototype = ctx
+            .global_object()
+            .clone()
+            .get_field("WeakMap", ctx)?
+            .get_field(PROTOTYPE, ctx)?;
+
+        this.as_object()
+            .expect("this is weak map object")
+            .set_prototype_instance(prototype);
+
+        this.set_data(ObjectData::WeakMap(EphemeronTable::new()));
+
+        let iterable = args.first().cloned().unwrap_or_else(Value::undefined);
+        Self::add_entries_from_iterable(this, iterable, ctx)?;
+
+        Ok(this.clone())
+    }
+
+    /// Adds the `[key, value]` entries of `iterable` to the new weak map `target`, by calling
+    /// its `set` method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-add-entries-from-iterable
+    fn add_entries_from_iterable(target: &Value, iterable: Value, ctx: &mut Context) -> Result<()> {
+        if iterable.is_null_or_undefined() {
+            return Ok(());
+        }
+
+        let adder = target.get_field("set", ctx)?;
+        if !adder.is_function() {
+            return Err(ctx.construct_type_error("'set' is not a function"));
+        }
+
+        let iterator = get_iterator(ctx, iterable)?;
+        loop {
+            let next = iterator.next(ctx)?;
+            if next.is_done() {
+                return Ok(());
+            }
+            let entry = next.value();
+            let status = if entry.is_object() {
+                entry.get_field("0", ctx).and_then(|key| {
+                    let value = entry.get_field("1", ctx)?;
+                    ctx.call(&adder, target, &[key, value])
+                })
+            } else {
+                Err(ctx.construct_type_error("iterator value is not an entry object"))
+            };
+            if status.is_err() {
+                iterator.close(status, ctx)?;
+            }
+        }
+    }
+
+    /// `WeakMap.prototype.delete( key )`
+    ///
+    /// This method removes the entry of the key, if it exists. Returns true if there was an
+    /// entry, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let key = args.first().and_then(Value::as_object);
+
+        if let Value::Object(ref object) = this {
+            if let Some(map) = object.borrow_mut().as_weak_map_mut() {
+                return Ok(key.is_some_and(|key| map.remove(&key)).into());
+            }
+        }
+
+        Err(ctx.construct_type_error("'this' is not a WeakMap"))
+    }
+
+    /// `WeakMap.prototype.get( key )`
+    ///
+    /// This method returns the value associated with the key, or undefined if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let key = args.first().and_then(Value::as_object);
+
+        if let Value::Object(ref object) = this {
+            if let Some(map) = object.borrow().as_weak_map_ref() {
+                return Ok(key
+                    .and_then(|key| map.get(&key))
+                    .unwrap_or_else(Value::undefined));
+            }
+        }
+
+        Err(ctx.construct_type_error("'this' is not a WeakMap"))
+    }
+
+    /// `WeakMap.prototype.has( key )`
+    ///
+    /// This method checks if the weak map contains an entry for the given key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let key = args.first().and_then(Value::as_object);
+
+        if let Value::Object(ref object) = this {
+            if let Some(map) = object.borrow().as_weak_map_ref() {
+                return Ok(key.is_some_and(|key| map.contains_key(&key)).into());
+            }
+        }
+
+        Err(ctx.construct_type_error("'this' is not a WeakMap"))
+    }
+
+    /// `WeakMap.prototype.set( key, value )`
+    ///
+    /// This method associates the value with the key, which must be an object. Returns the
+    /// weak map object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let is_weak_map = this
+            .as_object()
+            .is_some_and(|object| object.borrow().as_weak_map_ref().is_some());
+        if !is_weak_map {
+            return ctx.throw_type_error("'this' is not a WeakMap");
+        }
+
+        let key = match args.first().and_then(Value::as_object) {
+            Some(key) => key,
+            None => return ctx.throw_type_error("WeakMap.prototype.set: key is not an object"),
+        };
+        let value = args.get(1).cloned().unwrap_or_else(Value::undefined);
+
+        if let Some(map) = this
+            .as_object()
+            .expect("this is weak map object")
+            .borrow_mut()
+            .as_weak_map_mut()
+        {
+            map.insert(&key, value);
+        }
+        Ok(this.clone())
+    }
+}
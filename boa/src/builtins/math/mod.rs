@@ -15,7 +15,7 @@ use crate::{
     builtins::BuiltIn, object::ObjectInitializer, property::Attribute, BoaProfiler, Context,
     Result, Value,
 };
-use std::f64;
+use std::f64::consts;
 
 #[cfg(test)]
 mod tests;
@@ -36,14 +36,14 @@ impl BuiltIn for Math {
 
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
         let object = ObjectInitializer::new(context)
-            .property("E", f64::consts::E, attribute)
-            .property("LN2", f64::consts::LN_2, attribute)
-            .property("LN10", f64::consts::LN_10, attribute)
-            .property("LOG2E", f64::consts::LOG2_E, attribute)
-            .property("LOG10E", f64::consts::LOG10_E, attribute)
+            .property("E", consts::E, attribute)
+            .property("LN2", consts::LN_2, attribute)
+            .property("LN10", consts::LN_10, attribute)
+            .property("LOG2E", consts::LOG2_E, attribute)
+            .property("LOG10E", consts::LOG10_E, attribute)
             .property("SQRT1_2", 0.5_f64.sqrt(), attribute)
-            .property("SQRT2", f64::consts::SQRT_2, attribute)
-            .property("PI", f64::consts::PI, attribute)
+            .property("SQRT2", consts::SQRT_2, attribute)
+            .property("PI", consts::PI, attribute)
             .function(Self::abs, "abs", 1)
             .function(Self::acos, "acos", 1)
             .function(Self::acosh, "acosh", 1)
@@ -96,7 +96,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/abs
     pub(crate) fn abs(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::abs)
@@ -113,7 +113,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acos
     pub(crate) fn acos(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::acos)
@@ -130,7 +130,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/acosh
     pub(crate) fn acosh(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::acosh)
@@ -147,7 +147,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asin
     pub(crate) fn asin(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::asin)
@@ -164,7 +164,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/asinh
     pub(crate) fn asinh(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::asinh)
@@ -181,7 +181,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atan
     pub(crate) fn atan(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::atan)
@@ -198,7 +198,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atanh
     pub(crate) fn atanh(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::atanh)
@@ -215,7 +215,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/atan2
     pub(crate) fn atan2(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(match (
-            args.get(0).map(|x| x.to_number(ctx)).transpose()?,
+            args.first().map(|x| x.to_number(ctx)).transpose()?,
             args.get(1).map(|x| x.to_number(ctx)).transpose()?,
         ) {
             (Some(x), Some(y)) => x.atan2(y),
@@ -234,7 +234,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cbrt
     pub(crate) fn cbrt(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::cbrt)
@@ -251,7 +251,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/ceil
     pub(crate) fn ceil(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::ceil)
@@ -268,7 +268,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/clz32
     pub(crate) fn clz32(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_u32(ctx))
             .transpose()?
             .map(u32::leading_zeros)
@@ -286,7 +286,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cos
     pub(crate) fn cos(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::cos)
@@ -303,7 +303,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/cosh
     pub(crate) fn cosh(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::cosh)
@@ -320,7 +320,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/exp
     pub(crate) fn exp(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::exp)
@@ -339,7 +339,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/expm1
     pub(crate) fn expm1(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::exp_m1)
@@ -356,7 +356,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/floor
     pub(crate) fn floor(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::floor)
@@ -373,7 +373,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/fround
     pub(crate) fn fround(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, |x| (x as f32) as f64)
@@ -407,7 +407,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/imul
     pub(crate) fn imul(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(match (
-            args.get(0).map(|x| x.to_u32(ctx)).transpose()?,
+            args.first().map(|x| x.to_u32(ctx)).transpose()?,
             args.get(1).map(|x| x.to_u32(ctx)).transpose()?,
         ) {
             (Some(x), Some(y)) => x.wrapping_mul(y) as i32,
@@ -426,7 +426,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log
     pub(crate) fn log(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, |x| if x <= 0.0 { f64::NAN } else { x.ln() })
@@ -443,7 +443,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log1p
     pub(crate) fn log1p(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::ln_1p)
@@ -460,7 +460,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log10
     pub(crate) fn log10(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, |x| if x <= 0.0 { f64::NAN } else { x.log10() })
@@ -477,7 +477,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/log2
     pub(crate) fn log2(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, |x| if x <= 0.0 { f64::NAN } else { x.log2() })
@@ -528,7 +528,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/pow
     pub(crate) fn pow(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(match (
-            args.get(0).map(|x| x.to_number(ctx)).transpose()?,
+            args.first().map(|x| x.to_number(ctx)).transpose()?,
             args.get(1).map(|x| x.to_number(ctx)).transpose()?,
         ) {
             (Some(x), Some(y)) => x.powf(y),
@@ -559,7 +559,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/round
     pub(crate) fn round(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::round)
@@ -576,7 +576,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sign
     pub(crate) fn sign(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(
@@ -602,7 +602,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sin
     pub(crate) fn sin(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::sin)
@@ -619,7 +619,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sinh
     pub(crate) fn sinh(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::sinh)
@@ -636,7 +636,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sqrt
     pub(crate) fn sqrt(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::sqrt)
@@ -653,7 +653,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tan
     pub(crate) fn tan(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::tan)
@@ -670,7 +670,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/tanh
     pub(crate) fn tanh(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::tanh)
@@ -687,7 +687,7 @@ impl Math {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/trunc
     pub(crate) fn trunc(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         Ok(args
-            .get(0)
+            .first()
             .map(|x| x.to_number(ctx))
             .transpose()?
             .map_or(f64::NAN, f64::trunc)
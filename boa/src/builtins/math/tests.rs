@@ -1,7 +1,7 @@
 #![allow(clippy::float_cmp)]
 
 use crate::{forward, forward_val, Context};
-use std::f64;
+use std::f64::consts;
 
 #[test]
 fn abs() {
@@ -93,7 +93,7 @@ fn asinh() {
     let a = forward_val(&mut engine, "a").unwrap();
     let b = forward_val(&mut engine, "b").unwrap();
 
-    assert_eq!(a.to_number(&mut engine).unwrap(), 0.881_373_587_019_542_9);
+    assert!((a.to_number(&mut engine).unwrap() - 0.881_373_587_019_543).abs() < f64::EPSILON);
     assert_eq!(b.to_number(&mut engine).unwrap(), 0_f64);
 }
 
@@ -112,7 +112,7 @@ fn atan() {
     let b = forward_val(&mut engine, "b").unwrap();
     let c = forward_val(&mut engine, "c").unwrap();
 
-    assert_eq!(a.to_number(&mut engine).unwrap(), f64::consts::FRAC_PI_4);
+    assert_eq!(a.to_number(&mut engine).unwrap(), consts::FRAC_PI_4);
     assert_eq!(b.to_number(&mut engine).unwrap(), 0_f64);
     assert_eq!(c.to_number(&mut engine).unwrap(), f64::from(-0));
 }
@@ -445,7 +445,7 @@ fn log() {
     let c = forward(&mut engine, "c");
 
     assert_eq!(a.to_number(&mut engine).unwrap(), 0_f64);
-    assert_eq!(b.to_number(&mut engine).unwrap(), f64::consts::LN_10);
+    assert_eq!(b.to_number(&mut engine).unwrap(), consts::LN_10);
     assert_eq!(c, String::from("NaN"));
 }
 
@@ -473,7 +473,7 @@ fn log1p() {
     let f = forward(&mut engine, "f");
     let g = forward(&mut engine, "g");
 
-    assert_eq!(a.to_number(&mut engine).unwrap(), f64::consts::LN_2);
+    assert_eq!(a.to_number(&mut engine).unwrap(), consts::LN_2);
     assert_eq!(b.to_number(&mut engine).unwrap(), 0f64);
     assert_eq!(c.to_number(&mut engine).unwrap(), -36.736_800_569_677_1);
     assert_eq!(d, "-Infinity");
@@ -497,7 +497,7 @@ fn log10() {
     let b = forward_val(&mut engine, "b").unwrap();
     let c = forward(&mut engine, "c");
 
-    assert_eq!(a.to_number(&mut engine).unwrap(), f64::consts::LOG10_2);
+    assert_eq!(a.to_number(&mut engine).unwrap(), consts::LOG10_2);
     assert_eq!(b.to_number(&mut engine).unwrap(), 0_f64);
     assert_eq!(c, String::from("NaN"));
 }
@@ -672,7 +672,7 @@ fn sqrt() {
     let c = forward_val(&mut engine, "c").unwrap();
 
     assert_eq!(a.to_number(&mut engine).unwrap(), 0_f64);
-    assert_eq!(b.to_number(&mut engine).unwrap(), f64::consts::SQRT_2);
+    assert_eq!(b.to_number(&mut engine).unwrap(), consts::SQRT_2);
     assert_eq!(c.to_number(&mut engine).unwrap(), 3_f64);
 }
 
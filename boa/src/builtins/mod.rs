@@ -1,13 +1,19 @@
 //! Builtins live here, such as Object, String, Math, etc.
 
 pub mod array;
+pub mod array_buffer;
+pub mod async_function;
 pub mod bigint;
 pub mod boolean;
 #[cfg(feature = "console")]
 pub mod console;
+pub mod data_view;
 pub mod date;
 pub mod error;
+pub mod eval;
+pub mod finalization_registry;
 pub mod function;
+pub mod generator;
 pub mod global_this;
 pub mod infinity;
 pub mod iterable;
@@ -17,31 +23,56 @@ pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
+pub mod proxy;
+pub mod reflect;
 pub mod regexp;
+pub mod set;
 pub mod string;
 pub mod symbol;
+pub mod typed_array;
 pub mod undefined;
+pub mod weak_ref;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
+    array_buffer::ArrayBuffer,
+    async_function::AsyncFunction,
     bigint::BigInt,
     boolean::Boolean,
+    data_view::DataView,
     date::Date,
-    error::{Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError},
+    error::{
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
+        UriError,
+    },
+    eval::Eval,
+    finalization_registry::FinalizationRegistry,
     function::BuiltInFunctionObject,
+    generator::Generator,
     global_this::GlobalThis,
     infinity::Infinity,
     json::Json,
     map::map_iterator::MapIterator,
+    map::weak_map::WeakMap,
     map::Map,
     math::Math,
     nan::NaN,
     number::Number,
-    object::Object as BuiltInObjectObject,
+    object::{for_in_iterator::ForInIterator, Object as BuiltInObjectObject},
+    promise::Promise,
+    proxy::Proxy,
+    reflect::Reflect,
     regexp::RegExp,
+    set::{set_iterator::SetIterator, weak_set::WeakSet, Set},
     string::String,
     symbol::Symbol,
+    typed_array::{
+        BigInt64Array, BigUint64Array, Float32Array, Float64Array, Int16Array, Int32Array,
+        Int8Array, TypedArray, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
+    },
     undefined::Undefined,
+    weak_ref::WeakRef,
 };
 use crate::{
     property::{Attribute, DataDescriptor},
@@ -67,6 +98,7 @@ pub fn init(context: &mut Context) {
         GlobalThis::init,
         BuiltInFunctionObject::init,
         BuiltInObjectObject::init,
+        Eval::init,
         Math::init,
         Json::init,
         Array::init,
@@ -75,8 +107,12 @@ pub fn init(context: &mut Context) {
         Date::init,
         Map::init,
         Number::init,
+        Promise::init,
+        Proxy::init,
+        Reflect::init,
         String::init,
         RegExp::init,
+        Set::init,
         Symbol::init,
         Error::init,
         RangeError::init,
@@ -85,6 +121,24 @@ pub fn init(context: &mut Context) {
         SyntaxError::init,
         EvalError::init,
         UriError::init,
+        AggregateError::init,
+        WeakMap::init,
+        WeakSet::init,
+        WeakRef::init,
+        FinalizationRegistry::init,
+        ArrayBuffer::init,
+        Int8Array::init,
+        Uint8Array::init,
+        Uint8ClampedArray::init,
+        Int16Array::init,
+        Uint16Array::init,
+        Int32Array::init,
+        Uint32Array::init,
+        Float32Array::init,
+        Float64Array::init,
+        BigInt64Array::init,
+        BigUint64Array::init,
+        DataView::init,
         #[cfg(feature = "console")]
         console::Console::init,
     ];
@@ -100,4 +154,8 @@ pub fn init(context: &mut Context) {
         let property = DataDescriptor::new(value, attribute);
         global_object.borrow_mut().insert(name, property);
     }
+
+    // Intrinsics that are not bound to a global.
+    AsyncFunction::init_prototype(context);
+    TypedArray::init_intrinsic(context);
 }
@@ -154,8 +154,8 @@ impl Number {
 
     /// `Number( value )`
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let data = match args.get(0) {
-            Some(ref value) => value.to_numeric_number(ctx)?,
+        let data = match args.first() {
+            Some(value) => value.to_numeric_number(ctx)?,
             None => 0.0,
         };
         this.set_data(ObjectData::Number(data));
@@ -191,7 +191,7 @@ impl Number {
     fn num_to_exponential(n: f64) -> String {
         match n.abs() {
             x if x > 1.0 => format!("{:e}", n).replace("e", "e+"),
-            x if x == 0.0 => format!("{:e}", n).replace("e", "e+"),
+            0.0 => format!("{:e}", n).replace("e", "e+"),
             _ => format!("{:e}", n),
         }
     }
@@ -230,7 +230,7 @@ impl Number {
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_fixed(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let this_num = Self::this_number_value(this, ctx)?;
-        let precision = match args.get(0) {
+        let precision = match args.first() {
             Some(n) => match n.to_integer(ctx)? as i32 {
                 x if x > 0 => n.to_integer(ctx)? as usize,
                 _ => 0,
@@ -279,7 +279,7 @@ impl Number {
     pub(crate) fn to_precision(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let this_num = Self::this_number_value(this, ctx)?;
         let _num_str_len = format!("{}", this_num).len();
-        let _precision = match args.get(0) {
+        let _precision = match args.first() {
             Some(n) => match n.to_integer(ctx)? as i32 {
                 x if x > 0 => n.to_integer(ctx)? as usize,
                 _ => 0,
@@ -437,13 +437,13 @@ impl Number {
         // 2. If radix is undefined, let radixNumber be 10.
         // 3. Else, let radixNumber be ? ToInteger(radix).
         let radix = args
-            .get(0)
+            .first()
             .map(|arg| arg.to_integer(ctx))
             .transpose()?
             .map_or(10, |radix| radix as u8);
 
         // 4. If radixNumber < 2 or radixNumber > 36, throw a RangeError exception.
-        if radix < 2 || radix > 36 {
+        if !(2..=36).contains(&radix) {
             return ctx
                 .throw_range_error("radix must be an integer at least 2 and no greater than 36");
         }
@@ -504,7 +504,7 @@ impl Number {
     /// [spec]: https://tc39.es/ecma262/#sec-parseint-string-radix
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseInt
     pub(crate) fn parse_int(_this: &Value, args: &[Value], _ctx: &mut Context) -> Result<Value> {
-        if let (Some(val), r) = (args.get(0), args.get(1)) {
+        if let (Some(val), r) = (args.first(), args.get(1)) {
             let mut radix = if let Some(rx) = r {
                 if let Value::Integer(i) = rx {
                     *i as u32
@@ -570,7 +570,7 @@ impl Number {
     /// [spec]: https://tc39.es/ecma262/#sec-parsefloat-string
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseFloat
     pub(crate) fn parse_float(_this: &Value, args: &[Value], _ctx: &mut Context) -> Result<Value> {
-        if let Some(val) = args.get(0) {
+        if let Some(val) = args.first() {
             match val {
                 Value::String(s) => {
                     if let Ok(i) = s.parse::<i32>() {
@@ -616,7 +616,7 @@ impl Number {
         args: &[Value],
         ctx: &mut Context,
     ) -> Result<Value> {
-        if let Some(value) = args.get(0) {
+        if let Some(value) = args.first() {
             let number = value.to_number(ctx)?;
             Ok(number.is_finite().into())
         } else {
@@ -639,7 +639,7 @@ impl Number {
     /// [spec]: https://tc39.es/ecma262/#sec-isnan-number
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/isNaN
     pub(crate) fn global_is_nan(_this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        if let Some(value) = args.get(0) {
+        if let Some(value) = args.first() {
             let number = value.to_number(ctx)?;
             Ok(number.is_nan().into())
         } else {
@@ -666,7 +666,7 @@ impl Number {
         args: &[Value],
         _ctx: &mut Context,
     ) -> Result<Value> {
-        Ok(Value::from(if let Some(val) = args.get(0) {
+        Ok(Value::from(if let Some(val) = args.first() {
             match val {
                 Value::Integer(_) => true,
                 Value::Rational(number) => number.is_finite(),
@@ -692,7 +692,7 @@ impl Number {
         args: &[Value],
         _ctx: &mut Context,
     ) -> Result<Value> {
-        Ok(args.get(0).map_or(false, Self::is_integer).into())
+        Ok(args.first().is_some_and(Self::is_integer).into())
     }
 
     /// `Number.isNaN( number )`
@@ -714,7 +714,7 @@ impl Number {
         args: &[Value],
         _ctx: &mut Context,
     ) -> Result<Value> {
-        Ok(Value::from(if let Some(val) = args.get(0) {
+        Ok(Value::from(if let Some(val) = args.first() {
             match val {
                 Value::Integer(_) => false,
                 Value::Rational(number) => number.is_nan(),
@@ -744,7 +744,7 @@ impl Number {
         args: &[Value],
         _ctx: &mut Context,
     ) -> Result<Value> {
-        Ok(Value::from(match args.get(0) {
+        Ok(Value::from(match args.first() {
             Some(Value::Integer(_)) => true,
             Some(Value::Rational(number)) if Self::is_float_integer(*number) => {
                 number.abs() <= Number::MAX_SAFE_INTEGER
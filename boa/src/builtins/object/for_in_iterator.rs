@@ -0,0 +1,135 @@
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object},
+    gc::{Finalize, Trace},
+    object::ObjectData,
+    property::PropertyKey,
+    BoaProfiler, Context, Result, Value,
+};
+use rustc_hash::FxHashSet;
+use std::collections::VecDeque;
+
+/// The ForInIterator object represents the enumeration of the keys of an object in a
+/// `for...in` loop. It implements the iterator protocol.
+///
+/// Only string keys are enumerated. Keys of the prototype chain are enumerated after the own
+/// keys, unless they are shadowed, and keys deleted before they are reached are skipped.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-for-in-iterator-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct ForInIterator {
+    object: Value,
+    visited_keys: FxHashSet<PropertyKey>,
+    remaining_keys: VecDeque<PropertyKey>,
+    object_was_visited: bool,
+}
+
+impl ForInIterator {
+    pub(crate) const NAME: &'static str = "ForInIterator";
+
+    fn new(object: Value) -> Self {
+        ForInIterator {
+            object,
+            visited_keys: FxHashSet::default(),
+            remaining_keys: VecDeque::new(),
+            object_was_visited: false,
+        }
+    }
+
+    /// CreateForInIterator( object )
+    ///
+    /// Creates a new iterator over the enumerable string keys of the given object and its
+    /// prototypes.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createforiniterator
+    pub(crate) fn create_for_in_iterator(context: &Context, object: Value) -> Value {
+        let for_in_iterator = Value::new_object(Some(context.global_object()));
+        for_in_iterator.set_data(ObjectData::ForInIterator(Self::new(object)));
+        for_in_iterator
+            .as_object()
+            .expect("for in iterator object")
+            .set_prototype_instance(context.iterator_prototypes().for_in_iterator().into());
+        for_in_iterator
+    }
+
+    /// %ForInIteratorPrototype%.next( )
+    ///
+    /// Gets the next enumerable key of the object or of its prototypes.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%foriniteratorprototype%.next
+    pub(crate) fn next(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        if let Value::Object(ref o) = this {
+            let mut for_in_iterator = o.borrow_mut();
+            if let Some(iterator) = for_in_iterator.as_for_in_iterator_mut() {
+                let mut object = match iterator.object {
+                    Value::Object(ref object) => object.clone(),
+                    _ => return Ok(create_iter_result_object(context, Value::undefined(), true)),
+                };
+                loop {
+                    if !iterator.object_was_visited {
+                        let keys = object.own_property_keys(context)?;
+                        iterator.remaining_keys.extend(
+                            keys.into_iter()
+                                .filter(|key| !matches!(key, PropertyKey::Symbol(_))),
+                        );
+                        iterator.object_was_visited = true;
+                    }
+                    while let Some(key) = iterator.remaining_keys.pop_front() {
+                        if iterator.visited_keys.contains(&key) {
+                            continue;
+                        }
+                        // Keys that were deleted since the object was visited are skipped.
+                        if let Some(desc) = object.get_own_property(&key, context)? {
+                            let enumerable = desc.enumerable();
+                            let value = Value::from(key.to_string());
+                            iterator.visited_keys.insert(key);
+                            if enumerable {
+                                return Ok(create_iter_result_object(context, value, false));
+                            }
+                        }
+                    }
+                    iterator.object = object.get_prototype_of(context)?;
+                    iterator.object_was_visited = false;
+                    match iterator.object {
+                        Value::Object(ref prototype) => object = prototype.clone(),
+                        _ => {
+                            return Ok(create_iter_result_object(context, Value::undefined(), true))
+                        }
+                    }
+                }
+            } else {
+                context.throw_type_error("`this` is not a ForInIterator")
+            }
+        } else {
+            context.throw_type_error("`this` is not a ForInIterator")
+        }
+    }
+
+    /// Create the %ForInIteratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%foriniteratorprototype%-object
+    pub(crate) fn create_prototype(context: &mut Context, iterator_prototype: Value) -> Value {
+        let global = context.global_object();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let for_in_iterator = Value::new_object(Some(global));
+        make_builtin_fn(Self::next, "next", &for_in_iterator, 0, context);
+        for_in_iterator
+            .as_object()
+            .expect("for in iterator prototype object")
+            .set_prototype_instance(iterator_prototype);
+        for_in_iterator
+    }
+}
@@ -14,18 +14,27 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{iterable::get_iterator, Array, BuiltIn},
     object::{ConstructorBuilder, Object as BuiltinObject, ObjectData, ObjectInitializer},
+    object::{GcObject, IntegrityLevel},
     property::Attribute,
-    property::DataDescriptor,
-    property::PropertyDescriptor,
+    property::{AccessorDescriptor, DataDescriptor, PropertyDescriptor, PropertyKey},
     value::{same_value, Value},
     BoaProfiler, Context, Result,
 };
 
+pub mod for_in_iterator;
 #[cfg(test)]
 mod tests;
 
+/// The kinds of the values returned by `EnumerableOwnPropertyNames`.
+#[derive(Debug, Clone, Copy)]
+enum PropertyNameKind {
+    Key,
+    Value,
+    KeyAndValue,
+}
+
 /// The global JavaScript object.
 #[derive(Debug, Clone, Copy)]
 pub struct Object;
@@ -51,6 +60,10 @@ impl BuiltIn for Object {
         .method(Self::has_own_property, "hasOwnProperty", 0)
         .method(Self::property_is_enumerable, "propertyIsEnumerable", 0)
         .method(Self::to_string, "toString", 0)
+        .method(Self::define_getter, "__defineGetter__", 2)
+        .method(Self::define_setter, "__defineSetter__", 2)
+        .method(Self::lookup_getter, "__lookupGetter__", 1)
+        .method(Self::lookup_setter, "__lookupSetter__", 1)
         .static_method(Self::create, "create", 2)
         .static_method(Self::set_prototype_of, "setPrototypeOf", 2)
         .static_method(Self::get_prototype_of, "getPrototypeOf", 1)
@@ -67,6 +80,20 @@ impl BuiltIn for Object {
             "getOwnPropertyDescriptors",
             1,
         )
+        .static_method(Self::get_own_property_names, "getOwnPropertyNames", 1)
+        .static_method(Self::get_own_property_symbols, "getOwnPropertySymbols", 1)
+        .static_method(Self::keys, "keys", 1)
+        .static_method(Self::values, "values", 1)
+        .static_method(Self::entries, "entries", 1)
+        .static_method(Self::from_entries, "fromEntries", 1)
+        .static_method(Self::assign, "assign", 2)
+        .static_method(Self::freeze, "freeze", 1)
+        .static_method(Self::is_frozen, "isFrozen", 1)
+        .static_method(Self::seal, "seal", 1)
+        .static_method(Self::is_sealed, "isSealed", 1)
+        .static_method(Self::prevent_extensions, "preventExtensions", 1)
+        .static_method(Self::is_extensible, "isExtensible", 1)
+        .static_method(Self::has_own, "hasOwn", 2)
         .build();
 
         (Self::NAME, object.into(), Self::attribute())
@@ -77,7 +104,7 @@ impl Object {
     const LENGTH: usize = 1;
 
     fn constructor(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        if let Some(arg) = args.get(0) {
+        if let Some(arg) = args.first() {
             if !arg.is_null_or_undefined() {
                 return Ok(arg.to_object(context)?.into());
             }
@@ -98,7 +125,7 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-object.create
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/create
     pub fn create(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let prototype = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let prototype = args.first().cloned().unwrap_or_else(Value::undefined);
         let properties = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
         let obj = match prototype {
@@ -136,12 +163,12 @@ impl Object {
         args: &[Value],
         ctx: &mut Context,
     ) -> Result<Value> {
-        let object = args.get(0).unwrap_or(&Value::undefined()).to_object(ctx)?;
+        let object = args.first().unwrap_or(&Value::undefined()).to_object(ctx)?;
         if let Some(key) = args.get(1) {
             let key = key.to_property_key(ctx)?;
 
-            if let Some(desc) = object.get_own_property(&key) {
-                return Ok(Self::from_property_descriptor(desc, ctx)?);
+            if let Some(desc) = object.get_own_property(&key, ctx)? {
+                return Self::from_property_descriptor(desc, ctx);
             }
         }
 
@@ -163,15 +190,13 @@ impl Object {
         args: &[Value],
         ctx: &mut Context,
     ) -> Result<Value> {
-        let object = args.get(0).unwrap_or(&Value::undefined()).to_object(ctx)?;
+        let object = args.first().unwrap_or(&Value::undefined()).to_object(ctx)?;
         let descriptors = ctx.construct_object();
 
-        for key in object.borrow().keys() {
-            let descriptor = {
-                let desc = object
-                    .get_own_property(&key)
-                    .expect("Expected property to be on object.");
-                Self::from_property_descriptor(desc, ctx)?
+        for key in object.own_property_keys(ctx)? {
+            let descriptor = match object.get_own_property(&key, ctx)? {
+                Some(desc) => Self::from_property_descriptor(desc, ctx)?,
+                None => continue,
             };
 
             if !descriptor.is_undefined() {
@@ -189,30 +214,36 @@ impl Object {
     ///
     /// [ECMAScript reference][spec]
     /// [spec]: https://tc39.es/ecma262/#sec-frompropertydescriptor
-    fn from_property_descriptor(desc: PropertyDescriptor, ctx: &mut Context) -> Result<Value> {
+    pub(crate) fn from_property_descriptor(
+        desc: PropertyDescriptor,
+        ctx: &mut Context,
+    ) -> Result<Value> {
         let mut descriptor = ObjectInitializer::new(ctx);
 
-        if let PropertyDescriptor::Data(data_desc) = &desc {
-            descriptor.property("value", data_desc.value(), Attribute::all());
-        }
-
-        if let PropertyDescriptor::Accessor(accessor_desc) = &desc {
-            if let Some(setter) = accessor_desc.setter() {
-                descriptor.property("set", Value::Object(setter.to_owned()), Attribute::all());
+        match &desc {
+            PropertyDescriptor::Data(data_desc) => {
+                descriptor
+                    .property("value", data_desc.value(), Attribute::all())
+                    .property(
+                        "writable",
+                        Value::from(data_desc.writable()),
+                        Attribute::all(),
+                    );
             }
-            if let Some(getter) = accessor_desc.getter() {
-                descriptor.property("get", Value::Object(getter.to_owned()), Attribute::all());
+            PropertyDescriptor::Accessor(accessor_desc) => {
+                let getter = accessor_desc
+                    .getter()
+                    .map_or_else(Value::undefined, |getter| getter.clone().into());
+                let setter = accessor_desc
+                    .setter()
+                    .map_or_else(Value::undefined, |setter| setter.clone().into());
+                descriptor
+                    .property("get", getter, Attribute::all())
+                    .property("set", setter, Attribute::all());
             }
         }
 
-        let writable = if let PropertyDescriptor::Data(data_desc) = &desc {
-            data_desc.writable()
-        } else {
-            false
-        };
-
         descriptor
-            .property("writable", Value::from(writable), Attribute::all())
             .property(
                 "enumerable",
                 Value::from(desc.enumerable()),
@@ -229,7 +260,7 @@ impl Object {
 
     /// Uses the SameValue algorithm to check equality of objects
     pub fn is(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let x = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let x = args.first().cloned().unwrap_or_else(Value::undefined);
         let y = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
         Ok(same_value(&x, &y).into())
@@ -237,7 +268,7 @@ impl Object {
 
     /// Get the `prototype` of an object.
     pub fn get_prototype_of(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let obj = args.get(0).expect("Cannot get object");
+        let obj = args.first().expect("Cannot get object");
         Ok(obj
             .as_object()
             .map_or_else(Value::undefined, |object| object.prototype_instance()))
@@ -245,7 +276,7 @@ impl Object {
 
     /// Set the `prototype` of an object.
     pub fn set_prototype_of(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let obj = args.get(0).expect("Cannot get object").clone();
+        let obj = args.first().expect("Cannot get object").clone();
         let proto = args.get(1).expect("Cannot get object").clone();
         obj.as_object().unwrap().set_prototype_instance(proto);
         Ok(obj)
@@ -253,10 +284,15 @@ impl Object {
 
     /// Define a property in an object
     pub fn define_property(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let obj = args.get(0).expect("Cannot get object");
+        let obj = args.first().cloned().unwrap_or_default();
+        let mut object = match obj.as_object() {
+            Some(object) => object,
+            None => return context.throw_type_error("Object.defineProperty called on non-object"),
+        };
         let prop = args
             .get(1)
-            .expect("Cannot get object")
+            .cloned()
+            .unwrap_or_default()
             .to_property_key(context)?;
 
         let desc = if let Value::Object(ref object) = args.get(2).cloned().unwrap_or_default() {
@@ -264,8 +300,10 @@ impl Object {
         } else {
             return context.throw_type_error("Property description must be an object");
         };
-        obj.set_property(prop, desc);
-        Ok(Value::undefined())
+        if !object.define_own_property(prop.clone(), desc, context)? {
+            return context.throw_type_error(format!("Cannot redefine property: {}", prop));
+        }
+        Ok(obj)
     }
 
     /// `Object.defineProperties( proto, [propertiesObject] )`
@@ -279,7 +317,7 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-object.defineproperties
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperties
     pub fn define_properties(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let arg = args.get(0).cloned().unwrap_or_default();
+        let arg = args.first().cloned().unwrap_or_default();
         let arg_obj = arg.as_object();
         if let Some(mut obj) = arg_obj {
             let props = args.get(1).cloned().unwrap_or_else(Value::undefined);
@@ -289,6 +327,361 @@ impl Object {
             ctx.throw_type_error("Expected an object")
         }
     }
+
+    /// Abstract operation `EnumerableOwnPropertyNames ( O, kind )`
+    ///
+    /// Returns the keys, values or entries of the enumerable own string-keyed properties of the
+    /// object, in the order of its own property keys.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-enumerableownpropertynames
+    fn enumerable_own_property_names(
+        object: &GcObject,
+        kind: PropertyNameKind,
+        ctx: &mut Context,
+    ) -> Result<Vec<Value>> {
+        let mut properties = Vec::new();
+        for key in object.own_property_keys(ctx)? {
+            if let PropertyKey::Symbol(_) = key {
+                continue;
+            }
+            let enumerable = object
+                .get_own_property(&key, ctx)?
+                .is_some_and(|desc| desc.enumerable());
+            if !enumerable {
+                continue;
+            }
+            let property = match kind {
+                PropertyNameKind::Key => key.into(),
+                PropertyNameKind::Value => object.get(&key, object.clone().into(), ctx)?,
+                PropertyNameKind::KeyAndValue => {
+                    let value = object.get(&key, object.clone().into(), ctx)?;
+                    Array::construct_array(&Array::new_array(ctx)?, &[key.into(), value], ctx)?
+                }
+            };
+            properties.push(property);
+        }
+        Ok(properties)
+    }
+
+    /// `Object.keys( target )`
+    ///
+    /// Returns an array with the names of the enumerable own properties of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys
+    pub fn keys(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.first().cloned().unwrap_or_default().to_object(ctx)?;
+        let keys = Self::enumerable_own_property_names(&object, PropertyNameKind::Key, ctx)?;
+        Array::construct_array(&Array::new_array(ctx)?, &keys, ctx)
+    }
+
+    /// `Object.values( target )`
+    ///
+    /// Returns an array with the values of the enumerable own properties of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/values
+    pub fn values(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.first().cloned().unwrap_or_default().to_object(ctx)?;
+        let values = Self::enumerable_own_property_names(&object, PropertyNameKind::Value, ctx)?;
+        Array::construct_array(&Array::new_array(ctx)?, &values, ctx)
+    }
+
+    /// `Object.entries( target )`
+    ///
+    /// Returns an array with the `[key, value]` pairs of the enumerable own properties of the
+    /// object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/entries
+    pub fn entries(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.first().cloned().unwrap_or_default().to_object(ctx)?;
+        let entries =
+            Self::enumerable_own_property_names(&object, PropertyNameKind::KeyAndValue, ctx)?;
+        Array::construct_array(&Array::new_array(ctx)?, &entries, ctx)
+    }
+
+    /// `Object.fromEntries( iterable )`
+    ///
+    /// Creates an object with the properties given by the `[key, value]` pairs of an iterable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.fromentries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries
+    pub fn from_entries(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let iterable = args.first().cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return ctx.throw_type_error("Object.fromEntries: iterable is null or undefined");
+        }
+
+        let object = ctx.construct_object();
+        let iterator = get_iterator(ctx, iterable)?;
+        loop {
+            let next = iterator.next(ctx)?;
+            if next.is_done() {
+                return Ok(object.into());
+            }
+            let entry = next.value();
+            let result = if entry.is_object() {
+                Self::add_entry(&object, &entry, ctx)
+            } else {
+                Err(ctx.construct_type_error("Object.fromEntries: iterator value is not an entry"))
+            };
+            if let Err(error) = result {
+                return iterator.close(Err(error), ctx);
+            }
+        }
+    }
+
+    /// Defines the property given by a `[key, value]` entry on the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-create-data-property-on-object-functions
+    fn add_entry(object: &GcObject, entry: &Value, ctx: &mut Context) -> Result<()> {
+        let key = entry.get_field(0, ctx)?.to_property_key(ctx)?;
+        let value = entry.get_field(1, ctx)?;
+        object.borrow_mut().insert(
+            key,
+            PropertyDescriptor::from(DataDescriptor::new(value, Attribute::all())),
+        );
+        Ok(())
+    }
+
+    /// `Object.assign( target, ...sources )`
+    ///
+    /// Copies the values of the enumerable own properties of the sources to the target object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.assign
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign
+    pub fn assign(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let mut target = args.first().cloned().unwrap_or_default().to_object(ctx)?;
+
+        for source in args.iter().skip(1) {
+            if source.is_null_or_undefined() {
+                continue;
+            }
+            let source = source.to_object(ctx)?;
+            for key in source.own_property_keys(ctx)? {
+                let enumerable = source
+                    .get_own_property(&key, ctx)?
+                    .is_some_and(|desc| desc.enumerable());
+                if !enumerable {
+                    continue;
+                }
+                let value = source.get(&key, source.clone().into(), ctx)?;
+                if !target.set(key.clone(), value, target.clone().into(), ctx)? {
+                    return ctx.throw_type_error(format!(
+                        "Object.assign: cannot assign to property: {}",
+                        key
+                    ));
+                }
+            }
+        }
+
+        Ok(target.into())
+    }
+
+    /// `Object.freeze( target )`
+    ///
+    /// Prevents the properties of the object from being added, removed or changed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.freeze
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze
+    pub fn freeze(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = args.first().cloned().unwrap_or_default();
+        if let Value::Object(ref object) = target {
+            if !object
+                .clone()
+                .set_integrity_level(IntegrityLevel::Frozen, ctx)?
+            {
+                return ctx.throw_type_error("Object.freeze: cannot freeze object");
+            }
+        }
+        Ok(target)
+    }
+
+    /// `Object.isFrozen( target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isfrozen
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isFrozen
+    pub fn is_frozen(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        match args.first() {
+            Some(Value::Object(ref object)) => Ok(object
+                .test_integrity_level(IntegrityLevel::Frozen, ctx)?
+                .into()),
+            _ => Ok(true.into()),
+        }
+    }
+
+    /// `Object.seal( target )`
+    ///
+    /// Prevents the properties of the object from being added, removed or reconfigured.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.seal
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal
+    pub fn seal(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = args.first().cloned().unwrap_or_default();
+        if let Value::Object(ref object) = target {
+            if !object
+                .clone()
+                .set_integrity_level(IntegrityLevel::Sealed, ctx)?
+            {
+                return ctx.throw_type_error("Object.seal: cannot seal object");
+            }
+        }
+        Ok(target)
+    }
+
+    /// `Object.isSealed( target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.issealed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isSealed
+    pub fn is_sealed(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        match args.first() {
+            Some(Value::Object(ref object)) => Ok(object
+                .test_integrity_level(IntegrityLevel::Sealed, ctx)?
+                .into()),
+            _ => Ok(true.into()),
+        }
+    }
+
+    /// `Object.preventExtensions( target )`
+    ///
+    /// Prevents new properties from being added to the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.preventextensions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/preventExtensions
+    pub fn prevent_extensions(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = args.first().cloned().unwrap_or_default();
+        if let Value::Object(ref object) = target {
+            if !object.clone().prevent_extensions(ctx)? {
+                return ctx.throw_type_error("Object.preventExtensions: cannot prevent extensions");
+            }
+        }
+        Ok(target)
+    }
+
+    /// `Object.isExtensible( target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isextensible
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isExtensible
+    pub fn is_extensible(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        match args.first() {
+            Some(Value::Object(ref object)) => Ok(object.is_extensible(ctx)?.into()),
+            _ => Ok(false.into()),
+        }
+    }
+
+    /// `Object.getOwnPropertyNames( target )`
+    ///
+    /// Returns an array with the string keys of the own properties of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertynames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyNames
+    pub fn get_own_property_names(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.first().cloned().unwrap_or_default().to_object(ctx)?;
+        let names: Vec<Value> = object
+            .own_property_keys(ctx)?
+            .iter()
+            .filter(|key| !matches!(key, PropertyKey::Symbol(_)))
+            .map(Value::from)
+            .collect();
+        Array::construct_array(&Array::new_array(ctx)?, &names, ctx)
+    }
+
+    /// `Object.getOwnPropertySymbols( target )`
+    ///
+    /// Returns an array with the symbol keys of the own properties of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertysymbols
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertySymbols
+    pub fn get_own_property_symbols(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.first().cloned().unwrap_or_default().to_object(ctx)?;
+        let symbols: Vec<Value> = object
+            .own_property_keys(ctx)?
+            .iter()
+            .filter(|key| matches!(key, PropertyKey::Symbol(_)))
+            .map(Value::from)
+            .collect();
+        Array::construct_array(&Array::new_array(ctx)?, &symbols, ctx)
+    }
+
+    /// `Object.hasOwn( object, property )`
+    ///
+    /// Returns `true` if the object has the property as its own property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.hasown
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwn
+    pub fn has_own(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = args.first().cloned().unwrap_or_default().to_object(ctx)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+        Ok(object.get_own_property(&key, ctx)?.is_some().into())
+    }
+
     /// `Object.prototype.toString()`
     ///
     /// This method returns a string representing the object.
@@ -311,8 +704,8 @@ impl Object {
                 let o = o.borrow();
                 match &o.data {
                     ObjectData::Array => "Array",
-                    // TODO: Arguments Exotic Objects are currently not supported
-                    ObjectData::Function(_) => "Function",
+                    ObjectData::Arguments(_) => "Arguments",
+                    ObjectData::Function(_) | ObjectData::BoundFunction(_) => "Function",
                     ObjectData::Error => "Error",
                     ObjectData::Boolean(_) => "Boolean",
                     ObjectData::Number(_) => "Number",
@@ -323,7 +716,11 @@ impl Object {
                 }
             };
 
-            let tag = o.get(&ctx.well_known_symbols().to_string_tag_symbol().into());
+            let tag = o.get(
+                &ctx.well_known_symbols().to_string_tag_symbol().into(),
+                o.clone().into(),
+                ctx,
+            )?;
 
             let tag_str = tag.as_string().map(|s| s.as_str()).unwrap_or(builtin_tag);
 
@@ -346,12 +743,11 @@ impl Object {
         let prop = if args.is_empty() {
             None
         } else {
-            Some(args.get(0).expect("Cannot get object").to_string(ctx)?)
+            Some(args.first().expect("Cannot get object").to_string(ctx)?)
         };
         let own_property = this
-            .as_object()
-            .expect("Cannot get THIS object")
-            .get_own_property(&prop.expect("cannot get prop").into());
+            .to_object(ctx)?
+            .get_own_property(&prop.expect("cannot get prop").into(), ctx)?;
         if own_property.is_none() {
             Ok(Value::from(false))
         } else {
@@ -364,16 +760,158 @@ impl Object {
         args: &[Value],
         ctx: &mut Context,
     ) -> Result<Value> {
-        let key = match args.get(0) {
+        let key = match args.first() {
             None => return Ok(Value::from(false)),
             Some(key) => key,
         };
 
         let key = key.to_property_key(ctx)?;
-        let own_property = this.to_object(ctx)?.get_own_property(&key);
+        let own_property = this.to_object(ctx)?.get_own_property(&key, ctx)?;
 
         Ok(own_property.map_or(Value::from(false), |own_prop| {
             Value::from(own_prop.enumerable())
         }))
     }
+    /// `Object.prototype.__defineGetter__( property, getter )`
+    ///
+    /// Binds a property of the object to a function to be called when that property is looked up.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__defineGetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__defineGetter__
+    pub fn define_getter(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let getter = match args.get(1) {
+            Some(Value::Object(getter)) if getter.is_callable() => getter.clone(),
+            _ => {
+                return ctx
+                    .throw_type_error("Object.prototype.__defineGetter__: Expecting function")
+            }
+        };
+        let (mut object, key) = Self::legacy_accessor_target(this, args, ctx)?;
+        let setter = match object.get_own_property(&key, ctx)? {
+            Some(PropertyDescriptor::Accessor(ref accessor)) => accessor.setter().cloned(),
+            _ => None,
+        };
+        Self::define_legacy_accessor(&mut object, key, Some(getter), setter, ctx)
+    }
+
+    /// `Object.prototype.__defineSetter__( property, setter )`
+    ///
+    /// Binds a property of the object to a function to be called when an attempt is made to set
+    /// that property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__defineSetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__defineSetter__
+    pub fn define_setter(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let setter = match args.get(1) {
+            Some(Value::Object(setter)) if setter.is_callable() => setter.clone(),
+            _ => {
+                return ctx
+                    .throw_type_error("Object.prototype.__defineSetter__: Expecting function")
+            }
+        };
+        let (mut object, key) = Self::legacy_accessor_target(this, args, ctx)?;
+        let getter = match object.get_own_property(&key, ctx)? {
+            Some(PropertyDescriptor::Accessor(ref accessor)) => accessor.getter().cloned(),
+            _ => None,
+        };
+        Self::define_legacy_accessor(&mut object, key, getter, Some(setter), ctx)
+    }
+
+    /// `Object.prototype.__lookupGetter__( property )`
+    ///
+    /// Returns the function bound as a getter to the specified property, looking it up in the
+    /// prototype chain of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__lookupGetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__lookupGetter__
+    pub fn lookup_getter(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (object, key) = Self::legacy_accessor_target(this, args, ctx)?;
+        Ok(Self::lookup_accessor(object, &key, ctx)?
+            .and_then(|accessor| accessor.getter().cloned())
+            .map_or_else(Value::undefined, Value::from))
+    }
+
+    /// `Object.prototype.__lookupSetter__( property )`
+    ///
+    /// Returns the function bound as a setter to the specified property, looking it up in the
+    /// prototype chain of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.prototype.__lookupSetter__
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/__lookupSetter__
+    pub fn lookup_setter(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (object, key) = Self::legacy_accessor_target(this, args, ctx)?;
+        Ok(Self::lookup_accessor(object, &key, ctx)?
+            .and_then(|accessor| accessor.setter().cloned())
+            .map_or_else(Value::undefined, Value::from))
+    }
+
+    /// Converts the `this` value and the property argument of the legacy accessor methods to an
+    /// object and a property key.
+    fn legacy_accessor_target(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<(GcObject, PropertyKey)> {
+        let object = this.to_object(ctx)?;
+        let key = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(ctx)?;
+        Ok((object, key))
+    }
+
+    /// Defines an enumerable and configurable accessor property, throwing a `TypeError` if the
+    /// property cannot be redefined.
+    fn define_legacy_accessor(
+        object: &mut GcObject,
+        key: PropertyKey,
+        getter: Option<GcObject>,
+        setter: Option<GcObject>,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let desc = AccessorDescriptor::new(
+            getter,
+            setter,
+            Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        if !object.define_own_property(key.clone(), desc.into(), ctx)? {
+            return ctx.throw_type_error(format!("Cannot redefine property: {}", key));
+        }
+        Ok(Value::undefined())
+    }
+
+    /// Finds the property `key` in the prototype chain of `object`, returning it if it is an
+    /// accessor property.
+    fn lookup_accessor(
+        mut object: GcObject,
+        key: &PropertyKey,
+        ctx: &mut Context,
+    ) -> Result<Option<AccessorDescriptor>> {
+        loop {
+            match object.get_own_property(key, ctx)? {
+                Some(desc) => return Ok(desc.as_accessor_descriptor().cloned()),
+                None => match object.get_prototype_of(ctx)?.as_object() {
+                    Some(prototype) => object = prototype,
+                    None => return Ok(None),
+                },
+            }
+        }
+    }
 }
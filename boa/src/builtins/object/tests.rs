@@ -92,7 +92,8 @@ fn object_is() {
     assert_eq!(forward(&mut engine, "Object.is()"), "true");
     assert_eq!(forward(&mut engine, "Object.is(undefined)"), "true");
     assert!(engine.global_object().is_global());
-    assert!(!engine.global_object().get_field("Object").is_global());
+    let global = engine.global_object().clone();
+    assert!(!global.get_field("Object", &mut engine).unwrap().is_global());
 }
 #[test]
 fn object_has_own_property() {
@@ -180,6 +181,39 @@ fn object_to_string() {
     assert_eq!(forward(&mut ctx, "o.toString()"), "\"[object Object]\"");
 }
 
+#[test]
+fn object_to_string_uses_to_string_tag() {
+    let mut ctx = Context::new();
+    let init = r#"
+        let tagged = {};
+        tagged[Symbol.toStringTag] = "Custom";
+        let getter = {};
+        Object.defineProperty(getter, Symbol.toStringTag, { get: function() { return "Computed"; } });
+        let ignored = [];
+        ignored[Symbol.toStringTag] = 1;
+    "#;
+    forward(&mut ctx, init);
+    assert_eq!(
+        forward(&mut ctx, "Object.prototype.toString.call(tagged)"),
+        "\"[object Custom]\""
+    );
+    assert_eq!(
+        forward(&mut ctx, "Object.prototype.toString.call(getter)"),
+        "\"[object Computed]\""
+    );
+    assert_eq!(
+        forward(&mut ctx, "Object.prototype.toString.call(ignored)"),
+        "\"[object Array]\""
+    );
+    assert_eq!(
+        forward(
+            &mut ctx,
+            "Object.prototype.toString.call(Promise.resolve())"
+        ),
+        "\"[object Promise]\""
+    );
+}
+
 #[test]
 fn define_symbol_property() {
     let mut ctx = Context::new();
@@ -261,3 +295,321 @@ fn object_define_properties() {
 
     assert_eq!(forward(&mut ctx, "obj.p"), "42");
 }
+
+#[test]
+fn object_define_property_accessor() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const obj = { _value: 1 };
+
+        Object.defineProperty(obj, "value", {
+            get() {
+                return this._value * 2;
+            },
+            set(value) {
+                this._value = value;
+            },
+            configurable: true
+        });
+        obj.value = 5;
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "obj.value"), "10");
+    assert_eq!(forward(&mut ctx, "obj._value"), "5");
+    assert_eq!(forward(&mut ctx, "Object.create(obj).value"), "10");
+}
+
+#[test]
+fn object_define_property_getter_without_setter() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const obj = {};
+        Object.defineProperty(obj, "value", { get() { return 1; } });
+        obj.value = 2;
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "obj.value"), "1");
+    assert_eq!(
+        forward(
+            &mut ctx,
+            r#"
+            try {
+                Object.defineProperty(obj, "value", { value: 3 });
+            } catch (e) {
+                e instanceof TypeError;
+            }
+            "#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn get_own_property_descriptor_accessor() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const getter = function () {};
+        const obj = {};
+        Object.defineProperty(obj, "a", { get: getter, enumerable: true });
+        const desc = Object.getOwnPropertyDescriptor(obj, "a");
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "desc.get === getter"), "true");
+    assert_eq!(forward(&mut ctx, "desc.set"), "undefined");
+    assert_eq!(forward(&mut ctx, "'set' in desc"), "true");
+    assert_eq!(forward(&mut ctx, "'writable' in desc"), "false");
+    assert_eq!(forward(&mut ctx, "'value' in desc"), "false");
+    assert_eq!(forward(&mut ctx, "desc.enumerable"), "true");
+    assert_eq!(forward(&mut ctx, "desc.configurable"), "false");
+}
+
+#[test]
+fn object_define_getter_setter() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const values = [];
+        const obj = {};
+        obj.__defineGetter__("a", function () { return "get"; });
+        obj.__defineSetter__("a", function (value) { values.push(value); });
+        obj.a = 1;
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(forward(&mut ctx, "obj.a"), "\"get\"");
+    assert_eq!(forward(&mut ctx, "values.length"), "1");
+    assert_eq!(forward(&mut ctx, "values[0]"), "1");
+    assert_eq!(
+        forward(
+            &mut ctx,
+            "Object.getOwnPropertyDescriptor(obj, 'a').enumerable"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut ctx,
+            r#"
+            try {
+                obj.__defineGetter__("b", 1);
+            } catch (e) {
+                e instanceof TypeError;
+            }
+            "#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn object_lookup_getter_setter() {
+    let mut ctx = Context::new();
+
+    let init = r#"
+        const getter = function () {};
+        const setter = function (value) {};
+        const proto = {};
+        proto.__defineGetter__("a", getter);
+        proto.__defineSetter__("b", setter);
+        const obj = Object.create(proto);
+        const shadowing = Object.create(proto);
+        Object.defineProperty(shadowing, "a", { value: 1 });
+    "#;
+    forward(&mut ctx, init);
+
+    assert_eq!(
+        forward(&mut ctx, "obj.__lookupGetter__('a') === getter"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut ctx, "obj.__lookupSetter__('b') === setter"),
+        "true"
+    );
+    assert_eq!(forward(&mut ctx, "obj.__lookupSetter__('a')"), "undefined");
+    assert_eq!(forward(&mut ctx, "obj.__lookupGetter__('c')"), "undefined");
+    assert_eq!(
+        forward(&mut ctx, "shadowing.__lookupGetter__('a')"),
+        "undefined"
+    );
+}
+
+#[test]
+fn object_keys_values_entries() {
+    let mut engine = Context::new();
+    let init = r#"
+        const obj = { b: 1, a: 2, 2: 'x', 1: 'y' };
+        obj[Symbol('s')] = 3;
+        Object.defineProperty(obj, 'hidden', { value: 4, enumerable: false });
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify(Object.keys(obj))"),
+        r#""["1","2","b","a"]""#
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.values(obj).join()"),
+        "\"y,x,1,2\""
+    );
+    assert_eq!(
+        forward(&mut engine, "JSON.stringify(Object.entries(obj)[2])"),
+        r#""["b",1]""#
+    );
+    assert_eq!(forward(&mut engine, "Object.keys('ab').length"), "2");
+    assert_eq!(
+        forward(&mut engine, "Object.keys(null)"),
+        "Uncaught \"TypeError\": \"cannot convert 'null' or 'undefined' to object\""
+    );
+}
+
+#[test]
+fn object_from_entries() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "JSON.stringify(Object.fromEntries([['a', 1], ['b', 2]]))"
+        ),
+        r#""{"a":1,"b":2}""#
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.fromEntries(new Map([['m', 3]])).m"),
+        "3"
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.fromEntries([1])"),
+        "Uncaught \"TypeError\": \"Object.fromEntries: iterator value is not an entry\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.fromEntries()"),
+        "Uncaught \"TypeError\": \"Object.fromEntries: iterable is null or undefined\""
+    );
+}
+
+#[test]
+fn object_assign() {
+    let mut engine = Context::new();
+    let init = r#"
+        const target = { a: 1 };
+        const result = Object.assign(target, { b: 2 }, null, { a: 3 });
+        const frozen = Object.freeze({ a: 1 });
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "result === target"), "true");
+    assert_eq!(forward(&mut engine, "target.a + target.b"), "5");
+    assert_eq!(
+        forward(&mut engine, "Object.assign(frozen, { a: 2 })"),
+        "Uncaught \"TypeError\": \"Object.assign: cannot assign to property: a\""
+    );
+}
+
+#[test]
+fn object_freeze() {
+    let mut engine = Context::new();
+    let init = r#"
+        const frozen = Object.freeze({ a: 1, nested: { b: 2 } });
+        frozen.a = 2;
+        frozen.c = 3;
+        delete frozen.a;
+        const array = Object.freeze([1, 2, 3]);
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "frozen.a"), "1");
+    assert_eq!(forward(&mut engine, "frozen.c"), "undefined");
+    assert_eq!(forward(&mut engine, "Object.isFrozen(frozen)"), "true");
+    assert_eq!(forward(&mut engine, "Object.isSealed(frozen)"), "true");
+    assert_eq!(
+        forward(&mut engine, "Object.isFrozen(frozen.nested)"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "Object.isFrozen(1)"), "true");
+    assert_eq!(forward(&mut engine, "Object.freeze(1)"), "1");
+    assert_eq!(
+        forward(&mut engine, "array.pop()"),
+        "Uncaught \"TypeError\": \"cannot delete property: 2\""
+    );
+    assert_eq!(forward(&mut engine, "array.join()"), "\"1,2,3\"");
+}
+
+#[test]
+fn object_seal() {
+    let mut engine = Context::new();
+    let init = r#"
+        const sealed = Object.seal({ a: 1 });
+        sealed.a = 2;
+        sealed.b = 3;
+        delete sealed.a;
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "sealed.a"), "2");
+    assert_eq!(forward(&mut engine, "sealed.b"), "undefined");
+    assert_eq!(forward(&mut engine, "Object.isSealed(sealed)"), "true");
+    assert_eq!(forward(&mut engine, "Object.isFrozen(sealed)"), "false");
+    assert_eq!(forward(&mut engine, "Object.isSealed({})"), "false");
+}
+
+#[test]
+fn object_prevent_extensions() {
+    let mut engine = Context::new();
+    let init = r#"
+        const obj = Object.preventExtensions({ a: 1 });
+        obj.b = 2;
+        delete obj.a;
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "obj.b"), "undefined");
+    assert_eq!(forward(&mut engine, "obj.a"), "undefined");
+    assert_eq!(forward(&mut engine, "Object.isExtensible(obj)"), "false");
+    assert_eq!(forward(&mut engine, "Object.isExtensible({})"), "true");
+    assert_eq!(forward(&mut engine, "Object.isExtensible(1)"), "false");
+    assert_eq!(
+        forward(&mut engine, "Object.isFrozen(Object.preventExtensions({}))"),
+        "true"
+    );
+}
+
+#[test]
+fn object_get_own_property_names_and_symbols() {
+    let mut engine = Context::new();
+    let init = r#"
+        const sym = Symbol('s');
+        const obj = { a: 1 };
+        obj[sym] = 2;
+        Object.defineProperty(obj, 'hidden', { value: 3, enumerable: false });
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertyNames(obj).join()"),
+        "\"a,hidden\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertySymbols(obj)[0] === sym"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertySymbols(obj).length"),
+        "1"
+    );
+}
+
+#[test]
+fn object_has_own() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "Object.hasOwn({ a: 1 }, 'a')"), "true");
+    assert_eq!(
+        forward(&mut engine, "Object.hasOwn({ a: 1 }, 'toString')"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "Object.hasOwn([1], 0)"), "true");
+}
@@ -0,0 +1,1267 @@
+//! This module implements the global `Promise` object.
+//!
+//! A promise represents the eventual completion (or failure) of an asynchronous operation,
+//! and its resulting value. The reactions registered on a promise are run as jobs, which are
+//! queued on the [`Context`] and run by [`Context::run_jobs`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+use crate::{
+    builtins::{
+        function::{Captures, NativeClosure},
+        iterable::{get_iterator, IteratorRecord},
+        AggregateError, Array, BuiltIn, FinalizationRegistry,
+    },
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData},
+    property::Attribute,
+    value::same_value,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a promise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-internal-slots-of-promise-instances
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub enum PromiseState {
+    Pending,
+    Fulfilled,
+    Rejected,
+}
+
+unsafe impl Trace for PromiseState {
+    unsafe_empty_trace!();
+}
+
+/// The kind of a promise reaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+enum ReactionType {
+    Fulfill,
+    Reject,
+}
+
+unsafe impl Trace for ReactionType {
+    unsafe_empty_trace!();
+}
+
+/// A function that is run when a promise is settled.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct PromiseReaction {
+    /// The promise that is resolved with the result of the handler, if any.
+    capability: Option<PromiseCapability>,
+    kind: ReactionType,
+    /// The handler, or `None` if the settlement is passed through.
+    handler: Option<Value>,
+}
+
+/// A promise along with the functions that resolve or reject it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct PromiseCapability {
+    promise: Value,
+    resolve: Value,
+    reject: Value,
+}
+
+/// The state captured by the executor function created by `NewPromiseCapability`.
+#[derive(Debug, Trace, Finalize)]
+struct CapabilityExecutorState {
+    resolve: Value,
+    reject: Value,
+}
+
+impl PromiseCapability {
+    /// Creates a new promise from the constructor `constructor`, and gets its resolving
+    /// functions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    pub(crate) fn new(constructor: &Value, ctx: &mut Context) -> Result<Self> {
+        let constructor_object = match constructor {
+            Value::Object(object) if object.is_constructable() => object.clone(),
+            _ => return Err(ctx.construct_type_error("promise capability requires a constructor")),
+        };
+
+        let captures = Captures::new(CapabilityExecutorState {
+            resolve: Value::undefined(),
+            reject: Value::undefined(),
+        });
+        let executor = FunctionBuilder::closure(ctx, Self::executor, captures.clone())
+            .length(2)
+            .build();
+
+        let promise = constructor_object.construct(&[executor.into()], constructor, ctx)?;
+
+        let (resolve, reject) = {
+            let state = captures
+                .downcast_ref::<CapabilityExecutorState>()
+                .expect("captures of the capability executor changed type");
+            (state.resolve.clone(), state.reject.clone())
+        };
+        if !is_callable(&resolve) {
+            return Err(ctx.construct_type_error("promise resolve function is not callable"));
+        }
+        if !is_callable(&reject) {
+            return Err(ctx.construct_type_error("promise reject function is not callable"));
+        }
+
+        Ok(Self {
+            promise,
+            resolve,
+            reject,
+        })
+    }
+
+    /// The executor passed to the constructor by `NewPromiseCapability`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getcapabilitiesexecutor-functions
+    fn executor(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let mut state = captures
+            .downcast_mut::<CapabilityExecutorState>()
+            .expect("captures of the capability executor changed type");
+        if !state.resolve.is_undefined() || !state.reject.is_undefined() {
+            drop(state);
+            return ctx.throw_type_error("promise executor has already been called");
+        }
+        state.resolve = args.first().cloned().unwrap_or_default();
+        state.reject = args.get(1).cloned().unwrap_or_default();
+        Ok(Value::undefined())
+    }
+
+    /// Returns the promise of the capability.
+    pub(crate) fn promise(&self) -> &Value {
+        &self.promise
+    }
+
+    /// Resolves the promise of the capability with `value`.
+    pub(crate) fn resolve(&self, value: Value, ctx: &mut Context) -> Result<Value> {
+        call(ctx, &self.resolve, &Value::undefined(), &[value])
+    }
+
+    /// Rejects the promise of the capability with `reason`.
+    pub(crate) fn reject(&self, reason: Value, ctx: &mut Context) -> Result<Value> {
+        call(ctx, &self.reject, &Value::undefined(), &[reason])
+    }
+
+    /// Rejects the promise of the capability if `result` is an error.
+    ///
+    /// Returns the promise of the capability if it was rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ifabruptrejectpromise
+    fn if_abrupt_reject(&self, result: Result<Value>, ctx: &mut Context) -> Result<Value> {
+        match result {
+            Ok(value) => Ok(value),
+            Err(reason) => {
+                self.reject(reason, ctx)?;
+                Ok(self.promise.clone())
+            }
+        }
+    }
+}
+
+/// A job that is run once the code currently running has completed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-jobs
+#[derive(Debug)]
+pub(crate) enum Job {
+    /// Runs a reaction of a settled promise.
+    PromiseReaction {
+        reaction: PromiseReaction,
+        argument: Value,
+    },
+    /// Resolves `promise` by calling the `then` method of the thenable it was resolved with.
+    PromiseResolveThenable {
+        promise: GcObject,
+        thenable: Value,
+        then: Value,
+    },
+    /// Calls the cleanup callback of a finalization registry for its collected targets.
+    CleanupFinalizationRegistry { registry: GcObject },
+}
+
+impl Job {
+    /// Runs the job.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference (NewPromiseReactionJob)][reaction]
+    ///  - [ECMAScript reference (NewPromiseResolveThenableJob)][thenable]
+    ///  - [ECMAScript reference (HostEnqueueFinalizationRegistryCleanupJob)][cleanup]
+    ///
+    /// [reaction]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    /// [thenable]: https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob
+    /// [cleanup]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    pub(crate) fn run(self, ctx: &mut Context) -> Result<Value> {
+        match self {
+            Self::PromiseReaction { reaction, argument } => {
+                let handler_result = match reaction.handler {
+                    Some(ref handler) => call(ctx, handler, &Value::undefined(), &[argument]),
+                    None => match reaction.kind {
+                        ReactionType::Fulfill => Ok(argument),
+                        ReactionType::Reject => Err(argument),
+                    },
+                };
+
+                match &reaction.capability {
+                    Some(capability) => match handler_result {
+                        Ok(value) => capability.resolve(value, ctx),
+                        Err(reason) => capability.reject(reason, ctx),
+                    },
+                    None => handler_result.map(|_| Value::undefined()),
+                }
+            }
+            Self::PromiseResolveThenable {
+                promise,
+                thenable,
+                then,
+            } => {
+                let (resolve, reject) = Promise::create_resolving_functions(&promise, ctx);
+                let result = call(
+                    ctx,
+                    &then,
+                    &thenable,
+                    &[resolve.into(), reject.clone().into()],
+                );
+                match result {
+                    Ok(value) => Ok(value),
+                    Err(reason) => call(ctx, &reject.into(), &Value::undefined(), &[reason]),
+                }
+            }
+            Self::CleanupFinalizationRegistry { registry } => {
+                FinalizationRegistry::cleanup(&registry, ctx)
+            }
+        }
+    }
+}
+
+/// The state captured by the resolving functions of a promise.
+#[derive(Debug, Trace, Finalize)]
+struct ResolvingFunctionsState {
+    promise: GcObject,
+    already_resolved: bool,
+}
+
+/// The state shared by the element functions of `Promise.all()`, `Promise.allSettled()` and
+/// `Promise.any()`.
+#[derive(Debug, Trace, Finalize)]
+struct CombinatorState {
+    values: Vec<Value>,
+    remaining_elements: usize,
+    capability: PromiseCapability,
+}
+
+/// The state captured by an element function of a promise combinator.
+#[derive(Debug, Trace, Finalize)]
+struct ElementState {
+    already_called: bool,
+    index: usize,
+    state: Captures,
+}
+
+/// The state captured by the functions created by `Promise.prototype.finally()`.
+#[derive(Debug, Trace, Finalize)]
+struct FinallyState {
+    constructor: Value,
+    on_finally: Value,
+}
+
+/// The internal representation of a `Promise` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Promise {
+    state: PromiseState,
+    /// The value the promise was fulfilled with, or the reason it was rejected with.
+    result: Value,
+    fulfill_reactions: Vec<PromiseReaction>,
+    reject_reactions: Vec<PromiseReaction>,
+    is_handled: bool,
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let species_symbol = context.well_known_symbols().species_symbol();
+        let get_species = FunctionBuilder::new(context, Self::species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+
+        let promise_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().promise_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::then, "then", 2)
+        .method(Self::catch, "catch", 1)
+        .method(Self::finally, "finally", 1)
+        .property(
+            to_string_tag,
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .static_method(Self::all, "all", 1)
+        .static_method(Self::all_settled, "allSettled", 1)
+        .static_method(Self::any, "any", 1)
+        .static_method(Self::race, "race", 1)
+        .static_method(Self::reject, "reject", 1)
+        .static_method(Self::resolve, "resolve", 1)
+        .static_accessor(
+            species_symbol,
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .callable(false)
+        .build();
+
+        (Self::NAME, promise_object.into(), Self::attribute())
+    }
+}
+
+impl Promise {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `get Promise [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-promise-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/@@species
+    pub(crate) fn species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    fn new() -> Self {
+        Self {
+            state: PromiseState::Pending,
+            result: Value::undefined(),
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+            is_handled: false,
+        }
+    }
+
+    /// Returns the current state of the promise.
+    pub fn state(&self) -> PromiseState {
+        self.state
+    }
+
+    /// Returns the value the promise was fulfilled with, or the reason it was rejected with.
+    ///
+    /// This is `undefined` while the promise is pending.
+    pub fn result(&self) -> &Value {
+        &self.result
+    }
+
+    /// `Promise( executor )`
+    ///
+    /// Creates a new promise, and calls `executor` with the functions that resolve or
+    /// reject it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let executor = args.first().cloned().unwrap_or_default();
+        if !is_callable(&executor) {
+            return ctx.throw_type_error("Promise executor is not a function");
+        }
+
+        this.set_data(ObjectData::Promise(Self::new()));
+        let promise = this.as_object().expect("this is a promise object");
+
+        let (resolve, reject) = Self::create_resolving_functions(&promise, ctx);
+        let reject: Value = reject.into();
+        let result = call(
+            ctx,
+            &executor,
+            &Value::undefined(),
+            &[resolve.into(), reject.clone()],
+        );
+        if let Err(reason) = result {
+            call(ctx, &reject, &Value::undefined(), &[reason])?;
+        }
+
+        Ok(this.clone())
+    }
+
+    /// Creates the functions that resolve or reject `promise`.
+    ///
+    /// Only the first call to either of them has an effect.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    pub(crate) fn create_resolving_functions(
+        promise: &GcObject,
+        ctx: &mut Context,
+    ) -> (GcObject, GcObject) {
+        let captures = Captures::new(ResolvingFunctionsState {
+            promise: promise.clone(),
+            already_resolved: false,
+        });
+        let resolve = FunctionBuilder::closure(ctx, Self::resolve_function, captures.clone())
+            .length(1)
+            .build();
+        let reject = FunctionBuilder::closure(ctx, Self::reject_function, captures)
+            .length(1)
+            .build();
+        (resolve, reject)
+    }
+
+    /// Takes the promise out of the captures of its resolving functions, if they weren't
+    /// called already.
+    fn take_resolving_functions_promise(captures: &Captures) -> Option<GcObject> {
+        let mut state = captures
+            .downcast_mut::<ResolvingFunctionsState>()
+            .expect("captures of the resolving functions changed type");
+        if state.already_resolved {
+            None
+        } else {
+            state.already_resolved = true;
+            Some(state.promise.clone())
+        }
+    }
+
+    /// The promise resolve function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve-functions
+    fn resolve_function(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let promise = match Self::take_resolving_functions_promise(captures) {
+            Some(promise) => promise,
+            None => return Ok(Value::undefined()),
+        };
+        let resolution = args.first().cloned().unwrap_or_default();
+
+        let object = if let Value::Object(ref object) = resolution {
+            object
+        } else {
+            Self::fulfill(&promise, resolution, ctx);
+            return Ok(Value::undefined());
+        };
+        if GcObject::equals(object, &promise) {
+            let error = ctx.construct_type_error("cannot resolve a promise with itself");
+            Self::reject_with(&promise, error, ctx);
+            return Ok(Value::undefined());
+        }
+
+        let then = match resolution.get_field_with_receiver("then", &resolution, ctx) {
+            Ok(then) => then,
+            Err(error) => {
+                Self::reject_with(&promise, error, ctx);
+                return Ok(Value::undefined());
+            }
+        };
+        if !is_callable(&then) {
+            Self::fulfill(&promise, resolution, ctx);
+            return Ok(Value::undefined());
+        }
+
+        ctx.enqueue_job(Job::PromiseResolveThenable {
+            promise,
+            thenable: resolution,
+            then,
+        });
+        Ok(Value::undefined())
+    }
+
+    /// The promise reject function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-reject-functions
+    fn reject_function(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        if let Some(promise) = Self::take_resolving_functions_promise(captures) {
+            let reason = args.first().cloned().unwrap_or_default();
+            Self::reject_with(&promise, reason, ctx);
+        }
+        Ok(Value::undefined())
+    }
+
+    /// Settles a pending promise, and queues the reactions of the given kind.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference (FulfillPromise)][fulfill]
+    ///  - [ECMAScript reference (RejectPromise)][reject]
+    ///
+    /// [fulfill]: https://tc39.es/ecma262/#sec-fulfillpromise
+    /// [reject]: https://tc39.es/ecma262/#sec-rejectpromise
+    fn settle(promise: &GcObject, state: PromiseState, result: Value, ctx: &mut Context) {
+        let reactions = {
+            let mut object = promise.borrow_mut();
+            let promise = object
+                .as_promise_mut()
+                .expect("settling a non-promise object");
+            debug_assert_eq!(promise.state, PromiseState::Pending);
+
+            promise.state = state;
+            promise.result = result.clone();
+            let fulfill_reactions = std::mem::take(&mut promise.fulfill_reactions);
+            let reject_reactions = std::mem::take(&mut promise.reject_reactions);
+            if state == PromiseState::Fulfilled {
+                fulfill_reactions
+            } else {
+                reject_reactions
+            }
+        };
+
+        // <https://tc39.es/ecma262/#sec-triggerpromisereactions>
+        for reaction in reactions {
+            ctx.enqueue_job(Job::PromiseReaction {
+                reaction,
+                argument: result.clone(),
+            });
+        }
+    }
+
+    /// Fulfills a pending promise with `value`.
+    fn fulfill(promise: &GcObject, value: Value, ctx: &mut Context) {
+        Self::settle(promise, PromiseState::Fulfilled, value, ctx)
+    }
+
+    /// Rejects a pending promise with `reason`.
+    fn reject_with(promise: &GcObject, reason: Value, ctx: &mut Context) {
+        Self::settle(promise, PromiseState::Rejected, reason, ctx)
+    }
+
+    /// Registers the reactions to the settlement of `promise`.
+    ///
+    /// Returns the promise of the capability, or `undefined` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    pub(crate) fn perform_then(
+        promise: &GcObject,
+        on_fulfilled: &Value,
+        on_rejected: &Value,
+        capability: Option<PromiseCapability>,
+        ctx: &mut Context,
+    ) -> Value {
+        let result = capability
+            .as_ref()
+            .map_or_else(Value::undefined, |capability| capability.promise.clone());
+        let fulfill_reaction = PromiseReaction {
+            capability: capability.clone(),
+            kind: ReactionType::Fulfill,
+            handler: Some(on_fulfilled.clone()).filter(is_callable),
+        };
+        let reject_reaction = PromiseReaction {
+            capability,
+            kind: ReactionType::Reject,
+            handler: Some(on_rejected.clone()).filter(is_callable),
+        };
+
+        let mut object = promise.borrow_mut();
+        let data = object
+            .as_promise_mut()
+            .expect("registering reactions on a non-promise object");
+        data.is_handled = true;
+        let (reaction, argument) = match data.state {
+            PromiseState::Pending => {
+                data.fulfill_reactions.push(fulfill_reaction);
+                data.reject_reactions.push(reject_reaction);
+                return result;
+            }
+            PromiseState::Fulfilled => (fulfill_reaction, data.result.clone()),
+            PromiseState::Rejected => (reject_reaction, data.result.clone()),
+        };
+        drop(object);
+
+        ctx.enqueue_job(Job::PromiseReaction { reaction, argument });
+        result
+    }
+
+    /// Returns `value` if it is a promise created by `constructor`, or a new promise
+    /// resolved with `value` otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    pub(crate) fn promise_resolve(
+        constructor: &Value,
+        value: Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        if let Value::Object(ref object) = value {
+            if object.borrow().is_promise() {
+                let value_constructor =
+                    value.get_field_with_receiver("constructor", &value, ctx)?;
+                if same_value(&value_constructor, constructor) {
+                    return Ok(value);
+                }
+            }
+        }
+
+        let capability = PromiseCapability::new(constructor, ctx)?;
+        capability.resolve(value, ctx)?;
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.prototype.then( onFulfilled, onRejected )`
+    ///
+    /// Registers the functions called when the promise is fulfilled or rejected, and returns
+    /// a promise resolved with their result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let promise = match this {
+            Value::Object(object) if object.borrow().is_promise() => object.clone(),
+            _ => return ctx.throw_type_error("Promise.prototype.then called on a non-promise"),
+        };
+
+        let default_constructor = ctx.standard_objects().promise_object().constructor();
+        let constructor = promise.species_constructor(default_constructor.into(), ctx)?;
+        let capability = PromiseCapability::new(&constructor, ctx)?;
+
+        let on_fulfilled = args.first().cloned().unwrap_or_default();
+        let on_rejected = args.get(1).cloned().unwrap_or_default();
+        Ok(Self::perform_then(
+            &promise,
+            &on_fulfilled,
+            &on_rejected,
+            Some(capability),
+            ctx,
+        ))
+    }
+
+    /// `Promise.prototype.catch( onRejected )`
+    ///
+    /// Registers the function called when the promise is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let on_rejected = args.first().cloned().unwrap_or_default();
+        invoke_then(this, &[Value::undefined(), on_rejected], ctx)
+    }
+
+    /// `Promise.prototype.finally( onFinally )`
+    ///
+    /// Registers the function called when the promise is settled, without changing the
+    /// result of the promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let promise = match this {
+            Value::Object(object) => object.clone(),
+            _ => return ctx.throw_type_error("Promise.prototype.finally called on a non-object"),
+        };
+
+        let default_constructor = ctx.standard_objects().promise_object().constructor();
+        let constructor = promise.species_constructor(default_constructor.into(), ctx)?;
+
+        let on_finally = args.first().cloned().unwrap_or_default();
+        if !is_callable(&on_finally) {
+            return invoke_then(this, &[on_finally.clone(), on_finally], ctx);
+        }
+
+        let captures = Captures::new(FinallyState {
+            constructor,
+            on_finally,
+        });
+        let then_finally = FunctionBuilder::closure(ctx, Self::then_finally, captures.clone())
+            .length(1)
+            .build();
+        let catch_finally = FunctionBuilder::closure(ctx, Self::catch_finally, captures)
+            .length(1)
+            .build();
+        invoke_then(this, &[then_finally.into(), catch_finally.into()], ctx)
+    }
+
+    /// Calls the `onFinally` function of `Promise.prototype.finally()`, and returns a promise
+    /// that is settled once the result of `onFinally` is.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference (Then Finally Functions)][then]
+    ///  - [ECMAScript reference (Catch Finally Functions)][catch]
+    ///
+    /// [then]: https://tc39.es/ecma262/#sec-thenfinallyfunctions
+    /// [catch]: https://tc39.es/ecma262/#sec-catchfinallyfunctions
+    fn run_finally(
+        captures: &Captures,
+        on_settled: NativeClosure,
+        value: Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let (constructor, on_finally) = {
+            let state = captures
+                .downcast_ref::<FinallyState>()
+                .expect("captures of the finally functions changed type");
+            (state.constructor.clone(), state.on_finally.clone())
+        };
+
+        let result = call(ctx, &on_finally, &Value::undefined(), &[])?;
+        let promise = Self::promise_resolve(&constructor, result, ctx)?;
+        let on_settled = FunctionBuilder::closure(ctx, on_settled, Captures::new(value)).build();
+        invoke_then(&promise, &[on_settled.into()], ctx)
+    }
+
+    fn then_finally(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let value = args.first().cloned().unwrap_or_default();
+        Self::run_finally(
+            captures,
+            |_, _, captures, _| {
+                Ok(captures
+                    .downcast_ref::<Value>()
+                    .expect("captures of the value thunk changed type")
+                    .clone())
+            },
+            value,
+            ctx,
+        )
+    }
+
+    fn catch_finally(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let reason = args.first().cloned().unwrap_or_default();
+        Self::run_finally(
+            captures,
+            |_, _, captures, _| {
+                Err(captures
+                    .downcast_ref::<Value>()
+                    .expect("captures of the thrower changed type")
+                    .clone())
+            },
+            reason,
+            ctx,
+        )
+    }
+
+    /// `Promise.resolve( x )`
+    ///
+    /// Returns a promise resolved with `x`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        if !this.is_object() {
+            return ctx.throw_type_error("Promise.resolve called on a non-object");
+        }
+        let value = args.first().cloned().unwrap_or_default();
+        Self::promise_resolve(this, value, ctx)
+    }
+
+    /// `Promise.reject( r )`
+    ///
+    /// Returns a promise rejected with `r`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let capability = PromiseCapability::new(this, ctx)?;
+        let reason = args.first().cloned().unwrap_or_default();
+        capability.reject(reason, ctx)?;
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.all( iterable )`
+    ///
+    /// Returns a promise fulfilled with the values of all the given promises, or rejected
+    /// when any of them is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::combinator(this, args, ctx, |iterator, done, context| {
+            Self::perform_combinator(
+                iterator,
+                done,
+                context,
+                Some(Self::all_resolve_element),
+                None,
+                create_array,
+            )
+        })
+    }
+
+    /// `Promise.allSettled( iterable )`
+    ///
+    /// Returns a promise fulfilled with the outcomes of all the given promises, once all of
+    /// them are settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled
+    pub(crate) fn all_settled(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::combinator(this, args, ctx, |iterator, done, context| {
+            Self::perform_combinator(
+                iterator,
+                done,
+                context,
+                Some(Self::all_settled_resolve_element),
+                Some(Self::all_settled_reject_element),
+                create_array,
+            )
+        })
+    }
+
+    /// `Promise.any( iterable )`
+    ///
+    /// Returns a promise fulfilled with the value of the first of the given promises to be
+    /// fulfilled, or rejected with an `AggregateError` when all of them are rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any
+    pub(crate) fn any(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::combinator(this, args, ctx, |iterator, done, context| {
+            Self::perform_combinator(
+                iterator,
+                done,
+                context,
+                None,
+                Some(Self::any_reject_element),
+                |errors, ctx| Err(AggregateError::create(errors, ALL_REJECTED, ctx)?),
+            )
+        })
+    }
+
+    /// `Promise.race( iterable )`
+    ///
+    /// Returns a promise settled in the same way as the first of the given promises to be
+    /// settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        Self::combinator(this, args, ctx, |iterator, done, context| loop {
+            let value = match next_value(iterator, done, context.ctx)? {
+                Some(value) => value,
+                None => return Ok(context.capability.promise.clone()),
+            };
+            let next_promise = call(
+                context.ctx,
+                &context.promise_resolve,
+                &context.constructor,
+                &[value],
+            )?;
+            invoke_then(
+                &next_promise,
+                &[
+                    context.capability.resolve.clone(),
+                    context.capability.reject.clone(),
+                ],
+                context.ctx,
+            )?;
+        })
+    }
+
+    /// Runs the steps shared by `Promise.all()`, `Promise.allSettled()`, `Promise.any()` and
+    /// `Promise.race()`, which get the promises to combine from an iterable.
+    fn combinator<F>(this: &Value, args: &[Value], ctx: &mut Context, perform: F) -> Result<Value>
+    where
+        F: FnOnce(&IteratorRecord, &mut bool, &mut CombinatorContext<'_>) -> Result<Value>,
+    {
+        let capability = PromiseCapability::new(this, ctx)?;
+
+        // <https://tc39.es/ecma262/#sec-getpromiseresolve>
+        let promise_resolve = match this.get_field_with_receiver("resolve", this, ctx) {
+            Ok(resolve) if is_callable(&resolve) => Ok(resolve),
+            Ok(_) => Err(ctx.construct_type_error("Promise resolve function is not callable")),
+            Err(error) => Err(error),
+        };
+        let promise_resolve = match promise_resolve {
+            Ok(promise_resolve) => promise_resolve,
+            Err(error) => return capability.if_abrupt_reject(Err(error), ctx),
+        };
+
+        let iterable = args.first().cloned().unwrap_or_default();
+        let iterator = match get_iterator(ctx, iterable) {
+            Ok(iterator) => iterator,
+            Err(error) => return capability.if_abrupt_reject(Err(error), ctx),
+        };
+
+        let mut done = false;
+        let result = perform(
+            &iterator,
+            &mut done,
+            &mut CombinatorContext {
+                constructor: this.clone(),
+                promise_resolve,
+                capability: capability.clone(),
+                ctx,
+            },
+        );
+        let result = match result {
+            Err(error) if !done => iterator.close(Err(error), ctx),
+            result => result,
+        };
+        capability.if_abrupt_reject(result, ctx)
+    }
+
+    /// The loop of `Promise.all()`, `Promise.allSettled()` and `Promise.any()`.
+    ///
+    /// The element functions are called when the corresponding promise is fulfilled or
+    /// rejected, and `finish` creates the value the combined promise is resolved with, once
+    /// all the element functions have been called.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference (PerformPromiseAll)][all]
+    ///  - [ECMAScript reference (PerformPromiseAllSettled)][all_settled]
+    ///  - [ECMAScript reference (PerformPromiseAny)][any]
+    ///
+    /// [all]: https://tc39.es/ecma262/#sec-performpromiseall
+    /// [all_settled]: https://tc39.es/ecma262/#sec-performpromiseallsettled
+    /// [any]: https://tc39.es/ecma262/#sec-performpromiseany
+    fn perform_combinator(
+        iterator: &IteratorRecord,
+        done: &mut bool,
+        context: &mut CombinatorContext<'_>,
+        on_fulfilled: Option<NativeClosure>,
+        on_rejected: Option<NativeClosure>,
+        finish: fn(&[Value], &mut Context) -> Result<Value>,
+    ) -> Result<Value> {
+        let state = Captures::new(CombinatorState {
+            values: Vec::new(),
+            remaining_elements: 1,
+            capability: context.capability.clone(),
+        });
+
+        let mut index = 0;
+        loop {
+            let value = if let Some(value) = next_value(iterator, done, context.ctx)? {
+                value
+            } else {
+                if let Some(values) = Self::remove_element(&state, None) {
+                    let result = finish(&values, context.ctx)?;
+                    context.capability.resolve(result, context.ctx)?;
+                }
+                return Ok(context.capability.promise.clone());
+            };
+
+            {
+                let mut state = state
+                    .downcast_mut::<CombinatorState>()
+                    .expect("captures of the combinator changed type");
+                state.values.push(Value::undefined());
+                state.remaining_elements += 1;
+            }
+
+            let next_promise = call(
+                context.ctx,
+                &context.promise_resolve,
+                &context.constructor,
+                &[value],
+            )?;
+
+            let element = Captures::new(ElementState {
+                already_called: false,
+                index,
+                state: state.clone(),
+            });
+            // Without an element function, the combined promise is settled directly.
+            let element_function = |function, fallback: &Value, ctx: &mut Context| match function {
+                Some(function) => FunctionBuilder::closure(ctx, function, element.clone())
+                    .length(1)
+                    .build()
+                    .into(),
+                None => fallback.clone(),
+            };
+            let on_fulfilled =
+                element_function(on_fulfilled, &context.capability.resolve, context.ctx);
+            let on_rejected =
+                element_function(on_rejected, &context.capability.reject, context.ctx);
+
+            invoke_then(&next_promise, &[on_fulfilled, on_rejected], context.ctx)?;
+            index += 1;
+        }
+    }
+
+    /// Records the outcome of the element at `index` in the state of a combinator.
+    ///
+    /// Returns the outcomes of all the elements if it was the last one remaining.
+    fn remove_element(state: &Captures, outcome: Option<(usize, Value)>) -> Option<Vec<Value>> {
+        let mut state = state
+            .downcast_mut::<CombinatorState>()
+            .expect("captures of the combinator changed type");
+        if let Some((index, value)) = outcome {
+            state.values[index] = value;
+        }
+        state.remaining_elements -= 1;
+        if state.remaining_elements == 0 {
+            Some(std::mem::take(&mut state.values))
+        } else {
+            None
+        }
+    }
+
+    /// Runs the steps shared by the element functions of the combinators, and settles the
+    /// combined promise once the outcomes of all the elements are known.
+    fn resolve_element(
+        captures: &Captures,
+        value: Value,
+        rejected: bool,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let (index, state) = {
+            let mut element = captures
+                .downcast_mut::<ElementState>()
+                .expect("captures of the element function changed type");
+            if element.already_called {
+                return Ok(Value::undefined());
+            }
+            element.already_called = true;
+            (element.index, element.state.clone())
+        };
+
+        let capability = state
+            .downcast_ref::<CombinatorState>()
+            .expect("captures of the combinator changed type")
+            .capability
+            .clone();
+        if let Some(values) = Self::remove_element(&state, Some((index, value))) {
+            if rejected {
+                let error = AggregateError::create(&values, ALL_REJECTED, ctx)?;
+                return capability.reject(error, ctx);
+            }
+            let values = create_array(&values, ctx)?;
+            return capability.resolve(values, ctx);
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `Promise.all` resolve element functions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all-resolve-element-functions
+    fn all_resolve_element(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let value = args.first().cloned().unwrap_or_default();
+        Self::resolve_element(captures, value, false, ctx)
+    }
+
+    /// `Promise.allSettled` resolve element functions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled-resolve-element-functions
+    fn all_settled_resolve_element(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let value = args.first().cloned().unwrap_or_default();
+        let outcome: Value = ctx.construct_object().into();
+        outcome.set_field("status", "fulfilled", ctx)?;
+        outcome.set_field("value", value, ctx)?;
+        Self::resolve_element(captures, outcome, false, ctx)
+    }
+
+    /// `Promise.allSettled` reject element functions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled-reject-element-functions
+    fn all_settled_reject_element(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let reason = args.first().cloned().unwrap_or_default();
+        let outcome: Value = ctx.construct_object().into();
+        outcome.set_field("status", "rejected", ctx)?;
+        outcome.set_field("reason", reason, ctx)?;
+        Self::resolve_element(captures, outcome, false, ctx)
+    }
+
+    /// `Promise.any` reject element functions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any-reject-element-functions
+    fn any_reject_element(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        let reason = args.first().cloned().unwrap_or_default();
+        Self::resolve_element(captures, reason, true, ctx)
+    }
+}
+
+/// The message of the `AggregateError` a promise returned by `Promise.any()` is rejected with.
+const ALL_REJECTED: &str = "all promises were rejected";
+
+/// The values used by all the steps of a promise combinator.
+#[derive(Debug)]
+struct CombinatorContext<'context> {
+    constructor: Value,
+    promise_resolve: Value,
+    capability: PromiseCapability,
+    ctx: &'context mut Context,
+}
+
+/// Returns `true` if the value is a callable object.
+fn is_callable(value: &Value) -> bool {
+    matches!(value, Value::Object(object) if object.is_callable())
+}
+
+/// Calls `function`, without letting a `return` statement in it change the state of the
+/// code calling it.
+fn call(ctx: &mut Context, function: &Value, this: &Value, args: &[Value]) -> Result<Value> {
+    let state = ctx.executor().get_current_state().clone();
+    let result = ctx.call(function, this, args);
+    ctx.executor().set_current_state(state);
+    result
+}
+
+/// Calls the `then` method of `promise`.
+fn invoke_then(promise: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+    let then = promise.get_field_with_receiver("then", promise, ctx)?;
+    call(ctx, &then, promise, args)
+}
+
+/// Gets the next value of a combinator's iterator, or `None` once it is done.
+///
+/// `done` is set when the iterator must not be closed anymore, because it is done or it
+/// failed.
+fn next_value(
+    iterator: &IteratorRecord,
+    done: &mut bool,
+    ctx: &mut Context,
+) -> Result<Option<Value>> {
+    let next = match iterator.next(ctx) {
+        Ok(next) => next,
+        Err(error) => {
+            *done = true;
+            return Err(error);
+        }
+    };
+    if next.is_done() {
+        *done = true;
+        Ok(None)
+    } else {
+        Ok(Some(next.value()))
+    }
+}
+
+/// Creates an array with the given values.
+fn create_array(values: &[Value], ctx: &mut Context) -> Result<Value> {
+    let array = Array::new_array(ctx)?;
+    Array::add_to_array_object(&array, values, ctx)
+}
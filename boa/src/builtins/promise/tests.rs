@@ -0,0 +1,299 @@
+use crate::{forward, Context};
+
+#[test]
+fn reactions_run_as_jobs() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1).then(v => log.push('then ' + v));
+        log.push('sync');
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "log.join()"), "\"sync\"");
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "log.join()"), "\"sync,then 1\"");
+    assert!(!engine.has_pending_jobs());
+}
+
+#[test]
+fn run_jobs_on_eval() {
+    let mut engine = Context::new();
+    engine.set_run_jobs_on_eval(true);
+    engine
+        .eval("var x = 0; Promise.resolve(5).then(v => { x = v; });")
+        .unwrap();
+    assert_eq!(forward(&mut engine, "x"), "5");
+}
+
+#[test]
+fn run_jobs_on_eval_after_error() {
+    let mut engine = Context::new();
+    engine.set_run_jobs_on_eval(true);
+    let result = engine.eval("var x = 0; Promise.resolve(5).then(v => { x = v; }); throw 'error';");
+    assert_eq!(result.unwrap_err().display().to_string(), "\"error\"");
+    assert!(!engine.has_pending_jobs());
+    assert_eq!(forward(&mut engine, "x"), "5");
+}
+
+#[test]
+fn executor() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        new Promise((resolve, reject) => {
+            resolve('first');
+            resolve('second');
+            reject('third');
+        }).then(v => { result = v; });
+
+        var error;
+        new Promise(() => { throw 'thrown'; }).catch(e => { error = e; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"first\"");
+    assert_eq!(forward(&mut engine, "error"), "\"thrown\"");
+    assert_eq!(
+        forward(&mut engine, "new Promise(1)"),
+        "Uncaught \"TypeError\": \"Promise executor is not a function\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Promise(() => {})"),
+        "Uncaught \"TypeError\": \"function object is not callable\""
+    );
+}
+
+#[test]
+fn chaining() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        Promise.resolve(1)
+            .then(v => v + 1)
+            .then(v => { throw v * 10; })
+            .then(() => 'skipped')
+            .catch(e => e + 1)
+            .then(v => { result = v; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "21");
+}
+
+#[test]
+fn ordering() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        var p = Promise.resolve();
+        p.then(() => log.push('a1')).then(() => log.push('a2'));
+        p.then(() => log.push('b1')).then(() => log.push('b2'));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "log.join()"), "\"a1,b1,a2,b2\"");
+}
+
+#[test]
+fn thenables() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        var thenable = { then: function(resolve) { resolve(42); } };
+        Promise.resolve(thenable).then(v => { result = v; });
+
+        var nested;
+        new Promise(resolve => resolve(Promise.resolve('inner'))).then(v => { nested = v; });
+
+        var self_error;
+        var resolve_self;
+        var p = new Promise(resolve => { resolve_self = resolve; });
+        p.catch(e => { self_error = e.name; });
+        resolve_self(p);
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "42");
+    assert_eq!(forward(&mut engine, "nested"), "\"inner\"");
+    assert_eq!(forward(&mut engine, "self_error"), "\"TypeError\"");
+}
+
+#[test]
+fn resolve_returns_same_promise() {
+    let mut engine = Context::new();
+    let init = r#"
+        var p = Promise.resolve(1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "Promise.resolve(p) === p"), "true");
+    assert_eq!(forward(&mut engine, "p.then() === p"), "false");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(p.then()) === Promise.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn reject() {
+    let mut engine = Context::new();
+    let init = r#"
+        var reason;
+        Promise.reject('no').then(() => { reason = 'fulfilled'; }, r => { reason = r; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "reason"), "\"no\"");
+}
+
+#[test]
+fn finally() {
+    let mut engine = Context::new();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1)
+            .finally(() => { log.push('finally'); return 2; })
+            .then(v => log.push('value ' + v));
+        Promise.reject(3)
+            .finally(() => log.push('finally'))
+            .catch(r => log.push('reason ' + r));
+        Promise.resolve(4)
+            .finally(() => { throw 5; })
+            .catch(r => log.push('thrown ' + r));
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "log.join()"),
+        "\"finally,finally,thrown 5,value 1,reason 3\""
+    );
+}
+
+#[test]
+fn all() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        Promise.all([1, Promise.resolve(2), { then(r) { r(3); } }])
+            .then(v => { result = v.join(); });
+
+        var reason;
+        Promise.all([Promise.resolve(1), Promise.reject('bad'), 3])
+            .catch(r => { reason = r; });
+
+        var empty;
+        Promise.all([]).then(v => { empty = v.length; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"1,2,3\"");
+    assert_eq!(forward(&mut engine, "reason"), "\"bad\"");
+    assert_eq!(forward(&mut engine, "empty"), "0");
+}
+
+#[test]
+fn all_settled() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        Promise.allSettled([1, Promise.reject('bad')]).then(v => {
+            result = v.map(o => o.status + ':' + (o.status === 'fulfilled' ? o.value : o.reason)).join();
+        });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut engine, "result"),
+        "\"fulfilled:1,rejected:bad\""
+    );
+}
+
+#[test]
+fn any() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        Promise.any([Promise.reject(1), Promise.resolve(2), 3]).then(v => { result = v; });
+
+        var error;
+        Promise.any([Promise.reject(1), Promise.reject(2)]).catch(e => { error = e; });
+
+        var empty;
+        Promise.any([]).catch(e => { empty = e; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "2");
+    assert_eq!(
+        forward(&mut engine, "error instanceof AggregateError"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "error.errors.join()"), "\"1,2\"");
+    assert_eq!(forward(&mut engine, "empty.errors.length"), "0");
+}
+
+#[test]
+fn race() {
+    let mut engine = Context::new();
+    let init = r#"
+        var result;
+        var pending = new Promise(() => {});
+        Promise.race([pending, Promise.resolve('fast'), Promise.reject('slow')])
+            .then(v => { result = v; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "result"), "\"fast\"");
+}
+
+#[test]
+fn combinator_rejects_non_iterable() {
+    let mut engine = Context::new();
+    let init = r#"
+        var reason;
+        Promise.all(1).catch(e => { reason = e.name; });
+        "#;
+    forward(&mut engine, init);
+    engine.run_jobs().unwrap();
+    assert_eq!(forward(&mut engine, "reason"), "\"TypeError\"");
+}
+
+#[test]
+fn aggregate_error() {
+    let mut engine = Context::new();
+    let init = r#"
+        var e = new AggregateError([1, 2], 'message');
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "e.message"), "\"message\"");
+    assert_eq!(forward(&mut engine, "e.errors.length"), "2");
+    assert_eq!(
+        forward(&mut engine, "e.toString()"),
+        "\"AggregateError: message\""
+    );
+}
+
+#[test]
+fn then_uses_species_constructor() {
+    let mut engine = Context::new();
+    let init = r#"
+        var constructed = 0;
+        class Counted extends Promise {
+            static get [Symbol.species]() {
+                constructed++;
+                return Promise;
+            }
+        }
+        var derived = Counted.resolve(1).then(function() {});
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Promise[Symbol.species] === Promise"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "constructed"), "1");
+    assert_eq!(forward(&mut engine, "derived instanceof Counted"), "false");
+    assert_eq!(forward(&mut engine, "derived instanceof Promise"), "true");
+}
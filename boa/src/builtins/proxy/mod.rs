@@ -0,0 +1,204 @@
+//! This module implements the global `Proxy` object.
+//!
+//! A `Proxy` wraps a target object, and lets a handler object intercept the fundamental
+//! operations of the target, such as getting or setting its properties.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-proxy-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy
+
+use crate::{
+    builtins::{function::Captures, BuiltIn},
+    gc::{Finalize, Trace},
+    object::{
+        ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData, ObjectInitializer,
+        PROTOTYPE,
+    },
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The internal slots of a proxy object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Proxy {
+    /// The target and the handler of the proxy, which are cleared once it is revoked.
+    target: Option<GcObject>,
+    handler: Option<GcObject>,
+    callable: bool,
+    constructable: bool,
+}
+
+impl Proxy {
+    pub(crate) const LENGTH: usize = 2;
+
+    /// Returns the target and the handler of the proxy, or throws a `TypeError` if it was
+    /// revoked.
+    pub(crate) fn try_data(&self, context: &mut Context) -> Result<(GcObject, GcObject)> {
+        match (&self.target, &self.handler) {
+            (Some(target), Some(handler)) => Ok((target.clone(), handler.clone())),
+            _ => Err(context.construct_type_error("Proxy object has been revoked")),
+        }
+    }
+
+    /// Returns `true` if the proxy has a `[[Call]]` internal method, which is the case if its
+    /// target was callable when the proxy was created.
+    #[inline]
+    pub(crate) fn is_callable(&self) -> bool {
+        self.callable
+    }
+
+    /// Returns `true` if the proxy has a `[[Construct]]` internal method, which is the case if
+    /// its target was a constructor when the proxy was created.
+    #[inline]
+    pub(crate) fn is_constructable(&self) -> bool {
+        self.constructable
+    }
+
+    /// Abstract operation `ProxyCreate ( target, handler )`
+    ///
+    /// Validates the target and the handler, and returns the internal slots of the proxy.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxycreate
+    fn create(target: &Value, handler: &Value, context: &mut Context) -> Result<Self> {
+        let target = match target {
+            Value::Object(ref target) => target.clone(),
+            _ => return Err(context.construct_type_error("Proxy: target must be an object")),
+        };
+        let handler = match handler {
+            Value::Object(ref handler) => handler.clone(),
+            _ => return Err(context.construct_type_error("Proxy: handler must be an object")),
+        };
+
+        Ok(Self {
+            callable: target.is_callable(),
+            constructable: target.is_constructable(),
+            target: Some(target),
+            handler: Some(handler),
+        })
+    }
+
+    /// `Proxy( target, handler )`
+    ///
+    /// Creates a proxy of `target`, whose operations are intercepted by the traps of `handler`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-target-handler
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy
+    pub(crate) fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let undefined = Value::undefined();
+        let target = args.first().unwrap_or(&undefined);
+        let handler = args.get(1).unwrap_or(&undefined);
+
+        // The prototype of a proxy is never used, its internal methods forward to the target.
+        this.set_data(ObjectData::Proxy(Self::create(target, handler, context)?));
+        Ok(this.clone())
+    }
+
+    /// `Proxy.revocable( target, handler )`
+    ///
+    /// Creates a proxy that can be revoked, returning an object with the proxy and a function
+    /// revoking it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy.revocable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/revocable
+    pub(crate) fn revocable(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let undefined = Value::undefined();
+        let target = args.first().unwrap_or(&undefined);
+        let handler = args.get(1).unwrap_or(&undefined);
+
+        let proxy = GcObject::new(Object::with_prototype(
+            Value::null(),
+            ObjectData::Proxy(Self::create(target, handler, context)?),
+        ));
+        let revoke = FunctionBuilder::closure(
+            context,
+            Self::revoke,
+            Captures::new(RevokerState {
+                proxy: Some(proxy.clone()),
+            }),
+        )
+        .build();
+
+        let result = ObjectInitializer::new(context)
+            .property("proxy", proxy, Attribute::all())
+            .property("revoke", revoke, Attribute::all())
+            .build();
+        Ok(result.into())
+    }
+
+    /// A `Proxy` revocation function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-revocation-functions
+    fn revoke(_: &Value, _: &[Value], captures: &Captures, _: &mut Context) -> Result<Value> {
+        let proxy = captures
+            .downcast_mut::<RevokerState>()
+            .expect("revoke captures a revoker state")
+            .proxy
+            .take();
+        if let Some(proxy) = proxy {
+            let mut proxy = proxy.borrow_mut();
+            let proxy = proxy.as_proxy_mut().expect("revoked object is a proxy");
+            proxy.target = None;
+            proxy.handler = None;
+        }
+        Ok(Value::undefined())
+    }
+}
+
+/// The state captured by a proxy revocation function.
+#[derive(Debug, Trace, Finalize)]
+struct RevokerState {
+    proxy: Option<GcObject>,
+}
+
+impl BuiltIn for Proxy {
+    const NAME: &'static str = "Proxy";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let mut proxy_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .static_method(Self::revocable, "revocable", 2)
+            .callable(false)
+            .build();
+
+        // The `Proxy` constructor has no `prototype` property, as proxies don't inherit from it.
+        proxy_object.remove(&PROTOTYPE.into());
+
+        (Self::NAME, proxy_object.into(), Self::attribute())
+    }
+}
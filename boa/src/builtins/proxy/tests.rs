@@ -0,0 +1,291 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "new Proxy(1, {})"),
+        "Uncaught \"TypeError\": \"Proxy: target must be an object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Proxy({}, null)"),
+        "Uncaught \"TypeError\": \"Proxy: handler must be an object\""
+    );
+    assert_eq!(forward(&mut engine, "Proxy.prototype"), "undefined");
+    assert_eq!(forward(&mut engine, "Proxy.length"), "2");
+    assert_eq!(
+        forward(&mut engine, "typeof new Proxy({}, {})"),
+        "\"object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "typeof new Proxy(function() {}, {})"),
+        "\"function\""
+    );
+}
+
+#[test]
+fn forwards_to_target_without_traps() {
+    let mut engine = Context::new();
+    let init = r#"
+        let target = { a: 1 };
+        let proxy = new Proxy(target, {});
+        proxy.b = 2;
+        delete proxy.a;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "target.b"), "2");
+    assert_eq!(forward(&mut engine, "'a' in target"), "false");
+    assert_eq!(forward(&mut engine, "proxy.b"), "2");
+    assert_eq!(forward(&mut engine, "'b' in proxy"), "true");
+}
+
+#[test]
+fn get_and_set_traps() {
+    let mut engine = Context::new();
+    let init = r#"
+        let target = {};
+        let log = [];
+        let proxy = new Proxy(target, {
+            get(t, key, receiver) {
+                log.push("get " + key);
+                return receiver === proxy ? key + "!" : undefined;
+            },
+            set(t, key, value) {
+                log.push("set " + key);
+                t[key] = value * 2;
+                return true;
+            },
+        });
+        proxy.x = 21;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "proxy.foo"), "\"foo!\"");
+    assert_eq!(forward(&mut engine, "target.x"), "42");
+    assert_eq!(forward(&mut engine, "log.join()"), "\"set x,get foo\"");
+}
+
+#[test]
+fn has_and_delete_property_traps() {
+    let mut engine = Context::new();
+    let init = r#"
+        let proxy = new Proxy({ a: 1 }, {
+            has(t, key) {
+                return key.startsWith("x");
+            },
+            deleteProperty(t, key) {
+                return key !== "a";
+            },
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "'xyz' in proxy"), "true");
+    assert_eq!(forward(&mut engine, "'a' in proxy"), "false");
+    assert_eq!(forward(&mut engine, "delete proxy.a"), "false");
+    assert_eq!(forward(&mut engine, "delete proxy.b"), "true");
+}
+
+#[test]
+fn own_keys_trap() {
+    let mut engine = Context::new();
+    let init = r#"
+        let proxy = new Proxy({}, {
+            ownKeys(t) {
+                return ["b", "a"];
+            },
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(proxy).join()"),
+        "\"b,a\""
+    );
+
+    let init = r#"
+        let duplicates = new Proxy({}, { ownKeys(t) { return ["a", "a"]; } });
+        let numbers = new Proxy({}, { ownKeys(t) { return [1]; } });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(duplicates)"),
+        "Uncaught \"TypeError\": \"Proxy trap 'ownKeys' returned duplicate keys\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(numbers)"),
+        "Uncaught \"TypeError\": \"Proxy trap 'ownKeys' returned a key that is neither a string nor a symbol\""
+    );
+}
+
+#[test]
+fn apply_and_construct_traps() {
+    let mut engine = Context::new();
+    let init = r#"
+        function Point(x) { this.x = x; }
+        let callable = new Proxy(Point, {
+            apply(t, thisArg, args) {
+                return args.length;
+            },
+            construct(t, args, newTarget) {
+                return { x: args[0] + 1, isProxy: newTarget === callable };
+            },
+        });
+        let point = new callable(1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "callable(1, 2, 3)"), "3");
+    assert_eq!(forward(&mut engine, "point.x"), "2");
+    assert_eq!(forward(&mut engine, "point.isProxy"), "true");
+    assert_eq!(forward(&mut engine, "new (new Proxy(Point, {}))(5).x"), "5");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new (new Proxy(Point, { construct() { return 1; } }))()"
+        ),
+        "Uncaught \"TypeError\": \"Proxy trap 'construct' returned a non-object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Proxy({}, {})()"),
+        "Uncaught \"TypeError\": \"not a function\""
+    );
+}
+
+#[test]
+fn get_prototype_of_trap() {
+    let mut engine = Context::new();
+    let init = r#"
+        function Foo() {}
+        let proxy = new Proxy({}, {
+            getPrototypeOf(t) {
+                return Foo.prototype;
+            },
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "proxy instanceof Foo"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.getPrototypeOf(proxy) === Foo.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn define_property_trap() {
+    let mut engine = Context::new();
+    let init = r#"
+        let defined = [];
+        let proxy = new Proxy({}, {
+            defineProperty(t, key, desc) {
+                defined.push(key);
+                return Reflect.defineProperty(t, key, desc);
+            },
+        });
+        Object.defineProperty(proxy, "a", { value: 1 });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "defined.join()"), "\"a\"");
+    assert_eq!(forward(&mut engine, "proxy.a"), "1");
+}
+
+#[test]
+fn invariants() {
+    let mut engine = Context::new();
+    let init = r#"
+        let target = {};
+        Object.defineProperty(target, "fixed", { value: 1 });
+        let proxy = new Proxy(target, {
+            get(t, key) { return 2; },
+            has(t, key) { return false; },
+            deleteProperty(t, key) { return true; },
+            getOwnPropertyDescriptor(t, key) { return undefined; },
+            defineProperty(t, key, desc) { return true; },
+        });
+        "#;
+    forward(&mut engine, init);
+    let error = |name: &str| {
+        format!(
+            "Uncaught \"TypeError\": \"Proxy trap '{}' returned a result incompatible with the target\"",
+            name
+        )
+    };
+    assert_eq!(forward(&mut engine, "proxy.fixed"), error("get"));
+    assert_eq!(forward(&mut engine, "proxy.other"), "2");
+    assert_eq!(forward(&mut engine, "'fixed' in proxy"), error("has"));
+    assert_eq!(
+        forward(&mut engine, "delete proxy.fixed"),
+        error("deleteProperty")
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.getOwnPropertyDescriptor(proxy, 'fixed')"
+        ),
+        error("getOwnPropertyDescriptor")
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(proxy, 'fixed', { value: 3 })"
+        ),
+        error("defineProperty")
+    );
+
+    let init = r#"
+        let frozen = {};
+        Reflect.preventExtensions(frozen);
+        let lying = new Proxy(frozen, {
+            isExtensible(t) { return true; },
+            getPrototypeOf(t) { return Array.prototype; },
+            ownKeys(t) { return ["extra"]; },
+        });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Reflect.isExtensible(lying)"),
+        error("isExtensible")
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.getPrototypeOf(lying)"),
+        error("getPrototypeOf")
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(lying)"),
+        error("ownKeys")
+    );
+}
+
+#[test]
+fn trap_must_be_a_function() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "new Proxy({}, { get: 1 }).a"),
+        "Uncaught \"TypeError\": \"Proxy trap 'get' is not a function\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Proxy({ a: 1 }, { get: null }).a"),
+        "1"
+    );
+}
+
+#[test]
+fn revocable() {
+    let mut engine = Context::new();
+    let init = r#"
+        let { proxy, revoke } = Proxy.revocable({ a: 1 }, {});
+        let before = proxy.a;
+        revoke();
+        revoke();
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "before"), "1");
+    assert_eq!(
+        forward(&mut engine, "proxy.a"),
+        "Uncaught \"TypeError\": \"Proxy object has been revoked\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'a' in proxy"),
+        "Uncaught \"TypeError\": \"Proxy object has been revoked\""
+    );
+    assert_eq!(forward(&mut engine, "typeof proxy"), "\"object\"");
+}
@@ -0,0 +1,382 @@
+//! This module implements the global `Reflect` object.
+//!
+//! The `Reflect` object provides the internal methods of objects as functions, with the same
+//! names as the traps of proxy handlers.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-reflect-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect
+
+use crate::{
+    builtins::{Array, BuiltIn, BuiltInObjectObject},
+    object::{GcObject, ObjectInitializer},
+    property::{Attribute, PropertyKey},
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// JavaScript `Reflect` global object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Reflect;
+
+impl BuiltIn for Reflect {
+    const NAME: &'static str = "Reflect";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+
+        let reflect_object = ObjectInitializer::new(context)
+            .function(Self::apply, "apply", 3)
+            .function(Self::construct, "construct", 2)
+            .function(Self::define_property, "defineProperty", 3)
+            .function(Self::delete_property, "deleteProperty", 2)
+            .function(Self::get, "get", 2)
+            .function(
+                Self::get_own_property_descriptor,
+                "getOwnPropertyDescriptor",
+                2,
+            )
+            .function(Self::get_prototype_of, "getPrototypeOf", 1)
+            .function(Self::has, "has", 2)
+            .function(Self::is_extensible, "isExtensible", 1)
+            .function(Self::own_keys, "ownKeys", 1)
+            .function(Self::prevent_extensions, "preventExtensions", 1)
+            .function(Self::set, "set", 3)
+            .function(Self::set_prototype_of, "setPrototypeOf", 2)
+            .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+            .build();
+
+        (Self::NAME, reflect_object.into(), Self::attribute())
+    }
+}
+
+impl Reflect {
+    /// Returns the target argument of a `Reflect` function, throwing a `TypeError` if it is
+    /// not an object.
+    fn target(args: &[Value], name: &str, context: &mut Context) -> Result<GcObject> {
+        match args.first() {
+            Some(Value::Object(ref target)) => Ok(target.clone()),
+            _ => {
+                Err(context
+                    .construct_type_error(format!("Reflect.{}: target must be an object", name)))
+            }
+        }
+    }
+
+    /// Returns the property key argument of a `Reflect` function.
+    fn key(args: &[Value], context: &mut Context) -> Result<PropertyKey> {
+        args.get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(context)
+    }
+
+    /// `Reflect.apply( target, thisArgument, argumentsList )`
+    ///
+    /// Calls the target function with the given `this` value and arguments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.apply
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/apply
+    pub(crate) fn apply(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = match args.first() {
+            Some(Value::Object(ref target)) if target.is_callable() => target.clone(),
+            _ => return context.throw_type_error("Reflect.apply: target must be a function"),
+        };
+        let this_argument = args.get(1).cloned().unwrap_or_default();
+        let arguments_list = args
+            .get(2)
+            .cloned()
+            .unwrap_or_default()
+            .create_list_from_array_like(context)?;
+
+        target.call(&this_argument, &arguments_list, context)
+    }
+
+    /// `Reflect.construct( target, argumentsList [ , newTarget ] )`
+    ///
+    /// Calls the target constructor with the given arguments, as with the `new` operator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.construct
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/construct
+    pub(crate) fn construct(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = match args.first() {
+            Some(Value::Object(ref target)) if target.is_constructable() => target.clone(),
+            _ => {
+                return context.throw_type_error("Reflect.construct: target must be a constructor")
+            }
+        };
+        let new_target = match args.get(2) {
+            Some(Value::Object(ref new_target)) if new_target.is_constructable() => {
+                new_target.clone().into()
+            }
+            Some(_) => {
+                return context
+                    .throw_type_error("Reflect.construct: new target must be a constructor")
+            }
+            None => target.clone().into(),
+        };
+        let arguments_list = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .create_list_from_array_like(context)?;
+
+        target.construct(&arguments_list, &new_target, context)
+    }
+
+    /// `Reflect.defineProperty( target, propertyKey, attributes )`
+    ///
+    /// Defines a property on the target, returning `true` if it could be defined.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.defineproperty
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/defineProperty
+    pub(crate) fn define_property(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let mut target = Self::target(args, "defineProperty", context)?;
+        let key = Self::key(args, context)?;
+        let desc = match args.get(2) {
+            Some(Value::Object(ref attributes)) => attributes.to_property_descriptor(context)?,
+            _ => return context.throw_type_error("Property description must be an object"),
+        };
+
+        target
+            .define_own_property(key, desc, context)
+            .map(Value::from)
+    }
+
+    /// `Reflect.deleteProperty( target, propertyKey )`
+    ///
+    /// Deletes a property of the target, returning `true` if it was deleted.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.deleteproperty
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/deleteProperty
+    pub(crate) fn delete_property(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let mut target = Self::target(args, "deleteProperty", context)?;
+        let key = Self::key(args, context)?;
+
+        target.delete(&key, context).map(Value::from)
+    }
+
+    /// `Reflect.get( target, propertyKey [ , receiver ] )`
+    ///
+    /// Gets the value of a property of the target, calling its getter with `receiver` as the
+    /// `this` value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/get
+    pub(crate) fn get(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "get", context)?;
+        let key = Self::key(args, context)?;
+        let receiver = args
+            .get(2)
+            .cloned()
+            .unwrap_or_else(|| target.clone().into());
+
+        target.get(&key, receiver, context)
+    }
+
+    /// `Reflect.getOwnPropertyDescriptor( target, propertyKey )`
+    ///
+    /// Returns the descriptor of an own property of the target, or undefined if it doesn't
+    /// have it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.getownpropertydescriptor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getOwnPropertyDescriptor
+    pub(crate) fn get_own_property_descriptor(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let target = Self::target(args, "getOwnPropertyDescriptor", context)?;
+        let key = Self::key(args, context)?;
+
+        match target.get_own_property(&key, context)? {
+            Some(desc) => BuiltInObjectObject::from_property_descriptor(desc, context),
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// `Reflect.getPrototypeOf( target )`
+    ///
+    /// Returns the prototype of the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.getprototypeof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getPrototypeOf
+    pub(crate) fn get_prototype_of(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let target = Self::target(args, "getPrototypeOf", context)?;
+        target.get_prototype_of(context)
+    }
+
+    /// `Reflect.has( target, propertyKey )`
+    ///
+    /// Returns `true` if the target has the property, as its own or in its prototype chain.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/has
+    pub(crate) fn has(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "has", context)?;
+        let key = Self::key(args, context)?;
+
+        target.has_property(&key, context).map(Value::from)
+    }
+
+    /// `Reflect.isExtensible( target )`
+    ///
+    /// Returns `true` if properties can be added to the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.isextensible
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/isExtensible
+    pub(crate) fn is_extensible(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "isExtensible", context)?;
+        target.is_extensible(context).map(Value::from)
+    }
+
+    /// `Reflect.ownKeys( target )`
+    ///
+    /// Returns an array of the own property keys of the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.ownkeys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/ownKeys
+    pub(crate) fn own_keys(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::target(args, "ownKeys", context)?;
+        let keys: Vec<Value> = target
+            .own_property_keys(context)?
+            .iter()
+            .map(Value::from)
+            .collect();
+
+        Array::construct_array(&Array::new_array(context)?, &keys, context)
+    }
+
+    /// `Reflect.preventExtensions( target )`
+    ///
+    /// Prevents new properties from being added to the target, returning `true` if it worked.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.preventextensions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/preventExtensions
+    pub(crate) fn prevent_extensions(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let mut target = Self::target(args, "preventExtensions", context)?;
+        target.prevent_extensions(context).map(Value::from)
+    }
+
+    /// `Reflect.set( target, propertyKey, V [ , receiver ] )`
+    ///
+    /// Sets the value of a property of the target, calling its setter with `receiver` as the
+    /// `this` value, and returns `true` if it could be set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/set
+    pub(crate) fn set(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let mut target = Self::target(args, "set", context)?;
+        let key = Self::key(args, context)?;
+        let value = args.get(2).cloned().unwrap_or_default();
+        let receiver = args
+            .get(3)
+            .cloned()
+            .unwrap_or_else(|| target.clone().into());
+
+        target.set(key, value, receiver, context).map(Value::from)
+    }
+
+    /// `Reflect.setPrototypeOf( target, proto )`
+    ///
+    /// Sets the prototype of the target, returning `true` if it could be changed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.setprototypeof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/setPrototypeOf
+    pub(crate) fn set_prototype_of(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let mut target = Self::target(args, "setPrototypeOf", context)?;
+        let proto = match args.get(1) {
+            Some(proto @ Value::Object(_)) | Some(proto @ Value::Null) => proto.clone(),
+            _ => {
+                return context.throw_type_error(
+                    "Reflect.setPrototypeOf: prototype must be an object or null",
+                )
+            }
+        };
+
+        target.set_prototype_of(proto, context).map(Value::from)
+    }
+}
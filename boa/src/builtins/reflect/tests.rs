@@ -0,0 +1,172 @@
+use crate::{forward, Context};
+
+#[test]
+fn apply() {
+    let mut engine = Context::new();
+    let init = r#"
+        function add(a, b) { return this.base + a + b; }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "Reflect.apply(add, { base: 1 }, [2, 3])"),
+        "6"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.apply(1, undefined, [])"),
+        "Uncaught \"TypeError\": \"Reflect.apply: target must be a function\""
+    );
+}
+
+#[test]
+fn construct() {
+    let mut engine = Context::new();
+    let init = r#"
+        function Point(x, y) { this.x = x; this.y = y; }
+        function Other() {}
+        let point = Reflect.construct(Point, [1, 2]);
+        let other = Reflect.construct(Point, [3], Other);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "point.x + point.y"), "3");
+    assert_eq!(forward(&mut engine, "point instanceof Point"), "true");
+    assert_eq!(forward(&mut engine, "other.x"), "3");
+    assert_eq!(forward(&mut engine, "other instanceof Other"), "true");
+    assert_eq!(
+        forward(&mut engine, "Reflect.construct(Point, [], 1)"),
+        "Uncaught \"TypeError\": \"Reflect.construct: new target must be a constructor\""
+    );
+}
+
+#[test]
+fn define_and_delete_property() {
+    let mut engine = Context::new();
+    let init = r#"
+        let obj = {};
+        let defined = Reflect.defineProperty(obj, "a", { value: 1 });
+        let redefined = Reflect.defineProperty(obj, "a", { value: 2 });
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "defined"), "true");
+    assert_eq!(forward(&mut engine, "redefined"), "false");
+    assert_eq!(forward(&mut engine, "obj.a"), "1");
+    assert_eq!(
+        forward(&mut engine, "Reflect.deleteProperty(obj, 'a')"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(obj, 'b', { value: 1, configurable: true })"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.deleteProperty(obj, 'b')"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "obj.b"), "undefined");
+}
+
+#[test]
+fn get_and_set() {
+    let mut engine = Context::new();
+    let init = r#"
+        let obj = {
+            value: 1,
+            get double() { return this.value * 2; },
+            set double(v) { this.value = v / 2; },
+        };
+        let receiver = { value: 10 };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "Reflect.get(obj, 'double')"), "2");
+    assert_eq!(
+        forward(&mut engine, "Reflect.get(obj, 'double', receiver)"),
+        "20"
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.set(obj, 'double', 8)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "obj.value"), "4");
+    assert_eq!(
+        forward(&mut engine, "Reflect.set(obj, 'double', 30, receiver)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "receiver.value"), "15");
+    assert_eq!(
+        forward(&mut engine, "Reflect.get(1, 'a')"),
+        "Uncaught \"TypeError\": \"Reflect.get: target must be an object\""
+    );
+}
+
+#[test]
+fn get_own_property_descriptor() {
+    let mut engine = Context::new();
+    forward(
+        &mut engine,
+        "let desc = Reflect.getOwnPropertyDescriptor({ a: 1 }, 'a');",
+    );
+    assert_eq!(forward(&mut engine, "desc.value"), "1");
+    assert_eq!(forward(&mut engine, "desc.writable"), "true");
+    assert_eq!(
+        forward(&mut engine, "Reflect.getOwnPropertyDescriptor({}, 'a')"),
+        "undefined"
+    );
+}
+
+#[test]
+fn has_and_own_keys() {
+    let mut engine = Context::new();
+    forward(
+        &mut engine,
+        "let obj = Object.create({ inherited: 1 }); obj.own = 2;",
+    );
+    assert_eq!(
+        forward(&mut engine, "Reflect.has(obj, 'inherited')"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.has(obj, 'missing')"), "false");
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(obj).join()"),
+        "\"own\""
+    );
+}
+
+#[test]
+fn prototypes_and_extensibility() {
+    let mut engine = Context::new();
+    let init = r#"
+        let proto = {};
+        let obj = {};
+        let set = Reflect.setPrototypeOf(obj, proto);
+        let cycle = Reflect.setPrototypeOf(proto, obj);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "set"), "true");
+    assert_eq!(forward(&mut engine, "cycle"), "false");
+    assert_eq!(
+        forward(&mut engine, "Reflect.getPrototypeOf(obj) === proto"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(obj)"), "true");
+    assert_eq!(
+        forward(&mut engine, "Reflect.preventExtensions(obj)"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.isExtensible(obj)"), "false");
+    assert_eq!(
+        forward(&mut engine, "Reflect.setPrototypeOf(obj, null)"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "Reflect.set(obj, 'a', 1)"), "false");
+}
+
+#[test]
+fn to_string_tag() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(Reflect)"),
+        "\"[object Reflect]\""
+    );
+}
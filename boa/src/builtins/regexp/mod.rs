@@ -10,20 +10,56 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{string::get_substitution, Array, BuiltIn},
     gc::{empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, ObjectData},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
     property::{Attribute, DataDescriptor},
     value::{RcString, Value},
     BoaProfiler, Context, Result,
 };
-use regress::{Flags, Regex};
+use regress::{Flags, Match, Regex};
+use std::ops::Range;
 
 #[cfg(test)]
 mod tests;
 
+// The matcher works on the UTF-8 version of the strings, whose byte offsets are converted to
+// the UTF-16 code unit indices used by JavaScript. As the lone surrogates of a string are
+// replaced by `U+FFFD` in its UTF-8 version, both versions have the same code units count.
+
+/// Converts a byte offset in the UTF-8 version of `string` to a code unit index.
+fn code_unit_index(string: &RcString, byte_index: usize) -> usize {
+    string[..byte_index].encode_utf16().count()
+}
+
+/// Converts a code unit index to a byte offset in the UTF-8 version of `string`, or `None` if
+/// it is past the end of the string. An index in the middle of a surrogate pair is moved to the
+/// end of the pair.
+fn byte_index(string: &RcString, code_unit_index: usize) -> Option<usize> {
+    let mut code_units = 0;
+    for (byte_index, character) in string.char_indices() {
+        if code_units >= code_unit_index {
+            return Some(byte_index);
+        }
+        code_units += character.len_utf16();
+    }
+    if code_units >= code_unit_index {
+        Some(string.len())
+    } else {
+        None
+    }
+}
+
+/// Returns the substring of `string` for a byte range of its UTF-8 version.
+fn substring(string: &RcString, range: Range<usize>) -> Value {
+    let start = code_unit_index(string, range.start);
+    let end = start + string[range].encode_utf16().count();
+    Value::from(RcString::from(&string.code_units()[start..end]))
+}
+
 /// The internal representation on a `RegExp` object.
 #[derive(Debug, Clone, Finalize)]
+#[allow(dead_code)]
 pub struct RegExp {
     /// Regex matcher.
     matcher: Regex,
@@ -70,6 +106,16 @@ impl BuiltIn for RegExp {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        let symbol_replace = context.well_known_symbols().replace_symbol();
+        let symbol_search = context.well_known_symbols().search_symbol();
+        let symbol_split = context.well_known_symbols().split_symbol();
+
+        let species_symbol = context.well_known_symbols().species_symbol();
+        let get_species = FunctionBuilder::new(context, Self::species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+
         let regexp_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -81,6 +127,15 @@ impl BuiltIn for RegExp {
         .method(Self::test, "test", 1)
         .method(Self::exec, "exec", 1)
         .method(Self::to_string, "toString", 0)
+        .method(Self::replace, (symbol_replace, "[Symbol.replace]"), 2)
+        .method(Self::search, (symbol_search, "[Symbol.search]"), 1)
+        .method(Self::split, (symbol_split, "[Symbol.split]"), 2)
+        .static_accessor(
+            species_symbol,
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
         .build();
 
         // TODO: add them RegExp accessor properties
@@ -96,9 +151,52 @@ impl RegExp {
     /// The amount of arguments this function object takes.
     pub(crate) const LENGTH: usize = 2;
 
+    /// `get RegExp [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@species
+    pub(crate) fn species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// The sorted flags of this regular expression.
+    pub(crate) fn flags(&self) -> &str {
+        &self.flags
+    }
+
+    /// Returns a copy of the matcher and the flags of the `RegExp` object in `this`.
+    fn this_matcher(this: &Value, method: &str, ctx: &mut Context) -> Result<(Regex, String)> {
+        if let Some(object) = this.as_object() {
+            if let Some(regex) = object.borrow().as_regexp() {
+                return Ok((regex.matcher.clone(), regex.flags.clone()));
+            }
+        }
+        Err(ctx.construct_type_error(format!(
+            "Method RegExp.prototype{} called on incompatible receiver",
+            method
+        )))
+    }
+
+    /// Finds the first match of `matcher` in `string` at or after the code unit `last_index`.
+    fn find_from(matcher: &Regex, string: &RcString, last_index: usize) -> Option<Match> {
+        let start = byte_index(string, last_index)?;
+        matcher.find_from(string.as_str(), start).next()
+    }
+
     /// Create a new `RegExp`
-    pub(crate) fn constructor(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let arg = args.get(0).ok_or_else(Value::undefined)?;
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        // Calling `RegExp` as a function creates a new object, like `new RegExp` does.
+        let this = if this.is_object() && !this.is_global() {
+            this.clone()
+        } else {
+            let prototype = ctx.standard_objects().regexp_object().prototype();
+            Value::from(Object::create(prototype.into()))
+        };
+        let arg = args.first().ok_or_else(Value::undefined)?;
         let mut regex_body = String::new();
         let mut regex_flags = String::new();
         match arg {
@@ -304,30 +402,29 @@ impl RegExp {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/test
     pub(crate) fn test(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let arg_str = args
-            .get(0)
+            .first()
             .expect("could not get argument")
             .to_string(ctx)?;
-        let mut last_index = this.get_field("lastIndex").to_index(ctx)?;
+        let mut last_index = this.get_field("lastIndex", ctx)?.to_index(ctx)?;
         let result = if let Some(object) = this.as_object() {
             let object = object.borrow();
             let regex = object.as_regexp().unwrap();
-            let result =
-                if let Some(m) = regex.matcher.find_from(arg_str.as_str(), last_index).next() {
-                    if regex.use_last_index {
-                        last_index = m.total().end;
-                    }
-                    true
-                } else {
-                    if regex.use_last_index {
-                        last_index = 0;
-                    }
-                    false
-                };
+            let result = if let Some(m) = Self::find_from(&regex.matcher, &arg_str, last_index) {
+                if regex.use_last_index {
+                    last_index = code_unit_index(&arg_str, m.total().end);
+                }
+                true
+            } else {
+                if regex.use_last_index {
+                    last_index = 0;
+                }
+                false
+            };
             Ok(Value::boolean(result))
         } else {
             panic!("object is not a regexp")
         };
-        this.set_field("lastIndex", Value::from(last_index));
+        this.set_field("lastIndex", Value::from(last_index), ctx)?;
         result
     }
 
@@ -345,25 +442,23 @@ impl RegExp {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/exec
     pub(crate) fn exec(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let arg_str = args
-            .get(0)
+            .first()
             .expect("could not get argument")
             .to_string(ctx)?;
-        let mut last_index = this.get_field("lastIndex").to_index(ctx)?;
+        let mut last_index = this.get_field("lastIndex", ctx)?.to_index(ctx)?;
         let result = if let Some(object) = this.as_object() {
             let object = object.borrow();
             let regex = object.as_regexp().unwrap();
             let result = {
-                if let Some(m) = regex.matcher.find_from(arg_str.as_str(), last_index).next() {
+                if let Some(m) = Self::find_from(&regex.matcher, &arg_str, last_index) {
                     if regex.use_last_index {
-                        last_index = m.total().end;
+                        last_index = code_unit_index(&arg_str, m.total().end);
                     }
                     let groups = m.captures.len() + 1;
                     let mut result = Vec::with_capacity(groups);
                     for i in 0..groups {
                         if let Some(range) = m.group(i) {
-                            result.push(Value::from(
-                                arg_str.get(range).expect("Could not get slice"),
-                            ));
+                            result.push(substring(&arg_str, range));
                         } else {
                             result.push(Value::undefined());
                         }
@@ -372,7 +467,10 @@ impl RegExp {
                     let result = Value::from(result);
                     result.set_property(
                         "index",
-                        DataDescriptor::new(m.total().start, Attribute::all()),
+                        DataDescriptor::new(
+                            code_unit_index(&arg_str, m.total().start),
+                            Attribute::all(),
+                        ),
                     );
                     result.set_property("input", DataDescriptor::new(arg_str, Attribute::all()));
                     result
@@ -387,7 +485,7 @@ impl RegExp {
         } else {
             panic!("object is not a regexp")
         };
-        this.set_field("lastIndex", Value::from(last_index));
+        this.set_field("lastIndex", Value::from(last_index), ctx)?;
         result
     }
 
@@ -412,7 +510,7 @@ impl RegExp {
         if flags.contains('g') {
             let mut matches = Vec::new();
             for mat in matcher.find_iter(&arg) {
-                matches.push(Value::from(&arg[mat.total()]));
+                matches.push(substring(&arg, mat.total()));
             }
             if matches.is_empty() {
                 return Ok(Value::null());
@@ -448,6 +546,186 @@ impl RegExp {
         Ok(Value::from(format!("/{}/{}", body, flags)))
     }
 
+    /// `RegExp.prototype[ @@replace ]( string, replaceValue )`
+    ///
+    /// This method replaces the first match, or every match if the `g` flag is set, of the
+    /// regular expression in `string` with `replaceValue`.
+    ///
+    /// `replaceValue` can be a string with substitution patterns or a function called for each match.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@replace
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@replace
+    pub(crate) fn replace(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (matcher, flags) = Self::this_matcher(this, "[Symbol.replace]", ctx)?;
+        let arg_str = args.first().cloned().unwrap_or_default().to_string(ctx)?;
+
+        let replace_value = args.get(1).cloned().unwrap_or_default();
+        let replace_string = if replace_value.is_function() {
+            None
+        } else {
+            Some(replace_value.to_string(ctx)?)
+        };
+
+        let matches: Vec<_> = if flags.contains('g') {
+            this.set_field("lastIndex", 0, ctx)?;
+            matcher.find_iter(&arg_str).collect()
+        } else {
+            matcher.find(&arg_str).into_iter().collect()
+        };
+
+        let code_units = arg_str.code_units();
+        let mut result = Vec::new();
+        let mut next_position = 0;
+        for mat in matches {
+            let position = code_unit_index(&arg_str, mat.total().start);
+            let end = code_unit_index(&arg_str, mat.total().end);
+            let matched = &code_units[position..end];
+            let captures: Vec<Value> = mat
+                .groups()
+                .skip(1)
+                .map(|group| match group {
+                    Some(range) => substring(&arg_str, range),
+                    None => Value::undefined(),
+                })
+                .collect();
+
+            let replacement = if let Some(ref replace_string) = replace_string {
+                get_substitution(
+                    matched,
+                    code_units,
+                    position,
+                    &captures,
+                    replace_string.code_units(),
+                )
+            } else {
+                let mut replacer_args = Vec::with_capacity(captures.len() + 3);
+                replacer_args.push(Value::from(RcString::from(matched)));
+                replacer_args.extend(captures);
+                replacer_args.push(Value::from(position));
+                replacer_args.push(Value::from(arg_str.clone()));
+                ctx.call(&replace_value, &Value::undefined(), &replacer_args)?
+                    .to_string(ctx)?
+                    .code_units()
+                    .to_vec()
+            };
+
+            if position >= next_position {
+                result.extend_from_slice(&code_units[next_position..position]);
+                result.extend_from_slice(&replacement);
+                next_position = end;
+            }
+        }
+        result.extend_from_slice(&code_units[next_position..]);
+
+        Ok(Value::from(RcString::from(result)))
+    }
+
+    /// `RegExp.prototype[ @@search ]( string )`
+    ///
+    /// This method returns the index of the first match of the regular expression in `string`,
+    /// or `-1` if there is no match.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@search
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@search
+    pub(crate) fn search(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (matcher, _) = Self::this_matcher(this, "[Symbol.search]", ctx)?;
+        let arg_str = args.first().cloned().unwrap_or_default().to_string(ctx)?;
+
+        match matcher.find(&arg_str) {
+            Some(mat) => Ok(Value::from(code_unit_index(&arg_str, mat.total().start))),
+            None => Ok(Value::from(-1)),
+        }
+    }
+
+    /// `RegExp.prototype[ @@split ]( string, limit )`
+    ///
+    /// This method splits `string` into an array of substrings separated by the matches of the
+    /// regular expression. Capture groups of the matches are spliced into the array.
+    ///
+    /// The matches are those of a splitter created by the species constructor of the regular
+    /// expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@split
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@split
+    pub(crate) fn split(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (matcher, flags) = Self::this_matcher(this, "[Symbol.split]", ctx)?;
+        let rx = this.as_object().expect("checked by this_matcher");
+        let default_constructor = ctx.standard_objects().regexp_object().constructor();
+        let constructor = rx.species_constructor(default_constructor.clone().into(), ctx)?;
+        let matcher = match constructor {
+            Value::Object(ref constructor)
+                if !GcObject::equals(constructor, &default_constructor) =>
+            {
+                let splitter = constructor.construct(
+                    &[this.clone(), flags.into()],
+                    &constructor.clone().into(),
+                    ctx,
+                )?;
+                Self::this_matcher(&splitter, "[Symbol.split]", ctx)?.0
+            }
+            _ => matcher,
+        };
+        let arg_str = args.first().cloned().unwrap_or_default().to_string(ctx)?;
+        let limit = match args.get(1) {
+            None | Some(Value::Undefined) => u32::MAX,
+            Some(limit) => limit.to_u32(ctx)?,
+        } as usize;
+
+        let mut parts = Vec::new();
+        if limit == 0 {
+            return Array::construct_array(&Array::new_array(ctx)?, &parts, ctx);
+        }
+
+        if arg_str.is_empty() {
+            if matcher.find(&arg_str).is_none() {
+                parts.push(Value::from(arg_str));
+            }
+            return Array::construct_array(&Array::new_array(ctx)?, &parts, ctx);
+        }
+
+        let mut last_end = 0;
+        for mat in matcher.find_iter(&arg_str) {
+            let (start, end) = (mat.total().start, mat.total().end);
+            // Empty matches at the end of the previous match or at the end of the string do
+            // not split the string.
+            if start >= arg_str.len() || end == last_end {
+                continue;
+            }
+
+            parts.push(substring(&arg_str, last_end..start));
+            if parts.len() == limit {
+                return Array::construct_array(&Array::new_array(ctx)?, &parts, ctx);
+            }
+
+            for group in mat.groups().skip(1) {
+                parts.push(match group {
+                    Some(range) => substring(&arg_str, range),
+                    None => Value::undefined(),
+                });
+                if parts.len() == limit {
+                    return Array::construct_array(&Array::new_array(ctx)?, &parts, ctx);
+                }
+            }
+
+            last_end = end;
+        }
+        parts.push(substring(&arg_str, last_end..arg_str.len()));
+
+        Array::construct_array(&Array::new_array(ctx)?, &parts, ctx)
+    }
+
     /// `RegExp.prototype[ @@matchAll ]( string )`
     ///
     /// The `[@@matchAll]` method returns all matches of the regular expression against a string.
@@ -459,7 +737,7 @@ impl RegExp {
     /// [spec]: https://tc39.es/ecma262/#sec-regexp-prototype-matchall
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@matchAll
     // TODO: it's returning an array, it should return an iterator
-    pub(crate) fn match_all(this: &Value, arg_str: String) -> Result<Value> {
+    pub(crate) fn match_all(this: &Value, arg_str: RcString) -> Result<Value> {
         let matches = if let Some(object) = this.as_object() {
             let object = object.borrow();
             let regex = object.as_regexp().unwrap();
@@ -469,7 +747,7 @@ impl RegExp {
                 let match_vec: Vec<Value> = mat
                     .groups()
                     .map(|group| match group {
-                        Some(range) => Value::from(&arg_str[range]),
+                        Some(range) => substring(&arg_str, range),
                         None => Value::undefined(),
                     })
                     .collect();
@@ -478,7 +756,10 @@ impl RegExp {
 
                 match_val.set_property(
                     "index",
-                    DataDescriptor::new(mat.total().start, Attribute::all()),
+                    DataDescriptor::new(
+                        code_unit_index(&arg_str, mat.total().start),
+                        Attribute::all(),
+                    ),
                 );
                 match_val.set_property(
                     "input",
@@ -498,7 +779,13 @@ impl RegExp {
 
         let length = matches.len();
         let result = Value::from(matches);
-        result.set_field("length", Value::from(length));
+        result.set_property(
+            "length",
+            DataDescriptor::new(
+                length,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
         result.set_data(ObjectData::Array);
 
         Ok(result)
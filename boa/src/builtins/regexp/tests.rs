@@ -98,3 +98,32 @@ fn to_string() {
     );
     assert_eq!(forward(&mut engine, "/\\n/g.toString()"), "\"/\\n/g\"");
 }
+
+#[test]
+fn split_uses_species_constructor() {
+    let mut engine = Context::new();
+    let init = r#"
+        var constructed = 0;
+        var re = /-/;
+        re.constructor = function() {};
+        re.constructor[Symbol.species] = function(pattern, flags) {
+            constructed++;
+            return new RegExp(",", flags);
+        };
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(
+        forward(&mut engine, "RegExp[Symbol.species] === RegExp"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "re[Symbol.split]('a,b-c').join('|')"),
+        "\"a|b-c\""
+    );
+    assert_eq!(forward(&mut engine, "constructed"), "1");
+    assert_eq!(
+        forward(&mut engine, "/-/[Symbol.split]('a,b-c').join('|')"),
+        "\"a,b|c\""
+    );
+}
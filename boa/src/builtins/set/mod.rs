@@ -0,0 +1,351 @@
+#![allow(clippy::mutable_key_type)]
+
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn},
+    object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor},
+    BoaProfiler, Context, Result, Value,
+};
+use ordered_set::OrderedSet;
+
+pub mod set_iterator;
+use set_iterator::{SetIterationKind, SetIterator};
+
+pub mod ordered_set;
+#[cfg(test)]
+mod tests;
+pub mod weak_set;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Set;
+
+impl BuiltIn for Set {
+    const NAME: &'static str = "Set";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let iterator_symbol = context.well_known_symbols().iterator_symbol();
+
+        let values_function = FunctionBuilder::new(context, Self::values)
+            .name("values")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        let species_symbol = context.well_known_symbols().species_symbol();
+        let get_species = FunctionBuilder::new(context, Self::species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .build();
+
+        let set_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .property(
+                "values",
+                values_function.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                "keys",
+                values_function.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                iterator_symbol,
+                values_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::add, "add", 1)
+            .method(Self::clear, "clear", 0)
+            .method(Self::delete, "delete", 1)
+            .method(Self::entries, "entries", 0)
+            .method(Self::for_each, "forEach", 1)
+            .method(Self::has, "has", 1)
+            .static_accessor(
+                species_symbol,
+                Some(get_species),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .callable(false)
+            .build();
+
+        (Self::NAME, set_object.into(), Self::attribute())
+    }
+}
+
+impl Set {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `get Set [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-set-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/@@species
+    pub(crate) fn species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// Create a new set
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        // Set Prototype
+        let prototype = ctx
+            .global_object()
+            .clone()
+            .get_field("Set", ctx)?
+            .get_field(PROTOTYPE, ctx)?;
+
+        this.as_object()
+            .expect("this is set object")
+            .set_prototype_instance(prototype);
+
+        this.set_data(ObjectData::Set(OrderedSet::new()));
+        Self::set_size(this, 0);
+
+        let iterable = args.first().cloned().unwrap_or_else(Value::undefined);
+        add_entries_from_iterable(this, iterable, ctx)?;
+
+        Ok(this.clone())
+    }
+
+    /// Helper function to set the size property.
+    fn set_size(this: &Value, size: usize) {
+        let size = DataDescriptor::new(
+            size,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+
+        this.set_property("size".to_string(), size);
+    }
+
+    /// `Set.prototype.add( value )`
+    ///
+    /// This method appends the value to the set, if it is not already in it. Returns the set
+    /// object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/add
+    pub(crate) fn add(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = args.first().cloned().unwrap_or_else(Value::undefined);
+
+        let size = if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(set) = object.as_set_mut() {
+                set.insert(value);
+                set.len()
+            } else {
+                return Err(ctx.construct_type_error("'this' is not a Set"));
+            }
+        } else {
+            return Err(ctx.construct_type_error("'this' is not a Set"));
+        };
+
+        Self::set_size(this, size);
+        Ok(this.clone())
+    }
+
+    /// `Set.prototype.clear( )`
+    ///
+    /// This method removes all values from the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.clear
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/clear
+    pub(crate) fn clear(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        if let Value::Object(ref object) = this {
+            if let Some(set) = object.borrow_mut().as_set_mut() {
+                set.clear();
+            } else {
+                return Err(ctx.construct_type_error("'this' is not a Set"));
+            }
+        } else {
+            return Err(ctx.construct_type_error("'this' is not a Set"));
+        }
+
+        Self::set_size(this, 0);
+        Ok(Value::undefined())
+    }
+
+    /// `Set.prototype.delete( value )`
+    ///
+    /// This method removes the value from the set, if it exists. Returns true if there was
+    /// such a value, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let undefined = Value::undefined();
+        let value = args.first().unwrap_or(&undefined);
+
+        let (deleted, size) = if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(set) = object.as_set_mut() {
+                let deleted = set.remove(value);
+                (deleted, set.len())
+            } else {
+                return Err(ctx.construct_type_error("'this' is not a Set"));
+            }
+        } else {
+            return Err(ctx.construct_type_error("'this' is not a Set"));
+        };
+
+        Self::set_size(this, size);
+        Ok(deleted.into())
+    }
+
+    /// `Set.prototype.entries()`
+    ///
+    /// Returns a new Iterator object that contains a [value, value] pair for each value in
+    /// the set, in insertion order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/entries
+    pub(crate) fn entries(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        if !this.as_object().is_some_and(|object| object.is_set()) {
+            return ctx.throw_type_error("'this' is not a Set");
+        }
+        SetIterator::create_set_iterator(ctx, this.clone(), SetIterationKind::KeyAndValue)
+    }
+
+    /// `Set.prototype.forEach( callbackFn [ , thisArg ] )`
+    ///
+    /// This method executes the provided callback function for each value in the set. Values
+    /// added during the iteration are visited too.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/forEach
+    pub(crate) fn for_each(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let object = match this {
+            Value::Object(ref object) if object.is_set() => object,
+            _ => return ctx.throw_type_error("'this' is not a Set"),
+        };
+
+        let callback = args.first().cloned().unwrap_or_else(Value::undefined);
+        if !callback.is_function() {
+            return ctx.throw_type_error("Set.prototype.forEach: callback is not a function");
+        }
+        let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
+
+        let mut index = 0;
+        loop {
+            let value = object
+                .borrow()
+                .as_set_ref()
+                .and_then(|set| set.get_index(index).cloned());
+            let value = match value {
+                Some(value) => value,
+                None => break,
+            };
+            ctx.call(&callback, &this_arg, &[value.clone(), value, this.clone()])?;
+            index += 1;
+        }
+
+        Ok(Value::undefined())
+    }
+
+    /// `Set.prototype.has( value )`
+    ///
+    /// This method checks if the set contains the given value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/has
+    pub(crate) fn has(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let undefined = Value::undefined();
+        let value = args.first().unwrap_or(&undefined);
+
+        if let Value::Object(ref object) = this {
+            if let Some(set) = object.borrow().as_set_ref() {
+                return Ok(set.contains(value).into());
+            }
+        }
+
+        Err(ctx.construct_type_error("'this' is not a Set"))
+    }
+
+    /// `Set.prototype.values()`
+    ///
+    /// Returns a new Iterator object that contains the values of the set, in insertion order.
+    /// It is also `Set.prototype.keys` and `Set.prototype[@@iterator]`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values
+    pub(crate) fn values(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        if !this.as_object().is_some_and(|object| object.is_set()) {
+            return ctx.throw_type_error("'this' is not a Set");
+        }
+        SetIterator::create_set_iterator(ctx, this.clone(), SetIterationKind::Value)
+    }
+}
+
+/// Adds the values of `iterable` to the new collection `target`, by calling its `add` method.
+///
+/// This is shared by the constructors of `Set` and `WeakSet`, and nothing is added when
+/// `iterable` is `undefined` or `null`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set-iterable
+pub(crate) fn add_entries_from_iterable(
+    target: &Value,
+    iterable: Value,
+    ctx: &mut Context,
+) -> Result<()> {
+    if iterable.is_null_or_undefined() {
+        return Ok(());
+    }
+
+    let adder = target.get_field("add", ctx)?;
+    if !adder.is_function() {
+        return Err(ctx.construct_type_error("'add' is not a function"));
+    }
+
+    let iterator = get_iterator(ctx, iterable)?;
+    loop {
+        let next = iterator.next(ctx)?;
+        if next.is_done() {
+            return Ok(());
+        }
+        let status = ctx.call(&adder, target, &[next.value()]);
+        if status.is_err() {
+            iterator.close(status, ctx)?;
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, Array, Value},
+    object::ObjectData,
+    property::{Attribute, DataDescriptor},
+    BoaProfiler, Context, Result,
+};
+use gc::{Finalize, Trace};
+
+#[derive(Debug, Clone, Finalize, Trace)]
+pub enum SetIterationKind {
+    Value,
+    KeyAndValue,
+}
+
+/// The Set Iterator object represents an iteration over a set. It implements the iterator protocol.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set-iterator-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct SetIterator {
+    iterated_set: Value,
+    set_next_index: usize,
+    set_iteration_kind: SetIterationKind,
+}
+
+impl SetIterator {
+    pub(crate) const NAME: &'static str = "SetIterator";
+
+    fn new(set: Value, kind: SetIterationKind) -> Self {
+        SetIterator {
+            iterated_set: set,
+            set_next_index: 0,
+            set_iteration_kind: kind,
+        }
+    }
+
+    /// Abstract operation CreateSetIterator( set, kind )
+    ///
+    /// Creates a new iterator over the given set.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createsetiterator
+    pub(crate) fn create_set_iterator(
+        ctx: &Context,
+        set: Value,
+        kind: SetIterationKind,
+    ) -> Result<Value> {
+        let set_iterator = Value::new_object(Some(ctx.global_object()));
+        set_iterator.set_data(ObjectData::SetIterator(Self::new(set, kind)));
+        set_iterator
+            .as_object()
+            .expect("set iterator object")
+            .set_prototype_instance(ctx.iterator_prototypes().set_iterator().into());
+        Ok(set_iterator)
+    }
+
+    /// %SetIteratorPrototype%.next( )
+    ///
+    /// Advances the iterator and gets the next result in the set.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%setiteratorprototype%.next
+    pub(crate) fn next(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let (value, kind) = if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(set_iterator) = object.as_set_iterator_mut() {
+                let value = match set_iterator.iterated_set {
+                    Value::Object(ref set) => {
+                        let index = set_iterator.set_next_index;
+                        let value = set
+                            .borrow()
+                            .as_set_ref()
+                            .map(|set| set.get_index(index).cloned());
+                        match value {
+                            Some(value) => value,
+                            None => return ctx.throw_type_error("'this' is not a Set"),
+                        }
+                    }
+                    _ => None,
+                };
+                if value.is_some() {
+                    set_iterator.set_next_index += 1;
+                } else {
+                    set_iterator.iterated_set = Value::undefined();
+                }
+                (value, set_iterator.set_iteration_kind.clone())
+            } else {
+                return ctx.throw_type_error("`this` is not a SetIterator");
+            }
+        } else {
+            return ctx.throw_type_error("`this` is not a SetIterator");
+        };
+
+        match (value, kind) {
+            (None, _) => Ok(create_iter_result_object(ctx, Value::undefined(), true)),
+            (Some(value), SetIterationKind::Value) => {
+                Ok(create_iter_result_object(ctx, value, false))
+            }
+            (Some(value), SetIterationKind::KeyAndValue) => {
+                let result =
+                    Array::construct_array(&Array::new_array(ctx)?, &[value.clone(), value], ctx)?;
+                Ok(create_iter_result_object(ctx, result, false))
+            }
+        }
+    }
+
+    /// Create the %SetIteratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%setiteratorprototype%-object
+    pub(crate) fn create_prototype(ctx: &mut Context, iterator_prototype: Value) -> Value {
+        let global = ctx.global_object();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let set_iterator = Value::new_object(Some(global));
+        make_builtin_fn(Self::next, "next", &set_iterator, 0, ctx);
+        set_iterator
+            .as_object()
+            .expect("set iterator prototype object")
+            .set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = ctx.well_known_symbols().to_string_tag_symbol();
+        let to_string_tag_property = DataDescriptor::new("Set Iterator", Attribute::CONFIGURABLE);
+        set_iterator.set_property(to_string_tag, to_string_tag_property);
+        set_iterator
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct_empty() {
+    let mut engine = Context::new();
+    forward(&mut engine, "var empty = new Set();");
+    assert_eq!(forward(&mut engine, "empty.size"), "0");
+}
+
+#[test]
+fn construct_from_array_removes_duplicates() {
+    let mut engine = Context::new();
+    let init = r#"
+        let set = new Set([1, "1", 1, 2, 0.5 * 2, NaN, 0 / 0, 0, -0]);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "set.size"), "5");
+    assert_eq!(
+        forward(&mut engine, "[...set].map(String).join()"),
+        "\"1,1,2,NaN,0\""
+    );
+}
+
+#[test]
+fn construct_from_iterable() {
+    let mut engine = Context::new();
+    let init = r#"
+        function* gen() { yield "a"; yield "b"; yield "a"; }
+        let fromGenerator = new Set(gen());
+        let fromString = new Set("hello");
+        let fromSet = new Set(fromGenerator);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "fromGenerator.size"), "2");
+    assert_eq!(forward(&mut engine, "fromString.size"), "4");
+    assert_eq!(forward(&mut engine, "fromSet.size"), "2");
+    assert_eq!(
+        forward(&mut engine, "new Set(1)"),
+        "Uncaught \"TypeError\": \"Not an iterable\""
+    );
+}
+
+#[test]
+fn construct_closes_iterator_on_error() {
+    let mut engine = Context::new();
+    let init = r#"
+        let closed = false;
+        let iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                next: function () { return { value: 1, done: false }; },
+                return: function () { closed = true; return {}; },
+            };
+        };
+        let originalAdd = Set.prototype.add;
+        Set.prototype.add = function () { throw "add"; };
+        try { new Set(iterable); } catch (e) {}
+        Set.prototype.add = originalAdd;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "closed"), "true");
+}
+
+#[test]
+fn add_has_delete_clear() {
+    let mut engine = Context::new();
+    let init = r#"
+        let object = {};
+        let set = new Set();
+        let returned = set.add(object).add("a").add("a");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "returned === set"), "true");
+    assert_eq!(forward(&mut engine, "set.size"), "2");
+    assert_eq!(forward(&mut engine, "set.has(object)"), "true");
+    assert_eq!(forward(&mut engine, "set.has({})"), "false");
+    assert_eq!(forward(&mut engine, "set.delete(object)"), "true");
+    assert_eq!(forward(&mut engine, "set.delete(object)"), "false");
+    assert_eq!(forward(&mut engine, "set.size"), "1");
+    assert_eq!(forward(&mut engine, "set.clear()"), "undefined");
+    assert_eq!(forward(&mut engine, "set.size"), "0");
+    assert_eq!(forward(&mut engine, "set.has('a')"), "false");
+}
+
+#[test]
+fn iterators() {
+    let mut engine = Context::new();
+    let init = r#"
+        let set = new Set(["b", "a", "c"]);
+        set.delete("a");
+        set.add("a");
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "[...set].join()"), "\"b,c,a\"");
+    assert_eq!(
+        forward(&mut engine, "[...set.values()].join()"),
+        "\"b,c,a\""
+    );
+    assert_eq!(forward(&mut engine, "[...set.keys()].join()"), "\"b,c,a\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "[...set.entries()].map(e => e.join(':')).join()"
+        ),
+        "\"b:b,c:c,a:a\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Set.prototype.keys === Set.prototype.values"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Set.prototype[Symbol.iterator] === Set.prototype.values"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "set.values()[Symbol.toStringTag]"),
+        "\"Set Iterator\""
+    );
+}
+
+#[test]
+fn iterator_sees_added_values() {
+    let mut engine = Context::new();
+    let init = r#"
+        let set = new Set([1]);
+        let seen = [];
+        for (let value of set) {
+            seen.push(value);
+            if (value < 3) {
+                set.add(value + 1);
+            }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "seen.join()"), "\"1,2,3\"");
+}
+
+#[test]
+fn for_each() {
+    let mut engine = Context::new();
+    let init = r#"
+        let set = new Set([1, 2]);
+        let calls = [];
+        let thisArg = {};
+        set.forEach(function (value, key, target) {
+            calls.push(value + ":" + key + ":" + (target === set) + ":" + (this === thisArg));
+            if (value === 1) {
+                set.add(3);
+            }
+        }, thisArg);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "calls.join()"),
+        "\"1:1:true:true,2:2:true:true,3:3:true:true\""
+    );
+}
+
+#[test]
+fn not_a_set() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "Set.prototype.add.call({}, 1)"),
+        "Uncaught \"TypeError\": \"'this' is not a Set\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Set.prototype.values.call(new Map())"),
+        "Uncaught \"TypeError\": \"'this' is not a Set\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Set()"),
+        forward(&mut engine, "Map()").replace("Map", "Set")
+    );
+}
+
+#[test]
+fn weak_set() {
+    let mut engine = Context::new();
+    let init = r#"
+        let a = {};
+        let b = {};
+        let set = new WeakSet([a]);
+        let returned = set.add(b);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "returned === set"), "true");
+    assert_eq!(forward(&mut engine, "set.has(a)"), "true");
+    assert_eq!(forward(&mut engine, "set.has(b)"), "true");
+    assert_eq!(forward(&mut engine, "set.has({})"), "false");
+    assert_eq!(forward(&mut engine, "set.has(1)"), "false");
+    assert_eq!(forward(&mut engine, "set.delete(a)"), "true");
+    assert_eq!(forward(&mut engine, "set.delete(a)"), "false");
+    assert_eq!(forward(&mut engine, "set.has(a)"), "false");
+    assert_eq!(forward(&mut engine, "set.delete(1)"), "false");
+    assert_eq!(forward(&mut engine, "set.size"), "undefined");
+    assert_eq!(
+        forward(&mut engine, "set.add(1)"),
+        "Uncaught \"TypeError\": \"WeakSet.prototype.add: value is not an object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new WeakSet([1])"),
+        "Uncaught \"TypeError\": \"WeakSet.prototype.add: value is not an object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "WeakSet.prototype.has.call(new Set(), a)"),
+        "Uncaught \"TypeError\": \"'this' is not a WeakSet\""
+    );
+}
+
+#[test]
+fn species() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "Set[Symbol.species] === Set"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(Set, Symbol.species).get.call(1) === 1"
+        ),
+        "true"
+    );
+}
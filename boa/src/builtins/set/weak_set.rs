@@ -0,0 +1,141 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! A `WeakSet` is a collection of objects, which doesn't keep them alive: once an object is
+//! garbage collected, it is removed from the set.
+
+use super::add_entries_from_iterable;
+use crate::{
+    builtins::{map::ephemeron_table::EphemeronTable, BuiltIn},
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakSet;
+
+impl BuiltIn for WeakSet {
+    const NAME: &'static str = "WeakSet";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_set_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .method(Self::add, "add", 1)
+            .method(Self::delete, "delete", 1)
+            .method(Self::has, "has", 1)
+            .callable(false)
+            .build();
+
+        (Self::NAME, weak_set_object.into(), Self::attribute())
+    }
+}
+
+impl WeakSet {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Create a new weak set
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let prototype = ctx
+            .global_object()
+            .clone()
+            .get_field("WeakSet", ctx)?
+            .get_field(PROTOTYPE, ctx)?;
+
+        this.as_object()
+            .expect("this is weak set object")
+            .set_prototype_instance(prototype);
+
+        this.set_data(ObjectData::WeakSet(EphemeronTable::new()));
+
+        let iterable = args.first().cloned().unwrap_or_else(Value::undefined);
+        add_entries_from_iterable(this, iterable, ctx)?;
+
+        Ok(this.clone())
+    }
+
+    /// `WeakSet.prototype.add( value )`
+    ///
+    /// This method adds the object to the weak set. Returns the weak set object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+    pub(crate) fn add(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let is_weak_set = this
+            .as_object()
+            .is_some_and(|object| object.borrow().as_weak_set_ref().is_some());
+        if !is_weak_set {
+            return ctx.throw_type_error("'this' is not a WeakSet");
+        }
+
+        let value = match args.first().and_then(Value::as_object) {
+            Some(value) => value,
+            None => return ctx.throw_type_error("WeakSet.prototype.add: value is not an object"),
+        };
+
+        if let Some(set) = this
+            .as_object()
+            .expect("this is weak set object")
+            .borrow_mut()
+            .as_weak_set_mut()
+        {
+            set.insert(&value, ());
+        }
+        Ok(this.clone())
+    }
+
+    /// `WeakSet.prototype.delete( value )`
+    ///
+    /// This method removes the object from the weak set. Returns true if the object was in
+    /// the weak set, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = args.first().and_then(Value::as_object);
+
+        if let Value::Object(ref object) = this {
+            if let Some(set) = object.borrow_mut().as_weak_set_mut() {
+                return Ok(value.is_some_and(|value| set.remove(&value)).into());
+            }
+        }
+
+        Err(ctx.construct_type_error("'this' is not a WeakSet"))
+    }
+
+    /// `WeakSet.prototype.has( value )`
+    ///
+    /// This method checks if the weak set contains the given object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+    pub(crate) fn has(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let value = args.first().and_then(Value::as_object);
+
+        if let Value::Object(ref object) = this {
+            if let Some(set) = object.borrow().as_weak_set_ref() {
+                return Ok(value.is_some_and(|value| set.contains_key(&value)).into());
+            }
+        }
+
+        Err(ctx.construct_type_error("'this' is not a WeakSet"))
+    }
+}
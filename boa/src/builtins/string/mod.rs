@@ -14,47 +14,167 @@ pub mod string_iterator;
 mod tests;
 
 use crate::{
-    builtins::{string::string_iterator::StringIterator, BuiltIn, RegExp},
+    builtins::{string::string_iterator::StringIterator, Array, BuiltIn, RegExp},
     object::{ConstructorBuilder, Object, ObjectData},
-    property::Attribute,
+    property::{Attribute, DataDescriptor},
     value::{RcString, Value},
     BoaProfiler, Context, Result,
 };
-use regress::Regex;
 use std::{
     char::decode_utf16,
     cmp::{max, min},
-    f64::NAN,
     string::String as StdString,
 };
-
-pub(crate) fn code_point_at(string: RcString, position: i32) -> Option<(u32, u8, bool)> {
-    let size = string.encode_utf16().count() as i32;
-    if position < 0 || position >= size {
-        return None;
-    }
-    let mut encoded = string.encode_utf16();
-    let first = encoded.nth(position as usize)?;
+use unicode_normalization::UnicodeNormalization;
+
+/// Abstract operation `CodePointAt( string, position )`.
+///
+/// Returns the code point starting at the code unit `position`, the number of code units it
+/// spans and whether it is an unpaired surrogate, or `None` if `position` is out of range.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-codepointat
+pub(crate) fn code_point_at(code_units: &[u16], position: usize) -> Option<(u32, u8, bool)> {
+    let first = *code_units.get(position)?;
     if !is_leading_surrogate(first) && !is_trailing_surrogate(first) {
-        return Some((first as u32, 1, false));
+        return Some((u32::from(first), 1, false));
     }
-    if is_trailing_surrogate(first) || position + 1 == size {
-        return Some((first as u32, 1, true));
+    if is_trailing_surrogate(first) {
+        return Some((u32::from(first), 1, true));
     }
-    let second = encoded.next()?;
-    if !is_trailing_surrogate(second) {
-        return Some((first as u32, 1, true));
+    match code_units.get(position + 1) {
+        Some(&second) if is_trailing_surrogate(second) => {
+            let cp = (u32::from(first) - 0xD800) * 0x400 + (u32::from(second) - 0xDC00) + 0x10000;
+            Some((cp, 2, false))
+        }
+        _ => Some((u32::from(first), 1, true)),
     }
-    let cp = (first as u32 - 0xD800) * 0x400 + (second as u32 - 0xDC00) + 0x10000;
-    Some((cp, 2, false))
 }
 
 fn is_leading_surrogate(value: u16) -> bool {
-    value >= 0xD800 && value <= 0xDBFF
+    (0xD800..=0xDBFF).contains(&value)
 }
 
 fn is_trailing_surrogate(value: u16) -> bool {
-    value >= 0xDC00 && value <= 0xDFFF
+    (0xDC00..=0xDFFF).contains(&value)
+}
+
+/// Abstract operation `StringIndexOf( string, searchValue, fromIndex )`.
+///
+/// Returns the index of the first occurrence of `search` in `string` at or after `from`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-stringindexof
+pub(crate) fn string_index_of(string: &[u16], search: &[u16], from: usize) -> Option<usize> {
+    if search.is_empty() {
+        return if from <= string.len() {
+            Some(from)
+        } else {
+            None
+        };
+    }
+    let last_start = string.len().checked_sub(search.len())?;
+    (from..=last_start).find(|&index| string[index..].starts_with(search))
+}
+
+/// Abstract operation `GetSubstitution`.
+///
+/// Expands the `$` patterns of `replacement` for a match of `matched` found at the code unit
+/// `position` of `string`. `captures` holds the values of the capture groups, `undefined` for
+/// groups that did not participate in the match.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
+pub(crate) fn get_substitution(
+    matched: &[u16],
+    string: &[u16],
+    position: usize,
+    captures: &[Value],
+    replacement: &[u16],
+) -> Vec<u16> {
+    let capture = |n: usize| match captures[n - 1] {
+        Value::String(ref capture) => capture.code_units(),
+        _ => &[],
+    };
+    let digit = |code_unit: Option<&u16>| {
+        code_unit
+            .and_then(|&code_unit| char::from_u32(u32::from(code_unit)))
+            .and_then(|ch| ch.to_digit(10))
+            .map(|digit| digit as usize)
+    };
+
+    let m = captures.len();
+    let mut result = Vec::with_capacity(replacement.len());
+    let mut index = 0;
+
+    while index < replacement.len() {
+        let first = replacement[index];
+        if first != u16::from(b'$') || index + 1 == replacement.len() {
+            result.push(first);
+            index += 1;
+            continue;
+        }
+
+        let second = replacement[index + 1];
+        match char::from_u32(u32::from(second)) {
+            Some('$') => {
+                // $$
+                result.push(first);
+                index += 2;
+            }
+            Some('&') => {
+                // $&
+                result.extend_from_slice(matched);
+                index += 2;
+            }
+            Some('`') => {
+                // $`
+                result.extend_from_slice(&string[..position]);
+                index += 2;
+            }
+            Some('\'') => {
+                // $'
+                let end_of_match = min(position + matched.len(), string.len());
+                result.extend_from_slice(&string[end_of_match..]);
+                index += 2;
+            }
+            _ => match (digit(Some(&second)), digit(replacement.get(index + 2))) {
+                (Some(tens), Some(units)) => {
+                    // $nn
+                    let nn = 10 * tens + units;
+                    if nn == 0 || nn > m {
+                        result.extend_from_slice(&replacement[index..index + 3]);
+                    } else {
+                        result.extend_from_slice(capture(nn));
+                    }
+                    index += 3;
+                }
+                (Some(n), None) => {
+                    // $n
+                    if n == 0 || n > m {
+                        result.extend_from_slice(&replacement[index..index + 2]);
+                    } else {
+                        result.extend_from_slice(capture(n));
+                    }
+                    index += 2;
+                }
+                _ => {
+                    // $?, ? is none of the above. Named groups are not supported, so `$<` is
+                    // kept as is too.
+                    result.extend_from_slice(&replacement[index..index + 2]);
+                    index += 2;
+                }
+            },
+        }
+    }
+
+    result
 }
 
 /// JavaScript `String` implementation.
@@ -84,6 +204,8 @@ impl BuiltIn for String {
         .property("length", 0, attribute)
         .method(Self::char_at, "charAt", 1)
         .method(Self::char_code_at, "charCodeAt", 1)
+        .method(Self::code_point_at, "codePointAt", 1)
+        .method(Self::at, "at", 1)
         .method(Self::to_string, "toString", 0)
         .method(Self::concat, "concat", 1)
         .method(Self::repeat, "repeat", 1)
@@ -94,6 +216,8 @@ impl BuiltIn for String {
         .method(Self::index_of, "indexOf", 1)
         .method(Self::last_index_of, "lastIndexOf", 1)
         .method(Self::r#match, "match", 1)
+        .method(Self::split, "split", 2)
+        .method(Self::search, "search", 1)
         .method(Self::pad_end, "padEnd", 1)
         .method(Self::pad_start, "padStart", 1)
         .method(Self::trim, "trim", 0)
@@ -101,12 +225,20 @@ impl BuiltIn for String {
         .method(Self::trim_end, "trimEnd", 0)
         .method(Self::to_lowercase, "toLowerCase", 0)
         .method(Self::to_uppercase, "toUpperCase", 0)
+        .method(Self::to_locale_lowercase, "toLocaleLowerCase", 0)
+        .method(Self::to_locale_uppercase, "toLocaleUpperCase", 0)
+        .method(Self::locale_compare, "localeCompare", 1)
+        .method(Self::normalize, "normalize", 0)
         .method(Self::substring, "substring", 2)
         .method(Self::substr, "substr", 2)
         .method(Self::value_of, "valueOf", 0)
         .method(Self::match_all, "matchAll", 1)
         .method(Self::replace, "replace", 2)
+        .method(Self::replace_all, "replaceAll", 2)
         .method(Self::iterator, (symbol_iterator, "[Symbol.iterator]"), 0)
+        .static_method(Self::raw, "raw", 1)
+        .static_method(Self::from_char_code, "fromCharCode", 1)
+        .static_method(Self::from_code_point, "fromCodePoint", 1)
         .build();
 
         (Self::NAME, string_object.into(), Self::attribute())
@@ -130,14 +262,17 @@ impl String {
     pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         // This value is used by console.log and other routines to match Obexpecty"failed to parse argument for String method"pe
         // to its Javascript Identifier (global constructor method name)
-        let string = match args.get(0) {
-            Some(ref value) => value.to_string(ctx)?,
+        let string = match args.first() {
+            Some(value) => value.to_string(ctx)?,
             None => RcString::default(),
         };
 
-        let length = string.encode_utf16().count();
+        let length = string.code_units().len();
 
-        this.set_field("length", Value::from(length as i32));
+        this.set_property(
+            "length",
+            DataDescriptor::new(length, Attribute::NON_ENUMERABLE | Attribute::PERMANENT),
+        );
 
         this.set_data(ObjectData::String(string.clone()));
 
@@ -184,32 +319,15 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.charat
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/charAt
     pub(crate) fn char_at(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the engine has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(ctx)?;
-        let pos = args
-            .get(0)
-            .cloned()
-            .unwrap_or_else(Value::undefined)
-            .to_integer(ctx)? as i32;
-
-        // Calling .len() on a string would give the wrong result, as they are bytes not the number of
-        // unicode code points
-        // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of
-        // bytes is an O(1) operation.
-        let length = primitive_val.chars().count();
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
+        let position = args.first().cloned().unwrap_or_default().to_integer(ctx)?;
 
         // We should return an empty string is pos is out of range
-        if pos >= length as i32 || pos < 0 {
-            return Ok("".into());
+        match Self::code_unit_at(&string, position) {
+            Some(code_unit) => Ok(Value::from(RcString::from_utf16(&[code_unit]))),
+            None => Ok(Value::from("")),
         }
-
-        Ok(Value::from(
-            primitive_val
-                .chars()
-                .nth(pos as usize)
-                .expect("failed to get value"),
-        ))
     }
 
     /// `String.prototype.charCodeAt( index )`
@@ -227,29 +345,81 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.charcodeat
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/charCodeAt
     pub(crate) fn char_code_at(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the engine has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(ctx)?;
-
-        // Calling .len() on a string would give the wrong result, as they are bytes not the number of unicode code points
-        // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of bytes is an O(1) operation.
-        let length = primitive_val.chars().count();
-        let pos = args
-            .get(0)
-            .cloned()
-            .unwrap_or_else(Value::undefined)
-            .to_integer(ctx)? as i32;
-
-        if pos >= length as i32 || pos < 0 {
-            return Ok(Value::from(NAN));
-        }
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
+        let position = args.first().cloned().unwrap_or_default().to_integer(ctx)?;
 
-        let utf16_val = primitive_val
-            .encode_utf16()
-            .nth(pos as usize)
-            .expect("failed to get utf16 value");
         // If there is no element at that index, the result is NaN
-        Ok(Value::from(f64::from(utf16_val)))
+        match Self::code_unit_at(&string, position) {
+            Some(code_unit) => Ok(Value::from(i32::from(code_unit))),
+            None => Ok(Value::from(f64::NAN)),
+        }
+    }
+
+    /// Returns the code unit of `string` at `position`, or `None` if it is out of range.
+    fn code_unit_at(string: &RcString, position: f64) -> Option<u16> {
+        if position < 0.0 {
+            return None;
+        }
+        string.code_units().get(position as usize).copied()
+    }
+
+    /// `String.prototype.codePointAt( pos )`
+    ///
+    /// The `codePointAt()` method returns a non-negative integer that is the Unicode code point value
+    /// starting at the given UTF-16 code unit index.
+    ///
+    /// If the code unit at the index is a lone surrogate, the surrogate itself is returned, and if the
+    /// index is out of range `undefined` is returned.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.codepointat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/codePointAt
+    pub(crate) fn code_point_at(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
+        let position = args.first().cloned().unwrap_or_default().to_integer(ctx)?;
+
+        if position < 0.0 {
+            return Ok(Value::undefined());
+        }
+
+        match code_point_at(string.code_units(), position as usize) {
+            Some((code_point, _, _)) => Ok(Value::from(code_point)),
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// `String.prototype.at( index )`
+    ///
+    /// The `at()` method returns a new string consisting of the single UTF-16 code unit located at the
+    /// specified offset. Negative integers count back from the last character of the string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-relative-indexing-method/#sec-string.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/at
+    pub(crate) fn at(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
+        let length = string.code_units().len() as f64;
+
+        let relative_index = args.first().cloned().unwrap_or_default().to_integer(ctx)?;
+        let k = if relative_index >= 0.0 {
+            relative_index
+        } else {
+            length + relative_index
+        };
+
+        match Self::code_unit_at(&string, k) {
+            Some(code_unit) => Ok(Value::from(RcString::from_utf16(&[code_unit]))),
+            None => Ok(Value::undefined()),
+        }
     }
 
     /// `String.prototype.concat( str1[, ...strN] )`
@@ -268,13 +438,103 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/concat
     pub(crate) fn concat(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         let object = this.require_object_coercible(ctx)?;
-        let mut string = object.to_string(ctx)?.to_string();
+        let mut code_units = object.to_string(ctx)?.code_units().to_vec();
 
         for arg in args {
-            string.push_str(&arg.to_string(ctx)?);
+            code_units.extend_from_slice(arg.to_string(ctx)?.code_units());
         }
 
-        Ok(Value::from(string))
+        Ok(Value::from(RcString::from(code_units)))
+    }
+
+    /// `String.raw( template, ...substitutions )`
+    ///
+    /// The `String.raw()` static method is the tag function of template literals, returning the
+    /// raw strings of the template with the substitutions interleaved.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.raw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw
+    pub(crate) fn raw(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let substitutions = args.get(1..).unwrap_or(&[]);
+
+        let cooked = args.first().cloned().unwrap_or_default().to_object(ctx)?;
+        let raw = Value::from(cooked).get_field("raw", ctx)?.to_object(ctx)?;
+        let raw = Value::from(raw);
+        let literal_segments = raw.get_field("length", ctx)?.to_length(ctx)?;
+
+        let mut code_units = Vec::new();
+        for index in 0..literal_segments {
+            code_units.extend_from_slice(raw.get_field(index, ctx)?.to_string(ctx)?.code_units());
+            if index + 1 == literal_segments {
+                break;
+            }
+            if let Some(substitution) = substitutions.get(index) {
+                code_units.extend_from_slice(substitution.to_string(ctx)?.code_units());
+            }
+        }
+
+        Ok(Value::from(RcString::from(code_units)))
+    }
+
+    /// `String.fromCharCode( ...codeUnits )`
+    ///
+    /// The `String.fromCharCode()` static method returns a string created from the specified sequence
+    /// of UTF-16 code units.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.fromcharcode
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCharCode
+    pub(crate) fn from_char_code(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let mut code_units = Vec::with_capacity(args.len());
+        for arg in args {
+            code_units.push(arg.to_u32(ctx)? as u16);
+        }
+
+        Ok(Value::from(RcString::from(code_units)))
+    }
+
+    /// `String.fromCodePoint( ...codePoints )`
+    ///
+    /// The `String.fromCodePoint()` static method returns a string created from the specified sequence
+    /// of code points.
+    ///
+    /// Throws a `RangeError` if a value is not an integer between `0` and `0x10FFFF`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.fromcodepoint
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint
+    pub(crate) fn from_code_point(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let mut code_units = Vec::with_capacity(args.len());
+        for arg in args {
+            let next_code_point = arg.to_number(ctx)?;
+            if next_code_point.fract() != 0.0 || !(0.0..=1_114_111.0).contains(&next_code_point) {
+                return ctx.throw_range_error(format!(
+                    "invalid code point: {}",
+                    Value::from(next_code_point).display()
+                ));
+            }
+
+            let code_point = next_code_point as u32;
+            if code_point <= 0xFFFF {
+                code_units.push(code_point as u16);
+            } else {
+                let code_point = code_point - 0x10000;
+                code_units.push((code_point / 0x400 + 0xD800) as u16);
+                code_units.push((code_point % 0x400 + 0xDC00) as u16);
+            }
+        }
+
+        Ok(Value::from(RcString::from(code_units)))
     }
 
     /// `String.prototype.repeat( count )`
@@ -292,7 +552,7 @@ impl String {
         let object = this.require_object_coercible(ctx)?;
         let string = object.to_string(ctx)?;
 
-        if let Some(arg) = args.get(0) {
+        if let Some(arg) = args.first() {
             let n = arg.to_integer(ctx)?;
             if n < 0.0 {
                 return ctx.throw_range_error("repeat count cannot be a negative number");
@@ -302,11 +562,13 @@ impl String {
                 return ctx.throw_range_error("repeat count cannot be infinity");
             }
 
-            if n * (string.len() as f64) > Self::MAX_STRING_LENGTH {
+            if n * (string.code_units().len() as f64) > Self::MAX_STRING_LENGTH {
                 return ctx
                     .throw_range_error("repeat count must not overflow maximum string length");
             }
-            Ok(string.repeat(n as usize).into())
+            Ok(Value::from(RcString::from(
+                string.code_units().repeat(n as usize),
+            )))
         } else {
             Ok("".into())
         }
@@ -323,44 +585,31 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.slice
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/slice
     pub(crate) fn slice(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the engine has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(ctx)?;
-
-        // Calling .len() on a string would give the wrong result, as they are bytes not the number of unicode code points
-        // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of bytes is an O(1) operation.
-        let length = primitive_val.chars().count() as i32;
-
-        let start = args
-            .get(0)
-            .cloned()
-            .unwrap_or_else(Value::undefined)
-            .to_integer(ctx)? as i32;
-        let end = args
-            .get(1)
-            .cloned()
-            .unwrap_or_else(|| Value::integer(length))
-            .to_integer(ctx)? as i32;
-
-        let from = if start < 0 {
-            max(length.wrapping_add(start), 0)
-        } else {
-            min(start, length)
-        };
-        let to = if end < 0 {
-            max(length.wrapping_add(end), 0)
-        } else {
-            min(end, length)
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
+        let length = string.code_units().len() as f64;
+
+        let start = args.first().cloned().unwrap_or_default().to_integer(ctx)?;
+        let end = match args.get(1) {
+            None | Some(Value::Undefined) => length,
+            Some(end) => end.to_integer(ctx)?,
         };
 
-        let span = max(to.wrapping_sub(from), 0);
+        let from = if start < 0.0 {
+            (length + start).max(0.0)
+        } else {
+            start.min(length)
+        } as usize;
+        let to = if end < 0.0 {
+            (length + end).max(0.0)
+        } else {
+            end.min(length)
+        } as usize;
 
-        let new_str: StdString = primitive_val
-            .chars()
-            .skip(from as usize)
-            .take(span as usize)
-            .collect();
-        Ok(Value::from(new_str))
+        if from >= to {
+            return Ok(Value::from(""));
+        }
+        Ok(Value::from(RcString::from(&string.code_units()[from..to])))
     }
 
     /// `String.prototype.startWith( searchString[, position] )`
@@ -374,40 +623,26 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.startswith
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/startsWith
     pub(crate) fn starts_with(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the engine has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(ctx)?;
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
 
-        let arg = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let arg = args.first().cloned().unwrap_or_default();
 
-        if Self::is_regexp_object(&arg) {
-            ctx.throw_type_error(
+        if Self::is_regexp(&arg, ctx)? {
+            return ctx.throw_type_error(
                 "First argument to String.prototype.startsWith must not be a regular expression",
-            )?;
+            );
         }
 
         let search_string = arg.to_string(ctx)?;
 
-        let length = primitive_val.chars().count() as i32;
-        let search_length = search_string.chars().count() as i32;
-
-        // If less than 2 args specified, position is 'undefined', defaults to 0
-        let position = if args.len() < 2 {
-            0
-        } else {
-            args.get(1).expect("failed to get arg").to_integer(ctx)? as i32
-        };
-
-        let start = min(max(position, 0), length);
-        let end = start.wrapping_add(search_length);
+        let length = string.code_units().len() as f64;
+        let position = args.get(1).cloned().unwrap_or_default().to_integer(ctx)?;
+        let start = position.max(0.0).min(length) as usize;
 
-        if end > length {
-            Ok(Value::from(false))
-        } else {
-            // Only use the part of the string from "start"
-            let this_string: StdString = primitive_val.chars().skip(start as usize).collect();
-            Ok(Value::from(this_string.starts_with(search_string.as_str())))
-        }
+        Ok(Value::from(
+            string.code_units()[start..].starts_with(search_string.code_units()),
+        ))
     }
 
     /// `String.prototype.endsWith( searchString[, length] )`
@@ -421,43 +656,30 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.endswith
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/endsWith
     pub(crate) fn ends_with(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the engine has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(ctx)?;
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
 
-        let arg = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let arg = args.first().cloned().unwrap_or_default();
 
-        if Self::is_regexp_object(&arg) {
-            ctx.throw_type_error(
+        if Self::is_regexp(&arg, ctx)? {
+            return ctx.throw_type_error(
                 "First argument to String.prototype.endsWith must not be a regular expression",
-            )?;
+            );
         }
 
         let search_string = arg.to_string(ctx)?;
 
-        let length = primitive_val.chars().count() as i32;
-        let search_length = search_string.chars().count() as i32;
-
-        // If less than 2 args specified, end_position is 'undefined', defaults to
-        // length of this
-        let end_position = if args.len() < 2 {
-            length
-        } else {
-            args.get(1)
-                .expect("Could not get argumetn")
-                .to_integer(ctx)? as i32
+        let length = string.code_units().len() as f64;
+        // If the end position is 'undefined', it defaults to the length of this
+        let end_position = match args.get(1) {
+            None | Some(Value::Undefined) => length,
+            Some(end_position) => end_position.to_integer(ctx)?,
         };
+        let end = end_position.max(0.0).min(length) as usize;
 
-        let end = min(max(end_position, 0), length);
-        let start = end.wrapping_sub(search_length);
-
-        if start < 0 {
-            Ok(Value::from(false))
-        } else {
-            // Only use the part of the string up to "end"
-            let this_string: StdString = primitive_val.chars().take(end as usize).collect();
-            Ok(Value::from(this_string.ends_with(search_string.as_str())))
-        }
+        Ok(Value::from(
+            string.code_units()[..end].ends_with(search_string.code_units()),
+        ))
     }
 
     /// `String.prototype.includes( searchString[, position] )`
@@ -471,54 +693,26 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.includes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/includes
     pub(crate) fn includes(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the engine has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(ctx)?;
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
 
-        let arg = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let arg = args.first().cloned().unwrap_or_default();
 
-        if Self::is_regexp_object(&arg) {
-            ctx.throw_type_error(
+        if Self::is_regexp(&arg, ctx)? {
+            return ctx.throw_type_error(
                 "First argument to String.prototype.includes must not be a regular expression",
-            )?;
+            );
         }
 
         let search_string = arg.to_string(ctx)?;
 
-        let length = primitive_val.chars().count() as i32;
-
-        // If less than 2 args specified, position is 'undefined', defaults to 0
-        let position = if args.len() < 2 {
-            0
-        } else {
-            args.get(1)
-                .expect("Could not get argument")
-                .to_integer(ctx)? as i32
-        };
-
-        let start = min(max(position, 0), length);
-
-        // Take the string from "this" and use only the part of it after "start"
-        let this_string: StdString = primitive_val.chars().skip(start as usize).collect();
-
-        Ok(Value::from(this_string.contains(search_string.as_str())))
-    }
-
-    /// Return either the string itself or the string of the regex equivalent
-    fn get_regex_string(value: &Value) -> StdString {
-        match value {
-            Value::String(ref body) => body.to_string(),
-            Value::Object(ref obj) => {
-                let obj = obj.borrow();
+        let length = string.code_units().len() as f64;
+        let position = args.get(1).cloned().unwrap_or_default().to_integer(ctx)?;
+        let start = position.max(0.0).min(length) as usize;
 
-                if let Some(regexp) = obj.as_regexp() {
-                    // first argument is another `RegExp` object, so copy its pattern and flags
-                    return regexp.original_source.clone();
-                }
-                "undefined".to_string()
-            }
-            _ => "undefined".to_string(),
-        }
+        Ok(Value::from(
+            string_index_of(string.code_units(), search_string.code_units(), start).is_some(),
+        ))
     }
 
     fn is_regexp_object(value: &Value) -> bool {
@@ -528,6 +722,23 @@ impl String {
         }
     }
 
+    /// Abstract operation `IsRegExp( argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isregexp
+    fn is_regexp(value: &Value, ctx: &mut Context) -> Result<bool> {
+        if !value.is_object() {
+            return Ok(false);
+        }
+        let matcher = value.get_field(ctx.well_known_symbols().match_symbol(), ctx)?;
+        if !matcher.is_undefined() {
+            return Ok(matcher.to_boolean());
+        }
+        Ok(Self::is_regexp_object(value))
+    }
+
     /// `String.prototype.replace( regexp|substr, newSubstr|function )`
     ///
     /// The `replace()` method returns a new string with some or all matches of a `pattern` replaced by a `replacement`.
@@ -544,148 +755,159 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replace
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace
     pub(crate) fn replace(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // TODO: Support Symbol replacer
-        let primitive_val = this.to_string(ctx)?;
-        if args.is_empty() {
-            return Ok(Value::from(primitive_val));
+        let this = this.require_object_coercible(ctx)?;
+        let search_value = args.first().cloned().unwrap_or_default();
+        let replace_value = args.get(1).cloned().unwrap_or_default();
+
+        if !search_value.is_null_or_undefined() {
+            let symbol_replace = ctx.well_known_symbols().replace_symbol();
+            if let Some(replacer) = search_value.get_method(symbol_replace, ctx)? {
+                return ctx.call(&replacer, &search_value, &[this.clone(), replace_value]);
+            }
         }
 
-        let regex_body = Self::get_regex_string(args.get(0).expect("Value needed"));
-        let re = Regex::new(&regex_body).expect("unable to convert regex to regex object");
-        let mat = match re.find(&primitive_val) {
-            Some(mat) => mat,
-            None => return Ok(Value::from(primitive_val)),
+        let string = this.to_string(ctx)?;
+        let search_string = search_value.to_string(ctx)?;
+        let replace_string = if replace_value.is_function() {
+            None
+        } else {
+            Some(replace_value.to_string(ctx)?)
+        };
+
+        let position = match string_index_of(string.code_units(), search_string.code_units(), 0) {
+            Some(position) => position,
+            None => return Ok(Value::from(string)),
         };
-        let caps = re
-            .find(&primitive_val)
-            .expect("unable to get capture groups from text")
-            .captures;
-
-        let replace_value = if args.len() > 1 {
-            // replace_object could be a string or function or not exist at all
-            let replace_object: &Value = args.get(1).expect("second argument expected");
-            match replace_object {
-                Value::String(val) => {
-                    // https://tc39.es/ecma262/#table-45
-                    let mut result = StdString::new();
-                    let mut chars = val.chars().peekable();
-
-                    let m = caps.len();
-
-                    while let Some(first) = chars.next() {
-                        if first == '$' {
-                            let second = chars.next();
-                            let second_is_digit = second.map_or(false, |ch| ch.is_digit(10));
-                            // we use peek so that it is still in the iterator if not used
-                            let third = if second_is_digit { chars.peek() } else { None };
-                            let third_is_digit = third.map_or(false, |ch| ch.is_digit(10));
-
-                            match (second, third) {
-                                (Some('$'), _) => {
-                                    // $$
-                                    result.push('$');
-                                }
-                                (Some('&'), _) => {
-                                    // $&
-                                    result.push_str(&primitive_val[mat.total()]);
-                                }
-                                (Some('`'), _) => {
-                                    // $`
-                                    let start_of_match = mat.total().start;
-                                    result.push_str(&primitive_val[..start_of_match]);
-                                }
-                                (Some('\''), _) => {
-                                    // $'
-                                    let end_of_match = mat.total().end;
-                                    result.push_str(&primitive_val[end_of_match..]);
-                                }
-                                (Some(second), Some(third))
-                                    if second_is_digit && third_is_digit =>
-                                {
-                                    // $nn
-                                    let tens = second.to_digit(10).unwrap() as usize;
-                                    let units = third.to_digit(10).unwrap() as usize;
-                                    let nn = 10 * tens + units;
-                                    if nn == 0 || nn > m {
-                                        result.push(first);
-                                        result.push(second);
-                                        if let Some(ch) = chars.next() {
-                                            result.push(ch);
-                                        }
-                                    } else {
-                                        let group = match mat.group(nn) {
-                                            Some(range) => &primitive_val[range.clone()],
-                                            _ => "",
-                                        };
-                                        result.push_str(group);
-                                        chars.next(); // consume third
-                                    }
-                                }
-                                (Some(second), _) if second_is_digit => {
-                                    // $n
-                                    let n = second.to_digit(10).unwrap() as usize;
-                                    if n == 0 || n > m {
-                                        result.push(first);
-                                        result.push(second);
-                                    } else {
-                                        let group = match mat.group(n) {
-                                            Some(range) => &primitive_val[range.clone()],
-                                            _ => "",
-                                        };
-                                        result.push_str(group);
-                                    }
-                                }
-                                (Some('<'), _) => {
-                                    // $<
-                                    todo!("named capture groups")
-                                }
-                                _ => {
-                                    // $?, ? is none of the above
-                                    // we can consume second because it isn't $
-                                    result.push(first);
-                                    if let Some(second) = second {
-                                        result.push(second);
-                                    }
-                                }
-                            }
-                        } else {
-                            result.push(first);
-                        }
-                    }
 
-                    result
-                }
-                Value::Object(_) => {
-                    // This will return the matched substring first, then captured parenthesized groups later
-                    let mut results: Vec<Value> = mat
-                        .groups()
-                        .map(|group| match group {
-                            Some(range) => Value::from(&primitive_val[range]),
-                            None => Value::undefined(),
-                        })
-                        .collect();
-
-                    // Returns the starting byte offset of the match
-                    let start = mat.total().start;
-                    results.push(Value::from(start));
-                    // Push the whole string being examined
-                    results.push(Value::from(primitive_val.to_string()));
-
-                    let result = ctx.call(&replace_object, this, &results)?;
-
-                    result.to_string(ctx)?.to_string()
+        let replacement = Self::replacement_for(
+            &string,
+            &search_string,
+            position,
+            &replace_value,
+            replace_string.as_ref(),
+            ctx,
+        )?;
+
+        let end = position + search_string.code_units().len();
+        let mut result = string.code_units()[..position].to_vec();
+        result.extend_from_slice(&replacement);
+        result.extend_from_slice(&string.code_units()[end..]);
+        Ok(Value::from(RcString::from(result)))
+    }
+
+    /// `String.prototype.replaceAll( regexp|substr, newSubstr|function )`
+    ///
+    /// The `replaceAll()` method returns a new string with all matches of a `pattern` replaced by a `replacement`.
+    ///
+    /// A `RegExp` pattern must have the global flag set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replaceall
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll
+    pub(crate) fn replace_all(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(ctx)?;
+        let search_value = args.first().cloned().unwrap_or_default();
+        let replace_value = args.get(1).cloned().unwrap_or_default();
+
+        if !search_value.is_null_or_undefined() {
+            if Self::is_regexp(&search_value, ctx)? {
+                let internal_flags = search_value
+                    .as_object()
+                    .and_then(|object| object.borrow().as_regexp().map(|r| r.flags().to_owned()));
+                let flags = if let Some(flags) = internal_flags {
+                    flags
+                } else {
+                    let flags = search_value.get_field("flags", ctx)?;
+                    flags.require_object_coercible(ctx)?;
+                    flags.to_string(ctx)?.to_string()
+                };
+                if !flags.contains('g') {
+                    return ctx.throw_type_error(
+                        "String.prototype.replaceAll called with a non-global RegExp argument",
+                    );
                 }
-                _ => "undefined".to_string(),
             }
+
+            let symbol_replace = ctx.well_known_symbols().replace_symbol();
+            if let Some(replacer) = search_value.get_method(symbol_replace, ctx)? {
+                return ctx.call(&replacer, &search_value, &[this.clone(), replace_value]);
+            }
+        }
+
+        let string = this.to_string(ctx)?;
+        let search_string = search_value.to_string(ctx)?;
+        let replace_string = if replace_value.is_function() {
+            None
         } else {
-            "undefined".to_string()
+            Some(replace_value.to_string(ctx)?)
         };
 
-        Ok(Value::from(primitive_val.replacen(
-            &primitive_val[mat.total()],
-            &replace_value,
-            1,
-        )))
+        let search_length = search_string.code_units().len();
+        // An empty search string matches before every code unit and at the end of the string.
+        let advance_by = max(1, search_length);
+        let mut positions = Vec::new();
+        let mut position = string_index_of(string.code_units(), search_string.code_units(), 0);
+        while let Some(p) = position {
+            positions.push(p);
+            position = string_index_of(
+                string.code_units(),
+                search_string.code_units(),
+                p + advance_by,
+            );
+        }
+
+        let mut result = Vec::new();
+        let mut end_of_last_match = 0;
+        for position in positions {
+            result.extend_from_slice(&string.code_units()[end_of_last_match..position]);
+            result.extend_from_slice(&Self::replacement_for(
+                &string,
+                &search_string,
+                position,
+                &replace_value,
+                replace_string.as_ref(),
+                ctx,
+            )?);
+            end_of_last_match = position + search_length;
+        }
+        result.extend_from_slice(&string.code_units()[end_of_last_match..]);
+
+        Ok(Value::from(RcString::from(result)))
+    }
+
+    /// Computes the replacement of a string match at the code unit `position`, either by
+    /// calling the replacer function or by expanding the substitutions of `replace_string`.
+    fn replacement_for(
+        string: &RcString,
+        matched: &RcString,
+        position: usize,
+        replace_value: &Value,
+        replace_string: Option<&RcString>,
+        ctx: &mut Context,
+    ) -> Result<Vec<u16>> {
+        if let Some(replace_string) = replace_string {
+            return Ok(get_substitution(
+                matched.code_units(),
+                string.code_units(),
+                position,
+                &[],
+                replace_string.code_units(),
+            ));
+        }
+
+        let args = [
+            Value::from(matched.clone()),
+            Value::from(position),
+            Value::from(string.clone()),
+        ];
+        Ok(ctx
+            .call(replace_value, &Value::undefined(), &args)?
+            .to_string(ctx)?
+            .code_units()
+            .to_vec())
     }
 
     /// `String.prototype.indexOf( searchValue[, fromIndex] )`
@@ -705,30 +927,16 @@ impl String {
         let this = this.require_object_coercible(ctx)?;
         let string = this.to_string(ctx)?;
 
-        let search_string = args
-            .get(0)
-            .cloned()
-            .unwrap_or_else(Value::undefined)
-            .to_string(ctx)?;
-
-        let length = string.chars().count();
-        let start = args
-            .get(1)
-            .map(|position| position.to_integer(ctx))
-            .transpose()?
-            .map_or(0, |position| position.max(0.0).min(length as f64) as usize);
-
-        if search_string.is_empty() {
-            return Ok(start.min(length).into());
-        }
+        let search_string = args.first().cloned().unwrap_or_default().to_string(ctx)?;
 
-        if start < length {
-            if let Some(position) = string.find(search_string.as_str()) {
-                return Ok(string[..position].chars().count().into());
-            }
-        }
+        let length = string.code_units().len() as f64;
+        let position = args.get(1).cloned().unwrap_or_default().to_integer(ctx)?;
+        let start = position.max(0.0).min(length) as usize;
 
-        Ok(Value::from(-1))
+        match string_index_of(string.code_units(), search_string.code_units(), start) {
+            Some(index) => Ok(Value::from(index)),
+            None => Ok(Value::from(-1)),
+        }
     }
 
     /// `String.prototype.lastIndexOf( searchValue[, fromIndex] )`
@@ -748,30 +956,31 @@ impl String {
         let this = this.require_object_coercible(ctx)?;
         let string = this.to_string(ctx)?;
 
-        let search_string = args
-            .get(0)
-            .cloned()
-            .unwrap_or_else(Value::undefined)
-            .to_string(ctx)?;
-
-        let length = string.chars().count();
-        let start = args
-            .get(1)
-            .map(|position| position.to_integer(ctx))
-            .transpose()?
-            .map_or(0, |position| position.max(0.0).min(length as f64) as usize);
-
-        if search_string.is_empty() {
-            return Ok(start.min(length).into());
-        }
+        let search_string = args.first().cloned().unwrap_or_default().to_string(ctx)?;
 
-        if start < length {
-            if let Some(position) = string.rfind(search_string.as_str()) {
-                return Ok(string[..position].chars().count().into());
-            }
-        }
+        let length = string.code_units().len();
+        // A missing or `NaN` position searches from the end of the string.
+        let position = args.get(1).cloned().unwrap_or_default().to_number(ctx)?;
+        let position = if position.is_nan() {
+            f64::INFINITY
+        } else {
+            Value::from(position).to_integer(ctx)?
+        };
+        let start = position.max(0.0).min(length as f64) as usize;
+
+        let search = search_string.code_units();
+        let last_start = match length.checked_sub(search.len()) {
+            Some(last_start) => min(start, last_start),
+            None => return Ok(Value::from(-1)),
+        };
 
-        Ok(Value::from(-1))
+        match (0..=last_start)
+            .rev()
+            .find(|&index| string.code_units()[index..].starts_with(search))
+        {
+            Some(index) => Ok(Value::from(index)),
+            None => Ok(Value::from(-1)),
+        }
     }
 
     /// `String.prototype.match( regexp )`
@@ -790,38 +999,132 @@ impl String {
         RegExp::r#match(&re, this.to_string(ctx)?, ctx)
     }
 
+    /// `String.prototype.split( separator[, limit] )`
+    ///
+    /// The `split()` method divides a `String` into an ordered list of substrings, puts these substrings
+    /// into an array, and returns the array.
+    ///
+    /// If `separator` has a `Symbol.split` method, like `RegExp` objects do, the splitting is delegated to it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.split
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split
+    pub(crate) fn split(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(ctx)?;
+        let separator = args.first().cloned().unwrap_or_default();
+        let limit = args.get(1).cloned().unwrap_or_default();
+
+        if !separator.is_null_or_undefined() {
+            let symbol_split = ctx.well_known_symbols().split_symbol();
+            if let Some(splitter) = separator.get_method(symbol_split, ctx)? {
+                return ctx.call(&splitter, &separator, &[this.clone(), limit]);
+            }
+        }
+
+        let string = this.to_string(ctx)?;
+        let limit = if limit.is_undefined() {
+            u32::MAX
+        } else {
+            limit.to_u32(ctx)?
+        } as usize;
+        let separator_string = separator.to_string(ctx)?;
+
+        let code_units = string.code_units();
+        let separator_units = separator_string.code_units();
+        let mut parts = Vec::new();
+        if limit == 0 {
+            // No parts are returned.
+        } else if separator.is_undefined() {
+            parts.push(Value::from(string.clone()));
+        } else if separator_units.is_empty() {
+            parts.extend(
+                code_units
+                    .iter()
+                    .take(limit)
+                    .map(|&code_unit| Value::from(RcString::from_utf16(&[code_unit]))),
+            );
+        } else {
+            let mut start = 0;
+            while let Some(index) = string_index_of(code_units, separator_units, start) {
+                parts.push(Value::from(RcString::from(&code_units[start..index])));
+                if parts.len() == limit {
+                    return Array::construct_array(&Array::new_array(ctx)?, &parts, ctx);
+                }
+                start = index + separator_units.len();
+            }
+            parts.push(Value::from(RcString::from(&code_units[start..])));
+        }
+
+        Array::construct_array(&Array::new_array(ctx)?, &parts, ctx)
+    }
+
+    /// `String.prototype.search( regexp )`
+    ///
+    /// The `search()` method executes a search for a match between a regular expression and this `String` object.
+    ///
+    /// Returns the index of the first match, or `-1` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.search
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/search
+    pub(crate) fn search(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(ctx)?;
+        let regexp = args.first().cloned().unwrap_or_default();
+
+        if !regexp.is_null_or_undefined() {
+            let symbol_search = ctx.well_known_symbols().search_symbol();
+            if let Some(searcher) = regexp.get_method(symbol_search, ctx)? {
+                return ctx.call(&searcher, &regexp, std::slice::from_ref(this));
+            }
+        }
+
+        let string = this.to_string(ctx)?;
+        let pattern = if regexp.is_undefined() {
+            Value::from("")
+        } else {
+            Value::from(regexp.to_string(ctx)?)
+        };
+        let rx = RegExp::constructor(&Value::from(Object::default()), &[pattern], ctx)?;
+        RegExp::search(&rx, &[Value::from(string)], ctx)
+    }
+
     /// Abstract method `StringPad`.
     ///
     /// Performs the actual string padding for padStart/End.
     /// <https://tc39.es/ecma262/#sec-stringpad/>
     fn string_pad(
-        primitive: RcString,
-        max_length: i32,
+        string: RcString,
+        max_length: f64,
         fill_string: Option<RcString>,
         at_start: bool,
     ) -> Result<Value> {
-        let primitive_length = primitive.len() as i32;
+        let string_length = string.code_units().len();
 
-        if max_length <= primitive_length {
-            return Ok(Value::from(primitive));
+        if max_length <= string_length as f64 {
+            return Ok(Value::from(string));
         }
 
-        let filter = fill_string.as_deref().unwrap_or(" ");
-
-        let fill_len = max_length.wrapping_sub(primitive_length);
-        let mut fill_str = StdString::new();
-
-        while fill_str.len() < fill_len as usize {
-            fill_str.push_str(filter);
+        let filler = fill_string.unwrap_or_else(|| RcString::from(" "));
+        if filler.code_units().is_empty() {
+            return Ok(Value::from(string));
         }
+
+        let fill_len = max_length as usize - string_length;
         // Cut to size max_length
-        let concat_fill_str: StdString = fill_str.chars().take(fill_len as usize).collect();
+        let fill = filler.code_units().iter().copied().cycle().take(fill_len);
 
-        if at_start {
-            Ok(Value::from(format!("{}{}", concat_fill_str, &primitive)))
+        let code_units: Vec<u16> = if at_start {
+            fill.chain(string.code_units().iter().copied()).collect()
         } else {
-            Ok(Value::from(format!("{}{}", primitive, &concat_fill_str)))
-        }
+            string.code_units().iter().copied().chain(fill).collect()
+        };
+        Ok(Value::from(RcString::from(code_units)))
     }
 
     /// `String.prototype.padEnd( targetLength[, padString] )`
@@ -837,18 +1140,22 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.padend
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padEnd
     pub(crate) fn pad_end(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let primitive = this.to_string(ctx)?;
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
         if args.is_empty() {
             return Err(Value::from("padEnd requires maxLength argument"));
         }
         let max_length = args
-            .get(0)
+            .first()
             .expect("failed to get argument for String method")
-            .to_integer(ctx)? as i32;
+            .to_length(ctx)? as f64;
 
-        let fill_string = args.get(1).map(|arg| arg.to_string(ctx)).transpose()?;
+        let fill_string = match args.get(1) {
+            None | Some(Value::Undefined) => None,
+            Some(fill_string) => Some(fill_string.to_string(ctx)?),
+        };
 
-        Self::string_pad(primitive, max_length, fill_string, false)
+        Self::string_pad(string, max_length, fill_string, false)
     }
 
     /// `String.prototype.padStart( targetLength [, padString] )`
@@ -864,18 +1171,22 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.padstart
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padStart
     pub(crate) fn pad_start(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let primitive = this.to_string(ctx)?;
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
         if args.is_empty() {
             return Err(Value::from("padStart requires maxLength argument"));
         }
         let max_length = args
-            .get(0)
+            .first()
             .expect("failed to get argument for String method")
-            .to_integer(ctx)? as i32;
+            .to_length(ctx)? as f64;
 
-        let fill_string = args.get(1).map(|arg| arg.to_string(ctx)).transpose()?;
+        let fill_string = match args.get(1) {
+            None | Some(Value::Undefined) => None,
+            Some(fill_string) => Some(fill_string.to_string(ctx)?),
+        };
 
-        Self::string_pad(primitive, max_length, fill_string, true)
+        Self::string_pad(string, max_length, fill_string, true)
     }
 
     /// Helper function to check if a `char` is trimmable.
@@ -899,6 +1210,37 @@ impl String {
         )
     }
 
+    /// Helper function to check if a UTF-16 code unit is trimmable.
+    #[inline]
+    fn is_trimmable_code_unit(code_unit: u16) -> bool {
+        char::from_u32(u32::from(code_unit)).is_some_and(Self::is_trimmable_whitespace)
+    }
+
+    /// Abstract operation `TrimString`, which removes the whitespace at the start and/or the end
+    /// of the string.
+    ///
+    /// <https://tc39.es/ecma262/#sec-trimstring>
+    fn trim_string(string: &RcString, start: bool, end: bool) -> RcString {
+        let code_units = string.code_units();
+        let begin = if start {
+            code_units
+                .iter()
+                .position(|&unit| !Self::is_trimmable_code_unit(unit))
+                .unwrap_or(code_units.len())
+        } else {
+            0
+        };
+        let finish = if end {
+            code_units
+                .iter()
+                .rposition(|&unit| !Self::is_trimmable_code_unit(unit))
+                .map_or(begin, |index| index + 1)
+        } else {
+            code_units.len()
+        };
+        RcString::from(&code_units[begin..finish])
+    }
+
     /// String.prototype.trim()
     ///
     /// The `trim()` method removes whitespace from both ends of a string.
@@ -914,9 +1256,7 @@ impl String {
     pub(crate) fn trim(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
         let this = this.require_object_coercible(ctx)?;
         let string = this.to_string(ctx)?;
-        Ok(Value::from(
-            string.trim_matches(Self::is_trimmable_whitespace),
-        ))
+        Ok(Value::from(Self::trim_string(&string, true, true)))
     }
 
     /// `String.prototype.trimStart()`
@@ -932,10 +1272,9 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.trimstart
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimStart
     pub(crate) fn trim_start(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(ctx)?;
         let string = this.to_string(ctx)?;
-        Ok(Value::from(
-            string.trim_start_matches(Self::is_trimmable_whitespace),
-        ))
+        Ok(Value::from(Self::trim_string(&string, true, false)))
     }
 
     /// String.prototype.trimEnd()
@@ -953,9 +1292,7 @@ impl String {
     pub(crate) fn trim_end(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
         let this = this.require_object_coercible(ctx)?;
         let string = this.to_string(ctx)?;
-        Ok(Value::from(
-            string.trim_end_matches(Self::is_trimmable_whitespace),
-        ))
+        Ok(Value::from(Self::trim_string(&string, false, true)))
     }
 
     /// `String.prototype.toLowerCase()`
@@ -970,12 +1307,11 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLowerCase
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_lowercase(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the engine has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let this_str = this.to_string(ctx)?;
-        // The Rust String is mapped to uppercase using the builtin .to_lowercase().
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
+        // The Rust String is mapped to lowercase using the builtin .to_lowercase().
         // There might be corner cases where it does not behave exactly like Javascript expects
-        Ok(Value::from(this_str.to_lowercase()))
+        Ok(Value::from(Self::map_case(&string, str::to_lowercase)))
     }
 
     /// `String.prototype.toUpperCase()`
@@ -992,12 +1328,134 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toUpperCase
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_uppercase(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the engine has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let this_str = this.to_string(ctx)?;
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
         // The Rust String is mapped to uppercase using the builtin .to_uppercase().
         // There might be corner cases where it does not behave exactly like Javascript expects
-        Ok(Value::from(this_str.to_uppercase()))
+        Ok(Value::from(Self::map_case(&string, str::to_uppercase)))
+    }
+
+    /// Applies a case mapping to the well-formed parts of the string, keeping its lone
+    /// surrogates as they are.
+    fn map_case(string: &RcString, mapping: fn(&str) -> StdString) -> RcString {
+        let mut code_units = Vec::with_capacity(string.code_units().len());
+        let mut run = StdString::new();
+        for character in decode_utf16(string.code_units().iter().copied()) {
+            match character {
+                Ok(character) => run.push(character),
+                Err(error) => {
+                    code_units.extend(mapping(&run).encode_utf16());
+                    run.clear();
+                    code_units.push(error.unpaired_surrogate());
+                }
+            }
+        }
+        code_units.extend(mapping(&run).encode_utf16());
+        RcString::from(code_units)
+    }
+
+    /// `String.prototype.toLocaleLowerCase( [ locales ] )`
+    ///
+    /// The `toLocaleLowerCase()` method returns the calling string value converted to lower case,
+    /// according to any locale-specific case mappings.
+    ///
+    /// Locales are not supported yet, so this behaves like `toLowerCase()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.tolocalelowercase
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLocaleLowerCase
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_lowercase(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        this.require_object_coercible(ctx)?;
+        Self::to_lowercase(this, args, ctx)
+    }
+
+    /// `String.prototype.toLocaleUpperCase( [ locales ] )`
+    ///
+    /// The `toLocaleUpperCase()` method returns the calling string value converted to upper case,
+    /// according to any locale-specific case mappings.
+    ///
+    /// Locales are not supported yet, so this behaves like `toUpperCase()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.tolocaleuppercase
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toLocaleUpperCase
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_uppercase(
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        this.require_object_coercible(ctx)?;
+        Self::to_uppercase(this, args, ctx)
+    }
+
+    /// `String.prototype.localeCompare( compareString )`
+    ///
+    /// The `localeCompare()` method returns a number indicating whether a reference string comes before,
+    /// or after, or is the same as the given string in sort order.
+    ///
+    /// Without locale data the strings are compared by code point, after normalizing them to NFC so
+    /// that canonically equivalent strings compare as equal.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.localecompare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare
+    pub(crate) fn locale_compare(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
+        let that = args.first().cloned().unwrap_or_default().to_string(ctx)?;
+
+        let ordering = string.nfc().cmp(that.nfc());
+        Ok(Value::from(ordering as i32))
+    }
+
+    /// `String.prototype.normalize( [ form ] )`
+    ///
+    /// The `normalize()` method returns the Unicode Normalization Form of the string.
+    ///
+    /// `form` is one of `"NFC"` (the default), `"NFD"`, `"NFKC"` or `"NFKD"`, anything else throws a `RangeError`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.normalize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/normalize
+    pub(crate) fn normalize(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
+        let form = match args.first() {
+            None | Some(Value::Undefined) => RcString::from("NFC"),
+            Some(form) => form.to_string(ctx)?,
+        };
+
+        let normalized: StdString = match form.as_str() {
+            "NFC" => string.nfc().collect(),
+            "NFD" => string.nfd().collect(),
+            "NFKC" => string.nfkc().collect(),
+            "NFKD" => string.nfkd().collect(),
+            _ => {
+                return ctx.throw_range_error(
+                    "The normalization form should be one of NFC, NFD, NFKC, NFKD",
+                )
+            }
+        };
+
+        Ok(Value::from(normalized))
     }
 
     /// `String.prototype.substring( indexStart[, indexEnd] )`
@@ -1011,43 +1469,26 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.substring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/substring
     pub(crate) fn substring(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the engine has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(ctx)?;
-        // If no args are specified, start is 'undefined', defaults to 0
-        let start = if args.is_empty() {
-            0
-        } else {
-            args.get(0)
-                .expect("failed to get argument for String method")
-                .to_integer(ctx)? as i32
-        };
-        let length = primitive_val.encode_utf16().count() as i32;
-        // If less than 2 args specified, end is the length of the this object converted to a String
-        let end = if args.len() < 2 {
-            length
-        } else {
-            args.get(1)
-                .expect("Could not get argument")
-                .to_integer(ctx)? as i32
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
+        let length = string.code_units().len() as f64;
+
+        let start = args.first().cloned().unwrap_or_default().to_integer(ctx)?;
+        // If end is 'undefined', it is the length of the string
+        let end = match args.get(1) {
+            None | Some(Value::Undefined) => length,
+            Some(end) => end.to_integer(ctx)?,
         };
         // Both start and end args replaced by 0 if they were negative
         // or by the length of the String if they were greater
-        let final_start = min(max(start, 0), length);
-        let final_end = min(max(end, 0), length);
+        let final_start = start.max(0.0).min(length);
+        let final_end = end.max(0.0).min(length);
         // Start and end are swapped if start is greater than end
-        let from = min(final_start, final_end) as usize;
-        let to = max(final_start, final_end) as usize;
+        let from = final_start.min(final_end) as usize;
+        let to = final_start.max(final_end) as usize;
         // Extract the part of the string contained between the start index and the end index
         // where start is guaranteed to be smaller or equals to end
-        let extracted_string: std::result::Result<StdString, _> = decode_utf16(
-            primitive_val
-                .encode_utf16()
-                .skip(from)
-                .take(to.wrapping_sub(from)),
-        )
-        .collect();
-        Ok(Value::from(extracted_string.expect("Invalid string")))
+        Ok(Value::from(RcString::from(&string.code_units()[from..to])))
     }
 
     /// `String.prototype.substr( start[, length] )`
@@ -1062,48 +1503,32 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/substr
     /// <https://tc39.es/ecma262/#sec-string.prototype.substr>
     pub(crate) fn substr(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the engine has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(ctx)?;
-        // If no args are specified, start is 'undefined', defaults to 0
-        let mut start = if args.is_empty() {
-            0
+        let this = this.require_object_coercible(ctx)?;
+        let string = this.to_string(ctx)?;
+        let size = string.code_units().len() as f64;
+
+        let start = args.first().cloned().unwrap_or_default().to_integer(ctx)?;
+        // If start is negative it become the number of code units from the end of the string
+        let start = if start < 0.0 {
+            (size + start).max(0.0)
         } else {
-            args.get(0)
-                .expect("failed to get argument for String method")
-                .to_integer(ctx)? as i32
+            start.min(size)
         };
-        let length = primitive_val.chars().count() as i32;
-        // If less than 2 args specified, end is +infinity, the maximum number value.
-        // Using i32::max_value() should be safe because the final length used is at most
-        // the number of code units from start to the end of the string,
-        // which should always be smaller or equals to both +infinity and i32::max_value
-        let end = if args.len() < 2 {
-            i32::max_value()
-        } else {
-            args.get(1)
-                .expect("Could not get argument")
-                .to_integer(ctx)? as i32
+        // If length is 'undefined', the rest of the string is extracted
+        let length = match args.get(1) {
+            None | Some(Value::Undefined) => size,
+            Some(length) => length.to_integer(ctx)?,
         };
-        // If start is negative it become the number of code units from the end of the string
-        if start < 0 {
-            start = max(length.wrapping_add(start), 0);
-        }
-        // length replaced by 0 if it was negative
-        // or by the number of code units from start to the end of the string if it was greater
-        let result_length = min(max(end, 0), length.wrapping_sub(start));
+        let end = (start + length).min(size);
+
         // If length is negative we return an empty string
         // otherwise we extract the part of the string from start and is length code units long
-        if result_length <= 0 {
+        if start >= end {
             Ok(Value::from(""))
         } else {
-            let extracted_string: StdString = primitive_val
-                .chars()
-                .skip(start as usize)
-                .take(result_length as usize)
-                .collect();
-
-            Ok(Value::from(extracted_string))
+            Ok(Value::from(RcString::from(
+                &string.code_units()[start as usize..end as usize],
+            )))
         }
     }
 
@@ -1136,7 +1561,7 @@ impl String {
     /// [cg]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions/Groups_and_Ranges
     // TODO: update this method to return iterator
     pub(crate) fn match_all(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let re: Value = match args.get(0) {
+        let re: Value = match args.first() {
             Some(arg) => {
                 if arg.is_null() {
                     RegExp::constructor(
@@ -1161,7 +1586,7 @@ impl String {
             ),
         }?;
 
-        RegExp::match_all(&re, this.to_string(ctx)?.to_string())
+        RegExp::match_all(&re, this.to_string(ctx)?)
     }
 
     pub(crate) fn iterator(this: &Value, _args: &[Value], ctx: &mut Context) -> Result<Value> {
@@ -39,14 +39,15 @@ impl StringIterator {
                     return Ok(create_iter_result_object(ctx, Value::undefined(), true));
                 }
                 let native_string = string_iterator.string.to_string(ctx)?;
-                let len = native_string.encode_utf16().count() as i32;
+                let len = native_string.code_units().len() as i32;
                 let position = string_iterator.next_index;
                 if position >= len {
                     string_iterator.string = Value::undefined();
                     return Ok(create_iter_result_object(ctx, Value::undefined(), true));
                 }
                 let (_, code_unit_count, _) =
-                    code_point_at(native_string, position).expect("Invalid code point position");
+                    code_point_at(native_string.code_units(), position as usize)
+                        .expect("Invalid code point position");
                 string_iterator.next_index += code_unit_count as i32;
                 let result_string = crate::builtins::string::String::substring(
                     &string_iterator.string,
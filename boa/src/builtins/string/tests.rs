@@ -96,8 +96,8 @@ fn construct_and_call() {
     let hello = forward_val(&mut engine, "hello").unwrap();
     let world = forward_val(&mut engine, "world").unwrap();
 
-    assert_eq!(hello.is_object(), true);
-    assert_eq!(world.is_string(), true);
+    assert!(hello.is_object());
+    assert!(world.is_string());
 }
 
 #[test]
@@ -708,14 +708,14 @@ fn last_index_of_with_non_string_search_string_argument() {
 fn last_index_of_with_from_index_argument() {
     let mut engine = Context::new();
     assert_eq!(forward(&mut engine, "''.lastIndexOf('x', 2)"), "-1");
-    assert_eq!(forward(&mut engine, "'x'.lastIndexOf('x', 2)"), "-1");
-    assert_eq!(forward(&mut engine, "'abcxx'.lastIndexOf('x', 2)"), "4");
-    assert_eq!(forward(&mut engine, "'x'.lastIndexOf('x', 2)"), "-1");
-    assert_eq!(forward(&mut engine, "'µµµxµµµ'.lastIndexOf('x', 2)"), "3");
+    assert_eq!(forward(&mut engine, "'x'.lastIndexOf('x', 2)"), "0");
+    assert_eq!(forward(&mut engine, "'abcxx'.lastIndexOf('x', 2)"), "-1");
+    assert_eq!(forward(&mut engine, "'x'.lastIndexOf('x', 2)"), "0");
+    assert_eq!(forward(&mut engine, "'µµµxµµµ'.lastIndexOf('x', 2)"), "-1");
 
     assert_eq!(
         forward(&mut engine, "'µµµxµµµ'.lastIndexOf('x', 10000000)"),
-        "-1"
+        "3"
     );
 }
 
@@ -757,13 +757,13 @@ fn last_index_non_integer_position_argument() {
     );
     assert_eq!(
         forward(&mut engine, "'abcx'.lastIndexOf('x', new String('1'))"),
-        "3"
+        "-1"
     );
     assert_eq!(
         forward(&mut engine, "'abcx'.lastIndexOf('x', new String('100'))"),
-        "-1"
+        "3"
     );
-    assert_eq!(forward(&mut engine, "'abcx'.lastIndexOf('x', null)"), "3");
+    assert_eq!(forward(&mut engine, "'abcx'.lastIndexOf('x', null)"), "-1");
 }
 
 #[test]
@@ -892,3 +892,395 @@ fn unicode_iter() {
     assert_eq!(forward(&mut engine, "next.value"), "undefined");
     assert_eq!(forward(&mut engine, "next.done"), "true");
 }
+
+#[test]
+fn raw() {
+    let mut engine = Context::new();
+
+    assert_eq!(
+        forward(&mut engine, r"String.raw`a\n${1 + 1}b\unicode${2}`"),
+        r#""a\n2b\unicode2""#
+    );
+    assert_eq!(
+        forward(&mut engine, "String.raw({ raw: ['x', 'y', 'z'] }, 1, 2, 3)"),
+        "\"x1y2z\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "String.raw({ raw: { length: 2, 0: 'a', 1: 'b' } })"
+        ),
+        "\"ab\""
+    );
+    assert_eq!(forward(&mut engine, "String.raw({ raw: [] }, 1)"), "\"\"");
+}
+
+#[test]
+fn split() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "'a,b'.split(',').length"), "2");
+    assert_eq!(
+        forward(&mut engine, "'a,b,c'.split(',').join('|')"),
+        "\"a|b|c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'a,b,c'.split(',', 2).join('|')"),
+        "\"a|b\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.split('').join('|')"),
+        "\"a|b|c\""
+    );
+    assert_eq!(forward(&mut engine, "'abc'.split().length"), "1");
+    assert_eq!(forward(&mut engine, "''.split(',').length"), "1");
+    assert_eq!(forward(&mut engine, "''.split('').length"), "0");
+    assert_eq!(forward(&mut engine, "'a,b'.split(',', 0).length"), "0");
+    assert_eq!(
+        forward(&mut engine, "Array.isArray('a b'.split(' '))"),
+        "true"
+    );
+}
+
+#[test]
+fn split_with_regexp() {
+    let mut engine = Context::new();
+
+    assert_eq!(
+        forward(&mut engine, "'a1b22c'.split(/\\d+/).join('|')"),
+        "\"a|b|c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'a1b2c'.split(/(\\d)/).join('|')"),
+        "\"a|1|b|2|c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.split(/(?:)/).join('|')"),
+        "\"a|b|c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'a1b2c'.split(/\\d/, 2).join('|')"),
+        "\"a|b\""
+    );
+    assert_eq!(forward(&mut engine, "''.split(/x/).length"), "1");
+    assert_eq!(forward(&mut engine, "''.split(/(?:)/).length"), "0");
+}
+
+#[test]
+fn split_uses_symbol_split() {
+    let mut engine = Context::new();
+    let init = r#"
+        var separator = {};
+        separator[Symbol.split] = function (string, limit) {
+            return [string, limit, this === separator];
+        };
+        var result = "abc".split(separator, 3);
+        "#;
+
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "result.join('|')"), "\"abc|3|true\"");
+}
+
+#[test]
+fn search() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "'hello world'.search(/o/)"), "4");
+    assert_eq!(forward(&mut engine, "'hello world'.search(/x/)"), "-1");
+    assert_eq!(forward(&mut engine, "'a.b'.search('.')"), "0");
+    assert_eq!(forward(&mut engine, "'abc'.search()"), "0");
+    assert_eq!(forward(&mut engine, "'ä.b'.search(/b/)"), "2");
+
+    let init = r#"
+        var searcher = {};
+        searcher[Symbol.search] = function (string) { return string + "!"; };
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "'abc'.search(searcher)"), "\"abc!\"");
+}
+
+#[test]
+fn replace_string_pattern_is_literal() {
+    let mut engine = Context::new();
+
+    assert_eq!(
+        forward(&mut engine, "'a.b.c'.replace('.', '-')"),
+        "\"a-b.c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.replace('b', '[$&$`$\\']')"),
+        "\"a[bac]c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.replace('b', (m, p, s) => m + p + s)"),
+        "\"ab1abcc\""
+    );
+}
+
+#[test]
+fn replace_global_regexp() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "'aaa'.replace(/a/g, 'b')"), "\"bbb\"");
+    assert_eq!(forward(&mut engine, "'aaa'.replace(/a/, 'b')"), "\"baa\"");
+    assert_eq!(
+        forward(&mut engine, "'x1y2'.replace(/(\\d)/g, '<$1>')"),
+        "\"x<1>y<2>\""
+    );
+}
+
+#[test]
+fn replace_uses_symbol_replace() {
+    let mut engine = Context::new();
+    let init = r#"
+        var pattern = {};
+        pattern[Symbol.replace] = function (string, replacement) {
+            return string + ":" + replacement;
+        };
+        "#;
+
+    forward(&mut engine, init);
+
+    assert_eq!(
+        forward(&mut engine, "'abc'.replace(pattern, 'x')"),
+        "\"abc:x\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.replaceAll(pattern, 'y')"),
+        "\"abc:y\""
+    );
+}
+
+#[test]
+fn replace_all() {
+    let mut engine = Context::new();
+
+    assert_eq!(
+        forward(&mut engine, "'a.b.c'.replaceAll('.', '-')"),
+        "\"a-b-c\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'abc'.replaceAll('', '-')"),
+        "\"-a-b-c-\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'aXbX'.replaceAll('X', '$&$&')"),
+        "\"aXXbXX\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'a1b1'.replaceAll('1', (m, p) => p)"),
+        "\"a1b3\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'aaa'.replaceAll(/a/g, 'b')"),
+        "\"bbb\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { 'aaa'.replaceAll(/a/, 'b') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn code_point_at() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "'abc'.codePointAt(1)"), "98");
+    assert_eq!(forward(&mut engine, "'😀'.codePointAt(0)"), "128512");
+    assert_eq!(forward(&mut engine, "'😀'.codePointAt(1)"), "56832");
+    assert_eq!(forward(&mut engine, "'abc'.codePointAt(3)"), "undefined");
+    assert_eq!(forward(&mut engine, "'abc'.codePointAt(-1)"), "undefined");
+}
+
+#[test]
+fn at() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "'abc'.at(0)"), "\"a\"");
+    assert_eq!(forward(&mut engine, "'abc'.at(-1)"), "\"c\"");
+    assert_eq!(forward(&mut engine, "'abc'.at(3)"), "undefined");
+    assert_eq!(forward(&mut engine, "'abc'.at(-4)"), "undefined");
+}
+
+#[test]
+fn locale_compare() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "'a'.localeCompare('b')"), "-1");
+    assert_eq!(forward(&mut engine, "'b'.localeCompare('a')"), "1");
+    assert_eq!(forward(&mut engine, "'a'.localeCompare('a')"), "0");
+    assert_eq!(
+        forward(&mut engine, "'\\u00F1'.localeCompare('n\\u0303')"),
+        "0"
+    );
+}
+
+#[test]
+fn normalize() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "'n\\u0303'.normalize().length"), "1");
+    assert_eq!(
+        forward(&mut engine, "'\\u00F1'.normalize('NFD').length"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut engine, "'\\uFB01'.normalize('NFKC')"),
+        "\"fi\""
+    );
+    assert_eq!(
+        forward(&mut engine, "'\\uFB01'.normalize('NFKD')"),
+        "\"fi\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { 'a'.normalize('nfc') } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn to_locale_case() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "'Abc'.toLocaleUpperCase()"), "\"ABC\"");
+    assert_eq!(forward(&mut engine, "'Abc'.toLocaleLowerCase()"), "\"abc\"");
+}
+
+#[test]
+fn from_char_code() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "String.fromCharCode()"), "\"\"");
+    assert_eq!(
+        forward(&mut engine, "String.fromCharCode(65, 66, 67)"),
+        "\"ABC\""
+    );
+    assert_eq!(forward(&mut engine, "String.fromCharCode(65601)"), "\"A\"");
+    assert_eq!(
+        forward(&mut engine, "String.fromCharCode(0xD83D, 0xDE00)"),
+        "\"😀\""
+    );
+}
+
+#[test]
+fn from_code_point() {
+    let mut engine = Context::new();
+
+    assert_eq!(
+        forward(&mut engine, "String.fromCodePoint(65, 0x1F600)"),
+        "\"A😀\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[...String.fromCodePoint(0x1F600, 97)].length"),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { String.fromCodePoint(0x110000) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { String.fromCodePoint(1.5) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn utf16_length_and_indexing() {
+    let mut engine = Context::new();
+    let init = r#"
+        var emoji = "a😀b";
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "emoji.length"), "4");
+    assert_eq!(forward(&mut engine, "emoji.charCodeAt(1)"), "55357");
+    assert_eq!(forward(&mut engine, "emoji.charCodeAt(2)"), "56832");
+    assert_eq!(forward(&mut engine, "emoji[3]"), "\"b\"");
+    assert_eq!(forward(&mut engine, "emoji.indexOf('b')"), "3");
+    assert_eq!(forward(&mut engine, "emoji.slice(1, 3) === '😀'"), "true");
+    assert_eq!(forward(&mut engine, "emoji.split('').length"), "4");
+    assert_eq!(forward(&mut engine, "/b/.exec(emoji).index"), "3");
+    assert_eq!(forward(&mut engine, "'中文字'.length"), "3");
+    assert_eq!(forward(&mut engine, "'中文字'.charAt(1)"), "\"文\"");
+}
+
+#[test]
+fn lone_surrogates() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "'\\uD83D'.length"), "1");
+    assert_eq!(forward(&mut engine, "'\\uD83D'.charCodeAt(0)"), "55357");
+    assert_eq!(
+        forward(&mut engine, "'\\uD83D' + '\\uDE00' === '😀'"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "'😀'[0] + '😀'[1] === '😀'"), "true");
+    assert_eq!(
+        forward(&mut engine, "'😀'.slice(0, 1) === '\\uD83D'"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "`\\uD83D`.charCodeAt(0)"), "55357");
+    assert_eq!(
+        forward(&mut engine, "String.fromCharCode(0xDE00).charCodeAt(0)"),
+        "56832"
+    );
+}
+
+#[test]
+fn comparison_uses_code_units() {
+    let mut engine = Context::new();
+
+    // U+FF61 is a single code unit, which is greater than the surrogates that encode U+1F600.
+    assert_eq!(forward(&mut engine, "'\\uFF61' > '😀'"), "true");
+    assert_eq!(
+        forward(&mut engine, "['\\uFF61', '😀'].sort()[0] === '😀'"),
+        "true"
+    );
+}
+
+#[test]
+fn string_object_index_properties() {
+    let mut engine = Context::new();
+
+    assert_eq!(forward(&mut engine, "Object.keys('ab').join()"), "\"0,1\"");
+    assert_eq!(
+        forward(&mut engine, "Object.getOwnPropertyNames('ab').join()"),
+        "\"0,1,length\""
+    );
+    assert_eq!(forward(&mut engine, "'ab'.hasOwnProperty(1)"), "true");
+    assert_eq!(forward(&mut engine, "'ab'.hasOwnProperty(2)"), "false");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "var d = Object.getOwnPropertyDescriptor('ab', 0); [d.value, d.writable, d.enumerable, d.configurable].join()"
+        ),
+        "\"a,false,true,false\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "var s = new String('ab'); s[0] = 'x'; s[5] = 'y'; s[0] + s[5] + Object.keys(s).join()"
+        ),
+        "\"ay0,1,5\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "try { Object.defineProperty(new String('ab'), 0, { value: 'x' }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
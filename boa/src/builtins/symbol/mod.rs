@@ -21,7 +21,7 @@ mod tests;
 use crate::{
     builtins::BuiltIn,
     gc::{Finalize, Trace},
-    object::ConstructorBuilder,
+    object::{ConstructorBuilder, FunctionBuilder},
     property::Attribute,
     value::{RcString, RcSymbol, Value},
     BoaProfiler, Context, Result,
@@ -113,7 +113,7 @@ impl WellKnownSymbols {
     /// Called by the semantics of the instanceof operator.
     #[inline]
     pub fn has_instance_symbol(&self) -> RcSymbol {
-        self.async_iterator.clone()
+        self.has_instance.clone()
     }
 
     /// The `Symbol.isConcatSpreadable` well known symbol.
@@ -260,6 +260,11 @@ impl BuiltIn for Symbol {
 
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        let get_description = FunctionBuilder::new(context, Self::get_description)
+            .name("get description")
+            .constructable(false)
+            .build();
+
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
         let symbol_object = ConstructorBuilder::with_standard_object(
             context,
@@ -278,10 +283,29 @@ impl BuiltIn for Symbol {
         .static_property("search", symbol_search, attribute)
         .static_property("species", symbol_species, attribute)
         .static_property("split", symbol_split, attribute)
-        .static_property("toPrimitive", symbol_to_primitive, attribute)
-        .static_property("toStringTag", symbol_to_string_tag, attribute)
+        .static_property("toPrimitive", symbol_to_primitive.clone(), attribute)
+        .static_property("toStringTag", symbol_to_string_tag.clone(), attribute)
         .static_property("unscopables", symbol_unscopables, attribute)
+        .static_method(Self::for_, "for", 1)
+        .static_method(Self::key_for, "keyFor", 1)
         .method(Self::to_string, "toString", 0)
+        .method(Self::value_of, "valueOf", 0)
+        .method(
+            Self::to_primitive,
+            (symbol_to_primitive, "[Symbol.toPrimitive]"),
+            1,
+        )
+        .accessor(
+            "description",
+            Some(get_description),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .property(
+            symbol_to_string_tag,
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .callable(true)
         .constructable(false)
         .build();
@@ -316,8 +340,8 @@ impl Symbol {
     /// [spec]: https://tc39.es/ecma262/#sec-symbol-description
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/Symbol
     pub(crate) fn constructor(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let description = match args.get(0) {
-            Some(ref value) if !value.is_undefined() => Some(value.to_string(ctx)?),
+        let description = match args.first() {
+            Some(value) if !value.is_undefined() => Some(value.to_string(ctx)?),
             _ => None,
         };
 
@@ -355,4 +379,97 @@ impl Symbol {
         let description = symbol.description().unwrap_or("");
         Ok(Value::from(format!("Symbol({})", description)))
     }
+
+    /// `Symbol.prototype.valueOf()`
+    ///
+    /// This method returns the primitive value of a `Symbol` object.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/valueOf
+    pub(crate) fn value_of(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        Ok(Self::this_symbol_value(this, ctx)?.into())
+    }
+
+    /// `Symbol.prototype [ @@toPrimitive ] ( hint )`
+    ///
+    /// This method converts a `Symbol` object to its primitive value, whatever the hint is.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype-@@toprimitive
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/@@toPrimitive
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_primitive(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        Ok(Self::this_symbol_value(this, ctx)?.into())
+    }
+
+    /// `get Symbol.prototype.description`
+    ///
+    /// This accessor returns the optional description of a `Symbol`.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.prototype.description
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/description
+    pub(crate) fn get_description(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let symbol = Self::this_symbol_value(this, ctx)?;
+        Ok(symbol
+            .description
+            .clone()
+            .map_or_else(Value::undefined, Value::from))
+    }
+
+    /// `Symbol.for( key )`
+    ///
+    /// This method returns the symbol registered with `key` in the global symbol registry,
+    /// registering a new symbol if there is none.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.for
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for
+    pub(crate) fn for_(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let key = args.first().cloned().unwrap_or_default().to_string(ctx)?;
+        if let Some(symbol) = ctx.symbol_registry().get(&key) {
+            return Ok(symbol.clone().into());
+        }
+
+        let symbol = ctx.construct_symbol(Some(key.clone()));
+        ctx.symbol_registry_mut().insert(key, symbol.clone());
+        Ok(symbol.into())
+    }
+
+    /// `Symbol.keyFor( sym )`
+    ///
+    /// This method returns the key a symbol is registered with in the global symbol registry,
+    /// or `undefined` if it is not registered.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.keyfor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/keyFor
+    pub(crate) fn key_for(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let symbol = match args.first() {
+            Some(Value::Symbol(ref symbol)) => symbol,
+            _ => return ctx.throw_type_error("Symbol.keyFor: argument is not a symbol"),
+        };
+
+        Ok(ctx
+            .symbol_registry()
+            .iter()
+            .find(|(_, registered)| *registered == symbol)
+            .map_or_else(Value::undefined, |(key, _)| key.clone().into()))
+    }
 }
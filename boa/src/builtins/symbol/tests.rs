@@ -8,7 +8,7 @@ fn call_symbol_and_check_return_type() {
         "#;
     eprintln!("{}", forward(&mut engine, init));
     let sym = forward_val(&mut engine, "sym").unwrap();
-    assert_eq!(sym.is_symbol(), true);
+    assert!(sym.is_symbol());
 }
 
 #[test]
@@ -37,3 +37,81 @@ fn symbol_access() {
     assert_eq!(forward(&mut engine, "x[sym2]"), "20");
     assert_eq!(forward(&mut engine, "x['Symbol(Hello)']"), "undefined");
 }
+
+#[test]
+fn symbol_strict_equality() {
+    let mut engine = Context::new();
+    let init = r#"
+        var sym1 = Symbol("Hello");
+        var sym2 = Symbol("Hello");
+        "#;
+    forward_val(&mut engine, init).unwrap();
+    assert_eq!(forward(&mut engine, "sym1 === sym1"), "true");
+    assert_eq!(forward(&mut engine, "sym1 === sym2"), "false");
+    assert_eq!(forward(&mut engine, "sym1 !== sym2"), "true");
+}
+
+#[test]
+fn symbol_registry() {
+    let mut engine = Context::new();
+    let init = r#"
+        var registered = Symbol.for("app");
+        "#;
+    forward_val(&mut engine, init).unwrap();
+    assert_eq!(
+        forward(&mut engine, "registered === Symbol.for('app')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "registered === Symbol('app')"),
+        "false"
+    );
+    assert_eq!(forward(&mut engine, "Symbol.keyFor(registered)"), "\"app\"");
+    assert_eq!(
+        forward(&mut engine, "Symbol.keyFor(Symbol('app'))"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut engine, "Symbol.keyFor(Symbol.iterator)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut engine, "Symbol.keyFor('app')"),
+        "Uncaught \"TypeError\": \"Symbol.keyFor: argument is not a symbol\""
+    );
+}
+
+#[test]
+fn symbol_description() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "Symbol('Hello').description"),
+        "\"Hello\""
+    );
+    assert_eq!(forward(&mut engine, "Symbol().description"), "undefined");
+    assert_eq!(forward(&mut engine, "Symbol('').description"), "\"\"");
+    assert_eq!(
+        forward(&mut engine, "Symbol.iterator.description"),
+        "\"Symbol.iterator\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Object(Symbol('boxed')).description"),
+        "\"boxed\""
+    );
+}
+
+#[test]
+fn symbol_to_primitive() {
+    let mut engine = Context::new();
+    let init = r#"
+        var sym = Symbol("Hello");
+        var boxed = Object(sym);
+        "#;
+    forward_val(&mut engine, init).unwrap();
+    assert_eq!(forward(&mut engine, "boxed.valueOf() === sym"), "true");
+    assert_eq!(forward(&mut engine, "boxed == sym"), "true");
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(sym)"),
+        "\"[object Symbol]\""
+    );
+}
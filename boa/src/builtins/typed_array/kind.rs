@@ -0,0 +1,206 @@
+//! This module defines the element types of typed arrays, and how their values are stored in
+//! the bytes of an `ArrayBuffer`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#table-the-typedarray-constructors
+
+use crate::{
+    builtins::{number::f64_to_int32, BigInt},
+    context::StandardConstructor,
+    Context, Result, Value,
+};
+use gc::{unsafe_empty_trace, Finalize, Trace};
+use std::convert::TryInto;
+
+/// The type of the elements of a typed array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub enum TypedArrayKind {
+    Int8,
+    Uint8,
+    Uint8Clamped,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+    BigInt64,
+    BigUint64,
+}
+
+unsafe impl Trace for TypedArrayKind {
+    unsafe_empty_trace!();
+}
+
+/// Writes a number to `$bytes` with the requested endianness.
+macro_rules! write_bytes {
+    ($bytes:expr, $value:expr, $little_endian:expr) => {{
+        let value = $value;
+        if $little_endian {
+            $bytes.copy_from_slice(&value.to_le_bytes())
+        } else {
+            $bytes.copy_from_slice(&value.to_be_bytes())
+        }
+    }};
+}
+
+/// Reads a number of type `$ty` from `$bytes` with the requested endianness.
+macro_rules! read_bytes {
+    ($ty:ty, $bytes:expr, $little_endian:expr) => {{
+        let bytes = $bytes
+            .try_into()
+            .expect("the slice has the size of the element");
+        if $little_endian {
+            <$ty>::from_le_bytes(bytes)
+        } else {
+            <$ty>::from_be_bytes(bytes)
+        }
+    }};
+}
+
+impl TypedArrayKind {
+    /// Returns the name of the constructor of the typed arrays of this kind.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Int8 => "Int8Array",
+            Self::Uint8 => "Uint8Array",
+            Self::Uint8Clamped => "Uint8ClampedArray",
+            Self::Int16 => "Int16Array",
+            Self::Uint16 => "Uint16Array",
+            Self::Int32 => "Int32Array",
+            Self::Uint32 => "Uint32Array",
+            Self::Float32 => "Float32Array",
+            Self::Float64 => "Float64Array",
+            Self::BigInt64 => "BigInt64Array",
+            Self::BigUint64 => "BigUint64Array",
+        }
+    }
+
+    /// Returns the size of an element, in bytes.
+    pub(crate) fn element_size(self) -> usize {
+        match self {
+            Self::Int8 | Self::Uint8 | Self::Uint8Clamped => 1,
+            Self::Int16 | Self::Uint16 => 2,
+            Self::Int32 | Self::Uint32 | Self::Float32 => 4,
+            Self::Float64 | Self::BigInt64 | Self::BigUint64 => 8,
+        }
+    }
+
+    /// Returns `true` if the elements are BigInts, and `false` if they are numbers.
+    pub(crate) fn is_bigint(self) -> bool {
+        matches!(self, Self::BigInt64 | Self::BigUint64)
+    }
+
+    /// Returns the standard constructor of the typed arrays of this kind.
+    pub(crate) fn standard_constructor(self, context: &Context) -> StandardConstructor {
+        let objects = context.standard_objects();
+        match self {
+            Self::Int8 => objects.int8_array_object(),
+            Self::Uint8 => objects.uint8_array_object(),
+            Self::Uint8Clamped => objects.uint8_clamped_array_object(),
+            Self::Int16 => objects.int16_array_object(),
+            Self::Uint16 => objects.uint16_array_object(),
+            Self::Int32 => objects.int32_array_object(),
+            Self::Uint32 => objects.uint32_array_object(),
+            Self::Float32 => objects.float32_array_object(),
+            Self::Float64 => objects.float64_array_object(),
+            Self::BigInt64 => objects.bigint64_array_object(),
+            Self::BigUint64 => objects.biguint64_array_object(),
+        }
+        .clone()
+    }
+
+    /// Converts a value to the type of the elements, which is a BigInt or a number.
+    ///
+    /// The result can then be stored with [`TypedArrayKind::write`].
+    pub(crate) fn to_element(self, value: &Value, context: &mut Context) -> Result<Value> {
+        if self.is_bigint() {
+            Ok(value.to_bigint(context)?.into())
+        } else {
+            Ok(value.to_number(context)?.into())
+        }
+    }
+
+    /// Abstract operation `NumericToRawBytes ( type, value, isLittleEndian )`
+    ///
+    /// Stores `value`, which must have been converted by [`TypedArrayKind::to_element`], in
+    /// `bytes`, whose length must be the size of an element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
+    pub(crate) fn write(self, value: &Value, bytes: &mut [u8], little_endian: bool) {
+        let bigint = || {
+            value
+                .as_bigint()
+                .map_or(0, |bigint| bigint.to_u64_wrapping())
+        };
+        let number = value.as_number().unwrap_or(f64::NAN);
+        match self {
+            Self::Int8 => write_bytes!(bytes, f64_to_int32(number) as i8, little_endian),
+            Self::Uint8 => write_bytes!(bytes, f64_to_int32(number) as u8, little_endian),
+            Self::Uint8Clamped => write_bytes!(bytes, to_uint8_clamp(number), little_endian),
+            Self::Int16 => write_bytes!(bytes, f64_to_int32(number) as i16, little_endian),
+            Self::Uint16 => write_bytes!(bytes, f64_to_int32(number) as u16, little_endian),
+            Self::Int32 => write_bytes!(bytes, f64_to_int32(number), little_endian),
+            Self::Uint32 => write_bytes!(bytes, f64_to_int32(number) as u32, little_endian),
+            Self::Float32 => write_bytes!(bytes, number as f32, little_endian),
+            Self::Float64 => write_bytes!(bytes, number, little_endian),
+            Self::BigInt64 | Self::BigUint64 => write_bytes!(bytes, bigint(), little_endian),
+        }
+    }
+
+    /// Abstract operation `RawBytesToNumeric ( type, rawBytes, isLittleEndian )`
+    ///
+    /// Reads the element stored in `bytes`, whose length must be the size of an element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rawbytestonumeric
+    pub(crate) fn read(self, bytes: &[u8], little_endian: bool) -> Value {
+        match self {
+            Self::Int8 => i32::from(read_bytes!(i8, bytes, little_endian)).into(),
+            Self::Uint8 | Self::Uint8Clamped => {
+                i32::from(read_bytes!(u8, bytes, little_endian)).into()
+            }
+            Self::Int16 => i32::from(read_bytes!(i16, bytes, little_endian)).into(),
+            Self::Uint16 => i32::from(read_bytes!(u16, bytes, little_endian)).into(),
+            Self::Int32 => read_bytes!(i32, bytes, little_endian).into(),
+            Self::Uint32 => read_bytes!(u32, bytes, little_endian).into(),
+            Self::Float32 => f64::from(read_bytes!(f32, bytes, little_endian)).into(),
+            Self::Float64 => read_bytes!(f64, bytes, little_endian).into(),
+            Self::BigInt64 => BigInt::from(read_bytes!(i64, bytes, little_endian)).into(),
+            Self::BigUint64 => BigInt::from(read_bytes!(u64, bytes, little_endian)).into(),
+        }
+    }
+}
+
+/// Abstract operation `ToUint8Clamp ( argument )`, for a number.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-touint8clamp
+fn to_uint8_clamp(number: f64) -> u8 {
+    if number.is_nan() || number <= 0.0 {
+        return 0;
+    }
+    if number >= 255.0 {
+        return 255;
+    }
+
+    // Rounds half to even.
+    let floor = number.floor();
+    let rounded = if floor + 0.5 < number {
+        floor + 1.0
+    } else if number < floor + 0.5 || floor % 2.0 == 0.0 {
+        floor
+    } else {
+        floor + 1.0
+    };
+    rounded as u8
+}
@@ -0,0 +1,1722 @@
+//! This module implements the `%TypedArray%` intrinsic and the typed array constructors, like
+//! `Uint8Array` or `Float64Array`.
+//!
+//! A typed array is a view of an `ArrayBuffer` as a list of elements of a numeric type. All the
+//! typed array constructors inherit from the `%TypedArray%` intrinsic, which holds the methods
+//! shared by all the typed arrays.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-typedarray-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray
+
+use crate::{
+    builtins::{
+        array::{
+            array_iterator::{ArrayIterationKind, ArrayIterator},
+            merge_sort,
+        },
+        array_buffer::ArrayBuffer,
+        function::NativeFunction,
+        iterable::iterable_to_list,
+        BuiltIn,
+    },
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
+    property::Attribute,
+    value::same_value_zero,
+    BoaProfiler, Context, Result, Value,
+};
+use std::cmp::Ordering;
+
+pub mod kind;
+
+pub use kind::TypedArrayKind;
+
+#[cfg(test)]
+mod tests;
+
+/// The data of a typed array object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct TypedArray {
+    kind: TypedArrayKind,
+    /// The `ArrayBuffer` object holding the elements.
+    buffer: GcObject,
+    byte_offset: usize,
+    /// The number of elements, or `None` if the length tracks the length of a resizable buffer.
+    array_length: Option<usize>,
+}
+
+impl TypedArray {
+    /// Returns the type of the elements.
+    #[inline]
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+
+    /// Returns the `ArrayBuffer` object holding the elements.
+    #[inline]
+    pub fn buffer(&self) -> &GcObject {
+        &self.buffer
+    }
+
+    /// Returns the offset of the first element in the buffer, in bytes.
+    #[inline]
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// Returns the length of the buffer, in bytes.
+    fn buffer_byte_length(&self) -> usize {
+        self.buffer
+            .borrow()
+            .as_array_buffer()
+            .expect("the buffer of a typed array is an ArrayBuffer")
+            .byte_length()
+    }
+
+    /// Returns the number of elements, or `None` if the typed array is out of the bounds of its
+    /// buffer, which can happen after the buffer is shrunk.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-istypedarrayoutofbounds
+    pub fn length(&self) -> Option<usize> {
+        let buffer_byte_length = self.buffer_byte_length();
+        if self.byte_offset > buffer_byte_length {
+            return None;
+        }
+
+        let element_size = self.kind.element_size();
+        match self.array_length {
+            Some(length) if self.byte_offset + length * element_size > buffer_byte_length => None,
+            Some(length) => Some(length),
+            None => Some((buffer_byte_length - self.byte_offset) / element_size),
+        }
+    }
+
+    /// Returns the length of the typed array, in bytes, or `0` if it is out of bounds.
+    pub fn byte_length(&self) -> usize {
+        self.length().unwrap_or(0) * self.kind.element_size()
+    }
+
+    /// Returns `true` if `index` is the index of an element of the typed array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isvalidintegerindex
+    pub(crate) fn is_valid_index(&self, index: f64) -> bool {
+        if index.fract() != 0.0 || (index == 0.0 && index.is_sign_negative()) || index < 0.0 {
+            return false;
+        }
+        index < self.length().unwrap_or(0) as f64
+    }
+
+    /// Returns the element at `index`, or `undefined` if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraygetelement
+    pub(crate) fn get_element(&self, index: usize) -> Value {
+        if index >= self.length().unwrap_or(0) {
+            return Value::undefined();
+        }
+
+        let element_size = self.kind.element_size();
+        let start = self.byte_offset + index * element_size;
+        let buffer = self.buffer.borrow();
+        let data = buffer
+            .as_array_buffer()
+            .expect("the buffer of a typed array is an ArrayBuffer")
+            .data();
+        self.kind.read(
+            &data[start..start + element_size],
+            cfg!(target_endian = "little"),
+        )
+    }
+
+    /// Stores `value`, which must have been converted by [`TypedArrayKind::to_element`], at
+    /// `index`, if it is the index of an element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraysetelement
+    pub(crate) fn set_element(&self, index: usize, value: &Value) {
+        if index >= self.length().unwrap_or(0) {
+            return;
+        }
+
+        let element_size = self.kind.element_size();
+        let start = self.byte_offset + index * element_size;
+        let mut buffer = self.buffer.borrow_mut();
+        let data = buffer
+            .as_array_buffer_mut()
+            .expect("the buffer of a typed array is an ArrayBuffer")
+            .data_mut();
+        self.kind.write(
+            value,
+            &mut data[start..start + element_size],
+            cfg!(target_endian = "little"),
+        )
+    }
+
+    /// Returns all the elements of the typed array.
+    fn elements(&self) -> Vec<Value> {
+        (0..self.length().unwrap_or(0))
+            .map(|index| self.get_element(index))
+            .collect()
+    }
+
+    /// Returns the typed array `value`, throwing a `TypeError` if it isn't one.
+    fn as_typed_array(
+        value: &Value,
+        method: &str,
+        context: &mut Context,
+    ) -> Result<(GcObject, Self)> {
+        if let Value::Object(ref object) = value {
+            if let Some(array) = object.borrow().as_typed_array() {
+                return Ok((object.clone(), array.clone()));
+            }
+        }
+        Err(context.construct_type_error(format!(
+            "TypedArray.prototype.{}: 'this' is not a typed array",
+            method
+        )))
+    }
+
+    /// Abstract operation `ValidateTypedArray ( O )`
+    ///
+    /// Returns the typed array `this` with its length, throwing a `TypeError` if it isn't a
+    /// typed array or it is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validatetypedarray
+    fn validate(
+        this: &Value,
+        method: &str,
+        context: &mut Context,
+    ) -> Result<(GcObject, Self, usize)> {
+        let (object, array) = Self::as_typed_array(this, method, context)?;
+        match array.length() {
+            Some(length) => Ok((object, array, length)),
+            None => Err(context.construct_type_error(format!(
+                "TypedArray.prototype.{}: typed array is out of bounds",
+                method
+            ))),
+        }
+    }
+
+    /// Returns the callback argument of a method, throwing a `TypeError` if it isn't callable.
+    fn callback(args: &[Value], method: &str, context: &mut Context) -> Result<Value> {
+        match args.first() {
+            Some(Value::Object(ref object)) if object.is_callable() => Ok(object.clone().into()),
+            _ => Err(context.construct_type_error(format!(
+                "TypedArray.prototype.{}: callback is not a function",
+                method
+            ))),
+        }
+    }
+
+    /// Allocates an `ArrayBuffer` object large enough for `length` elements of type `kind`.
+    fn allocate_buffer(
+        kind: TypedArrayKind,
+        length: usize,
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        let byte_length = match length.checked_mul(kind.element_size()) {
+            Some(byte_length) => byte_length,
+            None => return Err(context.construct_range_error("TypedArray: invalid length")),
+        };
+        let buffer = ArrayBuffer::allocate(byte_length, None, context)?;
+        let prototype = context.standard_objects().array_buffer_object().prototype();
+        Ok(GcObject::new(Object::with_prototype(
+            prototype.into(),
+            ObjectData::ArrayBuffer(buffer),
+        )))
+    }
+
+    /// Abstract operation `TypedArrayCreateFromConstructor ( constructor, argumentList )`
+    ///
+    /// Constructs a typed array with `constructor`, and checks that it has at least as many
+    /// elements as requested when `args` is a length.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#typedarray-create
+    fn create(
+        constructor: &Value,
+        args: &[Value],
+        method: &str,
+        context: &mut Context,
+    ) -> Result<(GcObject, Self)> {
+        let new = match constructor {
+            Value::Object(ref constructor) if constructor.is_constructable() => {
+                constructor.construct(args, &constructor.clone().into(), context)?
+            }
+            _ => {
+                return Err(context.construct_type_error(format!(
+                    "TypedArray.{}: 'this' is not a constructor",
+                    method
+                )))
+            }
+        };
+
+        let (object, array, length) = Self::validate(&new, method, context)?;
+        if let [Value::Integer(_)] | [Value::Rational(_)] = args {
+            if (length as f64) < args[0].to_number(context)? {
+                return Err(context.construct_type_error(format!(
+                    "TypedArray.prototype.{}: constructor returned a typed array that is too small",
+                    method
+                )));
+            }
+        }
+        Ok((object, array))
+    }
+
+    /// Abstract operation `TypedArraySpeciesCreate ( exemplar, argumentList )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#typedarray-species-create
+    fn species_create(
+        exemplar: &GcObject,
+        kind: TypedArrayKind,
+        args: &[Value],
+        method: &str,
+        context: &mut Context,
+    ) -> Result<(GcObject, Self)> {
+        let default_constructor = kind.standard_constructor(context).constructor();
+        let constructor = exemplar.species_constructor(default_constructor.into(), context)?;
+        let (object, array) = Self::create(&constructor, args, method, context)?;
+        if array.kind.is_bigint() != kind.is_bigint() {
+            return Err(context.construct_type_error(format!(
+                "TypedArray.prototype.{}: constructor returned a typed array with a different content type",
+                method
+            )));
+        }
+        Ok((object, array))
+    }
+
+    /// The constructor of a typed array with elements of type `kind`.
+    ///
+    /// Depending on its first argument, it creates a typed array of a given length, copies a
+    /// typed array, an iterable or an array-like object, or creates a view of an `ArrayBuffer`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/TypedArray
+    fn construct(
+        this: &Value,
+        args: &[Value],
+        kind: TypedArrayKind,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let first = args.first().cloned().unwrap_or_default();
+        let first = if let Value::Object(ref object) = first {
+            object.clone()
+        } else {
+            let length = first.to_index(context)?;
+            let buffer = Self::allocate_buffer(kind, length, context)?;
+            this.set_data(ObjectData::TypedArray(Self {
+                kind,
+                buffer,
+                byte_offset: 0,
+                array_length: Some(length),
+            }));
+            return Ok(this.clone());
+        };
+
+        let source = first.borrow().as_typed_array().cloned();
+        let is_buffer = first.borrow().as_array_buffer().is_some();
+        let array = if let Some(source) = source {
+            Self::from_typed_array(&source, kind, context)?
+        } else if is_buffer {
+            Self::from_array_buffer(first, args, kind, context)?
+        } else {
+            let iterator = context.well_known_symbols().iterator_symbol();
+            let using_iterator = first.get(&iterator.into(), first.clone().into(), context)?;
+            let values = if using_iterator.is_null_or_undefined() {
+                let length = first
+                    .get(&"length".into(), first.clone().into(), context)?
+                    .to_length(context)?;
+                let mut values = Vec::with_capacity(length.min(1024));
+                for index in 0..length {
+                    values.push(first.get(&index.into(), first.clone().into(), context)?);
+                }
+                values
+            } else if using_iterator.is_function() {
+                iterable_to_list(context, first.into())?
+            } else {
+                return context.throw_type_error("TypedArray: @@iterator is not a function");
+            };
+
+            let buffer = Self::allocate_buffer(kind, values.len(), context)?;
+            let array = Self {
+                kind,
+                buffer,
+                byte_offset: 0,
+                array_length: Some(values.len()),
+            };
+            for (index, value) in values.iter().enumerate() {
+                let value = kind.to_element(value, context)?;
+                array.set_element(index, &value);
+            }
+            array
+        };
+
+        this.set_data(ObjectData::TypedArray(array));
+        Ok(this.clone())
+    }
+
+    /// Abstract operation `InitializeTypedArrayFromTypedArray ( O, srcArray )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromtypedarray
+    fn from_typed_array(
+        source: &Self,
+        kind: TypedArrayKind,
+        context: &mut Context,
+    ) -> Result<Self> {
+        let length = match source.length() {
+            Some(length) => length,
+            None => return Err(context.construct_type_error("TypedArray: source is out of bounds")),
+        };
+        if source.kind.is_bigint() != kind.is_bigint() {
+            return Err(context
+                .construct_type_error("TypedArray: cannot mix BigInt and Number typed arrays"));
+        }
+
+        let buffer = Self::allocate_buffer(kind, length, context)?;
+        let array = Self {
+            kind,
+            buffer,
+            byte_offset: 0,
+            array_length: Some(length),
+        };
+        for index in 0..length {
+            array.set_element(index, &source.get_element(index));
+        }
+        Ok(array)
+    }
+
+    /// Abstract operation `InitializeTypedArrayFromArrayBuffer ( O, buffer, byteOffset, length )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromarraybuffer
+    fn from_array_buffer(
+        buffer: GcObject,
+        args: &[Value],
+        kind: TypedArrayKind,
+        context: &mut Context,
+    ) -> Result<Self> {
+        let element_size = kind.element_size();
+        let offset = args.get(1).cloned().unwrap_or_default().to_index(context)?;
+        if offset % element_size != 0 {
+            return Err(context.construct_range_error(format!(
+                "TypedArray: start offset of {} should be a multiple of {}",
+                kind.name(),
+                element_size
+            )));
+        }
+        let length = match args.get(2) {
+            Some(length) if !length.is_undefined() => Some(length.to_index(context)?),
+            _ => None,
+        };
+
+        let (buffer_byte_length, resizable) = {
+            let buffer = buffer.borrow();
+            let buffer = buffer
+                .as_array_buffer()
+                .expect("checked to be an ArrayBuffer");
+            (buffer.byte_length(), buffer.is_resizable())
+        };
+
+        let array_length = match length {
+            None if resizable => {
+                if offset > buffer_byte_length {
+                    return Err(context.construct_range_error(
+                        "TypedArray: start offset is outside the bounds of the buffer",
+                    ));
+                }
+                None
+            }
+            None => {
+                if buffer_byte_length % element_size != 0 {
+                    return Err(context.construct_range_error(format!(
+                        "TypedArray: byte length of {} should be a multiple of {}",
+                        kind.name(),
+                        element_size
+                    )));
+                }
+                if offset > buffer_byte_length {
+                    return Err(context.construct_range_error(
+                        "TypedArray: start offset is outside the bounds of the buffer",
+                    ));
+                }
+                Some((buffer_byte_length - offset) / element_size)
+            }
+            Some(length) => {
+                let fits = length
+                    .checked_mul(element_size)
+                    .and_then(|byte_length| byte_length.checked_add(offset))
+                    .is_some_and(|end| end <= buffer_byte_length);
+                if !fits {
+                    return Err(context.construct_range_error(
+                        "TypedArray: length is outside the bounds of the buffer",
+                    ));
+                }
+                Some(length)
+            }
+        };
+
+        Ok(Self {
+            kind,
+            buffer,
+            byte_offset: offset,
+            array_length,
+        })
+    }
+
+    /// The `%TypedArray%` intrinsic, which can't be constructed directly.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%
+    fn intrinsic_constructor(_: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        context.throw_type_error("TypedArray: abstract class TypedArray cannot be constructed")
+    }
+
+    /// `%TypedArray%.from( source [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// Creates a typed array with the elements of an iterable or array-like object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/from
+    pub(crate) fn from(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if !this
+            .as_object()
+            .is_some_and(|object| object.is_constructable())
+        {
+            return context.throw_type_error("TypedArray.from: 'this' is not a constructor");
+        }
+        let map_fn = match args.get(1) {
+            None | Some(Value::Undefined) => None,
+            Some(Value::Object(ref object)) if object.is_callable() => Some(object.clone()),
+            Some(_) => return context.throw_type_error("TypedArray.from: mapFn is not a function"),
+        };
+        let this_arg = args.get(2).cloned().unwrap_or_default();
+
+        let source = args.first().cloned().unwrap_or_default();
+        let iterator = context.well_known_symbols().iterator_symbol();
+        // Primitives, like strings, are iterable through their prototype.
+        let using_iterator = Value::from(source.to_object(context)?)
+            .get_field_with_receiver(iterator, &source, context)?;
+        let values = if using_iterator.is_null_or_undefined() {
+            let source = source.to_object(context)?;
+            let length = source
+                .get(&"length".into(), source.clone().into(), context)?
+                .to_length(context)?;
+            let mut values = Vec::with_capacity(length.min(1024));
+            for index in 0..length {
+                values.push(source.get(&index.into(), source.clone().into(), context)?);
+            }
+            values
+        } else if using_iterator.is_function() {
+            iterable_to_list(context, source)?
+        } else {
+            return context.throw_type_error("TypedArray.from: @@iterator is not a function");
+        };
+
+        let (mut target, _) = Self::create(this, &[values.len().into()], "from", context)?;
+        for (index, value) in values.into_iter().enumerate() {
+            let value = match map_fn {
+                Some(ref map_fn) => map_fn.call(&this_arg, &[value, index.into()], context)?,
+                None => value,
+            };
+            target.set(index.into(), value, target.clone().into(), context)?;
+        }
+        Ok(target.into())
+    }
+
+    /// `%TypedArray%.of( ...items )`
+    ///
+    /// Creates a typed array with the arguments as elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/of
+    pub(crate) fn of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (mut target, _) = Self::create(this, &[args.len().into()], "of", context)?;
+        for (index, value) in args.iter().enumerate() {
+            target.set(index.into(), value.clone(), target.clone().into(), context)?;
+        }
+        Ok(target.into())
+    }
+
+    /// `get %TypedArray% [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/@@species
+    pub(crate) fn species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `get %TypedArray%.prototype.buffer`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.buffer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/buffer
+    pub(crate) fn buffer_getter(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array) = Self::as_typed_array(this, "buffer", context)?;
+        Ok(array.buffer.clone().into())
+    }
+
+    /// `get %TypedArray%.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/byteLength
+    pub(crate) fn byte_length_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (_, array) = Self::as_typed_array(this, "byteLength", context)?;
+        Ok(array.byte_length().into())
+    }
+
+    /// `get %TypedArray%.prototype.byteOffset`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.byteoffset
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/byteOffset
+    pub(crate) fn byte_offset_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (_, array) = Self::as_typed_array(this, "byteOffset", context)?;
+        if array.length().is_none() {
+            return Ok(0.into());
+        }
+        Ok(array.byte_offset.into())
+    }
+
+    /// `get %TypedArray%.prototype.length`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.length
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/length
+    pub(crate) fn length_getter(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array) = Self::as_typed_array(this, "length", context)?;
+        Ok(array.length().unwrap_or(0).into())
+    }
+
+    /// `get %TypedArray%.prototype [ @@toStringTag ]`
+    ///
+    /// Returns the name of the constructor of a typed array, or `undefined` for other values.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-@@tostringtag
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/@@toStringTag
+    pub(crate) fn to_string_tag(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(match this {
+            Value::Object(ref object) => object
+                .borrow()
+                .as_typed_array()
+                .map_or_else(Value::undefined, |array| array.kind.name().into()),
+            _ => Value::undefined(),
+        })
+    }
+
+    /// `%TypedArray%.prototype.at( index )`
+    ///
+    /// Returns the element at `index`, counting from the end if it is negative.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/at
+    pub(crate) fn at(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "at", context)?;
+        let relative = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_integer(context)?;
+        let index = if relative >= 0.0 {
+            relative
+        } else {
+            length as f64 + relative
+        };
+        if index < 0.0 || index >= length as f64 {
+            return Ok(Value::undefined());
+        }
+        Ok(array.get_element(index as usize))
+    }
+
+    /// `%TypedArray%.prototype.copyWithin( target, start [ , end ] )`
+    ///
+    /// Copies the elements from `start` to `end` to the position `target`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.copywithin
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/copyWithin
+    pub(crate) fn copy_within(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "copyWithin", context)?;
+        let to = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        let from = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        let end = args.get(2).cloned().unwrap_or_default();
+        let last = if end.is_undefined() {
+            length
+        } else {
+            end.to_relative_index(length, context)?
+        };
+
+        let count = last.saturating_sub(from).min(length - to);
+        if count > 0 {
+            // The conversions of the arguments may have shrunk the buffer.
+            let length = match array.length() {
+                Some(length) => length,
+                None => {
+                    return context.throw_type_error(
+                        "TypedArray.prototype.copyWithin: typed array is out of bounds",
+                    )
+                }
+            };
+            let count = count.min(length.saturating_sub(from.max(to)));
+
+            let element_size = array.kind.element_size();
+            let from = array.byte_offset + from * element_size;
+            let to = array.byte_offset + to * element_size;
+            let mut buffer = array.buffer.borrow_mut();
+            let data = buffer
+                .as_array_buffer_mut()
+                .expect("the buffer of a typed array is an ArrayBuffer")
+                .data_mut();
+            data.copy_within(from..from + count * element_size, to);
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.entries( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/entries
+    pub(crate) fn entries(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::validate(this, "entries", context)?;
+        ArrayIterator::create_array_iterator(context, this.clone(), ArrayIterationKind::KeyAndValue)
+    }
+
+    /// `%TypedArray%.prototype.keys( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/keys
+    pub(crate) fn keys(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::validate(this, "keys", context)?;
+        ArrayIterator::create_array_iterator(context, this.clone(), ArrayIterationKind::Key)
+    }
+
+    /// `%TypedArray%.prototype.values( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/values
+    pub(crate) fn values(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::validate(this, "values", context)?;
+        ArrayIterator::create_array_iterator(context, this.clone(), ArrayIterationKind::Value)
+    }
+
+    /// `%TypedArray%.prototype.every( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.every
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/every
+    pub(crate) fn every(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "every", context)?;
+        let callback = Self::callback(args, "every", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..length {
+            let arguments = [array.get_element(index), index.into(), this.clone()];
+            if !context.call(&callback, &this_arg, &arguments)?.to_boolean() {
+                return Ok(false.into());
+            }
+        }
+        Ok(true.into())
+    }
+
+    /// `%TypedArray%.prototype.some( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.some
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/some
+    pub(crate) fn some(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "some", context)?;
+        let callback = Self::callback(args, "some", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..length {
+            let arguments = [array.get_element(index), index.into(), this.clone()];
+            if context.call(&callback, &this_arg, &arguments)?.to_boolean() {
+                return Ok(true.into());
+            }
+        }
+        Ok(false.into())
+    }
+
+    /// `%TypedArray%.prototype.forEach( callbackfn [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/forEach
+    pub(crate) fn for_each(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "forEach", context)?;
+        let callback = Self::callback(args, "forEach", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        for index in 0..length {
+            let arguments = [array.get_element(index), index.into(), this.clone()];
+            context.call(&callback, &this_arg, &arguments)?;
+        }
+        Ok(Value::undefined())
+    }
+
+    /// Calls the callback of `find`-like methods on the elements at `indices`, and returns the
+    /// first element for which it returns `true` with its index.
+    fn find_element(
+        this: &Value,
+        args: &[Value],
+        method: &str,
+        reverse: bool,
+        context: &mut Context,
+    ) -> Result<Option<(usize, Value)>> {
+        let (_, array, length) = Self::validate(this, method, context)?;
+        let callback = Self::callback(args, method, context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+        let indices: Box<dyn Iterator<Item = usize>> = if reverse {
+            Box::new((0..length).rev())
+        } else {
+            Box::new(0..length)
+        };
+        for index in indices {
+            let element = array.get_element(index);
+            let arguments = [element.clone(), index.into(), this.clone()];
+            if context.call(&callback, &this_arg, &arguments)?.to_boolean() {
+                return Ok(Some((index, element)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// `%TypedArray%.prototype.find( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.find
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/find
+    pub(crate) fn find(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let found = Self::find_element(this, args, "find", false, context)?;
+        Ok(found.map_or_else(Value::undefined, |(_, element)| element))
+    }
+
+    /// `%TypedArray%.prototype.findIndex( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/findIndex
+    pub(crate) fn find_index(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let found = Self::find_element(this, args, "findIndex", false, context)?;
+        Ok(found.map_or_else(|| (-1).into(), |(index, _)| index.into()))
+    }
+
+    /// `%TypedArray%.prototype.findLast( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlast
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/findLast
+    pub(crate) fn find_last(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let found = Self::find_element(this, args, "findLast", true, context)?;
+        Ok(found.map_or_else(Value::undefined, |(_, element)| element))
+    }
+
+    /// `%TypedArray%.prototype.findLastIndex( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlastindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/findLastIndex
+    pub(crate) fn find_last_index(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let found = Self::find_element(this, args, "findLastIndex", true, context)?;
+        Ok(found.map_or_else(|| (-1).into(), |(index, _)| index.into()))
+    }
+
+    /// `%TypedArray%.prototype.fill( value [ , start [ , end ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.fill
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/fill
+    pub(crate) fn fill(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "fill", context)?;
+        let value = array
+            .kind
+            .to_element(&args.first().cloned().unwrap_or_default(), context)?;
+        let start = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        let end = args.get(2).cloned().unwrap_or_default();
+        let end = if end.is_undefined() {
+            length
+        } else {
+            end.to_relative_index(length, context)?
+        };
+
+        // The conversions of the arguments may have shrunk the buffer.
+        let length = match array.length() {
+            Some(length) => length,
+            None => {
+                return context
+                    .throw_type_error("TypedArray.prototype.fill: typed array is out of bounds")
+            }
+        };
+        for index in start..end.min(length) {
+            array.set_element(index, &value);
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.filter( callbackfn [ , thisArg ] )`
+    ///
+    /// Returns a typed array, created by the species constructor, with the elements for which
+    /// the callback returns `true`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.filter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/filter
+    pub(crate) fn filter(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (object, array, length) = Self::validate(this, "filter", context)?;
+        let callback = Self::callback(args, "filter", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        let mut kept = Vec::new();
+        for index in 0..length {
+            let element = array.get_element(index);
+            let arguments = [element.clone(), index.into(), this.clone()];
+            if context.call(&callback, &this_arg, &arguments)?.to_boolean() {
+                kept.push(element);
+            }
+        }
+
+        let (new, new_array) =
+            Self::species_create(&object, array.kind, &[kept.len().into()], "filter", context)?;
+        for (index, element) in kept.iter().enumerate() {
+            new_array.set_element(index, element);
+        }
+        Ok(new.into())
+    }
+
+    /// `%TypedArray%.prototype.map( callbackfn [ , thisArg ] )`
+    ///
+    /// Returns a typed array, created by the species constructor, with the results of the
+    /// callback for each element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.map
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/map
+    pub(crate) fn map(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (object, array, length) = Self::validate(this, "map", context)?;
+        let callback = Self::callback(args, "map", context)?;
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        let (mut new, _) =
+            Self::species_create(&object, array.kind, &[length.into()], "map", context)?;
+        for index in 0..length {
+            let arguments = [array.get_element(index), index.into(), this.clone()];
+            let mapped = context.call(&callback, &this_arg, &arguments)?;
+            new.set(index.into(), mapped, new.clone().into(), context)?;
+        }
+        Ok(new.into())
+    }
+
+    /// `%TypedArray%.prototype.includes( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.includes
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/includes
+    pub(crate) fn includes(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "includes", context)?;
+        if length == 0 {
+            return Ok(false.into());
+        }
+        let search = args.first().cloned().unwrap_or_default();
+        let start = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        for index in start..length {
+            if same_value_zero(&array.get_element(index), &search) {
+                return Ok(true.into());
+            }
+        }
+        Ok(false.into())
+    }
+
+    /// `%TypedArray%.prototype.indexOf( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.indexof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/indexOf
+    pub(crate) fn index_of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "indexOf", context)?;
+        if length == 0 {
+            return Ok((-1).into());
+        }
+        let search = args.first().cloned().unwrap_or_default();
+        let start = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        for index in start..length.min(array.length().unwrap_or(0)) {
+            if array.get_element(index).strict_equals(&search) {
+                return Ok(index.into());
+            }
+        }
+        Ok((-1).into())
+    }
+
+    /// `%TypedArray%.prototype.lastIndexOf( searchElement [ , fromIndex ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.lastindexof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/lastIndexOf
+    pub(crate) fn last_index_of(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "lastIndexOf", context)?;
+        if length == 0 {
+            return Ok((-1).into());
+        }
+        let search = args.first().cloned().unwrap_or_default();
+        let from = match args.get(1) {
+            Some(from) => from.to_integer(context)?,
+            None => length as f64 - 1.0,
+        };
+        let last = if from >= 0.0 {
+            from.min(length as f64 - 1.0)
+        } else {
+            length as f64 + from
+        };
+        if last < 0.0 {
+            return Ok((-1).into());
+        }
+        for index in (0..=last as usize).rev() {
+            if index < array.length().unwrap_or(0)
+                && array.get_element(index).strict_equals(&search)
+            {
+                return Ok(index.into());
+            }
+        }
+        Ok((-1).into())
+    }
+
+    /// `%TypedArray%.prototype.join( separator )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.join
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/join
+    pub(crate) fn join(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "join", context)?;
+        let separator = match args.first() {
+            None | Some(Value::Undefined) => ",".into(),
+            Some(separator) => separator.to_string(context)?,
+        };
+
+        let mut result = String::new();
+        for index in 0..length {
+            if index > 0 {
+                result.push_str(&separator);
+            }
+            let element = array.get_element(index);
+            if !element.is_undefined() {
+                result.push_str(&element.to_string(context)?);
+            }
+        }
+        Ok(result.into())
+    }
+
+    /// `%TypedArray%.prototype.toLocaleString( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/toLocaleString
+    pub(crate) fn to_locale_string(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "toLocaleString", context)?;
+        let mut result = String::new();
+        for index in 0..length {
+            if index > 0 {
+                result.push(',');
+            }
+            let element = array.get_element(index);
+            if !element.is_undefined() {
+                let method = element.get_field("toLocaleString", context)?;
+                let string = context.call(&method, &element, &[])?;
+                result.push_str(&string.to_string(context)?);
+            }
+        }
+        Ok(result.into())
+    }
+
+    /// Calls the callback of `reduce` or `reduceRight` on the elements at `indices`.
+    fn reduce_elements(
+        this: &Value,
+        args: &[Value],
+        method: &str,
+        reverse: bool,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, method, context)?;
+        let callback = Self::callback(args, method, context)?;
+
+        let mut indices: Box<dyn Iterator<Item = usize>> = if reverse {
+            Box::new((0..length).rev())
+        } else {
+            Box::new(0..length)
+        };
+        let mut accumulator = match args.get(1) {
+            Some(initial) => initial.clone(),
+            None => match indices.next() {
+                Some(index) => array.get_element(index),
+                None => {
+                    return context.throw_type_error(format!(
+                        "TypedArray.prototype.{}: reduce of empty array with no initial value",
+                        method
+                    ))
+                }
+            },
+        };
+        for index in indices {
+            let arguments = [
+                accumulator,
+                array.get_element(index),
+                index.into(),
+                this.clone(),
+            ];
+            accumulator = context.call(&callback, &Value::undefined(), &arguments)?;
+        }
+        Ok(accumulator)
+    }
+
+    /// `%TypedArray%.prototype.reduce( callbackfn [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduce
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reduce
+    pub(crate) fn reduce(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::reduce_elements(this, args, "reduce", false, context)
+    }
+
+    /// `%TypedArray%.prototype.reduceRight( callbackfn [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduceright
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reduceRight
+    pub(crate) fn reduce_right(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        Self::reduce_elements(this, args, "reduceRight", true, context)
+    }
+
+    /// `%TypedArray%.prototype.reverse( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reverse
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/reverse
+    pub(crate) fn reverse(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array, length) = Self::validate(this, "reverse", context)?;
+        for lower in 0..length / 2 {
+            let upper = length - 1 - lower;
+            let lower_value = array.get_element(lower);
+            let upper_value = array.get_element(upper);
+            array.set_element(lower, &upper_value);
+            array.set_element(upper, &lower_value);
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.set( source [ , offset ] )`
+    ///
+    /// Stores the elements of a typed array or an array-like object, starting at `offset`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/set
+    pub(crate) fn set(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (_, array) = Self::as_typed_array(this, "set", context)?;
+        let offset = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer(context)?;
+        if offset < 0.0 {
+            return context.throw_range_error("TypedArray.prototype.set: offset must be >= 0");
+        }
+        let length = match array.length() {
+            Some(length) => length,
+            None => {
+                return context
+                    .throw_type_error("TypedArray.prototype.set: typed array is out of bounds")
+            }
+        };
+
+        let source = args.first().cloned().unwrap_or_default();
+        let source_array = match source {
+            Value::Object(ref object) => object.borrow().as_typed_array().cloned(),
+            _ => None,
+        };
+        if let Some(source_array) = source_array {
+            // SetTypedArrayFromTypedArray
+            let source_length = match source_array.length() {
+                Some(length) => length,
+                None => {
+                    return context
+                        .throw_type_error("TypedArray.prototype.set: source is out of bounds")
+                }
+            };
+            if source_array.kind.is_bigint() != array.kind.is_bigint() {
+                return context.throw_type_error(
+                    "TypedArray.prototype.set: cannot mix BigInt and Number typed arrays",
+                );
+            }
+            if source_length as f64 + offset > length as f64 {
+                return context.throw_range_error("TypedArray.prototype.set: source is too large");
+            }
+
+            // The elements are read first, in case both arrays share a buffer.
+            let offset = offset as usize;
+            for (index, element) in source_array.elements().iter().enumerate() {
+                array.set_element(offset + index, element);
+            }
+        } else {
+            // SetTypedArrayFromArrayLike
+            let source = source.to_object(context)?;
+            let source_length = source
+                .get(&"length".into(), source.clone().into(), context)?
+                .to_length(context)?;
+            if source_length as f64 + offset > length as f64 {
+                return context.throw_range_error("TypedArray.prototype.set: source is too large");
+            }
+
+            let offset = offset as usize;
+            for index in 0..source_length {
+                let value = source.get(&index.into(), source.clone().into(), context)?;
+                let value = array.kind.to_element(&value, context)?;
+                array.set_element(offset + index, &value);
+            }
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `%TypedArray%.prototype.slice( start, end )`
+    ///
+    /// Returns a typed array, created by the species constructor, with a copy of the elements
+    /// from `start` to `end`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/slice
+    pub(crate) fn slice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (object, array, length) = Self::validate(this, "slice", context)?;
+        let start = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        let end = args.get(1).cloned().unwrap_or_default();
+        let end = if end.is_undefined() {
+            length
+        } else {
+            end.to_relative_index(length, context)?
+        };
+        let count = end.saturating_sub(start);
+
+        let (new, new_array) =
+            Self::species_create(&object, array.kind, &[count.into()], "slice", context)?;
+        if count > 0 {
+            // The species constructor may have shrunk the buffer.
+            let length = match array.length() {
+                Some(length) => length,
+                None => {
+                    return context.throw_type_error(
+                        "TypedArray.prototype.slice: typed array is out of bounds",
+                    )
+                }
+            };
+            for (target, index) in (start..end.min(length)).enumerate() {
+                new_array.set_element(target, &array.get_element(index));
+            }
+        }
+        Ok(new.into())
+    }
+
+    /// `%TypedArray%.prototype.sort( comparefn )`
+    ///
+    /// Sorts the elements in place, in numeric order by default.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.sort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/sort
+    pub(crate) fn sort(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let compare_fn = match args.first() {
+            None | Some(Value::Undefined) => None,
+            Some(Value::Object(ref object)) if object.is_callable() => Some(object.clone()),
+            Some(_) => {
+                return context
+                    .throw_type_error("TypedArray.prototype.sort: comparefn is not a function")
+            }
+        };
+        let (_, array, _) = Self::validate(this, "sort", context)?;
+
+        let mut elements = array.elements();
+        merge_sort(&mut elements, &mut |x, y| match compare_fn {
+            Some(ref compare_fn) => {
+                let order = compare_fn
+                    .call(&Value::undefined(), &[x.clone(), y.clone()], context)?
+                    .to_number(context)?;
+                Ok(order.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+            }
+            None => Ok(compare_numeric(x, y)),
+        })?;
+        for (index, element) in elements.iter().enumerate() {
+            array.set_element(index, element);
+        }
+        Ok(this.clone())
+    }
+
+    /// `%TypedArray%.prototype.subarray( begin, end )`
+    ///
+    /// Returns a typed array, created by the species constructor, that views the elements
+    /// from `begin` to `end` of the same buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.subarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/subarray
+    pub(crate) fn subarray(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (object, array) = Self::as_typed_array(this, "subarray", context)?;
+        let length = array.length().unwrap_or(0);
+        let begin = args
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .to_relative_index(length, context)?;
+        let begin_byte_offset = array.byte_offset + begin * array.kind.element_size();
+
+        let end = args.get(1).cloned().unwrap_or_default();
+        let mut arguments = vec![array.buffer.clone().into(), begin_byte_offset.into()];
+        if array.array_length.is_some() || !end.is_undefined() {
+            let end = if end.is_undefined() {
+                length
+            } else {
+                end.to_relative_index(length, context)?
+            };
+            arguments.push(end.saturating_sub(begin).into());
+        }
+
+        let (new, _) = Self::species_create(&object, array.kind, &arguments, "subarray", context)?;
+        Ok(new.into())
+    }
+
+    /// Initializes the `%TypedArray%` intrinsic, which isn't bound to a global.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%-intrinsic-object
+    pub(crate) fn init_intrinsic(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event("TypedArray", "init");
+
+        let species_symbol = context.well_known_symbols().species_symbol();
+        let iterator_symbol = context.well_known_symbols().iterator_symbol();
+        let to_string_tag_symbol = context.well_known_symbols().to_string_tag_symbol();
+
+        let getter = |context: &mut Context, function: NativeFunction, name: &str| {
+            FunctionBuilder::new(context, function)
+                .name(name)
+                .constructable(false)
+                .build()
+        };
+        let species = getter(context, Self::species, "get [Symbol.species]");
+        let buffer = getter(context, Self::buffer_getter, "get buffer");
+        let byte_length = getter(context, Self::byte_length_getter, "get byteLength");
+        let byte_offset = getter(context, Self::byte_offset_getter, "get byteOffset");
+        let length = getter(context, Self::length_getter, "get length");
+        let to_string_tag = getter(context, Self::to_string_tag, "get [Symbol.toStringTag]");
+        let values = FunctionBuilder::new(context, Self::values)
+            .name("values")
+            .length(0)
+            .constructable(false)
+            .build();
+
+        // `%TypedArray%.prototype.toString` is the same function object as
+        // `Array.prototype.toString`.
+        let array_prototype: Value = context.standard_objects().array_object().prototype().into();
+        let to_string = array_prototype
+            .get_field("toString", context)
+            .expect("Array.prototype.toString is a data property");
+
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        ConstructorBuilder::with_standard_object(
+            context,
+            Self::intrinsic_constructor,
+            context.standard_objects().typed_array_object().clone(),
+        )
+        .name("TypedArray")
+        .length(0)
+        .static_method(Self::from, "from", 1)
+        .static_method(Self::of, "of", 0)
+        .static_accessor(species_symbol, Some(species), None, Attribute::CONFIGURABLE)
+        .accessor("buffer", Some(buffer), None, Attribute::CONFIGURABLE)
+        .accessor(
+            "byteLength",
+            Some(byte_length),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "byteOffset",
+            Some(byte_offset),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor("length", Some(length), None, Attribute::CONFIGURABLE)
+        .accessor(
+            to_string_tag_symbol,
+            Some(to_string_tag),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::at, "at", 1)
+        .method(Self::copy_within, "copyWithin", 2)
+        .method(Self::entries, "entries", 0)
+        .method(Self::every, "every", 1)
+        .method(Self::fill, "fill", 1)
+        .method(Self::filter, "filter", 1)
+        .method(Self::find, "find", 1)
+        .method(Self::find_index, "findIndex", 1)
+        .method(Self::find_last, "findLast", 1)
+        .method(Self::find_last_index, "findLastIndex", 1)
+        .method(Self::for_each, "forEach", 1)
+        .method(Self::includes, "includes", 1)
+        .method(Self::index_of, "indexOf", 1)
+        .method(Self::join, "join", 1)
+        .method(Self::keys, "keys", 0)
+        .method(Self::last_index_of, "lastIndexOf", 1)
+        .method(Self::map, "map", 1)
+        .method(Self::reduce, "reduce", 1)
+        .method(Self::reduce_right, "reduceRight", 1)
+        .method(Self::reverse, "reverse", 0)
+        .method(Self::set, "set", 1)
+        .method(Self::slice, "slice", 2)
+        .method(Self::some, "some", 1)
+        .method(Self::sort, "sort", 1)
+        .method(Self::subarray, "subarray", 2)
+        .method(Self::to_locale_string, "toLocaleString", 0)
+        .property("values", values.clone(), attribute)
+        .property(iterator_symbol, values, attribute)
+        .property("toString", to_string, attribute)
+        .callable(false)
+        .build();
+    }
+
+    /// Initializes the typed array constructor for elements of type `kind`, which inherits
+    /// from `%TypedArray%`.
+    fn init_constructor(
+        context: &mut Context,
+        kind: TypedArrayKind,
+        constructor: NativeFunction,
+    ) -> GcObject {
+        let intrinsic = context.standard_objects().typed_array_object().clone();
+        let standard_constructor = kind.standard_constructor(context);
+
+        let bytes_per_element_attribute =
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        let mut object =
+            ConstructorBuilder::with_standard_object(context, constructor, standard_constructor)
+                .name(kind.name())
+                .length(3)
+                .static_property(
+                    "BYTES_PER_ELEMENT",
+                    kind.element_size(),
+                    bytes_per_element_attribute,
+                )
+                .property(
+                    "BYTES_PER_ELEMENT",
+                    kind.element_size(),
+                    bytes_per_element_attribute,
+                )
+                .callable(false)
+                .inherit(intrinsic.prototype().into())
+                .build();
+        object.set_prototype_instance(intrinsic.constructor().into());
+        object
+    }
+}
+
+/// Compares two elements of a typed array in numeric order, where `-0` comes before `+0` and
+/// `NaN` comes last.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-typedarray-sort-compare
+fn compare_numeric(x: &Value, y: &Value) -> Ordering {
+    if let (Some(x), Some(y)) = (x.as_bigint(), y.as_bigint()) {
+        return x.cmp(y);
+    }
+
+    let x = x.as_number().unwrap_or(f64::NAN);
+    let y = y.as_number().unwrap_or(f64::NAN);
+    match (x.is_nan(), y.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) if x == 0.0 && y == 0.0 => y.is_sign_negative().cmp(&x.is_sign_negative()),
+        (false, false) => x.partial_cmp(&y).expect("neither number is NaN"),
+    }
+}
+
+/// Defines the constructor of the typed arrays with elements of type `$kind`.
+macro_rules! typed_array_constructor {
+    ($(#[$attr:meta])* $name:ident, $kind:expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy)]
+        pub(crate) struct $name;
+
+        impl $name {
+            fn constructor(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+                TypedArray::construct(this, args, $kind, context)
+            }
+        }
+
+        impl BuiltIn for $name {
+            const NAME: &'static str = stringify!($name);
+
+            fn attribute() -> Attribute {
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+            }
+
+            fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+                let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+                let object = TypedArray::init_constructor(context, $kind, Self::constructor);
+                (Self::NAME, object.into(), Self::attribute())
+            }
+        }
+    };
+}
+
+typed_array_constructor!(
+    /// The `Int8Array` constructor, for arrays of 8-bit signed integers.
+    Int8Array,
+    TypedArrayKind::Int8
+);
+typed_array_constructor!(
+    /// The `Uint8Array` constructor, for arrays of 8-bit unsigned integers.
+    Uint8Array,
+    TypedArrayKind::Uint8
+);
+typed_array_constructor!(
+    /// The `Uint8ClampedArray` constructor, for arrays of 8-bit unsigned integers where the
+    /// stored values are clamped to `0..=255`.
+    Uint8ClampedArray,
+    TypedArrayKind::Uint8Clamped
+);
+typed_array_constructor!(
+    /// The `Int16Array` constructor, for arrays of 16-bit signed integers.
+    Int16Array,
+    TypedArrayKind::Int16
+);
+typed_array_constructor!(
+    /// The `Uint16Array` constructor, for arrays of 16-bit unsigned integers.
+    Uint16Array,
+    TypedArrayKind::Uint16
+);
+typed_array_constructor!(
+    /// The `Int32Array` constructor, for arrays of 32-bit signed integers.
+    Int32Array,
+    TypedArrayKind::Int32
+);
+typed_array_constructor!(
+    /// The `Uint32Array` constructor, for arrays of 32-bit unsigned integers.
+    Uint32Array,
+    TypedArrayKind::Uint32
+);
+typed_array_constructor!(
+    /// The `Float32Array` constructor, for arrays of 32-bit floating point numbers.
+    Float32Array,
+    TypedArrayKind::Float32
+);
+typed_array_constructor!(
+    /// The `Float64Array` constructor, for arrays of 64-bit floating point numbers.
+    Float64Array,
+    TypedArrayKind::Float64
+);
+typed_array_constructor!(
+    /// The `BigInt64Array` constructor, for arrays of 64-bit signed integers stored as BigInts.
+    BigInt64Array,
+    TypedArrayKind::BigInt64
+);
+typed_array_constructor!(
+    /// The `BigUint64Array` constructor, for arrays of 64-bit unsigned integers stored as
+    /// BigInts.
+    BigUint64Array,
+    TypedArrayKind::BigUint64
+);
@@ -0,0 +1,373 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructors() {
+    let mut engine = Context::new();
+    assert_eq!(forward(&mut engine, "new Int8Array(3).length"), "3");
+    assert_eq!(
+        forward(&mut engine, "new Uint16Array([1, 2, 3]).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Float32Array(new Set([0.5, 1.5])).join()"),
+        "\"0.5,1.5\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Int16Array({ length: 2, 0: 7 }).join()"),
+        "\"7,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Uint8Array(new Int16Array([-1, 256])).join()"
+        ),
+        "\"255,0\""
+    );
+    assert_eq!(forward(&mut engine, "Uint32Array.BYTES_PER_ELEMENT"), "4");
+    assert_eq!(
+        forward(&mut engine, "new Float64Array(1).BYTES_PER_ELEMENT"),
+        "8"
+    );
+    assert_eq!(forward(&mut engine, "Uint8Array.length"), "3");
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array(new BigInt64Array(1))"),
+        "Uncaught \"TypeError\": \"TypedArray: cannot mix BigInt and Number typed arrays\""
+    );
+}
+
+#[test]
+fn views_of_array_buffers() {
+    let mut engine = Context::new();
+    let init = r#"
+        let buffer = new ArrayBuffer(8);
+        let bytes = new Uint8Array(buffer);
+        let words = new Uint16Array(buffer, 2, 2);
+        words[0] = 0xffff;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(&mut engine, "bytes.join()"),
+        "\"0,0,255,255,0,0,0,0\""
+    );
+    assert_eq!(forward(&mut engine, "words.byteOffset"), "2");
+    assert_eq!(forward(&mut engine, "words.byteLength"), "4");
+    assert_eq!(forward(&mut engine, "words.buffer === buffer"), "true");
+    assert_eq!(
+        forward(&mut engine, "new Uint16Array(buffer, 1)"),
+        "Uncaught \"RangeError\": \"TypedArray: start offset of Uint16Array should be a multiple of 2\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Uint32Array(buffer, 4, 2)"),
+        "Uncaught \"RangeError\": \"TypedArray: length is outside the bounds of the buffer\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Float64Array(new ArrayBuffer(7))"),
+        "Uncaught \"RangeError\": \"TypedArray: byte length of Float64Array should be a multiple of 8\""
+    );
+}
+
+#[test]
+fn element_conversions() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "new Int8Array([127, 128, -129]).join()"),
+        "\"127,-128,127\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Uint8ClampedArray([-5, 300, 1.5, 2.5, NaN]).join()"
+        ),
+        "\"0,255,2,2,0\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Float32Array([0.1])[0]"),
+        "0.10000000149011612"
+    );
+    assert_eq!(
+        forward(&mut engine, "new BigInt64Array([2n ** 63n])[0]"),
+        "-9223372036854775808n"
+    );
+    assert_eq!(
+        forward(&mut engine, "new BigUint64Array([-1n])[0]"),
+        "18446744073709551615n"
+    );
+}
+
+#[test]
+fn integer_indexed_properties() {
+    let mut engine = Context::new();
+    let init = r#"
+        let array = new Uint8Array(2);
+        array[5] = 1;
+        array["-0"] = 1;
+        array.foo = "bar";
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "array[5]"), "undefined");
+    assert_eq!(forward(&mut engine, "5 in array"), "false");
+    assert_eq!(forward(&mut engine, "1 in array"), "true");
+    assert_eq!(forward(&mut engine, "'-0' in array"), "false");
+    assert_eq!(forward(&mut engine, "array.foo"), "\"bar\"");
+    assert_eq!(
+        forward(&mut engine, "Reflect.ownKeys(array).join()"),
+        "\"0,1,foo\""
+    );
+    assert_eq!(forward(&mut engine, "delete array[0]"), "false");
+    assert_eq!(forward(&mut engine, "delete array[7]"), "true");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(array, 0, { value: 9 })"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "array[0]"), "9");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Reflect.defineProperty(array, 0, { get() { return 1; } })"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn intrinsic() {
+    let mut engine = Context::new();
+    forward(
+        &mut engine,
+        "let TypedArray = Object.getPrototypeOf(Int8Array);",
+    );
+    assert_eq!(forward(&mut engine, "TypedArray.name"), "\"TypedArray\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(Uint8Array.prototype) === TypedArray.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "new TypedArray()"),
+        "Uncaught \"TypeError\": \"TypedArray: abstract class TypedArray cannot be constructed\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "TypedArray.prototype.toString === Array.prototype.toString"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "TypedArray.prototype[Symbol.iterator] === TypedArray.prototype.values"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Uint8ClampedArray(1)[Symbol.toStringTag]"),
+        "\"Uint8ClampedArray\""
+    );
+    assert_eq!(
+        forward(&mut engine, "TypedArray.prototype[Symbol.toStringTag]"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut engine, "TypedArray.prototype.at.call([1], 0)"),
+        "Uncaught \"TypeError\": \"TypedArray.prototype.at: 'this' is not a typed array\""
+    );
+}
+
+#[test]
+fn from_and_of() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "Int16Array.from([1, 2], x => x * 10).join()"),
+        "\"10,20\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Uint8Array.from('123').join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Float64Array.of(1.5, 2).join()"),
+        "\"1.5,2\""
+    );
+    assert_eq!(
+        forward(&mut engine, "Uint8Array.of.call(Object, 1)"),
+        "Uncaught \"TypeError\": \"TypedArray.prototype.of: 'this' is not a typed array\""
+    );
+}
+
+#[test]
+fn iteration_methods() {
+    let mut engine = Context::new();
+    forward(&mut engine, "let array = new Int32Array([3, 1, 4, 1, 5]);");
+    assert_eq!(forward(&mut engine, "array.at(-1)"), "5");
+    assert_eq!(forward(&mut engine, "array.every(x => x > 0)"), "true");
+    assert_eq!(forward(&mut engine, "array.some(x => x > 4)"), "true");
+    assert_eq!(forward(&mut engine, "array.find(x => x > 3)"), "4");
+    assert_eq!(forward(&mut engine, "array.findIndex(x => x > 3)"), "2");
+    assert_eq!(forward(&mut engine, "array.findLast(x => x < 4)"), "1");
+    assert_eq!(forward(&mut engine, "array.findLastIndex(x => x < 4)"), "3");
+    assert_eq!(forward(&mut engine, "array.includes(4)"), "true");
+    assert_eq!(forward(&mut engine, "array.indexOf(1)"), "1");
+    assert_eq!(forward(&mut engine, "array.lastIndexOf(1)"), "3");
+    assert_eq!(forward(&mut engine, "array.reduce((a, b) => a + b)"), "14");
+    assert_eq!(
+        forward(&mut engine, "array.reduceRight((a, b) => a + b, '')"),
+        "\"51413\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[...array.entries()].join(';')"),
+        "\"0,3;1,1;2,4;3,1;4,5\""
+    );
+    assert_eq!(
+        forward(&mut engine, "[...array.keys()].join()"),
+        "\"0,1,2,3,4\""
+    );
+    assert_eq!(forward(&mut engine, "[...array].join()"), "\"3,1,4,1,5\"");
+    assert_eq!(forward(&mut engine, "array.join('-')"), "\"3-1-4-1-5\"");
+    assert_eq!(forward(&mut engine, "String(array)"), "\"3,1,4,1,5\"");
+    assert_eq!(
+        forward(&mut engine, "new Int8Array(0).reduce((a, b) => a)"),
+        "Uncaught \"TypeError\": \"TypedArray.prototype.reduce: reduce of empty array with no initial value\""
+    );
+    assert_eq!(
+        forward(&mut engine, "array.forEach(1)"),
+        "Uncaught \"TypeError\": \"TypedArray.prototype.forEach: callback is not a function\""
+    );
+}
+
+#[test]
+fn creating_methods() {
+    let mut engine = Context::new();
+    forward(&mut engine, "let array = new Uint8Array([1, 2, 3, 4]);");
+    let init = r#"
+        let mapped = array.map(x => x * 100);
+        let filtered = array.filter(x => x % 2 === 0);
+        let sliced = array.slice(1, 3);
+        let sub = array.subarray(1, 3);
+        sub[0] = 20;
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "mapped.join()"), "\"100,200,44,144\"");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(mapped) === Uint8Array.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "filtered.join()"), "\"2,4\"");
+    assert_eq!(forward(&mut engine, "sliced.join()"), "\"2,3\"");
+    assert_eq!(forward(&mut engine, "sub.buffer === array.buffer"), "true");
+    assert_eq!(forward(&mut engine, "array.join()"), "\"1,20,3,4\"");
+
+    let init = r#"
+        class Custom extends Uint8Array {}
+        let custom = new Custom([1, 2]).map(x => x);
+        class Mixed extends Uint8Array {
+            static get [Symbol.species]() { return BigInt64Array; }
+        }
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getPrototypeOf(custom) === Custom.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut engine, "new Mixed(1).slice()"),
+        "Uncaught \"TypeError\": \"TypedArray.prototype.slice: constructor returned a typed array with a different content type\""
+    );
+}
+
+#[test]
+fn mutating_methods() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Uint8Array([1, 2, 3, 4, 5]).copyWithin(0, 3).join()"
+        ),
+        "\"4,5,3,4,5\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array(4).fill(7, 1, -1).join()"),
+        "\"0,7,7,0\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Int8Array([1, 2, 3]).reverse().join()"),
+        "\"3,2,1\""
+    );
+
+    let init = r#"
+        let target = new Uint8Array(5);
+        target.set([1, 2], 1);
+        target.set(new Int8Array([-1]), 4);
+        let overlapping = new Uint8Array([1, 2, 3, 4]);
+        overlapping.set(overlapping.subarray(0, 3), 1);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "target.join()"), "\"0,1,2,0,255\"");
+    assert_eq!(forward(&mut engine, "overlapping.join()"), "\"1,1,2,3\"");
+    assert_eq!(
+        forward(&mut engine, "target.set([1, 2], 4)"),
+        "Uncaught \"RangeError\": \"TypedArray.prototype.set: source is too large\""
+    );
+    assert_eq!(
+        forward(&mut engine, "target.set([1], -1)"),
+        "Uncaught \"RangeError\": \"TypedArray.prototype.set: offset must be >= 0\""
+    );
+}
+
+#[test]
+fn sort() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Float64Array([3, NaN, -0, 0, -1, 10]).sort().join()"
+        ),
+        "\"-1,0,0,3,10,NaN\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.is(new Float64Array([0, -0]).sort()[0], -0)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new Int8Array([1, 3, 2]).sort((a, b) => b - a).join()"
+        ),
+        "\"3,2,1\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "new BigInt64Array([3n, -1n, 2n]).sort().join()"
+        ),
+        "\"-1,2,3\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new Int8Array(1).sort(1)"),
+        "Uncaught \"TypeError\": \"TypedArray.prototype.sort: comparefn is not a function\""
+    );
+}
+
+#[test]
+fn display() {
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "new Uint8Array([1, 2, 3])"),
+        "Uint8Array(3) [ 1, 2, 3 ]"
+    );
+    assert_eq!(forward(&mut engine, "new Int8Array(0)"), "Int8Array(0)");
+}
@@ -0,0 +1,106 @@
+//! This module implements the global `WeakRef` object.
+//!
+//! A `WeakRef` holds a weak reference to an object, which doesn't prevent the object from
+//! being garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weak-ref-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef
+
+use crate::{
+    builtins::BuiltIn,
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakRef;
+
+impl BuiltIn for WeakRef {
+    const NAME: &'static str = "WeakRef";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+
+        let weak_ref_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .method(Self::deref, "deref", 0)
+            .property(to_string_tag, Self::NAME, Attribute::CONFIGURABLE)
+            .callable(false)
+            .build();
+
+        (Self::NAME, weak_ref_object.into(), Self::attribute())
+    }
+}
+
+impl WeakRef {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Create a new weak reference to the target object.
+    ///
+    /// The target is kept alive until the end of the current job.
+    pub(crate) fn constructor(this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = match args.first().and_then(Value::as_object) {
+            Some(target) => target,
+            None => return ctx.throw_type_error("WeakRef: target must be an object"),
+        };
+
+        let prototype = ctx
+            .global_object()
+            .clone()
+            .get_field("WeakRef", ctx)?
+            .get_field(PROTOTYPE, ctx)?;
+
+        this.as_object()
+            .expect("this is weak ref object")
+            .set_prototype_instance(prototype);
+
+        this.set_data(ObjectData::WeakRef(target.downgrade()));
+        ctx.add_to_kept_objects(target);
+
+        Ok(this.clone())
+    }
+
+    /// `WeakRef.prototype.deref( )`
+    ///
+    /// This method returns the target object, or undefined if it was garbage collected. The
+    /// target is then kept alive until the end of the current job.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref.prototype.deref
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/deref
+    pub(crate) fn deref(this: &Value, _: &[Value], ctx: &mut Context) -> Result<Value> {
+        let target = match this {
+            Value::Object(ref object) => match object.borrow().as_weak_ref() {
+                Some(weak_ref) => weak_ref.upgrade(),
+                None => return ctx.throw_type_error("'this' is not a WeakRef"),
+            },
+            _ => return ctx.throw_type_error("'this' is not a WeakRef"),
+        };
+
+        match target {
+            Some(target) => {
+                ctx.add_to_kept_objects(target.clone());
+                Ok(target.into())
+            }
+            None => Ok(Value::undefined()),
+        }
+    }
+}
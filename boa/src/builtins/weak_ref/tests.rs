@@ -0,0 +1,47 @@
+use crate::{forward, gc::force_collect, Context};
+
+#[test]
+fn deref() {
+    let mut engine = Context::new();
+    let init = r#"
+        let target = {};
+        let weakRef = new WeakRef(target);
+        "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "weakRef.deref() === target"), "true");
+    assert_eq!(
+        forward(&mut engine, "Object.prototype.toString.call(weakRef)"),
+        "\"[object WeakRef]\""
+    );
+    assert_eq!(
+        forward(&mut engine, "new WeakRef(1)"),
+        "Uncaught \"TypeError\": \"WeakRef: target must be an object\""
+    );
+    assert_eq!(
+        forward(&mut engine, "WeakRef.prototype.deref.call({})"),
+        "Uncaught \"TypeError\": \"'this' is not a WeakRef\""
+    );
+}
+
+#[test]
+fn target_is_collected() {
+    let mut engine = Context::new();
+    engine
+        .eval("let kept = {}; let keptRef = new WeakRef(kept); let weakRef = new WeakRef({});")
+        .unwrap();
+    force_collect();
+    assert_eq!(forward(&mut engine, "weakRef.deref()"), "undefined");
+    assert_eq!(forward(&mut engine, "keptRef.deref() === kept"), "true");
+}
+
+#[test]
+fn target_is_kept_until_the_job_completes() {
+    let mut engine = Context::new();
+    forward(&mut engine, "let weakRef = new WeakRef({});");
+    force_collect();
+    assert_eq!(forward(&mut engine, "typeof weakRef.deref()"), "\"object\"");
+
+    engine.eval("weakRef.deref();").unwrap();
+    force_collect();
+    assert_eq!(forward(&mut engine, "weakRef.deref()"), "undefined");
+}
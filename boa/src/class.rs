@@ -27,7 +27,7 @@
 //!     // This is what is called when we do `new Animal()`
 //!     fn constructor(_this: &Value, args: &[Value], ctx: &mut Context) -> Result<Self> {
 //!         // This is equivalent to `String(arg)`.
-//!         let kind = args.get(0).cloned().unwrap_or_default().to_string(ctx)?;
+//!         let kind = args.first().cloned().unwrap_or_default().to_string(ctx)?;
 //!
 //!         let animal = match kind.as_str() {
 //!             "cat" => Self::Cat,
@@ -62,7 +62,7 @@
 
 use crate::{
     builtins::function::NativeFunction,
-    object::{ConstructorBuilder, GcObject, NativeObject, ObjectData},
+    object::{ConstructorBuilder, FunctionBinding, GcObject, NativeObject, ObjectData},
     property::{Attribute, PropertyKey},
     Context, Result, Value,
 };
@@ -129,13 +129,14 @@ impl<'context> ClassBuilder<'context> {
 
     /// Add a method to the class.
     ///
-    /// It is added to `prototype`.
+    /// It is added to `prototype`. The method can be bound to a symbol, such as
+    /// `Symbol.toPrimitive`, by passing a `(symbol, name)` tuple.
     #[inline]
-    pub fn method<N>(&mut self, name: N, length: usize, function: NativeFunction) -> &mut Self
+    pub fn method<B>(&mut self, name: B, length: usize, function: NativeFunction) -> &mut Self
     where
-        N: AsRef<str>,
+        B: Into<FunctionBinding>,
     {
-        self.builder.method(function, name.as_ref(), length);
+        self.builder.method(function, name, length);
         self
     }
 
@@ -143,16 +144,16 @@ impl<'context> ClassBuilder<'context> {
     ///
     /// It is added to class object itself.
     #[inline]
-    pub fn static_method<N>(
+    pub fn static_method<B>(
         &mut self,
-        name: N,
+        name: B,
         length: usize,
         function: NativeFunction,
     ) -> &mut Self
     where
-        N: AsRef<str>,
+        B: Into<FunctionBinding>,
     {
-        self.builder.static_method(function, name.as_ref(), length);
+        self.builder.static_method(function, name, length);
         self
     }
 
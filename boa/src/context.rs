@@ -3,29 +3,36 @@
 use crate::{
     builtins::{
         self,
-        function::{Function, FunctionFlags, NativeFunction},
+        array::Array,
+        array_buffer::ArrayBuffer,
+        finalization_registry::FinalizationRegistry,
+        function::{self, Function, FunctionFlags, NativeFunction},
         iterable::IteratorPrototypes,
+        promise::Job,
         symbol::{Symbol, WellKnownSymbols},
     },
     class::{Class, ClassBuilder},
-    exec::Interpreter,
-    object::{GcObject, Object, ObjectData, PROTOTYPE},
-    property::{DataDescriptor, PropertyKey},
+    environment::lexical_environment::{Environment, EnvironmentErrorKind},
+    exec::{Interpreter, InterpreterState},
+    module::{self, Module, ModuleLoader},
+    object::{GcObject, Object, ObjectData, WeakGcObject, PROTOTYPE},
+    property::{Attribute, DataDescriptor, PropertyKey},
     realm::Realm,
     syntax::{
         ast::{
             node::{
                 statement_list::RcStatementList, Call, FormalParameter, Identifier, New,
-                StatementList,
+                RcFormalParameterList, StatementList,
             },
-            Const, Node,
+            Const,
         },
         Parser,
     },
     value::{RcString, RcSymbol, Value},
     BoaProfiler, Executable, Result,
 };
-use std::result::Result as StdResult;
+use rustc_hash::FxHashMap;
+use std::{collections::VecDeque, rc::Rc};
 
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
@@ -83,6 +90,23 @@ pub struct StandardObjects {
     syntax_error: StandardConstructor,
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
+    aggregate_error: StandardConstructor,
+    promise: StandardConstructor,
+    async_function_prototype: GcObject,
+    array_buffer: StandardConstructor,
+    typed_array: StandardConstructor,
+    int8_array: StandardConstructor,
+    uint8_array: StandardConstructor,
+    uint8_clamped_array: StandardConstructor,
+    int16_array: StandardConstructor,
+    uint16_array: StandardConstructor,
+    int32_array: StandardConstructor,
+    uint32_array: StandardConstructor,
+    float32_array: StandardConstructor,
+    float64_array: StandardConstructor,
+    bigint64_array: StandardConstructor,
+    biguint64_array: StandardConstructor,
+    data_view: StandardConstructor,
 }
 
 impl StandardObjects {
@@ -164,6 +188,76 @@ impl StandardObjects {
     pub fn uri_error_object(&self) -> &StandardConstructor {
         &self.uri_error
     }
+
+    pub fn aggregate_error_object(&self) -> &StandardConstructor {
+        &self.aggregate_error
+    }
+
+    pub fn promise_object(&self) -> &StandardConstructor {
+        &self.promise
+    }
+
+    /// Returns `%AsyncFunction.prototype%`, the prototype of async functions.
+    pub fn async_function_prototype(&self) -> GcObject {
+        self.async_function_prototype.clone()
+    }
+
+    pub fn array_buffer_object(&self) -> &StandardConstructor {
+        &self.array_buffer
+    }
+
+    /// Returns `%TypedArray%`, the constructor that all the typed array constructors inherit from.
+    pub fn typed_array_object(&self) -> &StandardConstructor {
+        &self.typed_array
+    }
+
+    pub fn int8_array_object(&self) -> &StandardConstructor {
+        &self.int8_array
+    }
+
+    pub fn uint8_array_object(&self) -> &StandardConstructor {
+        &self.uint8_array
+    }
+
+    pub fn uint8_clamped_array_object(&self) -> &StandardConstructor {
+        &self.uint8_clamped_array
+    }
+
+    pub fn int16_array_object(&self) -> &StandardConstructor {
+        &self.int16_array
+    }
+
+    pub fn uint16_array_object(&self) -> &StandardConstructor {
+        &self.uint16_array
+    }
+
+    pub fn int32_array_object(&self) -> &StandardConstructor {
+        &self.int32_array
+    }
+
+    pub fn uint32_array_object(&self) -> &StandardConstructor {
+        &self.uint32_array
+    }
+
+    pub fn float32_array_object(&self) -> &StandardConstructor {
+        &self.float32_array
+    }
+
+    pub fn float64_array_object(&self) -> &StandardConstructor {
+        &self.float64_array
+    }
+
+    pub fn bigint64_array_object(&self) -> &StandardConstructor {
+        &self.bigint64_array
+    }
+
+    pub fn biguint64_array_object(&self) -> &StandardConstructor {
+        &self.biguint64_array
+    }
+
+    pub fn data_view_object(&self) -> &StandardConstructor {
+        &self.data_view
+    }
 }
 
 /// Javascript context. It is the primary way to interact with the runtime.
@@ -184,6 +278,9 @@ pub struct Context {
     /// For now this is an incremented u32 number.
     symbol_count: u32,
 
+    /// The global symbol registry of `Symbol.for`, which maps the keys to their symbols.
+    symbol_registry: FxHashMap<RcString, RcSymbol>,
+
     /// console object state.
     #[cfg(feature = "console")]
     console: Console,
@@ -196,6 +293,34 @@ pub struct Context {
 
     /// Cached standard objects and their prototypes
     standard_objects: StandardObjects,
+
+    /// The `%ThrowTypeError%` intrinsic function.
+    throw_type_error_function: GcObject,
+
+    /// The jobs waiting to be run, such as the reactions of settled promises.
+    job_queue: VecDeque<Job>,
+
+    /// Whether the pending jobs are run after each call to `eval`.
+    run_jobs_on_eval: bool,
+
+    /// Whether code can be compiled from strings by `eval` and the `Function` constructor.
+    allow_code_generation_from_strings: bool,
+
+    /// The targets of the weak references created or dereferenced by the current job, which
+    /// are kept alive until it completes.
+    kept_objects: Vec<GcObject>,
+
+    /// The finalization registries, whose cleanup jobs are queued after a garbage collection.
+    finalization_registries: Vec<WeakGcObject>,
+
+    /// The number of garbage collections when the finalization registries were last checked.
+    collections: usize,
+
+    /// The loader of the imported modules, if modules can be imported.
+    module_loader: Option<Rc<dyn ModuleLoader>>,
+
+    /// The loaded modules, by the key their specifier was resolved to.
+    modules: FxHashMap<String, Module>,
 }
 
 impl Default for Context {
@@ -207,11 +332,21 @@ impl Default for Context {
             realm,
             executor,
             symbol_count,
+            symbol_registry: FxHashMap::default(),
             #[cfg(feature = "console")]
             console: Console::default(),
             well_known_symbols,
             iterator_prototypes: IteratorPrototypes::default(),
             standard_objects: Default::default(),
+            throw_type_error_function: GcObject::default(),
+            job_queue: VecDeque::new(),
+            run_jobs_on_eval: false,
+            allow_code_generation_from_strings: true,
+            kept_objects: Vec::new(),
+            finalization_registries: Vec::new(),
+            collections: 0,
+            module_loader: None,
+            modules: FxHashMap::default(),
         };
 
         // Add new builtIns to Context Realm
@@ -219,6 +354,7 @@ impl Default for Context {
         // but for now we almost always want these default builtins
         context.create_intrinsics();
         context.iterator_prototypes = IteratorPrototypes::init(&mut context);
+        context.throw_type_error_function = function::create_throw_type_error(&mut context);
         context
     }
 }
@@ -270,6 +406,18 @@ impl Context {
         hash
     }
 
+    /// Returns the global symbol registry.
+    #[inline]
+    pub(crate) fn symbol_registry(&self) -> &FxHashMap<RcString, RcSymbol> {
+        &self.symbol_registry
+    }
+
+    /// Returns a mutable reference to the global symbol registry.
+    #[inline]
+    pub(crate) fn symbol_registry_mut(&mut self) -> &mut FxHashMap<RcString, RcSymbol> {
+        &mut self.symbol_registry
+    }
+
     /// Construct a new `Symbol` with an optional description.
     #[inline]
     pub fn construct_symbol(&mut self, description: Option<RcString>) -> RcSymbol {
@@ -279,11 +427,19 @@ impl Context {
     /// Construct an empty object.
     #[inline]
     pub fn construct_object(&self) -> GcObject {
-        let object_prototype = self
-            .global_object()
-            .get_field("Object")
-            .get_field(PROTOTYPE);
-        GcObject::new(Object::create(object_prototype))
+        let object_prototype = self.standard_objects().object_object().prototype();
+        GcObject::new(Object::create(object_prototype.into()))
+    }
+
+    /// Constructs an `ArrayBuffer` object that takes ownership of `data`, without copying it.
+    ///
+    /// This is the way for the host to hand binary data over to JavaScript code.
+    pub fn construct_array_buffer(&self, data: Vec<u8>) -> GcObject {
+        let prototype = self.standard_objects().array_buffer_object().prototype();
+        GcObject::new(Object::with_prototype(
+            prototype.into(),
+            ObjectData::ArrayBuffer(ArrayBuffer::new(data)),
+        ))
     }
 
     /// <https://tc39.es/ecma262/#sec-call>
@@ -438,32 +594,79 @@ impl Context {
         P: Into<Box<[FormalParameter]>>,
         B: Into<StatementList>,
     {
-        let function_prototype = self
-            .global_object()
-            .get_field("Function")
-            .get_field(PROTOTYPE);
+        // A function nested in strict mode code is strict.
+        let flags = if self.executor.strict() {
+            flags | FunctionFlags::STRICT
+        } else {
+            flags
+        };
+        let environment = self.realm.environment.get_current_environment().clone();
+        self.create_function_in_environment(params, body, flags, environment)
+    }
 
-        // Every new function has a prototype property pre-made
-        let proto = Value::new_object(Some(self.global_object()));
+    /// Creates a function whose scope is `environment` instead of the current environment, as
+    /// the functions created by the `Function` constructor, whose scope is the global one.
+    pub(crate) fn create_function_in_environment<P, B>(
+        &mut self,
+        params: P,
+        body: B,
+        flags: FunctionFlags,
+        environment: Environment,
+    ) -> Value
+    where
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        let function_prototype = if flags.is_generator() {
+            self.iterator_prototypes().generator_function().into()
+        } else if flags.is_async() {
+            self.standard_objects().async_function_prototype().into()
+        } else {
+            self.standard_objects().function_object().prototype().into()
+        };
 
         let params = params.into();
         let params_len = params.len();
+        let body = body.into();
+        let flags = if body.strict() {
+            flags | FunctionFlags::STRICT
+        } else {
+            flags
+        };
         let func = Function::Ordinary {
             flags,
-            body: RcStatementList::from(body.into()),
-            params,
-            environment: self.realm.environment.get_current_environment().clone(),
+            body: RcStatementList::from(body),
+            params: RcFormalParameterList::from(params),
+            environment,
+            home_object: None,
+            source_text: None,
         };
 
         let new_func = Object::function(func, function_prototype);
 
         let val = Value::from(new_func);
 
-        // Set constructor field to the newly created Value (function object)
-        proto.set_field("constructor", val.clone());
-
-        val.set_field(PROTOTYPE, proto);
-        val.set_field("length", Value::from(params_len));
+        if flags.is_generator() {
+            // The prototype of the generator objects created by the function, which has no
+            // `constructor` property.
+            let proto = Object::create(self.iterator_prototypes().generator().into());
+            val.set_property(PROTOTYPE, DataDescriptor::new(proto, Attribute::WRITABLE));
+        } else if !flags.is_async() {
+            // Every new function has a prototype property pre-made
+            let proto = Value::new_object(Some(self.global_object()));
+
+            // Set constructor field to the newly created Value (function object)
+            proto.set_property(
+                "constructor",
+                DataDescriptor::new(
+                    val.clone(),
+                    Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+
+            val.set_property(PROTOTYPE, DataDescriptor::new(proto, Attribute::all()));
+        }
+        val.set_property("length", DataDescriptor::new(params_len, Attribute::all()));
 
         val
     }
@@ -475,20 +678,17 @@ impl Context {
         length: usize,
         body: NativeFunction,
     ) -> Result<GcObject> {
-        let function_prototype = self
-            .global_object()
-            .get_field("Function")
-            .get_field(PROTOTYPE);
+        let function_prototype = self.standard_objects().function_object().prototype();
 
         // Every new function has a prototype property pre-made
         let proto = Value::new_object(Some(self.global_object()));
         let mut function = GcObject::new(Object::function(
             Function::BuiltIn(body.into(), FunctionFlags::CALLABLE),
-            function_prototype,
+            function_prototype.into(),
         ));
-        function.set(PROTOTYPE.into(), proto);
-        function.set("length".into(), length.into());
-        function.set("name".into(), name.into());
+        function.insert_property(PROTOTYPE, proto, Attribute::all());
+        function.insert_property("length", length, Attribute::all());
+        function.insert_property("name", name, Attribute::all());
 
         Ok(function)
     }
@@ -501,88 +701,104 @@ impl Context {
         body: NativeFunction,
     ) -> Result<()> {
         let function = self.create_builtin_function(name, length, body)?;
-        self.global_object().set_field(name, function);
+        self.global_object()
+            .clone()
+            .set_field(name, function, self)?;
         Ok(())
     }
 
     /// Converts an array object into a rust vector of values.
     ///
-    /// This is useful for the spread operator, for any other object an `Err` is returned
+    /// This is useful for the spread operator, for any other object `None` is returned
     /// TODO: Not needed for spread of arrays. Check in the future for Map and remove if necessary
-    pub(crate) fn extract_array_properties(&mut self, value: &Value) -> StdResult<Vec<Value>, ()> {
+    pub(crate) fn extract_array_properties(&mut self, value: &Value) -> Result<Option<Vec<Value>>> {
         if let Value::Object(ref x) = value {
             // Check if object is array
-            if let ObjectData::Array = x.borrow().data {
-                let length = value.get_field("length").as_number().unwrap() as i32;
+            if x.is_array() {
+                let length = value.get_field("length", self)?.as_number().unwrap() as i32;
                 let values = (0..length)
-                    .map(|idx| value.get_field(idx.to_string()))
-                    .collect();
-                return Ok(values);
+                    .map(|idx| value.get_field(idx, self))
+                    .collect::<Result<_>>()?;
+                return Ok(Some(values));
             }
             // Check if object is a Map
-            else if let ObjectData::Map(ref map) = x.borrow().data {
-                let values = map
-                    .iter()
+            let entries = x.borrow().as_map_ref().map(|map| {
+                map.iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect::<Vec<_>>()
+            });
+            if let Some(entries) = entries {
+                // Construct a new array containing each key-value pair
+                let values = entries
+                    .into_iter()
                     .map(|(key, value)| {
-                        // Construct a new array containing the key-value pair
-                        let array = Value::new_object(Some(
-                            &self
-                                .realm()
-                                .environment
-                                .get_global_object()
-                                .expect("Could not get global object"),
-                        ));
-                        array.set_data(ObjectData::Array);
-                        array.as_object().expect("object").set_prototype_instance(
-                            self.realm()
-                                .environment
-                                .get_binding_value("Array")
-                                .expect("Array was not initialized")
-                                .get_field(PROTOTYPE),
-                        );
-                        array.set_field("0", key);
-                        array.set_field("1", value);
-                        array.set_field("length", Value::from(2));
-                        array
+                        Array::construct_array(&Array::new_array(self)?, &[key, value], self)
                     })
-                    .collect();
-                return Ok(values);
+                    .collect::<Result<_>>()?;
+                return Ok(Some(values));
             }
-
-            return Err(());
         }
 
-        Err(())
+        Ok(None)
     }
 
     /// https://tc39.es/ecma262/#sec-hasproperty
-    pub(crate) fn has_property(&self, obj: &Value, key: &PropertyKey) -> bool {
+    pub(crate) fn has_property(&mut self, obj: &Value, key: &PropertyKey) -> Result<bool> {
         if let Some(obj) = obj.as_object() {
-            obj.has_property(key)
+            obj.has_property(key, self)
         } else {
-            false
+            Ok(false)
         }
     }
 
-    pub(crate) fn set_value(&mut self, node: &Node, value: Value) -> Result<Value> {
-        match node {
-            Node::Identifier(ref name) => {
-                self.realm
-                    .environment
-                    .set_mutable_binding(name.as_ref(), value.clone(), true);
-                Ok(value)
-            }
-            Node::GetConstField(ref get_const_field_node) => Ok(get_const_field_node
-                .obj()
-                .run(self)?
-                .set_field(get_const_field_node.field(), value)),
-            Node::GetField(ref get_field) => {
-                let field = get_field.field().run(self)?;
-                let key = field.to_property_key(self)?;
-                Ok(get_field.obj().run(self)?.set_field(key, value))
-            }
-            _ => panic!("TypeError: invalid assignment to {}", node),
+    /// Gets the value of the binding `name`, throwing a `ReferenceError` if there is none.
+    ///
+    /// The bindings of a `with` statement are the properties of its object, whose getters are
+    /// called.
+    pub(crate) fn get_binding_value(&mut self, name: &str) -> Result<Value> {
+        if let Some(object) = self.realm.environment.get_with_base_object(name) {
+            return object.get_field(name, self);
         }
+
+        self.realm
+            .environment
+            .get_binding_value(name)
+            .ok_or_else(|| self.construct_reference_error(name))
+    }
+
+    /// Sets the value of the binding `name`.
+    ///
+    /// The bindings of a `with` statement are set with the `[[Set]]` method of its object.
+    ///
+    /// If there is no such binding, strict mode code throws a `ReferenceError`, and non-strict
+    /// code creates a property of the global object.
+    pub(crate) fn set_mutable_binding(&mut self, name: &str, value: Value) -> Result<()> {
+        if let Some(object) = self.realm.environment.get_with_base_object(name) {
+            object.set_field(name, value, self)?;
+            return Ok(());
+        }
+
+        let strict = self.executor.strict();
+        self.realm
+            .environment
+            .set_mutable_binding(name, value, strict)
+            .map_err(|error| match error.kind() {
+                EnvironmentErrorKind::Reference => self.construct_reference_error(name),
+                EnvironmentErrorKind::Type => self.construct_type_error(error.to_string()),
+            })
+    }
+
+    /// Runs `f` as strict mode code if `strict` is `true`, or as non-strict code otherwise,
+    /// before going back to the strictness of the running code.
+    pub(crate) fn run_with_strict_mode<T, F>(&mut self, strict: bool, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        let running = self.executor.strict();
+        self.executor.set_strict(strict);
+        let result = f(self);
+        self.executor.set_strict(running);
+        result
     }
 
     /// Register a global class of type `T`, where `T` implements `Class`.
@@ -626,7 +842,7 @@ impl Context {
     /// assert!(value.is_number());
     /// assert_eq!(value.as_number().unwrap(), 4.0);
     /// ```
-    #[allow(clippy::unit_arg, clippy::drop_copy)]
+    #[allow(clippy::unit_arg, dropping_copy_types)]
     pub fn eval(&mut self, src: &str) -> Result<Value> {
         let main_timer = BoaProfiler::global().start_event("Main", "Main");
 
@@ -635,9 +851,17 @@ impl Context {
             .map_err(|e| e.to_string());
 
         let execution_result = match parsing_result {
-            Ok(statement_list) => statement_list.run(self),
+            Ok(statement_list) => {
+                self.run_with_strict_mode(statement_list.strict(), |ctx| statement_list.run(ctx))
+            }
             Err(e) => self.throw_syntax_error(e),
         };
+        self.clear_kept_objects();
+        let execution_result = if self.run_jobs_on_eval {
+            self.drain_jobs(execution_result)
+        } else {
+            execution_result
+        };
 
         // The main_timer needs to be dropped before the BoaProfiler is.
         drop(main_timer);
@@ -646,6 +870,227 @@ impl Context {
         execution_result
     }
 
+    /// Adds a job to the end of the job queue.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuepromisejob
+    pub(crate) fn enqueue_job(&mut self, job: Job) {
+        self.job_queue.push_back(job);
+    }
+
+    /// Runs the pending jobs in the order they were queued, until the queue is empty.
+    ///
+    /// This includes the jobs queued by the jobs that are run, so it runs all the reactions
+    /// to the promises that get settled in the process.
+    ///
+    /// If a job throws an error, it is returned and the remaining jobs are left in the queue.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// context.eval("var x = 0; Promise.resolve(1).then(v => { x = v; });").unwrap();
+    /// assert_eq!(context.eval("x").unwrap().as_number(), Some(0.0));
+    ///
+    /// context.run_jobs().unwrap();
+    /// assert_eq!(context.eval("x").unwrap().as_number(), Some(1.0));
+    /// ```
+    pub fn run_jobs(&mut self) -> Result<()> {
+        loop {
+            self.enqueue_finalization_cleanup_jobs();
+            let job = match self.job_queue.pop_front() {
+                Some(job) => job,
+                None => return Ok(()),
+            };
+            let result = job.run(self);
+            self.clear_kept_objects();
+            self.executor.set_current_state(InterpreterState::Executing);
+            result?;
+        }
+    }
+
+    /// Runs all the pending jobs after the evaluation of a script, even if some of them fail.
+    ///
+    /// The result of the script is returned if it threw an error, otherwise the first error
+    /// thrown by a job is returned.
+    fn drain_jobs(&mut self, mut result: Result<Value>) -> Result<Value> {
+        self.enqueue_finalization_cleanup_jobs();
+        while self.has_pending_jobs() {
+            if let Err(error) = self.run_jobs() {
+                if result.is_ok() {
+                    result = Err(error);
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns `true` if there are jobs waiting to be run.
+    #[inline]
+    pub fn has_pending_jobs(&self) -> bool {
+        !self.job_queue.is_empty()
+    }
+
+    /// Keeps `object` alive until the current job completes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-addtokeptobjects
+    pub(crate) fn add_to_kept_objects(&mut self, object: GcObject) {
+        self.kept_objects.push(object);
+    }
+
+    /// Releases the objects kept alive by the job that just completed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-clear-kept-objects
+    fn clear_kept_objects(&mut self) {
+        self.kept_objects.clear();
+    }
+
+    /// Registers a finalization registry, so that its cleanup jobs are queued once some of its
+    /// targets are garbage collected.
+    pub(crate) fn add_finalization_registry(&mut self, registry: &GcObject) {
+        self.finalization_registries.push(registry.downgrade());
+    }
+
+    /// Queues a cleanup job for each finalization registry whose targets were collected since
+    /// the last garbage collection.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    fn enqueue_finalization_cleanup_jobs(&mut self) {
+        let collections = crate::gc::collections();
+        if collections == self.collections {
+            return;
+        }
+        self.collections = collections;
+
+        self.finalization_registries.retain(WeakGcObject::is_alive);
+        let registries: Vec<GcObject> = self
+            .finalization_registries
+            .iter()
+            .filter_map(WeakGcObject::upgrade)
+            .filter(|registry| {
+                registry
+                    .borrow()
+                    .as_finalization_registry()
+                    .is_some_and(FinalizationRegistry::needs_cleanup)
+            })
+            .collect();
+        for registry in registries {
+            self.enqueue_job(Job::CleanupFinalizationRegistry { registry });
+        }
+    }
+
+    /// Sets whether the pending jobs are run by [`Context::eval`], once the code has been
+    /// evaluated, whether it completed normally or threw an error.
+    ///
+    /// This is disabled by default, in which case the jobs are only run by calling
+    /// [`Context::run_jobs`].
+    #[inline]
+    pub fn set_run_jobs_on_eval(&mut self, run_jobs: bool) {
+        self.run_jobs_on_eval = run_jobs;
+    }
+
+    /// Sets whether the code run by the context can compile code from strings, with the global
+    /// `eval` function or the `Function` constructor.
+    ///
+    /// This is allowed by default. Once disallowed, they throw an `EvalError` instead, which
+    /// is useful to sandbox the code that is run.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// context.set_allow_code_generation_from_strings(false);
+    /// assert!(context.eval("eval('1 + 1')").is_err());
+    /// ```
+    #[inline]
+    pub fn set_allow_code_generation_from_strings(&mut self, allow: bool) {
+        self.allow_code_generation_from_strings = allow;
+    }
+
+    /// Throws an `EvalError` if the host does not allow code to be compiled from strings.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostensurecancompilestrings
+    pub(crate) fn ensure_can_compile_strings(&mut self) -> Result<()> {
+        if self.allow_code_generation_from_strings {
+            Ok(())
+        } else {
+            Err(self.construct_eval_error("code generation from strings is disallowed"))
+        }
+    }
+
+    /// Sets the loader used to load the modules imported by the code of the context.
+    ///
+    /// Modules can't be imported until a loader is set.
+    ///
+    /// # Examples
+    /// ```no_run
+    ///# use boa::{module::FsModuleLoader, Context};
+    /// let mut context = Context::new();
+    ///
+    /// context.set_module_loader(FsModuleLoader::new("."));
+    /// context.import_module("./main.mjs").unwrap();
+    /// ```
+    #[inline]
+    pub fn set_module_loader<L>(&mut self, loader: L)
+    where
+        L: ModuleLoader + 'static,
+    {
+        self.module_loader = Some(Rc::new(loader));
+    }
+
+    /// Returns the module loader, or throws a `TypeError` if there is none.
+    pub(crate) fn module_loader(&mut self) -> Result<Rc<dyn ModuleLoader>> {
+        match self.module_loader {
+            Some(ref loader) => Ok(loader.clone()),
+            None => Err(self.construct_type_error("Cannot import modules without a module loader")),
+        }
+    }
+
+    /// Returns the loaded modules.
+    #[inline]
+    pub(crate) fn modules(&self) -> &FxHashMap<String, Module> {
+        &self.modules
+    }
+
+    /// Returns a mutable reference to the loaded modules.
+    #[inline]
+    pub(crate) fn modules_mut(&mut self) -> &mut FxHashMap<String, Module> {
+        &mut self.modules
+    }
+
+    /// Imports the module `specifier`, and returns its namespace object.
+    ///
+    /// The module, and the modules it imports, are loaded with the module loader of the context,
+    /// then evaluated, unless they were already imported.
+    ///
+    /// Like [`Context::eval`], the pending jobs are run once the module is evaluated if
+    /// [`Context::set_run_jobs_on_eval`] is enabled.
+    pub fn import_module(&mut self, specifier: &str) -> Result<Value> {
+        let result = module::import(specifier, None, self);
+        self.clear_kept_objects();
+        if self.run_jobs_on_eval {
+            self.drain_jobs(result)
+        } else {
+            result
+        }
+    }
+
     /// Returns a structure that contains the JavaScript well known symbols.
     ///
     /// # Examples
@@ -668,6 +1113,12 @@ impl Context {
         &self.iterator_prototypes
     }
 
+    /// Returns the `%ThrowTypeError%` intrinsic function.
+    #[inline]
+    pub(crate) fn throw_type_error_function(&self) -> GcObject {
+        self.throw_type_error_function.clone()
+    }
+
     /// Return the core standard objects.
     #[inline]
     pub fn standard_objects(&self) -> &StandardObjects {
@@ -8,7 +8,7 @@
 use crate::{
     environment::{
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
     },
     Value,
 };
@@ -89,16 +89,20 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
     }
 
     #[allow(clippy::else_if_without_else)]
-    fn set_mutable_binding(&mut self, name: &str, value: Value, mut strict: bool) {
-        if self.env_rec.get(name).is_none() {
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        mut strict: bool,
+    ) -> Result<(), EnvironmentError> {
+        if !self.env_rec.contains_key(name) {
             if strict {
-                // TODO: change this when error handling comes into play
-                panic!("Reference Error: Cannot set mutable binding for {}", name);
+                return Err(EnvironmentError::new(&format!("{} is not defined", name)));
             }
 
             self.create_mutable_binding(name.to_owned(), true);
             self.initialize_binding(name, value);
-            return;
+            return Ok(());
         }
 
         let record: &mut DeclarativeEnvironmentRecordBinding = self.env_rec.get_mut(name).unwrap();
@@ -106,16 +110,21 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
             strict = true
         }
         if record.value.is_none() {
-            // TODO: change this when error handling comes into play
-            panic!("Reference Error: Cannot set mutable binding for {}", name);
+            return Err(EnvironmentError::new(&format!(
+                "Cannot access {} before initialization",
+                name
+            )));
         }
 
         if record.mutable {
             record.value = Some(value);
         } else if strict {
-            // TODO: change this when error handling comes into play
-            panic!("TypeError: Cannot mutate an immutable binding {}", name);
+            return Err(EnvironmentError::type_error(&format!(
+                "Cannot assign to the constant {}",
+                name
+            )));
         }
+        Ok(())
     }
 
     fn get_binding_value(&self, name: &str, _strict: bool) -> Value {
@@ -133,15 +142,11 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
 
     fn delete_binding(&mut self, name: &str) -> bool {
         match self.env_rec.get(name) {
-            Some(binding) => {
-                if binding.can_delete {
-                    self.env_rec.remove(name);
-                    true
-                } else {
-                    false
-                }
+            Some(binding) if binding.can_delete => {
+                self.env_rec.remove(name);
+                true
             }
-            None => false,
+            _ => false,
         }
     }
 
@@ -149,8 +154,8 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         false
     }
 
-    fn get_this_binding(&self) -> Value {
-        Value::undefined()
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(Value::undefined())
     }
 
     fn has_super_binding(&self) -> bool {
@@ -9,7 +9,8 @@
 //! There are 5 Environment record kinds. They all have methods in common, these are implemented as a the `EnvironmentRecordTrait`
 //!
 use crate::{
-    environment::lexical_environment::{Environment, EnvironmentType},
+    environment::lexical_environment::{Environment, EnvironmentError, EnvironmentType},
+    object::GcObject,
     Value,
 };
 use gc::{Finalize, Trace};
@@ -33,6 +34,21 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// regardless of the strict mode setting of operations that reference that binding.
     fn create_immutable_binding(&mut self, name: String, strict: bool) -> bool;
 
+    /// Create an immutable indirect binding, which gets its value from the binding `target_name`
+    /// of the `target` Environment Record.
+    ///
+    /// Only module Environment Records can create import bindings, the rest return an error.
+    fn create_import_binding(
+        &mut self,
+        _name: String,
+        _target: Environment,
+        _target_name: String,
+    ) -> Result<(), EnvironmentError> {
+        Err(EnvironmentError::new(
+            "Cannot create an import binding in a non-module environment",
+        ))
+    }
+
     /// Set the value of an already existing but uninitialized binding in an Environment Record.
     /// The String value N is the text of the bound name.
     /// V is the value for the binding and is a value of any ECMAScript language type.
@@ -42,7 +58,13 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// The String value `name` is the text of the bound name.
     /// value is the `value` for the binding and may be a value of any ECMAScript language type. S is a Boolean flag.
     /// If `strict` is true and the binding cannot be set throw a TypeError exception.
-    fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool);
+    /// If `strict` is true and the binding does not exist throw a ReferenceError exception.
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError>;
 
     /// Returns the value of an already existing binding from an Environment Record.
     /// The String value N is the text of the bound name.
@@ -61,12 +83,42 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     fn has_this_binding(&self) -> bool;
 
     /// Return the `this` binding from the environment
-    fn get_this_binding(&self) -> Value;
+    ///
+    /// Fails if the `this` binding has not been initialized yet, for example when
+    /// `this` is accessed in a derived class constructor before calling `super()`.
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError>;
+
+    /// Bind the `this` value of a function environment.
+    ///
+    /// Only function Environment Records can bind `this`, the rest return an error.
+    fn bind_this_value(&mut self, _value: Value) -> Result<Value, EnvironmentError> {
+        Err(EnvironmentError::new(
+            "Cannot bind this in a non-function environment",
+        ))
+    }
 
     /// Determine if an Environment Record establishes a super method binding.
     /// Return true if it does and false if it does not.
     fn has_super_binding(&self) -> bool;
 
+    /// Return the object that `super` property accesses are bound to.
+    ///
+    /// This is the prototype of the `[[HomeObject]]` of the function, or undefined
+    /// if the Environment Record has no super binding.
+    fn get_super_base(&self) -> Value {
+        Value::undefined()
+    }
+
+    /// Return the `[[NewTarget]]` of a function Environment Record, or undefined otherwise.
+    fn get_new_target(&self) -> Value {
+        Value::undefined()
+    }
+
+    /// Return the function object whose invocation caused this Environment Record to be created.
+    fn get_function_object(&self) -> Option<GcObject> {
+        None
+    }
+
     /// If this Environment Record is associated with a with statement, return the with object.
     /// Otherwise, return undefined.
     fn with_base_object(&self) -> Value;
@@ -82,4 +134,10 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
 
     /// Fetch global variable
     fn get_global_object(&self) -> Option<Value>;
+
+    /// Return the key of the module this Environment Record is the scope of, if it is the
+    /// Environment Record of a module.
+    fn get_module_key(&self) -> Option<String> {
+        None
+    }
 }
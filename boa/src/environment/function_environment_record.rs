@@ -12,7 +12,7 @@ use crate::{
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecordBinding,
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
     },
     object::GcObject,
     Value,
@@ -59,31 +59,7 @@ pub struct FunctionEnvironmentRecord {
     pub outer_env: Option<Environment>,
 }
 
-impl FunctionEnvironmentRecord {
-    pub fn bind_this_value(&mut self, value: Value) {
-        match self.this_binding_status {
-            // You can not bind an arrow function, their `this` value comes from the lexical scope above
-            BindingStatus::Lexical => {
-                // TODO: change this when error handling comes into play
-                panic!("Cannot bind to an arrow function!");
-            }
-            // You can not bind a function twice
-            BindingStatus::Initialized => {
-                // TODO: change this when error handling comes into play
-                panic!("Reference Error: Cannot bind to an initialised function!");
-            }
-
-            BindingStatus::Uninitialized => {
-                self.this_value = value;
-                self.this_binding_status = BindingStatus::Initialized;
-            }
-        }
-    }
-}
-
 impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
-    // TODO: get_super_base can't implement until GetPrototypeof is implemented on object
-
     fn has_binding(&self, name: &str) -> bool {
         self.env_rec.contains_key(name)
     }
@@ -105,18 +81,33 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         );
     }
 
-    fn get_this_binding(&self) -> Value {
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
         match self.this_binding_status {
-            BindingStatus::Lexical => {
-                // TODO: change this when error handling comes into play
-                panic!("There is no this for a lexical function record");
-            }
+            BindingStatus::Lexical => Err(EnvironmentError::new(
+                "There is no this for a lexical function record",
+            )),
+            BindingStatus::Uninitialized => Err(EnvironmentError::new(
+                "Must call super constructor before accessing 'this'",
+            )),
+            BindingStatus::Initialized => Ok(self.this_value.clone()),
+        }
+    }
+
+    fn bind_this_value(&mut self, value: Value) -> Result<Value, EnvironmentError> {
+        match self.this_binding_status {
+            // You can not bind an arrow function, their `this` value comes from the lexical scope above
+            BindingStatus::Lexical => Err(EnvironmentError::new(
+                "Cannot bind this to an arrow function",
+            )),
+            // You can not bind a function twice
+            BindingStatus::Initialized => Err(EnvironmentError::new(
+                "Super constructor may only be called once",
+            )),
             BindingStatus::Uninitialized => {
-                // TODO: change this when error handling comes into play
-                panic!("Reference Error: Uninitialised binding for this function");
+                self.this_value = value.clone();
+                self.this_binding_status = BindingStatus::Initialized;
+                Ok(value)
             }
-
-            BindingStatus::Initialized => self.this_value.clone(),
         }
     }
 
@@ -152,16 +143,20 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
     }
 
     #[allow(clippy::else_if_without_else)]
-    fn set_mutable_binding(&mut self, name: &str, value: Value, mut strict: bool) {
-        if self.env_rec.get(name).is_none() {
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        mut strict: bool,
+    ) -> Result<(), EnvironmentError> {
+        if !self.env_rec.contains_key(name) {
             if strict {
-                // TODO: change this when error handling comes into play
-                panic!("Reference Error: Cannot set mutable binding for {}", name);
+                return Err(EnvironmentError::new(&format!("{} is not defined", name)));
             }
 
             self.create_mutable_binding(name.to_owned(), true);
             self.initialize_binding(name, value);
-            return;
+            return Ok(());
         }
 
         let record: &mut DeclarativeEnvironmentRecordBinding = self.env_rec.get_mut(name).unwrap();
@@ -170,16 +165,21 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         }
 
         if record.value.is_none() {
-            // TODO: change this when error handling comes into play
-            panic!("Reference Error: Cannot set mutable binding for {}", name);
+            return Err(EnvironmentError::new(&format!(
+                "Cannot access {} before initialization",
+                name
+            )));
         }
 
         if record.mutable {
             record.value = Some(value);
         } else if strict {
-            // TODO: change this when error handling comes into play
-            panic!("TypeError: Cannot mutate an immutable binding {}", name);
+            return Err(EnvironmentError::type_error(&format!(
+                "Cannot assign to the constant {}",
+                name
+            )));
         }
+        Ok(())
     }
 
     fn get_binding_value(&self, name: &str, _strict: bool) -> Value {
@@ -197,15 +197,11 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
 
     fn delete_binding(&mut self, name: &str) -> bool {
         match self.env_rec.get(name) {
-            Some(binding) => {
-                if binding.can_delete {
-                    self.env_rec.remove(name);
-                    true
-                } else {
-                    false
-                }
+            Some(binding) if binding.can_delete => {
+                self.env_rec.remove(name);
+                true
             }
-            None => false,
+            _ => false,
         }
     }
 
@@ -217,6 +213,21 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         }
     }
 
+    fn get_super_base(&self) -> Value {
+        match self.home_object {
+            Value::Object(ref home) => home.borrow().prototype_instance().clone(),
+            _ => Value::undefined(),
+        }
+    }
+
+    fn get_new_target(&self) -> Value {
+        self.new_target.clone()
+    }
+
+    fn get_function_object(&self) -> Option<GcObject> {
+        Some(self.function.clone())
+    }
+
     fn has_this_binding(&self) -> bool {
         !matches!(self.this_binding_status, BindingStatus::Lexical)
     }
@@ -226,10 +237,7 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
     }
 
     fn get_outer_environment(&self) -> Option<Environment> {
-        match &self.outer_env {
-            Some(outer) => Some(outer.clone()),
-            None => None,
-        }
+        self.outer_env.clone()
     }
 
     fn set_outer_environment(&mut self, env: Environment) {
@@ -11,7 +11,7 @@ use crate::{
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
         object_environment_record::ObjectEnvironmentRecord,
     },
     property::{Attribute, DataDescriptor},
@@ -90,8 +90,8 @@ impl GlobalEnvironmentRecord {
 }
 
 impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
-    fn get_this_binding(&self) -> Value {
-        self.global_this_binding.clone()
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(self.global_this_binding.clone())
     }
 
     fn has_binding(&self, name: &str) -> bool {
@@ -122,7 +122,7 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
     }
 
     fn initialize_binding(&mut self, name: &str, value: Value) {
-        if self.declarative_record.has_binding(&name) {
+        if self.declarative_record.has_binding(name) {
             // TODO: assert binding is in the object environment record
             return self.declarative_record.initialize_binding(name, value);
         }
@@ -130,8 +130,13 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         panic!("Should not initialized binding without creating first.");
     }
 
-    fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool) {
-        if self.declarative_record.has_binding(&name) {
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
+        if self.declarative_record.has_binding(name) {
             return self
                 .declarative_record
                 .set_mutable_binding(name, value, strict);
@@ -140,14 +145,14 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
     }
 
     fn get_binding_value(&self, name: &str, strict: bool) -> Value {
-        if self.declarative_record.has_binding(&name) {
+        if self.declarative_record.has_binding(name) {
             return self.declarative_record.get_binding_value(name, strict);
         }
         self.object_record.get_binding_value(name, strict)
     }
 
     fn delete_binding(&mut self, name: &str) -> bool {
-        if self.declarative_record.has_binding(&name) {
+        if self.declarative_record.has_binding(name) {
             return self.declarative_record.delete_binding(name);
         }
 
@@ -11,9 +11,11 @@ use crate::{
         environment_record_trait::EnvironmentRecordTrait,
         function_environment_record::{BindingStatus, FunctionEnvironmentRecord},
         global_environment_record::GlobalEnvironmentRecord,
+        module_environment_record::ModuleEnvironmentRecord,
         object_environment_record::ObjectEnvironmentRecord,
     },
     object::GcObject,
+    value::RcSymbol,
     BoaProfiler, Value,
 };
 use gc::{Gc, GcCell};
@@ -30,6 +32,7 @@ pub enum EnvironmentType {
     Declarative,
     Function,
     Global,
+    Module,
     Object,
 }
 
@@ -47,18 +50,43 @@ pub struct LexicalEnvironment {
     environment_stack: VecDeque<Environment>,
 }
 
+/// The kind of JavaScript error that an `EnvironmentError` is thrown as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EnvironmentErrorKind {
+    /// A `ReferenceError`, for a binding that does not exist or is not initialized.
+    Reference,
+    /// A `TypeError`, for a binding that cannot be changed.
+    Type,
+}
+
 /// An error that occurred during lexing or compiling of the source input.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct EnvironmentError {
     details: String,
+    kind: EnvironmentErrorKind,
 }
 
 impl EnvironmentError {
+    /// Creates an error that is thrown as a `ReferenceError`.
     pub fn new(msg: &str) -> Self {
         Self {
             details: msg.to_string(),
+            kind: EnvironmentErrorKind::Reference,
+        }
+    }
+
+    /// Creates an error that is thrown as a `TypeError`.
+    pub fn type_error(msg: &str) -> Self {
+        Self {
+            details: msg.to_string(),
+            kind: EnvironmentErrorKind::Type,
         }
     }
+
+    /// Gets the kind of JavaScript error the error is thrown as.
+    pub fn kind(&self) -> EnvironmentErrorKind {
+        self.kind
+    }
 }
 
 impl fmt::Display for EnvironmentError {
@@ -91,9 +119,16 @@ impl LexicalEnvironment {
         lexical_env
     }
 
+    /// Pushes `env` on the stack, making it the current environment.
+    ///
+    /// An environment that has no outer environment yet gets the current environment as its
+    /// outer environment, the others, such as the environment of a function, keep the scope they
+    /// were created in.
     pub fn push(&mut self, env: Environment) {
-        let current_env: Environment = self.get_current_environment().clone();
-        env.borrow_mut().set_outer_environment(current_env);
+        if env.borrow().get_outer_environment().is_none() {
+            let current_env: Environment = self.get_current_environment().clone();
+            env.borrow_mut().set_outer_environment(current_env);
+        }
         self.environment_stack.push_back(env);
     }
 
@@ -101,23 +136,81 @@ impl LexicalEnvironment {
         self.environment_stack.pop_back()
     }
 
-    pub fn environments(&self) -> impl Iterator<Item = &Environment> {
-        self.environment_stack.iter().rev()
+    /// Returns the number of environments on the stack.
+    pub(crate) fn depth(&self) -> usize {
+        self.environment_stack.len()
+    }
+
+    /// Removes the environments above the given depth from the stack, and returns them from
+    /// the outermost to the innermost.
+    pub(crate) fn split_off(&mut self, depth: usize) -> Vec<Environment> {
+        self.environment_stack.split_off(depth).into()
+    }
+
+    /// Returns the chain of environments visible from the current environment, from the
+    /// innermost to the outermost.
+    pub fn environments(&self) -> impl Iterator<Item = Environment> {
+        std::iter::successors(Some(self.get_current_environment_ref().clone()), |env| {
+            env.borrow().get_outer_environment()
+        })
+    }
+
+    /// Returns the global environment, which is the outermost environment of the stack.
+    pub(crate) fn get_global_environment(&self) -> &Environment {
+        self.environment_stack
+            .front()
+            .expect("Could not get the global environment")
     }
 
     pub fn get_global_object(&self) -> Option<Value> {
         self.environment_stack
-            .get(0)
+            .front()
             .expect("")
             .borrow()
             .get_global_object()
     }
 
-    pub fn get_this_binding(&self) -> Value {
+    pub fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
         self.environments()
             .find(|env| env.borrow().has_this_binding())
             .map(|env| env.borrow().get_this_binding())
-            .unwrap_or_else(Value::undefined)
+            .unwrap_or_else(|| Ok(Value::undefined()))
+    }
+
+    /// Finds the environment that currently supplies the binding of the keyword `this`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getthisenvironment
+    pub fn get_this_environment(&self) -> Environment {
+        self.environments()
+            .find(|env| env.borrow().has_this_binding())
+            .expect("The global environment always has a this binding")
+    }
+
+    /// Returns the environment that holds the `var` and function declarations of the running
+    /// code, which is the first function, global or module environment of the chain.
+    pub(crate) fn get_var_environment(&self) -> Environment {
+        self.environments()
+            .find(|env| {
+                matches!(
+                    env.borrow().get_environment_type(),
+                    EnvironmentType::Function | EnvironmentType::Global | EnvironmentType::Module
+                )
+            })
+            .expect("No function, global or module environment")
+    }
+
+    /// Declares the variable `name` in the var environment, with the value `undefined`, unless
+    /// it is already declared there.
+    pub(crate) fn declare_var(&self, name: &str) {
+        let env = self.get_var_environment();
+        if !env.borrow().has_binding(name) {
+            let mut env = env.borrow_mut();
+            env.create_mutable_binding(name.to_owned(), false);
+            env.initialize_binding(name, Value::undefined());
+        }
     }
 
     pub fn create_mutable_binding(&mut self, name: String, deletion: bool, scope: VariableScope) {
@@ -127,16 +220,7 @@ impl LexicalEnvironment {
                 .borrow_mut()
                 .create_mutable_binding(name, deletion),
             VariableScope::Function => {
-                // Find the first function or global environment (from the top of the stack)
-                let env = self
-                    .environments()
-                    .find(|env| {
-                        matches!(
-                            env.borrow().get_environment_type(),
-                            EnvironmentType::Function | EnvironmentType::Global
-                        )
-                    })
-                    .expect("No function or global environment");
+                let env = self.get_var_environment();
 
                 env.borrow_mut().create_mutable_binding(name, deletion);
             }
@@ -155,30 +239,33 @@ impl LexicalEnvironment {
                 .borrow_mut()
                 .create_immutable_binding(name, deletion),
             VariableScope::Function => {
-                // Find the first function or global environment (from the top of the stack)
-                let env = self
-                    .environments()
-                    .find(|env| {
-                        matches!(
-                            env.borrow().get_environment_type(),
-                            EnvironmentType::Function | EnvironmentType::Global
-                        )
-                    })
-                    .expect("No function or global environment");
-
-                env.borrow_mut().create_immutable_binding(name, deletion)
+                let env = self.get_var_environment();
+
+                let created = env.borrow_mut().create_immutable_binding(name, deletion);
+                created
             }
         }
     }
 
-    pub fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool) {
+    /// Sets the value of the binding `name` in the first environment that has it.
+    ///
+    /// If there is no such binding, it is created in the global environment, unless `strict`
+    /// is `true`.
+    pub fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
         // Find the first environment which has the given binding
         let env = self
             .environments()
             .find(|env| env.borrow().has_binding(name))
-            .expect("Binding does not exists"); // TODO graceful error handling
+            .or_else(|| self.environment_stack.front().cloned())
+            .expect("there is always a global environment");
 
-        env.borrow_mut().set_mutable_binding(name, value, strict);
+        let result = env.borrow_mut().set_mutable_binding(name, value, strict);
+        result
     }
 
     pub fn initialize_binding(&mut self, name: &str, value: Value) {
@@ -217,6 +304,15 @@ impl LexicalEnvironment {
             .find(|env| env.borrow().has_binding(name))
             .map(|env| env.borrow().get_binding_value(name, false))
     }
+
+    /// Returns the object of the `with` statement environment that binds `name`, if it is bound
+    /// by one.
+    pub fn get_with_base_object(&self, name: &str) -> Option<Value> {
+        self.environments()
+            .find(|env| env.borrow().has_binding(name))
+            .map(|env| env.borrow().with_base_object())
+            .filter(Value::is_object)
+    }
 }
 
 pub fn new_declarative_environment(env: Option<Environment>) -> Environment {
@@ -234,33 +330,72 @@ pub fn new_function_environment(
     this: Option<Value>,
     outer: Option<Environment>,
     binding_status: BindingStatus,
+    new_target: Value,
 ) -> Environment {
+    let home_object = f
+        .borrow()
+        .as_function()
+        .and_then(|function| function.home_object().cloned())
+        .map(Value::from)
+        .unwrap_or_else(Value::undefined);
     let mut func_env = FunctionEnvironmentRecord {
         env_rec: FxHashMap::default(),
         function: f,
         this_binding_status: binding_status,
-        home_object: Value::undefined(),
-        new_target: Value::undefined(),
+        home_object,
+        new_target,
         outer_env: outer, // this will come from Environment set as a private property of F - https://tc39.es/ecma262/#sec-ecmascript-function-objects
         this_value: Value::undefined(),
     };
     // If a `this` value has been passed, bind it to the environment
     if let Some(v) = this {
-        func_env.bind_this_value(v);
+        func_env
+            .bind_this_value(v)
+            .expect("Could not bind this to a new function environment");
     }
     Gc::new(GcCell::new(Box::new(func_env)))
 }
 
+/// Creates the environment of the module identified by `module_key`, which is the scope of its
+/// top level declarations and of its imports.
+pub fn new_module_environment(module_key: String, outer: Option<Environment>) -> Environment {
+    let _timer = BoaProfiler::global().start_event("new_module_environment", "env");
+    Gc::new(GcCell::new(Box::new(ModuleEnvironmentRecord {
+        declarative_record: DeclarativeEnvironmentRecord {
+            env_rec: FxHashMap::default(),
+            outer_env: outer,
+        },
+        import_bindings: FxHashMap::default(),
+        module_key,
+    })))
+}
+
 pub fn new_object_environment(object: Value, environment: Option<Environment>) -> Environment {
     Gc::new(GcCell::new(Box::new(ObjectEnvironmentRecord {
         bindings: object,
         outer_env: environment,
-        /// Object Environment Records created for with statements (13.11)
-        /// can provide their binding object as an implicit this value for use in function calls.
-        /// The capability is controlled by a withEnvironment Boolean value that is associated
-        /// with each object Environment Record. By default, the value of withEnvironment is false
-        /// for any object Environment Record.
+        // Object Environment Records created for with statements (13.11)
+        // can provide their binding object as an implicit this value for use in function calls.
+        // The capability is controlled by a withEnvironment Boolean value that is associated
+        // with each object Environment Record. By default, the value of withEnvironment is false
+        // for any object Environment Record.
         with_environment: false,
+        unscopables: None,
+    })))
+}
+
+/// Creates the object environment of a `with` statement, whose bindings are the properties of
+/// `object` that are not excluded by its `Symbol.unscopables` property.
+pub fn new_with_environment(
+    object: Value,
+    unscopables: RcSymbol,
+    environment: Option<Environment>,
+) -> Environment {
+    Gc::new(GcCell::new(Box::new(ObjectEnvironmentRecord {
+        bindings: object,
+        outer_env: environment,
+        with_environment: true,
+        unscopables: Some(unscopables),
     })))
 }
 
@@ -268,12 +403,13 @@ pub fn new_global_environment(global: Value, this_value: Value) -> Environment {
     let obj_rec = ObjectEnvironmentRecord {
         bindings: global,
         outer_env: None,
-        /// Object Environment Records created for with statements (13.11)
-        /// can provide their binding object as an implicit this value for use in function calls.
-        /// The capability is controlled by a withEnvironment Boolean value that is associated
-        /// with each object Environment Record. By default, the value of withEnvironment is false
-        /// for any object Environment Record.
+        // Object Environment Records created for with statements (13.11)
+        // can provide their binding object as an implicit this value for use in function calls.
+        // The capability is controlled by a withEnvironment Boolean value that is associated
+        // with each object Environment Record. By default, the value of withEnvironment is false
+        // for any object Environment Record.
         with_environment: false,
+        unscopables: None,
     };
 
     let dcl_rec = DeclarativeEnvironmentRecord {
@@ -5,4 +5,5 @@ pub mod environment_record_trait;
 pub mod function_environment_record;
 pub mod global_environment_record;
 pub mod lexical_environment;
+pub mod module_environment_record;
 pub mod object_environment_record;
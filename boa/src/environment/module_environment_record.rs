@@ -0,0 +1,158 @@
+//! # Module Environment Records
+//!
+//! A module Environment Record is a declarative Environment Record that is used to represent
+//! the outer scope of an ECMAScript Module. In addition to normal mutable and immutable
+//! bindings, module Environment Records also provide immutable import bindings which are
+//! bindings that provide indirect access to a target binding that exists in another
+//! Environment Record.
+//! More info: <https://tc39.es/ecma262/#sec-module-environment-records>
+
+use crate::{
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        environment_record_trait::EnvironmentRecordTrait,
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
+    },
+    Value,
+};
+use gc::{Finalize, Trace};
+use rustc_hash::FxHashMap;
+
+/// An import binding, which gets its value from the binding `name` of the environment of the
+/// module that exports it.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ImportBinding {
+    pub target: Environment,
+    pub name: String,
+}
+
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ModuleEnvironmentRecord {
+    pub declarative_record: DeclarativeEnvironmentRecord,
+    pub import_bindings: FxHashMap<String, ImportBinding>,
+    /// The key the host resolved the module to, used as the referrer of its imports.
+    pub module_key: String,
+}
+
+impl EnvironmentRecordTrait for ModuleEnvironmentRecord {
+    fn has_binding(&self, name: &str) -> bool {
+        match self.import_bindings.get(name) {
+            // The target binding doesn't exist until its declaration has been evaluated.
+            Some(binding) => binding.target.borrow().has_binding(&binding.name),
+            None => self.declarative_record.has_binding(name),
+        }
+    }
+
+    fn create_mutable_binding(&mut self, name: String, deletion: bool) {
+        if self.import_bindings.contains_key(&name) {
+            // TODO: change this when error handling comes into play
+            panic!("Identifier {} has already been declared", name);
+        }
+        self.declarative_record
+            .create_mutable_binding(name, deletion)
+    }
+
+    fn create_immutable_binding(&mut self, name: String, strict: bool) -> bool {
+        if self.import_bindings.contains_key(&name) {
+            // TODO: change this when error handling comes into play
+            panic!("Identifier {} has already been declared", name);
+        }
+        self.declarative_record
+            .create_immutable_binding(name, strict)
+    }
+
+    fn create_import_binding(
+        &mut self,
+        name: String,
+        target: Environment,
+        target_name: String,
+    ) -> Result<(), EnvironmentError> {
+        if self.has_binding(&name) || self.import_bindings.contains_key(&name) {
+            return Err(EnvironmentError::new(&format!(
+                "Identifier {} has already been declared",
+                name
+            )));
+        }
+        self.import_bindings.insert(
+            name,
+            ImportBinding {
+                target,
+                name: target_name,
+            },
+        );
+        Ok(())
+    }
+
+    fn initialize_binding(&mut self, name: &str, value: Value) {
+        self.declarative_record.initialize_binding(name, value)
+    }
+
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
+        if self.import_bindings.contains_key(name) {
+            return Err(EnvironmentError::type_error(&format!(
+                "Cannot assign to the import {}",
+                name
+            )));
+        }
+        self.declarative_record
+            .set_mutable_binding(name, value, strict)
+    }
+
+    fn get_binding_value(&self, name: &str, strict: bool) -> Value {
+        match self.import_bindings.get(name) {
+            Some(binding) => binding
+                .target
+                .borrow()
+                .get_binding_value(&binding.name, strict),
+            None => self.declarative_record.get_binding_value(name, strict),
+        }
+    }
+
+    fn delete_binding(&mut self, name: &str) -> bool {
+        if self.import_bindings.contains_key(name) {
+            return false;
+        }
+        self.declarative_record.delete_binding(name)
+    }
+
+    fn has_this_binding(&self) -> bool {
+        true
+    }
+
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(Value::undefined())
+    }
+
+    fn has_super_binding(&self) -> bool {
+        false
+    }
+
+    fn with_base_object(&self) -> Value {
+        Value::undefined()
+    }
+
+    fn get_outer_environment(&self) -> Option<Environment> {
+        self.declarative_record.get_outer_environment()
+    }
+
+    fn set_outer_environment(&mut self, env: Environment) {
+        self.declarative_record.set_outer_environment(env)
+    }
+
+    fn get_environment_type(&self) -> EnvironmentType {
+        EnvironmentType::Module
+    }
+
+    fn get_global_object(&self) -> Option<Value> {
+        self.declarative_record.get_global_object()
+    }
+
+    fn get_module_key(&self) -> Option<String> {
+        Some(self.module_key.clone())
+    }
+}
@@ -9,9 +9,10 @@
 use crate::{
     environment::{
         environment_record_trait::EnvironmentRecordTrait,
-        lexical_environment::{Environment, EnvironmentType},
+        lexical_environment::{Environment, EnvironmentError, EnvironmentType},
     },
-    property::{Attribute, DataDescriptor},
+    property::{Attribute, DataDescriptor, PropertyDescriptor},
+    value::RcSymbol,
     Value,
 };
 use gc::{Finalize, Trace};
@@ -21,18 +22,26 @@ pub struct ObjectEnvironmentRecord {
     pub bindings: Value,
     pub with_environment: bool,
     pub outer_env: Option<Environment>,
+    /// The `Symbol.unscopables` symbol, whose property on the binding object of a `with`
+    /// environment lists the names it does not bind.
+    pub unscopables: Option<RcSymbol>,
 }
 
 impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
     fn has_binding(&self, name: &str) -> bool {
-        if self.bindings.has_field(name) {
-            if self.with_environment {
-                // TODO: implement unscopables
+        if !self.bindings.has_field(name) {
+            return false;
+        }
+
+        if let Some(ref unscopables) = self.unscopables {
+            // No JavaScript code can run here, so only the data properties of the unscopables
+            // object are looked up, without calling getters.
+            let unscopables = self.bindings.get_data_field(unscopables.clone());
+            if unscopables.is_object() && unscopables.get_data_field(name).to_boolean() {
+                return false;
             }
-            true
-        } else {
-            false
         }
+        true
     }
 
     fn create_mutable_binding(&mut self, name: String, deletion: bool) {
@@ -56,24 +65,53 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         // We should never need to check if a binding has been created,
         // As all calls to create_mutable_binding are followed by initialized binding
         // The below is just a check.
-        debug_assert!(self.has_binding(&name));
+        debug_assert!(self.has_binding(name));
         self.set_mutable_binding(name, value, false)
+            .expect("initializing an existing binding cannot fail")
     }
 
-    fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool) {
-        debug_assert!(value.is_object() || value.is_function());
+    fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<(), EnvironmentError> {
+        // No JavaScript code can run here, so the setters of accessor properties are not called.
+        let attributes = match self.bindings.get_property(name) {
+            None if strict => {
+                return Err(EnvironmentError::new(&format!("{} is not defined", name)));
+            }
+            Some(PropertyDescriptor::Data(ref desc)) if !desc.writable() => {
+                return if strict {
+                    Err(EnvironmentError::type_error(&format!(
+                        "Cannot assign to read only property '{}'",
+                        name
+                    )))
+                } else {
+                    Ok(())
+                };
+            }
+            Some(PropertyDescriptor::Accessor(_)) => return Ok(()),
+            _ => match self
+                .bindings
+                .as_object()
+                .and_then(|object| object.ordinary_get_own_property(&name.into()))
+            {
+                Some(own) => own.attributes(),
+                None => Attribute::all(),
+            },
+        };
 
-        let mut property = DataDescriptor::new(value, Attribute::ENUMERABLE);
-        property.set_configurable(strict);
         self.bindings
             .as_object()
             .expect("binding object")
-            .insert(name, property);
+            .insert(name, DataDescriptor::new(value, attributes));
+        Ok(())
     }
 
     fn get_binding_value(&self, name: &str, strict: bool) -> Value {
         if self.bindings.has_field(name) {
-            self.bindings.get_field(name)
+            self.bindings.get_data_field(name)
         } else {
             if strict {
                 // TODO: throw error here
@@ -92,8 +130,8 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         false
     }
 
-    fn get_this_binding(&self) -> Value {
-        Value::undefined()
+    fn get_this_binding(&self) -> Result<Value, EnvironmentError> {
+        Ok(Value::undefined())
     }
 
     fn has_super_binding(&self) -> bool {
@@ -111,10 +149,7 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
     }
 
     fn get_outer_environment(&self) -> Option<Environment> {
-        match &self.outer_env {
-            Some(outer) => Some(outer.clone()),
-            None => None,
-        }
+        self.outer_env.clone()
     }
 
     fn set_outer_environment(&mut self, env: Environment) {
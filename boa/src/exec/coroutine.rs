@@ -0,0 +1,324 @@
+//! Suspendable execution of function bodies.
+//!
+//! Generator and async function bodies are run by the same tree-walking interpreter as any
+//! other code, but they must be able to stop in the middle of an expression and continue
+//! later on.
+//!
+//! A `yield` or `await` expression suspends the running body by returning an error, which
+//! unwinds the interpreter up to the [`Coroutine`] that runs the body, without running any
+//! `catch` or `finally` block. On the way out, each node that was being evaluated saves how far
+//! it got in a [`NodeState`]. These states are kept by the coroutine, along with the
+//! environments of the body, until it is resumed. The body is then run again from its root:
+//! every node on the way down restores its state and continues with the child that was
+//! suspended, until the suspended expression is reached and evaluates to the value the
+//! coroutine was resumed with.
+//!
+//! Nodes that don't do anything observable before evaluating their first child don't need to
+//! save a state to be suspended in that child, as running them again does the same thing.
+
+use crate::{
+    environment::lexical_environment::Environment, exec::Executable, exec::InterpreterState,
+    syntax::ast::node::RcStatementList, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::{convert::TryInto, mem};
+
+/// The way a suspended coroutine is resumed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-generatorresumeabrupt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResumeKind {
+    /// Continue normally, with the resumption value as the result of the suspending expression.
+    Next,
+    /// Throw the resumption value from the point where the coroutine was suspended.
+    Throw,
+    /// Return the resumption value from the point where the coroutine was suspended, running
+    /// any `finally` blocks on the way out.
+    Return,
+}
+
+/// The outcome of resuming a coroutine.
+#[derive(Debug)]
+pub(crate) enum CoroutineState {
+    /// The coroutine suspended itself with the given value.
+    Suspended(Value),
+    /// The coroutine ran to completion.
+    Completed(Result<Value>),
+}
+
+/// How far the evaluation of a node got before its coroutine was suspended.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct NodeState {
+    /// The address of the node, to check that the state is restored by the node that saved it.
+    node: usize,
+    /// The step of its evaluation the node was at, which is specific to each kind of node.
+    pub(crate) step: usize,
+    /// The values computed by the node before it was suspended.
+    values: Vec<Value>,
+}
+
+impl NodeState {
+    /// Returns the values of the state, when the node saves a fixed number of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state doesn't have `N` values.
+    pub(crate) fn into_values<const N: usize>(self) -> [Value; N] {
+        self.into_vec()
+            .try_into()
+            .expect("the state of the node has the wrong number of values")
+    }
+
+    /// Returns the values of the state, when the node saves a varying number of them.
+    pub(crate) fn into_vec(mut self) -> Vec<Value> {
+        mem::take(&mut self.values)
+    }
+}
+
+/// Returns the address of a node, which identifies it while its coroutine is suspended.
+fn address<N>(node: &N) -> usize {
+    node as *const N as usize
+}
+
+/// The book-keeping of a running coroutine, kept by the interpreter.
+#[derive(Debug, Default)]
+pub(crate) struct CoroutineFrame {
+    /// The states of the nodes that are being resumed, outermost last. While the coroutine is
+    /// being suspended, the states of the nodes it is unwinding, innermost first.
+    states: Vec<NodeState>,
+    /// How the coroutine was resumed, until the suspended expression takes it.
+    resumption: Option<(ResumeKind, Value)>,
+    /// The value the coroutine is suspended with, while its body is unwinding.
+    suspension: Option<Value>,
+    /// The value being returned, when the coroutine was resumed with [`ResumeKind::Return`].
+    pending_return: Option<Value>,
+}
+
+/// A function body that can be suspended and resumed.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct Coroutine {
+    body: RcStatementList,
+    /// Whether the body is strict mode code.
+    strict: bool,
+    /// The environments of the body while it is not running, outermost first.
+    environments: Vec<Environment>,
+    /// The states of the nodes that were being evaluated when the body was suspended.
+    states: Vec<NodeState>,
+    /// Whether the body has been started.
+    started: bool,
+    /// Whether the body has completed.
+    done: bool,
+}
+
+impl Coroutine {
+    /// Creates a new coroutine that runs `body` in `environment` when it is first resumed.
+    ///
+    /// Only a `return` statement gives a value to the completion of the body.
+    pub(crate) fn new(body: RcStatementList, environment: Environment, strict: bool) -> Self {
+        Self {
+            body,
+            strict,
+            environments: vec![environment],
+            states: Vec::new(),
+            started: false,
+            done: false,
+        }
+    }
+
+    /// Resumes the coroutine, running it until it suspends itself again or completes.
+    pub(crate) fn resume(
+        &mut self,
+        kind: ResumeKind,
+        value: Value,
+        context: &mut Context,
+    ) -> CoroutineState {
+        if self.done || (!self.started && kind != ResumeKind::Next) {
+            self.done = true;
+            self.environments.clear();
+            return CoroutineState::Completed(match kind {
+                ResumeKind::Throw => Err(value),
+                ResumeKind::Next | ResumeKind::Return => Ok(value),
+            });
+        }
+
+        let state = context.executor().get_current_state().clone();
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        let env_depth = context.realm().environment.depth();
+        for environment in self.environments.drain(..) {
+            context.realm_mut().environment.push(environment);
+        }
+        context.executor().coroutine_frames.push(CoroutineFrame {
+            states: mem::take(&mut self.states),
+            resumption: if self.started {
+                Some((kind, value))
+            } else {
+                None
+            },
+            ..CoroutineFrame::default()
+        });
+        self.started = true;
+
+        let body = &self.body;
+        let result = context.run_with_strict_mode(self.strict, |context| body.run(context));
+
+        let frame = context
+            .executor()
+            .coroutine_frames
+            .pop()
+            .expect("the frame of a running coroutine disappeared");
+        let environments = context.realm_mut().environment.split_off(env_depth);
+        let returned = context.executor().get_current_state() == &InterpreterState::Return;
+        context.executor().set_current_state(state);
+
+        if let Some(value) = frame.suspension {
+            self.environments = environments;
+            self.states = frame.states;
+            return CoroutineState::Suspended(value);
+        }
+
+        debug_assert!(frame.states.is_empty(), "a suspended node was not resumed");
+        self.done = true;
+        CoroutineState::Completed(match frame.pending_return {
+            Some(value) => Ok(value),
+            None if returned => result,
+            None => result.map(|_| Value::undefined()),
+        })
+    }
+}
+
+impl Context {
+    /// Takes the state saved by `node` when its coroutine was suspended, if it is being resumed.
+    ///
+    /// Nodes that save their state must call this before evaluating any of their children, and
+    /// must evaluate all of their children with [`Context::run_step`], so that a state is
+    /// always saved when they are suspended.
+    pub(crate) fn resume_state<N>(&mut self, node: &N) -> Option<NodeState> {
+        let state = self.executor().coroutine_frames.last_mut()?.states.pop()?;
+        debug_assert_eq!(
+            state.node,
+            address(node),
+            "the state of a suspended node was restored by another node"
+        );
+        Some(state)
+    }
+
+    /// Runs `f` as the given step of the evaluation of `node`.
+    ///
+    /// If the coroutine is suspended while it runs, the state of the node is saved, with the
+    /// values returned by `values`, so that its evaluation resumes from this step.
+    pub(crate) fn run_step<N, T, V, F>(
+        &mut self,
+        node: &N,
+        step: usize,
+        values: V,
+        f: F,
+    ) -> Result<T>
+    where
+        V: FnOnce() -> Vec<Value>,
+        F: FnOnce(&mut Self) -> Result<T>,
+    {
+        let result = f(self);
+        if result.is_err() && self.is_suspending() {
+            self.save_state(node, step, values());
+        }
+        result
+    }
+
+    /// Saves the state of `node`, while its coroutine is being suspended.
+    fn save_state<N>(&mut self, node: &N, step: usize, values: Vec<Value>) {
+        if let Some(frame) = self.executor().coroutine_frames.last_mut() {
+            frame.states.push(NodeState {
+                node: address(node),
+                step,
+                values,
+            });
+        }
+    }
+
+    /// Returns `true` if the running coroutine is being suspended.
+    ///
+    /// The error that is propagated while it unwinds must not be handled by anything.
+    pub(crate) fn is_suspending(&mut self) -> bool {
+        self.executor()
+            .coroutine_frames
+            .last()
+            .is_some_and(|frame| frame.suspension.is_some())
+    }
+
+    /// Suspends the running coroutine, passing `value` to the code that resumed it.
+    ///
+    /// `node` is the suspending expression, which is resumed at the given step. This returns
+    /// the value that must be propagated as an error, so that the body of the coroutine is
+    /// unwound.
+    pub(crate) fn suspend<N>(
+        &mut self,
+        node: &N,
+        step: usize,
+        values: Vec<Value>,
+        value: Value,
+    ) -> Value {
+        match self.executor().coroutine_frames.last_mut() {
+            Some(frame) => {
+                debug_assert!(frame.states.is_empty(), "a suspended node was not resumed");
+                frame.suspension = Some(value);
+            }
+            None => return self.construct_syntax_error("cannot suspend outside of a generator"),
+        }
+        self.save_state(node, step, values);
+        Value::undefined()
+    }
+
+    /// Returns how the running coroutine was resumed, along with the value it was resumed with.
+    ///
+    /// This is called by the suspended expression, once its state is restored.
+    pub(crate) fn resumption(&mut self) -> (ResumeKind, Value) {
+        self.executor()
+            .coroutine_frames
+            .last_mut()
+            .and_then(|frame| frame.resumption.take())
+            .expect("a suspended expression was resumed without a value")
+    }
+
+    /// Starts returning `value` from the running coroutine.
+    ///
+    /// This returns the value that must be propagated as an error, so that the execution of
+    /// the coroutine is aborted. `catch` blocks don't handle it, but `finally` blocks are run.
+    pub(crate) fn return_from_coroutine(&mut self, value: Value) -> Value {
+        if let Some(frame) = self.executor().coroutine_frames.last_mut() {
+            frame.pending_return = Some(value);
+        }
+        Value::undefined()
+    }
+
+    /// Returns `true` if the running coroutine is returning, instead of throwing an error.
+    pub(crate) fn is_returning_from_coroutine(&mut self) -> bool {
+        self.executor()
+            .coroutine_frames
+            .last()
+            .is_some_and(|frame| frame.pending_return.is_some())
+    }
+
+    /// Takes the value being returned from the running coroutine, if any.
+    ///
+    /// This is used to run `finally` blocks as if no return was in progress.
+    pub(crate) fn take_coroutine_return(&mut self) -> Option<Value> {
+        self.executor()
+            .coroutine_frames
+            .last_mut()
+            .and_then(|frame| frame.pending_return.take())
+    }
+
+    /// Restores the value being returned from the running coroutine, once a `finally` block
+    /// has completed normally.
+    pub(crate) fn restore_coroutine_return(&mut self, value: Option<Value>) {
+        if let Some(frame) = self.executor().coroutine_frames.last_mut() {
+            frame.pending_return = value;
+        }
+    }
+}
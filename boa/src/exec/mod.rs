@@ -1,8 +1,10 @@
 //! Execution of the AST, this is where the interpreter actually runs
 
+pub(crate) mod coroutine;
 #[cfg(test)]
 mod tests;
 
+use self::coroutine::CoroutineFrame;
 use crate::{Context, Result, Value};
 
 pub trait Executable {
@@ -10,7 +12,7 @@ pub trait Executable {
     fn run(&self, interpreter: &mut Context) -> Result<Value>;
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum InterpreterState {
     Executing,
     Return,
@@ -23,6 +25,10 @@ pub(crate) enum InterpreterState {
 pub struct Interpreter {
     /// the current state of the interpreter.
     state: InterpreterState,
+    /// The coroutines that are currently running, innermost last.
+    pub(crate) coroutine_frames: Vec<CoroutineFrame>,
+    /// Whether the running code is strict mode code.
+    strict: bool,
 }
 
 impl Default for Interpreter {
@@ -36,6 +42,8 @@ impl Interpreter {
     pub fn new() -> Self {
         Self {
             state: InterpreterState::Executing,
+            coroutine_frames: Vec::new(),
+            strict: false,
         }
     }
 
@@ -48,4 +56,15 @@ impl Interpreter {
     pub(crate) fn get_current_state(&self) -> &InterpreterState {
         &self.state
     }
+
+    /// Returns `true` if the running code is strict mode code.
+    #[inline]
+    pub(crate) fn strict(&self) -> bool {
+        self.strict
+    }
+
+    #[inline]
+    pub(crate) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict
+    }
 }
@@ -782,10 +782,23 @@ mod in_operator {
         let foo_val = forward_val(&mut engine, "Foo").unwrap();
         assert!(bar_obj
             .prototype_instance()
-            .strict_equals(&foo_val.get_field("prototype")));
+            .strict_equals(&foo_val.get_field("prototype", &mut engine).unwrap()));
     }
 }
 
+#[test]
+fn var_decl_shadows_outer_binding() {
+    let scenario = r#"
+        var x = 'outer';
+        function f() {
+            var x = 'inner';
+            return x;
+        }
+        f() + ' ' + x;
+    "#;
+    assert_eq!(&exec(scenario), "\"inner outer\"");
+}
+
 #[test]
 fn var_decl_hoisting_simple() {
     let scenario = r#"
@@ -990,7 +1003,7 @@ fn to_int32() {
         ($from:expr => $to:expr) => {
             assert_eq!(Value::from($from).to_i32(&mut engine).unwrap(), $to);
         };
-    };
+    }
 
     check_to_int32!(f64::NAN => 0);
     check_to_int32!(f64::NEG_INFINITY => 0);
@@ -1314,7 +1327,7 @@ fn multicharacter_assignment_to_non_assignable() {
     let test_cases = ["3 **= 5", "3 <<= 5", "3 >>= 5"];
 
     for case in test_cases.iter() {
-        let string = dbg!(forward(&mut engine, case));
+        let string = forward(&mut engine, case);
 
         assert!(string.starts_with("Uncaught \"SyntaxError\": "));
         assert!(string.contains("1:3"));
@@ -1330,7 +1343,7 @@ fn multicharacter_bitwise_assignment_to_non_assignable() {
     let test_cases = ["3 >>>= 5", "3 &&= 5", "3 ||= 5", "3 ??= 5"];
 
     for case in test_cases.iter() {
-        let string = dbg!(forward(&mut engine, case));
+        let string = forward(&mut engine, case);
 
         assert!(string.starts_with("Uncaught \"SyntaxError\": "));
         assert!(string.contains("1:3"));
@@ -1409,7 +1422,7 @@ fn test_strict_mode_octal() {
 
     let mut engine = Context::new();
 
-    let string = dbg!(forward(&mut engine, scenario));
+    let string = forward(&mut engine, scenario);
 
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
@@ -1430,7 +1443,7 @@ fn test_strict_mode_with() {
 
     let mut engine = Context::new();
 
-    let string = dbg!(forward(&mut engine, scenario));
+    let string = forward(&mut engine, scenario);
 
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
@@ -1448,7 +1461,7 @@ fn test_strict_mode_delete() {
 
     let mut engine = Context::new();
 
-    let string = dbg!(forward(&mut engine, scenario));
+    let string = forward(&mut engine, scenario);
 
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
@@ -1476,7 +1489,7 @@ fn test_strict_mode_reserved_name() {
         let mut engine = Context::new();
         let scenario = format!("'use strict'; \n {}", case);
 
-        let string = dbg!(forward(&mut engine, &scenario));
+        let string = forward(&mut engine, &scenario);
 
         assert!(string.starts_with("Uncaught \"SyntaxError\": "));
     }
@@ -1496,7 +1509,7 @@ fn test_strict_mode_func_decl_in_block() {
 
     let mut engine = Context::new();
 
-    let string = dbg!(forward(&mut engine, scenario));
+    let string = forward(&mut engine, scenario);
 
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
@@ -1513,7 +1526,168 @@ fn test_strict_mode_dup_func_parameters() {
 
     let mut engine = Context::new();
 
-    let string = dbg!(forward(&mut engine, scenario));
+    let string = forward(&mut engine, scenario);
 
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
+
+#[test]
+fn test_strict_mode_dup_parameters_directive() {
+    // Duplicate parameter names are an error in a function whose own body is strict, and in
+    // an arrow function or a method in any case.
+
+    let test_cases = [
+        "function f(a, a) { 'use strict'; }",
+        "(a, a) => 1",
+        "({ m(a, a) {} })",
+    ];
+
+    for case in test_cases.iter() {
+        let mut engine = Context::new();
+
+        let string = forward(&mut engine, case);
+
+        assert!(string.starts_with("Uncaught \"SyntaxError\": "));
+    }
+
+    let mut engine = Context::new();
+    assert_eq!(
+        forward(&mut engine, "function f(a, a) { return a; } f(1, 2)"),
+        "2"
+    );
+}
+
+#[test]
+fn test_strict_mode_octal_escape() {
+    let mut engine = Context::new();
+
+    let string = forward(&mut engine, "'use strict'; '\\01'");
+    assert!(string.starts_with("Uncaught \"SyntaxError\": "));
+
+    assert_eq!(forward(&mut engine, "'\\01' === '\\x01'"), "true");
+}
+
+#[test]
+fn test_strict_mode_assign_undeclared() {
+    let scenario = r#"
+    function sloppy() { notDeclared = 1; }
+    sloppy();
+    var error;
+    try {
+        (function () { 'use strict'; alsoNotDeclared = 1; })();
+    } catch (e) {
+        error = e;
+    }
+    "#;
+
+    let mut engine = Context::new();
+    forward(&mut engine, scenario);
+
+    assert_eq!(forward(&mut engine, "notDeclared"), "1");
+    assert_eq!(
+        forward(&mut engine, "error instanceof ReferenceError"),
+        "true"
+    );
+    assert_eq!(forward(&mut engine, "'alsoNotDeclared' in this"), "false");
+}
+
+#[test]
+fn test_strict_mode_assign_read_only() {
+    let scenario = r#"
+    var frozen = Object.freeze({ a: 1 });
+    function sloppy() { frozen.a = 2; frozen.b = 3; return frozen.a; }
+    function strict() {
+        'use strict';
+        try {
+            frozen.a = 2;
+        } catch (e) {
+            return e instanceof TypeError;
+        }
+    }
+    function strictUndefined() {
+        'use strict';
+        try {
+            undefined = 1;
+        } catch (e) {
+            return e instanceof TypeError;
+        }
+    }
+    function strictDelete() {
+        'use strict';
+        try {
+            delete Object.prototype;
+        } catch (e) {
+            return e instanceof TypeError;
+        }
+    }
+    "#;
+
+    let mut engine = Context::new();
+    forward(&mut engine, scenario);
+
+    assert_eq!(forward(&mut engine, "sloppy()"), "1");
+    assert_eq!(forward(&mut engine, "strict()"), "true");
+    assert_eq!(forward(&mut engine, "strictUndefined()"), "true");
+    assert_eq!(forward(&mut engine, "strictDelete()"), "true");
+}
+
+#[test]
+fn test_strict_mode_const_assignment() {
+    let scenario = r#"
+    function f() {
+        const c = 1;
+        try {
+            c = 2;
+        } catch (e) {
+            return e instanceof TypeError && c === 1;
+        }
+    }
+    "#;
+
+    let mut engine = Context::new();
+    forward(&mut engine, scenario);
+
+    assert_eq!(forward(&mut engine, "f()"), "true");
+}
+
+#[test]
+fn test_strict_mode_this() {
+    let scenario = r#"
+    function sloppy() { return this; }
+    function strict() { 'use strict'; return this; }
+    function sloppyType() { return typeof this; }
+    function strictType() { 'use strict'; return typeof this; }
+    class C { m() { return this; } }
+    var m = new C().m;
+    "#;
+
+    let mut engine = Context::new();
+    forward(&mut engine, scenario);
+
+    assert_eq!(forward(&mut engine, "sloppy() === globalThis"), "true");
+    assert_eq!(forward(&mut engine, "strict()"), "undefined");
+    assert_eq!(forward(&mut engine, "sloppyType.call(1)"), "\"object\"");
+    assert_eq!(forward(&mut engine, "strictType.call(1)"), "\"number\"");
+    assert_eq!(forward(&mut engine, "m()"), "undefined");
+}
+
+#[test]
+fn test_strict_mode_arguments_callee() {
+    let scenario = r#"
+    function sloppy() { return arguments.callee === sloppy; }
+    function strict() {
+        'use strict';
+        try {
+            arguments.callee;
+        } catch (e) {
+            return e instanceof TypeError;
+        }
+    }
+    "#;
+
+    let mut engine = Context::new();
+    forward(&mut engine, scenario);
+
+    assert_eq!(forward(&mut engine, "sloppy()"), "true");
+    assert_eq!(forward(&mut engine, "strict()"), "true");
+}
@@ -4,8 +4,8 @@
 // when it should be `empty_trace`.
 #![allow(clippy::unsafe_removed_from_name)]
 
-pub use crate::object::GcObject;
+pub use crate::object::{GcObject, WeakGcObject};
 pub use ::gc::{
-    custom_trace, force_collect, unsafe_empty_trace as empty_trace, Finalize, GcCellRef as Ref,
-    GcCellRefMut as RefMut, Trace,
+    collections, custom_trace, force_collect, unsafe_empty_trace as empty_trace, Finalize,
+    GcCellRef as Ref, GcCellRefMut as RefMut, Trace,
 };
@@ -15,7 +15,6 @@ This is an experimental Javascript lexer, parser and compiler written in Rust. C
 #![deny(
     unused_qualifications,
     clippy::all,
-    unused_qualifications,
     unused_import_braces,
     unused_lifetimes,
     unreachable_pub,
@@ -39,6 +38,7 @@ This is an experimental Javascript lexer, parser and compiler written in Rust. C
     clippy::missing_errors_doc,
     clippy::as_conversions,
     clippy::let_unit_value,
+    non_local_definitions,
     missing_doc_code_examples
 )]
 
@@ -47,6 +47,7 @@ pub mod class;
 pub mod environment;
 pub mod exec;
 pub mod gc;
+pub mod module;
 pub mod object;
 pub mod profiler;
 pub mod property;
@@ -70,7 +71,6 @@ use crate::syntax::{
 };
 
 /// The result of a Javascript expression is represented like this so it can succeed (`Ok`) or fail (`Err`)
-#[must_use]
 pub type Result<T> = StdResult<T, Value>;
 
 /// Parses the given source code.
@@ -109,7 +109,7 @@ pub(crate) fn forward(engine: &mut Context, src: &str) -> String {
 /// The str is consumed and the state of the Context is changed
 /// Similar to `forward`, except the current value is returned instad of the string
 /// If the interpreter fails parsing an error value is returned instead (error object)
-#[allow(clippy::unit_arg, clippy::drop_copy)]
+#[allow(clippy::unit_arg, dropping_copy_types)]
 #[cfg(test)]
 pub(crate) fn forward_val(engine: &mut Context, src: &str) -> Result<Value> {
     let main_timer = BoaProfiler::global().start_event("Main", "Main");
@@ -0,0 +1,79 @@
+//! Module loaders, which resolve module specifiers and load the source of modules.
+
+use crate::{Context, Result};
+use std::{
+    fmt::Debug,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The host hooks used to load the modules imported by the code of a context.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+pub trait ModuleLoader: Debug {
+    /// Resolves the `specifier` of a module imported by the module `referrer`, or by a script
+    /// if it is `None`, to the key that identifies the module in the context.
+    fn resolve(
+        &self,
+        specifier: &str,
+        referrer: Option<&str>,
+        context: &mut Context,
+    ) -> Result<String>;
+
+    /// Loads the source of the module identified by `key`.
+    fn load(&self, key: &str, context: &mut Context) -> Result<String>;
+}
+
+/// A module loader that loads modules from the file system.
+///
+/// Relative specifiers, starting with `./` or `../`, are resolved from the directory of the
+/// importing module, or from the root directory when they are imported by a script. Other
+/// specifiers are resolved from the root directory.
+#[derive(Debug, Clone)]
+pub struct FsModuleLoader {
+    root: PathBuf,
+}
+
+impl FsModuleLoader {
+    /// Creates a new `FsModuleLoader`, which resolves the modules imported by scripts from
+    /// the `root` directory.
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { root: root.into() }
+    }
+}
+
+impl ModuleLoader for FsModuleLoader {
+    fn resolve(
+        &self,
+        specifier: &str,
+        referrer: Option<&str>,
+        context: &mut Context,
+    ) -> Result<String> {
+        let base = match referrer {
+            Some(referrer) if specifier.starts_with("./") || specifier.starts_with("../") => {
+                Path::new(referrer)
+                    .parent()
+                    .map_or_else(|| self.root.clone(), Path::to_path_buf)
+            }
+            _ => self.root.clone(),
+        };
+        match base.join(specifier).canonicalize() {
+            Ok(path) => Ok(path.to_string_lossy().into_owned()),
+            Err(_) => {
+                Err(context.construct_type_error(format!("Cannot find module '{}'", specifier)))
+            }
+        }
+    }
+
+    fn load(&self, key: &str, context: &mut Context) -> Result<String> {
+        fs::read_to_string(key).map_err(|e| {
+            context.construct_type_error(format!("Cannot load module '{}': {}", key, e))
+        })
+    }
+}
@@ -0,0 +1,810 @@
+//! This module implements ECMAScript modules.
+//!
+//! A module is loaded through the [`ModuleLoader`] of the context, with the modules it imports,
+//! then it is linked, which creates its environment and binds its imports to the exports of
+//! the imported modules, and finally it is evaluated, after the modules it imports.
+//!
+//! Each module is only loaded and evaluated once per context: importing it again returns the
+//! same namespace object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+mod loader;
+#[cfg(test)]
+mod tests;
+
+pub use loader::{FsModuleLoader, ModuleLoader};
+
+use crate::{
+    builtins::function::Captures,
+    environment::lexical_environment::{new_module_environment, Environment},
+    exec::InterpreterState,
+    object::{FunctionBuilder, GcObject, Object},
+    property::{AccessorDescriptor, Attribute, DataDescriptor},
+    syntax::{
+        ast::node::{module::DEFAULT_BINDING, statement_list::RcStatementList, ExportDecl, Node},
+        Parser,
+    },
+    BoaProfiler, Context, Executable, Result, Value,
+};
+use gc::{Finalize, Gc, GcCell, Trace};
+use rustc_hash::FxHashMap;
+
+/// A module, shared by the modules that import it.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct Module(Gc<GcCell<ModuleRecord>>);
+
+impl PartialEq for Module {
+    fn eq(&self, other: &Self) -> bool {
+        Gc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The state of a module, which is linked and evaluated once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModuleStatus {
+    Unlinked,
+    Linking,
+    Linked,
+    Evaluating,
+    Evaluated,
+}
+
+/// The name of a binding imported from a module, or its namespace object.
+#[derive(Debug, Clone, PartialEq, Trace, Finalize)]
+enum ImportName {
+    Name(String),
+    Namespace,
+}
+
+/// An import of a binding of another module, as in `import { importName as localName }`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ImportEntry {
+    module_request: String,
+    import_name: ImportName,
+    local_name: String,
+}
+
+/// An export of a binding of the module, as in `export { local as exported }`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct LocalExport {
+    exported: String,
+    local: String,
+}
+
+/// An export of a binding of another module, as in
+/// `export { importName as exported } from "module"`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct IndirectExport {
+    exported: String,
+    module_request: String,
+    import_name: ImportName,
+}
+
+/// A Source Text Module Record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-source-text-module-records
+#[derive(Debug, Trace, Finalize)]
+struct ModuleRecord {
+    /// The key the loader resolved the module to.
+    key: String,
+    body: RcStatementList,
+    #[unsafe_ignore_trace]
+    status: ModuleStatus,
+    /// The specifiers of the imported modules, in the order of their first import.
+    requests: Vec<String>,
+    requested_modules: FxHashMap<String, Module>,
+    import_entries: Vec<ImportEntry>,
+    local_exports: Vec<LocalExport>,
+    indirect_exports: Vec<IndirectExport>,
+    /// The specifiers of the modules whose exports are all exported, as in
+    /// `export * from "module"`.
+    star_exports: Vec<String>,
+    environment: Option<Environment>,
+    namespace: Option<GcObject>,
+    evaluation_error: Option<Value>,
+}
+
+/// The binding an export name resolves to.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+#[derive(Debug, Clone, PartialEq)]
+enum Resolution {
+    /// The binding `name` of the environment of the module.
+    Binding(Module, String),
+    /// The namespace object of the module.
+    Namespace(Module),
+    NotFound,
+    /// The name is exported by several modules through star exports.
+    Ambiguous,
+}
+
+/// The binding read by a property of a namespace object.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum NamespaceBinding {
+    Binding {
+        environment: Environment,
+        name: String,
+    },
+    Namespace(Module),
+}
+
+impl ModuleRecord {
+    /// Parses the source of the module, and collects its imports and exports.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-parsemodule
+    fn parse(key: String, source: &str, context: &mut Context) -> Result<Self> {
+        let body = Parser::new(source.as_bytes())
+            .parse_module()
+            .map_err(|e| context.construct_syntax_error(e.to_string()))?;
+
+        let mut record = Self {
+            key,
+            body: body.into(),
+            status: ModuleStatus::Unlinked,
+            requests: Vec::new(),
+            requested_modules: FxHashMap::default(),
+            import_entries: Vec::new(),
+            local_exports: Vec::new(),
+            indirect_exports: Vec::new(),
+            star_exports: Vec::new(),
+            environment: None,
+            namespace: None,
+            evaluation_error: None,
+        };
+
+        let mut declared_names = Vec::new();
+        let body = record.body.clone();
+        for node in body.statements() {
+            match node {
+                Node::ImportDecl(decl) => {
+                    record.add_request(decl.module());
+                    let mut import = |import_name, local_name: &str| {
+                        record.import_entries.push(ImportEntry {
+                            module_request: decl.module().to_owned(),
+                            import_name,
+                            local_name: local_name.to_owned(),
+                        })
+                    };
+                    if let Some(local) = decl.default() {
+                        import(ImportName::Name(DEFAULT_BINDING.to_owned()), local);
+                    }
+                    if let Some(local) = decl.namespace() {
+                        import(ImportName::Namespace, local);
+                    }
+                    for specifier in decl.specifiers() {
+                        import(
+                            ImportName::Name(specifier.imported().to_owned()),
+                            specifier.local(),
+                        );
+                    }
+                }
+                Node::ExportDecl(decl) => record.add_export(decl, &mut declared_names),
+                node => declared_names.extend(declared_names_of(node)),
+            }
+        }
+
+        // A local name can only be declared once, by an import or by a declaration.
+        let mut local_names = declared_names;
+        for entry in &record.import_entries {
+            if local_names.contains(&entry.local_name.as_str()) {
+                return Err(context.construct_syntax_error(format!(
+                    "Identifier '{}' has already been declared",
+                    entry.local_name
+                )));
+            }
+            local_names.push(&entry.local_name);
+        }
+
+        // The export of an imported binding is the export of the binding of the imported
+        // module, unless it is its namespace object, which is a binding of this module.
+        let local_exports = std::mem::take(&mut record.local_exports);
+        for export in &local_exports {
+            match record
+                .import_entries
+                .iter()
+                .find(|entry| entry.local_name == export.local)
+            {
+                Some(entry) if entry.import_name != ImportName::Namespace => {
+                    record.indirect_exports.push(IndirectExport {
+                        exported: export.exported.clone(),
+                        module_request: entry.module_request.clone(),
+                        import_name: entry.import_name.clone(),
+                    })
+                }
+                _ => record.local_exports.push(export.clone()),
+            }
+        }
+
+        let mut exported_names = Vec::new();
+        let exports = record
+            .local_exports
+            .iter()
+            .map(|export| &export.exported)
+            .chain(
+                record
+                    .indirect_exports
+                    .iter()
+                    .map(|export| &export.exported),
+            );
+        for name in exports {
+            if exported_names.contains(&name) {
+                return Err(
+                    context.construct_syntax_error(format!("Duplicate export of '{}'", name))
+                );
+            }
+            exported_names.push(name);
+        }
+
+        Ok(record)
+    }
+
+    /// Adds the specifier of an imported module, if it isn't already imported.
+    fn add_request(&mut self, specifier: &str) {
+        if !self.requests.iter().any(|request| request == specifier) {
+            self.requests.push(specifier.to_owned());
+        }
+    }
+
+    /// Adds the exports of an export declaration, and the names it declares.
+    fn add_export<'a>(&mut self, decl: &'a ExportDecl, declared_names: &mut Vec<&'a str>) {
+        match decl {
+            ExportDecl::Declaration(declaration) => {
+                for name in declared_names_of(declaration) {
+                    self.local_exports.push(LocalExport {
+                        exported: name.to_owned(),
+                        local: name.to_owned(),
+                    });
+                    declared_names.push(name);
+                }
+            }
+            ExportDecl::DefaultDeclaration(declaration) => {
+                for name in declared_names_of(declaration) {
+                    self.local_exports.push(LocalExport {
+                        exported: DEFAULT_BINDING.to_owned(),
+                        local: name.to_owned(),
+                    });
+                    declared_names.push(name);
+                }
+            }
+            ExportDecl::DefaultExpression(_) => {
+                self.local_exports.push(LocalExport {
+                    exported: DEFAULT_BINDING.to_owned(),
+                    local: DEFAULT_BINDING.to_owned(),
+                });
+                declared_names.push(DEFAULT_BINDING);
+            }
+            ExportDecl::Named {
+                specifiers,
+                module: None,
+            } => {
+                for specifier in specifiers.iter() {
+                    self.local_exports.push(LocalExport {
+                        exported: specifier.exported().to_owned(),
+                        local: specifier.local().to_owned(),
+                    });
+                }
+            }
+            ExportDecl::Named {
+                specifiers,
+                module: Some(module),
+            } => {
+                self.add_request(module);
+                for specifier in specifiers.iter() {
+                    self.indirect_exports.push(IndirectExport {
+                        exported: specifier.exported().to_owned(),
+                        module_request: module.to_string(),
+                        import_name: ImportName::Name(specifier.local().to_owned()),
+                    });
+                }
+            }
+            ExportDecl::All {
+                alias: None,
+                module,
+            } => {
+                self.add_request(module);
+                self.star_exports.push(module.to_string());
+            }
+            ExportDecl::All {
+                alias: Some(alias),
+                module,
+            } => {
+                self.add_request(module);
+                self.indirect_exports.push(IndirectExport {
+                    exported: alias.to_string(),
+                    module_request: module.to_string(),
+                    import_name: ImportName::Namespace,
+                });
+            }
+        }
+    }
+}
+
+/// Returns the names of the bindings declared by a declaration.
+fn declared_names_of(node: &Node) -> Vec<&str> {
+    match node {
+        Node::VarDeclList(list) => list
+            .as_ref()
+            .iter()
+            .flat_map(|decl| decl.target().bound_names())
+            .collect(),
+        Node::LetDeclList(list) => list
+            .as_ref()
+            .iter()
+            .flat_map(|decl| decl.target().bound_names())
+            .collect(),
+        Node::ConstDeclList(list) => list
+            .as_ref()
+            .iter()
+            .flat_map(|decl| decl.target().bound_names())
+            .collect(),
+        Node::FunctionDecl(decl) => vec![decl.name()],
+        Node::GeneratorDecl(decl) => vec![decl.name()],
+        Node::AsyncFunctionDecl(decl) => decl.name().into_iter().collect(),
+        Node::ClassDecl(decl) => vec![decl.name()],
+        _ => Vec::new(),
+    }
+}
+
+impl Module {
+    fn status(&self) -> ModuleStatus {
+        self.0.borrow().status
+    }
+
+    fn environment(&self) -> Environment {
+        self.0
+            .borrow()
+            .environment
+            .clone()
+            .expect("the environment of a module is created when it is linked")
+    }
+
+    fn requested_module(&self, specifier: &str) -> Module {
+        self.0.borrow().requested_modules[specifier].clone()
+    }
+
+    /// Links the module and the modules it imports.
+    ///
+    /// If linking fails, all the modules that were being linked are left unlinked.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduledeclarationlinking
+    fn link(&self, context: &mut Context) -> Result<()> {
+        let mut stack = Vec::new();
+        let result = self.inner_link(&mut stack, context);
+        for module in stack {
+            let mut record = module.0.borrow_mut();
+            if result.is_ok() {
+                record.status = ModuleStatus::Linked;
+            } else {
+                record.status = ModuleStatus::Unlinked;
+                record.environment = None;
+                record.namespace = None;
+            }
+        }
+        result
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-InnerModuleLinking
+    fn inner_link(&self, stack: &mut Vec<Module>, context: &mut Context) -> Result<()> {
+        if self.status() != ModuleStatus::Unlinked {
+            return Ok(());
+        }
+
+        let global = context.realm().environment.get_global_environment().clone();
+        let environment = new_module_environment(self.0.borrow().key.clone(), Some(global));
+        {
+            let mut record = self.0.borrow_mut();
+            record.status = ModuleStatus::Linking;
+            record.environment = Some(environment.clone());
+        }
+        stack.push(self.clone());
+
+        let requests = self.0.borrow().requests.clone();
+        for request in &requests {
+            self.requested_module(request).inner_link(stack, context)?;
+        }
+
+        let indirect_exports = self.0.borrow().indirect_exports.clone();
+        for export in indirect_exports {
+            let resolution = self.resolve_export(&export.exported, &mut Vec::new());
+            check_resolution(
+                &resolution,
+                &export.module_request,
+                &export.exported,
+                context,
+            )?;
+        }
+
+        let import_entries = self.0.borrow().import_entries.clone();
+        for entry in import_entries {
+            let imported = self.requested_module(&entry.module_request);
+            let resolution = match entry.import_name {
+                ImportName::Namespace => Resolution::Namespace(imported),
+                ImportName::Name(ref name) => {
+                    let resolution = imported.resolve_export(name, &mut Vec::new());
+                    check_resolution(&resolution, &entry.module_request, name, context)?;
+                    resolution
+                }
+            };
+            match resolution {
+                Resolution::Binding(module, name) => environment
+                    .borrow_mut()
+                    .create_import_binding(entry.local_name.clone(), module.environment(), name)
+                    .map_err(|e| context.construct_syntax_error(e.to_string()))?,
+                Resolution::Namespace(module) => {
+                    let namespace = module.namespace(context)?;
+                    let mut environment = environment.borrow_mut();
+                    environment.create_immutable_binding(entry.local_name.clone(), true);
+                    environment.initialize_binding(&entry.local_name, namespace.into());
+                }
+                Resolution::NotFound | Resolution::Ambiguous => {
+                    unreachable!("unresolved imports are rejected")
+                }
+            }
+        }
+
+        // The function declarations are instantiated before any module is evaluated, so that
+        // they can be called by the modules that import them, even in a cycle.
+        let body = self.0.borrow().body.clone();
+        let depth = context.realm().environment.depth();
+        context.realm_mut().environment.push(environment);
+        // Module code is always strict mode code.
+        let result = context.run_with_strict_mode(true, |context| {
+            body.statements()
+                .iter()
+                .filter(|node| node.is_hoistable_declaration())
+                .try_for_each(|node| node.run(context).map(drop))
+        });
+        context.realm_mut().environment.split_off(depth);
+        result
+    }
+
+    /// Finds the binding an export name of the module resolves to.
+    ///
+    /// `resolve_set` holds the exports that are being resolved, to detect cycles.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+    fn resolve_export(&self, name: &str, resolve_set: &mut Vec<(Module, String)>) -> Resolution {
+        if resolve_set
+            .iter()
+            .any(|(module, export)| module == self && export == name)
+        {
+            // A circular import request.
+            return Resolution::NotFound;
+        }
+        resolve_set.push((self.clone(), name.to_owned()));
+
+        let (local_export, indirect_export, star_exports) = {
+            let record = self.0.borrow();
+            (
+                record
+                    .local_exports
+                    .iter()
+                    .find(|export| export.exported == name)
+                    .cloned(),
+                record
+                    .indirect_exports
+                    .iter()
+                    .find(|export| export.exported == name)
+                    .cloned(),
+                record.star_exports.clone(),
+            )
+        };
+
+        if let Some(export) = local_export {
+            return Resolution::Binding(self.clone(), export.local.clone());
+        }
+        if let Some(export) = indirect_export {
+            let imported = self.requested_module(&export.module_request);
+            return match export.import_name {
+                ImportName::Namespace => Resolution::Namespace(imported),
+                ImportName::Name(ref name) => imported.resolve_export(name, resolve_set),
+            };
+        }
+
+        // The default export is never exported by `export *`.
+        if name == DEFAULT_BINDING {
+            return Resolution::NotFound;
+        }
+
+        let mut star_resolution = Resolution::NotFound;
+        for request in star_exports {
+            let resolution = self
+                .requested_module(&request)
+                .resolve_export(name, resolve_set);
+            match resolution {
+                Resolution::Ambiguous => return Resolution::Ambiguous,
+                Resolution::NotFound => {}
+                resolution if star_resolution == Resolution::NotFound => {
+                    star_resolution = resolution
+                }
+                resolution if resolution != star_resolution => return Resolution::Ambiguous,
+                _ => {}
+            }
+        }
+        star_resolution
+    }
+
+    /// Returns the names exported by the module.
+    ///
+    /// `visited` holds the modules whose names are being collected, to detect cycles.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getexportednames
+    fn exported_names(&self, visited: &mut Vec<Module>) -> Vec<String> {
+        if visited.contains(self) {
+            return Vec::new();
+        }
+        visited.push(self.clone());
+
+        let (mut names, star_exports) = {
+            let record = self.0.borrow();
+            let names: Vec<_> = record
+                .local_exports
+                .iter()
+                .map(|export| export.exported.clone())
+                .chain(
+                    record
+                        .indirect_exports
+                        .iter()
+                        .map(|export| export.exported.clone()),
+                )
+                .collect();
+            (names, record.star_exports.clone())
+        };
+
+        for request in star_exports {
+            for name in self.requested_module(&request).exported_names(visited) {
+                if name != DEFAULT_BINDING && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// Returns the namespace object of the module, creating it the first time.
+    ///
+    /// The properties of the namespace object read the live values of the exported bindings.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
+    fn namespace(&self, context: &mut Context) -> Result<GcObject> {
+        if let Some(namespace) = self.0.borrow().namespace.clone() {
+            return Ok(namespace);
+        }
+
+        let mut names = self.exported_names(&mut Vec::new());
+        names.sort();
+
+        let mut namespace = GcObject::new(Object::default());
+        self.0.borrow_mut().namespace = Some(namespace.clone());
+
+        for name in names {
+            let binding = match self.resolve_export(&name, &mut Vec::new()) {
+                Resolution::Binding(module, name) => NamespaceBinding::Binding {
+                    environment: module.environment(),
+                    name,
+                },
+                Resolution::Namespace(module) => NamespaceBinding::Namespace(module),
+                // Ambiguous names aren't exported.
+                Resolution::NotFound | Resolution::Ambiguous => continue,
+            };
+            let getter =
+                FunctionBuilder::closure(context, namespace_getter, Captures::new(binding))
+                    .name(format!("get {}", name))
+                    .build();
+            namespace.insert(
+                name,
+                AccessorDescriptor::new(
+                    Some(getter),
+                    None,
+                    Attribute::ENUMERABLE | Attribute::PERMANENT,
+                ),
+            );
+        }
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        namespace.insert(
+            to_string_tag,
+            DataDescriptor::new(
+                "Module",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
+        namespace.prevent_extensions(context)?;
+
+        Ok(namespace)
+    }
+
+    /// Evaluates the modules imported by the module, then the module itself.
+    ///
+    /// A module is only evaluated once: if it throws an error, the same error is thrown each
+    /// time it is imported.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduleevaluation
+    fn evaluate(&self, context: &mut Context) -> Result<()> {
+        match self.status() {
+            ModuleStatus::Evaluated => {
+                return match self.0.borrow().evaluation_error.clone() {
+                    Some(error) => Err(error),
+                    None => Ok(()),
+                }
+            }
+            // The module imports itself through a cycle, it is already being evaluated.
+            ModuleStatus::Evaluating => return Ok(()),
+            ModuleStatus::Linked => {}
+            ModuleStatus::Unlinked | ModuleStatus::Linking => {
+                unreachable!("a module is linked before it is evaluated")
+            }
+        }
+        self.0.borrow_mut().status = ModuleStatus::Evaluating;
+
+        let result = self.execute(context);
+
+        let mut record = self.0.borrow_mut();
+        record.status = ModuleStatus::Evaluated;
+        record.evaluation_error = result.as_ref().err().cloned();
+        result
+    }
+
+    /// Evaluates the imported modules, then the statements of the module.
+    fn execute(&self, context: &mut Context) -> Result<()> {
+        let requests = self.0.borrow().requests.clone();
+        for request in &requests {
+            self.requested_module(request).evaluate(context)?;
+        }
+
+        let _timer = BoaProfiler::global().start_event("Module", "exec");
+        let body = self.0.borrow().body.clone();
+        let depth = context.realm().environment.depth();
+        context.realm_mut().environment.push(self.environment());
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+        let result = context.run_with_strict_mode(true, |context| {
+            body.statements()
+                .iter()
+                .filter(|node| !node.is_hoistable_declaration())
+                .try_for_each(|node| node.run(context).map(drop))
+        });
+        context.realm_mut().environment.split_off(depth);
+        result
+    }
+}
+
+/// Throws a `SyntaxError` if an imported name isn't exported by the requested module.
+fn check_resolution(
+    resolution: &Resolution,
+    module_request: &str,
+    name: &str,
+    context: &mut Context,
+) -> Result<()> {
+    match resolution {
+        Resolution::NotFound => Err(context.construct_syntax_error(format!(
+            "The requested module '{}' does not provide an export named '{}'",
+            module_request, name
+        ))),
+        Resolution::Ambiguous => Err(context.construct_syntax_error(format!(
+            "The requested module '{}' contains conflicting star exports for name '{}'",
+            module_request, name
+        ))),
+        Resolution::Binding(..) | Resolution::Namespace(_) => Ok(()),
+    }
+}
+
+/// The getter of a property of a namespace object.
+fn namespace_getter(
+    _: &Value,
+    _: &[Value],
+    captures: &Captures,
+    context: &mut Context,
+) -> Result<Value> {
+    let binding = captures
+        .downcast_ref::<NamespaceBinding>()
+        .expect("the captures of a namespace getter are a namespace binding")
+        .clone();
+    match &binding {
+        NamespaceBinding::Binding { environment, name } => {
+            let environment = environment.borrow();
+            if environment.has_binding(name) {
+                Ok(environment.get_binding_value(name, true))
+            } else {
+                drop(environment);
+                context.throw_reference_error(format!(
+                    "Cannot access '{}' before initialization",
+                    name
+                ))
+            }
+        }
+        NamespaceBinding::Namespace(module) => module.namespace(context).map(Value::from),
+    }
+}
+
+/// Loads the module `specifier` and the modules it imports, unless they are already loaded.
+///
+/// If loading fails, the modules loaded in the process are discarded.
+fn load(specifier: &str, referrer: Option<&str>, context: &mut Context) -> Result<Module> {
+    let mut loaded = Vec::new();
+    let result = load_graph(specifier, referrer, &mut loaded, context);
+    if result.is_err() {
+        for key in loaded {
+            context.modules_mut().remove(&key);
+        }
+    }
+    result
+}
+
+fn load_graph(
+    specifier: &str,
+    referrer: Option<&str>,
+    loaded: &mut Vec<String>,
+    context: &mut Context,
+) -> Result<Module> {
+    let loader = context.module_loader()?;
+    let key = loader.resolve(specifier, referrer, context)?;
+    if let Some(module) = context.modules().get(&key) {
+        return Ok(module.clone());
+    }
+
+    let source = loader.load(&key, context)?;
+    let record = ModuleRecord::parse(key.clone(), &source, context)?;
+    let requests = record.requests.clone();
+    let module = Module(Gc::new(GcCell::new(record)));
+    context.modules_mut().insert(key.clone(), module.clone());
+    loaded.push(key.clone());
+
+    for request in requests {
+        let requested = load_graph(&request, Some(&key), loaded, context)?;
+        module
+            .0
+            .borrow_mut()
+            .requested_modules
+            .insert(request, requested);
+    }
+    Ok(module)
+}
+
+/// Imports the module `specifier`, imported by the module `referrer` if any, and returns its
+/// namespace object.
+///
+/// The module is loaded, linked and evaluated if it wasn't already.
+pub(crate) fn import(
+    specifier: &str,
+    referrer: Option<&str>,
+    context: &mut Context,
+) -> Result<Value> {
+    let _timer = BoaProfiler::global().start_event("import", "module");
+    let module = load(specifier, referrer, context)?;
+    module.link(context)?;
+    module.evaluate(context)?;
+    module.namespace(context).map(Value::from)
+}
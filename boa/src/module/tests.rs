@@ -0,0 +1,401 @@
+use super::ModuleLoader;
+use crate::{forward, Context, Result};
+use rustc_hash::FxHashMap;
+
+/// A module loader that loads modules from memory, by their specifier.
+#[derive(Debug)]
+struct MemoryModuleLoader {
+    modules: FxHashMap<String, String>,
+}
+
+impl ModuleLoader for MemoryModuleLoader {
+    fn resolve(&self, specifier: &str, _: Option<&str>, context: &mut Context) -> Result<String> {
+        if self.modules.contains_key(specifier) {
+            Ok(specifier.to_owned())
+        } else {
+            Err(context.construct_type_error(format!("Cannot find module '{}'", specifier)))
+        }
+    }
+
+    fn load(&self, key: &str, _: &mut Context) -> Result<String> {
+        Ok(self.modules[key].clone())
+    }
+}
+
+/// Creates a context that can import the given modules, by their specifier.
+fn context(modules: &[(&str, &str)]) -> Context {
+    let mut context = Context::new();
+    context.set_module_loader(MemoryModuleLoader {
+        modules: modules
+            .iter()
+            .map(|(specifier, source)| (specifier.to_string(), source.to_string()))
+            .collect(),
+    });
+    context
+}
+
+/// Imports the module `specifier`, binds its namespace object to the global `ns`, then
+/// evaluates `expression`.
+fn import(context: &mut Context, specifier: &str, expression: &str) -> String {
+    match context.import_module(specifier) {
+        Ok(namespace) => {
+            let global = context.global_object().clone();
+            global.set_field("ns", namespace, context).unwrap();
+            forward(context, expression)
+        }
+        Err(error) => format!("Uncaught {}", error.display()),
+    }
+}
+
+#[test]
+fn named_default_and_namespace_imports() {
+    let mut context = context(&[
+        (
+            "main",
+            r#"
+            import value, { a, b as c } from "lib";
+            import * as lib from "lib";
+            export const result = [value, a, c, lib.a, lib.default].join();
+            "#,
+        ),
+        (
+            "lib",
+            r#"
+            export default "d";
+            export const a = 1;
+            export let b = 2;
+            "#,
+        ),
+    ]);
+
+    assert_eq!(import(&mut context, "main", "ns.result"), "\"d,1,2,1,d\"");
+}
+
+#[test]
+fn imports_are_live_bindings() {
+    let mut context = context(&[
+        (
+            "main",
+            r#"
+            import { count, increment } from "counter";
+            increment();
+            export const seen = count;
+            "#,
+        ),
+        (
+            "counter",
+            r#"
+            export let count = 0;
+            export function increment() {
+                count += 1;
+            }
+            "#,
+        ),
+    ]);
+
+    assert_eq!(import(&mut context, "main", "ns.seen"), "1");
+    assert_eq!(
+        import(&mut context, "counter", "ns.increment(); ns.count"),
+        "2"
+    );
+}
+
+#[test]
+fn default_exports() {
+    let mut context = context(&[
+        ("expression", "export default 1 + 2;"),
+        ("function", "export default function () { return 1; }"),
+        (
+            "class",
+            "export default class { get value() { return 2; } }",
+        ),
+        (
+            "named",
+            "export default function named() {} named.value = 3;",
+        ),
+    ]);
+
+    assert_eq!(import(&mut context, "expression", "ns.default"), "3");
+    assert_eq!(
+        import(
+            &mut context,
+            "function",
+            "[ns.default.name, ns.default()].join()"
+        ),
+        "\"default,1\""
+    );
+    assert_eq!(import(&mut context, "class", "new ns.default().value"), "2");
+    assert_eq!(
+        import(
+            &mut context,
+            "named",
+            "[ns.default.name, ns.default.value].join()"
+        ),
+        "\"named,3\""
+    );
+}
+
+#[test]
+fn reexports_and_star_exports() {
+    let mut context = context(&[
+        (
+            "lib",
+            r#"
+            export * from "a";
+            export * from "b";
+            export { x as y } from "a";
+            export * as nsA from "a";
+            "#,
+        ),
+        (
+            "a",
+            r#"
+            export const x = 1;
+            export const shared = "a";
+            export default "ignored";
+            "#,
+        ),
+        (
+            "b",
+            r#"
+            export const z = 2;
+            export const shared = "b";
+            "#,
+        ),
+        ("ambiguous", r#"import { shared } from "lib";"#),
+    ]);
+
+    assert_eq!(
+        import(
+            &mut context,
+            "lib",
+            "let keys = []; for (let key in ns) keys.push(key); keys.join()"
+        ),
+        "\"nsA,x,y,z\""
+    );
+    assert_eq!(
+        import(&mut context, "lib", "[ns.x, ns.y, ns.z, ns.nsA.x].join()"),
+        "\"1,1,2,1\""
+    );
+    assert_eq!(
+        import(&mut context, "ambiguous", ""),
+        "Uncaught \"SyntaxError\": \"The requested module 'lib' contains conflicting star exports for name 'shared'\""
+    );
+}
+
+#[test]
+fn namespace_objects() {
+    let mut context = context(&[("lib", "export let b = 1; export const a = 2;")]);
+
+    assert_eq!(
+        import(
+            &mut context,
+            "lib",
+            "let keys = []; for (let key in ns) keys.push(key); keys.join()"
+        ),
+        "\"a,b\""
+    );
+    assert_eq!(
+        import(&mut context, "lib", "ns[Symbol.toStringTag]"),
+        "\"Module\""
+    );
+    assert_eq!(
+        import(
+            &mut context,
+            "lib",
+            "ns.added = 1; [Object.getPrototypeOf(ns), ns.added].map(String).join()"
+        ),
+        "\"null,undefined\""
+    );
+    assert_eq!(import(&mut context, "lib", "var first = ns;"), "undefined");
+    assert_eq!(import(&mut context, "lib", "first === ns"), "true");
+}
+
+#[test]
+fn missing_exports() {
+    let mut context = context(&[
+        ("main", r#"import { nope } from "lib";"#),
+        ("lib", "export const a = 1;"),
+        ("missing", r#"import "nowhere";"#),
+    ]);
+
+    assert_eq!(
+        import(&mut context, "main", ""),
+        "Uncaught \"SyntaxError\": \"The requested module 'lib' does not provide an export named 'nope'\""
+    );
+    assert_eq!(
+        import(&mut context, "missing", ""),
+        "Uncaught \"TypeError\": \"Cannot find module 'nowhere'\""
+    );
+}
+
+#[test]
+fn duplicate_exports_and_declarations() {
+    let mut context = context(&[
+        ("exports", "export const a = 1; export { a };"),
+        ("imports", r#"import { a } from "lib"; const a = 1;"#),
+        ("lib", "export const a = 1;"),
+    ]);
+
+    assert_eq!(
+        import(&mut context, "exports", ""),
+        "Uncaught \"SyntaxError\": \"Duplicate export of 'a'\""
+    );
+    assert_eq!(
+        import(&mut context, "imports", ""),
+        "Uncaught \"SyntaxError\": \"Identifier 'a' has already been declared\""
+    );
+}
+
+#[test]
+fn cyclic_imports() {
+    let mut context = context(&[
+        (
+            "a",
+            r#"
+            import { b } from "b";
+            export function a() {
+                return "a";
+            }
+            export const fromB = b();
+            "#,
+        ),
+        (
+            "b",
+            r#"
+            import { a } from "a";
+            export function b() {
+                return "b" + a();
+            }
+            "#,
+        ),
+    ]);
+
+    assert_eq!(import(&mut context, "a", "ns.fromB"), "\"ba\"");
+}
+
+#[test]
+fn uninitialized_namespace_binding() {
+    let mut context = context(&[
+        (
+            "a",
+            r#"
+            import * as b from "b";
+            export let early;
+            try {
+                b.late;
+            } catch (e) {
+                early = e.name;
+            }
+            "#,
+        ),
+        ("b", r#"import "a"; export let late = 1;"#),
+    ]);
+
+    assert_eq!(import(&mut context, "b", "ns.late"), "1");
+    assert_eq!(import(&mut context, "a", "ns.early"), "\"ReferenceError\"");
+}
+
+#[test]
+fn modules_are_evaluated_once_in_order() {
+    let mut context = context(&[
+        ("main", r#"import "a"; import "b"; log.push("main");"#),
+        ("a", r#"log.push("a");"#),
+        ("b", r#"import "a"; log.push("b");"#),
+    ]);
+    forward(&mut context, "var log = [];");
+
+    assert_eq!(import(&mut context, "main", "log.join()"), "\"a,b,main\"");
+    assert_eq!(import(&mut context, "b", "log.join()"), "\"a,b,main\"");
+}
+
+#[test]
+fn evaluation_errors_are_cached() {
+    let mut context = context(&[("lib", r#"log.push("lib"); throw new Error("boom");"#)]);
+    forward(&mut context, "var log = [];");
+
+    assert_eq!(
+        import(&mut context, "lib", ""),
+        "Uncaught \"Error\": \"boom\""
+    );
+    assert_eq!(
+        import(&mut context, "lib", ""),
+        "Uncaught \"Error\": \"boom\""
+    );
+    assert_eq!(forward(&mut context, "log.join()"), "\"lib\"");
+}
+
+#[test]
+fn module_scope() {
+    let mut context = context(&[(
+        "lib",
+        r#"
+        var hidden = 1;
+        function alsoHidden() {}
+        export const self = this;
+        "#,
+    )]);
+
+    assert_eq!(
+        import(
+            &mut context,
+            "lib",
+            "[this.hidden, this.alsoHidden, ns.self].map(String).join()"
+        ),
+        "\"undefined,undefined,undefined\""
+    );
+}
+
+#[test]
+fn dynamic_import() {
+    let mut context = context(&[
+        ("lib", "export const a = 1;"),
+        (
+            "main",
+            r#"
+            export let result;
+            import("lib").then(lib => {
+                result = lib.a;
+            });
+            "#,
+        ),
+    ]);
+
+    forward(
+        &mut context,
+        r#"
+        var value, error;
+        import("lib").then(lib => {
+            value = lib.a;
+        });
+        import("missing").catch(e => {
+            error = e.message;
+        });
+        "#,
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "value"), "1");
+    assert_eq!(
+        forward(&mut context, "error"),
+        "\"Cannot find module 'missing'\""
+    );
+
+    import(&mut context, "main", "");
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "ns.result"), "1");
+}
+
+#[test]
+fn import_without_loader() {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        r#"var error; import("lib").catch(e => { error = e; });"#,
+    );
+    context.run_jobs().unwrap();
+    assert_eq!(
+        forward(&mut context, "error.message"),
+        "\"Cannot import modules without a module loader\""
+    );
+}
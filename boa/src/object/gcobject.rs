@@ -4,18 +4,25 @@
 
 use super::{NativeObject, Object, PROTOTYPE};
 use crate::{
-    builtins::function::{
-        create_unmapped_arguments_object, BuiltInFunction, Function, NativeFunction,
+    builtins::{
+        function::{
+            bind_parameters, create_mapped_arguments_object, create_unmapped_arguments_object,
+            is_simple_parameter_list, BuiltInClosure, BuiltInFunction, Captures, Function,
+            NativeClosure, NativeFunction,
+        },
+        AsyncFunction, Generator,
     },
     environment::{
-        function_environment_record::BindingStatus, lexical_environment::new_function_environment,
+        function_environment_record::BindingStatus,
+        lexical_environment::{new_function_environment, Environment},
     },
+    exec::InterpreterState,
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
-    syntax::ast::node::RcStatementList,
+    syntax::ast::node::{RcFormalParameterList, RcStatementList},
     value::PreferredType,
     Context, Executable, Result, Value,
 };
-use gc::{Finalize, Gc, GcCell, GcCellRef, GcCellRefMut, Trace};
+use gc::{Ephemeron, Finalize, Gc, GcCell, GcCellRef, GcCellRefMut, Trace, WeakGc};
 use serde_json::{map::Map, Value as JSONValue};
 use std::{
     cell::RefCell,
@@ -31,17 +38,44 @@ pub type Ref<'a, T> = GcCellRef<'a, T>;
 /// A wrapper type for a mutably borrowed type T.
 pub type RefMut<'a, T> = GcCellRefMut<'a, T>;
 
+/// The integrity levels of an object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-setintegritylevel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityLevel {
+    /// The properties of the object cannot be added, removed or reconfigured.
+    Sealed,
+
+    /// The object is sealed, and the values of its data properties cannot be changed.
+    Frozen,
+}
+
 /// Garbage collected `Object`.
 #[derive(Trace, Finalize, Clone, Default)]
 pub struct GcObject(Gc<GcCell<Object>>);
 
+/// A weak reference to a garbage collected `Object`, which doesn't keep it alive.
+#[derive(Trace, Finalize, Clone)]
+pub struct WeakGcObject(WeakGc<GcCell<Object>>);
+
 /// The body of a JavaScript function.
 ///
 /// This is needed for the call method since we cannot mutate the function itself since we
 /// already borrow it so we get the function body clone it then drop the borrow and run the body
 enum FunctionBody {
     BuiltIn(NativeFunction),
-    Ordinary(RcStatementList),
+    Closure(NativeClosure, Captures),
+    /// The body of a function, along with its parameters and the environment it runs in.
+    Ordinary(RcStatementList, RcFormalParameterList, Environment),
+    /// The body of a generator function, along with its parameters and the environment it
+    /// runs in.
+    Generator(RcStatementList, RcFormalParameterList, Environment),
+    /// The body of an async function, along with its parameters and the environment it runs
+    /// in.
+    Async(RcStatementList, RcFormalParameterList, Environment),
 }
 
 impl GcObject {
@@ -106,7 +140,23 @@ impl GcObject {
         std::ptr::eq(lhs.as_ref(), rhs.as_ref())
     }
 
-    /// Call this object.
+    /// Creates a weak reference to the object.
+    #[inline]
+    pub fn downgrade(&self) -> WeakGcObject {
+        WeakGcObject(Gc::downgrade(&self.0))
+    }
+
+    /// Creates an ephemeron keyed by the object, which keeps `value` alive only as long as the
+    /// object is alive.
+    #[inline]
+    pub(crate) fn ephemeron<V>(&self, value: V) -> Ephemeron<GcCell<Object>, V>
+    where
+        V: Trace,
+    {
+        Ephemeron::new(&self.0, value)
+    }
+
+    /// The `[[Call]]` internal method of function objects.
     ///
     /// # Panics
     ///
@@ -114,29 +164,58 @@ impl GcObject {
     // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
     // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-call-thisargument-argumentslist>
     #[track_caller]
-    pub fn call(&self, this: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
+    pub(crate) fn call_function(
+        &self,
+        this: &Value,
+        args: &[Value],
+        ctx: &mut Context,
+    ) -> Result<Value> {
         let this_function_object = self.clone();
+        let mut strict = false;
         let f_body = if let Some(function) = self.borrow().as_function() {
             if function.is_callable() {
                 match function {
                     Function::BuiltIn(BuiltInFunction(function), _) => {
                         FunctionBody::BuiltIn(*function)
                     }
+                    Function::Closure {
+                        function: BuiltInClosure(function),
+                        captures,
+                        ..
+                    } => FunctionBody::Closure(*function, captures.clone()),
                     Function::Ordinary {
                         body,
                         params,
                         environment,
                         flags,
+                        ..
                     } => {
+                        // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-call-thisargument-argumentslist>
+                        if flags.is_class_constructor() {
+                            return ctx.throw_type_error(
+                                "class constructors cannot be invoked without 'new'",
+                            );
+                        }
+
+                        // Non-strict functions get an object as `this`, which is the global
+                        // object when they are called without one.
+                        // <https://tc39.es/ecma262/#sec-ordinarycallbindthis>
+                        strict = flags.is_strict();
+                        let this = if flags.is_lexical_this_mode() {
+                            None
+                        } else if strict {
+                            Some(this.clone())
+                        } else if this.is_null_or_undefined() {
+                            Some(ctx.global_object().clone())
+                        } else {
+                            Some(this.to_object(ctx)?.into())
+                        };
+
                         // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
                         // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
                         let local_env = new_function_environment(
                             this_function_object,
-                            if flags.is_lexical_this_mode() {
-                                None
-                            } else {
-                                Some(this.clone())
-                            },
+                            this,
                             Some(environment.clone()),
                             // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
                             if flags.is_lexical_this_mode() {
@@ -144,32 +223,36 @@ impl GcObject {
                             } else {
                                 BindingStatus::Uninitialized
                             },
+                            Value::undefined(),
                         );
 
-                        // Add argument bindings to the function environment
-                        for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
-                            if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, ctx, &local_env);
-                                break;
-                            }
-
-                            let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
-                            function.add_arguments_to_environment(param, value, &local_env);
+                        // Add arguments object, which the parameters are bound after, once the
+                        // function object is no longer borrowed. Arrow functions see the
+                        // arguments object of the enclosing function instead.
+                        if !flags.is_lexical_this_mode() {
+                            let arguments_obj = if strict || !is_simple_parameter_list(params) {
+                                create_unmapped_arguments_object(args, ctx)
+                            } else {
+                                create_mapped_arguments_object(self, params, args, &local_env, ctx)
+                            };
+                            local_env
+                                .borrow_mut()
+                                .create_mutable_binding("arguments".to_string(), false);
+                            local_env
+                                .borrow_mut()
+                                .initialize_binding("arguments", arguments_obj);
                         }
 
-                        // Add arguments object
-                        let arguments_obj = create_unmapped_arguments_object(args);
-                        local_env
-                            .borrow_mut()
-                            .create_mutable_binding("arguments".to_string(), false);
-                        local_env
-                            .borrow_mut()
-                            .initialize_binding("arguments", arguments_obj);
-
-                        ctx.realm_mut().environment.push(local_env);
-
-                        FunctionBody::Ordinary(body.clone())
+                        // The body of a generator function is only run when the generator
+                        // object is resumed, and the body of an async function runs apart
+                        // from the caller, so that it can be suspended.
+                        if flags.is_generator() {
+                            FunctionBody::Generator(body.clone(), params.clone(), local_env)
+                        } else if flags.is_async() {
+                            FunctionBody::Async(body.clone(), params.clone(), local_env)
+                        } else {
+                            FunctionBody::Ordinary(body.clone(), params.clone(), local_env)
+                        }
                     }
                 }
             } else {
@@ -181,66 +264,118 @@ impl GcObject {
 
         match f_body {
             FunctionBody::BuiltIn(func) => func(this, args, ctx),
-            FunctionBody::Ordinary(body) => {
-                let result = body.run(ctx);
+            FunctionBody::Closure(func, captures) => func(this, args, &captures, ctx),
+            FunctionBody::Ordinary(body, params, environment) => {
+                ctx.realm_mut().environment.push(environment);
+                let result = ctx.run_with_strict_mode(strict, |ctx| {
+                    bind_parameters(&params, args, ctx).and_then(|_| body.run(ctx))
+                });
+                // The `return` of the body must not end the evaluation of the caller, which may
+                // not be a call expression, as for getters and setters.
+                ctx.executor()
+                    .set_current_state(InterpreterState::Executing);
                 ctx.realm_mut().environment.pop();
 
                 result
             }
+            FunctionBody::Generator(body, params, environment) => {
+                ctx.realm_mut().environment.push(environment.clone());
+                let result =
+                    ctx.run_with_strict_mode(strict, |ctx| bind_parameters(&params, args, ctx));
+                ctx.realm_mut().environment.pop();
+
+                result?;
+                Generator::create(self, body, environment, strict, ctx)
+            }
+            FunctionBody::Async(body, params, environment) => {
+                ctx.realm_mut().environment.push(environment.clone());
+                let result =
+                    ctx.run_with_strict_mode(strict, |ctx| bind_parameters(&params, args, ctx));
+                ctx.realm_mut().environment.pop();
+
+                // An error while binding the parameters rejects the returned promise.
+                match result {
+                    Ok(()) => AsyncFunction::start(body, environment, strict, ctx),
+                    Err(error) => AsyncFunction::reject(error, ctx),
+                }
+            }
         }
     }
 
-    /// Construct an instance of this object with the specified arguments.
+    /// The `[[Construct]]` internal method of function objects.
+    ///
+    /// `new_target` is the constructor that `new` was initially applied to, it is used to
+    /// find the prototype of the newly created object.
     ///
     /// # Panics
     ///
     /// Panics if the object is currently mutably borrowed.
     // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget>
     #[track_caller]
-    pub fn construct(&self, args: &[Value], ctx: &mut Context) -> Result<Value> {
-        let this: Value = Object::create(self.get(&PROTOTYPE.into())).into();
+    pub(crate) fn construct_function(
+        &self,
+        args: &[Value],
+        new_target: &Value,
+        ctx: &mut Context,
+    ) -> Result<Value> {
+        // <https://tc39.es/ecma262/#sec-getprototypefromconstructor>
+        let prototype = match new_target {
+            Value::Object(ref object) => object.get(&PROTOTYPE.into(), new_target.clone(), ctx)?,
+            _ => self.get(&PROTOTYPE.into(), self.clone().into(), ctx)?,
+        };
+        let prototype = if prototype.is_object() {
+            prototype
+        } else {
+            ctx.standard_objects().object_object().prototype().into()
+        };
 
         let this_function_object = self.clone();
+        let mut derived = false;
+        let mut strict = false;
         let body = if let Some(function) = self.borrow().as_function() {
             if function.is_constructable() {
                 match function {
                     Function::BuiltIn(BuiltInFunction(function), _) => {
                         FunctionBody::BuiltIn(*function)
                     }
+                    Function::Closure {
+                        function: BuiltInClosure(function),
+                        captures,
+                        ..
+                    } => FunctionBody::Closure(*function, captures.clone()),
                     Function::Ordinary {
                         body,
                         params,
                         environment,
                         flags,
+                        ..
                     } => {
+                        // A derived constructor does not get a `this` value until `super()` is called.
+                        derived = flags.is_derived_constructor();
+                        strict = flags.is_strict();
+                        let this = if derived {
+                            None
+                        } else {
+                            Some(Object::create(prototype.clone()).into())
+                        };
+
                         // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
                         // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
                         let local_env = new_function_environment(
                             this_function_object,
-                            Some(this.clone()),
+                            this,
                             Some(environment.clone()),
-                            // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
-                            if flags.is_lexical_this_mode() {
-                                BindingStatus::Lexical
-                            } else {
-                                BindingStatus::Uninitialized
-                            },
+                            BindingStatus::Uninitialized,
+                            new_target.clone(),
                         );
 
-                        // Add argument bindings to the function environment
-                        for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
-                            if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, ctx, &local_env);
-                                break;
-                            }
-
-                            let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
-                            function.add_arguments_to_environment(param, value, &local_env);
-                        }
-
-                        // Add arguments object
-                        let arguments_obj = create_unmapped_arguments_object(args);
+                        // Add arguments object, which the parameters are bound after, once the
+                        // function object is no longer borrowed.
+                        let arguments_obj = if strict || !is_simple_parameter_list(params) {
+                            create_unmapped_arguments_object(args, ctx)
+                        } else {
+                            create_mapped_arguments_object(self, params, args, &local_env, ctx)
+                        };
                         local_env
                             .borrow_mut()
                             .create_mutable_binding("arguments".to_string(), false);
@@ -248,13 +383,14 @@ impl GcObject {
                             .borrow_mut()
                             .initialize_binding("arguments", arguments_obj);
 
-                        ctx.realm_mut().environment.push(local_env);
-
-                        FunctionBody::Ordinary(body.clone())
+                        FunctionBody::Ordinary(body.clone(), params.clone(), local_env)
                     }
                 }
             } else {
-                let name = this.get_field("name").display().to_string();
+                let name = self
+                    .get(&"name".into(), self.clone().into(), ctx)?
+                    .display()
+                    .to_string();
                 return ctx.throw_type_error(format!("{} is not a constructor", name));
             }
         } else {
@@ -262,16 +398,52 @@ impl GcObject {
         };
 
         match body {
+            // A built-in constructor either initializes the object it is given, or returns the
+            // object it created, as the `Function` constructor does.
             FunctionBody::BuiltIn(function) => {
-                function(&this, args, ctx)?;
-                Ok(this)
+                let this: Value = Object::create(prototype).into();
+                let result = function(&this, args, ctx)?;
+                Ok(if result.is_object() { result } else { this })
+            }
+            FunctionBody::Closure(function, captures) => {
+                let this: Value = Object::create(prototype).into();
+                let result = function(&this, args, &captures, ctx)?;
+                Ok(if result.is_object() { result } else { this })
             }
-            FunctionBody::Ordinary(body) => {
-                let _ = body.run(ctx);
+            FunctionBody::Ordinary(body, params, environment) => {
+                ctx.realm_mut().environment.push(environment);
+                let result = ctx.run_with_strict_mode(strict, |ctx| {
+                    bind_parameters(&params, args, ctx).and_then(|_| body.run(ctx))
+                });
+                let returned =
+                    matches!(ctx.executor().get_current_state(), InterpreterState::Return);
+                ctx.executor()
+                    .set_current_state(InterpreterState::Executing);
 
                 // local_env gets dropped here, its no longer needed
-                let binding = ctx.realm_mut().environment.get_this_binding();
-                Ok(binding)
+                let this = ctx.realm_mut().environment.get_this_binding();
+                ctx.realm_mut().environment.pop();
+
+                let result = result?;
+                if returned {
+                    // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget>
+                    if result.is_object() {
+                        return Ok(result);
+                    }
+                    if !result.is_undefined() && derived {
+                        return ctx.throw_type_error(
+                            "derived constructors may only return object or undefined",
+                        );
+                    }
+                }
+
+                this.map_err(|err| ctx.construct_reference_error(err.to_string()))
+            }
+            FunctionBody::Generator(..) => {
+                unreachable!("generator functions are not constructable")
+            }
+            FunctionBody::Async(..) => {
+                unreachable!("async functions are not constructable")
             }
         }
     }
@@ -308,7 +480,7 @@ impl GcObject {
         // a recursive structure
         // We can follow v8 & SpiderMonkey's lead and return a default value for the hint in this situation
         // (see https://repl.it/repls/IvoryCircularCertification#index.js)
-        let recursion_limiter = RecursionLimiter::new(&self);
+        let recursion_limiter = RecursionLimiter::new(self);
         if recursion_limiter.live {
             // we're in a recursive object, bail
             return Ok(match hint {
@@ -332,7 +504,7 @@ impl GcObject {
         let this = Value::from(self.clone());
         for name in &method_names {
             // a. Let method be ? Get(O, name).
-            let method: Value = this.get_field(*name);
+            let method: Value = this.get_field(*name, interpreter)?;
             // b. If IsCallable(method) is true, then
             if method.is_function() {
                 // i. Let result be ? Call(method, O).
@@ -348,6 +520,68 @@ impl GcObject {
         interpreter.throw_type_error("cannot convert object to primitive value")
     }
 
+    /// Determines if `object` inherits from the instance object inheritance path provided by
+    /// this constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryhasinstance
+    pub(crate) fn ordinary_has_instance(
+        &self,
+        object: &Value,
+        context: &mut Context,
+    ) -> Result<bool> {
+        // 1. If IsCallable(C) is false, return false.
+        // The constructors that must be called with `new`, like `Promise`, are not marked as
+        // callable, but they are function objects too.
+        if !self.is_callable() && !self.is_constructable() {
+            return Ok(false);
+        }
+
+        // 2. If C has a [[BoundTargetFunction]] internal slot, then
+        let bound_target = self
+            .borrow()
+            .as_bound_function()
+            .map(|bound| bound.target_function().clone());
+        if let Some(bound_target) = bound_target {
+            // a. Let BC be C.[[BoundTargetFunction]].
+            // b. Return ? InstanceofOperator(O, BC).
+            return bound_target.ordinary_has_instance(object, context);
+        }
+
+        // 3. If Type(O) is not Object, return false.
+        let mut object = match object {
+            Value::Object(ref object) => object.clone(),
+            _ => return Ok(false),
+        };
+
+        // 4. Let P be ? Get(C, "prototype").
+        // 5. If Type(P) is not Object, throw a TypeError exception.
+        let prototype = match self.get(&"prototype".into(), self.clone().into(), context)? {
+            Value::Object(ref prototype) => prototype.clone(),
+            _ => {
+                return Err(context
+                    .construct_type_error("function has non-object prototype in instanceof check"))
+            }
+        };
+
+        // 6. Repeat,
+        loop {
+            // a. Set O to ? O.[[GetPrototypeOf]]().
+            // b. If O is null, return false.
+            object = match object.get_prototype_of(context)? {
+                Value::Object(ref object) => object.clone(),
+                _ => return Ok(false),
+            };
+
+            // c. If SameValue(P, O) is true, return true.
+            if GcObject::equals(&prototype, &object) {
+                return Ok(true);
+            }
+        }
+    }
+
     /// Converts an object to JSON, checking for reference cycles and throwing a TypeError if one is found
     pub(crate) fn to_json(&self, interpreter: &mut Context) -> Result<JSONValue> {
         let rec_limiter = RecursionLimiter::new(self);
@@ -359,7 +593,7 @@ impl GcObject {
             let mut arr: Vec<JSONValue> = Vec::with_capacity(keys.len());
             let this = Value::from(self.clone());
             for key in keys {
-                let value = this.get_field(key);
+                let value = this.get_field(key, interpreter)?;
                 if value.is_undefined() || value.is_function() || value.is_symbol() {
                     arr.push(JSONValue::Null);
                 } else {
@@ -370,9 +604,10 @@ impl GcObject {
         } else {
             let mut new_obj = Map::new();
             let this = Value::from(self.clone());
-            for k in self.borrow().keys() {
+            let keys: Vec<PropertyKey> = self.borrow().keys().collect();
+            for k in keys {
                 let key = k.clone();
-                let value = this.get_field(k.to_string());
+                let value = this.get_field(k.to_string(), interpreter)?;
                 if !value.is_undefined() && !value.is_function() && !value.is_symbol() {
                     new_obj.insert(key.to_string(), value.to_json(interpreter)?);
                 }
@@ -388,40 +623,46 @@ impl GcObject {
     /// Panics if the object is currently mutably borrowed.
     pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PropertyDescriptor> {
         let mut attribute = Attribute::empty();
+        let this = Value::from(self.clone());
 
         let enumerable_key = PropertyKey::from("enumerable");
-        if self.has_property(&enumerable_key) && self.get(&enumerable_key).to_boolean() {
+        if self.has_property(&enumerable_key, context)?
+            && this.get_field(enumerable_key, context)?.to_boolean()
+        {
             attribute |= Attribute::ENUMERABLE;
         }
 
         let configurable_key = PropertyKey::from("configurable");
-        if self.has_property(&configurable_key) && self.get(&configurable_key).to_boolean() {
+        if self.has_property(&configurable_key, context)?
+            && this.get_field(configurable_key, context)?.to_boolean()
+        {
             attribute |= Attribute::CONFIGURABLE;
         }
 
         let mut value = None;
         let value_key = PropertyKey::from("value");
-        if self.has_property(&value_key) {
-            value = Some(self.get(&value_key));
+        if self.has_property(&value_key, context)? {
+            value = Some(this.get_field(value_key, context)?);
         }
 
         let mut has_writable = false;
         let writable_key = PropertyKey::from("writable");
-        if self.has_property(&writable_key) {
+        if self.has_property(&writable_key, context)? {
             has_writable = true;
-            if self.get(&writable_key).to_boolean() {
+            if this.get_field(writable_key, context)?.to_boolean() {
                 attribute |= Attribute::WRITABLE;
             }
         }
 
         let mut get = None;
         let get_key = PropertyKey::from("get");
-        if self.has_property(&get_key) {
-            let getter = self.get(&get_key);
-            match getter {
+        let has_get = self.has_property(&get_key, context)?;
+        if has_get {
+            match this.get_field(get_key, context)? {
                 Value::Object(ref object) if object.is_callable() => {
                     get = Some(object.clone());
                 }
+                Value::Undefined => {}
                 _ => {
                     return Err(
                         context.construct_type_error("Property descriptor getter must be callable")
@@ -432,12 +673,13 @@ impl GcObject {
 
         let mut set = None;
         let set_key = PropertyKey::from("set");
-        if self.has_property(&set_key) {
-            let setter = self.get(&set_key);
-            match setter {
+        let has_set = self.has_property(&set_key, context)?;
+        if has_set {
+            match this.get_field(set_key, context)? {
                 Value::Object(ref object) if object.is_callable() => {
                     set = Some(object.clone());
                 }
+                Value::Undefined => {}
                 _ => {
                     return Err(
                         context.construct_type_error("Property descriptor setter must be callable")
@@ -446,7 +688,7 @@ impl GcObject {
             };
         }
 
-        if get.is_some() || set.is_some() {
+        if has_get || has_set {
             if value.is_some() || has_writable {
                 return Err(context.construct_type_error("Invalid property descriptor. Cannot both specify accessors and a value or writable attribute"));
             }
@@ -457,6 +699,110 @@ impl GcObject {
         }
     }
 
+    /// Gets the constructor used to create the objects derived from this object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-speciesconstructor
+    pub(crate) fn species_constructor(&self, default: Value, ctx: &mut Context) -> Result<Value> {
+        let constructor = self.get(&"constructor".into(), self.clone().into(), ctx)?;
+        if constructor.is_undefined() {
+            return Ok(default);
+        }
+        if !constructor.is_object() {
+            return ctx.throw_type_error("object.constructor is not an object");
+        }
+
+        let species = ctx.well_known_symbols().species_symbol();
+        let species = constructor.get_field_with_receiver(species, &constructor, ctx)?;
+        match species {
+            Value::Null | Value::Undefined => Ok(default),
+            Value::Object(ref object) if object.is_constructable() => Ok(species),
+            _ => ctx.throw_type_error("object.constructor[Symbol.species] is not a constructor"),
+        }
+    }
+
+    /// Makes the object non-extensible, and its own properties non-configurable. If the level is
+    /// `Frozen`, the data properties are also made non-writable.
+    ///
+    /// Returns `false` if the object could not be made non-extensible.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setintegritylevel
+    pub(crate) fn set_integrity_level(
+        &mut self,
+        level: IntegrityLevel,
+        context: &mut Context,
+    ) -> Result<bool> {
+        if !self.prevent_extensions(context)? {
+            return Ok(false);
+        }
+
+        for key in self.own_property_keys(context)? {
+            let desc = match self.get_own_property(&key, context)? {
+                Some(desc) => desc,
+                None => continue,
+            };
+            let desc = match desc {
+                PropertyDescriptor::Data(ref desc) => {
+                    let mut desc = desc.clone();
+                    desc.set_configurable(false);
+                    if level == IntegrityLevel::Frozen {
+                        desc.set_writable(false);
+                    }
+                    desc.into()
+                }
+                PropertyDescriptor::Accessor(ref desc) => {
+                    let mut desc = desc.clone();
+                    desc.set_configurable(false);
+                    desc.into()
+                }
+            };
+            if !self.define_own_property(key.clone(), desc, context)? {
+                return Err(
+                    context.construct_type_error(format!("Cannot redefine property: {}", key))
+                );
+            }
+        }
+        Ok(true)
+    }
+
+    /// Checks if the object is non-extensible, and its own properties are non-configurable. If
+    /// the level is `Frozen`, the data properties must also be non-writable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-testintegritylevel
+    pub(crate) fn test_integrity_level(
+        &self,
+        level: IntegrityLevel,
+        context: &mut Context,
+    ) -> Result<bool> {
+        if self.is_extensible(context)? {
+            return Ok(false);
+        }
+
+        for key in self.own_property_keys(context)? {
+            if let Some(desc) = self.get_own_property(&key, context)? {
+                if desc.configurable() {
+                    return Ok(false);
+                }
+                if level == IntegrityLevel::Frozen
+                    && desc
+                        .as_data_descriptor()
+                        .is_some_and(|desc| desc.writable())
+                {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
     /// Reeturn `true` if it is a native object and the native type is `T`.
     ///
     /// # Panics
@@ -567,6 +913,17 @@ impl GcObject {
         self.borrow().is_map()
     }
 
+    /// Checks if it is a `Set` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_set(&self) -> bool {
+        self.borrow().is_set()
+    }
+
     /// Checks if it a `String` object.
     ///
     /// # Panics
@@ -681,7 +1038,30 @@ impl GcObject {
 impl AsRef<GcCell<Object>> for GcObject {
     #[inline]
     fn as_ref(&self) -> &GcCell<Object> {
-        &*self.0
+        &self.0
+    }
+}
+
+impl WeakGcObject {
+    /// Returns the object, or `None` if it was garbage collected.
+    #[inline]
+    pub fn upgrade(&self) -> Option<GcObject> {
+        self.0.upgrade().map(GcObject)
+    }
+
+    /// Returns `true` if the object wasn't garbage collected yet.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.0.is_alive()
+    }
+}
+
+impl Debug for WeakGcObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.upgrade() {
+            Some(object) => f.debug_tuple("WeakGcObject").field(&object).finish(),
+            None => f.write_str("WeakGcObject(<collected>)"),
+        }
     }
 }
 
@@ -794,8 +1174,8 @@ impl RecursionLimiter {
 }
 
 impl Debug for GcObject {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
-        let limiter = RecursionLimiter::new(&self);
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limiter = RecursionLimiter::new(self);
 
         // Typically, using `!limiter.live` would be good enough here.
         // However, the JS object hierarchy involves quite a bit of repitition, and the sheer amount of data makes
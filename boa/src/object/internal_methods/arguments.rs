@@ -0,0 +1,190 @@
+//! This module defines the internal methods of mapped arguments objects.
+//!
+//! The elements of a mapped arguments object that have a parameter are aliased to the binding
+//! of the parameter: reading one of them reads the binding, and writing one of them writes the
+//! binding. An element stops being mapped when it is deleted, redefined as an accessor or made
+//! read-only.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects
+
+use super::{
+    ordinary_delete, ordinary_get, ordinary_get_prototype_of, ordinary_has_property,
+    ordinary_is_extensible, ordinary_own_property_keys, ordinary_prevent_extensions, ordinary_set,
+    ordinary_set_prototype_of, InternalObjectMethods,
+};
+use crate::{
+    object::GcObject,
+    property::{DataDescriptor, PropertyDescriptor, PropertyKey},
+    Context, Result, Value,
+};
+
+/// The internal methods of mapped arguments objects.
+pub(crate) static ARGUMENTS_EXOTIC_INTERNAL_METHODS: InternalObjectMethods =
+    InternalObjectMethods {
+        get_prototype_of: ordinary_get_prototype_of,
+        set_prototype_of: ordinary_set_prototype_of,
+        is_extensible: ordinary_is_extensible,
+        prevent_extensions: ordinary_prevent_extensions,
+        get_own_property: arguments_exotic_get_own_property,
+        define_own_property: arguments_exotic_define_own_property,
+        has_property: ordinary_has_property,
+        get: arguments_exotic_get,
+        set: arguments_exotic_set,
+        delete: arguments_exotic_delete,
+        own_property_keys: ordinary_own_property_keys,
+        call: GcObject::call_function,
+        construct: GcObject::construct_function,
+    };
+
+/// Returns the index of `key` if it is an element that is mapped to a parameter.
+fn mapped_index(obj: &GcObject, key: &PropertyKey) -> Option<u32> {
+    match key {
+        PropertyKey::Index(index) => obj
+            .borrow()
+            .as_mapped_arguments()
+            .expect("arguments exotic internal methods are only used by mapped arguments")
+            .is_mapped(*index)
+            .then_some(*index),
+        _ => None,
+    }
+}
+
+/// Returns the value of the parameter the element at `index` is mapped to.
+fn get_mapped(obj: &GcObject, index: u32) -> Value {
+    obj.borrow()
+        .as_mapped_arguments()
+        .and_then(|map| map.get(index))
+        .unwrap_or_default()
+}
+
+/// Sets the value of the parameter the element at `index` is mapped to.
+fn set_mapped(obj: &GcObject, index: u32, value: Value) {
+    if let Some(map) = obj.borrow().as_mapped_arguments() {
+        map.set(index, value);
+    }
+}
+
+/// Removes the mapping of the element at `index`.
+fn unmap(obj: &GcObject, index: u32) {
+    if let Some(map) = obj.borrow_mut().as_mapped_arguments_mut() {
+        map.unmap(index);
+    }
+}
+
+/// `[[GetOwnProperty]] ( P )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-getownproperty-p
+fn arguments_exotic_get_own_property(
+    obj: &GcObject,
+    key: &PropertyKey,
+    _: &mut Context,
+) -> Result<Option<PropertyDescriptor>> {
+    let desc = match obj.ordinary_get_own_property(key) {
+        Some(desc) => desc,
+        None => return Ok(None),
+    };
+
+    Ok(Some(match mapped_index(obj, key) {
+        Some(index) if desc.is_data_descriptor() => {
+            DataDescriptor::new(get_mapped(obj, index), desc.attributes()).into()
+        }
+        _ => desc,
+    }))
+}
+
+/// `[[DefineOwnProperty]] ( P, Desc )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-defineownproperty-p-desc
+fn arguments_exotic_define_own_property(
+    obj: &GcObject,
+    key: PropertyKey,
+    desc: PropertyDescriptor,
+    _: &mut Context,
+) -> Result<bool> {
+    let index = mapped_index(obj, &key);
+    if !obj.ordinary_define_own_property(key, desc.clone()) {
+        return Ok(false);
+    }
+
+    if let Some(index) = index {
+        match desc.as_data_descriptor() {
+            Some(desc) => {
+                set_mapped(obj, index, desc.value());
+                if !desc.writable() {
+                    unmap(obj, index);
+                }
+            }
+            None => unmap(obj, index),
+        }
+    }
+    Ok(true)
+}
+
+/// `[[Get]] ( P, Receiver )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-get-p-receiver
+fn arguments_exotic_get(
+    obj: &GcObject,
+    key: &PropertyKey,
+    receiver: Value,
+    context: &mut Context,
+) -> Result<Value> {
+    match mapped_index(obj, key) {
+        Some(index) => Ok(get_mapped(obj, index)),
+        None => ordinary_get(obj, key, receiver, context),
+    }
+}
+
+/// `[[Set]] ( P, V, Receiver )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-set-p-v-receiver
+fn arguments_exotic_set(
+    obj: &GcObject,
+    key: PropertyKey,
+    value: Value,
+    receiver: Value,
+    context: &mut Context,
+) -> Result<bool> {
+    // The parameter is only changed when the arguments object is the receiver, and not when it
+    // is in the prototype chain of the receiver.
+    if matches!(receiver, Value::Object(ref receiver) if GcObject::equals(receiver, obj)) {
+        if let Some(index) = mapped_index(obj, &key) {
+            set_mapped(obj, index, value.clone());
+        }
+    }
+    ordinary_set(obj, key, value, receiver, context)
+}
+
+/// `[[Delete]] ( P )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-delete-p
+fn arguments_exotic_delete(
+    obj: &GcObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> Result<bool> {
+    let index = mapped_index(obj, key);
+    let result = ordinary_delete(obj, key, context)?;
+    if let (true, Some(index)) = (result, index) {
+        unmap(obj, index);
+    }
+    Ok(result)
+}
@@ -0,0 +1,97 @@
+//! This module defines the internal methods of bound function exotic objects.
+//!
+//! A bound function calls its target function with the bound `this` value, and with the bound
+//! arguments followed by the arguments it is called with. Its other internal methods are the
+//! ordinary ones.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects
+
+use super::{
+    ordinary_define_own_property, ordinary_delete, ordinary_get, ordinary_get_own_property,
+    ordinary_get_prototype_of, ordinary_has_property, ordinary_is_extensible,
+    ordinary_own_property_keys, ordinary_prevent_extensions, ordinary_set,
+    ordinary_set_prototype_of, InternalObjectMethods,
+};
+use crate::{
+    object::GcObject,
+    value::{same_value, Value},
+    Context, Result,
+};
+
+/// The internal methods of bound functions.
+pub(crate) static BOUND_FUNCTION_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    get_prototype_of: ordinary_get_prototype_of,
+    set_prototype_of: ordinary_set_prototype_of,
+    is_extensible: ordinary_is_extensible,
+    prevent_extensions: ordinary_prevent_extensions,
+    get_own_property: ordinary_get_own_property,
+    define_own_property: ordinary_define_own_property,
+    has_property: ordinary_has_property,
+    get: ordinary_get,
+    set: ordinary_set,
+    delete: ordinary_delete,
+    own_property_keys: ordinary_own_property_keys,
+    call: bound_function_call,
+    construct: bound_function_construct,
+};
+
+/// Returns the target function of a bound function, and the arguments it is called with, which
+/// are the bound arguments followed by `args`.
+fn bound_function_data(obj: &GcObject, args: &[Value]) -> (GcObject, Value, Vec<Value>) {
+    let obj = obj.borrow();
+    let bound = obj
+        .as_bound_function()
+        .expect("bound function internal methods are only used by bound functions");
+    let mut bound_args = bound.bound_args().to_vec();
+    bound_args.extend_from_slice(args);
+    (
+        bound.target_function().clone(),
+        bound.bound_this().clone(),
+        bound_args,
+    )
+}
+
+/// `[[Call]] ( thisArgument, argumentsList )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects-call-thisargument-argumentslist
+fn bound_function_call(
+    obj: &GcObject,
+    _: &Value,
+    args: &[Value],
+    context: &mut Context,
+) -> Result<Value> {
+    let (target, bound_this, args) = bound_function_data(obj, args);
+    target.call(&bound_this, &args, context)
+}
+
+/// `[[Construct]] ( argumentsList, newTarget )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects-construct-argumentslist-newtarget
+fn bound_function_construct(
+    obj: &GcObject,
+    args: &[Value],
+    new_target: &Value,
+    context: &mut Context,
+) -> Result<Value> {
+    let (target, _, args) = bound_function_data(obj, args);
+    if !target.is_constructable() {
+        return context.throw_type_error("bound function is not a constructor");
+    }
+
+    // A bound function that is the target of `new` constructs its target function instead.
+    let new_target = if same_value(&obj.clone().into(), new_target) {
+        target.clone().into()
+    } else {
+        new_target.clone()
+    };
+    target.construct(&args, &new_target, context)
+}
@@ -0,0 +1,202 @@
+//! This module defines the internal methods of integer-indexed exotic objects, which are the
+//! typed arrays.
+//!
+//! The properties whose key is a canonical numeric string are the elements of the typed array,
+//! stored in its buffer. The other properties are ordinary properties.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-integer-indexed-exotic-objects
+
+use super::{
+    ordinary_define_own_property, ordinary_delete, ordinary_get, ordinary_get_own_property,
+    ordinary_get_prototype_of, ordinary_has_property, ordinary_is_extensible,
+    ordinary_own_property_keys, ordinary_prevent_extensions, ordinary_set,
+    ordinary_set_prototype_of, InternalObjectMethods,
+};
+use crate::{
+    builtins::{typed_array::TypedArray, Number},
+    object::GcObject,
+    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    value::Value,
+    Context, Result,
+};
+
+/// The internal methods of typed arrays.
+pub(crate) static INTEGER_INDEXED_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    get_prototype_of: ordinary_get_prototype_of,
+    set_prototype_of: ordinary_set_prototype_of,
+    is_extensible: ordinary_is_extensible,
+    prevent_extensions: ordinary_prevent_extensions,
+    get_own_property: integer_indexed_get_own_property,
+    define_own_property: integer_indexed_define_own_property,
+    has_property: integer_indexed_has_property,
+    get: integer_indexed_get,
+    set: integer_indexed_set,
+    delete: integer_indexed_delete,
+    own_property_keys: integer_indexed_own_property_keys,
+    call: GcObject::call_function,
+    construct: GcObject::construct_function,
+};
+
+/// Returns the typed array data of an integer-indexed object.
+fn typed_array(obj: &GcObject) -> TypedArray {
+    obj.borrow()
+        .as_typed_array()
+        .expect("integer-indexed internal methods are only used by typed arrays")
+        .clone()
+}
+
+/// Abstract operation `CanonicalNumericIndexString ( argument )`
+///
+/// Returns the number that a property key is the canonical string of, if any.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalnumericindexstring
+fn canonical_numeric_index(key: &PropertyKey) -> Option<f64> {
+    match key {
+        PropertyKey::Index(index) => Some(f64::from(*index)),
+        PropertyKey::String(string) if string.as_str() == "-0" => Some(-0.0),
+        PropertyKey::String(string) => string
+            .parse::<f64>()
+            .ok()
+            .filter(|number| Number::to_native_string(*number) == string.as_str()),
+        PropertyKey::Symbol(_) => None,
+    }
+}
+
+fn integer_indexed_get_own_property(
+    obj: &GcObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> Result<Option<PropertyDescriptor>> {
+    match canonical_numeric_index(key) {
+        Some(index) => {
+            let array = typed_array(obj);
+            if !array.is_valid_index(index) {
+                return Ok(None);
+            }
+            let value = array.get_element(index as usize);
+            Ok(Some(
+                DataDescriptor::new(
+                    value,
+                    Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                )
+                .into(),
+            ))
+        }
+        None => ordinary_get_own_property(obj, key, context),
+    }
+}
+
+fn integer_indexed_has_property(
+    obj: &GcObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> Result<bool> {
+    match canonical_numeric_index(key) {
+        Some(index) => Ok(typed_array(obj).is_valid_index(index)),
+        None => ordinary_has_property(obj, key, context),
+    }
+}
+
+/// `[[DefineOwnProperty]]` of typed arrays.
+///
+/// Elements can only be redefined as data properties, whose value is stored in the buffer.
+fn integer_indexed_define_own_property(
+    obj: &GcObject,
+    key: PropertyKey,
+    desc: PropertyDescriptor,
+    context: &mut Context,
+) -> Result<bool> {
+    match canonical_numeric_index(&key) {
+        Some(index) => {
+            let array = typed_array(obj);
+            if !array.is_valid_index(index) {
+                return Ok(false);
+            }
+            match desc {
+                PropertyDescriptor::Accessor(_) => Ok(false),
+                PropertyDescriptor::Data(ref desc) => {
+                    let value = array.kind().to_element(&desc.value(), context)?;
+                    array.set_element(index as usize, &value);
+                    Ok(true)
+                }
+            }
+        }
+        None => ordinary_define_own_property(obj, key, desc, context),
+    }
+}
+
+fn integer_indexed_get(
+    obj: &GcObject,
+    key: &PropertyKey,
+    receiver: Value,
+    context: &mut Context,
+) -> Result<Value> {
+    match canonical_numeric_index(key) {
+        Some(index) => {
+            let array = typed_array(obj);
+            if !array.is_valid_index(index) {
+                return Ok(Value::undefined());
+            }
+            Ok(array.get_element(index as usize))
+        }
+        None => ordinary_get(obj, key, receiver, context),
+    }
+}
+
+fn integer_indexed_set(
+    obj: &GcObject,
+    key: PropertyKey,
+    value: Value,
+    receiver: Value,
+    context: &mut Context,
+) -> Result<bool> {
+    match canonical_numeric_index(&key) {
+        Some(index) => {
+            let array = typed_array(obj);
+            let is_receiver =
+                matches!(receiver, Value::Object(ref receiver) if GcObject::equals(receiver, obj));
+            if is_receiver {
+                // The value is converted even if the index is invalid.
+                let value = array.kind().to_element(&value, context)?;
+                if array.is_valid_index(index) {
+                    array.set_element(index as usize, &value);
+                }
+                Ok(true)
+            } else if !array.is_valid_index(index) {
+                Ok(true)
+            } else {
+                ordinary_set(obj, key, value, receiver, context)
+            }
+        }
+        None => ordinary_set(obj, key, value, receiver, context),
+    }
+}
+
+fn integer_indexed_delete(
+    obj: &GcObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> Result<bool> {
+    match canonical_numeric_index(key) {
+        Some(index) => Ok(!typed_array(obj).is_valid_index(index)),
+        None => ordinary_delete(obj, key, context),
+    }
+}
+
+/// `[[OwnPropertyKeys]]` of typed arrays, which returns the indices of the elements before
+/// the keys of the ordinary properties.
+fn integer_indexed_own_property_keys(
+    obj: &GcObject,
+    context: &mut Context,
+) -> Result<Vec<PropertyKey>> {
+    let length = typed_array(obj).length().unwrap_or(0);
+    let mut keys: Vec<PropertyKey> = (0..length).map(PropertyKey::from).collect();
+    keys.extend(ordinary_own_property_keys(obj, context)?);
+    Ok(keys)
+}
@@ -0,0 +1,771 @@
+//! This module defines the object internal methods.
+//!
+//! The internal methods of an object are looked up in its [`InternalObjectMethods`], so that
+//! exotic objects, like proxies, can override the ordinary behaviour.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots
+
+use crate::{
+    object::{GcObject, Object},
+    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    value::{same_value, Value},
+    BoaProfiler, Context, Result,
+};
+
+pub(crate) mod arguments;
+pub(crate) mod bound_function;
+pub(crate) mod integer_indexed;
+pub(crate) mod proxy;
+pub(crate) mod string;
+
+/// The essential internal methods of an object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-invariants-of-the-essential-internal-methods
+#[derive(Clone, Copy)]
+pub(crate) struct InternalObjectMethods {
+    pub(crate) get_prototype_of: fn(&GcObject, &mut Context) -> Result<Value>,
+    pub(crate) set_prototype_of: fn(&GcObject, Value, &mut Context) -> Result<bool>,
+    pub(crate) is_extensible: fn(&GcObject, &mut Context) -> Result<bool>,
+    pub(crate) prevent_extensions: fn(&GcObject, &mut Context) -> Result<bool>,
+    pub(crate) get_own_property:
+        fn(&GcObject, &PropertyKey, &mut Context) -> Result<Option<PropertyDescriptor>>,
+    pub(crate) define_own_property:
+        fn(&GcObject, PropertyKey, PropertyDescriptor, &mut Context) -> Result<bool>,
+    pub(crate) has_property: fn(&GcObject, &PropertyKey, &mut Context) -> Result<bool>,
+    pub(crate) get: fn(&GcObject, &PropertyKey, Value, &mut Context) -> Result<Value>,
+    pub(crate) set: fn(&GcObject, PropertyKey, Value, Value, &mut Context) -> Result<bool>,
+    pub(crate) delete: fn(&GcObject, &PropertyKey, &mut Context) -> Result<bool>,
+    pub(crate) own_property_keys: fn(&GcObject, &mut Context) -> Result<Vec<PropertyKey>>,
+    pub(crate) call: fn(&GcObject, &Value, &[Value], &mut Context) -> Result<Value>,
+    pub(crate) construct: fn(&GcObject, &[Value], &Value, &mut Context) -> Result<Value>,
+}
+
+/// The internal methods of ordinary objects, which are also used by the function objects.
+pub(crate) static ORDINARY_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    get_prototype_of: ordinary_get_prototype_of,
+    set_prototype_of: ordinary_set_prototype_of,
+    is_extensible: ordinary_is_extensible,
+    prevent_extensions: ordinary_prevent_extensions,
+    get_own_property: ordinary_get_own_property,
+    define_own_property: ordinary_define_own_property,
+    has_property: ordinary_has_property,
+    get: ordinary_get,
+    set: ordinary_set,
+    delete: ordinary_delete,
+    own_property_keys: ordinary_own_property_keys,
+    call: GcObject::call_function,
+    construct: GcObject::construct_function,
+};
+
+impl GcObject {
+    /// Returns the internal methods of the object.
+    #[inline]
+    fn internal_methods(&self) -> &'static InternalObjectMethods {
+        self.borrow().internal_methods()
+    }
+
+    /// `[[HasProperty]]`
+    ///
+    /// Check if object has property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-hasproperty-p
+    #[inline]
+    pub fn has_property(&self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        (self.internal_methods().has_property)(self, key, context)
+    }
+
+    /// `[[IsExtensible]]`
+    ///
+    /// Check if it is extensible.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-isextensible
+    #[inline]
+    pub fn is_extensible(&self, context: &mut Context) -> Result<bool> {
+        (self.internal_methods().is_extensible)(self, context)
+    }
+
+    /// `[[PreventExtensions]]`
+    ///
+    /// Disable extensibility.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-preventextensions
+    #[inline]
+    pub fn prevent_extensions(&mut self, context: &mut Context) -> Result<bool> {
+        (self.internal_methods().prevent_extensions)(self, context)
+    }
+
+    /// `[[Delete]]`
+    ///
+    /// Delete property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-delete-p
+    #[inline]
+    pub fn delete(&mut self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        (self.internal_methods().delete)(self, key, context)
+    }
+
+    /// `[[Get]]`
+    ///
+    /// Gets the value of the property `key`, calling its getter with `receiver` as the `this`
+    /// value if it is an accessor property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver
+    #[inline]
+    pub fn get(&self, key: &PropertyKey, receiver: Value, context: &mut Context) -> Result<Value> {
+        (self.internal_methods().get)(self, key, receiver, context)
+    }
+
+    /// `[[Set]]`
+    ///
+    /// Sets the value of the property `key`, calling its setter with `receiver` as the `this`
+    /// value if it is an accessor property. Otherwise the property is created or updated on the
+    /// `receiver`.
+    ///
+    /// Returns `false` if the property could not be set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-set-p-v-receiver
+    #[inline]
+    pub fn set(
+        &mut self,
+        key: PropertyKey,
+        val: Value,
+        receiver: Value,
+        context: &mut Context,
+    ) -> Result<bool> {
+        (self.internal_methods().set)(self, key, val, receiver, context)
+    }
+
+    /// `[[DefineOwnProperty]]`
+    ///
+    /// Define an own property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-defineownproperty-p-desc
+    #[inline]
+    pub fn define_own_property<K>(
+        &mut self,
+        key: K,
+        desc: PropertyDescriptor,
+        context: &mut Context,
+    ) -> Result<bool>
+    where
+        K: Into<PropertyKey>,
+    {
+        (self.internal_methods().define_own_property)(self, key.into(), desc, context)
+    }
+
+    /// `[[GetOwnProperty]]`
+    ///
+    /// The specification returns a Property Descriptor or Undefined.
+    ///
+    /// These are 2 separate types and we can't do that here.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-getownproperty-p
+    #[inline]
+    pub fn get_own_property(
+        &self,
+        key: &PropertyKey,
+        context: &mut Context,
+    ) -> Result<Option<PropertyDescriptor>> {
+        (self.internal_methods().get_own_property)(self, key, context)
+    }
+
+    /// `[[OwnPropertyKeys]]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-ownpropertykeys
+    #[inline]
+    pub fn own_property_keys(&self, context: &mut Context) -> Result<Vec<PropertyKey>> {
+        (self.internal_methods().own_property_keys)(self, context)
+    }
+
+    /// `[[GetPrototypeOf]]`
+    ///
+    /// Returns either the prototype or null
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-getprototypeof
+    #[inline]
+    pub fn get_prototype_of(&self, context: &mut Context) -> Result<Value> {
+        (self.internal_methods().get_prototype_of)(self, context)
+    }
+
+    /// `[[SetPrototypeOf]]`
+    ///
+    /// Sets the prototype of the object, returning `false` if it could not be changed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-setprototypeof-v
+    #[inline]
+    pub fn set_prototype_of(&mut self, val: Value, context: &mut Context) -> Result<bool> {
+        (self.internal_methods().set_prototype_of)(self, val, context)
+    }
+
+    /// `[[Call]]`
+    ///
+    /// Call this object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn call(&self, this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        (self.internal_methods().call)(self, this, args, context)
+    }
+
+    /// `[[Construct]]`
+    ///
+    /// Construct an instance of this object with the specified arguments.
+    ///
+    /// `new_target` is the constructor that `new` was initially applied to, it is used to
+    /// find the prototype of the newly created object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn construct(
+        &self,
+        args: &[Value],
+        new_target: &Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        (self.internal_methods().construct)(self, args, new_target, context)
+    }
+
+    /// Abstract operation `OrdinaryGetOwnProperty`
+    ///
+    /// Gets an own property without going through the internal methods of the object, so no
+    /// JavaScript code can run.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinarygetownproperty
+    #[inline]
+    pub(crate) fn ordinary_get_own_property(
+        &self,
+        key: &PropertyKey,
+    ) -> Option<PropertyDescriptor> {
+        let _timer = BoaProfiler::global().start_event("Object::get_own_property", "object");
+
+        let object = self.borrow();
+        let property = match key {
+            PropertyKey::Index(index) => object.indexed_properties.get(index),
+            PropertyKey::String(ref st) => object.string_properties.get(st),
+            PropertyKey::Symbol(ref symbol) => object.symbol_properties.get(symbol),
+        };
+
+        property.cloned()
+    }
+
+    /// Abstract operation `OrdinaryDefineOwnProperty`
+    ///
+    /// Defines an own property without going through the internal methods of the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinarydefineownproperty
+    pub(crate) fn ordinary_define_own_property<K>(&self, key: K, desc: PropertyDescriptor) -> bool
+    where
+        K: Into<PropertyKey>,
+    {
+        let _timer = BoaProfiler::global().start_event("Object::define_own_property", "object");
+
+        let key = key.into();
+        let current = self.ordinary_get_own_property(&key);
+        let extensible = self.borrow().extensible;
+        if !is_compatible_property_descriptor(extensible, &desc, current.as_ref()) {
+            return false;
+        }
+
+        self.borrow_mut().insert(key, desc);
+        true
+    }
+
+    /// Abstract operation `OrdinaryOwnPropertyKeys`
+    ///
+    /// The keys are ordered as integer indices in ascending order, then strings and symbols in
+    /// the order they were created.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
+    #[inline]
+    pub(crate) fn ordinary_own_property_keys(&self) -> Vec<PropertyKey> {
+        let object = self.borrow();
+        let mut indices: Vec<u32> = object.index_property_keys().copied().collect();
+        indices.sort_unstable();
+
+        indices
+            .into_iter()
+            .map(PropertyKey::from)
+            .chain(
+                object
+                    .string_property_keys()
+                    .cloned()
+                    .map(PropertyKey::from),
+            )
+            .chain(
+                object
+                    .symbol_property_keys()
+                    .cloned()
+                    .map(PropertyKey::from),
+            )
+            .collect()
+    }
+
+    /// The abstract operation ObjectDefineProperties
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.defineproperties
+    #[inline]
+    pub fn define_properties(&mut self, props: Value, ctx: &mut Context) -> Result<()> {
+        let props = props.to_object(ctx)?;
+        let keys = props.own_property_keys(ctx)?;
+        let mut descriptors: Vec<(PropertyKey, PropertyDescriptor)> = Vec::new();
+
+        for next_key in keys {
+            if let Some(prop_desc) = props.get_own_property(&next_key, ctx)? {
+                if prop_desc.enumerable() {
+                    let desc_obj = props.get(&next_key, props.clone().into(), ctx)?;
+                    let desc = desc_obj.to_property_descriptor(ctx)?;
+                    descriptors.push((next_key, desc));
+                }
+            }
+        }
+
+        for (p, d) in descriptors {
+            if !self.define_own_property(p.clone(), d, ctx)? {
+                return Err(ctx.construct_type_error(format!("Cannot redefine property: {}", p)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Helper function for property insertion.
+    #[inline]
+    pub(crate) fn insert<K, P>(&mut self, key: K, property: P) -> Option<PropertyDescriptor>
+    where
+        K: Into<PropertyKey>,
+        P: Into<PropertyDescriptor>,
+    {
+        self.borrow_mut().insert(key, property)
+    }
+
+    /// Helper function for property removal.
+    #[inline]
+    pub(crate) fn remove(&mut self, key: &PropertyKey) -> Option<PropertyDescriptor> {
+        self.borrow_mut().remove(key)
+    }
+
+    /// Inserts a field in the object `properties` without checking if it's writable.
+    ///
+    /// If a field was already in the object with the same name that a `Some` is returned
+    /// with that field, otherwise None is returned.
+    #[inline]
+    pub(crate) fn insert_property<K, V>(
+        &mut self,
+        key: K,
+        value: V,
+        attribute: Attribute,
+    ) -> Option<PropertyDescriptor>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        self.insert(key.into(), DataDescriptor::new(value, attribute))
+    }
+
+    /// It determines if Object is a callable function with a [[Call]] internal method.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iscallable
+    #[inline]
+    #[track_caller]
+    pub fn is_callable(&self) -> bool {
+        self.borrow().is_callable()
+    }
+
+    /// It determines if Object is a function object with a [[Construct]] internal method.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
+    #[inline]
+    #[track_caller]
+    pub fn is_constructable(&self) -> bool {
+        self.borrow().is_constructable()
+    }
+}
+
+/// Abstract operation `IsCompatiblePropertyDescriptor`
+///
+/// Returns `true` if the property `current` of an object, or the absence of it, can be
+/// redefined as `desc`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iscompatiblepropertydescriptor
+pub(crate) fn is_compatible_property_descriptor(
+    extensible: bool,
+    desc: &PropertyDescriptor,
+    current: Option<&PropertyDescriptor>,
+) -> bool {
+    let current = match current {
+        Some(current) => current,
+        None => return extensible,
+    };
+
+    if current.configurable() {
+        return true;
+    }
+
+    if desc.configurable() || desc.enumerable() != current.enumerable() {
+        return false;
+    }
+
+    match (current, desc) {
+        (PropertyDescriptor::Data(current), PropertyDescriptor::Data(desc)) => {
+            current.writable() || (!desc.writable() && same_value(&desc.value(), &current.value()))
+        }
+        (PropertyDescriptor::Accessor(current), PropertyDescriptor::Accessor(desc)) => {
+            let same_function =
+                |current: Option<&GcObject>, desc: Option<&GcObject>| match (current, desc) {
+                    (Some(current), Some(desc)) => GcObject::equals(current, desc),
+                    (None, None) => true,
+                    _ => false,
+                };
+            same_function(current.getter(), desc.getter())
+                && same_function(current.setter(), desc.setter())
+        }
+        // A non-configurable property cannot change between a data and an accessor property.
+        _ => false,
+    }
+}
+
+/// Abstract operation `OrdinaryGetPrototypeOf`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinarygetprototypeof
+fn ordinary_get_prototype_of(obj: &GcObject, _: &mut Context) -> Result<Value> {
+    Ok(obj.borrow().prototype.clone())
+}
+
+/// Abstract operation `OrdinarySetPrototypeOf`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinarysetprototypeof
+fn ordinary_set_prototype_of(obj: &GcObject, val: Value, _: &mut Context) -> Result<bool> {
+    debug_assert!(val.is_object() || val.is_null());
+
+    if same_value(&obj.borrow().prototype, &val) {
+        return Ok(true);
+    }
+    if !obj.borrow().extensible {
+        return Ok(false);
+    }
+
+    // Setting the prototype must not create a cycle, which is only checked through the
+    // prototypes that are ordinary objects, as a proxy can change its prototype at any time.
+    let mut p = val.clone();
+    while let Value::Object(ref prototype) = p.clone() {
+        if GcObject::equals(prototype, obj) {
+            return Ok(false);
+        }
+        let prototype = prototype.borrow();
+        if prototype.is_proxy() {
+            break;
+        }
+        p = prototype.prototype.clone();
+    }
+
+    obj.borrow_mut().prototype = val;
+    Ok(true)
+}
+
+/// Abstract operation `OrdinaryIsExtensible`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryisextensible
+fn ordinary_is_extensible(obj: &GcObject, _: &mut Context) -> Result<bool> {
+    Ok(obj.borrow().extensible)
+}
+
+/// Abstract operation `OrdinaryPreventExtensions`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinarypreventextensions
+fn ordinary_prevent_extensions(obj: &GcObject, _: &mut Context) -> Result<bool> {
+    obj.borrow_mut().extensible = false;
+    Ok(true)
+}
+
+fn ordinary_get_own_property(
+    obj: &GcObject,
+    key: &PropertyKey,
+    _: &mut Context,
+) -> Result<Option<PropertyDescriptor>> {
+    Ok(obj.ordinary_get_own_property(key))
+}
+
+fn ordinary_define_own_property(
+    obj: &GcObject,
+    key: PropertyKey,
+    desc: PropertyDescriptor,
+    _: &mut Context,
+) -> Result<bool> {
+    Ok(obj.ordinary_define_own_property(key, desc))
+}
+
+/// Abstract operation `OrdinaryHasProperty`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryhasproperty
+fn ordinary_has_property(obj: &GcObject, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+    if obj.get_own_property(key, context)?.is_some() {
+        return Ok(true);
+    }
+
+    // parent will either be null or an Object
+    match obj.get_prototype_of(context)? {
+        Value::Object(ref parent) => parent.has_property(key, context),
+        _ => Ok(false),
+    }
+}
+
+/// Abstract operation `OrdinaryGet`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryget
+fn ordinary_get(
+    obj: &GcObject,
+    key: &PropertyKey,
+    receiver: Value,
+    context: &mut Context,
+) -> Result<Value> {
+    match obj.get_own_property(key, context)? {
+        None => {
+            // parent will either be null or an Object
+            if let Value::Object(ref parent) = obj.get_prototype_of(context)? {
+                parent.get(key, receiver, context)
+            } else {
+                Ok(Value::undefined())
+            }
+        }
+        Some(PropertyDescriptor::Data(ref desc)) => Ok(desc.value()),
+        Some(PropertyDescriptor::Accessor(ref desc)) => match desc.getter() {
+            Some(getter) => getter.call(&receiver, &[], context),
+            None => Ok(Value::undefined()),
+        },
+    }
+}
+
+/// Abstract operation `OrdinarySet`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinaryset
+fn ordinary_set(
+    obj: &GcObject,
+    key: PropertyKey,
+    val: Value,
+    receiver: Value,
+    context: &mut Context,
+) -> Result<bool> {
+    let _timer = BoaProfiler::global().start_event("Object::set", "object");
+
+    // Fetch property key
+    let own_desc = if let Some(desc) = obj.get_own_property(&key, context)? {
+        desc
+    } else if let Value::Object(ref mut parent) = obj.get_prototype_of(context)? {
+        return parent.set(key, val, receiver, context);
+    } else {
+        DataDescriptor::new(
+            Value::undefined(),
+            Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .into()
+    };
+
+    match &own_desc {
+        PropertyDescriptor::Data(desc) => {
+            if !desc.writable() {
+                return Ok(false);
+            }
+
+            let mut receiver = match &receiver {
+                Value::Object(receiver) => receiver.clone(),
+                _ => return Ok(false),
+            };
+            let desc = match receiver.get_own_property(&key, context)? {
+                Some(PropertyDescriptor::Data(ref existing)) => {
+                    if !existing.writable() {
+                        return Ok(false);
+                    }
+                    DataDescriptor::new(val, existing.attributes())
+                }
+                Some(PropertyDescriptor::Accessor(_)) => return Ok(false),
+                None => DataDescriptor::new(
+                    val,
+                    Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            };
+            receiver.define_own_property(key, desc.into(), context)
+        }
+        PropertyDescriptor::Accessor(desc) => match desc.setter() {
+            Some(setter) => {
+                setter.call(&receiver, &[val], context)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        },
+    }
+}
+
+/// Abstract operation `OrdinaryDelete`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ordinarydelete
+fn ordinary_delete(obj: &GcObject, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+    Ok(match obj.get_own_property(key, context)? {
+        Some(desc) if desc.configurable() => {
+            obj.borrow_mut().remove(key);
+            true
+        }
+        Some(_) => false,
+        None => true,
+    })
+}
+
+fn ordinary_own_property_keys(obj: &GcObject, _: &mut Context) -> Result<Vec<PropertyKey>> {
+    Ok(obj.ordinary_own_property_keys())
+}
+
+impl Object {
+    /// Returns the internal methods of the object, which depend on its kind.
+    #[inline]
+    pub(crate) fn internal_methods(&self) -> &'static InternalObjectMethods {
+        if self.is_proxy() {
+            &proxy::PROXY_INTERNAL_METHODS
+        } else if self.is_bound_function() {
+            &bound_function::BOUND_FUNCTION_INTERNAL_METHODS
+        } else if self.is_typed_array() {
+            &integer_indexed::INTEGER_INDEXED_INTERNAL_METHODS
+        } else if self.is_string() {
+            &string::STRING_EXOTIC_INTERNAL_METHODS
+        } else if self.as_mapped_arguments().is_some() {
+            &arguments::ARGUMENTS_EXOTIC_INTERNAL_METHODS
+        } else {
+            &ORDINARY_INTERNAL_METHODS
+        }
+    }
+
+    /// Helper function for property insertion.
+    #[inline]
+    pub(crate) fn insert<K, P>(&mut self, key: K, property: P) -> Option<PropertyDescriptor>
+    where
+        K: Into<PropertyKey>,
+        P: Into<PropertyDescriptor>,
+    {
+        let property = property.into();
+        match key.into() {
+            PropertyKey::Index(index) => self.indexed_properties.insert(index, property),
+            PropertyKey::String(ref string) => {
+                self.string_properties.insert(string.clone(), property)
+            }
+            PropertyKey::Symbol(ref symbol) => {
+                self.symbol_properties.insert(symbol.clone(), property)
+            }
+        }
+    }
+
+    /// Helper function for property removal.
+    #[inline]
+    pub(crate) fn remove(&mut self, key: &PropertyKey) -> Option<PropertyDescriptor> {
+        match key {
+            PropertyKey::Index(index) => self.indexed_properties.remove(index),
+            PropertyKey::String(ref string) => self.string_properties.remove(string),
+            PropertyKey::Symbol(ref symbol) => self.symbol_properties.remove(symbol),
+        }
+    }
+
+    /// Inserts a field in the object `properties` without checking if it's writable.
+    ///
+    /// If a field was already in the object with the same name that a `Some` is returned
+    /// with that field, otherwise None is retuned.
+    #[inline]
+    pub(crate) fn insert_property<K, V>(
+        &mut self,
+        key: K,
+        value: V,
+        attribute: Attribute,
+    ) -> Option<PropertyDescriptor>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        self.insert(key.into(), DataDescriptor::new(value, attribute))
+    }
+}
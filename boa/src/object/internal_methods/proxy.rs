@@ -0,0 +1,563 @@
+//! This module defines the internal methods of proxy exotic objects.
+//!
+//! Each internal method calls the matching trap of the handler if it has one, and checks that
+//! the result of the trap respects the invariants of the target, or forwards the operation to
+//! the target otherwise.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots
+
+use super::{is_compatible_property_descriptor, InternalObjectMethods};
+use crate::{
+    builtins::{Array, BuiltInObjectObject},
+    object::GcObject,
+    property::{PropertyDescriptor, PropertyKey},
+    value::{same_value, Value},
+    Context, Result,
+};
+
+/// The internal methods of proxy objects.
+pub(crate) static PROXY_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    get_prototype_of: proxy_get_prototype_of,
+    set_prototype_of: proxy_set_prototype_of,
+    is_extensible: proxy_is_extensible,
+    prevent_extensions: proxy_prevent_extensions,
+    get_own_property: proxy_get_own_property,
+    define_own_property: proxy_define_own_property,
+    has_property: proxy_has_property,
+    get: proxy_get,
+    set: proxy_set,
+    delete: proxy_delete,
+    own_property_keys: proxy_own_property_keys,
+    call: proxy_call,
+    construct: proxy_construct,
+};
+
+/// Returns the target and the handler of a proxy, throwing a `TypeError` if it was revoked.
+fn proxy_data(obj: &GcObject, context: &mut Context) -> Result<(GcObject, GcObject)> {
+    obj.borrow()
+        .as_proxy()
+        .expect("proxy internal methods are only used by proxy objects")
+        .try_data(context)
+}
+
+/// Returns the trap `name` of the handler, or `None` if it is undefined or null.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getmethod
+fn get_trap(handler: &GcObject, name: &str, context: &mut Context) -> Result<Option<Value>> {
+    let trap = handler.get(&name.into(), handler.clone().into(), context)?;
+    match trap {
+        Value::Undefined | Value::Null => Ok(None),
+        Value::Object(ref object) if object.is_callable() => Ok(Some(trap)),
+        _ => Err(context.construct_type_error(format!("Proxy trap '{}' is not a function", name))),
+    }
+}
+
+/// Throws a `TypeError` because the result of the trap `name` breaks an invariant.
+fn invariant_error<T>(name: &str, context: &mut Context) -> Result<T> {
+    Err(context.construct_type_error(format!(
+        "Proxy trap '{}' returned a result incompatible with the target",
+        name
+    )))
+}
+
+/// `[[GetPrototypeOf]] ( )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getprototypeof
+fn proxy_get_prototype_of(obj: &GcObject, context: &mut Context) -> Result<Value> {
+    let (target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "getPrototypeOf", context)? {
+        Some(trap) => trap,
+        None => return target.get_prototype_of(context),
+    };
+
+    let handler_proto = context.call(&trap, &handler.into(), &[target.clone().into()])?;
+    if !handler_proto.is_object() && !handler_proto.is_null() {
+        return context
+            .throw_type_error("Proxy trap 'getPrototypeOf' returned neither an object nor null");
+    }
+
+    if !target.is_extensible(context)?
+        && !same_value(&handler_proto, &target.get_prototype_of(context)?)
+    {
+        return invariant_error("getPrototypeOf", context);
+    }
+    Ok(handler_proto)
+}
+
+/// `[[SetPrototypeOf]] ( V )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-setprototypeof-v
+fn proxy_set_prototype_of(obj: &GcObject, val: Value, context: &mut Context) -> Result<bool> {
+    let (mut target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "setPrototypeOf", context)? {
+        Some(trap) => trap,
+        None => return target.set_prototype_of(val, context),
+    };
+
+    let result = context
+        .call(
+            &trap,
+            &handler.into(),
+            &[target.clone().into(), val.clone()],
+        )?
+        .to_boolean();
+    if !result {
+        return Ok(false);
+    }
+
+    if !target.is_extensible(context)? && !same_value(&val, &target.get_prototype_of(context)?) {
+        return invariant_error("setPrototypeOf", context);
+    }
+    Ok(true)
+}
+
+/// `[[IsExtensible]] ( )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-isextensible
+fn proxy_is_extensible(obj: &GcObject, context: &mut Context) -> Result<bool> {
+    let (target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "isExtensible", context)? {
+        Some(trap) => trap,
+        None => return target.is_extensible(context),
+    };
+
+    let result = context
+        .call(&trap, &handler.into(), &[target.clone().into()])?
+        .to_boolean();
+    if result != target.is_extensible(context)? {
+        return invariant_error("isExtensible", context);
+    }
+    Ok(result)
+}
+
+/// `[[PreventExtensions]] ( )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-preventextensions
+fn proxy_prevent_extensions(obj: &GcObject, context: &mut Context) -> Result<bool> {
+    let (mut target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "preventExtensions", context)? {
+        Some(trap) => trap,
+        None => return target.prevent_extensions(context),
+    };
+
+    let result = context
+        .call(&trap, &handler.into(), &[target.clone().into()])?
+        .to_boolean();
+    if result && target.is_extensible(context)? {
+        return invariant_error("preventExtensions", context);
+    }
+    Ok(result)
+}
+
+/// `[[GetOwnProperty]] ( P )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-getownproperty-p
+fn proxy_get_own_property(
+    obj: &GcObject,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> Result<Option<PropertyDescriptor>> {
+    let (target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "getOwnPropertyDescriptor", context)? {
+        Some(trap) => trap,
+        None => return target.get_own_property(key, context),
+    };
+
+    let trap_result = context.call(&trap, &handler.into(), &[target.clone().into(), key.into()])?;
+    let target_desc = target.get_own_property(key, context)?;
+
+    let trap_result =
+        match trap_result {
+            Value::Undefined => {
+                if let Some(target_desc) = target_desc {
+                    // A property can only be reported as missing if it could be deleted.
+                    if !target_desc.configurable() || !target.is_extensible(context)? {
+                        return invariant_error("getOwnPropertyDescriptor", context);
+                    }
+                }
+                return Ok(None);
+            }
+            Value::Object(ref object) => object.to_property_descriptor(context)?,
+            _ => return Err(context.construct_type_error(
+                "Proxy trap 'getOwnPropertyDescriptor' returned neither an object nor undefined",
+            )),
+        };
+
+    let extensible_target = target.is_extensible(context)?;
+    if !is_compatible_property_descriptor(extensible_target, &trap_result, target_desc.as_ref()) {
+        return invariant_error("getOwnPropertyDescriptor", context);
+    }
+
+    // A property can only be reported as non-configurable, or as non-configurable and
+    // non-writable, if it is so on the target.
+    if !trap_result.configurable() {
+        match target_desc {
+            Some(ref target_desc) if !target_desc.configurable() => {
+                if let (PropertyDescriptor::Data(result), PropertyDescriptor::Data(target)) =
+                    (&trap_result, target_desc)
+                {
+                    if !result.writable() && target.writable() {
+                        return invariant_error("getOwnPropertyDescriptor", context);
+                    }
+                }
+            }
+            _ => return invariant_error("getOwnPropertyDescriptor", context),
+        }
+    }
+    Ok(Some(trap_result))
+}
+
+/// `[[DefineOwnProperty]] ( P, Desc )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-defineownproperty-p-desc
+fn proxy_define_own_property(
+    obj: &GcObject,
+    key: PropertyKey,
+    desc: PropertyDescriptor,
+    context: &mut Context,
+) -> Result<bool> {
+    let (mut target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "defineProperty", context)? {
+        Some(trap) => trap,
+        None => return target.define_own_property(key, desc, context),
+    };
+
+    let desc_obj = BuiltInObjectObject::from_property_descriptor(desc.clone(), context)?;
+    let result = context
+        .call(
+            &trap,
+            &handler.into(),
+            &[target.clone().into(), (&key).into(), desc_obj],
+        )?
+        .to_boolean();
+    if !result {
+        return Ok(false);
+    }
+
+    let target_desc = target.get_own_property(&key, context)?;
+    let extensible_target = target.is_extensible(context)?;
+    let setting_config_false = !desc.configurable();
+    match target_desc {
+        None => {
+            if !extensible_target || setting_config_false {
+                return invariant_error("defineProperty", context);
+            }
+        }
+        Some(ref target_desc) => {
+            if !is_compatible_property_descriptor(extensible_target, &desc, Some(target_desc))
+                || (setting_config_false && target_desc.configurable())
+            {
+                return invariant_error("defineProperty", context);
+            }
+            if let (PropertyDescriptor::Data(target_desc), PropertyDescriptor::Data(desc)) =
+                (target_desc, &desc)
+            {
+                if !target_desc.configurable() && target_desc.writable() && !desc.writable() {
+                    return invariant_error("defineProperty", context);
+                }
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// `[[HasProperty]] ( P )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-hasproperty-p
+fn proxy_has_property(obj: &GcObject, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+    let (target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "has", context)? {
+        Some(trap) => trap,
+        None => return target.has_property(key, context),
+    };
+
+    let result = context
+        .call(&trap, &handler.into(), &[target.clone().into(), key.into()])?
+        .to_boolean();
+    if !result {
+        if let Some(target_desc) = target.get_own_property(key, context)? {
+            if !target_desc.configurable() || !target.is_extensible(context)? {
+                return invariant_error("has", context);
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// `[[Get]] ( P, Receiver )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-get-p-receiver
+fn proxy_get(
+    obj: &GcObject,
+    key: &PropertyKey,
+    receiver: Value,
+    context: &mut Context,
+) -> Result<Value> {
+    let (target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "get", context)? {
+        Some(trap) => trap,
+        None => return target.get(key, receiver, context),
+    };
+
+    let trap_result = context.call(
+        &trap,
+        &handler.into(),
+        &[target.clone().into(), key.into(), receiver],
+    )?;
+    match target.get_own_property(key, context)? {
+        Some(PropertyDescriptor::Data(ref desc))
+            if !desc.configurable()
+                && !desc.writable()
+                && !same_value(&trap_result, &desc.value()) =>
+        {
+            invariant_error("get", context)
+        }
+        Some(PropertyDescriptor::Accessor(ref desc))
+            if !desc.configurable() && desc.getter().is_none() && !trap_result.is_undefined() =>
+        {
+            invariant_error("get", context)
+        }
+        _ => Ok(trap_result),
+    }
+}
+
+/// `[[Set]] ( P, V, Receiver )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-set-p-v-receiver
+fn proxy_set(
+    obj: &GcObject,
+    key: PropertyKey,
+    val: Value,
+    receiver: Value,
+    context: &mut Context,
+) -> Result<bool> {
+    let (mut target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "set", context)? {
+        Some(trap) => trap,
+        None => return target.set(key, val, receiver, context),
+    };
+
+    let result = context
+        .call(
+            &trap,
+            &handler.into(),
+            &[target.clone().into(), (&key).into(), val.clone(), receiver],
+        )?
+        .to_boolean();
+    if !result {
+        return Ok(false);
+    }
+
+    match target.get_own_property(&key, context)? {
+        Some(PropertyDescriptor::Data(ref desc))
+            if !desc.configurable() && !desc.writable() && !same_value(&val, &desc.value()) =>
+        {
+            invariant_error("set", context)
+        }
+        Some(PropertyDescriptor::Accessor(ref desc))
+            if !desc.configurable() && desc.setter().is_none() =>
+        {
+            invariant_error("set", context)
+        }
+        _ => Ok(true),
+    }
+}
+
+/// `[[Delete]] ( P )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-delete-p
+fn proxy_delete(obj: &GcObject, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+    let (mut target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "deleteProperty", context)? {
+        Some(trap) => trap,
+        None => return target.delete(key, context),
+    };
+
+    let result = context
+        .call(&trap, &handler.into(), &[target.clone().into(), key.into()])?
+        .to_boolean();
+    if !result {
+        return Ok(false);
+    }
+
+    if let Some(target_desc) = target.get_own_property(key, context)? {
+        if !target_desc.configurable() || !target.is_extensible(context)? {
+            return invariant_error("deleteProperty", context);
+        }
+    }
+    Ok(true)
+}
+
+/// `[[OwnPropertyKeys]] ( )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-ownpropertykeys
+fn proxy_own_property_keys(obj: &GcObject, context: &mut Context) -> Result<Vec<PropertyKey>> {
+    let (target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "ownKeys", context)? {
+        Some(trap) => trap,
+        None => return target.own_property_keys(context),
+    };
+
+    let trap_result_array = context.call(&trap, &handler.into(), &[target.clone().into()])?;
+    let mut trap_result = Vec::new();
+    for element in trap_result_array.create_list_from_array_like(context)? {
+        let key = match element {
+            Value::String(ref string) => PropertyKey::from(string.clone()),
+            Value::Symbol(ref symbol) => PropertyKey::from(symbol.clone()),
+            _ => {
+                return Err(context.construct_type_error(
+                    "Proxy trap 'ownKeys' returned a key that is neither a string nor a symbol",
+                ))
+            }
+        };
+        if trap_result.contains(&key) {
+            return Err(
+                context.construct_type_error("Proxy trap 'ownKeys' returned duplicate keys")
+            );
+        }
+        trap_result.push(key);
+    }
+
+    let extensible_target = target.is_extensible(context)?;
+    let mut target_configurable_keys = Vec::new();
+    let mut target_nonconfigurable_keys = Vec::new();
+    for key in target.own_property_keys(context)? {
+        match target.get_own_property(&key, context)? {
+            Some(desc) if !desc.configurable() => target_nonconfigurable_keys.push(key),
+            _ => target_configurable_keys.push(key),
+        }
+    }
+    if extensible_target && target_nonconfigurable_keys.is_empty() {
+        return Ok(trap_result);
+    }
+
+    // The non-configurable keys of the target must be reported, as well as all of its keys if
+    // it is not extensible, in which case no other key can be reported.
+    let mut unchecked_result_keys = trap_result.clone();
+    for key in target_nonconfigurable_keys {
+        match unchecked_result_keys.iter().position(|k| *k == key) {
+            Some(index) => {
+                unchecked_result_keys.remove(index);
+            }
+            None => return invariant_error("ownKeys", context),
+        }
+    }
+    if extensible_target {
+        return Ok(trap_result);
+    }
+    for key in target_configurable_keys {
+        match unchecked_result_keys.iter().position(|k| *k == key) {
+            Some(index) => {
+                unchecked_result_keys.remove(index);
+            }
+            None => return invariant_error("ownKeys", context),
+        }
+    }
+    if !unchecked_result_keys.is_empty() {
+        return invariant_error("ownKeys", context);
+    }
+    Ok(trap_result)
+}
+
+/// `[[Call]] ( thisArgument, argumentsList )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-call-thisargument-argumentslist
+fn proxy_call(
+    obj: &GcObject,
+    this: &Value,
+    args: &[Value],
+    context: &mut Context,
+) -> Result<Value> {
+    if !obj.is_callable() {
+        return context.throw_type_error("not a function");
+    }
+
+    let (target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "apply", context)? {
+        Some(trap) => trap,
+        None => return target.call(this, args, context),
+    };
+
+    let arg_array = Array::construct_array(&Array::new_array(context)?, args, context)?;
+    context.call(
+        &trap,
+        &handler.into(),
+        &[target.into(), this.clone(), arg_array],
+    )
+}
+
+/// `[[Construct]] ( argumentsList, newTarget )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-construct-argumentslist-newtarget
+fn proxy_construct(
+    obj: &GcObject,
+    args: &[Value],
+    new_target: &Value,
+    context: &mut Context,
+) -> Result<Value> {
+    if !obj.is_constructable() {
+        return context.throw_type_error("proxy is not a constructor");
+    }
+
+    let (target, handler) = proxy_data(obj, context)?;
+    let trap = match get_trap(&handler, "construct", context)? {
+        Some(trap) => trap,
+        None => return target.construct(args, new_target, context),
+    };
+
+    let arg_array = Array::construct_array(&Array::new_array(context)?, args, context)?;
+    let new_obj = context.call(
+        &trap,
+        &handler.into(),
+        &[target.into(), arg_array, new_target.clone()],
+    )?;
+    if !new_obj.is_object() {
+        return context.throw_type_error("Proxy trap 'construct' returned a non-object");
+    }
+    Ok(new_obj)
+}
@@ -0,0 +1,133 @@
+//! This module defines the internal methods of string exotic objects.
+//!
+//! A `String` object has an own property for each code unit of its string value, whose key is
+//! the index of the code unit. These properties are not stored in the object, and they can't be
+//! changed. The other properties are ordinary properties.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-string-exotic-objects
+
+use super::{
+    is_compatible_property_descriptor, ordinary_delete, ordinary_get, ordinary_get_prototype_of,
+    ordinary_has_property, ordinary_is_extensible, ordinary_prevent_extensions, ordinary_set,
+    ordinary_set_prototype_of, InternalObjectMethods,
+};
+use crate::{
+    object::GcObject,
+    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    value::RcString,
+    Context, Result,
+};
+
+/// The internal methods of `String` objects.
+pub(crate) static STRING_EXOTIC_INTERNAL_METHODS: InternalObjectMethods = InternalObjectMethods {
+    get_prototype_of: ordinary_get_prototype_of,
+    set_prototype_of: ordinary_set_prototype_of,
+    is_extensible: ordinary_is_extensible,
+    prevent_extensions: ordinary_prevent_extensions,
+    get_own_property: string_exotic_get_own_property,
+    define_own_property: string_exotic_define_own_property,
+    has_property: ordinary_has_property,
+    get: ordinary_get,
+    set: ordinary_set,
+    delete: ordinary_delete,
+    own_property_keys: string_exotic_own_property_keys,
+    call: GcObject::call_function,
+    construct: GcObject::construct_function,
+};
+
+/// Returns the string value of a `String` object.
+fn string_data(obj: &GcObject) -> RcString {
+    obj.borrow()
+        .as_string()
+        .expect("string exotic internal methods are only used by string objects")
+}
+
+/// Abstract operation `StringGetOwnProperty ( S, P )`
+///
+/// Returns the property of the code unit at the index `key`, if it is within the string.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-stringgetownproperty
+fn string_get_own_property(obj: &GcObject, key: &PropertyKey) -> Option<PropertyDescriptor> {
+    let index = match key {
+        PropertyKey::Index(index) => *index as usize,
+        _ => return None,
+    };
+
+    let string = string_data(obj);
+    let code_unit = *string.code_units().get(index)?;
+    Some(
+        DataDescriptor::new(
+            RcString::from(vec![code_unit]),
+            Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+        )
+        .into(),
+    )
+}
+
+/// `[[GetOwnProperty]] ( P )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string-exotic-objects-getownproperty-p
+fn string_exotic_get_own_property(
+    obj: &GcObject,
+    key: &PropertyKey,
+    _: &mut Context,
+) -> Result<Option<PropertyDescriptor>> {
+    Ok(obj
+        .ordinary_get_own_property(key)
+        .or_else(|| string_get_own_property(obj, key)))
+}
+
+/// `[[DefineOwnProperty]] ( P, Desc )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string-exotic-objects-defineownproperty-p-desc
+fn string_exotic_define_own_property(
+    obj: &GcObject,
+    key: PropertyKey,
+    desc: PropertyDescriptor,
+    _: &mut Context,
+) -> Result<bool> {
+    match string_get_own_property(obj, &key) {
+        // The code unit properties can only be "redefined" with a compatible descriptor, which
+        // leaves them unchanged.
+        Some(string_desc) => {
+            let extensible = obj.borrow().extensible;
+            Ok(is_compatible_property_descriptor(
+                extensible,
+                &desc,
+                Some(&string_desc),
+            ))
+        }
+        None => Ok(obj.ordinary_define_own_property(key, desc)),
+    }
+}
+
+/// `[[OwnPropertyKeys]] ( )`
+///
+/// The indices of the code units come first, followed by the ordinary keys of the object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-string-exotic-objects-ownpropertykeys
+fn string_exotic_own_property_keys(obj: &GcObject, _: &mut Context) -> Result<Vec<PropertyKey>> {
+    let len = string_data(obj).code_units().len();
+    let mut keys: Vec<PropertyKey> = (0..len as u32).map(PropertyKey::from).collect();
+    keys.extend(
+        obj.ordinary_own_property_keys()
+            .into_iter()
+            .filter(|key| !matches!(key, PropertyKey::Index(index) if (*index as usize) < len)),
+    );
+    Ok(keys)
+}
@@ -3,15 +3,27 @@
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
-        function::{BuiltInFunction, Function, FunctionFlags, NativeFunction},
+        array_buffer::ArrayBuffer,
+        data_view::DataView,
+        finalization_registry::FinalizationRegistry,
+        function::{
+            Arguments, BoundFunction, BuiltInClosure, BuiltInFunction, Captures, Function,
+            FunctionFlags, NativeClosure, NativeFunction, ParameterMap,
+        },
+        generator::Generator,
         map::map_iterator::MapIterator,
-        map::ordered_map::OrderedMap,
+        map::{ephemeron_table::EphemeronTable, ordered_map::OrderedMap},
+        object::for_in_iterator::ForInIterator,
+        promise::Promise,
+        proxy::Proxy,
+        set::{ordered_set::OrderedSet, set_iterator::SetIterator},
         string::string_iterator::StringIterator,
+        typed_array::TypedArray,
         BigInt, Date, RegExp,
     },
     context::StandardConstructor,
     gc::{Finalize, Trace},
-    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     value::{RcBigInt, RcString, RcSymbol, Value},
     BoaProfiler, Context,
 };
@@ -26,7 +38,7 @@ mod gcobject;
 mod internal_methods;
 mod iter;
 
-pub use gcobject::{GcObject, RecursionLimiter, Ref, RefMut};
+pub use gcobject::{GcObject, IntegrityLevel, RecursionLimiter, Ref, RefMut, WeakGcObject};
 pub use iter::*;
 
 /// Static `prototype`, usually set on constructors as a key to point to their respective prototype object.
@@ -61,10 +73,10 @@ pub struct Object {
     /// The type of the object.
     pub data: ObjectData,
     indexed_properties: FxHashMap<u32, PropertyDescriptor>,
-    /// Properties
-    string_properties: FxHashMap<RcString, PropertyDescriptor>,
-    /// Symbol Properties
-    symbol_properties: FxHashMap<RcSymbol, PropertyDescriptor>,
+    /// Properties, in insertion order
+    string_properties: OrderedMap<RcString, PropertyDescriptor>,
+    /// Symbol Properties, in insertion order
+    symbol_properties: OrderedMap<RcSymbol, PropertyDescriptor>,
     /// Instance prototype `__proto__`.
     prototype: Value,
     /// Whether it can have new properties added to it.
@@ -74,14 +86,29 @@ pub struct Object {
 /// Defines the different types of objects.
 #[derive(Debug, Trace, Finalize)]
 pub enum ObjectData {
+    Arguments(Arguments),
     Array,
     ArrayIterator(ArrayIterator),
+    ArrayBuffer(ArrayBuffer),
+    TypedArray(TypedArray),
+    DataView(DataView),
     Map(OrderedMap<Value, Value>),
     MapIterator(MapIterator),
+    Set(OrderedSet<Value>),
+    SetIterator(SetIterator),
+    WeakMap(EphemeronTable<Value>),
+    WeakSet(EphemeronTable<()>),
+    WeakRef(WeakGcObject),
+    FinalizationRegistry(FinalizationRegistry),
+    Proxy(Proxy),
+    ForInIterator(ForInIterator),
     RegExp(Box<RegExp>),
     BigInt(RcBigInt),
     Boolean(bool),
     Function(Function),
+    BoundFunction(BoundFunction),
+    Generator(Generator),
+    Promise(Promise),
     String(RcString),
     StringIterator(StringIterator),
     Number(f64),
@@ -99,12 +126,27 @@ impl Display for ObjectData {
             f,
             "{}",
             match self {
+                Self::Arguments(_) => "Arguments",
                 Self::Array => "Array",
                 Self::ArrayIterator(_) => "ArrayIterator",
+                Self::ArrayBuffer(_) => "ArrayBuffer",
+                Self::TypedArray(_) => "TypedArray",
+                Self::DataView(_) => "DataView",
                 Self::Function(_) => "Function",
+                Self::BoundFunction(_) => "BoundFunction",
+                Self::Generator(_) => "Generator",
+                Self::Promise(_) => "Promise",
                 Self::RegExp(_) => "RegExp",
                 Self::Map(_) => "Map",
                 Self::MapIterator(_) => "MapIterator",
+                Self::Set(_) => "Set",
+                Self::SetIterator(_) => "SetIterator",
+                Self::WeakMap(_) => "WeakMap",
+                Self::WeakSet(_) => "WeakSet",
+                Self::WeakRef(_) => "WeakRef",
+                Self::FinalizationRegistry(_) => "FinalizationRegistry",
+                Self::Proxy(_) => "Proxy",
+                Self::ForInIterator(_) => "ForInIterator",
                 Self::String(_) => "String",
                 Self::StringIterator(_) => "StringIterator",
                 Self::Symbol(_) => "Symbol",
@@ -128,8 +170,8 @@ impl Default for Object {
         Self {
             data: ObjectData::Ordinary,
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -150,8 +192,8 @@ impl Object {
         Self {
             data: ObjectData::Function(function),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype,
             extensible: true,
         }
@@ -177,8 +219,8 @@ impl Object {
         Self {
             data: ObjectData::Boolean(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -190,8 +232,8 @@ impl Object {
         Self {
             data: ObjectData::Number(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -206,8 +248,8 @@ impl Object {
         Self {
             data: ObjectData::String(value.into()),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -219,8 +261,8 @@ impl Object {
         Self {
             data: ObjectData::BigInt(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -235,8 +277,8 @@ impl Object {
         Self {
             data: ObjectData::NativeObject(Box::new(value)),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: OrderedMap::default(),
+            symbol_properties: OrderedMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -250,7 +292,12 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-iscallable
     #[inline]
     pub fn is_callable(&self) -> bool {
-        matches!(self.data, ObjectData::Function(ref f) if f.is_callable())
+        match self.data {
+            ObjectData::Function(ref function) => function.is_callable(),
+            ObjectData::BoundFunction(_) => true,
+            ObjectData::Proxy(ref proxy) => proxy.is_callable(),
+            _ => false,
+        }
     }
 
     /// It determines if Object is a function object with a [[Construct]] internal method.
@@ -261,7 +308,35 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
     #[inline]
     pub fn is_constructable(&self) -> bool {
-        matches!(self.data, ObjectData::Function(ref f) if f.is_constructable())
+        match self.data {
+            ObjectData::Function(ref function) => function.is_constructable(),
+            ObjectData::BoundFunction(ref bound) => bound.target_function().is_constructable(),
+            ObjectData::Proxy(ref proxy) => proxy.is_constructable(),
+            _ => false,
+        }
+    }
+
+    /// Checks if it is an arguments object.
+    #[inline]
+    pub fn is_arguments(&self) -> bool {
+        matches!(self.data, ObjectData::Arguments(_))
+    }
+
+    /// Returns the parameter map of a mapped arguments object.
+    #[inline]
+    pub fn as_mapped_arguments(&self) -> Option<&ParameterMap> {
+        match self.data {
+            ObjectData::Arguments(Arguments::Mapped(ref map)) => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_mapped_arguments_mut(&mut self) -> Option<&mut ParameterMap> {
+        match self.data {
+            ObjectData::Arguments(Arguments::Mapped(ref mut map)) => Some(map),
+            _ => None,
+        }
     }
 
     /// Checks if it an `Array` object.
@@ -300,6 +375,50 @@ impl Object {
         }
     }
 
+    /// Checks if it is a `Generator` object.
+    #[inline]
+    pub fn is_generator(&self) -> bool {
+        matches!(self.data, ObjectData::Generator(_))
+    }
+
+    #[inline]
+    pub fn as_generator(&self) -> Option<&Generator> {
+        match self.data {
+            ObjectData::Generator(ref generator) => Some(generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_generator_mut(&mut self) -> Option<&mut Generator> {
+        match &mut self.data {
+            ObjectData::Generator(generator) => Some(generator),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `Promise` object.
+    #[inline]
+    pub fn is_promise(&self) -> bool {
+        matches!(self.data, ObjectData::Promise(_))
+    }
+
+    #[inline]
+    pub fn as_promise(&self) -> Option<&Promise> {
+        match self.data {
+            ObjectData::Promise(ref promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match &mut self.data {
+            ObjectData::Promise(promise) => Some(promise),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn as_string_iterator_mut(&mut self) -> Option<&mut StringIterator> {
         match &mut self.data {
@@ -338,6 +457,174 @@ impl Object {
         }
     }
 
+    /// Checks if it is a `Set` object.
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        matches!(self.data, ObjectData::Set(_))
+    }
+
+    #[inline]
+    pub fn as_set_ref(&self) -> Option<&OrderedSet<Value>> {
+        match self.data {
+            ObjectData::Set(ref set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_set_mut(&mut self) -> Option<&mut OrderedSet<Value>> {
+        match &mut self.data {
+            ObjectData::Set(set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_set_iterator_mut(&mut self) -> Option<&mut SetIterator> {
+        match &mut self.data {
+            ObjectData::SetIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_map_ref(&self) -> Option<&EphemeronTable<Value>> {
+        match self.data {
+            ObjectData::WeakMap(ref map) => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_map_mut(&mut self) -> Option<&mut EphemeronTable<Value>> {
+        match &mut self.data {
+            ObjectData::WeakMap(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_set_ref(&self) -> Option<&EphemeronTable<()>> {
+        match self.data {
+            ObjectData::WeakSet(ref set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_set_mut(&mut self) -> Option<&mut EphemeronTable<()>> {
+        match &mut self.data {
+            ObjectData::WeakSet(set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_ref(&self) -> Option<&WeakGcObject> {
+        match self.data {
+            ObjectData::WeakRef(ref weak_ref) => Some(weak_ref),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_finalization_registry(&self) -> Option<&FinalizationRegistry> {
+        match self.data {
+            ObjectData::FinalizationRegistry(ref registry) => Some(registry),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_finalization_registry_mut(&mut self) -> Option<&mut FinalizationRegistry> {
+        match &mut self.data {
+            ObjectData::FinalizationRegistry(registry) => Some(registry),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a bound function exotic object.
+    #[inline]
+    pub fn is_bound_function(&self) -> bool {
+        matches!(self.data, ObjectData::BoundFunction(_))
+    }
+
+    #[inline]
+    pub fn as_bound_function(&self) -> Option<&BoundFunction> {
+        match self.data {
+            ObjectData::BoundFunction(ref bound) => Some(bound),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `Proxy` object.
+    #[inline]
+    pub fn is_proxy(&self) -> bool {
+        matches!(self.data, ObjectData::Proxy(_))
+    }
+
+    #[inline]
+    pub fn as_proxy(&self) -> Option<&Proxy> {
+        match self.data {
+            ObjectData::Proxy(ref proxy) => Some(proxy),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_proxy_mut(&mut self) -> Option<&mut Proxy> {
+        match &mut self.data {
+            ObjectData::Proxy(proxy) => Some(proxy),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_array_buffer(&self) -> Option<&ArrayBuffer> {
+        match self.data {
+            ObjectData::ArrayBuffer(ref buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_array_buffer_mut(&mut self) -> Option<&mut ArrayBuffer> {
+        match &mut self.data {
+            ObjectData::ArrayBuffer(buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a typed array, such as an `Uint8Array`.
+    #[inline]
+    pub fn is_typed_array(&self) -> bool {
+        matches!(self.data, ObjectData::TypedArray(_))
+    }
+
+    #[inline]
+    pub fn as_typed_array(&self) -> Option<&TypedArray> {
+        match self.data {
+            ObjectData::TypedArray(ref array) => Some(array),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_data_view(&self) -> Option<&DataView> {
+        match self.data {
+            ObjectData::DataView(ref view) => Some(view),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_for_in_iterator_mut(&mut self) -> Option<&mut ForInIterator> {
+        match &mut self.data {
+            ObjectData::ForInIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
     /// Checks if it a `String` object.
     #[inline]
     pub fn is_string(&self) -> bool {
@@ -352,10 +639,14 @@ impl Object {
         }
     }
 
-    /// Checks if it a `Function` object.
+    /// Checks if it a `Function` object, or a `Proxy` of a callable object.
     #[inline]
     pub fn is_function(&self) -> bool {
-        matches!(self.data, ObjectData::Function(_))
+        match self.data {
+            ObjectData::Function(_) | ObjectData::BoundFunction(_) => true,
+            ObjectData::Proxy(ref proxy) => proxy.is_callable(),
+            _ => false,
+        }
     }
 
     #[inline]
@@ -366,6 +657,14 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_function_mut(&mut self) -> Option<&mut Function> {
+        match self.data {
+            ObjectData::Function(ref mut function) => Some(function),
+            _ => None,
+        }
+    }
+
     /// Checks if it a Symbol object.
     #[inline]
     pub fn is_symbol(&self) -> bool {
@@ -542,9 +841,9 @@ impl Object {
 ///
 /// There are two implementations:
 ///  - From a single type `T` which implements `Into<FunctionBinding>` which sets the binding
-/// name and the function name to the same value
+///    name and the function name to the same value
 ///  - From a tuple `(B: Into<PropertyKey>, N: AsRef<str>)` the `B` is the binding name
-/// and the `N` is the function name.
+///    and the `N` is the function name.
 #[derive(Debug, Clone)]
 pub struct FunctionBinding {
     binding: PropertyKey,
@@ -599,11 +898,18 @@ where
     }
 }
 
+/// The native code of a function object created by a `FunctionBuilder`.
+#[derive(Debug, Clone)]
+enum NativeFunctionBody {
+    Function(BuiltInFunction),
+    Closure(BuiltInClosure, Captures),
+}
+
 /// Builder for creating native function objects
 #[derive(Debug)]
 pub struct FunctionBuilder<'context> {
     context: &'context mut Context,
-    function: BuiltInFunction,
+    function: NativeFunctionBody,
     name: Option<String>,
     length: usize,
     callable: bool,
@@ -616,7 +922,7 @@ impl<'context> FunctionBuilder<'context> {
     pub fn new(context: &'context mut Context, function: NativeFunction) -> Self {
         Self {
             context,
-            function: function.into(),
+            function: NativeFunctionBody::Function(function.into()),
             name: None,
             length: 0,
             callable: true,
@@ -624,6 +930,38 @@ impl<'context> FunctionBuilder<'context> {
         }
     }
 
+    /// Create a new `FunctionBuilder` for a function that captures some state.
+    ///
+    /// The captures are passed to the function every time it is called.
+    #[inline]
+    pub fn closure(
+        context: &'context mut Context,
+        function: NativeClosure,
+        captures: Captures,
+    ) -> Self {
+        Self {
+            context,
+            function: NativeFunctionBody::Closure(function.into(), captures),
+            name: None,
+            length: 0,
+            callable: true,
+            constructable: false,
+        }
+    }
+
+    /// Create the function with the native code and the flags of the builder.
+    fn function(&self) -> Function {
+        let flags = FunctionFlags::from_parameters(self.callable, self.constructable);
+        match self.function.clone() {
+            NativeFunctionBody::Function(function) => Function::BuiltIn(function, flags),
+            NativeFunctionBody::Closure(function, captures) => Function::Closure {
+                function,
+                captures,
+                flags,
+            },
+        }
+    }
+
     /// Specify the name property of object function object.
     ///
     /// The default is `""` (empty string).
@@ -669,10 +1007,7 @@ impl<'context> FunctionBuilder<'context> {
     #[inline]
     pub fn build(&mut self) -> GcObject {
         let mut function = Object::function(
-            Function::BuiltIn(
-                self.function,
-                FunctionFlags::from_parameters(self.callable, self.constructable),
-            ),
+            self.function(),
             self.context
                 .standard_objects()
                 .function_object()
@@ -693,10 +1028,7 @@ impl<'context> FunctionBuilder<'context> {
     /// Initializes the `Function.prototype` function object.
     pub(crate) fn build_function_prototype(&mut self, object: &GcObject) {
         let mut object = object.borrow_mut();
-        object.data = ObjectData::Function(Function::BuiltIn(
-            self.function,
-            FunctionFlags::from_parameters(self.callable, self.constructable),
-        ));
+        object.data = ObjectData::Function(self.function());
         object.set_prototype_instance(
             self.context
                 .standard_objects()
@@ -927,6 +1259,40 @@ impl<'context> ConstructorBuilder<'context> {
         self
     }
 
+    /// Add new accessor property to the constructors prototype.
+    #[inline]
+    pub fn accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<GcObject>,
+        set: Option<GcObject>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+    {
+        let property = AccessorDescriptor::new(get, set, attribute);
+        self.prototype.borrow_mut().insert(key, property);
+        self
+    }
+
+    /// Add new static accessor property to the constructors object itself.
+    #[inline]
+    pub fn static_accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<GcObject>,
+        set: Option<GcObject>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+    {
+        let property = AccessorDescriptor::new(get, set, attribute);
+        self.constructor_object.borrow_mut().insert(key, property);
+        self
+    }
+
     /// Specify how many arguments the constructor function takes.
     ///
     /// Default is `0`.
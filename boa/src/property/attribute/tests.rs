@@ -135,3 +135,15 @@ fn set_configurable_to_false() {
     assert!(!attribute.enumerable());
     assert!(!attribute.configurable());
 }
+
+#[test]
+fn clear_set_flags() {
+    let mut attribute = Attribute::all();
+
+    attribute.set_writable(false);
+    attribute.set_configurable(false);
+
+    assert!(!attribute.writable());
+    assert!(attribute.enumerable());
+    assert!(!attribute.configurable());
+}
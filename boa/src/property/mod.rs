@@ -303,7 +303,7 @@ impl PropertyDescriptor {
 /// - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-ispropertykey
-#[derive(Trace, Finalize, Debug, Clone)]
+#[derive(Trace, Finalize, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PropertyKey {
     String(RcString),
     Symbol(RcSymbol),
@@ -378,13 +378,7 @@ impl From<&PropertyKey> for Value {
         match property_key {
             PropertyKey::String(ref string) => string.clone().into(),
             PropertyKey::Symbol(ref symbol) => symbol.clone().into(),
-            PropertyKey::Index(index) => {
-                if let Ok(integer) = i32::try_from(*index) {
-                    Value::integer(integer)
-                } else {
-                    Value::number(*index)
-                }
-            }
+            PropertyKey::Index(index) => index.to_string().into(),
         }
     }
 }
@@ -395,13 +389,7 @@ impl From<PropertyKey> for Value {
         match property_key {
             PropertyKey::String(ref string) => string.clone().into(),
             PropertyKey::Symbol(ref symbol) => symbol.clone().into(),
-            PropertyKey::Index(index) => {
-                if let Ok(integer) = i32::try_from(index) {
-                    Value::integer(integer)
-                } else {
-                    Value::number(index)
-                }
-            }
+            PropertyKey::Index(index) => index.to_string().into(),
         }
     }
 }
@@ -11,6 +11,7 @@ use crate::{
         lexical_environment::LexicalEnvironment,
         object_environment_record::ObjectEnvironmentRecord,
     },
+    object::GcObject,
     BoaProfiler, Value,
 };
 use gc::{Gc, GcCell};
@@ -24,6 +25,14 @@ pub struct Realm {
     pub global_obj: Value,
     pub global_env: Gc<GcCell<GlobalEnvironmentRecord>>,
     pub environment: LexicalEnvironment,
+    /// The template objects of the tagged templates that were evaluated, by template site.
+    pub template_map: FxHashMap<usize, GcObject>,
+    /// The `%eval%` intrinsic, which evaluates code in the scope of a call made through the
+    /// name `eval`.
+    pub eval_function: GcObject,
+    /// The `%Array.prototype.values%` intrinsic, which is also the `@@iterator` method of the
+    /// arguments objects.
+    pub array_prototype_values: GcObject,
 }
 
 impl Realm {
@@ -43,6 +52,9 @@ impl Realm {
             global_obj: global.clone(),
             global_env,
             environment: LexicalEnvironment::new(global),
+            template_map: FxHashMap::default(),
+            eval_function: GcObject::default(),
+            array_prototype_values: GcObject::default(),
         }
     }
 }
@@ -52,12 +64,13 @@ fn new_global_environment(global: Value, this_value: Value) -> Gc<GcCell<GlobalE
     let obj_rec = ObjectEnvironmentRecord {
         bindings: global,
         outer_env: None,
-        /// Object Environment Records created for with statements (13.11)
-        /// can provide their binding object as an implicit this value for use in function calls.
-        /// The capability is controlled by a withEnvironment Boolean value that is associated
-        /// with each object Environment Record. By default, the value of withEnvironment is false
-        /// for any object Environment Record.
+        // Object Environment Records created for with statements (13.11)
+        // can provide their binding object as an implicit this value for use in function calls.
+        // The capability is controlled by a withEnvironment Boolean value that is associated
+        // with each object Environment Record. By default, the value of withEnvironment is false
+        // for any object Environment Record.
         with_environment: false,
+        unscopables: None,
     };
 
     let dcl_rec = DeclarativeEnvironmentRecord {
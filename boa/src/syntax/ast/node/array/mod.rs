@@ -38,30 +38,46 @@ pub struct ArrayDecl {
 impl Executable for ArrayDecl {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ArrayDecl", "exec");
-        let array = Array::new_array(interpreter)?;
-        let mut elements = Vec::new();
-        for elem in self.as_ref() {
+        // Each element is evaluated at the step of its index, with the elements so far.
+        let (first, mut elements) = match interpreter.resume_state(self) {
+            Some(state) => (state.step, state.into_vec()),
+            None => (0, Vec::new()),
+        };
+        for (i, elem) in self.as_ref().iter().enumerate().skip(first) {
             if let Node::Spread(ref x) = elem {
-                let val = x.run(interpreter)?;
-                let iterator_record = iterable::get_iterator(interpreter, val)?;
-                // TODO after proper internal Array representation as per https://github.com/boa-dev/boa/pull/811#discussion_r502460858
-                // next_index variable should be utilized here as per https://tc39.es/ecma262/#sec-runtime-semantics-arrayaccumulation
-                // let mut next_index = 0;
-                loop {
-                    let next = iterator_record.next(interpreter)?;
-                    if next.is_done() {
-                        break;
-                    }
-                    let next_value = next.value();
-                    //next_index += 1;
-                    elements.push(next_value.clone());
-                }
+                let mut values = interpreter.run_step(
+                    self,
+                    i,
+                    || elements.clone(),
+                    |ctx| {
+                        let val = x.run(ctx)?;
+                        let iterator_record = iterable::get_iterator(ctx, val)?;
+                        // TODO after proper internal Array representation as per https://github.com/boa-dev/boa/pull/811#discussion_r502460858
+                        // next_index variable should be utilized here as per https://tc39.es/ecma262/#sec-runtime-semantics-arrayaccumulation
+                        // let mut next_index = 0;
+                        let mut values = Vec::new();
+                        loop {
+                            let next = iterator_record.next(ctx)?;
+                            if next.is_done() {
+                                break;
+                            }
+                            let next_value = next.value();
+                            //next_index += 1;
+                            values.push(next_value.clone());
+                        }
+                        Ok(values)
+                    },
+                )?;
+                elements.append(&mut values);
             } else {
-                elements.push(elem.run(interpreter)?);
+                let value =
+                    interpreter.run_step(self, i, || elements.clone(), |ctx| elem.run(ctx))?;
+                elements.push(value);
             }
         }
 
-        Array::add_to_array_object(&array, &elements)?;
+        let array = Array::new_array(interpreter)?;
+        Array::add_to_array_object(&array, &elements, interpreter)?;
         Ok(array)
     }
 }
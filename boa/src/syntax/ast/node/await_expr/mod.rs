@@ -1,7 +1,10 @@
 //! Await expression node.
 
 use super::Node;
-use crate::{exec::Executable, BoaProfiler, Context, Result, Value};
+use crate::{
+    exec::{coroutine::ResumeKind, Executable},
+    BoaProfiler, Context, Result, Value,
+};
 use gc::{Finalize, Trace};
 use std::fmt;
 
@@ -24,17 +27,32 @@ pub struct AwaitExpr {
 }
 
 impl Executable for AwaitExpr {
-    fn run(&self, _: &mut Context) -> Result<Value> {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("AwaitExpression", "exec");
-        // TODO: Implement AwaitExpr
-        Ok(Value::Undefined)
+        // The expression is evaluated at step 0, and the function is suspended at step 1.
+        if interpreter
+            .resume_state(self)
+            .is_some_and(|state| state.step > 0)
+        {
+            return match interpreter.resumption() {
+                (ResumeKind::Next, value) => Ok(value),
+                (ResumeKind::Throw, reason) => Err(reason),
+                (ResumeKind::Return, value) => Err(interpreter.return_from_coroutine(value)),
+            };
+        }
+
+        let value = interpreter.run_step(self, 0, Vec::new, |ctx| self.expr.run(ctx))?;
+
+        // The async function that is running resolves the awaited value, and resumes its body
+        // once the resulting promise is settled.
+        Err(interpreter.suspend(self, 1, Vec::new(), value))
     }
 }
 
 impl AwaitExpr {
     /// Implements the display formatting with indentation.
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        writeln!(f, "await ")?;
+        write!(f, "await ")?;
         self.expr.display(f, indentation)
     }
 }
@@ -51,18 +51,30 @@ impl Block {
 impl Executable for Block {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Block", "exec");
-        {
+        // The environment of a resumed block is restored along with its coroutine.
+        let first = if let Some(state) = interpreter.resume_state(self) {
+            state.step
+        } else {
             let env = &mut interpreter.realm_mut().environment;
             env.push(new_declarative_environment(Some(
                 env.get_current_environment_ref().clone(),
             )));
-        }
+            0
+        };
 
         // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
         // The return value is uninitialized, which means it defaults to Value::Undefined
         let mut obj = Value::default();
-        for statement in self.statements() {
-            obj = statement.run(interpreter)?;
+        for (i, statement) in self.statements().iter().enumerate().skip(first) {
+            obj = match interpreter.run_step(self, i, Vec::new, |ctx| statement.run(ctx)) {
+                Ok(value) => value,
+                // A suspended block is not left.
+                Err(error) if interpreter.is_suspending() => return Err(error),
+                Err(error) => {
+                    let _ = interpreter.realm_mut().environment.pop();
+                    return Err(error);
+                }
+            };
 
             match interpreter.executor().get_current_state() {
                 InterpreterState::Return => {
@@ -1,8 +1,9 @@
 use crate::{
+    builtins::{iterable::iterable_to_list, Eval},
     exec::Executable,
     exec::InterpreterState,
-    syntax::ast::node::{join_nodes, Node},
-    value::{Type, Value},
+    syntax::ast::node::{join_nodes, super_node::super_base_and_this, Node},
+    value::Value,
     BoaProfiler, Context, Result,
 };
 use gc::{Finalize, Trace};
@@ -56,39 +57,114 @@ impl Call {
     }
 }
 
+/// Evaluates the callee of a call to `node`, returning the `this` value of the call along with
+/// the function.
+///
+/// The object of a member callee is evaluated at step 0, and a computed key at step 1.
+pub(in crate::syntax::ast::node) fn run_callee<N>(
+    node: &N,
+    callee: &Node,
+    resumed: Option<Value>,
+    interpreter: &mut Context,
+) -> Result<(Value, Value)> {
+    Ok(match callee {
+        Node::GetConstField(ref get_const_field) => {
+            // A primitive is only converted to an object to look up the method, which receives
+            // the primitive itself as `this`.
+            let this =
+                interpreter.run_step(node, 0, Vec::new, |ctx| get_const_field.obj().run(ctx))?;
+            let obj = Value::from(this.to_object(interpreter)?);
+            let func = obj.get_field_with_receiver(get_const_field.field(), &this, interpreter)?;
+            (this, func)
+        }
+        Node::GetField(ref get_field) => {
+            let obj = match resumed {
+                Some(obj) => obj,
+                None => interpreter.run_step(node, 0, Vec::new, |ctx| get_field.obj().run(ctx))?,
+            };
+            let field = interpreter.run_step(
+                node,
+                1,
+                || vec![obj.clone()],
+                |ctx| get_field.field().run(ctx),
+            )?;
+            let key = field.to_property_key(interpreter)?;
+            let func = Value::from(obj.to_object(interpreter)?).get_field_with_receiver(
+                key,
+                &obj,
+                interpreter,
+            )?;
+            (obj, func)
+        }
+        Node::GetSuperConstField(ref get_super_field) => {
+            let (base, this) = super_base_and_this(interpreter)?;
+            let func = base.get_field_with_receiver(get_super_field.field(), &this, interpreter)?;
+            (this, func)
+        }
+        Node::GetSuperField(ref get_super_field) => {
+            let field =
+                interpreter.run_step(node, 0, Vec::new, |ctx| get_super_field.field().run(ctx))?;
+            let (base, this) = super_base_and_this(interpreter)?;
+            let key = field.to_property_key(interpreter)?;
+            let func = base.get_field_with_receiver(key, &this, interpreter)?;
+            (this, func)
+        }
+        // A function that is called through a binding of a `with` statement receives its object
+        // as `this`, any other one gets `undefined`, which non-strict functions replace with the
+        // global object.
+        Node::Identifier(ref name) => (
+            interpreter
+                .realm()
+                .environment
+                .get_with_base_object(name.as_ref())
+                .unwrap_or_else(Value::undefined),
+            interpreter.run_step(node, 0, Vec::new, |ctx| callee.run(ctx))?,
+        ),
+        _ => (
+            Value::undefined(),
+            interpreter.run_step(node, 0, Vec::new, |ctx| callee.run(ctx))?,
+        ),
+    })
+}
+
+/// The step at which the first argument of a call is evaluated, after the callee.
+pub(in crate::syntax::ast::node) const FIRST_ARGUMENT_STEP: usize = 2;
+
 impl Executable for Call {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Call", "exec");
-        let (this, func) = match self.expr() {
-            Node::GetConstField(ref get_const_field) => {
-                let mut obj = get_const_field.obj().run(interpreter)?;
-                if obj.get_type() != Type::Object {
-                    obj = Value::Object(obj.to_object(interpreter)?);
-                }
-                (obj.clone(), obj.get_field(get_const_field.field()))
+        let (this, func, v_args) = match interpreter.resume_state(self) {
+            Some(state) if state.step >= FIRST_ARGUMENT_STEP => {
+                let mut v_args = state.into_vec();
+                let func = v_args.remove(1);
+                let this = v_args.remove(0);
+                (this, func, v_args)
             }
-            Node::GetField(ref get_field) => {
-                let obj = get_field.obj().run(interpreter)?;
-                let field = get_field.field().run(interpreter)?;
-                (
-                    obj.clone(),
-                    obj.get_field(field.to_property_key(interpreter)?),
-                )
+            state => {
+                let resumed = state.filter(|state| state.step == 1).map(|state| {
+                    let [obj] = state.into_values();
+                    obj
+                });
+                let (this, func) = run_callee(self, self.expr(), resumed, interpreter)?;
+                (this, func, Vec::with_capacity(self.args().len()))
             }
-            _ => (
-                interpreter.realm().global_obj.clone(),
-                self.expr().run(interpreter)?,
-            ), // 'this' binding should come from the function's self-contained environment
         };
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            if let Node::Spread(ref x) = arg {
-                let val = x.run(interpreter)?;
-                let mut vals = interpreter.extract_array_properties(&val).unwrap();
-                v_args.append(&mut vals);
-                break; // after spread we don't accept any new arguments
-            }
-            v_args.push(arg.run(interpreter)?);
+        let v_args = run_arguments(
+            self,
+            self.args(),
+            FIRST_ARGUMENT_STEP,
+            &[this.clone(), func.clone()],
+            v_args,
+            interpreter,
+        )?;
+
+        // A call to the `eval` function through its name is a direct eval, which evaluates the
+        // code in the scope of the call.
+        if matches!(self.expr(), Node::Identifier(name) if name.as_ref() == "eval")
+            && Eval::is_eval_function(&func, interpreter)
+        {
+            let x = v_args.into_iter().next().unwrap_or_default();
+            return Eval::perform_eval(x, true, interpreter);
         }
 
         // execute the function call itself
@@ -103,6 +179,35 @@ impl Executable for Call {
     }
 }
 
+/// Evaluates the arguments of a call to `node`, the argument `i` at step `first_step + i`.
+///
+/// `v_args` are the arguments that were evaluated before the node was suspended, if it is
+/// being resumed. The state saved when it is suspended holds `saved`, followed by the arguments
+/// evaluated so far.
+pub(in crate::syntax::ast::node) fn run_arguments<N>(
+    node: &N,
+    args: &[Node],
+    first_step: usize,
+    saved: &[Value],
+    mut v_args: Vec<Value>,
+    interpreter: &mut Context,
+) -> Result<Vec<Value>> {
+    for (i, arg) in args.iter().enumerate().skip(v_args.len()) {
+        let values = || saved.iter().chain(&v_args).cloned().collect();
+        if let Node::Spread(ref x) = arg {
+            let mut vals = interpreter.run_step(node, first_step + i, values, |ctx| {
+                let val = x.run(ctx)?;
+                iterable_to_list(ctx, val)
+            })?;
+            v_args.append(&mut vals);
+            break; // after spread we don't accept any new arguments
+        }
+        let val = interpreter.run_step(node, first_step + i, values, |ctx| arg.run(ctx))?;
+        v_args.push(val);
+    }
+    Ok(v_args)
+}
+
 impl fmt::Display for Call {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}(", self.expr)?;
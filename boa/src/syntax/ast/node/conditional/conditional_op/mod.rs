@@ -57,11 +57,23 @@ impl ConditionalOp {
 
 impl Executable for ConditionalOp {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        Ok(if self.cond().run(interpreter)?.to_boolean() {
-            self.if_true().run(interpreter)?
+        let step = match interpreter.resume_state(self) {
+            Some(state) if state.step > 0 => state.step,
+            _ => {
+                let cond = interpreter.run_step(self, 0, Vec::new, |ctx| self.cond().run(ctx))?;
+                if cond.to_boolean() {
+                    1
+                } else {
+                    2
+                }
+            }
+        };
+
+        if step == 1 {
+            interpreter.run_step(self, 1, Vec::new, |ctx| self.if_true().run(ctx))
         } else {
-            self.if_false().run(interpreter)?
-        })
+            interpreter.run_step(self, 2, Vec::new, |ctx| self.if_false().run(ctx))
+        }
     }
 }
 
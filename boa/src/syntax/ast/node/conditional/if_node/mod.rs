@@ -76,10 +76,22 @@ impl If {
 
 impl Executable for If {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        Ok(if self.cond().run(interpreter)?.to_boolean() {
-            self.body().run(interpreter)?
-        } else if let Some(ref else_e) = self.else_node() {
-            else_e.run(interpreter)?
+        let step = match interpreter.resume_state(self) {
+            Some(state) if state.step > 0 => state.step,
+            _ => {
+                let cond = interpreter.run_step(self, 0, Vec::new, |ctx| self.cond().run(ctx))?;
+                if cond.to_boolean() {
+                    1
+                } else {
+                    2
+                }
+            }
+        };
+
+        Ok(if step == 1 {
+            interpreter.run_step(self, 1, Vec::new, |ctx| self.body().run(ctx))?
+        } else if let Some(else_e) = self.else_node() {
+            interpreter.run_step(self, 2, Vec::new, |ctx| else_e.run(ctx))?
         } else {
             Value::undefined()
         })
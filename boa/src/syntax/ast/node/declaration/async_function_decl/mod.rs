@@ -1,7 +1,10 @@
 //! Async Function Declaration.
 
 use crate::{
+    builtins::function::FunctionFlags,
+    environment::lexical_environment::VariableScope,
     exec::Executable,
+    property::{Attribute, DataDescriptor},
     syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
     BoaProfiler, Context, Result, Value,
 };
@@ -81,9 +84,29 @@ impl AsyncFunctionDecl {
 }
 
 impl Executable for AsyncFunctionDecl {
-    fn run(&self, _: &mut Context) -> Result<Value> {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("AsyncFunctionDecl", "exec");
-        // TODO: Implement AsyncFunctionDecl
+        let val = interpreter.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+        );
+
+        // Set the name and assign it in the current environment
+        if let Some(name) = self.name() {
+            val.set_property("name", DataDescriptor::new(name, Attribute::CONFIGURABLE));
+            interpreter.realm_mut().environment.create_mutable_binding(
+                name.to_owned(),
+                false,
+                VariableScope::Function,
+            );
+
+            interpreter
+                .realm_mut()
+                .environment
+                .initialize_binding(name, val);
+        }
+
         Ok(Value::undefined())
     }
 }
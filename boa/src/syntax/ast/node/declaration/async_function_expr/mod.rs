@@ -1,7 +1,9 @@
 //! Async Function Expression.
 
 use crate::{
+    builtins::function::FunctionFlags,
     exec::Executable,
+    property::{Attribute, DataDescriptor},
     syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
     Context, Result, Value,
 };
@@ -64,7 +66,7 @@ impl AsyncFunctionExpr {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
-        f.write_str("function")?;
+        f.write_str("async function")?;
         if let Some(ref name) = self.name {
             write!(f, " {}", name)?;
         }
@@ -79,9 +81,21 @@ impl AsyncFunctionExpr {
 }
 
 impl Executable for AsyncFunctionExpr {
-    fn run(&self, _: &mut Context) -> Result<Value> {
-        // TODO: Implement AsyncFunctionExpr
-        Ok(Value::Undefined)
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let val = interpreter.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+        );
+
+        if let Some(name) = self.name() {
+            val.set_property(
+                "name",
+                DataDescriptor::new(Value::from(name), Attribute::CONFIGURABLE),
+            );
+        }
+
+        Ok(val)
     }
 }
 
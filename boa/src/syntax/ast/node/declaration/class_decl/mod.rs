@@ -0,0 +1,512 @@
+use crate::{
+    builtins::function::{Function, FunctionFlags},
+    environment::lexical_environment::VariableScope,
+    exec::Executable,
+    object::{GcObject, Object, PROTOTYPE},
+    property::{AccessorDescriptor, Attribute, PropertyDescriptor, PropertyKey},
+    syntax::ast::node::{
+        join_nodes, FormalParameter, FunctionExpr, Identifier, MethodDefinitionKind, Node,
+        PropertyName, RcStatementList, Spread, StatementList, SuperCall,
+    },
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The `class` declaration creates a new class with a given name using prototype-based
+/// inheritance.
+///
+/// A class declaration is not hoisted, and the class name is bound in the enclosing block
+/// in the same way as a `let` declaration.
+///
+/// A class can also be defined using a [class expression][class_expr].
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+/// [class_expr]: ../class_expr/struct.ClassExpr.html
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassDecl {
+    name: Box<str>,
+    super_ref: Option<Box<Node>>,
+    constructor: Option<FunctionExpr>,
+    elements: Box<[ClassElement]>,
+}
+
+impl ClassDecl {
+    /// Creates a new class declaration.
+    pub(in crate::syntax) fn new<N, S, C, E>(
+        name: N,
+        super_ref: S,
+        constructor: C,
+        elements: E,
+    ) -> Self
+    where
+        N: Into<Box<str>>,
+        S: Into<Option<Node>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        Self {
+            name: name.into(),
+            super_ref: super_ref.into().map(Box::new),
+            constructor: constructor.into(),
+            elements: elements.into(),
+        }
+    }
+
+    /// Gets the name of the class.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the expression of the `extends` clause, if any.
+    pub fn super_ref(&self) -> Option<&Node> {
+        self.super_ref.as_deref()
+    }
+
+    /// Gets the explicit constructor of the class, if any.
+    pub fn constructor(&self) -> Option<&FunctionExpr> {
+        self.constructor.as_ref()
+    }
+
+    /// Gets the methods, getters and setters of the class.
+    pub fn elements(&self) -> &[ClassElement] {
+        &self.elements
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        display_class(
+            f,
+            indentation,
+            Some(self.name()),
+            self.super_ref(),
+            self.constructor(),
+            self.elements(),
+        )
+    }
+}
+
+impl Executable for ClassDecl {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ClassDecl", "exec");
+        let class = create_class(
+            self,
+            interpreter,
+            self.name(),
+            self.super_ref(),
+            self.constructor(),
+            self.elements(),
+        )?;
+
+        let environment = &mut interpreter.realm_mut().environment;
+        environment.create_mutable_binding(self.name().to_owned(), false, VariableScope::Block);
+        environment.initialize_binding(self.name(), class);
+
+        Ok(Value::undefined())
+    }
+}
+
+impl fmt::Display for ClassDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ClassDecl> for Node {
+    fn from(decl: ClassDecl) -> Self {
+        Self::ClassDecl(decl)
+    }
+}
+
+/// A method, getter or setter defined in the body of a class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes#Class_body_and_method_definitions
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassElement {
+    kind: MethodDefinitionKind,
+    name: PropertyName,
+    function: FunctionExpr,
+    is_static: bool,
+}
+
+impl ClassElement {
+    /// Creates a new class element.
+    pub(in crate::syntax) fn new<N>(
+        kind: MethodDefinitionKind,
+        name: N,
+        function: FunctionExpr,
+        is_static: bool,
+    ) -> Self
+    where
+        N: Into<PropertyName>,
+    {
+        Self {
+            kind,
+            name: name.into(),
+            function,
+            is_static,
+        }
+    }
+
+    /// Gets the kind of method this element defines.
+    pub fn kind(&self) -> MethodDefinitionKind {
+        self.kind
+    }
+
+    /// Gets the name of the element.
+    pub fn name(&self) -> &PropertyName {
+        &self.name
+    }
+
+    /// Gets the function that implements the element.
+    pub fn function(&self) -> &FunctionExpr {
+        &self.function
+    }
+
+    /// Returns `true` if the element is defined on the constructor instead of the prototype.
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+}
+
+/// Implements the display formatting of class declarations and expressions.
+pub(in crate::syntax::ast::node) fn display_class(
+    f: &mut fmt::Formatter<'_>,
+    indentation: usize,
+    name: Option<&str>,
+    super_ref: Option<&Node>,
+    constructor: Option<&FunctionExpr>,
+    elements: &[ClassElement],
+) -> fmt::Result {
+    f.write_str("class")?;
+    if let Some(name) = name {
+        write!(f, " {}", name)?;
+    }
+    if let Some(super_ref) = super_ref {
+        write!(f, " extends {}", super_ref)?;
+    }
+    writeln!(f, " {{")?;
+
+    if let Some(constructor) = constructor {
+        display_method(f, indentation + 1, "", "constructor", constructor)?;
+    }
+    for element in elements {
+        let prefix = match (element.is_static(), element.kind()) {
+            (false, MethodDefinitionKind::Ordinary) => "",
+            (false, MethodDefinitionKind::Get) => "get ",
+            (false, MethodDefinitionKind::Set) => "set ",
+            (true, MethodDefinitionKind::Ordinary) => "static ",
+            (true, MethodDefinitionKind::Get) => "static get ",
+            (true, MethodDefinitionKind::Set) => "static set ",
+            (false, MethodDefinitionKind::Generator) => "*",
+            (true, MethodDefinitionKind::Generator) => "static *",
+        };
+        display_method(
+            f,
+            indentation + 1,
+            prefix,
+            element.name(),
+            element.function(),
+        )?;
+    }
+
+    write!(f, "{}}}", "    ".repeat(indentation))
+}
+
+/// Implements the display formatting of a single method of a class.
+fn display_method<N>(
+    f: &mut fmt::Formatter<'_>,
+    indentation: usize,
+    prefix: &str,
+    name: N,
+    function: &FunctionExpr,
+) -> fmt::Result
+where
+    N: fmt::Display,
+{
+    let indent = "    ".repeat(indentation);
+    write!(f, "{}{}{}(", indent, prefix, name)?;
+    join_nodes(f, function.parameters())?;
+    writeln!(f, ") {{")?;
+    for node in function.body() {
+        node.display(f, indentation + 1)?;
+        writeln!(f, ";")?;
+    }
+    writeln!(f, "{}}}", indent)
+}
+
+/// Runs the class definition evaluation, creating the constructor function of the class and
+/// defining all its methods.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+///
+/// The class heritage of `node` is evaluated at step 0, and the computed key of the element `i`
+/// at step `1 + i`.
+pub(in crate::syntax::ast::node) fn create_class<N>(
+    node: &N,
+    interpreter: &mut Context,
+    name: &str,
+    super_ref: Option<&Node>,
+    constructor: Option<&FunctionExpr>,
+    elements: &[ClassElement],
+) -> Result<Value> {
+    // All the parts of a class are strict mode code.
+    interpreter.run_with_strict_mode(true, |interpreter| {
+        class_definition_evaluation(node, interpreter, name, super_ref, constructor, elements)
+    })
+}
+
+/// Creates the class of a class declaration or expression, as strict mode code.
+fn class_definition_evaluation<N>(
+    node: &N,
+    interpreter: &mut Context,
+    name: &str,
+    super_ref: Option<&Node>,
+    constructor: Option<&FunctionExpr>,
+    elements: &[ClassElement],
+) -> Result<Value> {
+    let function_prototype: Value = interpreter
+        .standard_objects()
+        .function_object()
+        .prototype()
+        .into();
+    let (first, class, proto) = match interpreter.resume_state(node) {
+        Some(state) if state.step > 0 => {
+            let step = state.step;
+            let [class, proto] = state.into_values();
+            (
+                step - 1,
+                class.as_object().expect("the class was not saved"),
+                proto
+                    .as_object()
+                    .expect("the class prototype was not saved"),
+            )
+        }
+        _ => {
+            let object_prototype: Value = interpreter
+                .standard_objects()
+                .object_object()
+                .prototype()
+                .into();
+
+            let (proto_parent, constructor_parent) = match super_ref {
+                None => (object_prototype, function_prototype.clone()),
+                Some(super_ref) => {
+                    let superclass =
+                        interpreter.run_step(node, 0, Vec::new, |ctx| super_ref.run(ctx))?;
+                    match superclass {
+                        Value::Null => (Value::null(), function_prototype.clone()),
+                        Value::Object(ref object) if object.is_constructable() => {
+                            let proto_parent =
+                                object.get(&PROTOTYPE.into(), superclass.clone(), interpreter)?;
+                            if !proto_parent.is_object() && !proto_parent.is_null() {
+                                return interpreter.throw_type_error(
+                                    "class extends value does not have a valid prototype property",
+                                );
+                            }
+                            (proto_parent, superclass.clone())
+                        }
+                        _ => {
+                            return interpreter.throw_type_error(format!(
+                                "class extends value {} is not a constructor or null",
+                                superclass.display()
+                            ))
+                        }
+                    }
+                }
+            };
+
+            let mut proto = GcObject::new(Object::create(proto_parent));
+
+            let mut flags = FunctionFlags::CALLABLE
+                | FunctionFlags::CONSTRUCTABLE
+                | FunctionFlags::CLASS_CONSTRUCTOR;
+            if super_ref.is_some() {
+                flags |= FunctionFlags::DERIVED_CONSTRUCTOR;
+            }
+
+            // If the class has no explicit constructor, a default one is used:
+            // `constructor() {}` for base classes, and
+            // `constructor(...args) { super(...args); }` for derived classes.
+            let (params, body): (Box<[FormalParameter]>, StatementList) = match constructor {
+                Some(constructor) => (
+                    constructor.parameters().into(),
+                    constructor.statement_list().clone(),
+                ),
+                None if super_ref.is_some() => (
+                    vec![FormalParameter::new("args", None, true)].into(),
+                    vec![Node::from(SuperCall::new(vec![Node::from(Spread::new(
+                        Identifier::from("args"),
+                    ))]))]
+                    .into(),
+                ),
+                None => (Box::default(), Vec::new().into()),
+            };
+            let mut class =
+                make_method(interpreter, params, body, flags, &proto, constructor_parent);
+            let length = constructor.map_or(0, |constructor| {
+                expected_argument_count(constructor.parameters())
+            });
+            class.insert_property("length", length, Attribute::CONFIGURABLE);
+            class.insert_property("name", name, Attribute::CONFIGURABLE);
+            class.insert_property(PROTOTYPE, proto.clone(), Attribute::PERMANENT);
+            proto.insert_property("constructor", class.clone(), Attribute::NON_ENUMERABLE);
+
+            (0, class, proto)
+        }
+    };
+
+    for (i, element) in elements.iter().enumerate().skip(first) {
+        let mut target = if element.is_static() {
+            class.clone()
+        } else {
+            proto.clone()
+        };
+        let key = match element.name() {
+            PropertyName::Literal(name) => PropertyKey::from(name.clone()),
+            PropertyName::Computed(key) => interpreter
+                .run_step(
+                    node,
+                    1 + i,
+                    || vec![class.clone().into(), proto.clone().into()],
+                    |ctx| key.run(ctx),
+                )?
+                .to_property_key(interpreter)?,
+        };
+        let function = element.function();
+        let (flags, method_prototype) = if element.kind() == MethodDefinitionKind::Generator {
+            let generator_function = interpreter.iterator_prototypes().generator_function();
+            (
+                FunctionFlags::CALLABLE | FunctionFlags::GENERATOR,
+                generator_function.into(),
+            )
+        } else {
+            (FunctionFlags::CALLABLE, function_prototype.clone())
+        };
+        let mut method = make_method(
+            interpreter,
+            function.parameters().into(),
+            function.statement_list().clone(),
+            flags,
+            &target,
+            method_prototype,
+        );
+        method.insert_property(
+            "length",
+            expected_argument_count(function.parameters()),
+            Attribute::CONFIGURABLE,
+        );
+
+        match element.kind() {
+            MethodDefinitionKind::Ordinary => {
+                method.insert_property("name", key.to_string(), Attribute::CONFIGURABLE);
+                target.insert_property(key, method, Attribute::NON_ENUMERABLE);
+            }
+            MethodDefinitionKind::Generator => {
+                let generator = interpreter.iterator_prototypes().generator();
+                method.insert_property(
+                    PROTOTYPE,
+                    GcObject::new(Object::create(generator.into())),
+                    Attribute::WRITABLE,
+                );
+                method.insert_property("name", key.to_string(), Attribute::CONFIGURABLE);
+                target.insert_property(key, method, Attribute::NON_ENUMERABLE);
+            }
+            MethodDefinitionKind::Get => {
+                method.insert_property("name", format!("get {}", key), Attribute::CONFIGURABLE);
+                let setter = match target.ordinary_get_own_property(&key) {
+                    Some(PropertyDescriptor::Accessor(ref accessor)) => accessor.setter().cloned(),
+                    _ => None,
+                };
+                target.insert(
+                    key,
+                    AccessorDescriptor::new(Some(method), setter, Attribute::CONFIGURABLE),
+                );
+            }
+            MethodDefinitionKind::Set => {
+                method.insert_property("name", format!("set {}", key), Attribute::CONFIGURABLE);
+                let getter = match target.ordinary_get_own_property(&key) {
+                    Some(PropertyDescriptor::Accessor(ref accessor)) => accessor.getter().cloned(),
+                    _ => None,
+                };
+                target.insert(
+                    key,
+                    AccessorDescriptor::new(getter, Some(method), Attribute::CONFIGURABLE),
+                );
+            }
+        }
+    }
+
+    Ok(class.into())
+}
+
+/// Returns the `length` of a class constructor or method, which is the number of parameters
+/// before the first one with a default value or the rest parameter.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-expectedargumentcount
+fn expected_argument_count(params: &[FormalParameter]) -> usize {
+    params
+        .iter()
+        .take_while(|param| param.init().is_none() && !param.is_rest_param())
+        .count()
+}
+
+/// Creates an ordinary function object for a class method, with `home_object` as the
+/// `[[HomeObject]]` used to resolve `super` property accesses. The method is strict, as is the
+/// class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makemethod
+fn make_method(
+    interpreter: &mut Context,
+    params: Box<[FormalParameter]>,
+    body: StatementList,
+    flags: FunctionFlags,
+    home_object: &GcObject,
+    prototype: Value,
+) -> GcObject {
+    let function = Function::Ordinary {
+        flags: flags | FunctionFlags::STRICT,
+        body: RcStatementList::from(body),
+        params: params.into(),
+        environment: interpreter
+            .realm_mut()
+            .environment
+            .get_current_environment()
+            .clone(),
+        home_object: Some(home_object.clone()),
+        source_text: None,
+    };
+
+    GcObject::new(Object::function(function, prototype))
+}
@@ -0,0 +1,248 @@
+use crate::{exec, forward, Context};
+
+#[test]
+fn class_declaration() {
+    let scenario = r#"
+        class Point {
+            constructor(x, y) {
+                this.x = x;
+                this.y = y;
+            }
+
+            sum() {
+                return this.x + this.y;
+            }
+        }
+
+        let p = new Point(1, 2);
+        p.sum();
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn class_without_constructor() {
+    let scenario = r#"
+        class A {
+            foo() {
+                return "foo";
+            }
+        }
+
+        new A().foo();
+    "#;
+    assert_eq!(&exec(scenario), "\"foo\"");
+}
+
+#[test]
+fn class_static_methods() {
+    let mut engine = Context::new();
+    let init = r#"
+        class A {
+            static create() {
+                return new A();
+            }
+        }
+    "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "A.create() instanceof A"), "true");
+    assert_eq!(forward(&mut engine, "new A().create"), "undefined");
+}
+
+#[test]
+fn class_getters_and_setters() {
+    let scenario = r#"
+        class Temperature {
+            constructor() {
+                this.celsius = 0;
+            }
+
+            get fahrenheit() {
+                return this.celsius * 1.8 + 32;
+            }
+
+            set fahrenheit(value) {
+                this.celsius = (value - 32) / 1.8;
+            }
+        }
+
+        let t = new Temperature();
+        t.fahrenheit = 212;
+        t.celsius + t.fahrenheit;
+    "#;
+    assert_eq!(&exec(scenario), "312");
+}
+
+#[test]
+fn class_methods_are_not_enumerable() {
+    let scenario = r#"
+        class A {
+            foo() {}
+        }
+
+        A.prototype.propertyIsEnumerable("foo");
+    "#;
+    assert_eq!(&exec(scenario), "false");
+}
+
+#[test]
+fn class_extends() {
+    let scenario = r#"
+        class Animal {
+            constructor(name) {
+                this.name = name;
+            }
+
+            speak() {
+                return this.name + " makes a noise";
+            }
+        }
+
+        class Dog extends Animal {
+            constructor(name) {
+                super(name);
+            }
+
+            speak() {
+                return super.speak() + ", woof";
+            }
+        }
+
+        new Dog("Rex").speak();
+    "#;
+    assert_eq!(&exec(scenario), "\"Rex makes a noise, woof\"");
+}
+
+#[test]
+fn class_default_derived_constructor() {
+    let mut engine = Context::new();
+    let init = r#"
+        class A {
+            constructor(a, b) {
+                this.sum = a + b;
+            }
+        }
+
+        class B extends A {}
+
+        let b = new B(1, 2);
+    "#;
+    forward(&mut engine, init);
+    assert_eq!(forward(&mut engine, "b.sum"), "3");
+    assert_eq!(forward(&mut engine, "b instanceof A"), "true");
+    assert_eq!(forward(&mut engine, "b instanceof B"), "true");
+}
+
+#[test]
+fn class_static_inheritance() {
+    let scenario = r#"
+        class A {
+            static foo() {
+                return "foo";
+            }
+        }
+
+        class B extends A {
+            static bar() {
+                return super.foo() + "bar";
+            }
+        }
+
+        B.bar();
+    "#;
+    assert_eq!(&exec(scenario), "\"foobar\"");
+}
+
+#[test]
+fn class_expression() {
+    let scenario = r#"
+        let A = class {
+            foo() {
+                return 1;
+            }
+        };
+
+        new A().foo();
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn class_call_without_new() {
+    let scenario = r#"
+        class A {}
+        try {
+            A();
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn class_this_before_super() {
+    let scenario = r#"
+        class A {}
+        class B extends A {
+            constructor() {
+                this.a = 1;
+                super();
+            }
+        }
+
+        try {
+            new B();
+        } catch (e) {
+            e instanceof ReferenceError;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn class_invalid_heritage() {
+    let scenario = r#"
+        try {
+            class A extends 5 {}
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn class_extends_null() {
+    let scenario = r#"
+        class A extends null {}
+        Object.getPrototypeOf(A.prototype) === null;
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn class_method_lengths() {
+    let scenario = r#"
+        class A {
+            constructor(a, b = 1) {}
+            method(a, b, c = 2, d) {}
+            rest(a, ...b) {}
+            *generator(a = 0) {}
+            get value() {}
+            set value(v = 1) {}
+            static fixed(a, b) {}
+        }
+        var accessor = Object.getOwnPropertyDescriptor(A.prototype, 'value');
+        [
+            A.length,
+            A.prototype.method.length,
+            A.prototype.rest.length,
+            A.prototype.generator.length,
+            accessor.get.length,
+            accessor.set.length,
+            A.fixed.length,
+        ].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"1,2,1,0,0,0,2\"");
+}
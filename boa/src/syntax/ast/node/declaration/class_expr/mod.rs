@@ -0,0 +1,118 @@
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{
+        declaration::class_decl::{create_class, display_class},
+        ClassElement, FunctionExpr, Node,
+    },
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `class` expression is one way to define a class.
+///
+/// A class expression can be named or unnamed. The name given to a named class expression is
+/// local to the class's body.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassExpr {
+    name: Option<Box<str>>,
+    super_ref: Option<Box<Node>>,
+    constructor: Option<FunctionExpr>,
+    elements: Box<[ClassElement]>,
+}
+
+impl ClassExpr {
+    /// Creates a new class expression.
+    pub(in crate::syntax) fn new<N, S, C, E>(
+        name: N,
+        super_ref: S,
+        constructor: C,
+        elements: E,
+    ) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        S: Into<Option<Node>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        Self {
+            name: name.into(),
+            super_ref: super_ref.into().map(Box::new),
+            constructor: constructor.into(),
+            elements: elements.into(),
+        }
+    }
+
+    /// Gets the name of the class, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(Box::as_ref)
+    }
+
+    /// Gets the expression of the `extends` clause, if any.
+    pub fn super_ref(&self) -> Option<&Node> {
+        self.super_ref.as_deref()
+    }
+
+    /// Gets the explicit constructor of the class, if any.
+    pub fn constructor(&self) -> Option<&FunctionExpr> {
+        self.constructor.as_ref()
+    }
+
+    /// Gets the methods, getters and setters of the class.
+    pub fn elements(&self) -> &[ClassElement] {
+        &self.elements
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        display_class(
+            f,
+            indentation,
+            self.name(),
+            self.super_ref(),
+            self.constructor(),
+            self.elements(),
+        )
+    }
+}
+
+impl Executable for ClassExpr {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ClassExpr", "exec");
+        create_class(
+            self,
+            interpreter,
+            self.name().unwrap_or(""),
+            self.super_ref(),
+            self.constructor(),
+            self.elements(),
+        )
+    }
+}
+
+impl fmt::Display for ClassExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ClassExpr> for Node {
+    fn from(expr: ClassExpr) -> Self {
+        Self::ClassExpr(expr)
+    }
+}
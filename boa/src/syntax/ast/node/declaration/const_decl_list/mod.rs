@@ -1,7 +1,10 @@
 use crate::{
-    environment::lexical_environment::VariableScope,
     exec::Executable,
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    syntax::ast::node::{
+        join_nodes,
+        pattern::{BindingKind, Target},
+        Node,
+    },
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -37,22 +40,35 @@ pub struct ConstDeclList {
 
 impl Executable for ConstDeclList {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        for decl in self.as_ref() {
-            let val = if let Some(init) = decl.init() {
-                init.run(interpreter)?
-            } else {
-                return interpreter.throw_syntax_error("missing = in const declaration");
+        // The initializer of each declaration is evaluated at step `2 * i`, and its target is
+        // bound to the value at step `2 * i + 1`.
+        let (first, mut resumed_value) = match interpreter.resume_state(self) {
+            Some(state) if state.step % 2 == 1 => {
+                let step = state.step;
+                let [value] = state.into_values();
+                (step, Some(value))
+            }
+            Some(state) => (state.step, None),
+            None => (0, None),
+        };
+        for (i, decl) in self.as_ref().iter().enumerate().skip(first / 2) {
+            let val = match resumed_value.take() {
+                Some(val) => val,
+                None => match decl.init() {
+                    Some(init) => {
+                        interpreter.run_step(self, 2 * i, Vec::new, |ctx| init.run(ctx))?
+                    }
+                    None => {
+                        return interpreter.throw_syntax_error("missing = in const declaration")
+                    }
+                },
             };
-
-            interpreter
-                .realm_mut()
-                .environment
-                .create_immutable_binding(decl.name().to_owned(), false, VariableScope::Block);
-
-            interpreter
-                .realm_mut()
-                .environment
-                .initialize_binding(decl.name(), val);
+            interpreter.run_step(
+                self,
+                2 * i + 1,
+                || vec![val.clone()],
+                |ctx| decl.target().bind(val.clone(), BindingKind::Const, ctx),
+            )?;
         }
         Ok(Value::undefined())
     }
@@ -102,13 +118,13 @@ impl From<ConstDeclList> for Node {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ConstDecl {
-    name: Identifier,
+    target: Target,
     init: Option<Node>,
 }
 
 impl fmt::Display for ConstDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
+        fmt::Display::fmt(&self.target, f)?;
         if let Some(ref init) = self.init {
             write!(f, " = {}", init)?;
         }
@@ -118,20 +134,20 @@ impl fmt::Display for ConstDecl {
 
 impl ConstDecl {
     /// Creates a new variable declaration.
-    pub(in crate::syntax) fn new<N, I>(name: N, init: Option<I>) -> Self
+    pub(in crate::syntax) fn new<T, I>(target: T, init: Option<I>) -> Self
     where
-        N: Into<Identifier>,
+        T: Into<Target>,
         I: Into<Node>,
     {
         Self {
-            name: name.into(),
+            target: target.into(),
             init: init.map(|n| n.into()),
         }
     }
 
-    /// Gets the name of the variable.
-    pub fn name(&self) -> &str {
-        self.name.as_ref()
+    /// Gets the target the variable is bound to.
+    pub fn target(&self) -> &Target {
+        &self.target
     }
 
     /// Gets the initialization node for the variable, if any.
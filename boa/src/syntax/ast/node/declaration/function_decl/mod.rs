@@ -1,7 +1,7 @@
 use crate::{
     builtins::function::FunctionFlags,
-    environment::lexical_environment::VariableScope,
     exec::Executable,
+    property::{Attribute, DataDescriptor},
     syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
     BoaProfiler, Context, Result, Value,
 };
@@ -30,11 +30,13 @@ use serde::{Deserialize, Serialize};
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function
 /// [func_expr]: ../enum.Node.html#variant.FunctionExpr
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct FunctionDecl {
     name: Box<str>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    /// The source text of the function, which is returned by `Function.prototype.toString()`.
+    source_text: Option<Box<str>>,
 }
 
 impl FunctionDecl {
@@ -49,9 +51,19 @@ impl FunctionDecl {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source_text: None,
         }
     }
 
+    /// Sets the source text of the function declaration.
+    pub(in crate::syntax) fn with_source_text<S>(mut self, source_text: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        self.source_text = Some(source_text.into());
+        self
+    }
+
     /// Gets the name of the function declaration.
     pub fn name(&self) -> &str {
         &self.name
@@ -67,6 +79,16 @@ impl FunctionDecl {
         self.body.statements()
     }
 
+    /// Gets the statement list of the body, which knows whether it is strict mode code.
+    pub(crate) fn statement_list(&self) -> &StatementList {
+        &self.body
+    }
+
+    /// Gets the source text of the function declaration, if it was parsed from source code.
+    pub fn source_text(&self) -> Option<&str> {
+        self.source_text.as_deref()
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -88,22 +110,39 @@ impl Executable for FunctionDecl {
         let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
         let val = interpreter.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
         );
 
+        if let Some(source_text) = self.source_text() {
+            if let Some(function) = val
+                .as_object()
+                .expect("functions are objects")
+                .borrow_mut()
+                .as_function_mut()
+            {
+                function.set_source_text(source_text.into());
+            }
+        }
+
         // Set the name and assign it in the current environment
-        val.set_field("name", self.name());
-        interpreter.realm_mut().environment.create_mutable_binding(
-            self.name().to_owned(),
-            false,
-            VariableScope::Function,
+        val.set_property(
+            "name",
+            DataDescriptor::new(self.name(), Attribute::CONFIGURABLE),
         );
-
-        interpreter
-            .realm_mut()
-            .environment
-            .initialize_binding(self.name(), val);
+        // A function that is declared again, for instance by running the same code with `eval`
+        // twice, replaces the previous one.
+        let environment = interpreter.realm().environment.get_var_environment();
+        if environment.borrow().has_binding(self.name()) {
+            environment
+                .borrow_mut()
+                .set_mutable_binding(self.name(), val, false)
+                .map_err(|error| interpreter.construct_type_error(error.to_string()))?;
+        } else {
+            let mut environment = environment.borrow_mut();
+            environment.create_mutable_binding(self.name().to_owned(), false);
+            environment.initialize_binding(self.name(), val);
+        }
 
         Ok(Value::undefined())
     }
@@ -115,6 +154,14 @@ impl From<FunctionDecl> for Node {
     }
 }
 
+// The source text is left out of the comparison, so that parsed functions compare equal to the
+// functions built by hand.
+impl PartialEq for FunctionDecl {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.parameters == other.parameters && self.body == other.body
+    }
+}
+
 impl fmt::Display for FunctionDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -1,6 +1,7 @@
 use crate::{
     builtins::function::FunctionFlags,
     exec::Executable,
+    property::{Attribute, DataDescriptor},
     syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
     Context, Result, Value,
 };
@@ -27,11 +28,13 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#sec-terms-and-definitions-function
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct FunctionExpr {
     name: Option<Box<str>>,
     parameters: Box<[FormalParameter]>,
     body: StatementList,
+    /// The source text of the function, which is returned by `Function.prototype.toString()`.
+    source_text: Option<Box<str>>,
 }
 
 impl FunctionExpr {
@@ -46,9 +49,19 @@ impl FunctionExpr {
             name: name.into(),
             parameters: parameters.into(),
             body: body.into(),
+            source_text: None,
         }
     }
 
+    /// Sets the source text of the function expression.
+    pub(in crate::syntax) fn with_source_text<S>(mut self, source_text: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        self.source_text = Some(source_text.into());
+        self
+    }
+
     /// Gets the name of the function declaration.
     pub fn name(&self) -> Option<&str> {
         self.name.as_ref().map(Box::as_ref)
@@ -64,6 +77,16 @@ impl FunctionExpr {
         self.body.statements()
     }
 
+    /// Gets the statement list of the body, which knows whether it is strict mode code.
+    pub(crate) fn statement_list(&self) -> &StatementList {
+        &self.body
+    }
+
+    /// Gets the source text of the function expression, if it was parsed from source code.
+    pub fn source_text(&self) -> Option<&str> {
+        self.source_text.as_deref()
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -88,18 +111,39 @@ impl Executable for FunctionExpr {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let val = interpreter.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
         );
 
+        if let Some(source_text) = self.source_text() {
+            if let Some(function) = val
+                .as_object()
+                .expect("functions are objects")
+                .borrow_mut()
+                .as_function_mut()
+            {
+                function.set_source_text(source_text.into());
+            }
+        }
+
         if let Some(name) = self.name() {
-            val.set_field("name", Value::from(name));
+            val.set_property(
+                "name",
+                DataDescriptor::new(Value::from(name), Attribute::CONFIGURABLE),
+            );
         }
 
         Ok(val)
     }
 }
 
+// Like for `FunctionDecl`, the source text is not compared.
+impl PartialEq for FunctionExpr {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.parameters == other.parameters && self.body == other.body
+    }
+}
+
 impl fmt::Display for FunctionExpr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -1,7 +1,10 @@
 use crate::{
-    environment::lexical_environment::VariableScope,
     exec::Executable,
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    syntax::ast::node::{
+        join_nodes,
+        pattern::{BindingKind, Target},
+        Node,
+    },
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -36,20 +39,31 @@ pub struct LetDeclList {
 
 impl Executable for LetDeclList {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        for var in self.as_ref() {
-            let val = match var.init() {
-                Some(v) => v.run(interpreter)?,
-                None => Value::undefined(),
+        // The initializer of each declaration is evaluated at step `2 * i`, and its target is
+        // bound to the value at step `2 * i + 1`.
+        let (first, mut resumed_value) = match interpreter.resume_state(self) {
+            Some(state) if state.step % 2 == 1 => {
+                let step = state.step;
+                let [value] = state.into_values();
+                (step, Some(value))
+            }
+            Some(state) => (state.step, None),
+            None => (0, None),
+        };
+        for (i, var) in self.as_ref().iter().enumerate().skip(first / 2) {
+            let val = match resumed_value.take() {
+                Some(val) => val,
+                None => match var.init() {
+                    Some(v) => interpreter.run_step(self, 2 * i, Vec::new, |ctx| v.run(ctx))?,
+                    None => Value::undefined(),
+                },
             };
-            interpreter.realm_mut().environment.create_mutable_binding(
-                var.name().to_owned(),
-                false,
-                VariableScope::Block,
-            );
-            interpreter
-                .realm_mut()
-                .environment
-                .initialize_binding(var.name(), val);
+            interpreter.run_step(
+                self,
+                2 * i + 1,
+                || vec![val.clone()],
+                |ctx| var.target().bind(val.clone(), BindingKind::Let, ctx),
+            )?;
         }
         Ok(Value::undefined())
     }
@@ -99,13 +113,13 @@ impl From<LetDeclList> for Node {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct LetDecl {
-    name: Identifier,
+    target: Target,
     init: Option<Node>,
 }
 
 impl fmt::Display for LetDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
+        fmt::Display::fmt(&self.target, f)?;
         if let Some(ref init) = self.init {
             write!(f, " = {}", init)?;
         }
@@ -115,20 +129,20 @@ impl fmt::Display for LetDecl {
 
 impl LetDecl {
     /// Creates a new variable declaration.
-    pub(in crate::syntax) fn new<N, I>(name: N, init: I) -> Self
+    pub(in crate::syntax) fn new<T, I>(target: T, init: I) -> Self
     where
-        N: Into<Identifier>,
+        T: Into<Target>,
         I: Into<Option<Node>>,
     {
         Self {
-            name: name.into(),
+            target: target.into(),
             init: init.into(),
         }
     }
 
-    /// Gets the name of the variable.
-    pub fn name(&self) -> &str {
-        self.name.as_ref()
+    /// Gets the target the variable is bound to.
+    pub fn target(&self) -> &Target {
+        &self.target
     }
 
     /// Gets the initialization node for the variable, if any.
@@ -1,21 +1,31 @@
 //! Declaration nodes
 
 pub mod arrow_function_decl;
+pub mod async_arrow_function_decl;
 pub mod async_function_decl;
 pub mod async_function_expr;
+pub mod class_decl;
+pub mod class_expr;
 pub mod const_decl_list;
 pub mod function_decl;
 pub mod function_expr;
+pub mod generator_decl;
+pub mod generator_expr;
 pub mod let_decl_list;
 pub mod var_decl_list;
 
 pub use self::{
     arrow_function_decl::ArrowFunctionDecl,
+    async_arrow_function_decl::AsyncArrowFunctionDecl,
     async_function_decl::AsyncFunctionDecl,
     async_function_expr::AsyncFunctionExpr,
+    class_decl::{ClassDecl, ClassElement},
+    class_expr::ClassExpr,
     const_decl_list::{ConstDecl, ConstDeclList},
     function_decl::FunctionDecl,
     function_expr::FunctionExpr,
+    generator_decl::GeneratorDecl,
+    generator_expr::GeneratorExpr,
     let_decl_list::{LetDecl, LetDeclList},
     var_decl_list::{VarDecl, VarDeclList},
 };
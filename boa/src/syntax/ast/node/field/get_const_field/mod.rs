@@ -69,7 +69,7 @@ impl Executable for GetConstField {
             obj = Value::Object(obj.to_object(interpreter)?);
         }
 
-        Ok(obj.get_field(self.field()))
+        obj.get_field_with_receiver(self.field(), &obj, interpreter)
     }
 }
 
@@ -63,13 +63,26 @@ impl GetField {
 
 impl Executable for GetField {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let mut obj = self.obj().run(interpreter)?;
-        if obj.get_type() != Type::Object {
-            obj = Value::Object(obj.to_object(interpreter)?);
-        }
-        let field = self.field().run(interpreter)?;
+        // The object is evaluated at step 0, and the key at step 1.
+        let obj = match interpreter.resume_state(self) {
+            Some(state) if state.step > 0 => {
+                let [obj] = state.into_values();
+                obj
+            }
+            _ => {
+                let obj = interpreter.run_step(self, 0, Vec::new, |ctx| self.obj().run(ctx))?;
+                if obj.get_type() != Type::Object {
+                    Value::Object(obj.to_object(interpreter)?)
+                } else {
+                    obj
+                }
+            }
+        };
+        let field =
+            interpreter.run_step(self, 1, || vec![obj.clone()], |ctx| self.field().run(ctx))?;
 
-        Ok(obj.get_field(field.to_property_key(interpreter)?))
+        let key = field.to_property_key(interpreter)?;
+        obj.get_field_with_receiver(key, &obj, interpreter)
     }
 }
 
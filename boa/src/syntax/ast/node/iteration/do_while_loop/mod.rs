@@ -26,7 +26,7 @@ use serde::{Deserialize, Serialize};
 pub struct DoWhileLoop {
     body: Box<Node>,
     cond: Box<Node>,
-    label: Option<Box<str>>,
+    labels: Box<[Box<str>]>,
 }
 
 impl DoWhileLoop {
@@ -38,8 +38,18 @@ impl DoWhileLoop {
         &self.cond
     }
 
-    pub fn label(&self) -> Option<&str> {
-        self.label.as_ref().map(Box::as_ref)
+    /// Gets the labels of the loop, which are the targets of the `continue` statements in its
+    /// body.
+    pub fn labels(&self) -> &[Box<str>] {
+        &self.labels
+    }
+
+    /// Sets the labels of the loop.
+    pub(in crate::syntax) fn set_labels<L>(&mut self, labels: L)
+    where
+        L: Into<Box<[Box<str>]>>,
+    {
+        self.labels = labels.into();
     }
 
     /// Creates a `DoWhileLoop` AST node.
@@ -51,7 +61,7 @@ impl DoWhileLoop {
         Self {
             body: Box::new(body.into()),
             cond: Box::new(condition.into()),
-            label: None,
+            labels: Box::default(),
         }
     }
 
@@ -68,57 +78,36 @@ impl DoWhileLoop {
 
 impl Executable for DoWhileLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let mut result = self.body().run(interpreter)?;
-        match interpreter.executor().get_current_state() {
-            InterpreterState::Break(_label) => {
-                // TODO break to label.
-
-                // Loops 'consume' breaks.
-                interpreter
-                    .executor()
-                    .set_current_state(InterpreterState::Executing);
-                return Ok(result);
-            }
-            InterpreterState::Continue(_label) => {
-                // TODO continue to label;
-                interpreter
-                    .executor()
-                    .set_current_state(InterpreterState::Executing);
-                // after breaking out of the block, continue execution of the loop
-            }
-            InterpreterState::Return => {
-                return Ok(result);
-            }
-            InterpreterState::Executing => {
-                // Continue execution.
+        // The body is run at step 0, and the condition at step 1.
+        let mut in_cond = interpreter
+            .resume_state(self)
+            .is_some_and(|state| state.step == 1);
+        let mut result = Value::undefined();
+        loop {
+            if !in_cond {
+                result = interpreter.run_step(self, 0, Vec::new, |ctx| self.body().run(ctx))?;
+                match interpreter.executor().get_current_state() {
+                    InterpreterState::Break(label) => {
+                        handle_state_with_labels!(self, label, interpreter, break);
+                        break;
+                    }
+                    InterpreterState::Continue(label) => {
+                        handle_state_with_labels!(self, label, interpreter, continue);
+                        // after breaking out of the block, continue execution of the loop
+                    }
+                    InterpreterState::Return => {
+                        return Ok(result);
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution.
+                    }
+                }
             }
-        }
+            in_cond = false;
 
-        while self.cond().run(interpreter)?.to_boolean() {
-            result = self.body().run(interpreter)?;
-            match interpreter.executor().get_current_state() {
-                InterpreterState::Break(_label) => {
-                    // TODO break to label.
-
-                    // Loops 'consume' breaks.
-                    interpreter
-                        .executor()
-                        .set_current_state(InterpreterState::Executing);
-                    break;
-                }
-                InterpreterState::Continue(_label) => {
-                    // TODO continue to label.
-                    interpreter
-                        .executor()
-                        .set_current_state(InterpreterState::Executing);
-                    // after breaking out of the block, continue execution of the loop
-                }
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
-                }
+            let cond = interpreter.run_step(self, 1, Vec::new, |ctx| self.cond().run(ctx))?;
+            if !cond.to_boolean() {
+                break;
             }
         }
         Ok(result)
@@ -0,0 +1,213 @@
+use crate::{
+    builtins::{iterable::IteratorRecord, ForInIterator},
+    environment::lexical_environment::new_declarative_environment,
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::{IterableLoopInitializer, Node},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `for...in` statement iterates over the enumerable string keys of an object, including
+/// the inherited ones.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-for-in-and-for-of-statements
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for...in
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ForInLoop {
+    variable: Box<IterableLoopInitializer>,
+    expr: Box<Node>,
+    body: Box<Node>,
+    labels: Box<[Box<str>]>,
+}
+
+impl ForInLoop {
+    pub fn new<V, E, B>(variable: V, expr: E, body: B) -> Self
+    where
+        V: Into<IterableLoopInitializer>,
+        E: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            variable: Box::new(variable.into()),
+            expr: Box::new(expr.into()),
+            body: Box::new(body.into()),
+            labels: Box::default(),
+        }
+    }
+
+    pub fn variable(&self) -> &IterableLoopInitializer {
+        &self.variable
+    }
+
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    /// Gets the labels of the loop, which are the targets of the `continue` statements in its
+    /// body.
+    pub fn labels(&self) -> &[Box<str>] {
+        &self.labels
+    }
+
+    /// Sets the labels of the loop.
+    pub(in crate::syntax) fn set_labels<L>(&mut self, labels: L)
+    where
+        L: Into<Box<[Box<str>]>>,
+    {
+        self.labels = labels.into();
+    }
+
+    pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        write!(f, "for ({} in {}) {{", self.variable, self.expr)?;
+        self.body().display(f, indentation + 1)?;
+        f.write_str("}")
+    }
+}
+
+impl fmt::Display for ForInLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ForInLoop> for Node {
+    fn from(for_in: ForInLoop) -> Node {
+        Self::ForInLoop(for_in)
+    }
+}
+
+impl Executable for ForInLoop {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ForIn", "exec");
+        // The object is run at step 0. In each iteration, the key is bound to the variable at
+        // step 1 and the body is run at step 2, like in a `for...of` loop. Unlike the iterator
+        // of a `for...of` loop, the key iterator is never closed.
+        let (iterator, mut resumed) = match interpreter.resume_state(self) {
+            Some(state) if state.step == 1 => {
+                let [iterator, next_function, key] = state.into_values();
+                (IteratorRecord::new(iterator, next_function), Some((1, key)))
+            }
+            Some(state) if state.step == 2 => {
+                let [iterator, next_function] = state.into_values();
+                let iterator = IteratorRecord::new(iterator, next_function);
+                (iterator, Some((2, Value::undefined())))
+            }
+            _ => {
+                let object = interpreter.run_step(self, 0, Vec::new, |ctx| self.expr().run(ctx))?;
+                if object.is_null_or_undefined() {
+                    return Ok(Value::undefined());
+                }
+                let object = object.to_object(interpreter)?;
+                let iterator = ForInIterator::create_for_in_iterator(interpreter, object.into());
+                let next_function = iterator.get_field("next", interpreter)?;
+                (IteratorRecord::new(iterator, next_function), None)
+            }
+        };
+        let mut result = Value::undefined();
+
+        loop {
+            let (step, key) = if let Some(resumed) = resumed.take() {
+                resumed
+            } else {
+                {
+                    let env = &mut interpreter.realm_mut().environment;
+                    env.push(new_declarative_environment(Some(
+                        env.get_current_environment_ref().clone(),
+                    )));
+                }
+                let iterator_result = match iterator.next(interpreter) {
+                    Ok(iterator_result) => iterator_result,
+                    Err(error) => {
+                        let _ = interpreter.realm_mut().environment.pop();
+                        return Err(error);
+                    }
+                };
+                if iterator_result.is_done() {
+                    let _ = interpreter.realm_mut().environment.pop();
+                    break;
+                }
+                (1, iterator_result.value())
+            };
+
+            if step == 1 {
+                let values = || {
+                    vec![
+                        iterator.iterator_object().clone(),
+                        iterator.next_function().clone(),
+                        key.clone(),
+                    ]
+                };
+                let bound = interpreter.run_step(self, 1, values, |ctx| {
+                    self.variable().bind(key.clone(), ctx)
+                });
+                if let Err(error) = bound {
+                    if !interpreter.is_suspending() {
+                        let _ = interpreter.realm_mut().environment.pop();
+                    }
+                    return Err(error);
+                }
+            }
+
+            let values = || {
+                vec![
+                    iterator.iterator_object().clone(),
+                    iterator.next_function().clone(),
+                ]
+            };
+            result = match interpreter.run_step(self, 2, values, |ctx| self.body().run(ctx)) {
+                Ok(result) => result,
+                // A suspended loop is not left.
+                Err(error) if interpreter.is_suspending() => return Err(error),
+                Err(error) => {
+                    let _ = interpreter.realm_mut().environment.pop();
+                    return Err(error);
+                }
+            };
+            match interpreter.executor().get_current_state() {
+                InterpreterState::Break(label) => {
+                    // Loops 'consume' the breaks that target them.
+                    if super::targets_loop(self.labels(), label) {
+                        interpreter
+                            .executor()
+                            .set_current_state(InterpreterState::Executing);
+                    }
+                    let _ = interpreter.realm_mut().environment.pop();
+                    break;
+                }
+                InterpreterState::Continue(label) => {
+                    // The loop is left if an outer loop is continued.
+                    if !super::targets_loop(self.labels(), label) {
+                        let _ = interpreter.realm_mut().environment.pop();
+                        break;
+                    }
+                    interpreter
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+                    // after breaking out of the block, continue execution of the loop
+                }
+                InterpreterState::Return => {
+                    let _ = interpreter.realm_mut().environment.pop();
+                    return Ok(result);
+                }
+                InterpreterState::Executing => {
+                    // Continue execution.
+                }
+            }
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+        Ok(result)
+    }
+}
@@ -26,7 +26,7 @@ use serde::{Deserialize, Serialize};
 pub struct ForLoop {
     #[cfg_attr(feature = "serde", serde(flatten))]
     inner: Box<InnerForLoop>,
-    label: Option<Box<str>>,
+    labels: Box<[Box<str>]>,
 }
 
 impl ForLoop {
@@ -40,7 +40,7 @@ impl ForLoop {
     {
         Self {
             inner: Box::new(InnerForLoop::new(init, condition, final_expr, body)),
-            label: None,
+            labels: Box::default(),
         }
     }
 
@@ -88,12 +88,18 @@ impl ForLoop {
         write!(f, "}}")
     }
 
-    pub fn label(&self) -> Option<&str> {
-        self.label.as_ref().map(Box::as_ref)
+    /// Gets the labels of the loop, which are the targets of the `continue` statements in its
+    /// body.
+    pub fn labels(&self) -> &[Box<str>] {
+        &self.labels
     }
 
-    pub fn set_label(&mut self, label: Box<str>) {
-        self.label = Some(label);
+    /// Sets the labels of the loop.
+    pub(in crate::syntax) fn set_labels<L>(&mut self, labels: L)
+    where
+        L: Into<Box<[Box<str>]>>,
+    {
+        self.labels = labels.into();
     }
 }
 
@@ -101,45 +107,61 @@ impl Executable for ForLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         // Create the block environment.
         let _timer = BoaProfiler::global().start_event("ForLoop", "exec");
-        {
+        // The initializer is run at step 0, the condition at step 1, the body at step 2 and the
+        // final expression at step 3. The environment of a resumed loop is restored along with
+        // its coroutine.
+        let mut step = if let Some(state) = interpreter.resume_state(self) {
+            state.step
+        } else {
             let env = &mut interpreter.realm_mut().environment;
             env.push(new_declarative_environment(Some(
                 env.get_current_environment_ref().clone(),
             )));
-        }
+            0
+        };
 
-        if let Some(init) = self.init() {
-            init.run(interpreter)?;
+        if step == 0 {
+            if let Some(init) = self.init() {
+                interpreter.run_step(self, 0, Vec::new, |ctx| init.run(ctx))?;
+            }
+            step = 1;
         }
 
-        while self
-            .condition()
-            .map(|cond| cond.run(interpreter).map(|v| v.to_boolean()))
-            .transpose()?
-            .unwrap_or(true)
-        {
-            let result = self.body().run(interpreter)?;
-
-            match interpreter.executor().get_current_state() {
-                InterpreterState::Break(label) => {
-                    handle_state_with_labels!(self, label, interpreter, break);
-                    break;
-                }
-                InterpreterState::Continue(label) => {
-                    handle_state_with_labels!(self, label, interpreter, continue);
+        loop {
+            if step <= 1 {
+                if let Some(cond) = self.condition() {
+                    let cond = interpreter.run_step(self, 1, Vec::new, |ctx| cond.run(ctx))?;
+                    if !cond.to_boolean() {
+                        break;
+                    }
                 }
+            }
 
-                InterpreterState::Return => {
-                    return Ok(result);
-                }
-                InterpreterState::Executing => {
-                    // Continue execution.
+            if step <= 2 {
+                let result = interpreter.run_step(self, 2, Vec::new, |ctx| self.body().run(ctx))?;
+
+                match interpreter.executor().get_current_state() {
+                    InterpreterState::Break(label) => {
+                        handle_state_with_labels!(self, label, interpreter, break);
+                        break;
+                    }
+                    InterpreterState::Continue(label) => {
+                        handle_state_with_labels!(self, label, interpreter, continue);
+                    }
+
+                    InterpreterState::Return => {
+                        return Ok(result);
+                    }
+                    InterpreterState::Executing => {
+                        // Continue execution.
+                    }
                 }
             }
 
             if let Some(final_expr) = self.final_expr() {
-                final_expr.run(interpreter)?;
+                interpreter.run_step(self, 3, Vec::new, |ctx| final_expr.run(ctx))?;
             }
+            step = 1;
         }
 
         // pop the block env
@@ -1,8 +1,11 @@
 use crate::{
-    builtins::iterable::get_iterator,
-    environment::lexical_environment::{new_declarative_environment, VariableScope},
+    builtins::iterable::{get_iterator, IteratorRecord},
+    environment::lexical_environment::new_declarative_environment,
     exec::{Executable, InterpreterState},
-    syntax::ast::node::Node,
+    syntax::ast::node::{
+        pattern::{BindingKind, Target},
+        Node,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -14,15 +17,16 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct ForOfLoop {
-    variable: Box<Node>,
+    variable: Box<IterableLoopInitializer>,
     iterable: Box<Node>,
     body: Box<Node>,
+    labels: Box<[Box<str>]>,
 }
 
 impl ForOfLoop {
     pub fn new<V, I, B>(variable: V, iterable: I, body: B) -> Self
     where
-        V: Into<Node>,
+        V: Into<IterableLoopInitializer>,
         I: Into<Node>,
         B: Into<Node>,
     {
@@ -30,10 +34,11 @@ impl ForOfLoop {
             variable: Box::new(variable.into()),
             iterable: Box::new(iterable.into()),
             body: Box::new(body.into()),
+            labels: Box::default(),
         }
     }
 
-    pub fn variable(&self) -> &Node {
+    pub fn variable(&self) -> &IterableLoopInitializer {
         &self.variable
     }
 
@@ -45,6 +50,20 @@ impl ForOfLoop {
         &self.body
     }
 
+    /// Gets the labels of the loop, which are the targets of the `continue` statements in its
+    /// body.
+    pub fn labels(&self) -> &[Box<str>] {
+        &self.labels
+    }
+
+    /// Sets the labels of the loop.
+    pub(in crate::syntax) fn set_labels<L>(&mut self, labels: L)
+    where
+        L: Into<Box<[Box<str>]>>,
+    {
+        self.labels = labels.into();
+    }
+
     pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         write!(f, "for ({} of {}) {{", self.variable, self.iterable)?;
         self.body().display(f, indentation + 1)?;
@@ -67,136 +86,116 @@ impl From<ForOfLoop> for Node {
 impl Executable for ForOfLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ForOf", "exec");
-        let iterable = self.iterable().run(interpreter)?;
-        let iterator = get_iterator(interpreter, iterable)?;
+        // The iterable is run at step 0. In each iteration, the value is bound to the variable
+        // at step 1 and the body is run at step 2. The environment of a resumed iteration is
+        // restored along with its coroutine.
+        let (iterator, mut resumed) = match interpreter.resume_state(self) {
+            Some(state) if state.step == 1 => {
+                let [iterator, next_function, value] = state.into_values();
+                (
+                    IteratorRecord::new(iterator, next_function),
+                    Some((1, value)),
+                )
+            }
+            Some(state) if state.step == 2 => {
+                let [iterator, next_function] = state.into_values();
+                let iterator = IteratorRecord::new(iterator, next_function);
+                (iterator, Some((2, Value::undefined())))
+            }
+            _ => {
+                let iterable =
+                    interpreter.run_step(self, 0, Vec::new, |ctx| self.iterable().run(ctx))?;
+                (get_iterator(interpreter, iterable)?, None)
+            }
+        };
         let mut result = Value::undefined();
 
         loop {
-            {
-                let env = &mut interpreter.realm_mut().environment;
-                env.push(new_declarative_environment(Some(
-                    env.get_current_environment_ref().clone(),
-                )));
-            }
-            let iterator_result = iterator.next(interpreter)?;
-            if iterator_result.is_done() {
-                break;
-            }
-            let next_result = iterator_result.value();
-
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    let environment = &mut interpreter.realm_mut().environment;
-
-                    if environment.has_binding(name.as_ref()) {
-                        // Binding already exists
-                        environment.set_mutable_binding(name.as_ref(), next_result.clone(), true);
-                    } else {
-                        environment.create_mutable_binding(
-                            name.as_ref().to_owned(),
-                            true,
-                            VariableScope::Function,
-                        );
-                        environment.initialize_binding(name.as_ref(), next_result.clone());
-                    }
+            let (step, value) = if let Some(resumed) = resumed.take() {
+                resumed
+            } else {
+                {
+                    let env = &mut interpreter.realm_mut().environment;
+                    env.push(new_declarative_environment(Some(
+                        env.get_current_environment_ref().clone(),
+                    )));
                 }
-                Node::VarDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut interpreter.realm_mut().environment;
-
-                        if var.init().is_some() {
-                            return interpreter.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        if environment.has_binding(var.name()) {
-                            environment.set_mutable_binding(var.name(), next_result, true);
-                        } else {
-                            environment.create_mutable_binding(
-                                var.name().to_owned(),
-                                false,
-                                VariableScope::Function,
-                            );
-                            environment.initialize_binding(var.name(), next_result);
-                        }
+                let iterator_result = match iterator.next(interpreter) {
+                    Ok(iterator_result) => iterator_result,
+                    Err(error) => {
+                        let _ = interpreter.realm_mut().environment.pop();
+                        return Err(error);
                     }
-                    _ => {
-                        return interpreter.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut interpreter.realm_mut().environment;
-
-                        if var.init().is_some() {
-                            return interpreter.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        environment.create_mutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        );
-                        environment.initialize_binding(var.name(), next_result);
-                    }
-                    _ => {
-                        return interpreter.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        let environment = &mut interpreter.realm_mut().environment;
-
-                        if var.init().is_some() {
-                            return interpreter.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        environment.create_immutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        );
-                        environment.initialize_binding(var.name(), next_result);
-                    }
-                    _ => {
-                        return interpreter.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
-                    }
-                },
-                Node::Assign(_) => {
-                    return interpreter.throw_syntax_error(
-                        "a declaration in the head of a for-of loop can't have an initializer",
-                    );
+                };
+                if iterator_result.is_done() {
+                    let _ = interpreter.realm_mut().environment.pop();
+                    break;
                 }
-                _ => {
-                    return interpreter
-                        .throw_syntax_error("unknown left hand side in head of for-of loop")
+                (1, iterator_result.value())
+            };
+
+            if step == 1 {
+                let values = || {
+                    vec![
+                        iterator.iterator_object().clone(),
+                        iterator.next_function().clone(),
+                        value.clone(),
+                    ]
+                };
+                let bound = interpreter.run_step(self, 1, values, |ctx| {
+                    self.variable().bind(value.clone(), ctx)
+                });
+                match bound {
+                    Ok(()) => {}
+                    Err(error) if interpreter.is_suspending() => return Err(error),
+                    Err(error) => {
+                        let _ = interpreter.realm_mut().environment.pop();
+                        return iterator.close(Err(error), interpreter);
+                    }
                 }
             }
 
-            result = self.body().run(interpreter)?;
+            let values = || {
+                vec![
+                    iterator.iterator_object().clone(),
+                    iterator.next_function().clone(),
+                ]
+            };
+            result = match interpreter.run_step(self, 2, values, |ctx| self.body().run(ctx)) {
+                Ok(result) => result,
+                // A suspended loop is neither left nor closed.
+                Err(error) if interpreter.is_suspending() => return Err(error),
+                Err(error) => {
+                    let _ = interpreter.realm_mut().environment.pop();
+                    return iterator.close(Err(error), interpreter);
+                }
+            };
             match interpreter.executor().get_current_state() {
-                InterpreterState::Break(_label) => {
-                    // TODO break to label.
-
-                    // Loops 'consume' breaks.
-                    interpreter
-                        .executor()
-                        .set_current_state(InterpreterState::Executing);
-                    break;
+                InterpreterState::Break(label) => {
+                    // Loops 'consume' the breaks that target them.
+                    if super::targets_loop(self.labels(), label) {
+                        interpreter
+                            .executor()
+                            .set_current_state(InterpreterState::Executing);
+                    }
+                    let _ = interpreter.realm_mut().environment.pop();
+                    return iterator.close(Ok(result), interpreter);
                 }
-                InterpreterState::Continue(_label) => {
-                    // TODO continue to label.
+                InterpreterState::Continue(label) => {
+                    // Continuing an outer loop closes the iterator, like a `break`.
+                    if !super::targets_loop(self.labels(), label) {
+                        let _ = interpreter.realm_mut().environment.pop();
+                        return iterator.close(Ok(result), interpreter);
+                    }
                     interpreter
                         .executor()
                         .set_current_state(InterpreterState::Executing);
                     // after breaking out of the block, continue execution of the loop
                 }
-                InterpreterState::Return => return Ok(result),
+                InterpreterState::Return => {
+                    let _ = interpreter.realm_mut().environment.pop();
+                    return iterator.close(Ok(result), interpreter);
+                }
                 InterpreterState::Executing => {
                     // Continue execution.
                 }
@@ -206,3 +205,59 @@ impl Executable for ForOfLoop {
         Ok(result)
     }
 }
+
+/// The variable in the head of a `for...of` loop, which each value of the iterable is bound to.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForInOfStatement
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum IterableLoopInitializer {
+    /// A variable declared with `var`.
+    Var(Target),
+
+    /// A variable declared with `let`, which is bound again in each iteration.
+    Let(Target),
+
+    /// A variable declared with `const`, which is bound again in each iteration.
+    Const(Target),
+
+    /// A target that is assigned to, such as a variable declared outside of the loop.
+    Assignment(Target),
+}
+
+impl IterableLoopInitializer {
+    /// Gets the target the values are bound to.
+    pub fn target(&self) -> &Target {
+        match self {
+            Self::Var(target)
+            | Self::Let(target)
+            | Self::Const(target)
+            | Self::Assignment(target) => target,
+        }
+    }
+
+    /// Binds the value of the current iteration to the variable.
+    pub(super) fn bind(&self, value: Value, interpreter: &mut Context) -> Result<()> {
+        let kind = match self {
+            Self::Var(_) => BindingKind::Var,
+            Self::Let(_) => BindingKind::Let,
+            Self::Const(_) => BindingKind::Const,
+            Self::Assignment(_) => BindingKind::Assignment,
+        };
+        self.target().bind(value, kind, interpreter)
+    }
+}
+
+impl fmt::Display for IterableLoopInitializer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Var(target) => write!(f, "var {}", target),
+            Self::Let(target) => write!(f, "let {}", target),
+            Self::Const(target) => write!(f, "const {}", target),
+            Self::Assignment(target) => fmt::Display::fmt(target, f),
+        }
+    }
+}
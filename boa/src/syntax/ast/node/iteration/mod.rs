@@ -1,7 +1,11 @@
 //! Iteration nodes
 
 pub use self::{
-    continue_node::Continue, do_while_loop::DoWhileLoop, for_loop::ForLoop, for_of_loop::ForOfLoop,
+    continue_node::Continue,
+    do_while_loop::DoWhileLoop,
+    for_in_loop::ForInLoop,
+    for_loop::ForLoop,
+    for_of_loop::{ForOfLoop, IterableLoopInitializer},
     while_loop::WhileLoop,
 };
 
@@ -9,19 +13,11 @@ pub use self::{
 mod tests;
 
 // Checking labels for break and continue is the same operation for `ForLoop`, `While` and `DoWhile`
-#[macro_use]
 macro_rules! handle_state_with_labels {
     ($self:ident, $label:ident, $interpreter:ident, $state:tt) => {{
-        if let Some(brk_label) = $label {
-            if let Some(stmt_label) = $self.label() {
-                // Break from where we are, keeping "continue" set as the state
-                if stmt_label != brk_label.as_ref() {
-                    break;
-                }
-            } else {
-                // if a label is set but the current block has no label, break
-                break;
-            }
+        // Break from where we are, keeping the state, if the statement targets an outer statement
+        if !super::targets_loop($self.labels(), $label) {
+            break;
         }
 
         $interpreter
@@ -30,8 +26,15 @@ macro_rules! handle_state_with_labels {
     }};
 }
 
+/// Returns `true` if a `break` or `continue` statement with the given label targets a loop with
+/// the given labels, rather than an outer statement.
+fn targets_loop(labels: &[Box<str>], label: &Option<Box<str>>) -> bool {
+    label.as_ref().is_none_or(|label| labels.contains(label))
+}
+
 pub mod continue_node;
 pub mod do_while_loop;
+pub mod for_in_loop;
 pub mod for_loop;
 pub mod for_of_loop;
 pub mod while_loop;
@@ -353,3 +353,118 @@ fn for_loop_continue_label() {
     "#;
     assert_eq!(&exec(scenario), "10");
 }
+
+#[test]
+fn for_in_loop_key_order() {
+    let scenario = r#"
+        var keys = [];
+        for (var key in { b: 1, a: 2, 10: 3, 2: 4 }) {
+            keys.push(key);
+        }
+        keys.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"2,10,b,a\"");
+}
+
+#[test]
+fn for_in_loop_inherited_keys() {
+    let scenario = r#"
+        function Base() {
+            this.own = 1;
+            this.shadowed = 2;
+        }
+        Base.prototype.inherited = 3;
+        Base.prototype.shadowed = 4;
+        var keys = [];
+        for (let key in new Base()) {
+            keys.push(key);
+        }
+        keys.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"own,shadowed,inherited\"");
+}
+
+#[test]
+fn for_in_loop_skips_non_enumerable_and_symbol_keys() {
+    let scenario = r#"
+        var keys = [];
+        for (const key in [1, 2]) {
+            keys.push(typeof key + key);
+        }
+        var obj = { a: 1 };
+        obj[Symbol()] = 2;
+        for (const key in obj) {
+            keys.push(key);
+        }
+        keys.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"string0,string1,a\"");
+}
+
+#[test]
+fn for_in_loop_deleted_keys() {
+    let scenario = r#"
+        var obj = { a: 1, b: 2, c: 3 };
+        var keys = [];
+        for (var key in obj) {
+            keys.push(key);
+            delete obj.b;
+        }
+        keys.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"a,c\"");
+}
+
+#[test]
+fn for_in_loop_null_and_undefined() {
+    let scenario = r#"
+        var count = 0;
+        for (var key in null) {
+            count++;
+        }
+        for (var key in undefined) {
+            count++;
+        }
+        count;
+    "#;
+    assert_eq!(&exec(scenario), "0");
+}
+
+#[test]
+fn for_in_loop_break_continue_and_patterns() {
+    let scenario = r#"
+        var obj = {};
+        var keys = [];
+        for (obj.key in { a: 1, b: 2, c: 3, d: 4 }) {
+            if (obj.key === "b") {
+                continue;
+            }
+            if (obj.key === "d") {
+                break;
+            }
+            keys.push(obj.key);
+        }
+        for (var { length } in { xy: 1 }) {
+            keys.push(length);
+        }
+        keys.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"a,c,2\"");
+}
+
+#[test]
+fn for_in_loop_in_generator() {
+    let scenario = r#"
+        function* keys(obj) {
+            for (var key in obj) {
+                yield key;
+            }
+        }
+        var result = [];
+        for (var key of keys({ a: 1, b: 2 })) {
+            result.push(key);
+        }
+        result.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"a,b\"");
+}
@@ -25,7 +25,7 @@ use serde::{Deserialize, Serialize};
 pub struct WhileLoop {
     cond: Box<Node>,
     expr: Box<Node>,
-    label: Option<Box<str>>,
+    labels: Box<[Box<str>]>,
 }
 
 impl WhileLoop {
@@ -37,8 +37,18 @@ impl WhileLoop {
         &self.expr
     }
 
-    pub fn label(&self) -> Option<&str> {
-        self.label.as_ref().map(Box::as_ref)
+    /// Gets the labels of the loop, which are the targets of the `continue` statements in its
+    /// body.
+    pub fn labels(&self) -> &[Box<str>] {
+        &self.labels
+    }
+
+    /// Sets the labels of the loop.
+    pub(in crate::syntax) fn set_labels<L>(&mut self, labels: L)
+    where
+        L: Into<Box<[Box<str>]>>,
+    {
+        self.labels = labels.into();
     }
 
     /// Creates a `WhileLoop` AST node.
@@ -50,7 +60,7 @@ impl WhileLoop {
         Self {
             cond: Box::new(condition.into()),
             expr: Box::new(body.into()),
-            label: None,
+            labels: Box::default(),
         }
     }
 
@@ -66,9 +76,18 @@ impl WhileLoop {
 
 impl Executable for WhileLoop {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        // The condition is run at step 0, and the body at step 1.
+        let mut in_body = interpreter
+            .resume_state(self)
+            .is_some_and(|state| state.step == 1);
         let mut result = Value::undefined();
-        while self.cond().run(interpreter)?.to_boolean() {
-            result = self.expr().run(interpreter)?;
+        while in_body
+            || interpreter
+                .run_step(self, 0, Vec::new, |ctx| self.cond().run(ctx))?
+                .to_boolean()
+        {
+            in_body = false;
+            result = interpreter.run_step(self, 1, Vec::new, |ctx| self.expr().run(ctx))?;
             match interpreter.executor().get_current_state() {
                 InterpreterState::Break(label) => {
                     handle_state_with_labels!(self, label, interpreter, break);
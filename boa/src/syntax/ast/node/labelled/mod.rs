@@ -0,0 +1,92 @@
+use super::Node;
+use crate::{
+    exec::{Executable, InterpreterState},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// A labelled statement can be the target of the `break` statements in it, and of the
+/// `continue` statements if it is a loop.
+///
+/// Any statement can be labelled. A `break` statement with the label of a statement that is not
+/// a loop, such as a block or an `if` statement, ends the evaluation of that statement.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-labelled-statements
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/label
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Labelled {
+    label: Box<str>,
+    statement: Box<Node>,
+}
+
+impl Labelled {
+    /// Creates a `Labelled` AST node.
+    pub fn new<L, S>(label: L, statement: S) -> Self
+    where
+        L: Into<Box<str>>,
+        S: Into<Node>,
+    {
+        Self {
+            label: label.into(),
+            statement: Box::new(statement.into()),
+        }
+    }
+
+    /// Gets the label of the statement.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Gets the labelled statement.
+    pub fn statement(&self) -> &Node {
+        &self.statement
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        write!(f, "{}: ", self.label)?;
+        self.statement.display(f, indentation)
+    }
+}
+
+impl Executable for Labelled {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Labelled", "exec");
+        let result = self.statement().run(interpreter)?;
+
+        // The statement consumes the breaks that target it.
+        if let InterpreterState::Break(Some(label)) = interpreter.executor().get_current_state() {
+            if label.as_ref() == self.label() {
+                interpreter
+                    .executor()
+                    .set_current_state(InterpreterState::Executing);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl fmt::Display for Labelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<Labelled> for Node {
+    fn from(labelled: Labelled) -> Self {
+        Self::Labelled(labelled)
+    }
+}
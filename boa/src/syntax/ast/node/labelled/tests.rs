@@ -0,0 +1,157 @@
+use crate::exec;
+
+#[test]
+fn break_labelled_block() {
+    let scenario = r#"
+        var str = "";
+        outer: {
+            str += "a";
+            inner: {
+                str += "b";
+                break outer;
+            }
+            str += "c";
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"ab\"");
+}
+
+#[test]
+fn break_labelled_if() {
+    let scenario = r#"
+        var str = "";
+        check: if (true) {
+            str += "a";
+            if (str.length === 1) {
+                break check;
+            }
+            str += "b";
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"a\"");
+}
+
+#[test]
+fn break_labelled_block_in_loop() {
+    let scenario = r#"
+        var str = "";
+        for (var i = 0; i < 3; i++) {
+            body: {
+                if (i === 1) {
+                    break body;
+                }
+                str += i;
+            }
+            str += ";";
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"0;;2;\"");
+}
+
+#[test]
+fn continue_outer_loop_through_switch() {
+    let scenario = r#"
+        var str = "";
+        outer: for (var i = 0; i < 3; i++) {
+            switch (i) {
+                case 1:
+                    continue outer;
+                default:
+                    str += i;
+            }
+            str += ";";
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"0;2;\"");
+}
+
+#[test]
+fn continue_outer_loop_through_finally() {
+    let scenario = r#"
+        var str = "";
+        outer: for (var i = 0; i < 2; i++) {
+            for (var j = 0; j < 2; j++) {
+                try {
+                    continue outer;
+                } finally {
+                    str += i + "" + j + ";";
+                }
+            }
+            str += "unreachable";
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"00;10;\"");
+}
+
+#[test]
+fn break_labelled_switch() {
+    let scenario = r#"
+        var str = "";
+        choice: switch (1) {
+            case 1:
+                while (true) {
+                    break choice;
+                }
+                str += "unreachable";
+        }
+        str += "done";
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"done\"");
+}
+
+#[test]
+fn continue_with_multiple_labels() {
+    let scenario = r#"
+        var count = 0;
+        a: b: for (var i = 0; i < 3; i++) {
+            do {
+                count++;
+                continue a;
+            } while (false);
+        }
+        count
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn continue_outer_for_of_closes_iterator() {
+    let scenario = r#"
+        var closed = 0;
+        var iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                next: function () { return { value: 1, done: false }; },
+                return: function () { closed++; return {}; }
+            };
+        };
+        outer: for (var i = 0; i < 2; i++) {
+            for (var x of iterable) {
+                continue outer;
+            }
+        }
+        closed
+    "#;
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn continue_outer_for_in() {
+    let scenario = r#"
+        var keys = "";
+        outer: for (var key in { a: 1, b: 2 }) {
+            for (var inner in { c: 1, d: 2 }) {
+                keys += key + inner;
+                continue outer;
+            }
+        }
+        keys
+    "#;
+    assert_eq!(&exec(scenario), "\"acbc\"");
+}
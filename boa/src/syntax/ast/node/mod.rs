@@ -10,15 +10,23 @@ pub mod declaration;
 pub mod field;
 pub mod identifier;
 pub mod iteration;
+pub mod labelled;
+pub mod module;
 pub mod new;
 pub mod object;
 pub mod operator;
+pub mod optional;
+pub mod pattern;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
+pub mod super_node;
 pub mod switch;
+pub mod template;
 pub mod throw;
 pub mod try_node;
+pub mod with;
+pub mod yield_expr;
 
 pub use self::{
     array::ArrayDecl,
@@ -28,21 +36,32 @@ pub use self::{
     call::Call,
     conditional::{ConditionalOp, If},
     declaration::{
-        ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, ConstDecl, ConstDeclList,
-        FunctionDecl, FunctionExpr, LetDecl, LetDeclList, VarDecl, VarDeclList,
+        ArrowFunctionDecl, AsyncArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, ClassDecl,
+        ClassElement, ClassExpr, ConstDecl, ConstDeclList, FunctionDecl, FunctionExpr,
+        GeneratorDecl, GeneratorExpr, LetDecl, LetDeclList, VarDecl, VarDeclList,
     },
     field::{GetConstField, GetField},
     identifier::Identifier,
-    iteration::{Continue, DoWhileLoop, ForLoop, ForOfLoop, WhileLoop},
+    iteration::{
+        Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, IterableLoopInitializer, WhileLoop,
+    },
+    labelled::Labelled,
+    module::{ExportDecl, ExportSpecifier, ImportCall, ImportDecl, ImportSpecifier},
     new::New,
     object::Object,
     operator::{Assign, BinOp, UnaryOp},
+    optional::{Optional, OptionalOperation, OptionalOperationKind},
+    pattern::{ArrayPattern, ObjectPattern, Pattern, PatternElement, PatternProperty, Target},
     return_smt::Return,
     spread::Spread,
     statement_list::{RcStatementList, StatementList},
+    super_node::{GetSuperConstField, GetSuperField, SuperCall},
     switch::{Case, Switch},
+    template::{TaggedTemplate, TemplateElement, TemplateLit},
     throw::Throw,
     try_node::{Catch, Finally, Try},
+    with::With,
+    yield_expr::YieldExpr,
 };
 use super::Const;
 use crate::{exec::Executable, BoaProfiler, Context, Result, Value};
@@ -50,6 +69,8 @@ use gc::{unsafe_empty_trace, Finalize, Trace};
 use std::{
     cmp::Ordering,
     fmt::{self, Display},
+    ops::Deref,
+    rc::Rc,
 };
 
 #[cfg(feature = "serde")]
@@ -67,6 +88,9 @@ pub enum Node {
     /// An assignment operator node. [More information](./operator/struct.Assign.html).
     Assign(Assign),
 
+    /// An async arrow function expression node. [More information](./declaration/struct.AsyncArrowFunctionDecl.html).
+    AsyncArrowFunctionDecl(AsyncArrowFunctionDecl),
+
     /// An async function declaration node. [More information](./declaration/struct.AsyncFunctionDecl.html).
     AsyncFunctionDecl(AsyncFunctionDecl),
 
@@ -88,6 +112,12 @@ pub enum Node {
     /// A function call. [More information](./expression/struct.Call.html).
     Call(Call),
 
+    /// A class declaration node. [More information](./declaration/struct.ClassDecl.html).
+    ClassDecl(ClassDecl),
+
+    /// A class expression node. [More information](./declaration/struct.ClassExpr.html).
+    ClassExpr(ClassExpr),
+
     /// A javascript conditional operand ( x ? y : z ). [More information](./conditional/struct.ConditionalOp.html).
     ConditionalOp(ConditionalOp),
 
@@ -112,27 +142,54 @@ pub enum Node {
     /// A do ... while statement. [More information](./iteration/struct.DoWhileLoop.html).
     DoWhileLoop(DoWhileLoop),
 
+    /// An export declaration. [More information](./module/enum.ExportDecl.html).
+    ExportDecl(ExportDecl),
+
     /// A function declaration node. [More information](./declaration/struct.FunctionDecl.html).
     FunctionDecl(FunctionDecl),
 
     /// A function expression node. [More information](./declaration/struct.FunctionExpr.html).
     FunctionExpr(FunctionExpr),
 
+    /// A generator declaration node. [More information](./declaration/struct.GeneratorDecl.html).
+    GeneratorDecl(GeneratorDecl),
+
+    /// A generator expression node. [More information](./declaration/struct.GeneratorExpr.html).
+    GeneratorExpr(GeneratorExpr),
+
     /// Provides access to an object types' constant properties. [More information](./declaration/struct.GetConstField.html).
     GetConstField(GetConstField),
 
     /// Provides access to object fields. [More information](./declaration/struct.GetField.html).
     GetField(GetField),
 
+    /// Provides access to a constant property of the parent class. [More information](./super_node/struct.GetSuperConstField.html).
+    GetSuperConstField(GetSuperConstField),
+
+    /// Provides access to a computed property of the parent class. [More information](./super_node/struct.GetSuperField.html).
+    GetSuperField(GetSuperField),
+
     /// A `for` statement. [More information](./iteration/struct.ForLoop.html).
     ForLoop(ForLoop),
 
+    /// A `for...in` statement. [More information](./iteration/struct.ForInLoop.html).
+    ForInLoop(ForInLoop),
+
     /// A `for...of` statement. [More information](./iteration/struct.ForOf.html).
     ForOfLoop(ForOfLoop),
 
     /// An 'if' statement. [More information](./conditional/struct.If.html).
     If(If),
 
+    /// A dynamic `import()` call. [More information](./module/struct.ImportCall.html).
+    ImportCall(ImportCall),
+
+    /// An import declaration. [More information](./module/struct.ImportDecl.html).
+    ImportDecl(ImportDecl),
+
+    /// A labelled statement. [More information](./labelled/struct.Labelled.html).
+    Labelled(Labelled),
+
     /// A `let` declaration list. [More information](./declaration/struct.LetDeclList.html).
     LetDeclList(LetDeclList),
 
@@ -145,6 +202,9 @@ pub enum Node {
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// An optional chain. [More information](./optional/struct.Optional.html).
+    Optional(Optional),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
@@ -154,6 +214,15 @@ pub enum Node {
     /// A spread (...x) statement. [More information](./spread/struct.Spread.html).
     Spread(Spread),
 
+    /// A call to the parent class constructor. [More information](./super_node/struct.SuperCall.html).
+    SuperCall(SuperCall),
+
+    /// A tagged template. [More information](./template/struct.TaggedTemplate.html).
+    TaggedTemplate(TaggedTemplate),
+
+    /// A template literal. [More information](./template/struct.TemplateLit.html).
+    TemplateLit(TemplateLit),
+
     /// A throw statement. [More information](./throw/struct.Throw.html).
     Throw(Throw),
 
@@ -182,6 +251,12 @@ pub enum Node {
 
     /// A 'while {...}' node. [More information](./iteration/struct.WhileLoop.html).
     WhileLoop(WhileLoop),
+
+    /// A `with` statement. [More information](./with/struct.With.html).
+    With(With),
+
+    /// A `yield` expression node. [More information](./yield_expr/struct.YieldExpr.html).
+    YieldExpr(YieldExpr),
 }
 
 impl Display for Node {
@@ -199,12 +274,24 @@ impl From<Const> for Node {
 impl Node {
     /// Returns a node ordering based on the hoistability of each node.
     pub(crate) fn hoistable_order(a: &Node, b: &Node) -> Ordering {
-        match (a, b) {
-            (Node::FunctionDecl(_), Node::FunctionDecl(_)) => Ordering::Equal,
-            (_, Node::FunctionDecl(_)) => Ordering::Greater,
-            (Node::FunctionDecl(_), _) => Ordering::Less,
+        let is_hoistable = |node: &Node| node.is_hoistable_declaration();
+        match (is_hoistable(a), is_hoistable(b)) {
+            (true, true) | (false, false) => Ordering::Equal,
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
 
-            (_, _) => Ordering::Equal,
+    /// Returns whether the node is a function declaration, which is instantiated before the
+    /// other statements of its scope are evaluated, or the export of one.
+    pub(crate) fn is_hoistable_declaration(&self) -> bool {
+        match self {
+            Node::FunctionDecl(_) | Node::GeneratorDecl(_) | Node::AsyncFunctionDecl(_) => true,
+            Node::ExportDecl(ExportDecl::Declaration(declaration))
+            | Node::ExportDecl(ExportDecl::DefaultDeclaration(declaration)) => {
+                declaration.is_hoistable_declaration()
+            }
+            _ => false,
         }
     }
 
@@ -223,9 +310,12 @@ impl Node {
 
         match *self {
             Self::Call(ref expr) => Display::fmt(expr, f),
+            Self::ClassDecl(ref decl) => decl.display(f, indentation),
+            Self::ClassExpr(ref expr) => expr.display(f, indentation),
             Self::Const(ref c) => write!(f, "{}", c),
             Self::ConditionalOp(ref cond_op) => Display::fmt(cond_op, f),
             Self::ForLoop(ref for_loop) => for_loop.display(f, indentation),
+            Self::ForInLoop(ref for_in) => for_in.display(f, indentation),
             Self::ForOfLoop(ref for_of) => for_of.display(f, indentation),
             Self::This => write!(f, "this"),
             Self::Try(ref try_catch) => try_catch.display(f, indentation),
@@ -237,15 +327,28 @@ impl Node {
             Self::New(ref expr) => Display::fmt(expr, f),
             Self::GetConstField(ref get_const_field) => Display::fmt(get_const_field, f),
             Self::GetField(ref get_field) => Display::fmt(get_field, f),
+            Self::GetSuperConstField(ref get_field) => Display::fmt(get_field, f),
+            Self::GetSuperField(ref get_field) => Display::fmt(get_field, f),
+            Self::SuperCall(ref call) => Display::fmt(call, f),
+            Self::TaggedTemplate(ref template) => Display::fmt(template, f),
+            Self::TemplateLit(ref template) => Display::fmt(template, f),
             Self::WhileLoop(ref while_loop) => while_loop.display(f, indentation),
+            Self::With(ref with) => with.display(f, indentation),
             Self::DoWhileLoop(ref do_while) => do_while.display(f, indentation),
             Self::If(ref if_smt) => if_smt.display(f, indentation),
+            Self::Labelled(ref labelled) => labelled.display(f, indentation),
+            Self::ImportCall(ref call) => Display::fmt(call, f),
+            Self::ImportDecl(ref decl) => Display::fmt(decl, f),
+            Self::ExportDecl(ref decl) => Display::fmt(decl, f),
             Self::Switch(ref switch) => switch.display(f, indentation),
             Self::Object(ref obj) => obj.display(f, indentation),
+            Self::Optional(ref optional) => Display::fmt(optional, f),
             Self::ArrayDecl(ref arr) => Display::fmt(arr, f),
             Self::VarDeclList(ref list) => Display::fmt(list, f),
             Self::FunctionDecl(ref decl) => decl.display(f, indentation),
             Self::FunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::GeneratorDecl(ref decl) => decl.display(f, indentation),
+            Self::GeneratorExpr(ref expr) => expr.display(f, indentation),
             Self::ArrowFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::BinOp(ref op) => Display::fmt(op, f),
             Self::UnaryOp(ref op) => Display::fmt(op, f),
@@ -254,9 +357,11 @@ impl Node {
             Self::Assign(ref op) => Display::fmt(op, f),
             Self::LetDeclList(ref decl) => Display::fmt(decl, f),
             Self::ConstDeclList(ref decl) => Display::fmt(decl, f),
+            Self::AsyncArrowFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::AsyncFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::AsyncFunctionExpr(ref expr) => expr.display(f, indentation),
             Self::AwaitExpr(ref expr) => expr.display(f, indentation),
+            Self::YieldExpr(ref expr) => expr.display(f, indentation),
         }
     }
 }
@@ -265,10 +370,14 @@ impl Executable for Node {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Executable", "exec");
         match *self {
+            Node::AsyncArrowFunctionDecl(ref decl) => decl.run(interpreter),
             Node::AsyncFunctionDecl(ref decl) => decl.run(interpreter),
             Node::AsyncFunctionExpr(ref function_expr) => function_expr.run(interpreter),
             Node::AwaitExpr(ref expr) => expr.run(interpreter),
+            Node::YieldExpr(ref expr) => expr.run(interpreter),
             Node::Call(ref call) => call.run(interpreter),
+            Node::ClassDecl(ref decl) => decl.run(interpreter),
+            Node::ClassExpr(ref expr) => expr.run(interpreter),
             Node::Const(Const::Null) => Ok(Value::null()),
             Node::Const(Const::Num(num)) => Ok(Value::rational(num)),
             Node::Const(Const::Int(num)) => Ok(Value::integer(num)),
@@ -277,25 +386,39 @@ impl Executable for Node {
             // we can't move String from Const into value, because const is a garbage collected value
             // Which means Drop() get's called on Const, but str will be gone at that point.
             // Do Const values need to be garbage collected? We no longer need them once we've generated Values
-            Node::Const(Const::String(ref value)) => Ok(Value::string(value.to_string())),
+            Node::Const(Const::String(ref value)) => Ok(Value::string(value.clone())),
             Node::Const(Const::Bool(value)) => Ok(Value::boolean(value)),
             Node::Block(ref block) => block.run(interpreter),
             Node::Identifier(ref identifier) => identifier.run(interpreter),
             Node::GetConstField(ref get_const_field_node) => get_const_field_node.run(interpreter),
             Node::GetField(ref get_field) => get_field.run(interpreter),
+            Node::GetSuperConstField(ref get_field) => get_field.run(interpreter),
+            Node::GetSuperField(ref get_field) => get_field.run(interpreter),
+            Node::SuperCall(ref call) => call.run(interpreter),
+            Node::TaggedTemplate(ref template) => template.run(interpreter),
+            Node::TemplateLit(ref template) => template.run(interpreter),
             Node::WhileLoop(ref while_loop) => while_loop.run(interpreter),
+            Node::With(ref with) => with.run(interpreter),
             Node::DoWhileLoop(ref do_while) => do_while.run(interpreter),
             Node::ForLoop(ref for_loop) => for_loop.run(interpreter),
+            Node::ForInLoop(ref for_in_loop) => for_in_loop.run(interpreter),
             Node::ForOfLoop(ref for_of_loop) => for_of_loop.run(interpreter),
             Node::If(ref if_smt) => if_smt.run(interpreter),
+            Node::Labelled(ref labelled) => labelled.run(interpreter),
+            Node::ImportCall(ref call) => call.run(interpreter),
+            Node::ImportDecl(ref decl) => decl.run(interpreter),
+            Node::ExportDecl(ref decl) => decl.run(interpreter),
             Node::ConditionalOp(ref op) => op.run(interpreter),
             Node::Switch(ref switch) => switch.run(interpreter),
             Node::Object(ref obj) => obj.run(interpreter),
+            Node::Optional(ref optional) => optional.run(interpreter),
             Node::ArrayDecl(ref arr) => arr.run(interpreter),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionDecl(ref decl) => decl.run(interpreter),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionExpr(ref function_expr) => function_expr.run(interpreter),
+            Node::GeneratorDecl(ref decl) => decl.run(interpreter),
+            Node::GeneratorExpr(ref expr) => expr.run(interpreter),
             Node::ArrowFunctionDecl(ref decl) => decl.run(interpreter),
             Node::BinOp(ref op) => op.run(interpreter),
             Node::UnaryOp(ref op) => op.run(interpreter),
@@ -309,7 +432,11 @@ impl Executable for Node {
             Node::Spread(ref spread) => spread.run(interpreter),
             Node::This => {
                 // Will either return `this` binding or undefined
-                Ok(interpreter.realm().environment.get_this_binding())
+                interpreter
+                    .realm()
+                    .environment
+                    .get_this_binding()
+                    .map_err(|e| interpreter.construct_reference_error(e.to_string()))
             }
             Node::Try(ref try_node) => try_node.run(interpreter),
             Node::Break(ref break_node) => break_node.run(interpreter),
@@ -336,8 +463,8 @@ where
 
 /// "Formal parameter" is a fancy way of saying "function parameter".
 ///
-/// In the declaration of a function, the parameters must be identifiers,
-/// not any value like numbers, strings, or objects.
+/// In the declaration of a function, the parameters must be identifiers or destructuring
+/// patterns, not any value like numbers, strings, or objects.
 ///```text
 ///function foo(formalParameter1, formalParameter2) {
 ///}
@@ -352,27 +479,27 @@ where
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, Trace, Finalize)]
 pub struct FormalParameter {
-    name: Box<str>,
+    target: Target,
     init: Option<Node>,
     is_rest_param: bool,
 }
 
 impl FormalParameter {
     /// Creates a new formal parameter.
-    pub(in crate::syntax) fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
+    pub(in crate::syntax) fn new<T>(target: T, init: Option<Node>, is_rest_param: bool) -> Self
     where
-        N: Into<Box<str>>,
+        T: Into<Target>,
     {
         Self {
-            name: name.into(),
+            target: target.into(),
             init,
             is_rest_param,
         }
     }
 
-    /// Gets the name of the formal parameter.
-    pub fn name(&self) -> &str {
-        &self.name
+    /// Gets the target the argument is bound to.
+    pub fn target(&self) -> &Target {
+        &self.target
     }
 
     /// Gets the initialization node of the formal parameter, if any.
@@ -391,7 +518,7 @@ impl Display for FormalParameter {
         if self.is_rest_param {
             write!(f, "...")?;
         }
-        write!(f, "{}", self.name)?;
+        write!(f, "{}", self.target)?;
         if let Some(n) = self.init.as_ref() {
             write!(f, " = {}", n)?;
         }
@@ -399,6 +526,30 @@ impl Display for FormalParameter {
     }
 }
 
+// List of formal parameters wrapped with Rc, like `RcStatementList`, so that the parameters of
+// a function can be cloned cheaply along with its body when it is called.
+#[derive(Clone, Debug, Finalize, PartialEq)]
+pub struct RcFormalParameterList(Rc<[FormalParameter]>);
+
+impl Deref for RcFormalParameterList {
+    type Target = [FormalParameter];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Box<[FormalParameter]>> for RcFormalParameterList {
+    #[inline]
+    fn from(params: Box<[FormalParameter]>) -> Self {
+        Self(Rc::from(params))
+    }
+}
+
+// SAFETY: This is safe for types not containing any `Trace` types.
+unsafe impl Trace for RcFormalParameterList {
+    unsafe_empty_trace!();
+}
+
 /// A JavaScript property is a characteristic of an object, often describing attributes associated with a data structure.
 ///
 /// A property has a name (a string) and a value (primitive, method, or object reference).
@@ -494,6 +645,53 @@ impl PropertyDefinition {
     }
 }
 
+/// The name of a method or an accessor defined in a class body.
+///
+/// The name can either be a literal name, or computed from an expression at runtime.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PropertyName
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Computed_property_names
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub enum PropertyName {
+    /// A literal property name, such as an identifier, a string or a number.
+    Literal(Box<str>),
+
+    /// A computed property name, such as `[Symbol.iterator]`.
+    Computed(Node),
+}
+
+impl Display for PropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(name) => write!(f, "{}", name),
+            Self::Computed(node) => write!(f, "[{}]", node),
+        }
+    }
+}
+
+impl From<&str> for PropertyName {
+    fn from(name: &str) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
+impl From<Box<str>> for PropertyName {
+    fn from(name: Box<str>) -> Self {
+        Self::Literal(name)
+    }
+}
+
+impl From<Node> for PropertyName {
+    fn from(node: Node) -> Self {
+        Self::Computed(node)
+    }
+}
+
 /// Method definition kinds.
 ///
 /// Starting with ECMAScript 2015, a shorter syntax for method definitions on objects initializers is introduced.
@@ -548,7 +746,17 @@ pub enum MethodDefinitionKind {
     /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions#Method_definition_syntax
     Ordinary,
-    // TODO: support other method definition kinds, like `Generator`.
+
+    /// Generator methods are defined with an asterisk before their name, and behave like
+    /// generator functions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-GeneratorMethod
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Method_definitions#generator_methods
+    Generator,
 }
 
 unsafe impl Trace for MethodDefinitionKind {
@@ -0,0 +1,387 @@
+//! Module declaration nodes, and the dynamic `import()` call.
+
+use crate::{
+    builtins::promise::PromiseCapability,
+    environment::lexical_environment::VariableScope,
+    exec::Executable,
+    module,
+    syntax::ast::node::{join_nodes, Node},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The name of the local binding of the value exported by `export default`, when it isn't
+/// declared with a name.
+///
+/// As `default` is a reserved word, this binding can't be referenced by the module code.
+pub(crate) const DEFAULT_BINDING: &str = "default";
+
+/// An `import` declaration binds values exported by another module in the scope of the module.
+///
+/// Syntax:
+/// - `import defaultExport, { a, b as c } from "module";`
+/// - `import * as namespace from "module";`
+/// - `import "module";`
+///
+/// The bindings are created when the module is linked, so the declaration does nothing when it
+/// is evaluated.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportDecl {
+    default: Option<Box<str>>,
+    namespace: Option<Box<str>>,
+    specifiers: Box<[ImportSpecifier]>,
+    module: Box<str>,
+}
+
+impl ImportDecl {
+    /// Creates a new `ImportDecl` AST node.
+    pub(in crate::syntax) fn new<S>(
+        default: Option<Box<str>>,
+        namespace: Option<Box<str>>,
+        specifiers: S,
+        module: Box<str>,
+    ) -> Self
+    where
+        S: Into<Box<[ImportSpecifier]>>,
+    {
+        Self {
+            default,
+            namespace,
+            specifiers: specifiers.into(),
+            module,
+        }
+    }
+
+    /// Gets the local name the default export of the module is bound to, if any.
+    pub fn default(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    /// Gets the local name the namespace object of the module is bound to, if any.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Gets the named imports of the declaration.
+    pub fn specifiers(&self) -> &[ImportSpecifier] {
+        &self.specifiers
+    }
+
+    /// Gets the specifier of the imported module.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+}
+
+impl Executable for ImportDecl {
+    fn run(&self, _: &mut Context) -> Result<Value> {
+        Ok(Value::undefined())
+    }
+}
+
+impl fmt::Display for ImportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("import ")?;
+        let mut clauses = Vec::new();
+        if let Some(ref default) = self.default {
+            clauses.push(default.to_string());
+        }
+        if let Some(ref namespace) = self.namespace {
+            clauses.push(format!("* as {}", namespace));
+        }
+        if !self.specifiers.is_empty() {
+            let specifiers: Vec<_> = self.specifiers.iter().map(|s| s.to_string()).collect();
+            clauses.push(format!("{{ {} }}", specifiers.join(", ")));
+        }
+        if !clauses.is_empty() {
+            write!(f, "{} from ", clauses.join(", "))?;
+        }
+        write!(f, "{:?}", self.module)
+    }
+}
+
+impl From<ImportDecl> for Node {
+    fn from(decl: ImportDecl) -> Self {
+        Self::ImportDecl(decl)
+    }
+}
+
+/// A named import, as in `{ imported as local }`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportSpecifier {
+    imported: Box<str>,
+    local: Box<str>,
+}
+
+impl ImportSpecifier {
+    /// Creates a new named import.
+    pub(in crate::syntax) fn new<I, L>(imported: I, local: L) -> Self
+    where
+        I: Into<Box<str>>,
+        L: Into<Box<str>>,
+    {
+        Self {
+            imported: imported.into(),
+            local: local.into(),
+        }
+    }
+
+    /// Gets the name of the export of the imported module.
+    pub fn imported(&self) -> &str {
+        &self.imported
+    }
+
+    /// Gets the local name the export is bound to.
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+}
+
+impl fmt::Display for ImportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.imported == self.local {
+            write!(f, "{}", self.local)
+        } else {
+            write!(f, "{} as {}", self.imported, self.local)
+        }
+    }
+}
+
+/// An `export` declaration makes bindings of the module, or of other modules, available to the
+/// modules that import it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ExportDecl {
+    /// The export of declared bindings, as in `export const a = 1;` or `export function f() {}`.
+    Declaration(Box<Node>),
+
+    /// The default export of a function or class declaration, as in
+    /// `export default function () {}`.
+    ///
+    /// An anonymous declaration is named `default`.
+    DefaultDeclaration(Box<Node>),
+
+    /// The default export of the value of an expression, as in `export default 1 + 2;`.
+    DefaultExpression(Box<Node>),
+
+    /// The export of a list of bindings, as in `export { a, b as c };`, or of a list of exports
+    /// of another module, as in `export { a, b as c } from "module";`.
+    Named {
+        specifiers: Box<[ExportSpecifier]>,
+        module: Option<Box<str>>,
+    },
+
+    /// The export of all the exports of another module, as in `export * from "module";`, or of
+    /// its namespace object, as in `export * as namespace from "module";`.
+    All {
+        alias: Option<Box<str>>,
+        module: Box<str>,
+    },
+}
+
+impl ExportDecl {
+    /// Gets the specifier of the module whose exports are exported again, if any.
+    pub fn module(&self) -> Option<&str> {
+        match self {
+            Self::Named { module, .. } => module.as_deref(),
+            Self::All { module, .. } => Some(module),
+            _ => None,
+        }
+    }
+}
+
+impl Executable for ExportDecl {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ExportDecl", "exec");
+        match self {
+            Self::Declaration(declaration) | Self::DefaultDeclaration(declaration) => {
+                declaration.run(interpreter)?;
+            }
+            Self::DefaultExpression(expression) => {
+                let value = expression.run(interpreter)?;
+                let environment = &mut interpreter.realm_mut().environment;
+                environment.create_immutable_binding(
+                    DEFAULT_BINDING.to_owned(),
+                    false,
+                    VariableScope::Block,
+                );
+                environment.initialize_binding(DEFAULT_BINDING, value);
+            }
+            Self::Named { .. } | Self::All { .. } => {}
+        }
+        Ok(Value::undefined())
+    }
+}
+
+impl fmt::Display for ExportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Declaration(declaration) => write!(f, "export {}", declaration),
+            Self::DefaultDeclaration(declaration) | Self::DefaultExpression(declaration) => {
+                write!(f, "export default {}", declaration)
+            }
+            Self::Named { specifiers, module } => {
+                f.write_str("export { ")?;
+                join_nodes(f, specifiers)?;
+                f.write_str(" }")?;
+                if let Some(module) = module {
+                    write!(f, " from {:?}", module)?;
+                }
+                Ok(())
+            }
+            Self::All { alias, module } => {
+                f.write_str("export * ")?;
+                if let Some(alias) = alias {
+                    write!(f, "as {} ", alias)?;
+                }
+                write!(f, "from {:?}", module)
+            }
+        }
+    }
+}
+
+impl From<ExportDecl> for Node {
+    fn from(decl: ExportDecl) -> Self {
+        Self::ExportDecl(decl)
+    }
+}
+
+/// A named export, as in `{ local as exported }`.
+///
+/// When the export is exported from another module, `local` is the name of the export of that
+/// module.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ExportSpecifier {
+    local: Box<str>,
+    exported: Box<str>,
+}
+
+impl ExportSpecifier {
+    /// Creates a new named export.
+    pub(in crate::syntax) fn new<L, E>(local: L, exported: E) -> Self
+    where
+        L: Into<Box<str>>,
+        E: Into<Box<str>>,
+    {
+        Self {
+            local: local.into(),
+            exported: exported.into(),
+        }
+    }
+
+    /// Gets the local name of the exported binding.
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+
+    /// Gets the name the binding is exported as.
+    pub fn exported(&self) -> &str {
+        &self.exported
+    }
+}
+
+impl fmt::Display for ExportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.local == self.exported {
+            write!(f, "{}", self.local)
+        } else {
+            write!(f, "{} as {}", self.local, self.exported)
+        }
+    }
+}
+
+/// A dynamic `import()` call loads a module, and returns a promise resolved with its namespace
+/// object once it is evaluated.
+///
+/// Unlike `import` declarations, it can be used in scripts.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-import-calls
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import#dynamic_imports
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportCall {
+    specifier: Box<Node>,
+}
+
+impl ImportCall {
+    /// Creates a new `ImportCall` AST node.
+    pub fn new<S>(specifier: S) -> Self
+    where
+        S: Into<Node>,
+    {
+        Self {
+            specifier: Box::new(specifier.into()),
+        }
+    }
+
+    /// Gets the expression of the specifier of the imported module.
+    pub fn specifier(&self) -> &Node {
+        &self.specifier
+    }
+}
+
+impl Executable for ImportCall {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ImportCall", "exec");
+        let specifier = self.specifier().run(interpreter)?;
+
+        let constructor = interpreter
+            .standard_objects()
+            .promise_object()
+            .constructor();
+        let capability = PromiseCapability::new(&constructor.into(), interpreter)?;
+
+        // The module is imported by the module whose code is running, if any.
+        let referrer = interpreter
+            .realm()
+            .environment
+            .environments()
+            .find_map(|env| env.borrow().get_module_key());
+
+        let namespace = specifier
+            .to_string(interpreter)
+            .and_then(|specifier| module::import(&specifier, referrer.as_deref(), interpreter));
+        match namespace {
+            Ok(namespace) => capability.resolve(namespace, interpreter)?,
+            Err(error) => capability.reject(error, interpreter)?,
+        };
+        Ok(capability.promise().clone())
+    }
+}
+
+impl fmt::Display for ImportCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "import({})", self.specifier)
+    }
+}
+
+impl From<ImportCall> for Node {
+    fn from(call: ImportCall) -> Self {
+        Self::ImportCall(call)
+    }
+}
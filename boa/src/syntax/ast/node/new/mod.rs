@@ -1,6 +1,6 @@
 use crate::{
     exec::Executable,
-    syntax::ast::node::{Call, Node},
+    syntax::ast::node::{call::run_arguments, Call, Node},
     value::Value,
     BoaProfiler, Context, Result,
 };
@@ -34,12 +34,12 @@ pub struct New {
 impl New {
     /// Gets the name of the function call.
     pub fn expr(&self) -> &Node {
-        &self.call.expr()
+        self.call.expr()
     }
 
     /// Retrieves the arguments passed to the function.
     pub fn args(&self) -> &[Node] {
-        &self.call.args()
+        self.call.args()
     }
 }
 
@@ -47,16 +47,30 @@ impl Executable for New {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("New", "exec");
 
-        let func_object = self.expr().run(interpreter)?;
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            v_args.push(arg.run(interpreter)?);
-        }
+        // The constructor is evaluated at step 0, and the arguments from step 1.
+        let (func_object, v_args) = match interpreter.resume_state(self) {
+            Some(state) if state.step > 0 => {
+                let mut v_args = state.into_vec();
+                let func_object = v_args.remove(0);
+                (func_object, v_args)
+            }
+            _ => (
+                interpreter.run_step(self, 0, Vec::new, |ctx| self.expr().run(ctx))?,
+                Vec::with_capacity(self.args().len()),
+            ),
+        };
+        let v_args = run_arguments(
+            self,
+            self.args(),
+            1,
+            std::slice::from_ref(&func_object),
+            v_args,
+            interpreter,
+        )?;
 
         match func_object {
-            Value::Object(ref object) => object.construct(&v_args, interpreter),
-            _ => interpreter
-                .throw_type_error(format!("{} is not a constructor", self.expr().to_string(),)),
+            Value::Object(ref object) => object.construct(&v_args, &func_object, interpreter),
+            _ => interpreter.throw_type_error(format!("{} is not a constructor", self.expr(),)),
         }
     }
 }
@@ -1,8 +1,11 @@
 //! Object node.
 
 use crate::{
+    builtins::function::FunctionFlags,
     exec::Executable,
-    syntax::ast::node::{MethodDefinitionKind, Node, PropertyDefinition},
+    object::GcObject,
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor},
+    syntax::ast::node::{join_nodes, MethodDefinitionKind, Node, PropertyDefinition},
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -11,6 +14,9 @@ use std::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// Objects in JavaScript may be defined as an unordered collection of related data, of
 /// primitive or reference types, in the form of “key: value” pairs.
 ///
@@ -60,9 +66,21 @@ impl Object {
                 PropertyDefinition::SpreadObject(key) => {
                     write!(f, "{}    ...{},", indent, key)?;
                 }
-                PropertyDefinition::MethodDefinition(_kind, _key, _node) => {
-                    // TODO: Implement display for PropertyDefinition::MethodDefinition.
-                    unimplemented!("Display for PropertyDefinition::MethodDefinition");
+                PropertyDefinition::MethodDefinition(kind, key, node) => {
+                    let prefix = match kind {
+                        MethodDefinitionKind::Get => "get ",
+                        MethodDefinitionKind::Set => "set ",
+                        MethodDefinitionKind::Generator => "*",
+                        MethodDefinitionKind::Ordinary => "",
+                    };
+                    write!(f, "{}    {}{}(", indent, prefix, key)?;
+                    join_nodes(f, node.parameters())?;
+                    f.write_str(") {")?;
+                    for node in node.body() {
+                        node.display(f, indent + 1)?;
+                        f.write_str(";")?;
+                    }
+                    f.write_str("},")?;
                 }
             }
         }
@@ -72,28 +90,96 @@ impl Object {
 
 impl Executable for Object {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let global_val = &interpreter
-            .realm()
-            .environment
-            .get_global_object()
-            .expect("Could not get the global object");
-        let obj = Value::new_object(Some(global_val));
+        // Each property is evaluated at the step of its index.
+        let (first, obj) = if let Some(state) = interpreter.resume_state(self) {
+            let step = state.step;
+            let [obj] = state.into_values();
+            (step, obj)
+        } else {
+            let global_val = &interpreter
+                .realm()
+                .environment
+                .get_global_object()
+                .expect("Could not get the global object");
+            (0, Value::new_object(Some(global_val)))
+        };
 
-        // TODO: Implement the rest of the property types.
-        for property in self.properties().iter() {
+        let object = obj.as_object().expect("object literal is not an object");
+        for (i, property) in self.properties().iter().enumerate().skip(first) {
+            let values = || vec![obj.clone()];
             match property {
+                PropertyDefinition::IdentifierReference(key) => {
+                    let value = interpreter.get_binding_value(key)?;
+                    obj.set_property(key.clone(), DataDescriptor::new(value, Attribute::all()));
+                }
                 PropertyDefinition::Property(key, value) => {
-                    obj.set_field(key.clone(), value.run(interpreter)?);
+                    let value = interpreter.run_step(self, i, values, |ctx| value.run(ctx))?;
+                    obj.set_property(key.clone(), DataDescriptor::new(value, Attribute::all()));
                 }
-                PropertyDefinition::MethodDefinition(kind, name, func) => {
-                    if let MethodDefinitionKind::Ordinary = kind {
-                        obj.set_field(name.clone(), func.run(interpreter)?);
-                    } else {
-                        // TODO: Implement other types of MethodDefinitionKinds.
-                        unimplemented!("other types of property method definitions.");
+                PropertyDefinition::MethodDefinition(kind, name, func) => match kind {
+                    MethodDefinitionKind::Ordinary => {
+                        let func = interpreter.run_step(self, i, values, |ctx| func.run(ctx))?;
+                        obj.set_property(name.clone(), DataDescriptor::new(func, Attribute::all()));
+                    }
+                    MethodDefinitionKind::Generator => {
+                        let generator = interpreter.create_function(
+                            func.parameters().to_vec(),
+                            func.statement_list().clone(),
+                            FunctionFlags::CALLABLE | FunctionFlags::GENERATOR,
+                        );
+                        generator.set_property(
+                            "name",
+                            DataDescriptor::new(
+                                Value::from(name.as_ref()),
+                                Attribute::CONFIGURABLE,
+                            ),
+                        );
+                        obj.set_property(
+                            name.clone(),
+                            DataDescriptor::new(generator, Attribute::all()),
+                        );
                     }
+                    MethodDefinitionKind::Get | MethodDefinitionKind::Set => {
+                        let func = interpreter.run_step(self, i, values, |ctx| func.run(ctx))?;
+                        let mut func = func
+                            .as_object()
+                            .expect("accessor function is not an object");
+                        let (getter, setter) =
+                            match object.ordinary_get_own_property(&name.clone().into()) {
+                                Some(PropertyDescriptor::Accessor(ref accessor)) => {
+                                    (accessor.getter().cloned(), accessor.setter().cloned())
+                                }
+                                _ => (None, None),
+                            };
+                        let (getter, setter) = if *kind == MethodDefinitionKind::Get {
+                            func.insert_property(
+                                "name",
+                                format!("get {}", name),
+                                Attribute::CONFIGURABLE,
+                            );
+                            (Some(func), setter)
+                        } else {
+                            func.insert_property(
+                                "name",
+                                format!("set {}", name),
+                                Attribute::CONFIGURABLE,
+                            );
+                            (getter, Some(func))
+                        };
+                        obj.set_property(
+                            name.clone(),
+                            AccessorDescriptor::new(
+                                getter,
+                                setter,
+                                Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                            ),
+                        );
+                    }
+                },
+                PropertyDefinition::SpreadObject(source) => {
+                    let source = interpreter.run_step(self, i, values, |ctx| source.run(ctx))?;
+                    copy_data_properties(&object, &source, interpreter)?;
                 }
-                i => unimplemented!("{:?} type of property", i),
             }
         }
 
@@ -101,6 +187,34 @@ impl Executable for Object {
     }
 }
 
+/// Copies the own enumerable properties of `source` to `target`, as done by the spread of
+/// `source` in an object literal.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-copydataproperties
+fn copy_data_properties(target: &GcObject, source: &Value, context: &mut Context) -> Result<()> {
+    if source.is_null_or_undefined() {
+        return Ok(());
+    }
+    let source = source.to_object(context)?;
+    for key in source.own_property_keys(context)? {
+        if source
+            .get_own_property(&key, context)?
+            .is_some_and(|desc| desc.enumerable())
+        {
+            let value = source.get(&key, source.clone().into(), context)?;
+            target.clone().define_own_property(
+                key,
+                DataDescriptor::new(value, Attribute::all()).into(),
+                context,
+            )?;
+        }
+    }
+    Ok(())
+}
+
 impl fmt::Display for Object {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
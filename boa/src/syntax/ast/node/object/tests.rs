@@ -0,0 +1,162 @@
+use crate::{exec, forward, Context};
+
+#[test]
+fn object_literal_getter_setter() {
+    let mut engine = Context::new();
+    let init = r#"
+        let obj = {
+            _value: 1,
+            get value() {
+                return this._value * 2;
+            },
+            set value(value) {
+                this._value = value;
+            },
+        };
+        obj.value = 5;
+    "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "obj.value"), "10");
+    assert_eq!(forward(&mut engine, "obj._value"), "5");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(obj, 'value').get.name"
+        ),
+        "\"get value\""
+    );
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(obj, 'value').set.name"
+        ),
+        "\"set value\""
+    );
+}
+
+#[test]
+fn getter_receiver_is_inheriting_object() {
+    let scenario = r#"
+        let proto = {
+            get name() {
+                return this._name;
+            },
+            set name(name) {
+                this._name = name;
+            },
+        };
+        let child = Object.create(proto);
+        child.name = "child";
+        [child.name, child.hasOwnProperty("_name"), proto._name].map(String).join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"child,true,undefined\"");
+}
+
+#[test]
+fn getter_return_does_not_end_caller() {
+    let scenario = r#"
+        let obj = {
+            get value() {
+                return 1;
+            },
+        };
+        let value = obj.value;
+        value + 1;
+    "#;
+
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn getter_errors_are_catchable() {
+    let scenario = r#"
+        let obj = {
+            get value() {
+                throw new Error("getter");
+            },
+        };
+        try {
+            obj.value;
+        } catch (e) {
+            e.message;
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), "\"getter\"");
+}
+
+#[test]
+fn inherited_non_writable_property_is_not_shadowed() {
+    let scenario = r#"
+        let proto = {};
+        Object.defineProperty(proto, "value", { value: 1 });
+        let child = Object.create(proto);
+        child.value = 2;
+        [child.value, child.hasOwnProperty("value")].join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,false\"");
+}
+
+#[test]
+fn object_literal_defines_properties() {
+    let scenario = r#"
+        let calls = 0;
+        Object.defineProperty(Object.prototype, "value", {
+            set(value) {
+                calls += 1;
+            },
+            configurable: true,
+        });
+        let obj = { value: 1 };
+        delete Object.prototype.value;
+        [obj.value, calls].join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,0\"");
+}
+
+#[test]
+fn object_literal_method_named_get() {
+    let scenario = r#"
+        let obj = {
+            get() {
+                return "get";
+            },
+            set: 1,
+        };
+        obj.get() + obj.set;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"get1\"");
+}
+
+#[test]
+fn object_spread() {
+    let mut engine = Context::new();
+    let init = r#"
+        let source = {
+            a: 1,
+            get b() {
+                return this.a + 1;
+            },
+        };
+        Object.defineProperty(source, "hidden", { value: 3 });
+        let obj = { a: 0, ...source, c: 3, ...null, ...undefined };
+    "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "obj.a"), "1");
+    assert_eq!(forward(&mut engine, "obj.b"), "2");
+    assert_eq!(
+        forward(
+            &mut engine,
+            "Object.getOwnPropertyDescriptor(obj, 'b').value"
+        ),
+        "2"
+    );
+    assert_eq!(forward(&mut engine, "obj.c"), "3");
+    assert_eq!(forward(&mut engine, "obj.hidden"), "undefined");
+}
@@ -1,5 +1,9 @@
 use crate::{
-    environment::lexical_environment::VariableScope, exec::Executable, syntax::ast::node::Node,
+    exec::Executable,
+    syntax::ast::node::{
+        pattern::{BindingKind, Target},
+        Node,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -22,7 +26,7 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Assign {
-    lhs: Box<Node>,
+    lhs: Box<Target>,
     rhs: Box<Node>,
 }
 
@@ -30,7 +34,7 @@ impl Assign {
     /// Creates an `Assign` AST node.
     pub(in crate::syntax) fn new<L, R>(lhs: L, rhs: R) -> Self
     where
-        L: Into<Node>,
+        L: Into<Target>,
         R: Into<Node>,
     {
         Self {
@@ -40,7 +44,7 @@ impl Assign {
     }
 
     /// Gets the left hand side of the assignment operation.
-    pub fn lhs(&self) -> &Node {
+    pub fn lhs(&self) -> &Target {
         &self.lhs
     }
 
@@ -53,35 +57,21 @@ impl Assign {
 impl Executable for Assign {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Assign", "exec");
-        let val = self.rhs().run(interpreter)?;
-        match self.lhs() {
-            Node::Identifier(ref name) => {
-                let environment = &mut interpreter.realm_mut().environment;
-
-                if environment.has_binding(name.as_ref()) {
-                    // Binding already exists
-                    environment.set_mutable_binding(name.as_ref(), val.clone(), true);
-                } else {
-                    environment.create_mutable_binding(
-                        name.as_ref().to_owned(),
-                        true,
-                        VariableScope::Function,
-                    );
-                    environment.initialize_binding(name.as_ref(), val.clone());
-                }
-            }
-            Node::GetConstField(ref get_const_field) => {
-                let val_obj = get_const_field.obj().run(interpreter)?;
-                val_obj.set_field(get_const_field.field(), val.clone());
+        // The right hand side is evaluated at step 0, then it is assigned to the target at
+        // step 1.
+        let val = match interpreter.resume_state(self) {
+            Some(state) if state.step > 0 => {
+                let [val] = state.into_values();
+                val
             }
-            Node::GetField(ref get_field) => {
-                let object = get_field.obj().run(interpreter)?;
-                let field = get_field.field().run(interpreter)?;
-                let key = field.to_property_key(interpreter)?;
-                object.set_field(key, val.clone());
-            }
-            _ => (),
-        }
+            _ => interpreter.run_step(self, 0, Vec::new, |ctx| self.rhs().run(ctx))?,
+        };
+        interpreter.run_step(
+            self,
+            1,
+            || vec![val.clone()],
+            |ctx| self.lhs().bind(val.clone(), BindingKind::Assignment, ctx),
+        )?;
         Ok(val)
     }
 }
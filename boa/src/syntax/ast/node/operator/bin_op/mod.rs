@@ -71,16 +71,42 @@ impl BinOp {
             AssignOp::Shl => x.shl(&y, interpreter),
             AssignOp::Shr => x.shr(&y, interpreter),
             AssignOp::Ushr => x.ushr(&y, interpreter),
+            AssignOp::BoolAnd | AssignOp::BoolOr | AssignOp::Coalesce => Ok(y),
         }
     }
+
+    /// Returns whether an assignment with the operator `op` leaves its target with its current
+    /// value `x`, without evaluating its right hand side.
+    ///
+    /// This is the case of the logical assignment operators when they short-circuit.
+    fn short_circuits(op: AssignOp, x: &Value) -> bool {
+        match op {
+            AssignOp::BoolAnd => !x.to_boolean(),
+            AssignOp::BoolOr => x.to_boolean(),
+            AssignOp::Coalesce => !x.is_null_or_undefined(),
+            _ => false,
+        }
+    }
+
+    /// Evaluates both operands, the left hand side at step 0 and the right hand side at step 1.
+    fn run_operands(&self, interpreter: &mut Context) -> Result<(Value, Value)> {
+        let x = match interpreter.resume_state(self) {
+            Some(state) if state.step > 0 => {
+                let [x] = state.into_values();
+                x
+            }
+            _ => interpreter.run_step(self, 0, Vec::new, |ctx| self.lhs().run(ctx))?,
+        };
+        let y = interpreter.run_step(self, 1, || vec![x.clone()], |ctx| self.rhs().run(ctx))?;
+        Ok((x, y))
+    }
 }
 
 impl Executable for BinOp {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         match self.op() {
             op::BinOp::Num(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 match op {
                     NumOp::Add => x.add(&y, interpreter),
                     NumOp::Sub => x.sub(&y, interpreter),
@@ -91,8 +117,7 @@ impl Executable for BinOp {
                 }
             }
             op::BinOp::Bit(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 match op {
                     BitOp::And => x.bitand(&y, interpreter),
                     BitOp::Or => x.bitor(&y, interpreter),
@@ -103,8 +128,7 @@ impl Executable for BinOp {
                 }
             }
             op::BinOp::Comp(op) => {
-                let x = self.lhs().run(interpreter)?;
-                let y = self.rhs().run(interpreter)?;
+                let (x, y) = self.run_operands(interpreter)?;
                 Ok(Value::from(match op {
                     CompOp::Equal => x.equals(&y, interpreter)?,
                     CompOp::NotEqual => !x.equals(&y, interpreter)?,
@@ -122,64 +146,157 @@ impl Executable for BinOp {
                             ));
                         }
                         let key = x.to_property_key(interpreter)?;
-                        interpreter.has_property(&y, &key)
+                        interpreter.has_property(&y, &key)?
                     }
                     CompOp::InstanceOf => {
-                        if !y.is_object() {
-                            return interpreter.throw_type_error(format!(
-                                "right-hand side of 'instanceof' should be an object, got {}",
-                                y.get_type().as_str()
-                            ));
-                        }
-
                         // spec: https://tc39.es/ecma262/#sec-instanceofoperator
-                        todo!("instanceof operator")
+                        let constructor = match y {
+                            Value::Object(ref object) => object.clone(),
+                            _ => {
+                                return interpreter.throw_type_error(format!(
+                                    "right-hand side of 'instanceof' should be an object, got {}",
+                                    y.get_type().as_str()
+                                ))
+                            }
+                        };
+                        let has_instance = interpreter.well_known_symbols().has_instance_symbol();
+                        if let Some(handler) = y.get_method(has_instance, interpreter)? {
+                            interpreter.call(&handler, &y, &[x])?.to_boolean()
+                        } else if !constructor.is_callable() && !constructor.is_constructable() {
+                            return interpreter.throw_type_error(
+                                "right-hand side of 'instanceof' is not callable",
+                            );
+                        } else {
+                            constructor.ordinary_has_instance(&x, interpreter)?
+                        }
                     }
                 }))
             }
             op::BinOp::Log(op) => {
-                // turn a `Value` into a `bool`
-                let to_bool = |value| bool::from(&value);
-                Ok(match op {
-                    LogOp::And => Value::from(
-                        to_bool(self.lhs().run(interpreter)?)
-                            && to_bool(self.rhs().run(interpreter)?),
-                    ),
-                    LogOp::Or => Value::from(
-                        to_bool(self.lhs().run(interpreter)?)
-                            || to_bool(self.rhs().run(interpreter)?),
-                    ),
-                })
+                // The right hand side is only evaluated, at step 1, if the left hand side doesn't
+                // decide the result.
+                if interpreter
+                    .resume_state(self)
+                    .is_none_or(|state| state.step == 0)
+                {
+                    let x = interpreter.run_step(self, 0, Vec::new, |ctx| self.lhs().run(ctx))?;
+                    let decided = match op {
+                        LogOp::And => !x.to_boolean(),
+                        LogOp::Or => x.to_boolean(),
+                        LogOp::Coalesce => !x.is_null_or_undefined(),
+                    };
+                    if decided {
+                        return Ok(x);
+                    }
+                }
+                interpreter.run_step(self, 1, Vec::new, |ctx| self.rhs().run(ctx))
             }
             op::BinOp::Assign(op) => match self.lhs() {
                 Node::Identifier(ref name) => {
-                    let v_a = interpreter
-                        .realm()
-                        .environment
-                        .get_binding_value(name.as_ref())
-                        .ok_or_else(|| interpreter.construct_reference_error(name.as_ref()))?;
-                    let v_b = self.rhs().run(interpreter)?;
+                    let v_a = if let Some(state) = interpreter.resume_state(self) {
+                        let [v_a] = state.into_values();
+                        v_a
+                    } else {
+                        let v_a = interpreter.get_binding_value(name.as_ref())?;
+                        if Self::short_circuits(op, &v_a) {
+                            return Ok(v_a);
+                        }
+                        v_a
+                    };
+                    let v_b = interpreter.run_step(
+                        self,
+                        0,
+                        || vec![v_a.clone()],
+                        |ctx| self.rhs().run(ctx),
+                    )?;
                     let value = Self::run_assign(op, v_a, v_b, interpreter)?;
-                    interpreter.realm_mut().environment.set_mutable_binding(
-                        name.as_ref(),
-                        value.clone(),
-                        true,
-                    );
+                    interpreter.set_mutable_binding(name.as_ref(), value.clone())?;
                     Ok(value)
                 }
                 Node::GetConstField(ref get_const_field) => {
-                    let v_r_a = get_const_field.obj().run(interpreter)?;
-                    let v_a = v_r_a.get_field(get_const_field.field());
-                    let v_b = self.rhs().run(interpreter)?;
+                    let (v_r_a, v_a) = match interpreter.resume_state(self) {
+                        Some(state) if state.step > 0 => {
+                            let [v_r_a, v_a] = state.into_values();
+                            (v_r_a, v_a)
+                        }
+                        _ => {
+                            let v_r_a = interpreter.run_step(self, 0, Vec::new, |ctx| {
+                                get_const_field.obj().run(ctx)
+                            })?;
+                            let v_a = v_r_a.get_field(get_const_field.field(), interpreter)?;
+                            if Self::short_circuits(op, &v_a) {
+                                return Ok(v_a);
+                            }
+                            (v_r_a, v_a)
+                        }
+                    };
+                    let v_b = interpreter.run_step(
+                        self,
+                        1,
+                        || vec![v_r_a.clone(), v_a.clone()],
+                        |ctx| self.rhs().run(ctx),
+                    )?;
                     let value = Self::run_assign(op, v_a, v_b, interpreter)?;
-                    v_r_a.set_field(get_const_field.field(), value.clone());
+                    v_r_a.put_field_with_receiver(
+                        get_const_field.field(),
+                        value.clone(),
+                        &v_r_a,
+                        interpreter,
+                    )?;
+                    Ok(value)
+                }
+                Node::GetField(ref get_field) => {
+                    // The object is evaluated at step 0, the key at step 1 and the right hand
+                    // side at step 2.
+                    let (v_r_a, key, v_a) = match interpreter.resume_state(self) {
+                        Some(state) if state.step > 1 => {
+                            let [v_r_a, key, v_a] = state.into_values();
+                            (v_r_a, key.to_property_key(interpreter)?, v_a)
+                        }
+                        state => {
+                            let v_r_a = match state {
+                                Some(state) if state.step == 1 => {
+                                    let [v_r_a] = state.into_values();
+                                    v_r_a
+                                }
+                                _ => interpreter
+                                    .run_step(self, 0, Vec::new, |ctx| get_field.obj().run(ctx))?,
+                            };
+                            let key = interpreter
+                                .run_step(
+                                    self,
+                                    1,
+                                    || vec![v_r_a.clone()],
+                                    |ctx| get_field.field().run(ctx),
+                                )?
+                                .to_property_key(interpreter)?;
+                            let v_a = v_r_a.get_field(key.clone(), interpreter)?;
+                            if Self::short_circuits(op, &v_a) {
+                                return Ok(v_a);
+                            }
+                            (v_r_a, key, v_a)
+                        }
+                    };
+                    let v_b = interpreter.run_step(
+                        self,
+                        2,
+                        || vec![v_r_a.clone(), Value::from(&key), v_a.clone()],
+                        |ctx| self.rhs().run(ctx),
+                    )?;
+                    let value = Self::run_assign(op, v_a, v_b, interpreter)?;
+                    v_r_a.put_field_with_receiver(key, value.clone(), &v_r_a, interpreter)?;
                     Ok(value)
                 }
                 _ => Ok(Value::undefined()),
             },
             op::BinOp::Comma => {
-                self.lhs().run(interpreter)?;
-                Ok(self.rhs().run(interpreter)?)
+                if interpreter
+                    .resume_state(self)
+                    .is_none_or(|state| state.step == 0)
+                {
+                    interpreter.run_step(self, 0, Vec::new, |ctx| self.lhs().run(ctx))?;
+                }
+                interpreter.run_step(self, 1, Vec::new, |ctx| self.rhs().run(ctx))
             }
         }
     }
@@ -26,3 +26,97 @@ fn assignmentoperator_rhs_throws_error() {
 
     assert_eq!(&exec(scenario), "\"ReferenceError: b is not defined\"");
 }
+
+#[test]
+fn logical_operators_return_operand() {
+    let scenario = r#"
+        [0 || "a", 1 || "a", 0 && "b", 1 && "b"].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"a,1,0,b\"");
+}
+
+#[test]
+fn nullish_coalescing() {
+    let scenario = r#"
+        let calls = 0;
+        function rhs() {
+            calls += 1;
+            return "rhs";
+        }
+        [null ?? 1, undefined ?? 2, 0 ?? rhs(), "" ?? rhs(), false ?? rhs(), calls].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,0,,false,0\"");
+}
+
+#[test]
+fn logical_assignment() {
+    let scenario = r#"
+        let calls = 0;
+        function rhs(value) {
+            calls += 1;
+            return value;
+        }
+        let a = 0, b = 1, c = null, d = 0;
+        a ||= rhs(2);
+        b ||= rhs(3);
+        c ??= rhs(4);
+        d ??= rhs(5);
+        let e = 1, f = 0;
+        e &&= rhs(6);
+        f &&= rhs(7);
+        [a, b, c, d, e, f, calls].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"2,1,4,0,6,0,3\"");
+}
+
+#[test]
+fn logical_assignment_to_property() {
+    let scenario = r#"
+        let obj = { a: null, b: 1 };
+        let key = "b";
+        obj.a ??= "x";
+        obj[key] ||= "y";
+        obj["c"] ??= "z";
+        [obj.a, obj.b, obj.c].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"x,1,z\"");
+}
+
+#[test]
+fn logical_assignment_to_property_short_circuits() {
+    let scenario = r#"
+        let calls = 0;
+        function rhs() {
+            calls += 1;
+            return "rhs";
+        }
+        let obj = { a: 1, b: 0 };
+        let key = 0;
+        obj.a ||= rhs();
+        obj.b &&= rhs();
+        [1, 2][key++] ??= rhs();
+        [obj.a, obj.b, key, calls].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,0,1,0\"");
+}
+
+#[test]
+fn compound_assignment_to_computed_property() {
+    let scenario = r#"
+        let obj = { a: 1 };
+        let calls = 0;
+        function key() {
+            calls += 1;
+            return "a";
+        }
+        obj[key()] += 2;
+        [obj.a, calls].join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"3,1\"");
+}
@@ -1,6 +1,11 @@
 use crate::{
     exec::Executable,
-    syntax::ast::{node::Node, op},
+    object::GcObject,
+    property::PropertyKey,
+    syntax::ast::{
+        node::{GetField, Node},
+        op,
+    },
     Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -45,38 +50,166 @@ impl UnaryOp {
     pub fn target(&self) -> &Node {
         self.target.as_ref()
     }
-}
 
-impl Executable for UnaryOp {
-    fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let x = self.target().run(interpreter)?;
+    /// Evaluates the object and the key of a computed member target, the object at step 0 and
+    /// the key at step 1.
+    fn run_get_field(
+        &self,
+        get_field: &GetField,
+        interpreter: &mut Context,
+    ) -> Result<(Value, Value)> {
+        let obj = match interpreter.resume_state(self) {
+            Some(state) if state.step > 0 => {
+                let [obj] = state.into_values();
+                obj
+            }
+            _ => interpreter.run_step(self, 0, Vec::new, |ctx| get_field.obj().run(ctx))?,
+        };
+        let field = interpreter.run_step(
+            self,
+            1,
+            || vec![obj.clone()],
+            |ctx| get_field.field().run(ctx),
+        )?;
+        Ok((obj, field))
+    }
+
+    /// Runs an increment or decrement operator, evaluating its target only once.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-update-expressions
+    fn run_update(&self, interpreter: &mut Context) -> Result<Value> {
+        let update = |old_value: f64| match self.op() {
+            op::UnaryOp::IncrementPost | op::UnaryOp::IncrementPre => old_value + 1.0,
+            _ => old_value - 1.0,
+        };
+
+        let old_value = match *self.target() {
+            Node::Identifier(ref name) => {
+                let old_value = self.target().run(interpreter)?.to_number(interpreter)?;
+                interpreter.set_mutable_binding(name.as_ref(), update(old_value).into())?;
+                old_value
+            }
+            Node::GetConstField(ref get_const_field) => {
+                let obj = get_const_field.obj().run(interpreter)?;
+                let old_value = obj
+                    .get_field(get_const_field.field(), interpreter)?
+                    .to_number(interpreter)?;
+                obj.put_field_with_receiver(
+                    get_const_field.field(),
+                    update(old_value),
+                    &obj,
+                    interpreter,
+                )?;
+                old_value
+            }
+            Node::GetField(ref get_field) => {
+                let (obj, field) = self.run_get_field(get_field, interpreter)?;
+                let key = field.to_property_key(interpreter)?;
+                let old_value = obj
+                    .get_field(key.clone(), interpreter)?
+                    .to_number(interpreter)?;
+                obj.put_field_with_receiver(key, update(old_value), &obj, interpreter)?;
+                old_value
+            }
+            _ => {
+                return interpreter.throw_reference_error(format!(
+                    "invalid update expression target {}",
+                    self.target()
+                ))
+            }
+        };
 
         Ok(match self.op() {
-            op::UnaryOp::Minus => x.neg(interpreter)?,
-            op::UnaryOp::Plus => Value::from(x.to_number(interpreter)?),
-            op::UnaryOp::IncrementPost => {
-                let ret = x.clone();
-                let result = x.to_number(interpreter)? + 1.0;
-                interpreter.set_value(self.target(), result.into())?;
-                ret
+            op::UnaryOp::IncrementPost | op::UnaryOp::DecrementPost => old_value.into(),
+            _ => update(old_value).into(),
+        })
+    }
+
+    /// Deletes the property `key` of `object`, which throws a `TypeError` in strict mode code if
+    /// the property is not configurable.
+    fn delete_property(
+        object: &mut GcObject,
+        key: &PropertyKey,
+        interpreter: &mut Context,
+    ) -> Result<Value> {
+        let deleted = object.delete(key, interpreter)?;
+        if !deleted && interpreter.executor().strict() {
+            return interpreter.throw_type_error(format!("Cannot delete property '{}'", key));
+        }
+        Ok(Value::boolean(deleted))
+    }
+
+    /// Runs the `delete` operator.
+    fn run_delete(&self, interpreter: &mut Context) -> Result<Value> {
+        Ok(match *self.target() {
+            Node::GetConstField(ref get_const_field) => {
+                let mut obj = get_const_field
+                    .obj()
+                    .run(interpreter)?
+                    .to_object(interpreter)?;
+                let key = PropertyKey::from(get_const_field.field());
+                Self::delete_property(&mut obj, &key, interpreter)?
             }
-            op::UnaryOp::IncrementPre => {
-                let result = x.to_number(interpreter)? + 1.0;
-                interpreter.set_value(self.target(), result.into())?
+            Node::GetField(ref get_field) => {
+                let (obj, field) = self.run_get_field(get_field, interpreter)?;
+                let mut obj = obj.to_object(interpreter)?;
+                let key = field.to_property_key(interpreter)?;
+                Self::delete_property(&mut obj, &key, interpreter)?
             }
-            op::UnaryOp::DecrementPost => {
-                let ret = x.clone();
-                let result = x.to_number(interpreter)? - 1.0;
-                interpreter.set_value(self.target(), result.into())?;
-                ret
+            // The bindings of a `with` statement are properties of its object.
+            Node::Identifier(ref name) => {
+                let base = interpreter
+                    .realm()
+                    .environment
+                    .get_with_base_object(name.as_ref());
+                if let Some(object) = base {
+                    let mut object = object.to_object(interpreter)?;
+                    let key = PropertyKey::from(name.as_ref());
+                    Value::boolean(object.delete(&key, interpreter)?)
+                } else {
+                    self.target().run(interpreter)?;
+                    Value::boolean(false)
+                }
             }
-            op::UnaryOp::DecrementPre => {
-                let result = x.to_number(interpreter)? - 1.0;
-                interpreter.set_value(self.target(), result.into())?
+            ref target => {
+                target.run(interpreter)?;
+                match target {
+                    Node::Identifier(_) => Value::boolean(false),
+                    Node::ArrayDecl(_)
+                    | Node::Block(_)
+                    | Node::Const(_)
+                    | Node::FunctionDecl(_)
+                    | Node::FunctionExpr(_)
+                    | Node::New(_)
+                    | Node::Object(_)
+                    | Node::Optional(_)
+                    | Node::TaggedTemplate(_)
+                    | Node::TemplateLit(_)
+                    | Node::UnaryOp(_) => Value::boolean(true),
+                    _ => panic!("SyntaxError: wrong delete argument {}", self),
+                }
             }
-            op::UnaryOp::Not => x.not(interpreter)?.into(),
+        })
+    }
+}
+
+impl Executable for UnaryOp {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        Ok(match self.op() {
+            op::UnaryOp::Minus => self.target().run(interpreter)?.neg(interpreter)?,
+            op::UnaryOp::Plus => {
+                Value::from(self.target().run(interpreter)?.to_number(interpreter)?)
+            }
+            op::UnaryOp::IncrementPost
+            | op::UnaryOp::IncrementPre
+            | op::UnaryOp::DecrementPost
+            | op::UnaryOp::DecrementPre => self.run_update(interpreter)?,
+            op::UnaryOp::Not => self.target().run(interpreter)?.not(interpreter)?.into(),
             op::UnaryOp::Tilde => {
-                let num_v_a = x.to_number(interpreter)?;
+                let num_v_a = self.target().run(interpreter)?.to_number(interpreter)?;
                 Value::from(if num_v_a.is_nan() {
                     -1
                 } else {
@@ -84,32 +217,12 @@ impl Executable for UnaryOp {
                     !(num_v_a as i32)
                 })
             }
-            op::UnaryOp::Void => Value::undefined(),
-            op::UnaryOp::Delete => match *self.target() {
-                Node::GetConstField(ref get_const_field) => Value::boolean(
-                    get_const_field
-                        .obj()
-                        .run(interpreter)?
-                        .remove_property(get_const_field.field()),
-                ),
-                Node::GetField(ref get_field) => {
-                    let obj = get_field.obj().run(interpreter)?;
-                    let field = &get_field.field().run(interpreter)?;
-                    let res = obj.remove_property(field.to_string(interpreter)?.as_str());
-                    return Ok(Value::boolean(res));
-                }
-                Node::Identifier(_) => Value::boolean(false),
-                Node::ArrayDecl(_)
-                | Node::Block(_)
-                | Node::Const(_)
-                | Node::FunctionDecl(_)
-                | Node::FunctionExpr(_)
-                | Node::New(_)
-                | Node::Object(_)
-                | Node::UnaryOp(_) => Value::boolean(true),
-                _ => panic!("SyntaxError: wrong delete argument {}", self),
-            },
-            op::UnaryOp::TypeOf => Value::from(x.get_type().as_str()),
+            op::UnaryOp::Void => {
+                self.target().run(interpreter)?;
+                Value::undefined()
+            }
+            op::UnaryOp::Delete => self.run_delete(interpreter)?,
+            op::UnaryOp::TypeOf => Value::from(self.target().run(interpreter)?.get_type().as_str()),
         })
     }
 }
@@ -0,0 +1,248 @@
+//! Optional chain nodes.
+
+use crate::{
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::{
+        call::{run_arguments, run_callee},
+        join_nodes, Node,
+    },
+    value::Type,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::{fmt, mem};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// An optional chain applies a chain of property accesses and calls to its target, which are
+/// skipped, with the whole chain evaluating to `undefined`, when the value an optional operation
+/// (`?.`) applies to is `null` or `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Optional {
+    target: Box<Node>,
+    chain: Box<[OptionalOperation]>,
+}
+
+/// The step at which the first operation of the chain is evaluated, after the target.
+const FIRST_OPERATION_STEP: usize = 2;
+
+impl Optional {
+    /// Creates a new `Optional` AST node.
+    pub fn new<T, C>(target: T, chain: C) -> Self
+    where
+        T: Into<Node>,
+        C: Into<Box<[OptionalOperation]>>,
+    {
+        Self {
+            target: Box::new(target.into()),
+            chain: chain.into(),
+        }
+    }
+
+    /// Gets the target of the optional chain.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the operations of the optional chain.
+    pub fn chain(&self) -> &[OptionalOperation] {
+        &self.chain
+    }
+
+    /// Returns the first step of the evaluation of the operation `index` of the chain.
+    fn operation_step(&self, index: usize) -> usize {
+        FIRST_OPERATION_STEP
+            + self.chain[..index]
+                .iter()
+                .map(|operation| operation.kind().steps())
+                .sum::<usize>()
+    }
+}
+
+impl Executable for Optional {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Optional", "exec");
+        // The target is evaluated at steps 0 and 1, as the callee of a call, so that the chain
+        // can call a method of its target. Each operation is then evaluated at its own steps,
+        // saving the `this` value of the next call, the current value and the arguments
+        // evaluated so far.
+        let (mut this, mut value, first, mut v_args) = match interpreter.resume_state(self) {
+            Some(state) if state.step >= FIRST_OPERATION_STEP => {
+                let step = state.step;
+                let mut values = state.into_vec();
+                let value = values.remove(1);
+                let this = values.remove(0);
+                let index = (0..self.chain.len())
+                    .find(|&index| step < self.operation_step(index + 1))
+                    .expect("resumed at a step outside of the chain");
+                (this, value, index, values)
+            }
+            state => {
+                let resumed = state.filter(|state| state.step == 1).map(|state| {
+                    let [obj] = state.into_values();
+                    obj
+                });
+                let (this, value) = run_callee(self, self.target(), resumed, interpreter)?;
+                (this, value, 0, Vec::new())
+            }
+        };
+
+        for (index, operation) in self.chain.iter().enumerate().skip(first) {
+            if operation.is_optional() && value.is_null_or_undefined() {
+                return Ok(Value::undefined());
+            }
+
+            let step = self.operation_step(index);
+            let (next_this, next_value) = match operation.kind() {
+                OptionalOperationKind::GetConstField(field) => {
+                    let obj = to_object(value, interpreter)?;
+                    let field_value =
+                        obj.get_field_with_receiver(field.as_ref(), &obj, interpreter)?;
+                    (obj, field_value)
+                }
+                OptionalOperationKind::GetField(field) => {
+                    let key = interpreter.run_step(
+                        self,
+                        step,
+                        || vec![this.clone(), value.clone()],
+                        |ctx| field.run(ctx),
+                    )?;
+                    let obj = to_object(value, interpreter)?;
+                    let key = key.to_property_key(interpreter)?;
+                    let field_value = obj.get_field_with_receiver(key, &obj, interpreter)?;
+                    (obj, field_value)
+                }
+                OptionalOperationKind::Call(args) => {
+                    let v_args = run_arguments(
+                        self,
+                        args,
+                        step,
+                        &[this.clone(), value.clone()],
+                        mem::take(&mut v_args),
+                        interpreter,
+                    )?;
+                    let result = interpreter.call(&value, &this, &v_args);
+
+                    // unset the early return flag
+                    interpreter
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+
+                    (interpreter.realm().global_obj.clone(), result?)
+                }
+            };
+            this = next_this;
+            value = next_value;
+        }
+
+        Ok(value)
+    }
+}
+
+/// Converts the value a property is accessed on to an object.
+fn to_object(value: Value, interpreter: &mut Context) -> Result<Value> {
+    if value.get_type() == Type::Object {
+        Ok(value)
+    } else {
+        Ok(Value::Object(value.to_object(interpreter)?))
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.target)?;
+        for operation in self.chain.iter() {
+            write!(f, "{}", operation)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Optional> for Node {
+    fn from(optional: Optional) -> Self {
+        Self::Optional(optional)
+    }
+}
+
+/// An operation of an optional chain.
+///
+/// The operations following `?.` are optional: they are skipped, along with the rest of the
+/// chain, if the value they apply to is `null` or `undefined`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct OptionalOperation {
+    kind: OptionalOperationKind,
+    optional: bool,
+}
+
+impl OptionalOperation {
+    /// Creates a new operation of an optional chain.
+    pub fn new(kind: OptionalOperationKind, optional: bool) -> Self {
+        Self { kind, optional }
+    }
+
+    /// Gets the kind of the operation.
+    pub fn kind(&self) -> &OptionalOperationKind {
+        &self.kind
+    }
+
+    /// Returns whether the operation follows `?.`.
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+}
+
+impl fmt::Display for OptionalOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.optional {
+            f.write_str("?.")?;
+        }
+        match &self.kind {
+            OptionalOperationKind::GetConstField(field) if self.optional => write!(f, "{}", field),
+            OptionalOperationKind::GetConstField(field) => write!(f, ".{}", field),
+            OptionalOperationKind::GetField(field) => write!(f, "[{}]", field),
+            OptionalOperationKind::Call(args) => {
+                f.write_str("(")?;
+                join_nodes(f, args)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The kind of an operation of an optional chain.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum OptionalOperationKind {
+    /// An access to a property with a constant name, as in `a?.b`.
+    GetConstField(Box<str>),
+
+    /// An access to a computed property, as in `a?.[b]`.
+    GetField(Node),
+
+    /// A call, as in `a?.()`.
+    Call(Box<[Node]>),
+}
+
+impl OptionalOperationKind {
+    /// Returns the number of steps taken by the evaluation of the operation.
+    fn steps(&self) -> usize {
+        match self {
+            Self::GetConstField(_) => 0,
+            Self::GetField(_) => 1,
+            Self::Call(args) => args.len(),
+        }
+    }
+}
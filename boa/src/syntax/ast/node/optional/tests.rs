@@ -0,0 +1,94 @@
+use crate::exec;
+
+#[test]
+fn optional_property_access() {
+    let scenario = r#"
+        let obj = { a: { b: 1 } };
+        let nothing = null;
+        [obj?.a.b, obj.a?.b, obj.c?.b, nothing?.a, obj?.["a"]?.["b"]].map(String).join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,1,undefined,undefined,1\"");
+}
+
+#[test]
+fn optional_chain_short_circuits_whole_chain() {
+    let scenario = r#"
+        let calls = 0;
+        function key() {
+            calls += 1;
+            return "b";
+        }
+        let a = undefined;
+        [a?.b.c(), a?.[key()].c, a?.b(key()), calls].map(String).join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"undefined,undefined,undefined,0\"");
+}
+
+#[test]
+fn optional_call() {
+    let scenario = r#"
+        let obj = {
+            value: 1,
+            method() {
+                return this.value;
+            },
+        };
+        let f;
+        [obj.method?.(), obj?.method(), obj.missing?.(), f?.(), obj?.["method"]?.()]
+            .map(String)
+            .join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,1,undefined,undefined,1\"");
+}
+
+#[test]
+fn optional_chain_does_not_catch_errors() {
+    let scenario = r#"
+        let obj = { a: null };
+        try {
+            obj?.a.b;
+        } catch (e) {
+            e instanceof TypeError;
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn optional_chain_parenthesized() {
+    let scenario = r#"
+        let a = null;
+        try {
+            (a?.b).c;
+        } catch (e) {
+            e instanceof TypeError;
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn optional_chain_in_generator() {
+    let scenario = r#"
+        let obj = {
+            add(a, b) {
+                return { sum: a + b };
+            },
+        };
+        function* gen() {
+            return obj?.add(yield 1, yield 2)?.[yield 3];
+        }
+        let it = gen();
+        it.next();
+        it.next(1);
+        it.next(2);
+        it.next("sum").value;
+        "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
@@ -0,0 +1,779 @@
+//! Destructuring pattern nodes, and the targets that values are bound or assigned to.
+
+use crate::{
+    builtins::{
+        iterable::{get_iterator, IteratorRecord},
+        Array,
+    },
+    environment::lexical_environment::VariableScope,
+    exec::Executable,
+    property::PropertyKey,
+    syntax::ast::node::{
+        join_nodes, super_node::super_base_and_this, Identifier, Node, PropertyName,
+    },
+    Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The way a value is bound to a target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BindingKind {
+    /// The target is assigned to, like the left hand side of an assignment expression.
+    Assignment,
+    /// The target is declared with `var`.
+    Var,
+    /// The target is declared with `let`, or is the parameter of a `catch` block.
+    Let,
+    /// The target is declared with `const`.
+    Const,
+    /// The target is a parameter of a function.
+    Parameter,
+}
+
+/// The target of a declaration, a parameter or an assignment, which a value is bound to.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForBinding
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum Target {
+    /// A single binding.
+    Identifier(Identifier),
+
+    /// A property access, which can only be the target of an assignment.
+    Expression(Box<Node>),
+
+    /// A destructuring pattern.
+    Pattern(Pattern),
+}
+
+impl Target {
+    /// Binds `value` to the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-bindinginitialization
+    pub(crate) fn bind(
+        &self,
+        value: Value,
+        kind: BindingKind,
+        interpreter: &mut Context,
+    ) -> Result<()> {
+        match self {
+            Self::Identifier(name) => bind_identifier(name.as_ref(), value, kind, interpreter),
+            Self::Expression(node) => self.assign_to_expression(node, value, interpreter),
+            Self::Pattern(Pattern::Object(pattern)) => pattern.bind(value, kind, interpreter),
+            Self::Pattern(Pattern::Array(pattern)) => pattern.bind(value, kind, interpreter),
+        }
+    }
+
+    /// Returns the names of the bindings declared by the target, in source order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-boundnames
+    pub fn bound_names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.collect_bound_names(&mut names);
+        names
+    }
+
+    /// Pushes the names of the bindings declared by the target to `names`.
+    fn collect_bound_names<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Self::Identifier(name) => names.push(name.as_ref()),
+            Self::Expression(_) => {}
+            Self::Pattern(Pattern::Object(pattern)) => {
+                for property in pattern.properties() {
+                    property.target().collect_bound_names(names);
+                }
+                if let Some(rest) = pattern.rest() {
+                    rest.collect_bound_names(names);
+                }
+            }
+            Self::Pattern(Pattern::Array(pattern)) => {
+                for element in pattern.elements().iter().flatten() {
+                    element.target().collect_bound_names(names);
+                }
+                if let Some(rest) = pattern.rest() {
+                    rest.collect_bound_names(names);
+                }
+            }
+        }
+    }
+
+    /// Assigns `value` to the property accessed by `node`.
+    fn assign_to_expression(
+        &self,
+        node: &Node,
+        value: Value,
+        interpreter: &mut Context,
+    ) -> Result<()> {
+        match node {
+            Node::GetConstField(ref get_const_field) => {
+                let object = get_const_field.obj().run(interpreter)?;
+                object.put_field_with_receiver(
+                    get_const_field.field(),
+                    value,
+                    &object,
+                    interpreter,
+                )?;
+            }
+            Node::GetField(ref get_field) => {
+                // The object is evaluated at step 0, and the key at step 1.
+                let object = match interpreter.resume_state(self) {
+                    Some(state) if state.step == 1 => {
+                        let [object] = state.into_values();
+                        object
+                    }
+                    _ => interpreter.run_step(self, 0, Vec::new, |ctx| get_field.obj().run(ctx))?,
+                };
+                let field = interpreter.run_step(
+                    self,
+                    1,
+                    || vec![object.clone()],
+                    |ctx| get_field.field().run(ctx),
+                )?;
+                let key = field.to_property_key(interpreter)?;
+                object.put_field_with_receiver(key, value, &object, interpreter)?;
+            }
+            Node::GetSuperConstField(ref get_super_field) => {
+                let (base, this) = super_base_and_this(interpreter)?;
+                base.put_field_with_receiver(get_super_field.field(), value, &this, interpreter)?;
+            }
+            Node::GetSuperField(ref get_super_field) => {
+                let field = get_super_field.field().run(interpreter)?;
+                let (base, this) = super_base_and_this(interpreter)?;
+                let key = field.to_property_key(interpreter)?;
+                base.put_field_with_receiver(key, value, &this, interpreter)?;
+            }
+            _ => {
+                return Err(interpreter.construct_syntax_error("invalid assignment target"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Binds `value` to the identifier `name`.
+fn bind_identifier(
+    name: &str,
+    value: Value,
+    kind: BindingKind,
+    interpreter: &mut Context,
+) -> Result<()> {
+    let environment = &mut interpreter.realm_mut().environment;
+    match kind {
+        // An assignment to an unresolvable name throws in strict mode code, and creates a
+        // property of the global object otherwise.
+        BindingKind::Assignment => interpreter.set_mutable_binding(name, value)?,
+        // The variable is declared in the function or the script, but the initializer assigns
+        // the binding the name resolves to, which can be a catch parameter or a property of the
+        // object of a `with` statement.
+        BindingKind::Var => {
+            environment.declare_var(name);
+            interpreter.set_mutable_binding(name, value)?;
+        }
+        BindingKind::Let => {
+            environment.create_mutable_binding(name.to_owned(), false, VariableScope::Block);
+            environment.initialize_binding(name, value);
+        }
+        BindingKind::Const => {
+            // Assigning to a constant throws a `TypeError` even in non-strict code.
+            environment.create_immutable_binding(name.to_owned(), true, VariableScope::Block);
+            environment.initialize_binding(name, value);
+        }
+        BindingKind::Parameter => {
+            // A parameter replaces the `arguments` object, or a previous parameter with the
+            // same name.
+            let function_env = environment.get_current_environment_ref();
+            if function_env.borrow().has_binding(name) {
+                function_env
+                    .borrow_mut()
+                    .set_mutable_binding(name, value, false)
+                    .expect("the parameter binding was just checked to exist");
+            } else {
+                function_env
+                    .borrow_mut()
+                    .create_mutable_binding(name.to_owned(), false);
+                function_env.borrow_mut().initialize_binding(name, value);
+            }
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(name) => fmt::Display::fmt(name, f),
+            Self::Expression(node) => fmt::Display::fmt(node, f),
+            Self::Pattern(pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+impl From<&str> for Target {
+    fn from(name: &str) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<Box<str>> for Target {
+    fn from(name: Box<str>) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<Identifier> for Target {
+    fn from(name: Identifier) -> Self {
+        Self::Identifier(name)
+    }
+}
+
+impl From<Pattern> for Target {
+    fn from(pattern: Pattern) -> Self {
+        Self::Pattern(pattern)
+    }
+}
+
+impl From<ObjectPattern> for Target {
+    fn from(pattern: ObjectPattern) -> Self {
+        Self::Pattern(Pattern::Object(pattern))
+    }
+}
+
+impl From<ArrayPattern> for Target {
+    fn from(pattern: ArrayPattern) -> Self {
+        Self::Pattern(Pattern::Array(pattern))
+    }
+}
+
+/// A destructuring pattern, which binds parts of a value to several targets.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum Pattern {
+    /// An object pattern, such as `{ a, b: c }`.
+    Object(ObjectPattern),
+
+    /// An array pattern, such as `[a, , b]`.
+    Array(ArrayPattern),
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Object(pattern) => fmt::Display::fmt(pattern, f),
+            Self::Array(pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+/// An object pattern binds properties of an object to targets.
+///
+/// The rest of the own enumerable properties of the object can be copied to a new object,
+/// which is bound to the rest target.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment#object_destructuring
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ObjectPattern {
+    properties: Box<[PatternProperty]>,
+    rest: Option<Box<Target>>,
+}
+
+impl ObjectPattern {
+    /// Creates a new object pattern.
+    pub(in crate::syntax) fn new<P>(properties: P, rest: Option<Target>) -> Self
+    where
+        P: Into<Box<[PatternProperty]>>,
+    {
+        Self {
+            properties: properties.into(),
+            rest: rest.map(Box::new),
+        }
+    }
+
+    /// Gets the properties bound by the pattern.
+    pub fn properties(&self) -> &[PatternProperty] {
+        &self.properties
+    }
+
+    /// Gets the target of the rest of the properties, if any.
+    pub fn rest(&self) -> Option<&Target> {
+        self.rest.as_deref()
+    }
+
+    /// Binds the properties of `value` to the targets of the pattern.
+    fn bind(&self, value: Value, kind: BindingKind, interpreter: &mut Context) -> Result<()> {
+        let object = Value::from(value.to_object(interpreter)?);
+
+        // Each property is bound from step `3 * i`: its key is computed at the first step, its
+        // default value at the second one and its target is bound at the third one. The rest
+        // of the object is bound at step `3 * n`. The keys of the properties that have been
+        // bound are kept, as they are not part of the rest.
+        let (first, mut key, mut property_value, mut excluded) =
+            match interpreter.resume_state(self) {
+                Some(state) => {
+                    let step = state.step;
+                    let mut values = state.into_vec();
+                    let excluded = values.split_off(2);
+                    let property_value = values.pop().unwrap_or_default();
+                    let key = values.pop().unwrap_or_default();
+                    (Some(step), key, property_value, excluded)
+                }
+                None => (None, Value::undefined(), Value::undefined(), Vec::new()),
+            };
+        let step = first.unwrap_or(0);
+
+        for (i, property) in self.properties.iter().enumerate().skip(step / 3) {
+            let start = if first.is_some() && i == step / 3 {
+                step % 3
+            } else {
+                0
+            };
+
+            if start == 0 {
+                key = match property.name() {
+                    PropertyName::Literal(name) => Value::from(name.as_ref()),
+                    PropertyName::Computed(node) => {
+                        let values = || state_values(&key, &property_value, &excluded);
+                        let key = interpreter.run_step(self, 3 * i, values, |ctx| node.run(ctx))?;
+                        key.to_property_key(interpreter)?.into()
+                    }
+                };
+                let property_key = key.to_property_key(interpreter)?;
+                property_value =
+                    object.get_field_with_receiver(property_key, &object, interpreter)?;
+            }
+
+            if start <= 1 && property_value.is_undefined() {
+                if let Some(init) = property.init() {
+                    let values = || state_values(&key, &Value::undefined(), &excluded);
+                    property_value =
+                        interpreter.run_step(self, 3 * i + 1, values, |ctx| init.run(ctx))?;
+                }
+            }
+
+            interpreter.run_step(
+                self,
+                3 * i + 2,
+                || state_values(&key, &property_value, &excluded),
+                |ctx| property.target().bind(property_value.clone(), kind, ctx),
+            )?;
+            excluded.push(key.clone());
+        }
+
+        if let Some(rest) = self.rest() {
+            let rest_step = 3 * self.properties.len();
+            let rest_object = if first == Some(rest_step) {
+                property_value
+            } else {
+                self.copy_rest(&object, &excluded, interpreter)?
+            };
+            interpreter.run_step(
+                self,
+                rest_step,
+                || state_values(&Value::undefined(), &rest_object, &excluded),
+                |ctx| rest.bind(rest_object.clone(), kind, ctx),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Copies the own enumerable properties of `object` to a new object, except for the
+    /// `excluded` ones.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-copydataproperties
+    fn copy_rest(
+        &self,
+        object: &Value,
+        excluded: &[Value],
+        interpreter: &mut Context,
+    ) -> Result<Value> {
+        let excluded = excluded
+            .iter()
+            .map(|key| key.to_property_key(interpreter))
+            .collect::<Result<Vec<PropertyKey>>>()?;
+        let rest = Value::new_object(Some(interpreter.global_object()));
+        let source = match object.as_object() {
+            Some(source) => source,
+            None => return Ok(rest),
+        };
+
+        let keys = source.own_property_keys(interpreter)?;
+        for key in keys {
+            if excluded.contains(&key) {
+                continue;
+            }
+            let enumerable = source
+                .get_own_property(&key, interpreter)?
+                .is_some_and(|desc| desc.enumerable());
+            if enumerable {
+                let value = object.get_field_with_receiver(key.clone(), object, interpreter)?;
+                rest.set_field(key, value, interpreter)?;
+            }
+        }
+        Ok(rest)
+    }
+}
+
+/// Returns the values saved by an object pattern that is suspended.
+fn state_values(key: &Value, value: &Value, excluded: &[Value]) -> Vec<Value> {
+    let mut values = Vec::with_capacity(excluded.len() + 2);
+    values.push(key.clone());
+    values.push(value.clone());
+    values.extend_from_slice(excluded);
+    values
+}
+
+impl fmt::Display for ObjectPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        join_nodes(f, &self.properties)?;
+        if let Some(ref rest) = self.rest {
+            if !self.properties.is_empty() {
+                f.write_str(", ")?;
+            }
+            write!(f, "...{}", rest)?;
+        }
+        f.write_str("}")
+    }
+}
+
+impl From<ObjectPattern> for Pattern {
+    fn from(pattern: ObjectPattern) -> Self {
+        Self::Object(pattern)
+    }
+}
+
+/// A property of an object pattern, with the target its value is bound to and a default value
+/// for when it is `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingProperty
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct PatternProperty {
+    name: PropertyName,
+    target: Target,
+    init: Option<Node>,
+}
+
+impl PatternProperty {
+    /// Creates a new property of an object pattern.
+    pub(in crate::syntax) fn new<N, T>(name: N, target: T, init: Option<Node>) -> Self
+    where
+        N: Into<PropertyName>,
+        T: Into<Target>,
+    {
+        Self {
+            name: name.into(),
+            target: target.into(),
+            init,
+        }
+    }
+
+    /// Gets the name of the property.
+    pub fn name(&self) -> &PropertyName {
+        &self.name
+    }
+
+    /// Gets the target the property is bound to.
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    /// Gets the default value of the property, if any.
+    pub fn init(&self) -> Option<&Node> {
+        self.init.as_ref()
+    }
+}
+
+impl fmt::Display for PatternProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.name, &self.target) {
+            (PropertyName::Literal(name), Target::Identifier(target))
+                if name.as_ref() == target.as_ref() =>
+            {
+                write!(f, "{}", target)?
+            }
+            (name, target) => write!(f, "{}: {}", name, target)?,
+        }
+        if let Some(ref init) = self.init {
+            write!(f, " = {}", init)?;
+        }
+        Ok(())
+    }
+}
+
+/// An array pattern binds the values produced by an iterable to targets.
+///
+/// The rest of the values can be collected in an array, which is bound to the rest target.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment#array_destructuring
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ArrayPattern {
+    elements: Box<[Option<PatternElement>]>,
+    rest: Option<Box<Target>>,
+}
+
+impl ArrayPattern {
+    /// Creates a new array pattern, where `None` elements are holes.
+    pub(in crate::syntax) fn new<E>(elements: E, rest: Option<Target>) -> Self
+    where
+        E: Into<Box<[Option<PatternElement>]>>,
+    {
+        Self {
+            elements: elements.into(),
+            rest: rest.map(Box::new),
+        }
+    }
+
+    /// Gets the elements of the pattern, where `None` elements are holes.
+    pub fn elements(&self) -> &[Option<PatternElement>] {
+        &self.elements
+    }
+
+    /// Gets the target of the rest of the values, if any.
+    pub fn rest(&self) -> Option<&Target> {
+        self.rest.as_deref()
+    }
+
+    /// Binds the values produced by iterating `value` to the targets of the pattern.
+    ///
+    /// The iterator is closed if it is not done once the pattern is bound.
+    fn bind(&self, value: Value, kind: BindingKind, interpreter: &mut Context) -> Result<()> {
+        let (first, iterator, mut done, element) = match interpreter.resume_state(self) {
+            Some(state) => {
+                let step = state.step;
+                let [iterator, next_function, done, element] = state.into_values();
+                (
+                    Some(step),
+                    IteratorRecord::new(iterator, next_function),
+                    done.to_boolean(),
+                    element,
+                )
+            }
+            None => (
+                None,
+                get_iterator(interpreter, value)?,
+                false,
+                Value::undefined(),
+            ),
+        };
+
+        let result = self.bind_elements(first, &iterator, &mut done, element, kind, interpreter);
+        if done || interpreter.is_suspending() {
+            return result;
+        }
+        iterator
+            .close(result.map(|_| Value::undefined()), interpreter)
+            .map(|_| ())
+    }
+
+    /// Binds the elements of the pattern, starting from the `first` step if it is resumed.
+    fn bind_elements(
+        &self,
+        first: Option<usize>,
+        iterator: &IteratorRecord,
+        done: &mut bool,
+        mut element: Value,
+        kind: BindingKind,
+        interpreter: &mut Context,
+    ) -> Result<()> {
+        // Each element is bound from step `2 * i`: the next value of the iterator, or its
+        // default value, is computed at the first step and its target is bound at the second
+        // one. The rest of the values is bound at step `2 * n`.
+        let step = first.unwrap_or(0);
+        for (i, pattern_element) in self.elements.iter().enumerate().skip(step / 2) {
+            let resumed = first.is_some() && i == step / 2;
+            if !resumed {
+                element = next_value(iterator, done, interpreter)?;
+            }
+            let pattern_element = match pattern_element {
+                Some(pattern_element) => pattern_element,
+                None => continue,
+            };
+
+            if !(resumed && step % 2 == 1) && element.is_undefined() {
+                if let Some(init) = pattern_element.init() {
+                    let values = || iterator_values(iterator, *done, &Value::undefined());
+                    element = interpreter.run_step(self, 2 * i, values, |ctx| init.run(ctx))?;
+                }
+            }
+
+            interpreter.run_step(
+                self,
+                2 * i + 1,
+                || iterator_values(iterator, *done, &element),
+                |ctx| pattern_element.target().bind(element.clone(), kind, ctx),
+            )?;
+        }
+
+        if let Some(rest) = self.rest() {
+            let rest_step = 2 * self.elements.len();
+            let rest_array = if first == Some(rest_step) {
+                element
+            } else {
+                let mut values = Vec::new();
+                while !*done {
+                    let value = next_value(iterator, done, interpreter)?;
+                    if !*done {
+                        values.push(value);
+                    }
+                }
+                let array = Array::new_array(interpreter)?;
+                Array::add_to_array_object(&array, &values, interpreter)?;
+                array
+            };
+            interpreter.run_step(
+                self,
+                rest_step,
+                || iterator_values(iterator, *done, &rest_array),
+                |ctx| rest.bind(rest_array.clone(), kind, ctx),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the next value of the iterator, or `undefined` if it is done.
+///
+/// The iterator is considered done if getting its next value fails.
+fn next_value(
+    iterator: &IteratorRecord,
+    done: &mut bool,
+    interpreter: &mut Context,
+) -> Result<Value> {
+    if *done {
+        return Ok(Value::undefined());
+    }
+    *done = true;
+    let next = iterator.next(interpreter)?;
+    if next.is_done() {
+        Ok(Value::undefined())
+    } else {
+        *done = false;
+        Ok(next.value())
+    }
+}
+
+/// Returns the values saved by an array pattern that is suspended.
+fn iterator_values(iterator: &IteratorRecord, done: bool, element: &Value) -> Vec<Value> {
+    vec![
+        iterator.iterator_object().clone(),
+        iterator.next_function().clone(),
+        Value::from(done),
+        element.clone(),
+    ]
+}
+
+impl fmt::Display for ArrayPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, element) in self.elements.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            if let Some(element) = element {
+                write!(f, "{}", element)?;
+            }
+        }
+        match self.rest {
+            Some(ref rest) if self.elements.is_empty() => write!(f, "...{}", rest)?,
+            Some(ref rest) => write!(f, ", ...{}", rest)?,
+            // A trailing hole needs a trailing comma.
+            None if matches!(self.elements.last(), Some(None)) => f.write_str(",")?,
+            None => {}
+        }
+        f.write_str("]")
+    }
+}
+
+impl From<ArrayPattern> for Pattern {
+    fn from(pattern: ArrayPattern) -> Self {
+        Self::Array(pattern)
+    }
+}
+
+/// An element of an array pattern, with the target its value is bound to and a default value
+/// for when it is `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct PatternElement {
+    target: Target,
+    init: Option<Node>,
+}
+
+impl PatternElement {
+    /// Creates a new element of an array pattern.
+    pub(in crate::syntax) fn new<T>(target: T, init: Option<Node>) -> Self
+    where
+        T: Into<Target>,
+    {
+        Self {
+            target: target.into(),
+            init,
+        }
+    }
+
+    /// Gets the target the element is bound to.
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    /// Gets the default value of the element, if any.
+    pub fn init(&self) -> Option<&Node> {
+        self.init.as_ref()
+    }
+}
+
+impl fmt::Display for PatternElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.target, f)?;
+        if let Some(ref init) = self.init {
+            write!(f, " = {}", init)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{exec, forward, Context};
+
+#[test]
+fn array_pattern_declarations() {
+    let scenario = r#"
+        var [a, b] = [1, 2];
+        let [c, , d] = [3, 4, 5];
+        const [e, f] = [6];
+        [a, b, c, d, e, f === undefined].join(",");
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,3,5,6,true\"");
+}
+
+#[test]
+fn object_pattern_declarations() {
+    let scenario = r#"
+        var { a, b: c } = { a: 1, b: 2 };
+        let { d, ["e" + "f"]: g } = { d: 3, ef: 4 };
+        const { h } = {};
+        [a, c, d, g, h === undefined].join(",");
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,3,4,true\"");
+}
+
+#[test]
+fn pattern_defaults() {
+    let scenario = r#"
+        let calls = 0;
+        function def() { calls++; return 10; }
+        let [a = def(), b = def()] = [1];
+        let { c = def(), d: e = def() } = { c: undefined, d: null };
+        [a, b, c, e === null, calls].join(",");
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,10,10,true,2\"");
+}
+
+#[test]
+fn pattern_rest() {
+    let scenario = r#"
+        let [a, ...b] = [1, 2, 3];
+        let { c, ...d } = { c: 4, e: 5, f: 6 };
+        [a, b.length, b[1], c, d.c === undefined, d.e + d.f].join(",");
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,3,4,true,11\"");
+}
+
+#[test]
+fn nested_patterns() {
+    let scenario = r#"
+        let { a: [b, { c }], d: { e = 5 } = {} } = { a: [1, { c: 2 }] };
+        [b, c, e].join(",");
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,5\"");
+}
+
+#[test]
+fn array_pattern_uses_iterator() {
+    let scenario = r#"
+        let closed = false;
+        function* gen() {
+            try {
+                yield "x";
+                yield "y";
+                yield "z";
+            } finally {
+                closed = true;
+            }
+        }
+        let [a, b] = gen();
+        a + b + closed;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"xytrue\"");
+}
+
+#[test]
+fn destructuring_null_throws() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            try {
+                let { a } = null;
+            } catch (e) {
+                e instanceof TypeError;
+            }
+            "#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            try {
+                let [a] = 1;
+            } catch (e) {
+                e instanceof TypeError;
+            }
+            "#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn destructuring_assignment() {
+    let scenario = r#"
+        let a = 1, b = 2, obj = {};
+        [a, b] = [b, a];
+        ({ x: obj.x, y: obj["y"] = 3 } = { x: 4 });
+        [a, b, obj.x, obj.y].join(",");
+    "#;
+
+    assert_eq!(&exec(scenario), "\"2,1,4,3\"");
+}
+
+#[test]
+fn shorthand_assignment_pattern() {
+    let scenario = r#"
+        let a, b;
+        ({ a, b = 2 } = { a: 1 });
+        a + b;
+    "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn parameter_patterns() {
+    let scenario = r#"
+        function f([a, b], { c } = { c: 3 }, ...[d, e]) {
+            return [a, b, c, d, e].join(",");
+        }
+        f([1, 2], undefined, 4, 5);
+    "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,3,4,5\"");
+}
+
+#[test]
+fn parameter_defaults_see_earlier_parameters() {
+    let scenario = r#"
+        function f(a, b = a + 1) {
+            return b;
+        }
+        f(1);
+    "#;
+
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn catch_parameter_pattern() {
+    let scenario = r#"
+        let result;
+        try {
+            throw { message: "oops", code: 7 };
+        } catch ({ message, code }) {
+            result = message + code;
+        }
+        result;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"oops7\"");
+}
+
+#[test]
+fn for_of_patterns() {
+    let scenario = r#"
+        let sum = 0;
+        for (const [a, b] of [[1, 2], [3, 4]]) {
+            sum += a * b;
+        }
+        let x;
+        for ({ x } of [{ x: 5 }]) {}
+        sum + x;
+    "#;
+
+    assert_eq!(&exec(scenario), "19");
+}
+
+#[test]
+fn yield_in_pattern_default() {
+    let scenario = r#"
+        function* gen() {
+            let [a = yield "first", b = yield "second"] = [];
+            return a + b;
+        }
+        let g = gen();
+        let results = [g.next().value, g.next(1).value, g.next(2).value];
+        results.join(",");
+    "#;
+
+    assert_eq!(&exec(scenario), "\"first,second,3\"");
+}
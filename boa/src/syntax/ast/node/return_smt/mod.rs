@@ -60,14 +60,14 @@ impl Return {
 impl Executable for Return {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let result = match self.expr() {
-            Some(ref v) => v.run(interpreter),
-            None => Ok(Value::undefined()),
+            Some(v) => v.run(interpreter)?,
+            None => Value::undefined(),
         };
         // Set flag for return
         interpreter
             .executor()
             .set_current_state(InterpreterState::Return);
-        result
+        Ok(result)
     }
 }
 
@@ -26,6 +26,7 @@ use serde::{Deserialize, Serialize};
 pub struct StatementList {
     #[cfg_attr(feature = "serde", serde(flatten))]
     statements: Box<[Node]>,
+    strict: bool,
 }
 
 impl StatementList {
@@ -34,6 +35,17 @@ impl StatementList {
         &self.statements
     }
 
+    /// Returns `true` if the statements start with a `"use strict"` directive, which makes them
+    /// strict mode code.
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether the statements start with a `"use strict"` directive.
+    pub(in crate::syntax) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -66,8 +78,9 @@ impl Executable for StatementList {
         interpreter
             .executor()
             .set_current_state(InterpreterState::Executing);
-        for (i, item) in self.statements().iter().enumerate() {
-            let val = item.run(interpreter)?;
+        let first = interpreter.resume_state(self).map_or(0, |state| state.step);
+        for (i, item) in self.statements().iter().enumerate().skip(first) {
+            let val = interpreter.run_step(self, i, Vec::new, |ctx| item.run(ctx))?;
             match interpreter.executor().get_current_state() {
                 InterpreterState::Return => {
                     // Early return.
@@ -101,6 +114,7 @@ where
     fn from(stm: T) -> Self {
         Self {
             statements: stm.into(),
+            strict: false,
         }
     }
 }
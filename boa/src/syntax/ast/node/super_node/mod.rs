@@ -0,0 +1,223 @@
+//! Super nodes.
+
+use crate::{
+    exec::Executable,
+    syntax::ast::node::{call::run_arguments, join_nodes, Node},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `super(...)` call invokes the constructor of the parent class, and binds its result as
+/// the `this` value of a derived class constructor.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperCall
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct SuperCall {
+    args: Box<[Node]>,
+}
+
+impl SuperCall {
+    /// Creates a new `SuperCall` AST node.
+    pub fn new<A>(args: A) -> Self
+    where
+        A: Into<Box<[Node]>>,
+    {
+        Self { args: args.into() }
+    }
+
+    /// Retrieves the arguments passed to the parent constructor.
+    pub fn args(&self) -> &[Node] {
+        &self.args
+    }
+}
+
+impl Executable for SuperCall {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("SuperCall", "exec");
+
+        // <https://tc39.es/ecma262/#sec-super-keyword-runtime-semantics-evaluation>
+        let this_env = interpreter.realm().environment.get_this_environment();
+        let new_target = this_env.borrow().get_new_target();
+        let active_function = this_env.borrow().get_function_object();
+        let constructor = match active_function {
+            Some(ref function) if !new_target.is_undefined() => {
+                function.get_prototype_of(interpreter)?
+            }
+            _ => {
+                return interpreter
+                    .throw_syntax_error("'super' keyword unexpected here, not in a constructor")
+            }
+        };
+        let constructor = match constructor {
+            Value::Object(ref object) if object.is_constructable() => object.clone(),
+            _ => return interpreter.throw_type_error("super constructor is not a constructor"),
+        };
+
+        let v_args = match interpreter.resume_state(self) {
+            Some(state) => state.into_vec(),
+            None => Vec::with_capacity(self.args().len()),
+        };
+        let v_args = run_arguments(self, self.args(), 0, &[], v_args, interpreter)?;
+
+        let result = constructor.construct(&v_args, &new_target, interpreter)?;
+        let bound = this_env.borrow_mut().bind_this_value(result);
+        bound.map_err(|e| interpreter.construct_reference_error(e.to_string()))
+    }
+}
+
+impl fmt::Display for SuperCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("super(")?;
+        join_nodes(f, &self.args)?;
+        f.write_str(")")
+    }
+}
+
+impl From<SuperCall> for Node {
+    fn from(call: SuperCall) -> Self {
+        Self::SuperCall(call)
+    }
+}
+
+/// The `super.name` syntax accesses a property of the parent class from a method.
+///
+/// The property is looked up on the prototype of the object the method was defined on, and
+/// getters are invoked with the current `this` value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperProperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetSuperConstField {
+    field: Box<str>,
+}
+
+impl GetSuperConstField {
+    /// Creates a `GetSuperConstField` AST node.
+    pub fn new<F>(field: F) -> Self
+    where
+        F: Into<Box<str>>,
+    {
+        Self {
+            field: field.into(),
+        }
+    }
+
+    /// Gets the name of the field to retrieve.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+}
+
+impl Executable for GetSuperConstField {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let (base, this) = super_base_and_this(interpreter)?;
+        base.get_field_with_receiver(self.field(), &this, interpreter)
+    }
+}
+
+impl fmt::Display for GetSuperConstField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "super.{}", self.field())
+    }
+}
+
+impl From<GetSuperConstField> for Node {
+    fn from(get_super_const_field: GetSuperConstField) -> Self {
+        Self::GetSuperConstField(get_super_const_field)
+    }
+}
+
+/// The `super[expression]` syntax accesses a computed property of the parent class from a
+/// method.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-SuperProperty
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct GetSuperField {
+    field: Box<Node>,
+}
+
+impl GetSuperField {
+    /// Creates a `GetSuperField` AST node.
+    pub fn new<F>(field: F) -> Self
+    where
+        F: Into<Node>,
+    {
+        Self {
+            field: Box::new(field.into()),
+        }
+    }
+
+    /// Gets the expression that computes the name of the field.
+    pub fn field(&self) -> &Node {
+        &self.field
+    }
+}
+
+impl Executable for GetSuperField {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let (base, this) = super_base_and_this(interpreter)?;
+        let field = self.field().run(interpreter)?;
+        let key = field.to_property_key(interpreter)?;
+        base.get_field_with_receiver(key, &this, interpreter)
+    }
+}
+
+impl fmt::Display for GetSuperField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "super[{}]", self.field())
+    }
+}
+
+impl From<GetSuperField> for Node {
+    fn from(get_super_field: GetSuperField) -> Self {
+        Self::GetSuperField(get_super_field)
+    }
+}
+
+/// Returns the object `super` property lookups start at, along with the current `this` value
+/// that is used as the receiver of the lookup.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makesuperpropertyreference
+pub(in crate::syntax::ast::node) fn super_base_and_this(
+    interpreter: &mut Context,
+) -> Result<(Value, Value)> {
+    let this_env = interpreter.realm().environment.get_this_environment();
+    if !this_env.borrow().has_super_binding() {
+        return Err(interpreter.construct_syntax_error("'super' keyword unexpected here"));
+    }
+
+    let this = this_env
+        .borrow()
+        .get_this_binding()
+        .map_err(|e| interpreter.construct_reference_error(e.to_string()))?;
+    let base = this_env.borrow().get_super_base();
+    if !base.is_object() {
+        return Err(interpreter.construct_type_error("super base is not an object"));
+    }
+
+    Ok((base, this))
+}
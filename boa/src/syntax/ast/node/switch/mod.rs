@@ -123,7 +123,20 @@ impl Switch {
 
 impl Executable for Switch {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
-        let val = self.val().run(interpreter)?;
+        // The value is run at step 0. The condition of the case `i` is run at step `2 * i + 1`
+        // and its body at step `2 * i + 2`, followed by the statements of the default case.
+        let (step, val) = match interpreter.resume_state(self) {
+            Some(state) if state.step > 0 => {
+                let step = state.step;
+                let [val] = state.into_values();
+                (step, val)
+            }
+            _ => (
+                0,
+                interpreter.run_step(self, 0, Vec::new, |ctx| self.val().run(ctx))?,
+            ),
+        };
+        let values = || vec![val.clone()];
         let mut result = Value::null();
         let mut matched = false;
         interpreter
@@ -134,27 +147,39 @@ impl Executable for Switch {
         // checking their conditions until a break is encountered.
         let mut fall_through: bool = false;
 
-        for case in self.cases().iter() {
+        for (i, case) in self.cases().iter().enumerate() {
+            let (cond_step, body_step) = (2 * i + 1, 2 * i + 2);
+            if step > body_step {
+                continue;
+            }
+
             let cond = case.condition();
             let block = case.body();
-            if fall_through || val.strict_equals(&cond.run(interpreter)?) {
+            if step == body_step
+                || fall_through
+                || val.strict_equals(
+                    &interpreter.run_step(self, cond_step, values, |ctx| cond.run(ctx))?,
+                )
+            {
                 matched = true;
-                let result = block.run(interpreter)?;
+                let result = interpreter.run_step(self, body_step, values, |ctx| block.run(ctx))?;
                 match interpreter.executor().get_current_state() {
                     InterpreterState::Return => {
                         // Early return.
                         return Ok(result);
                     }
-                    InterpreterState::Break(_label) => {
-                        // TODO, break to a label.
-                        // Break statement encountered so therefore end switch statement.
-                        interpreter
-                            .executor()
-                            .set_current_state(InterpreterState::Executing);
+                    InterpreterState::Break(label) => {
+                        // Break statement encountered so therefore end switch statement. A break
+                        // to a label ends the labelled statement instead.
+                        if label.is_none() {
+                            interpreter
+                                .executor()
+                                .set_current_state(InterpreterState::Executing);
+                        }
                         break;
                     }
-                    InterpreterState::Continue(_label) => {
-                        // TODO, continue to a label.
+                    InterpreterState::Continue(_) => {
+                        // The continue targets an enclosing loop.
                         break;
                     }
                     InterpreterState::Executing => {
@@ -170,21 +195,30 @@ impl Executable for Switch {
                 interpreter
                     .executor()
                     .set_current_state(InterpreterState::Executing);
-                for (i, item) in default.iter().enumerate() {
-                    let val = item.run(interpreter)?;
+                let default_step = 2 * self.cases().len() + 1;
+                let first = step.saturating_sub(default_step);
+                for (i, item) in default.iter().enumerate().skip(first) {
+                    let val = interpreter
+                        .run_step(self, default_step + i, values, |ctx| item.run(ctx))?;
                     match interpreter.executor().get_current_state() {
                         InterpreterState::Return => {
                             // Early return.
                             result = val;
                             break;
                         }
-                        InterpreterState::Break(_label) => {
-                            // TODO, break to a label.
-
+                        InterpreterState::Break(label) => {
                             // Early break.
+                            if label.is_none() {
+                                interpreter
+                                    .executor()
+                                    .set_current_state(InterpreterState::Executing);
+                            }
+                            break;
+                        }
+                        InterpreterState::Continue(_) => {
                             break;
                         }
-                        _ => {
+                        InterpreterState::Executing => {
                             // Continue execution
                         }
                     }
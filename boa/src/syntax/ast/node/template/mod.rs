@@ -0,0 +1,335 @@
+//! Template literal nodes.
+
+use crate::{
+    builtins::Array,
+    exec::{Executable, InterpreterState},
+    object::GcObject,
+    property::Attribute,
+    syntax::ast::node::{
+        call::{run_arguments, run_callee, FIRST_ARGUMENT_STEP},
+        Node,
+    },
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::{
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// Template literals are string literals allowing embedded expressions.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-template-literals
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct TemplateLit {
+    elements: Box<[TemplateElement]>,
+}
+
+impl TemplateLit {
+    /// Creates a new `TemplateLit` AST node.
+    pub fn new<E>(elements: E) -> Self
+    where
+        E: Into<Box<[TemplateElement]>>,
+    {
+        Self {
+            elements: elements.into(),
+        }
+    }
+
+    /// Gets the strings and substitutions of the template literal, in source order.
+    pub fn elements(&self) -> &[TemplateElement] {
+        &self.elements
+    }
+}
+
+impl Executable for TemplateLit {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("TemplateLit", "exec");
+        // The substitution of the element `i` is evaluated at step `i`, saving the string built
+        // from the previous elements.
+        let (mut result, first) = match interpreter.resume_state(self) {
+            Some(state) => {
+                let step = state.step;
+                let [string] = state.into_values();
+                (string.to_string(interpreter)?, step)
+            }
+            None => (RcString::default(), 0),
+        };
+
+        for (i, element) in self.elements().iter().enumerate().skip(first) {
+            match element {
+                TemplateElement::String(string) => result = result.concat(string),
+                TemplateElement::Expr(expr) => {
+                    let values = || vec![Value::from(result.clone())];
+                    let value = interpreter.run_step(self, i, values, |ctx| expr.run(ctx))?;
+                    result = result.concat(&value.to_string(interpreter)?);
+                }
+            }
+        }
+        Ok(result.into())
+    }
+}
+
+impl fmt::Display for TemplateLit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("`")?;
+        for element in self.elements().iter() {
+            match element {
+                TemplateElement::String(string) => {
+                    let escaped = string
+                        .replace('\\', "\\\\")
+                        .replace('`', "\\`")
+                        .replace("${", "\\${");
+                    f.write_str(&escaped)?
+                }
+                TemplateElement::Expr(expr) => write!(f, "${{{}}}", expr)?,
+            }
+        }
+        f.write_str("`")
+    }
+}
+
+impl From<TemplateLit> for Node {
+    fn from(template: TemplateLit) -> Self {
+        Self::TemplateLit(template)
+    }
+}
+
+/// An element of a template literal, which is either a string or a substitution.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum TemplateElement {
+    /// A string, with its escape sequences interpreted.
+    String(RcString),
+
+    /// A substitution, whose value is converted to a string.
+    Expr(Node),
+}
+
+/// A tagged template calls its tag function with the strings of the template and the values of
+/// its substitutions.
+///
+/// The strings are passed as an array, which also holds the raw strings of the template in its
+/// `raw` property. Both arrays are frozen, and the same array is passed each time the tagged
+/// template is evaluated.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-tagged-templates
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct TaggedTemplate {
+    tag: Box<Node>,
+    raws: Box<[Box<str>]>,
+    cookeds: Box<[Option<RcString>]>,
+    exprs: Box<[Node]>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    site: TemplateSite,
+}
+
+impl TaggedTemplate {
+    /// Creates a new `TaggedTemplate` AST node.
+    ///
+    /// There must be one more string than there are expressions.
+    pub fn new<T, R, C, E>(tag: T, raws: R, cookeds: C, exprs: E) -> Self
+    where
+        T: Into<Node>,
+        R: Into<Box<[Box<str>]>>,
+        C: Into<Box<[Option<RcString>]>>,
+        E: Into<Box<[Node]>>,
+    {
+        let (raws, cookeds, exprs) = (raws.into(), cookeds.into(), exprs.into());
+        debug_assert_eq!(raws.len(), cookeds.len());
+        debug_assert_eq!(raws.len(), exprs.len() + 1);
+        Self {
+            tag: Box::new(tag.into()),
+            raws,
+            cookeds,
+            exprs,
+            site: TemplateSite::default(),
+        }
+    }
+
+    /// Gets the tag function of the template.
+    pub fn tag(&self) -> &Node {
+        &self.tag
+    }
+
+    /// Gets the raw strings of the template.
+    pub fn raws(&self) -> &[Box<str>] {
+        &self.raws
+    }
+
+    /// Gets the cooked strings of the template, which are `None` for strings with invalid escape
+    /// sequences.
+    pub fn cookeds(&self) -> &[Option<RcString>] {
+        &self.cookeds
+    }
+
+    /// Gets the substitutions of the template.
+    pub fn exprs(&self) -> &[Node] {
+        &self.exprs
+    }
+
+    /// Gets the template object that is passed to the tag function, creating it the first time
+    /// the template is evaluated.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-gettemplateobject
+    fn template_object(&self, interpreter: &mut Context) -> Result<Value> {
+        if let Some(template) = interpreter.realm().template_map.get(&self.site.0) {
+            return Ok(template.clone().into());
+        }
+
+        let mut raw = readonly_array(
+            self.raws().iter().map(|raw| Value::from(raw.as_ref())),
+            interpreter,
+        )?;
+        raw.prevent_extensions(interpreter)?;
+
+        let mut template = readonly_array(
+            self.cookeds()
+                .iter()
+                .map(|cooked| cooked.clone().map_or_else(Value::undefined, Value::from)),
+            interpreter,
+        )?;
+        template.insert_property(
+            "raw",
+            raw,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        template.prevent_extensions(interpreter)?;
+
+        interpreter
+            .realm_mut()
+            .template_map
+            .insert(self.site.0, template.clone());
+        Ok(template.into())
+    }
+}
+
+/// Creates an array with the given values, whose elements and length are read-only.
+fn readonly_array<I>(values: I, interpreter: &Context) -> Result<GcObject>
+where
+    I: ExactSizeIterator<Item = Value>,
+{
+    let mut array = Array::new_array(interpreter)?
+        .as_object()
+        .expect("array object");
+    let length = values.len();
+    for (i, value) in values.enumerate() {
+        array.insert_property(
+            i,
+            value,
+            Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+        );
+    }
+    array.insert_property(
+        "length",
+        length,
+        Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+    );
+    Ok(array)
+}
+
+impl Executable for TaggedTemplate {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplate", "exec");
+        // The tag is evaluated like the callee of a call, followed by the substitutions, which
+        // are evaluated like its arguments.
+        let (this, func, template, exprs) = match interpreter.resume_state(self) {
+            Some(state) if state.step >= FIRST_ARGUMENT_STEP => {
+                let mut exprs = state.into_vec();
+                let template = exprs.remove(2);
+                let func = exprs.remove(1);
+                let this = exprs.remove(0);
+                (this, func, template, exprs)
+            }
+            state => {
+                let resumed = state.filter(|state| state.step == 1).map(|state| {
+                    let [obj] = state.into_values();
+                    obj
+                });
+                let (this, func) = run_callee(self, self.tag(), resumed, interpreter)?;
+                let template = self.template_object(interpreter)?;
+                (this, func, template, Vec::with_capacity(self.exprs().len()))
+            }
+        };
+        let exprs = run_arguments(
+            self,
+            self.exprs(),
+            FIRST_ARGUMENT_STEP,
+            &[this.clone(), func.clone(), template.clone()],
+            exprs,
+            interpreter,
+        )?;
+
+        let mut args = Vec::with_capacity(exprs.len() + 1);
+        args.push(template);
+        args.extend(exprs);
+        let result = interpreter.call(&func, &this, &args);
+
+        // unset the early return flag
+        interpreter
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        result
+    }
+}
+
+impl fmt::Display for TaggedTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}`", self.tag())?;
+        for (raw, expr) in self.raws().iter().zip(self.exprs().iter()) {
+            write!(f, "{}${{{}}}", raw, expr)?;
+        }
+        if let Some(last) = self.raws().last() {
+            f.write_str(last)?;
+        }
+        f.write_str("`")
+    }
+}
+
+impl From<TaggedTemplate> for Node {
+    fn from(template: TaggedTemplate) -> Self {
+        Self::TaggedTemplate(template)
+    }
+}
+
+/// Identifies the site of a tagged template, which its template object is cached for.
+///
+/// It is shared by the clones of the node, and ignored when comparing nodes.
+#[derive(Clone, Debug, Trace, Finalize)]
+struct TemplateSite(usize);
+
+impl Default for TemplateSite {
+    fn default() -> Self {
+        static NEXT_SITE: AtomicUsize = AtomicUsize::new(0);
+        Self(NEXT_SITE.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl PartialEq for TemplateSite {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{exec, forward, Context};
+
+#[test]
+fn template_literal() {
+    let scenario = r#"
+        let a = 10;
+        let b = { toString() { return "b"; } };
+        `result: ${a} and ${b}${`${a + 5}`}!`;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"result: 10 and b15!\"");
+}
+
+#[test]
+fn template_literal_escapes() {
+    let scenario = r#"
+        `\x41\u0042\u{43}\`\${} \
+line`;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"ABC`${} line\"");
+}
+
+#[test]
+fn template_literal_symbol_substitution() {
+    let scenario = r#"
+        try {
+            `${Symbol()}`;
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn tagged_template() {
+    let mut engine = Context::new();
+    let init = r#"
+        function tag(strings, ...values) {
+            return [strings, values];
+        }
+        let [strings, values] = tag`a${1}\n${2 + 3}\unicode`;
+    "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "strings.length"), "3");
+    assert_eq!(forward(&mut engine, "strings[0]"), "\"a\"");
+    assert_eq!(forward(&mut engine, "strings[1]"), "\"\n\"");
+    assert_eq!(forward(&mut engine, "strings[2]"), "undefined");
+    assert_eq!(forward(&mut engine, "strings.raw.length"), "3");
+    assert_eq!(forward(&mut engine, "strings.raw[1]"), "\"\\n\"");
+    assert_eq!(forward(&mut engine, "strings.raw[2]"), "\"\\unicode\"");
+    assert_eq!(forward(&mut engine, "values.length"), "2");
+    assert_eq!(forward(&mut engine, "values[1]"), "5");
+}
+
+#[test]
+fn tagged_template_object_is_frozen() {
+    let mut engine = Context::new();
+    let init = r#"
+        function tag(strings) {
+            return strings;
+        }
+        let strings = tag`a${0}b`;
+        strings[0] = "changed";
+        strings.raw[0] = "changed";
+        strings.extra = 1;
+        strings.raw.extra = 1;
+        strings.raw = [];
+    "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "strings[0]"), "\"a\"");
+    assert_eq!(forward(&mut engine, "strings.raw[0]"), "\"a\"");
+    assert_eq!(forward(&mut engine, "strings.extra"), "undefined");
+    assert_eq!(forward(&mut engine, "strings.raw.extra"), "undefined");
+    assert_eq!(forward(&mut engine, "strings.raw.length"), "2");
+}
+
+#[test]
+fn tagged_template_object_is_cached() {
+    let scenario = r#"
+        function tag(strings) {
+            return strings;
+        }
+        function call() {
+            return tag`a${0}b`;
+        }
+        let first = call();
+        let second = call();
+        let other = tag`a${0}b`;
+        [first === second, first === other].join();
+    "#;
+
+    assert_eq!(&exec(scenario), "\"true,false\"");
+}
+
+#[test]
+fn tagged_template_member_tag() {
+    let scenario = r#"
+        let obj = {
+            prefix: "x",
+            tag(strings, value) {
+                return this.prefix + strings[0] + value;
+            },
+        };
+        obj.tag`a${1}`;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"xa1\"");
+}
+
+#[test]
+fn tagged_template_chained() {
+    let scenario = r#"
+        let first;
+        function tag(strings) {
+            first = strings[0];
+            return next;
+        }
+        function next(strings) {
+            return first + strings[0];
+        }
+        tag`a``b`;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"ab\"");
+}
+
+#[test]
+fn tagged_template_in_generator() {
+    let scenario = r#"
+        function tag(strings, ...values) {
+            return strings.raw.join("|") + values.join();
+        }
+        function* gen() {
+            return tag`a${yield 1}b${yield 2}c`;
+        }
+        let it = gen();
+        it.next();
+        it.next("x");
+        it.next("y").value;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"a|b|cx,y\"");
+}
+
+#[test]
+fn template_literal_in_generator() {
+    let scenario = r#"
+        function* gen() {
+            return `a${yield 1}b${yield 2}c`;
+        }
+        let it = gen();
+        it.next();
+        it.next("x");
+        it.next("y").value;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"axbyc\"");
+}
@@ -1,7 +1,10 @@
 use crate::{
-    environment::lexical_environment::{new_declarative_environment, VariableScope},
-    exec::Executable,
-    syntax::ast::node::{Block, Identifier, Node},
+    environment::lexical_environment::new_declarative_environment,
+    exec::{Executable, InterpreterState},
+    syntax::ast::node::{
+        pattern::{BindingKind, Target},
+        Block, Node,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use gc::{Finalize, Trace};
@@ -91,47 +94,161 @@ impl Try {
     }
 }
 
+impl Try {
+    /// Runs the `catch` block from the given step, binding its parameter to `error` if it has
+    /// not been bound yet.
+    fn run_catch(&self, step: usize, error: Value, interpreter: &mut Context) -> Result<Value> {
+        let catch = self
+            .catch()
+            .expect("the catch block of a try statement without one was run");
+
+        // The environment of a resumed `catch` block is restored along with its coroutine.
+        if step == 0 {
+            let env = &mut interpreter.realm_mut().environment;
+            env.push(new_declarative_environment(Some(
+                env.get_current_environment_ref().clone(),
+            )));
+        }
+
+        let res = match catch.parameter() {
+            Some(param) if step < 2 => interpreter.run_step(
+                self,
+                1,
+                || vec![error.clone()],
+                |ctx| param.bind(error.clone(), BindingKind::Let, ctx),
+            ),
+            _ => Ok(()),
+        };
+        let res =
+            res.and_then(|_| interpreter.run_step(self, 2, Vec::new, |ctx| catch.block().run(ctx)));
+
+        if !interpreter.is_suspending() {
+            // pop the block env
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+
+        res
+    }
+
+    /// Runs the `finally` block, then completes the statement with `completion`, unless the
+    /// `finally` block returns, breaks or continues.
+    fn run_finally(&self, completion: Completion, interpreter: &mut Context) -> Result<Value> {
+        let finally = self
+            .finally()
+            .expect("the finally block of a try statement without one was run");
+        let value =
+            interpreter.run_step(self, 3, || completion.to_values(), |ctx| finally.run(ctx))?;
+
+        // A `finally` block that returns, breaks or continues replaces the completion of
+        // the `try` statement.
+        if interpreter.executor().get_current_state() != &InterpreterState::Executing {
+            return Ok(value);
+        }
+
+        match completion {
+            Completion::Normal(value, state) => {
+                interpreter.executor().set_current_state(state);
+                Ok(value)
+            }
+            Completion::Throw(error) => Err(error),
+            Completion::Return(value) => Err(interpreter.return_from_coroutine(value)),
+        }
+    }
+}
+
 impl Executable for Try {
     fn run(&self, interpreter: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Try", "exec");
-        let res = self.block().run(interpreter).map_or_else(
-            |err| {
-                if let Some(catch) = self.catch() {
-                    {
-                        let env = &mut interpreter.realm_mut().environment;
-                        env.push(new_declarative_environment(Some(
-                            env.get_current_environment_ref().clone(),
-                        )));
-
-                        if let Some(param) = catch.parameter() {
-                            env.create_mutable_binding(
-                                param.to_owned(),
-                                false,
-                                VariableScope::Block,
-                            );
-
-                            env.initialize_binding(param, err);
-                        }
-                    }
-
-                    let res = catch.block().run(interpreter);
-
-                    // pop the block env
-                    let _ = interpreter.realm_mut().environment.pop();
-
-                    res
-                } else {
+        // The `try` block is run at step 0, the parameter of the `catch` block is bound to the
+        // error at step 1 and the `catch` block is run at step 2. The `finally` block is run at
+        // step 3, with the completion of the other blocks.
+        let res = match interpreter.resume_state(self) {
+            Some(state) if state.step == 3 => {
+                let completion = Completion::from_values(state.into_values());
+                return self.run_finally(completion, interpreter);
+            }
+            Some(state) if state.step == 2 => self.run_catch(2, Value::undefined(), interpreter),
+            Some(state) if state.step == 1 => {
+                let [error] = state.into_values();
+                self.run_catch(1, error, interpreter)
+            }
+            _ => match interpreter.run_step(self, 0, Vec::new, |ctx| self.block().run(ctx)) {
+                // A generator that is returning unwinds its body with an error, which can only
+                // be intercepted by `finally` blocks. A suspended one is not intercepted at all.
+                Err(err)
+                    if interpreter.is_suspending() || interpreter.is_returning_from_coroutine() =>
+                {
                     Err(err)
                 }
+                Err(err) if self.catch().is_some() => self.run_catch(0, err, interpreter),
+                res => res,
             },
-            Ok,
-        );
+        };
 
-        if let Some(finally) = self.finally() {
-            finally.run(interpreter)?;
+        if self.finally().is_none() || interpreter.is_suspending() {
+            return res;
         }
 
-        res
+        // The `finally` block runs as if no return, break or continue was in progress.
+        let completion = match res {
+            Ok(value) => {
+                let state = interpreter.executor().get_current_state().clone();
+                Completion::Normal(value, state)
+            }
+            Err(error) => match interpreter.take_coroutine_return() {
+                Some(value) => Completion::Return(value),
+                None => Completion::Throw(error),
+            },
+        };
+        interpreter
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+        self.run_finally(completion, interpreter)
+    }
+}
+
+/// The completion of the `try` and `catch` blocks of a `try` statement, which is kept while its
+/// `finally` block runs.
+#[derive(Debug)]
+enum Completion {
+    /// The blocks completed normally, or with a `return`, `break` or `continue`.
+    Normal(Value, InterpreterState),
+    /// The blocks threw an error.
+    Throw(Value),
+    /// The running coroutine is returning a value.
+    Return(Value),
+}
+
+impl Completion {
+    /// Converts the completion to the values saved by a `try` statement that is suspended in
+    /// its `finally` block.
+    fn to_values(&self) -> Vec<Value> {
+        let (kind, value, label) = match self {
+            Self::Normal(value, InterpreterState::Executing) => (0, value, None),
+            Self::Normal(value, InterpreterState::Return) => (1, value, None),
+            Self::Normal(value, InterpreterState::Break(label)) => (2, value, label.as_deref()),
+            Self::Normal(value, InterpreterState::Continue(label)) => (3, value, label.as_deref()),
+            Self::Throw(error) => (4, error, None),
+            Self::Return(value) => (5, value, None),
+        };
+        vec![
+            Value::from(kind),
+            value.clone(),
+            label.map_or_else(Value::undefined, Value::from),
+        ]
+    }
+
+    /// Restores a completion from the values returned by [`Completion::to_values`].
+    fn from_values([kind, value, label]: [Value; 3]) -> Self {
+        let label = label.as_string().map(|label| Box::from(label.as_str()));
+        match kind.as_number().map(|kind| kind as u8) {
+            Some(0) => Self::Normal(value, InterpreterState::Executing),
+            Some(1) => Self::Normal(value, InterpreterState::Return),
+            Some(2) => Self::Normal(value, InterpreterState::Break(label)),
+            Some(3) => Self::Normal(value, InterpreterState::Continue(label)),
+            Some(4) => Self::Throw(value),
+            _ => Self::Return(value),
+        }
     }
 }
 
@@ -151,27 +268,27 @@ impl From<Try> for Node {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Catch {
-    parameter: Option<Identifier>,
+    parameter: Option<Target>,
     block: Block,
 }
 
 impl Catch {
     /// Creates a new catch block.
-    pub(in crate::syntax) fn new<OI, I, B>(parameter: OI, block: B) -> Self
+    pub(in crate::syntax) fn new<OT, T, B>(parameter: OT, block: B) -> Self
     where
-        OI: Into<Option<I>>,
-        I: Into<Identifier>,
+        OT: Into<Option<T>>,
+        T: Into<Target>,
         B: Into<Block>,
     {
         Self {
-            parameter: parameter.into().map(I::into),
+            parameter: parameter.into().map(T::into),
             block: block.into(),
         }
     }
 
     /// Gets the parameter of the catch block.
-    pub fn parameter(&self) -> Option<&str> {
-        self.parameter.as_ref().map(Identifier::as_ref)
+    pub fn parameter(&self) -> Option<&Target> {
+        self.parameter.as_ref()
     }
 
     /// Retrieves the catch execution block.
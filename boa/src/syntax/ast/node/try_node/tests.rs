@@ -93,3 +93,42 @@ fn catch_binding_finally() {
     "#;
     assert_eq!(&exec(scenario), "30");
 }
+
+#[test]
+fn finally_after_return() {
+    let scenario = r#"
+        let log = [];
+        function f() {
+            try {
+                return 10;
+            } finally {
+                log.push(1);
+                log.push(2);
+            }
+        }
+        f() + ',' + log.join('');
+    "#;
+    assert_eq!(&exec(scenario), "\"10,12\"");
+}
+
+#[test]
+fn finally_overrides_completion() {
+    let scenario = r#"
+        function returns() {
+            try {
+                return 10;
+            } finally {
+                return 20;
+            }
+        }
+        function throws() {
+            try {
+                throw 10;
+            } finally {
+                return 30;
+            }
+        }
+        returns() + throws();
+    "#;
+    assert_eq!(&exec(scenario), "50");
+}
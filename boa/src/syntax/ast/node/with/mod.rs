@@ -0,0 +1,104 @@
+use super::Node;
+use crate::{
+    environment::lexical_environment::new_with_environment, exec::Executable, BoaProfiler, Context,
+    Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The `with` statement extends the scope chain for a statement with the properties of an
+/// object.
+///
+/// Identifiers in the statement that are the name of a property of the object, and that are not
+/// excluded by its `Symbol.unscopables` property, refer to that property. It is not allowed in
+/// strict mode code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/with
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct With {
+    expr: Box<Node>,
+    statement: Box<Node>,
+}
+
+impl With {
+    /// Creates a `With` AST node.
+    pub fn new<E, S>(expr: E, statement: S) -> Self
+    where
+        E: Into<Node>,
+        S: Into<Node>,
+    {
+        Self {
+            expr: Box::new(expr.into()),
+            statement: Box::new(statement.into()),
+        }
+    }
+
+    /// Gets the expression of the object whose properties are in scope.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    /// Gets the statement that is run with the properties of the object in scope.
+    pub fn statement(&self) -> &Node {
+        &self.statement
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        write!(f, "with ({}) ", self.expr)?;
+        self.statement.display(f, indentation)
+    }
+}
+
+impl Executable for With {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("With", "exec");
+        // The object is evaluated at step 0, and the statement is run at step 1. The environment
+        // of a resumed statement is restored along with its coroutine.
+        if !matches!(interpreter.resume_state(self), Some(state) if state.step == 1) {
+            let object = interpreter
+                .run_step(self, 0, Vec::new, |ctx| self.expr().run(ctx))?
+                .to_object(interpreter)?;
+            let unscopables = interpreter.well_known_symbols().unscopables_symbol();
+            let env = &mut interpreter.realm_mut().environment;
+            env.push(new_with_environment(
+                object.into(),
+                unscopables,
+                Some(env.get_current_environment_ref().clone()),
+            ));
+        }
+
+        let result = interpreter.run_step(self, 1, Vec::new, |ctx| self.statement().run(ctx));
+
+        if !interpreter.is_suspending() {
+            // pop the object env
+            let _ = interpreter.realm_mut().environment.pop();
+        }
+
+        result
+    }
+}
+
+impl fmt::Display for With {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<With> for Node {
+    fn from(with: With) -> Self {
+        Self::With(with)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::exec;
+
+#[test]
+fn with_reads_and_writes_properties() {
+    let scenario = r#"
+        var obj = { a: 1, b: 2 };
+        var c = 3;
+        with (obj) {
+            a = a + b + c;
+            c = 4;
+        }
+        obj.a + "," + c + "," + obj.hasOwnProperty("c")
+    "#;
+    assert_eq!(&exec(scenario), "\"6,4,false\"");
+}
+
+#[test]
+fn with_inherited_properties_and_accessors() {
+    let scenario = r#"
+        var log = [];
+        var proto = { inherited: "i" };
+        var obj = Object.create(proto, {
+            x: {
+                get: function () { log.push("get"); return 1; },
+                set: function (v) { log.push("set " + v); },
+            },
+        });
+        with (obj) {
+            x = x + 1;
+            log.push(inherited);
+        }
+        log.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"get,set 2,i\"");
+}
+
+#[test]
+fn with_call_this() {
+    let scenario = r#"
+        var obj = {
+            name: "obj",
+            getName: function () { return this.name; },
+        };
+        with (obj) {
+            getName();
+        }
+    "#;
+    assert_eq!(&exec(scenario), "\"obj\"");
+}
+
+#[test]
+fn with_unscopables() {
+    let scenario = r#"
+        var keys = "outer";
+        var values = "outer";
+        var obj = { keys: "inner", values: "inner" };
+        obj[Symbol.unscopables] = { keys: true, values: false };
+        var result;
+        with (obj) {
+            result = keys + "," + values;
+        }
+        result
+    "#;
+    assert_eq!(&exec(scenario), "\"outer,inner\"");
+}
+
+#[test]
+fn with_array_unscopables() {
+    let scenario = r#"
+        var includes = "outer";
+        var result;
+        with ([1, 2, 3]) {
+            result = includes + "," + length;
+        }
+        result
+    "#;
+    assert_eq!(&exec(scenario), "\"outer,3\"");
+}
+
+#[test]
+fn with_primitive_and_nullish() {
+    let scenario = r#"
+        var result;
+        with ("abc") {
+            result = length;
+        }
+        result
+    "#;
+    assert_eq!(&exec(scenario), "3");
+
+    let scenario = r#"
+        try {
+            with (null) {}
+        } catch (e) {
+            e instanceof TypeError
+        }
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn with_scope_ends() {
+    let scenario = r#"
+        var a = "outer";
+        with ({ a: "inner" }) {}
+        try {
+            with ({ a: "inner" }) {
+                throw 1;
+            }
+        } catch (e) {}
+        a
+    "#;
+    assert_eq!(&exec(scenario), "\"outer\"");
+}
+
+#[test]
+fn with_delete_binding() {
+    let scenario = r#"
+        var obj = { a: 1 };
+        var deleted;
+        with (obj) {
+            deleted = delete a;
+        }
+        deleted + "," + ("a" in obj)
+    "#;
+    assert_eq!(&exec(scenario), "\"true,false\"");
+}
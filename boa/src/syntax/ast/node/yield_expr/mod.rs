@@ -0,0 +1,179 @@
+//! Yield expression node.
+
+use super::Node;
+use crate::{
+    builtins::iterable::{create_iter_result_object, get_iterator, IteratorRecord},
+    exec::{coroutine::ResumeKind, Executable, InterpreterState},
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The `yield` expression is used to pause and resume a generator function.
+///
+/// The value of the expression is returned to the caller of the generator, and the `yield`
+/// expression evaluates to the value passed to the `next()` method that resumes it. With
+/// `yield*`, the values are taken from another iterable until it is exhausted.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct YieldExpr {
+    expr: Option<Box<Node>>,
+    delegate: bool,
+}
+
+impl YieldExpr {
+    /// Creates a new `yield` expression. `yield*` expressions are created with `delegate` set.
+    pub fn new<E>(expr: E, delegate: bool) -> Self
+    where
+        E: Into<Option<Node>>,
+    {
+        Self {
+            expr: expr.into().map(Box::new),
+            delegate,
+        }
+    }
+
+    /// Gets the expression whose value is yielded, if any.
+    pub fn expr(&self) -> Option<&Node> {
+        self.expr.as_deref()
+    }
+
+    /// Returns `true` if this is a `yield*` expression.
+    pub fn delegate(&self) -> bool {
+        self.delegate
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        f.write_str(if self.delegate { "yield*" } else { "yield" })?;
+        if let Some(ref expr) = self.expr {
+            f.write_str(" ")?;
+            expr.display(f, indentation)?;
+        }
+        Ok(())
+    }
+
+    /// Runs a round of a `yield*` expression, passing `received` to the iterable as requested by
+    /// `kind`, then yielding the value it produces, unless it is done.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator-function-definitions-runtime-semantics-evaluation
+    fn run_delegate(
+        &self,
+        iterator_record: IteratorRecord,
+        kind: ResumeKind,
+        received: Value,
+        interpreter: &mut Context,
+    ) -> Result<Value> {
+        let iterator = iterator_record.iterator_object().clone();
+        let inner_result = match kind {
+            ResumeKind::Next => {
+                interpreter.call(iterator_record.next_function(), &iterator, &[received])?
+            }
+            ResumeKind::Throw => {
+                let throw = iterator.get_field_with_receiver("throw", &iterator, interpreter)?;
+                if throw.is_null_or_undefined() {
+                    // The delegate has no `throw` method, so it is closed and the protocol
+                    // violation is reported instead.
+                    iterator_record.close(Ok(Value::undefined()), interpreter)?;
+                    return interpreter
+                        .throw_type_error("the iterator does not have a 'throw' method");
+                }
+                interpreter.call(&throw, &iterator, &[received])?
+            }
+            ResumeKind::Return => {
+                let return_method =
+                    iterator.get_field_with_receiver("return", &iterator, interpreter)?;
+                if return_method.is_null_or_undefined() {
+                    return Err(interpreter.return_from_coroutine(received));
+                }
+                interpreter.call(&return_method, &iterator, &[received])?
+            }
+        };
+        // unset the early return flag of the called method
+        interpreter
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+        if !inner_result.is_object() {
+            return interpreter.throw_type_error("iterator result is not an object");
+        }
+        let done = inner_result
+            .get_field_with_receiver("done", &inner_result, interpreter)?
+            .to_boolean();
+        if done {
+            let value =
+                inner_result.get_field_with_receiver("value", &inner_result, interpreter)?;
+            return match kind {
+                ResumeKind::Return => Err(interpreter.return_from_coroutine(value)),
+                ResumeKind::Next | ResumeKind::Throw => Ok(value),
+            };
+        }
+
+        let values = vec![iterator, iterator_record.next_function().clone()];
+        Err(interpreter.suspend(self, 2, values, inner_result))
+    }
+}
+
+impl Executable for YieldExpr {
+    fn run(&self, interpreter: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("YieldExpr", "exec");
+        // The expression is evaluated at step 0. A `yield` expression is then suspended at
+        // step 1, and a `yield*` expression at step 2, along with the iterator it delegates to.
+        match interpreter.resume_state(self) {
+            Some(state) if state.step == 2 => {
+                let [iterator, next_function] = state.into_values();
+                let (kind, received) = interpreter.resumption();
+                let iterator_record = IteratorRecord::new(iterator, next_function);
+                self.run_delegate(iterator_record, kind, received, interpreter)
+            }
+            Some(state) if state.step == 1 => match interpreter.resumption() {
+                (ResumeKind::Next, value) => Ok(value),
+                (ResumeKind::Throw, value) => Err(value),
+                (ResumeKind::Return, value) => Err(interpreter.return_from_coroutine(value)),
+            },
+            _ => {
+                let value = match self.expr() {
+                    Some(expr) => interpreter.run_step(self, 0, Vec::new, |ctx| expr.run(ctx))?,
+                    None => Value::undefined(),
+                };
+
+                if self.delegate() {
+                    let iterator_record = get_iterator(interpreter, value)?;
+                    return self.run_delegate(
+                        iterator_record,
+                        ResumeKind::Next,
+                        Value::undefined(),
+                        interpreter,
+                    );
+                }
+
+                let result = create_iter_result_object(interpreter, value, false);
+                Err(interpreter.suspend(self, 1, Vec::new(), result))
+            }
+        }
+    }
+}
+
+impl fmt::Display for YieldExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<YieldExpr> for Node {
+    fn from(expr: YieldExpr) -> Self {
+        Self::YieldExpr(expr)
+    }
+}
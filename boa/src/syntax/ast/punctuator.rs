@@ -35,6 +35,12 @@ pub enum Punctuator {
     AssignAdd,
     /// `&=`
     AssignAnd,
+    /// `&&=`
+    AssignBoolAnd,
+    /// `||=`
+    AssignBoolOr,
+    /// `??=`
+    AssignCoalesce,
     /// `/=`
     AssignDiv,
     /// `<<=`
@@ -65,6 +71,8 @@ pub enum Punctuator {
     CloseBracket,
     /// `)`
     CloseParen,
+    /// `??`
+    Coalesce,
     /// `:`
     Colon,
     /// `,`
@@ -105,6 +113,8 @@ pub enum Punctuator {
     OpenBracket,
     /// `(`
     OpenParen,
+    /// `?.`
+    Optional,
     /// `|`
     Or,
     /// `**`
@@ -137,6 +147,9 @@ impl Punctuator {
         match self {
             Self::AssignAdd => Some(BinOp::Assign(AssignOp::Add)),
             Self::AssignAnd => Some(BinOp::Assign(AssignOp::And)),
+            Self::AssignBoolAnd => Some(BinOp::Assign(AssignOp::BoolAnd)),
+            Self::AssignBoolOr => Some(BinOp::Assign(AssignOp::BoolOr)),
+            Self::AssignCoalesce => Some(BinOp::Assign(AssignOp::Coalesce)),
             Self::AssignDiv => Some(BinOp::Assign(AssignOp::Div)),
             Self::AssignLeftSh => Some(BinOp::Assign(AssignOp::Shl)),
             Self::AssignMod => Some(BinOp::Assign(AssignOp::Mod)),
@@ -157,6 +170,7 @@ impl Punctuator {
             Self::Xor => Some(BinOp::Bit(BitOp::Xor)),
             Self::BoolAnd => Some(BinOp::Log(LogOp::And)),
             Self::BoolOr => Some(BinOp::Log(LogOp::Or)),
+            Self::Coalesce => Some(BinOp::Log(LogOp::Coalesce)),
             Self::Eq => Some(BinOp::Comp(CompOp::Equal)),
             Self::NotEq => Some(BinOp::Comp(CompOp::NotEqual)),
             Self::StrictEq => Some(BinOp::Comp(CompOp::StrictEqual)),
@@ -194,6 +208,9 @@ impl Display for Punctuator {
                 Self::Assign => "=",
                 Self::AssignAdd => "+=",
                 Self::AssignAnd => "&=",
+                Self::AssignBoolAnd => "&&=",
+                Self::AssignBoolOr => "||=",
+                Self::AssignCoalesce => "??=",
                 Self::AssignDiv => "/=",
                 Self::AssignLeftSh => "<<=",
                 Self::AssignMod => "%=",
@@ -209,6 +226,7 @@ impl Display for Punctuator {
                 Self::CloseBlock => "}",
                 Self::CloseBracket => "]",
                 Self::CloseParen => ")",
+                Self::Coalesce => "??",
                 Self::Colon => ":",
                 Self::Comma => ",",
                 Self::Dec => "--",
@@ -229,6 +247,7 @@ impl Display for Punctuator {
                 Self::OpenBlock => "{",
                 Self::OpenBracket => "[",
                 Self::OpenParen => "(",
+                Self::Optional => "?.",
                 Self::Or => "|",
                 Self::Exp => "**",
                 Self::Question => "?",
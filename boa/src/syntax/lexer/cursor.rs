@@ -32,6 +32,18 @@ impl<R> Cursor<R> {
         self.pos = Position::new(next_line, 1);
     }
 
+    /// Gets the source code that has been consumed by the cursor so far.
+    #[inline]
+    pub(super) fn source(&self) -> &str {
+        &self.iter.source
+    }
+
+    /// Gets the byte offset of the cursor in the source code.
+    #[inline]
+    pub(super) fn offset(&self) -> usize {
+        self.iter.source.len()
+    }
+
     #[inline]
     pub(super) fn strict_mode(&self) -> bool {
         self.strict_mode
@@ -49,6 +61,7 @@ where
 {
     /// Creates a new Lexer cursor.
     #[inline]
+    #[allow(clippy::unbuffered_bytes)]
     pub(super) fn new(inner: R) -> Self {
         Self {
             iter: InnerIter::new(inner.bytes()),
@@ -65,6 +78,14 @@ where
         self.iter.peek_char()
     }
 
+    /// Peeks the character after the next one.
+    #[inline]
+    pub(super) fn peek_second(&mut self) -> Result<Option<char>, Error> {
+        let _timer = BoaProfiler::global().start_event("cursor::peek_second()", "Lexing");
+
+        self.iter.peek_second_char()
+    }
+
     /// Compares the character passed in to the next character, if they match true is returned and the buffer is incremented
     #[inline]
     pub(super) fn next_is(&mut self, peek: char) -> io::Result<bool> {
@@ -109,7 +130,7 @@ where
             } else if let Some(ch) = self.next_char()? {
                 buf.push(ch);
             } else {
-                return Err(io::Error::new(
+                return Err(Error::new(
                     ErrorKind::UnexpectedEof,
                     format!("Unexpected end of file when looking for character {}", stop),
                 ));
@@ -180,6 +201,9 @@ where
 struct InnerIter<R> {
     iter: Bytes<R>,
     peeked_char: Option<Option<char>>,
+    second_peeked_char: Option<Option<char>>,
+    /// The characters consumed so far, which are kept to get back the source text of functions.
+    source: String,
 }
 
 impl<R> InnerIter<R> {
@@ -189,6 +213,8 @@ impl<R> InnerIter<R> {
         Self {
             iter,
             peeked_char: None,
+            second_peeked_char: None,
+            source: String::new(),
         }
     }
 }
@@ -205,8 +231,8 @@ where
     fn fill_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
         for byte in buf.iter_mut() {
             *byte = self.next_ascii()?.ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::UnexpectedEof,
+                Error::new(
+                    ErrorKind::UnexpectedEof,
                     "unexpected EOF when filling buffer",
                 )
             })?;
@@ -220,18 +246,43 @@ where
         if let Some(v) = self.peeked_char {
             Ok(v)
         } else {
-            let chr = self.next_char()?;
+            let chr = self.read_char()?;
             self.peeked_char = Some(chr);
             Ok(chr)
         }
     }
 
+    /// Peeks the UTF-8 checked character after the next one.
+    #[inline]
+    fn peek_second_char(&mut self) -> Result<Option<char>, Error> {
+        let _ = self.peek_char()?;
+        if let Some(v) = self.second_peeked_char {
+            Ok(v)
+        } else {
+            let chr = self.read_char()?;
+            self.second_peeked_char = Some(chr);
+            Ok(chr)
+        }
+    }
+
     /// Retrieves the next UTF-8 checked character.
     fn next_char(&mut self) -> io::Result<Option<char>> {
-        if let Some(v) = self.peeked_char.take() {
-            return Ok(v);
+        let chr = if let Some(v) = self.peeked_char.take() {
+            self.peeked_char = self.second_peeked_char.take();
+            v
+        } else {
+            self.read_char()?
+        };
+
+        if let Some(chr) = chr {
+            self.source.push(chr);
         }
+        Ok(chr)
+    }
 
+    /// Reads the next UTF-8 checked character from the underlying bytes, ignoring the peeked
+    /// characters.
+    fn read_char(&mut self) -> io::Result<Option<char>> {
         let first_byte = match self.iter.next().transpose()? {
             Some(b) => b,
             None => return Ok(None),
@@ -258,8 +309,8 @@ where
                     Some(Ok(b)) => b,
                     Some(Err(e)) => return Err(e),
                     None => {
-                        return Err(io::Error::new(
-                            io::ErrorKind::InvalidData,
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
                             "stream did not contain valid UTF-8",
                         ))
                     }
@@ -272,14 +323,14 @@ where
                 if let Some(chr) = s.chars().next() {
                     chr
                 } else {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidData,
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
                         "stream did not contain valid UTF-8",
                     ));
                 }
             } else {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
                     "stream did not contain valid UTF-8",
                 ));
             }
@@ -294,10 +345,7 @@ where
         match self.next_char() {
             Ok(Some(chr)) if chr.is_ascii() => Ok(Some(chr as u8)),
             Ok(None) => Ok(None),
-            _ => Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "non-ASCII byte found",
-            )),
+            _ => Err(Error::new(ErrorKind::InvalidData, "non-ASCII byte found")),
         }
     }
 }
@@ -10,20 +10,6 @@ use crate::{
 };
 use std::io::Read;
 
-const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 11] = [
-    "eval",
-    "arguments",
-    "implements",
-    "interface",
-    "let",
-    "package",
-    "private",
-    "protected",
-    "public",
-    "static",
-    "yield",
-];
-
 /// Identifier lexing.
 ///
 /// More information:
@@ -54,7 +40,7 @@ impl<R> Tokenizer<R> for Identifier {
         let mut buf = self.init.to_string();
 
         cursor.take_while_pred(&mut buf, &|c: char| {
-            c.is_alphabetic() || c.is_digit(10) || c == '_'
+            c.is_alphabetic() || c.is_ascii_digit() || c == '_'
         })?;
 
         let tk = match buf.as_str() {
@@ -71,16 +57,6 @@ impl<R> Tokenizer<R> for Identifier {
                     }
                     TokenKind::Keyword(keyword)
                 } else {
-                    if cursor.strict_mode() && STRICT_FORBIDDEN_IDENTIFIERS.contains(&slice) {
-                        return Err(Error::Syntax(
-                            format!(
-                                "using future reserved keyword '{}' not allowed in strict mode",
-                                slice
-                            )
-                            .into(),
-                            start_pos,
-                        ));
-                    }
                     TokenKind::identifier(slice)
                 }
             }
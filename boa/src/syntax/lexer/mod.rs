@@ -44,6 +44,7 @@ use crate::syntax::ast::{Punctuator, Span};
 pub use crate::{profiler::BoaProfiler, syntax::ast::Position};
 pub use error::Error;
 use std::io::Read;
+pub use template::TemplateString;
 pub use token::{Token, TokenKind};
 
 trait Tokenizer<R> {
@@ -90,6 +91,18 @@ impl<R> Lexer<R> {
         self.goal_symbol
     }
 
+    /// Gets the source code that has been lexed so far.
+    #[inline]
+    pub(super) fn source(&self) -> &str {
+        self.cursor.source()
+    }
+
+    /// Gets the byte offset of the lexer in the source code.
+    #[inline]
+    pub(super) fn offset(&self) -> usize {
+        self.cursor.offset()
+    }
+
     #[inline]
     pub(super) fn strict_mode(&self) -> bool {
         self.cursor.strict_mode()
@@ -169,6 +182,15 @@ impl<R> Lexer<R> {
         }
     }
 
+    /// Lexes the part of a template literal that follows a substitution, assuming that the `}`
+    /// ending the substitution has already been consumed.
+    pub(crate) fn lex_template(&mut self, start: Position) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        TemplateLiteral.lex(&mut self.cursor, start)
+    }
+
     /// Retrieves the next token from the lexer.
     // We intentionally don't implement Iterator trait as Result<Option> is cleaner to handle.
     #[allow(clippy::should_implement_trait)]
@@ -189,6 +211,7 @@ impl<R> Lexer<R> {
                 return Ok(None);
             }
         };
+        let start_offset = self.cursor.offset() - next_chr.len_utf8();
 
         let token = match next_chr {
             '\r' | '\n' | '\u{2028}' | '\u{2029}' => Ok(Token::new(
@@ -197,7 +220,9 @@ impl<R> Lexer<R> {
             )),
             '"' | '\'' => StringLiteral::new(next_chr).lex(&mut self.cursor, start),
             '`' => TemplateLiteral.lex(&mut self.cursor, start),
-            _ if next_chr.is_digit(10) => NumberLiteral::new(next_chr).lex(&mut self.cursor, start),
+            _ if next_chr.is_ascii_digit() => {
+                NumberLiteral::new(next_chr).lex(&mut self.cursor, start)
+            }
             _ if next_chr.is_alphabetic() || next_chr == '$' || next_chr == '_' => {
                 Identifier::new(next_chr).lex(&mut self.cursor, start)
             }
@@ -238,12 +263,8 @@ impl<R> Lexer<R> {
                 Punctuator::CloseBracket.into(),
                 Span::new(start, self.cursor.pos()),
             )),
-            '?' => Ok(Token::new(
-                Punctuator::Question.into(),
-                Span::new(start, self.cursor.pos()),
-            )),
             '/' => self.lex_slash_token(start),
-            '=' | '*' | '+' | '-' | '%' | '|' | '&' | '^' | '<' | '>' | '!' | '~' => {
+            '=' | '*' | '+' | '-' | '%' | '|' | '&' | '^' | '<' | '>' | '!' | '~' | '?' => {
                 Operator::new(next_chr).lex(&mut self.cursor, start)
             }
             _ => {
@@ -255,7 +276,8 @@ impl<R> Lexer<R> {
                 );
                 Err(Error::syntax(details, start))
             }
-        }?;
+        }?
+        .with_offsets(start_offset, self.cursor.offset());
 
         if token.kind() == &TokenKind::Comment {
             // Skip comment
@@ -269,16 +291,12 @@ impl<R> Lexer<R> {
 /// ECMAScript goal symbols.
 ///
 /// <https://tc39.es/ecma262/#sec-ecmascript-language-lexical-grammar>
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub(crate) enum InputElement {
     Div,
+    #[default]
     RegExp,
+    #[allow(dead_code)]
     RegExpOrTemplateTail,
     TemplateTail,
 }
-
-impl Default for InputElement {
-    fn default() -> Self {
-        InputElement::RegExp
-    }
-}
@@ -160,7 +160,7 @@ impl<R> Tokenizer<R> for NumberLiteral {
 
                         // Checks if the next char after '0x' is a digit of that base. if not return an error.
                         if let Some(digit) = cursor.peek()? {
-                            if !digit.is_digit(16) {
+                            if !digit.is_ascii_hexdigit() {
                                 return Err(Error::syntax(
                                     "expected hexadecimal digit after number base prefix",
                                     cursor.pos(),
@@ -230,7 +230,7 @@ impl<R> Tokenizer<R> for NumberLiteral {
 
                                 kind = NumericKind::Integer(8);
                             }
-                        } else if ch.is_digit(10) {
+                        } else if ch.is_ascii_digit() {
                             // Indicates a numerical digit comes after then 0 but it isn't an octal digit
                             // so therefore this must be a number with an unneeded leading 0. This is
                             // forbidden in strict mode.
@@ -118,10 +118,10 @@ impl<R> Tokenizer<R> for Operator {
                 Ok(Punctuator::Mod)
             ),
             '|' => op!(cursor, start_pos, Ok(Punctuator::AssignOr), Ok(Punctuator::Or), {
-                Some('|') => Ok(Punctuator::BoolOr)
+                Some('|') => vop!(cursor, Ok(Punctuator::AssignBoolOr), Ok(Punctuator::BoolOr))
             }),
             '&' => op!(cursor, start_pos, Ok(Punctuator::AssignAnd), Ok(Punctuator::And), {
-                Some('&') => Ok(Punctuator::BoolAnd)
+                Some('&') => vop!(cursor, Ok(Punctuator::AssignBoolAnd), Ok(Punctuator::BoolAnd))
             }),
             '^' => op!(
                 cursor,
@@ -154,6 +154,26 @@ impl<R> Tokenizer<R> for Operator {
                 vop!(cursor, Ok(Punctuator::StrictNotEq), Ok(Punctuator::NotEq)),
                 Ok(Punctuator::Not)
             ),
+            '?' => {
+                let punc = match cursor.peek()? {
+                    Some('?') => {
+                        let _ = cursor.next_char()?.expect("? token vanished");
+                        if cursor.next_is('=')? {
+                            Punctuator::AssignCoalesce
+                        } else {
+                            Punctuator::Coalesce
+                        }
+                    }
+                    // `?.` followed by a digit is a conditional operator followed by a number,
+                    // as in `a?.5:b`.
+                    Some('.') if !cursor.peek_second()?.is_some_and(|ch| ch.is_ascii_digit()) => {
+                        let _ = cursor.next_char()?.expect(". token vanished");
+                        Punctuator::Optional
+                    }
+                    _ => Punctuator::Question,
+                };
+                Ok(Token::new(punc.into(), Span::new(start_pos, cursor.pos())))
+            }
             '~' => Ok(Token::new(
                 Punctuator::Neg.into(),
                 Span::new(start_pos, cursor.pos()),
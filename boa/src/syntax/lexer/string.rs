@@ -90,14 +90,40 @@ impl<R> Tokenizer<R> for StringLiteral {
                             't' => buf.push('\t' as u16),
                             'b' => buf.push('\x08' as u16),
                             'f' => buf.push('\x0c' as u16),
-                            '0' => buf.push('\0' as u16),
+                            '0' if !cursor.next_is_pred(&|ch: char| ch.is_ascii_digit())? => {
+                                buf.push('\0' as u16)
+                            }
+                            '0'..='9' if cursor.strict_mode() => {
+                                return Err(Error::syntax(
+                                    "octal escape sequences are not allowed in strict mode",
+                                    next_chr_start,
+                                ));
+                            }
+                            '0'..='7' => {
+                                // LegacyOctalEscapeSequence, which has up to three digits if it
+                                // starts with `0` to `3`, and up to two otherwise.
+                                let max_len = if escape <= '3' { 3 } else { 2 };
+                                let mut code_unit = escape as u16 - '0' as u16;
+                                for _ in 1..max_len {
+                                    match cursor.peek()? {
+                                        Some(ch @ '0'..='7') => {
+                                            cursor.next_char()?;
+                                            code_unit = code_unit * 8 + (ch as u16 - '0' as u16);
+                                        }
+                                        _ => break,
+                                    }
+                                }
+                                buf.push(code_unit);
+                            }
+                            // NonOctalDecimalEscapeSequence
+                            '8' | '9' => buf.push(escape as u16),
                             'x' => {
                                 let mut code_point_utf8_bytes = [0u8; 2];
                                 cursor.fill_bytes(&mut code_point_utf8_bytes)?;
                                 let code_point_str = str::from_utf8(&code_point_utf8_bytes)
                                     .expect("malformed Hexadecimal character escape sequence");
                                 let code_point =
-                                    u16::from_str_radix(&code_point_str, 16).map_err(|_| {
+                                    u16::from_str_radix(code_point_str, 16).map_err(|_| {
                                         Error::syntax(
                                             "invalid Hexadecimal escape sequence",
                                             cursor.pos(),
@@ -183,7 +209,7 @@ impl<R> Tokenizer<R> for StringLiteral {
         }
 
         Ok(Token::new(
-            TokenKind::string_literal(String::from_utf16_lossy(buf.as_slice())),
+            TokenKind::string_literal(buf),
             Span::new(start_pos, cursor.pos()),
         ))
     }
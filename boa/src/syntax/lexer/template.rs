@@ -7,12 +7,22 @@ use crate::{
         ast::{Position, Span},
         lexer::{Token, TokenKind},
     },
+    value::RcString,
 };
-use std::io::{self, ErrorKind, Read};
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, ErrorKind, Read},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Template literal lexing.
 ///
-/// Expects: Initial ` to already be consumed by cursor.
+/// Lexes a part of a template literal, up to and including the backtick that ends the literal or
+/// the `${` that starts a substitution.
+///
+/// Expects: Initial ` or the `}` that ends a substitution to already be consumed by cursor.
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
@@ -30,23 +40,171 @@ impl<R> Tokenizer<R> for TemplateLiteral {
     {
         let _timer = BoaProfiler::global().start_event("TemplateLiteral", "Lexing");
 
-        let mut buf = String::new();
+        let unterminated = || {
+            Error::from(io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "Unterminated template literal",
+            ))
+        };
+
+        let mut raw = String::new();
         loop {
-            match cursor.next_char()? {
-                None => {
-                    return Err(Error::from(io::Error::new(
-                        ErrorKind::UnexpectedEof,
-                        "Unterminated template literal",
-                    )));
+            match cursor.next_char()?.ok_or_else(unterminated)? {
+                // Template literal finished.
+                '`' => {
+                    return Ok(Token::new(
+                        TokenKind::template_no_substitution(TemplateString::new(raw)),
+                        Span::new(start_pos, cursor.pos()),
+                    ));
+                }
+                // A substitution starts.
+                '$' if cursor.next_is('{')? => {
+                    return Ok(Token::new(
+                        TokenKind::template_middle(TemplateString::new(raw)),
+                        Span::new(start_pos, cursor.pos()),
+                    ));
+                }
+                // Escape sequences are kept as they are in the raw string, so that an escaped
+                // backtick or `$` does not end this part of the literal.
+                '\\' => {
+                    raw.push('\\');
+                    match cursor.next_char()?.ok_or_else(unterminated)? {
+                        '\r' => {
+                            let _ = cursor.next_is('\n')?;
+                            raw.push('\n');
+                        }
+                        escaped => raw.push(escaped),
+                    }
                 }
-                Some('`') => break,                 // Template literal finished.
-                Some(next_ch) => buf.push(next_ch), // TODO when there is an expression inside the literal
+                // <CR><LF> and <CR> line terminators are normalized to <LF>.
+                '\r' => {
+                    let _ = cursor.next_is('\n')?;
+                    raw.push('\n');
+                }
+                next_ch => raw.push(next_ch),
             }
         }
+    }
+}
+
+/// A part of a template literal, holding both its raw and its cooked string.
+///
+/// The raw string is the source text of the part, with line terminators normalized, and the
+/// cooked string is the value of the part, with its escape sequences interpreted. Tagged
+/// templates allow escape sequences that are not valid, in which case there is no cooked string.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-tv-and-trv
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateString {
+    raw: Box<str>,
+    cooked: Option<RcString>,
+}
+
+impl TemplateString {
+    /// Creates a new `TemplateString` from its raw string, cooking it.
+    pub fn new<R>(raw: R) -> Self
+    where
+        R: Into<Box<str>>,
+    {
+        let raw = raw.into();
+        let cooked = cook(&raw);
+        Self { raw, cooked }
+    }
+
+    /// Gets the raw string of the template part.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Gets the cooked string of the template part, if its escape sequences are all valid.
+    pub fn cooked(&self) -> Option<&RcString> {
+        self.cooked.as_ref()
+    }
+}
+
+impl Display for TemplateString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.raw, f)
+    }
+}
+
+/// Interprets the escape sequences of a raw template string.
+///
+/// Returns `None` if any of the escape sequences is not valid.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-tv-and-trv
+fn cook(raw: &str) -> Option<RcString> {
+    let mut buf: Vec<u16> = Vec::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            push_code_point(&mut buf, u32::from(ch));
+            continue;
+        }
+
+        match chars.next()? {
+            // Line continuation.
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            'n' => buf.push('\n' as u16),
+            'r' => buf.push('\r' as u16),
+            't' => buf.push('\t' as u16),
+            'b' => buf.push('\x08' as u16),
+            'f' => buf.push('\x0c' as u16),
+            'v' => buf.push('\x0b' as u16),
+            '0' if !chars.peek().is_some_and(char::is_ascii_digit) => buf.push(0),
+            // Legacy octal escape sequences are not allowed in templates.
+            '0'..='9' => return None,
+            'x' => {
+                let high = chars.next()?.to_digit(16)?;
+                let low = chars.next()?.to_digit(16)?;
+                buf.push((high * 16 + low) as u16);
+            }
+            'u' if chars.peek() == Some(&'{') => {
+                let _ = chars.next();
+                let mut code_point = 0u32;
+                let mut digits = 0;
+                loop {
+                    match chars.next()? {
+                        '}' if digits > 0 => break,
+                        digit => {
+                            code_point = code_point * 16 + digit.to_digit(16)?;
+                            if code_point > 0x10_FFFF {
+                                return None;
+                            }
+                            digits += 1;
+                        }
+                    }
+                }
+                push_code_point(&mut buf, code_point);
+            }
+            'u' => {
+                let mut code_unit = 0u32;
+                for _ in 0..4 {
+                    code_unit = code_unit * 16 + chars.next()?.to_digit(16)?;
+                }
+                buf.push(code_unit as u16);
+            }
+            escaped => push_code_point(&mut buf, u32::from(escaped)),
+        }
+    }
+
+    Some(buf.into())
+}
 
-        Ok(Token::new(
-            TokenKind::template_literal(buf),
-            Span::new(start_pos, cursor.pos()),
-        ))
+/// Pushes the UTF-16 encoding of a code point to the buffer.
+fn push_code_point(buf: &mut Vec<u16>, code_point: u32) {
+    if code_point <= 0xFFFF {
+        buf.push(code_point as u16);
+    } else {
+        let code_point = code_point - 0x1_0000;
+        buf.push((code_point / 0x400 + 0xD800) as u16);
+        buf.push((code_point % 0x400 + 0xDC00) as u16);
     }
 }
@@ -5,7 +5,7 @@ use super::regex::RegExpFlags;
 use super::token::Numeric;
 use super::*;
 use super::{Error, Position};
-use crate::syntax::ast::Keyword;
+use crate::{syntax::ast::Keyword, value::RcString};
 
 fn span(start: (u32, u32), end: (u32, u32)) -> Span {
     Span::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
@@ -25,6 +25,20 @@ where
     );
 }
 
+#[test]
+fn check_token_offsets() {
+    let s = "let é = /* ü */ \"ß\";";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let mut offsets = Vec::new();
+    while let Some(token) = lexer.next().unwrap() {
+        offsets.push((token.start_offset(), token.end_offset()));
+    }
+
+    assert_eq!(offsets, [(0, 3), (4, 6), (7, 8), (18, 22), (22, 23)]);
+    assert_eq!(&s[18..22], "\"ß\"");
+}
+
 #[test]
 fn check_single_line_comment() {
     let s1 = "var \n//This is a comment\ntrue";
@@ -67,6 +81,47 @@ fn check_string() {
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn check_string_lone_surrogate() {
+    let s = r"'\uD83D'";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [TokenKind::string_literal(&[0xD83D][..])];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_string_legacy_octal_escape() {
+    let s = r"'\01' '\101' '\477' '\8'";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::string_literal("\u{1}"),
+        TokenKind::string_literal("A"),
+        TokenKind::string_literal("'7"),
+        TokenKind::string_literal("8"),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_string_octal_escape_strict() {
+    let mut lexer = Lexer::new(&br"'\01'"[..]);
+    lexer.set_strict_mode(true);
+
+    assert!(lexer.next().is_err());
+
+    let mut lexer = Lexer::new(&br"'\0'"[..]);
+    lexer.set_strict_mode(true);
+
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::string_literal("\u{0}")
+    );
+}
+
 #[test]
 fn check_template_literal_simple() {
     let s = "`I'm a template literal`";
@@ -74,7 +129,80 @@ fn check_template_literal_simple() {
 
     assert_eq!(
         lexer.next().unwrap().unwrap().kind(),
-        &TokenKind::template_literal("I'm a template literal")
+        &TokenKind::template_no_substitution(TemplateString::new("I'm a template literal"))
+    );
+}
+
+#[test]
+fn check_template_literal_substitutions() {
+    let s = r"`a${b}c\\${d}\${e}`";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::template_middle(TemplateString::new("a"))
+    );
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::identifier("b")
+    );
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::Punctuator(Punctuator::CloseBlock)
+    );
+
+    let start = lexer.cursor.pos();
+    assert_eq!(
+        lexer.lex_template(start).unwrap().kind(),
+        &TokenKind::template_middle(TemplateString::new(r"c\\"))
+    );
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::identifier("d")
+    );
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::Punctuator(Punctuator::CloseBlock)
+    );
+
+    let start = lexer.cursor.pos();
+    assert_eq!(
+        lexer.lex_template(start).unwrap().kind(),
+        &TokenKind::template_no_substitution(TemplateString::new(r"\${e}"))
+    );
+}
+
+#[test]
+fn check_template_string_cooking() {
+    let template = TemplateString::new("a\\n\\x41\\u0042\\u{1F600}\\\\\\`\\\nb");
+    assert_eq!(
+        template.cooked().map(RcString::as_str),
+        Some("a\nAB\u{1F600}\\`b")
+    );
+
+    let template = TemplateString::new("\\uD83D");
+    assert_eq!(
+        template.cooked().map(RcString::code_units),
+        Some(&[0xD83D][..])
+    );
+
+    let template = TemplateString::new(r"\unicode and \u{55}");
+    assert_eq!(template.raw(), r"\unicode and \u{55}");
+    assert_eq!(template.cooked(), None);
+
+    let template = TemplateString::new(r"\01");
+    assert_eq!(template.cooked(), None);
+}
+
+#[test]
+fn check_template_literal_line_terminators() {
+    let s = "`a\r\nb\rc`";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let kind = lexer.next().unwrap().unwrap().kind().clone();
+    assert_eq!(
+        kind,
+        TokenKind::template_no_substitution(TemplateString::new("a\nb\nc"))
     );
 }
 
@@ -93,7 +221,7 @@ fn check_punctuators() {
     // https://tc39.es/ecma262/#sec-punctuators
     let s = "{ ( ) [ ] . ... ; , < > <= >= == != === !== \
              + - * % -- << >> >>> & | ^ ! ~ && || ? : \
-             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= =>";
+             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= => ?? ?. &&= ||= ??=";
     let mut lexer = Lexer::new(s.as_bytes());
 
     let expected = [
@@ -146,11 +274,31 @@ fn check_punctuators() {
         TokenKind::Punctuator(Punctuator::AssignOr),
         TokenKind::Punctuator(Punctuator::AssignXor),
         TokenKind::Punctuator(Punctuator::Arrow),
+        TokenKind::Punctuator(Punctuator::Coalesce),
+        TokenKind::Punctuator(Punctuator::Optional),
+        TokenKind::Punctuator(Punctuator::AssignBoolAnd),
+        TokenKind::Punctuator(Punctuator::AssignBoolOr),
+        TokenKind::Punctuator(Punctuator::AssignCoalesce),
     ];
 
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn check_optional_chain_before_digit() {
+    let s = "a?.5:b";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::identifier("a")
+    );
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::Punctuator(Punctuator::Question)
+    );
+}
+
 #[test]
 fn check_keywords() {
     // https://tc39.es/ecma262/#sec-keywords
@@ -640,7 +788,7 @@ fn illegal_code_point_following_numeric_literal() {
     let mut lexer = Lexer::new(&br#"17.4\u{{2764}}"#[..]);
     assert!(
         lexer.next().is_err(),
-        "IdentifierStart \\u{{2764}} following NumericLiteral not rejected as expected"
+        "IdentifierStart \\u2764 following NumericLiteral not rejected as expected"
     );
 }
 
@@ -5,11 +5,12 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-tokens
 
-use super::regex::RegExpFlags;
+use super::{regex::RegExpFlags, template::TemplateString};
 
 use crate::{
     builtins::BigInt,
     syntax::ast::{Keyword, Punctuator, Span},
+    value::RcString,
 };
 
 use std::fmt::{self, Debug, Display, Formatter};
@@ -30,13 +31,28 @@ pub struct Token {
     kind: TokenKind,
     /// The token position in the original source code.
     span: Span,
+    /// The byte offsets of the start and the end of the token in the original source code.
+    offsets: (usize, usize),
 }
 
 impl Token {
     /// Create a new detailed token from the token data, line number and column number
     #[inline]
     pub fn new(kind: TokenKind, span: Span) -> Self {
-        Self { kind, span }
+        Self {
+            kind,
+            span,
+            offsets: (0, 0),
+        }
+    }
+
+    /// Sets the byte offsets of the start and the end of the token in the source code.
+    #[inline]
+    pub(crate) fn with_offsets(self, start: usize, end: usize) -> Self {
+        Self {
+            offsets: (start, end),
+            ..self
+        }
     }
 
     /// Gets the kind of the token.
@@ -50,6 +66,18 @@ impl Token {
     pub fn span(&self) -> Span {
         self.span
     }
+
+    /// Gets the byte offset of the start of the token in the original source code.
+    #[inline]
+    pub(crate) fn start_offset(&self) -> usize {
+        self.offsets.0
+    }
+
+    /// Gets the byte offset of the end of the token in the original source code.
+    #[inline]
+    pub(crate) fn end_offset(&self) -> usize {
+        self.offsets.1
+    }
 }
 
 impl Display for Token {
@@ -123,9 +151,15 @@ pub enum TokenKind {
     Punctuator(Punctuator),
 
     /// A string literal.
-    StringLiteral(Box<str>),
+    StringLiteral(RcString),
+
+    /// A template literal without substitutions, or the last part of a template literal with
+    /// substitutions, which both end with a backtick.
+    TemplateNoSubstitution(TemplateString),
 
-    TemplateLiteral(Box<str>),
+    /// The first or a middle part of a template literal with substitutions, which ends with the
+    /// `${` that starts a substitution.
+    TemplateMiddle(TemplateString),
 
     /// A regular expression, consisting of body and flags.
     RegularExpressionLiteral(Box<str>, RegExpFlags),
@@ -201,17 +235,19 @@ impl TokenKind {
     /// Creates a `StringLiteral` token type.
     pub fn string_literal<S>(lit: S) -> Self
     where
-        S: Into<Box<str>>,
+        S: Into<RcString>,
     {
         Self::StringLiteral(lit.into())
     }
 
-    /// Creates a `TemplateLiteral` token type.
-    pub fn template_literal<S>(lit: S) -> Self
-    where
-        S: Into<Box<str>>,
-    {
-        Self::TemplateLiteral(lit.into())
+    /// Creates a `TemplateNoSubstitution` token type.
+    pub fn template_no_substitution(template_string: TemplateString) -> Self {
+        Self::TemplateNoSubstitution(template_string)
+    }
+
+    /// Creates a `TemplateMiddle` token type.
+    pub fn template_middle(template_string: TemplateString) -> Self {
+        Self::TemplateMiddle(template_string)
     }
 
     /// Creates a `RegularExpressionLiteral` token kind.
@@ -247,7 +283,8 @@ impl Display for TokenKind {
             Self::NumericLiteral(Numeric::BigInt(ref num)) => write!(f, "{}n", num),
             Self::Punctuator(ref punc) => write!(f, "{}", punc),
             Self::StringLiteral(ref lit) => write!(f, "{}", lit),
-            Self::TemplateLiteral(ref lit) => write!(f, "{}", lit),
+            Self::TemplateNoSubstitution(ref ts) => write!(f, "{}", ts),
+            Self::TemplateMiddle(ref ts) => write!(f, "{}${{", ts),
             Self::RegularExpressionLiteral(ref body, ref flags) => write!(f, "/{}/{}", body, flags),
             Self::LineTerminator => write!(f, "line terminator"),
             Self::Comment => write!(f, "comment"),
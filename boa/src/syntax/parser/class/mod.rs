@@ -0,0 +1,319 @@
+//! Class definition parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+//! [spec]: https://tc39.es/ecma262/#sec-class-definitions
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::Number,
+    syntax::{
+        ast::{
+            node::{self, FunctionExpr, MethodDefinitionKind, PropertyName},
+            Keyword, Punctuator,
+        },
+        lexer::{token::Numeric, TokenKind},
+        parser::{
+            expression::{AssignmentExpression, LeftHandSideExpression},
+            function::{check_parameter_names, FormalParameters, FunctionBody},
+            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// The parts of a class definition that follow its name.
+#[derive(Debug)]
+pub(in crate::syntax::parser) struct ClassTailOutput {
+    pub(in crate::syntax::parser) super_ref: Option<node::Node>,
+    pub(in crate::syntax::parser) constructor: Option<FunctionExpr>,
+    pub(in crate::syntax::parser) elements: Vec<node::ClassElement>,
+}
+
+/// Class tail parsing.
+///
+/// Parses the optional `extends` clause and the body of a class.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ClassTail {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassTail {
+    /// Creates a new `ClassTail` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassTail
+where
+    R: Read,
+{
+    type Output = ClassTailOutput;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassTail", "Parsing");
+
+        // All parts of a class definition are strict mode code.
+        let global_strict_mode = cursor.strict_mode();
+        cursor.set_strict_mode(true);
+        let tail = self.parse_tail(cursor);
+        cursor.set_strict_mode(global_strict_mode);
+
+        tail
+    }
+}
+
+impl ClassTail {
+    /// Parses the class tail, once strict mode has been enabled.
+    fn parse_tail<R>(self, cursor: &mut Cursor<R>) -> Result<ClassTailOutput, ParseError>
+    where
+        R: Read,
+    {
+        let super_ref = if cursor.next_if(Keyword::Extends)?.is_some() {
+            Some(LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?)
+        } else {
+            None
+        };
+
+        cursor.expect(Punctuator::OpenBlock, "class definition")?;
+
+        let mut constructor = None;
+        let mut elements = Vec::new();
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+            if cursor.next_if(Punctuator::Semicolon)?.is_some() {
+                continue;
+            }
+
+            let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+            let element = ClassElement::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            let is_constructor = !element.is_static()
+                && element.name() == &PropertyName::Literal("constructor".into());
+            if !is_constructor {
+                elements.push(element);
+                continue;
+            }
+
+            if element.kind() != MethodDefinitionKind::Ordinary {
+                return Err(ParseError::unexpected(
+                    start,
+                    "class constructor can't be a getter, a setter or a generator",
+                ));
+            }
+            if constructor.is_some() {
+                return Err(ParseError::unexpected(
+                    start,
+                    "a class may only have one constructor",
+                ));
+            }
+            constructor = Some(element.function().clone());
+        }
+
+        Ok(ClassTailOutput {
+            super_ref,
+            constructor,
+            elements,
+        })
+    }
+}
+
+/// Class element parsing.
+///
+/// Parses a method, getter, setter or generator method of a class, which can be `static`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+#[derive(Debug, Clone, Copy)]
+struct ClassElement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassElement {
+    /// Creates a new `ClassElement` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassElement
+where
+    R: Read,
+{
+    type Output = node::ClassElement;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassElement", "Parsing");
+
+        // `static` and `get`/`set` are only modifiers if they are not the name of the method.
+        let is_static = is_modifier(cursor, "static")?;
+        if is_static {
+            let _ = cursor.next()?.expect("static token disappeared");
+        }
+
+        let kind = if is_modifier(cursor, "get")? {
+            MethodDefinitionKind::Get
+        } else if is_modifier(cursor, "set")? {
+            MethodDefinitionKind::Set
+        } else if cursor.peek(0)?.map(|tok| tok.kind())
+            == Some(&TokenKind::Punctuator(Punctuator::Mul))
+        {
+            MethodDefinitionKind::Generator
+        } else {
+            MethodDefinitionKind::Ordinary
+        };
+        if kind != MethodDefinitionKind::Ordinary {
+            let _ = cursor.next()?.expect("method modifier token disappeared");
+        }
+        let is_generator = kind == MethodDefinitionKind::Generator;
+
+        let name = ClassElementName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        cursor.expect(Punctuator::OpenParen, "class method definition")?;
+        let first_param = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+        let params = FormalParameters::new(is_generator, false).parse(cursor)?;
+        cursor.expect(Punctuator::CloseParen, "class method definition")?;
+        check_parameter_names(&params, true, first_param.span().start())?;
+
+        match kind {
+            MethodDefinitionKind::Get if !params.is_empty() => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "getter functions must have no arguments",
+                ));
+            }
+            MethodDefinitionKind::Set if params.len() != 1 => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "setter functions must have one argument",
+                ));
+            }
+            _ => {}
+        }
+
+        cursor.expect(Punctuator::OpenBlock, "class method definition")?;
+        let body = FunctionBody::new(is_generator, false).parse(cursor)?;
+        cursor.expect(Punctuator::CloseBlock, "class method definition")?;
+
+        Ok(node::ClassElement::new(
+            kind,
+            name,
+            FunctionExpr::new(None, params, body),
+            is_static,
+        ))
+    }
+}
+
+/// Checks if the next token is the given contextual keyword, used as a modifier of the
+/// method name that follows it.
+fn is_modifier<R>(cursor: &mut Cursor<R>, modifier: &str) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    match cursor.peek(0)?.map(|tok| tok.kind()) {
+        Some(TokenKind::Identifier(name)) if name.as_ref() == modifier => {}
+        _ => return Ok(false),
+    }
+
+    Ok(!matches!(
+        cursor.peek(1)?.map(|tok| tok.kind()),
+        Some(TokenKind::Punctuator(Punctuator::OpenParen))
+    ))
+}
+
+/// Class element name parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElementName
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ClassElementName {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassElementName {
+    /// Creates a new `ClassElementName` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassElementName
+where
+    R: Read,
+{
+    type Output = PropertyName;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassElementName", "Parsing");
+
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let name = match token.kind() {
+            TokenKind::Identifier(name) => name.clone(),
+            TokenKind::StringLiteral(name) => name.as_str().into(),
+            TokenKind::Keyword(keyword) => keyword.as_str().into(),
+            TokenKind::BooleanLiteral(boolean) => boolean.to_string().into(),
+            TokenKind::NullLiteral => "null".into(),
+            TokenKind::NumericLiteral(Numeric::Integer(num)) => num.to_string().into(),
+            TokenKind::NumericLiteral(Numeric::Rational(num)) => {
+                Number::to_native_string(*num).into()
+            }
+            TokenKind::NumericLiteral(Numeric::BigInt(num)) => num.to_string().into(),
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                cursor.expect(Punctuator::CloseBracket, "computed class element name")?;
+                return Ok(PropertyName::Computed(node));
+            }
+            _ => {
+                return Err(ParseError::expected(
+                    vec![TokenKind::identifier("identifier")],
+                    token,
+                    "class element name",
+                ))
+            }
+        };
+
+        Ok(PropertyName::Literal(name))
+    }
+}
@@ -0,0 +1,130 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            ClassDecl, ClassElement, ClassExpr, FormalParameter, FunctionExpr, GetSuperConstField,
+            Identifier, LetDecl, LetDeclList, MethodDefinitionKind, Node, Return, SuperCall,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks an empty class declaration.
+#[test]
+fn empty_class() {
+    check_parser(
+        "class A {}",
+        vec![ClassDecl::new("A", None, None, vec![]).into()],
+    );
+}
+
+/// Checks a class declaration with a constructor and a method.
+#[test]
+fn class_with_constructor_and_method() {
+    check_parser(
+        "class A { constructor(x) {} get() { return 1; } }",
+        vec![ClassDecl::new(
+            "A",
+            None,
+            FunctionExpr::new(None, vec![FormalParameter::new("x", None, false)], vec![]),
+            vec![ClassElement::new(
+                MethodDefinitionKind::Ordinary,
+                "get",
+                FunctionExpr::new(
+                    None,
+                    vec![],
+                    vec![Return::new(Node::from(Const::from(1)), None).into()],
+                ),
+                false,
+            )],
+        )
+        .into()],
+    );
+}
+
+/// Checks static methods, getters and setters.
+#[test]
+fn class_with_modifiers() {
+    check_parser(
+        "class A { static s() {} get x() {} set x(v) {} static get y() {} }",
+        vec![ClassDecl::new(
+            "A",
+            None,
+            None,
+            vec![
+                ClassElement::new(
+                    MethodDefinitionKind::Ordinary,
+                    "s",
+                    FunctionExpr::new(None, vec![], vec![]),
+                    true,
+                ),
+                ClassElement::new(
+                    MethodDefinitionKind::Get,
+                    "x",
+                    FunctionExpr::new(None, vec![], vec![]),
+                    false,
+                ),
+                ClassElement::new(
+                    MethodDefinitionKind::Set,
+                    "x",
+                    FunctionExpr::new(None, vec![FormalParameter::new("v", None, false)], vec![]),
+                    false,
+                ),
+                ClassElement::new(
+                    MethodDefinitionKind::Get,
+                    "y",
+                    FunctionExpr::new(None, vec![], vec![]),
+                    true,
+                ),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks a derived class calling `super`.
+#[test]
+fn class_extends() {
+    check_parser(
+        "class B extends A { constructor() { super(); } m() { return super.m; } }",
+        vec![ClassDecl::new(
+            "B",
+            Node::from(Identifier::from("A")),
+            FunctionExpr::new(None, vec![], vec![SuperCall::new(vec![]).into()]),
+            vec![ClassElement::new(
+                MethodDefinitionKind::Ordinary,
+                "m",
+                FunctionExpr::new(
+                    None,
+                    vec![],
+                    vec![Return::new(GetSuperConstField::new("m"), None).into()],
+                ),
+                false,
+            )],
+        )
+        .into()],
+    );
+}
+
+/// Checks an anonymous class expression.
+#[test]
+fn class_expression() {
+    check_parser(
+        "let A = class {};",
+        vec![LetDeclList::from(vec![LetDecl::new(
+            "A",
+            Node::from(ClassExpr::new(None, None, None, vec![])),
+        )])
+        .into()],
+    );
+}
+
+/// Checks that invalid class definitions are rejected.
+#[test]
+fn invalid_classes() {
+    check_invalid("class {}");
+    check_invalid("class A { constructor() {} constructor() {} }");
+    check_invalid("class A { get constructor() {} }");
+    check_invalid("class A { get x(a) {} }");
+    check_invalid("class A { set x() {} }");
+}
@@ -29,6 +29,8 @@ pub(super) struct BufferedLexer<R> {
     peeked: [Option<Token>; PEEK_BUF_SIZE],
     read_index: usize,
     write_index: usize,
+    /// The byte offset of the end of the last token that was consumed.
+    last_end: usize,
 }
 
 impl<R> From<Lexer<R>> for BufferedLexer<R>
@@ -49,6 +51,7 @@ where
             ],
             read_index: 0,
             write_index: 0,
+            last_end: 0,
         }
     }
 }
@@ -79,7 +82,30 @@ where
     pub(super) fn lex_regex(&mut self, start: Position) -> Result<Token, ParseError> {
         let _timer = BoaProfiler::global().start_event("cursor::lex_regex()", "Parsing");
         self.set_goal(InputElement::RegExp);
-        self.lexer.lex_slash_token(start).map_err(|e| e.into())
+        let token = self.lexer.lex_slash_token(start)?;
+        self.last_end = self.lexer.offset();
+        Ok(token)
+    }
+
+    /// Lexes the next tokens as the part of a template literal that follows a substitution,
+    /// assuming that the `}` ending the substitution has already been consumed.
+    #[inline]
+    pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
+        let _timer = BoaProfiler::global().start_event("cursor::lex_template()", "Parsing");
+        debug_assert_eq!(
+            self.read_index, self.write_index,
+            "tokens after the end of a template substitution were already lexed"
+        );
+        let token = self.lexer.lex_template(start)?;
+        self.last_end = self.lexer.offset();
+        Ok(token)
+    }
+
+    /// Gets the source text from the byte offset `start` to the end of the last token that was
+    /// consumed.
+    #[inline]
+    pub(super) fn source_text(&self, start: usize) -> &str {
+        &self.lexer.source()[start..self.last_end]
     }
 
     #[inline]
@@ -168,6 +194,9 @@ where
                 self.peeked[self.read_index].take()
             };
             self.read_index = (self.read_index + 1) % PEEK_BUF_SIZE;
+            if let Some(ref tok) = tok {
+                self.last_end = tok.end_offset();
+            }
 
             Ok(tok)
         } else {
@@ -182,6 +211,7 @@ where
     /// i.e. if there are tokens `A`, `B`, `C`, `D`, `E` and `peek(0, false)` returns `A` then:
     ///  - `peek(1, false) == peek(1, true) == B`.
     ///  - `peek(2, false)` will return `C`.
+    ///
     /// where `A`, `B`, `C`, `D` and `E` are tokens but not line terminators.
     ///
     /// If `skip_line_terminators` is `true` then line terminators will be discarded.
@@ -16,12 +16,20 @@ pub(super) enum SemicolonResult<'s> {
     NotFound(&'s Token),
 }
 
+/// The label of a statement that encloses the statement being parsed.
+#[derive(Debug)]
+pub(super) struct Label {
+    name: Box<str>,
+    iteration: bool,
+}
+
 /// Token cursor.
 ///
 /// This internal structure gives basic testable operations to the parser.
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+    labels: Vec<Label>,
 }
 
 impl<R> Cursor<R>
@@ -33,6 +41,7 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            labels: Vec::new(),
         }
     }
 
@@ -46,6 +55,11 @@ where
         self.buffered_lexer.lex_regex(start)
     }
 
+    #[inline]
+    pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
+        self.buffered_lexer.lex_template(start)
+    }
+
     #[inline]
     pub(super) fn next(&mut self) -> Result<Option<Token>, ParseError> {
         self.buffered_lexer.next(true)
@@ -56,6 +70,13 @@ where
         self.buffered_lexer.peek(skip_n, true)
     }
 
+    /// Gets the source text from the byte offset `start` to the end of the last token that was
+    /// consumed.
+    #[inline]
+    pub(super) fn source_text(&self, start: usize) -> &str {
+        self.buffered_lexer.source_text(start)
+    }
+
     #[inline]
     pub(super) fn strict_mode(&self) -> bool {
         self.buffered_lexer.strict_mode()
@@ -66,6 +87,41 @@ where
         self.buffered_lexer.set_strict_mode(strict_mode)
     }
 
+    /// Returns `true` if a statement with the given label encloses the statement being parsed.
+    ///
+    /// If `iteration` is `true`, the labelled statement must also be a loop.
+    #[inline]
+    pub(super) fn has_label(&self, name: &str, iteration: bool) -> bool {
+        self.labels
+            .iter()
+            .any(|label| &*label.name == name && (label.iteration || !iteration))
+    }
+
+    /// Enters a statement with the given label.
+    #[inline]
+    pub(super) fn push_label(&mut self, name: Box<str>, iteration: bool) {
+        self.labels.push(Label { name, iteration });
+    }
+
+    /// Leaves the statement with the last label that was pushed.
+    #[inline]
+    pub(super) fn pop_label(&mut self) {
+        self.labels.pop();
+    }
+
+    /// Takes the labels of the enclosing statements, which can't be targeted from the body of a
+    /// function.
+    #[inline]
+    pub(super) fn take_labels(&mut self) -> Vec<Label> {
+        std::mem::take(&mut self.labels)
+    }
+
+    /// Restores the labels returned by [`Cursor::take_labels`].
+    #[inline]
+    pub(super) fn restore_labels(&mut self, labels: Vec<Label>) {
+        self.labels = labels;
+    }
+
     /// Returns an error if the next token is not of kind `kind`.
     #[inline]
     pub(super) fn expect<K>(&mut self, kind: K, context: &'static str) -> Result<Token, ParseError>
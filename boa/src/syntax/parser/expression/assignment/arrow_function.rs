@@ -17,7 +17,7 @@ use crate::{
         },
         parser::{
             error::{ErrorContext, ParseError, ParseResult},
-            function::{FormalParameters, FunctionBody},
+            function::{check_parameter_names, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             AllowAwait, AllowIn, AllowYield, Cursor, TokenParser,
         },
@@ -76,8 +76,10 @@ where
             // CoverParenthesizedExpressionAndArrowParameterList
             cursor.expect(Punctuator::OpenParen, "arrow function")?;
 
+            let params_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let params = FormalParameters::new(self.allow_yield, self.allow_await).parse(cursor)?;
             cursor.expect(Punctuator::CloseParen, "arrow function")?;
+            check_parameter_names(&params, true, params_start)?;
             params
         } else {
             let param = BindingIdentifier::new(self.allow_yield, self.allow_await)
@@ -89,25 +91,30 @@ where
         cursor.peek_expect_no_lineterminator(0, "arrow function")?;
 
         cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
-        let body = ConciseBody::new(self.allow_in).parse(cursor)?;
+        let body = ConciseBody::new(self.allow_in, false).parse(cursor)?;
         Ok(ArrowFunctionDecl::new(params, body))
     }
 }
 
 /// <https://tc39.es/ecma262/#prod-ConciseBody>
+///
+/// The body of async arrow functions allows `await` expressions.
 #[derive(Debug, Clone, Copy)]
-struct ConciseBody {
+pub(super) struct ConciseBody {
     allow_in: AllowIn,
+    allow_await: AllowAwait,
 }
 
 impl ConciseBody {
     /// Creates a new `ConcideBody` parser.
-    fn new<I>(allow_in: I) -> Self
+    pub(super) fn new<I, A>(allow_in: I, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
+        A: Into<AllowAwait>,
     {
         Self {
             allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
         }
     }
 }
@@ -122,12 +129,12 @@ where
         match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let _ = cursor.next();
-                let body = FunctionBody::new(false, false).parse(cursor)?;
+                let body = FunctionBody::new(false, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseBlock, "arrow function")?;
                 Ok(body)
             }
             _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor)?,
+                ExpressionBody::new(self.allow_in, self.allow_await).parse(cursor)?,
                 None,
             )
             .into()])),
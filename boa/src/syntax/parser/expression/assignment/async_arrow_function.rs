@@ -0,0 +1,93 @@
+//! Async arrow function parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
+//! [spec]: https://tc39.es/ecma262/#sec-async-arrow-function-definitions
+
+use super::arrow_function::ConciseBody;
+use crate::syntax::lexer::TokenKind;
+use crate::{
+    syntax::{
+        ast::{
+            node::{AsyncArrowFunctionDecl, FormalParameter},
+            Keyword, Punctuator,
+        },
+        parser::{
+            error::{ErrorContext, ParseError},
+            function::{check_parameter_names, FormalParameters},
+            statement::BindingIdentifier,
+            AllowIn, AllowYield, Cursor, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Async arrow function parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/Arrow_functions
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncArrowFunction
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct AsyncArrowFunction {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+}
+
+impl AsyncArrowFunction {
+    /// Creates a new `AsyncArrowFunction` parser.
+    pub(in crate::syntax::parser) fn new<I, Y>(allow_in: I, allow_yield: Y) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for AsyncArrowFunction
+where
+    R: Read,
+{
+    type Output = AsyncArrowFunctionDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("AsyncArrowFunction", "Parsing");
+
+        cursor.expect(Keyword::Async, "async arrow function")?;
+        let next_token = cursor.peek_expect_no_lineterminator(0, "async arrow function")?;
+        let params = if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind() {
+            cursor.expect(Punctuator::OpenParen, "async arrow function")?;
+
+            let params_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let params = FormalParameters::new(false, true).parse(cursor)?;
+            cursor.expect(Punctuator::CloseParen, "async arrow function")?;
+            check_parameter_names(&params, true, params_start)?;
+            params
+        } else {
+            let param = BindingIdentifier::new(self.allow_yield, true)
+                .parse(cursor)
+                .context("async arrow function")?;
+            Box::new([FormalParameter::new(param, None, false)])
+        };
+
+        cursor.peek_expect_no_lineterminator(0, "async arrow function")?;
+
+        cursor.expect(
+            TokenKind::Punctuator(Punctuator::Arrow),
+            "async arrow function",
+        )?;
+        let body = ConciseBody::new(self.allow_in, true).parse(cursor)?;
+        Ok(AsyncArrowFunctionDecl::new(params, body))
+    }
+}
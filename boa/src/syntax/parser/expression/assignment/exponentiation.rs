@@ -55,11 +55,19 @@ impl ExponentiationExpression {
 }
 
 /// Checks by looking at the next token to see whether it's a unary operator or not.
-fn is_unary_expression<R>(cursor: &mut Cursor<R>) -> Result<bool, ParseError>
+///
+/// `await` is a unary operator when await expressions are allowed.
+fn is_unary_expression<R>(
+    cursor: &mut Cursor<R>,
+    allow_await: AllowAwait,
+) -> Result<bool, ParseError>
 where
     R: Read,
 {
     Ok(if let Some(tok) = cursor.peek(0)? {
+        if allow_await.0 && tok.kind() == &TokenKind::Keyword(Keyword::Await) {
+            return Ok(true);
+        }
         matches!(
             tok.kind(),
             TokenKind::Keyword(Keyword::Delete)
@@ -84,7 +92,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("ExponentiationExpression", "Parsing");
 
-        if is_unary_expression(cursor)? {
+        if is_unary_expression(cursor, self.allow_await)? {
             return UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor);
         }
 
@@ -8,16 +8,24 @@
 //! [spec]: https://tc39.es/ecma262/#sec-assignment-operators
 
 mod arrow_function;
+mod async_arrow_function;
 mod conditional;
 mod exponentiation;
 
-use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
+use self::{
+    arrow_function::ArrowFunction, async_arrow_function::AsyncArrowFunction,
+    conditional::ConditionalExpression,
+};
+use super::yield_expr::YieldExpression;
 use crate::syntax::lexer::{Error as LexError, InputElement, TokenKind};
 use crate::{
     syntax::{
         ast::{
-            node::{Assign, BinOp, Node},
-            Keyword, Punctuator,
+            node::{
+                ArrayPattern, Assign, BinOp, Node, ObjectPattern, PatternElement, PatternProperty,
+                PropertyDefinition, Target,
+            },
+            Const, Keyword, Punctuator,
         },
         parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
     },
@@ -34,7 +42,7 @@ use std::io::Read;
 ///  - [`ConditionalExpression`](../conditional_operator/struct.ConditionalExpression.html)
 ///  - `YieldExpression`
 ///  - [`ArrowFunction`](../../function/arrow_function/struct.ArrowFunction.html)
-///  - `AsyncArrowFunction`
+///  - [`AsyncArrowFunction`](../../function/async_arrow_function/struct.AsyncArrowFunction.html)
 ///  - [`LeftHandSideExpression`][lhs] `=` `AssignmentExpression`
 ///  - [`LeftHandSideExpression`][lhs] `AssignmentOperator` `AssignmentExpression`
 ///
@@ -82,8 +90,41 @@ where
         let _timer = BoaProfiler::global().start_event("AssignmentExpression", "Parsing");
         cursor.set_goal(InputElement::Div);
 
+        // Yield expressions can only be used in generators, elsewhere `yield` is an identifier.
+        if self.allow_yield.0 {
+            if let Some(tok) = cursor.peek(0)? {
+                if tok.kind() == &TokenKind::Keyword(Keyword::Yield) {
+                    return YieldExpression::new(self.allow_in, self.allow_await)
+                        .parse(cursor)
+                        .map(Node::from);
+                }
+            }
+        }
+
         // Arrow function
         match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            // async a=>{} or async (a,b)=>{}
+            TokenKind::Keyword(Keyword::Async) => {
+                let is_async_arrow = match cursor
+                    .peek_expect_no_lineterminator(1, "assignment expression")
+                    .map(|tok| tok.kind().clone())
+                {
+                    Ok(TokenKind::Punctuator(Punctuator::OpenParen)) => true,
+                    Ok(TokenKind::Identifier(_))
+                    | Ok(TokenKind::Keyword(Keyword::Yield))
+                    | Ok(TokenKind::Keyword(Keyword::Await)) => matches!(
+                        cursor.peek(2)?.map(|tok| tok.kind()),
+                        Some(TokenKind::Punctuator(Punctuator::Arrow))
+                    ),
+                    _ => false,
+                };
+                if is_async_arrow {
+                    return AsyncArrowFunction::new(self.allow_in, self.allow_yield)
+                        .parse(cursor)
+                        .map(Node::AsyncArrowFunctionDecl);
+                }
+            }
+
             // a=>{}
             TokenKind::Identifier(_)
             | TokenKind::Keyword(Keyword::Yield)
@@ -181,8 +222,8 @@ where
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Assign) => {
                     cursor.next()?.expect("= token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
-                        lhs = Assign::new(lhs, self.parse(cursor)?).into();
+                    if let Some(target) = assignment_target(&lhs) {
+                        lhs = Assign::new(target, self.parse(cursor)?).into();
                     } else {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
@@ -219,3 +260,76 @@ where
 pub(crate) fn is_assignable(node: &Node) -> bool {
     !matches!(node, Node::Const(_) | Node::ArrayDecl(_))
 }
+
+/// Converts the left hand side of an `=` assignment to its target, reinterpreting array and
+/// object literals as destructuring patterns.
+///
+/// Returns `None` if the node is not a valid assignment target.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-destructuring-assignment
+pub(in crate::syntax::parser) fn assignment_target(node: &Node) -> Option<Target> {
+    match node {
+        Node::Identifier(ident) => Some(ident.clone().into()),
+        Node::GetConstField(_)
+        | Node::GetField(_)
+        | Node::GetSuperConstField(_)
+        | Node::GetSuperField(_) => Some(Target::Expression(Box::new(node.clone()))),
+        Node::ArrayDecl(array) => {
+            let nodes = array.as_ref();
+            let mut elements = Vec::with_capacity(nodes.len());
+            let mut rest = None;
+            for (i, element) in nodes.iter().enumerate() {
+                match element {
+                    // Elisions are parsed as `undefined` constants.
+                    Node::Const(Const::Undefined) => elements.push(None),
+                    Node::Spread(spread) if i == nodes.len() - 1 => {
+                        rest = Some(assignment_target(spread.val())?);
+                    }
+                    Node::Assign(assign) => elements.push(Some(PatternElement::new(
+                        assign.lhs().clone(),
+                        Some(assign.rhs().clone()),
+                    ))),
+                    element => {
+                        elements.push(Some(PatternElement::new(assignment_target(element)?, None)));
+                    }
+                }
+            }
+            Some(ArrayPattern::new(elements, rest).into())
+        }
+        Node::Object(object) => {
+            let definitions = object.properties();
+            let mut properties = Vec::with_capacity(definitions.len());
+            let mut rest = None;
+            for (i, definition) in definitions.iter().enumerate() {
+                match definition {
+                    PropertyDefinition::IdentifierReference(name) => {
+                        properties.push(PatternProperty::new(name.clone(), name.clone(), None));
+                    }
+                    PropertyDefinition::Property(name, Node::Assign(assign)) => {
+                        properties.push(PatternProperty::new(
+                            name.clone(),
+                            assign.lhs().clone(),
+                            Some(assign.rhs().clone()),
+                        ));
+                    }
+                    PropertyDefinition::Property(name, value) => {
+                        properties.push(PatternProperty::new(
+                            name.clone(),
+                            assignment_target(value)?,
+                            None,
+                        ));
+                    }
+                    PropertyDefinition::SpreadObject(value) if i == definitions.len() - 1 => {
+                        rest = Some(assignment_target(value)?);
+                    }
+                    _ => return None,
+                }
+            }
+            Some(ObjectPattern::new(properties, rest).into())
+        }
+        _ => None,
+    }
+}
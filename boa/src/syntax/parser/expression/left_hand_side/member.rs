@@ -5,13 +5,13 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#prod-MemberExpression
 
-use super::arguments::Arguments;
+use super::{arguments::Arguments, template::TaggedTemplateLiteral};
 use crate::{
     syntax::{
         ast::{
             node::{
                 field::{GetConstField, GetField},
-                Call, New, Node,
+                Call, GetSuperConstField, GetSuperField, New, Node, SuperCall,
             },
             Keyword, Punctuator,
         },
@@ -70,6 +70,11 @@ where
             let call_node = Call::new(lhs, args);
 
             Node::from(New::from(call_node))
+        } else if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+            == &TokenKind::Keyword(Keyword::Super)
+        {
+            let _ = cursor.next().expect("super keyword disappeared");
+            self.parse_super(cursor)?
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
         };
@@ -105,6 +110,10 @@ where
                     cursor.expect(Punctuator::CloseBracket, "member expression")?;
                     lhs = GetField::new(lhs, idx).into();
                 }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateMiddle(_) => {
+                    lhs = TaggedTemplateLiteral::new(self.allow_yield, self.allow_await, lhs)
+                        .parse(cursor)?;
+                }
                 _ => break,
             }
         }
@@ -112,3 +121,56 @@ where
         Ok(lhs)
     }
 }
+
+impl MemberExpression {
+    /// Parses what follows the `super` keyword: a call to the parent constructor or a property
+    /// access on the parent prototype.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-SuperProperty
+    fn parse_super<R>(self, cursor: &mut Cursor<R>) -> ParseResult
+    where
+        R: Read,
+    {
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+        match token.kind() {
+            TokenKind::Punctuator(Punctuator::OpenParen) => {
+                let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                Ok(SuperCall::new(args).into())
+            }
+            TokenKind::Punctuator(Punctuator::Dot) => {
+                let _ = cursor.next()?.expect("dot punctuator token disappeared");
+                let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                match token.kind() {
+                    TokenKind::Identifier(name) => Ok(GetSuperConstField::new(name.clone()).into()),
+                    TokenKind::Keyword(kw) => Ok(GetSuperConstField::new(kw.to_string()).into()),
+                    _ => Err(ParseError::expected(
+                        vec![TokenKind::identifier("identifier")],
+                        token,
+                        "super property",
+                    )),
+                }
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let _ = cursor
+                    .next()?
+                    .expect("open bracket punctuator token disappeared");
+                let idx =
+                    Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                cursor.expect(Punctuator::CloseBracket, "super property")?;
+                Ok(GetSuperField::new(idx).into())
+            }
+            _ => Err(ParseError::expected(
+                vec![
+                    TokenKind::Punctuator(Punctuator::OpenParen),
+                    TokenKind::Punctuator(Punctuator::Dot),
+                    TokenKind::Punctuator(Punctuator::OpenBracket),
+                ],
+                token,
+                "super expression",
+            )),
+        }
+    }
+}
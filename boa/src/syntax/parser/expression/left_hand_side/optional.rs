@@ -0,0 +1,134 @@
+//! Optional chain parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+//! [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+
+use super::arguments::Arguments;
+use crate::{
+    profiler::BoaProfiler,
+    syntax::{
+        ast::{
+            node::{Node, Optional, OptionalOperation, OptionalOperationKind},
+            Punctuator,
+        },
+        lexer::{Token, TokenKind},
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+};
+
+use std::io::Read;
+
+/// Parses an optional expression.
+///
+/// Expects the next token to be the `?.` that starts the optional chain of `target`.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+#[derive(Debug)]
+pub(super) struct OptionalExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    target: Node,
+}
+
+impl OptionalExpression {
+    /// Creates a new `OptionalExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, target: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            target,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for OptionalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("OptionalExpression", "Parsing");
+
+        let mut chain = Vec::new();
+        while let Some(tok) = cursor.peek(0)? {
+            let optional = tok.kind() == &TokenKind::Punctuator(Punctuator::Optional);
+            if optional {
+                let _ = cursor.next()?.expect("?. token vanished");
+            }
+
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+            let kind = match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => OptionalOperationKind::Call(
+                    Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                ),
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    let _ = cursor.next()?.expect("[ token vanished");
+                    let idx =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "optional chain")?;
+                    OptionalOperationKind::GetField(idx)
+                }
+                TokenKind::Punctuator(Punctuator::Dot) if !optional => {
+                    let _ = cursor.next()?.expect("dot punctuator token vanished");
+                    let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    OptionalOperationKind::GetConstField(field_name(token)?)
+                }
+                TokenKind::Identifier(_) | TokenKind::Keyword(_) if optional => {
+                    let token = cursor.next()?.expect("token vanished");
+                    OptionalOperationKind::GetConstField(field_name(token)?)
+                }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateMiddle(_) => {
+                    return Err(ParseError::general(
+                        "tagged template cannot be used in optional chain",
+                        token.span().start(),
+                    ));
+                }
+                _ if optional => {
+                    return Err(ParseError::expected(
+                        vec![
+                            TokenKind::Punctuator(Punctuator::OpenParen),
+                            TokenKind::Punctuator(Punctuator::OpenBracket),
+                            TokenKind::identifier("identifier"),
+                        ],
+                        token,
+                        "optional chain",
+                    ));
+                }
+                _ => break,
+            };
+            chain.push(OptionalOperation::new(kind, optional));
+        }
+
+        Ok(Optional::new(self.target, chain).into())
+    }
+}
+
+/// Gets the name of the property accessed by `token`, in an optional chain.
+fn field_name(token: Token) -> Result<Box<str>, ParseError> {
+    match token.kind() {
+        TokenKind::Identifier(name) => Ok(name.clone()),
+        TokenKind::Keyword(kw) => Ok(kw.to_string().into()),
+        _ => Err(ParseError::expected(
+            vec![TokenKind::identifier("identifier")],
+            token,
+            "optional chain",
+        )),
+    }
+}
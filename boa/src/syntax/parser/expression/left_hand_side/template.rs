@@ -0,0 +1,82 @@
+//! Tagged template parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+//! [spec]: https://tc39.es/ecma262/#sec-tagged-templates
+
+use crate::{
+    profiler::BoaProfiler,
+    syntax::{
+        ast::node::{Node, TaggedTemplate},
+        lexer::TokenKind,
+        parser::{
+            expression::primary::parse_substitutions, AllowAwait, AllowYield, Cursor, ParseError,
+            ParseResult, TokenParser,
+        },
+    },
+};
+
+use std::io::Read;
+
+/// Parses a tagged template.
+///
+/// Unlike in untagged template literals, escape sequences that are not valid are allowed, and
+/// leave the cooked string undefined.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+/// [spec]: https://tc39.es/ecma262/#prod-MemberExpression
+#[derive(Debug, Clone)]
+pub(super) struct TaggedTemplateLiteral {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    tag: Node,
+}
+
+impl TaggedTemplateLiteral {
+    /// Creates a new `TaggedTemplateLiteral` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, tag: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            tag,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for TaggedTemplateLiteral
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplateLiteral", "Parsing");
+
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let (strings, exprs) = match token.kind() {
+            TokenKind::TemplateNoSubstitution(string) => (vec![string.clone()], Vec::new()),
+            TokenKind::TemplateMiddle(string) => {
+                parse_substitutions(cursor, self.allow_yield, self.allow_await, string.clone())?
+            }
+            _ => return Err(ParseError::unexpected(token, "tagged template")),
+        };
+
+        let raws: Vec<Box<str>> = strings.iter().map(|string| string.raw().into()).collect();
+        let cookeds: Vec<_> = strings
+            .iter()
+            .map(|string| string.cooked().cloned())
+            .collect();
+        Ok(TaggedTemplate::new(self.tag, raws, cookeds, exprs).into())
+    }
+}
@@ -16,15 +16,21 @@ mod unary;
 mod update;
 
 pub(in crate::syntax::parser) mod await_expr;
+mod yield_expr;
 
 use self::assignment::ExponentiationExpression;
-pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
-use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser};
+pub(super) use self::{
+    assignment::{assignment_target, AssignmentExpression},
+    left_hand_side::LeftHandSideExpression,
+    primary::Initializer,
+};
+use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser};
 use crate::syntax::lexer::{InputElement, TokenKind};
 use crate::{
     profiler::BoaProfiler,
     syntax::ast::{
         node::{BinOp, Node},
+        op::LogOp,
         Keyword, Punctuator,
     },
 };
@@ -142,23 +148,26 @@ expression!(
     None::<InputElement>
 );
 
-/// Parses a logical `OR` expression.
+/// Parses a short-circuit expression, which is either a logical `OR` expression or a nullish
+/// coalescing expression.
+///
+/// The `??` operator cannot be mixed with the `&&` and `||` operators without parentheses.
 ///
 /// More information:
 ///  - [MDN documentation][mdn]
 ///  - [ECMAScript specification][spec]
 ///
-/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Logical_Operators#Logical_OR_2
-/// [spec]: https://tc39.es/ecma262/#prod-LogicalORExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Nullish_coalescing_operator
+/// [spec]: https://tc39.es/ecma262/#prod-ShortCircuitExpression
 #[derive(Debug, Clone, Copy)]
-struct LogicalORExpression {
+struct ShortCircuitExpression {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
-impl LogicalORExpression {
-    /// Creates a new `LogicalORExpression` parser.
+impl ShortCircuitExpression {
+    /// Creates a new `ShortCircuitExpression` parser.
     pub(super) fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
     where
         I: Into<AllowIn>,
@@ -173,13 +182,48 @@ impl LogicalORExpression {
     }
 }
 
-expression!(
-    LogicalORExpression,
-    LogicalANDExpression,
-    [Punctuator::BoolOr],
-    [allow_in, allow_yield, allow_await],
-    None::<InputElement>
-);
+impl<R> TokenParser<R> for ShortCircuitExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("ShortCircuitExpression", "Parsing");
+
+        let bitwise_or =
+            BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await);
+        let mut lhs = bitwise_or.parse(cursor)?;
+        let (mut logical, mut coalesce) = (false, false);
+        while let Some(tok) = cursor.peek(0)? {
+            let (op, is_coalesce) = match *tok.kind() {
+                TokenKind::Punctuator(Punctuator::Coalesce) => (LogOp::Coalesce, true),
+                TokenKind::Punctuator(Punctuator::BoolAnd) => (LogOp::And, false),
+                TokenKind::Punctuator(Punctuator::BoolOr) => (LogOp::Or, false),
+                _ => break,
+            };
+            if (is_coalesce && logical) || (!is_coalesce && coalesce) {
+                return Err(ParseError::general(
+                    "cannot mix `??` with `&&` or `||` without parentheses",
+                    tok.span().start(),
+                ));
+            }
+            logical |= !is_coalesce;
+            coalesce |= is_coalesce;
+
+            let _ = cursor.next()?.expect("token disappeared");
+            let rhs = if op == LogOp::Or {
+                LogicalANDExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                    .parse(cursor)?
+            } else {
+                bitwise_or.parse(cursor)?
+            };
+            lhs = BinOp::new(op, lhs, rhs).into();
+        }
+
+        Ok(lhs)
+    }
+}
 
 /// Parses a logical `AND` expression.
 ///
@@ -412,20 +456,52 @@ impl RelationalExpression {
     }
 }
 
-expression!(
-    RelationalExpression,
-    ShiftExpression,
-    [
-        Punctuator::LessThan,
-        Punctuator::GreaterThan,
-        Punctuator::LessThanOrEq,
-        Punctuator::GreaterThanOrEq,
-        Keyword::InstanceOf,
-        Keyword::In
-    ],
-    [allow_yield, allow_await],
-    None::<InputElement>
-);
+impl<R> TokenParser<R> for RelationalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("RelationalExpression", "Parsing");
+
+        let mut lhs = ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        while let Some(tok) = cursor.peek(0)? {
+            match *tok.kind() {
+                TokenKind::Punctuator(op)
+                    if op == Punctuator::LessThan
+                        || op == Punctuator::GreaterThan
+                        || op == Punctuator::LessThanOrEq
+                        || op == Punctuator::GreaterThanOrEq =>
+                {
+                    let _ = cursor.next().expect("token disappeared");
+                    lhs = BinOp::new(
+                        op.as_binop().expect("Could not get binary operation."),
+                        lhs,
+                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                    )
+                    .into();
+                }
+                // The `in` operator is not allowed in the head of a `for` statement, where it
+                // starts a `for...in` loop instead.
+                TokenKind::Keyword(op)
+                    if op == Keyword::InstanceOf || (op == Keyword::In && self.allow_in.0) =>
+                {
+                    let _ = cursor.next().expect("token disappeared");
+                    lhs = BinOp::new(
+                        op.as_binop().expect("Could not get binary operation."),
+                        lhs,
+                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                    )
+                    .into();
+                }
+                _ => break,
+            }
+        }
+
+        Ok(lhs)
+    }
+}
 
 /// Parses a bitwise shift expression.
 ///
@@ -6,7 +6,7 @@ use crate::{
         ast::{node::AsyncFunctionExpr, Keyword, Punctuator},
         lexer::TokenKind,
         parser::{
-            function::{FormalParameters, FunctionBody},
+            function::{check_parameter_names, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             AllowYield, Cursor, ParseError, TokenParser,
         },
@@ -65,6 +65,8 @@ where
 
         cursor.expect(Punctuator::OpenParen, "async function expression")?;
 
+        let params_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+
         let params = FormalParameters::new(false, true).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseParen, "async function expression")?;
@@ -72,6 +74,8 @@ where
 
         let body = FunctionBody::new(false, true).parse(cursor)?;
 
+        check_parameter_names(&params, cursor.strict_mode() || body.strict(), params_start)?;
+
         cursor.expect(Punctuator::CloseBlock, "async function expression")?;
 
         Ok(AsyncFunctionExpr::new(name, params, body))
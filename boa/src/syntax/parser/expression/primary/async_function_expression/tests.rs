@@ -1,9 +1,13 @@
 use crate::syntax::{
     ast::{
-        node::{AsyncFunctionExpr, ConstDecl, ConstDeclList, Return, StatementList},
+        node::{
+            AsyncFunctionExpr, AwaitExpr, BinOp, Call, ConstDecl, ConstDeclList, Identifier, Node,
+            Return, StatementList,
+        },
+        op::NumOp,
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks async expression parsing.
@@ -63,3 +67,40 @@ fn check_nested_async_expression() {
         .into()],
     );
 }
+
+/// Checks that `await` can be used inside of expressions in async functions.
+#[test]
+fn check_await_in_expression() {
+    check_parser(
+        "const f = async function() {
+            return (await a()) + await 1;
+        };
+        ",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "f",
+            Some(
+                AsyncFunctionExpr::new::<Option<Box<str>>, _, StatementList>(
+                    None,
+                    [],
+                    vec![Return::new::<_, _, Option<Box<str>>>(
+                        BinOp::new(
+                            NumOp::Add,
+                            AwaitExpr::from(Node::from(Call::new(Identifier::from("a"), vec![]))),
+                            AwaitExpr::from(Node::from(Const::from(1))),
+                        ),
+                        None,
+                    )
+                    .into()]
+                    .into(),
+                ),
+            ),
+        )])
+        .into()],
+    );
+}
+
+/// Checks that `await` expressions are not allowed outside of async functions.
+#[test]
+fn check_await_outside_async_function() {
+    check_invalid("function f() { await 1; }");
+}
@@ -15,7 +15,7 @@ use crate::{
         ast::{node::FunctionExpr, Keyword, Punctuator},
         lexer::TokenKind,
         parser::{
-            function::{FormalParameters, FunctionBody},
+            function::{check_parameter_names, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             Cursor, ParseError, TokenParser,
         },
@@ -34,7 +34,17 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function
 /// [spec]: https://tc39.es/ecma262/#prod-FunctionExpression
 #[derive(Debug, Clone, Copy)]
-pub(super) struct FunctionExpression;
+pub(super) struct FunctionExpression {
+    /// The byte offset of the `function` keyword, which was already consumed.
+    start: usize,
+}
+
+impl FunctionExpression {
+    /// Creates a new `FunctionExpression` parser.
+    pub(super) fn new(start: usize) -> Self {
+        Self { start }
+    }
+}
 
 impl<R> TokenParser<R> for FunctionExpression
 where
@@ -60,6 +70,8 @@ where
 
         cursor.expect(Punctuator::OpenParen, "function expression")?;
 
+        let params_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+
         let params = FormalParameters::new(false, false).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseParen, "function expression")?;
@@ -67,8 +79,10 @@ where
 
         let body = FunctionBody::new(false, false).parse(cursor)?;
 
+        check_parameter_names(&params, cursor.strict_mode() || body.strict(), params_start)?;
+
         cursor.expect(Punctuator::CloseBlock, "function expression")?;
 
-        Ok(FunctionExpr::new(name, params, body))
+        Ok(FunctionExpr::new(name, params, body).with_source_text(cursor.source_text(self.start)))
     }
 }
@@ -0,0 +1,57 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            ArrayDecl, ConstDecl, ConstDeclList, GeneratorExpr, Node, StatementList, YieldExpr,
+        },
+        Const,
+    },
+    parser::tests::check_parser,
+};
+
+/// Checks generator expression parsing.
+#[test]
+fn check_generator_expression() {
+    check_parser(
+        "const gen = function*() {
+            yield 1;
+        };
+        ",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "gen",
+            Some(GeneratorExpr::new::<Option<Box<str>>, _, StatementList>(
+                None,
+                [],
+                vec![YieldExpr::new(Node::from(Const::from(1)), false).into()].into(),
+            )),
+        )])
+        .into()],
+    );
+}
+
+/// Checks named generator expression parsing, with a `yield*` expression.
+#[test]
+fn check_named_generator_expression() {
+    check_parser(
+        "const gen = function* inner() {
+            yield* [1, 2];
+        };
+        ",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "gen",
+            Some(GeneratorExpr::new::<_, _, StatementList>(
+                Some("inner".into()),
+                [],
+                vec![YieldExpr::new(
+                    Node::from(ArrayDecl::from(vec![
+                        Const::from(1).into(),
+                        Const::from(2).into(),
+                    ])),
+                    true,
+                )
+                .into()]
+                .into(),
+            )),
+        )])
+        .into()],
+    );
+}
@@ -9,28 +9,40 @@
 
 mod array_initializer;
 mod async_function_expression;
+mod class_expression;
 mod function_expression;
+mod generator_expression;
 mod object_initializer;
+mod template;
 #[cfg(test)]
 mod tests;
 
 use self::{
-    array_initializer::ArrayLiteral, async_function_expression::AsyncFunctionExpression,
-    function_expression::FunctionExpression, object_initializer::ObjectLiteral,
+    array_initializer::ArrayLiteral,
+    async_function_expression::AsyncFunctionExpression,
+    class_expression::ClassExpression,
+    function_expression::FunctionExpression,
+    generator_expression::GeneratorExpression,
+    object_initializer::ObjectLiteral,
+    template::{cooked, TemplateLiteral},
 };
-use super::Expression;
+use super::{AssignmentExpression, Expression};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{
-            node::{Call, Identifier, New, Node},
+            node::{Call, Identifier, ImportCall, New, Node, TemplateElement, TemplateLit},
             Const, Keyword, Punctuator,
         },
-        lexer::{token::Numeric, InputElement, TokenKind},
-        parser::{AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
+        lexer::{token::Numeric, Error as LexError, InputElement, TokenKind},
+        parser::{
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
+            STRICT_RESERVED_IDENTIFIERS,
+        },
     },
 };
 pub(in crate::syntax::parser) use object_initializer::Initializer;
+pub(in crate::syntax::parser::expression) use template::parse_substitutions;
 
 use std::io::Read;
 
@@ -75,12 +87,32 @@ where
 
         match tok.kind() {
             TokenKind::Keyword(Keyword::This) => Ok(Node::This),
-            TokenKind::Keyword(Keyword::Function) => {
-                FunctionExpression.parse(cursor).map(Node::from)
+            TokenKind::Keyword(Keyword::Function)
+                if matches!(
+                    cursor.peek(0)?.map(|tok| tok.kind()),
+                    Some(TokenKind::Punctuator(Punctuator::Mul))
+                ) =>
+            {
+                GeneratorExpression.parse(cursor).map(Node::from)
             }
+            TokenKind::Keyword(Keyword::Function) => FunctionExpression::new(tok.start_offset())
+                .parse(cursor)
+                .map(Node::from),
             TokenKind::Keyword(Keyword::Async) => AsyncFunctionExpression::new(self.allow_yield)
                 .parse(cursor)
                 .map(Node::from),
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
+            TokenKind::Keyword(Keyword::Import) => {
+                cursor.expect(Punctuator::OpenParen, "import call")?;
+                let specifier = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                cursor.expect(Punctuator::CloseParen, "import call")?;
+                Ok(ImportCall::new(specifier).into())
+            }
             TokenKind::Punctuator(Punctuator::OpenParen) => {
                 cursor.set_goal(InputElement::RegExp);
                 let expr =
@@ -102,11 +134,36 @@ where
             }
             TokenKind::BooleanLiteral(boolean) => Ok(Const::from(*boolean).into()),
             TokenKind::NullLiteral => Ok(Const::Null.into()),
+            TokenKind::Identifier(ident)
+                if cursor.strict_mode()
+                    && STRICT_RESERVED_IDENTIFIERS.contains(&ident.as_ref()) =>
+            {
+                Err(ParseError::lex(LexError::Syntax(
+                    format!(
+                        "using future reserved keyword '{}' not allowed in strict mode",
+                        ident
+                    )
+                    .into(),
+                    tok.span().start(),
+                )))
+            }
             TokenKind::Identifier(ident) => Ok(Identifier::from(ident.as_ref()).into()), // TODO: IdentifierReference
-            TokenKind::StringLiteral(s) => Ok(Const::from(s.as_ref()).into()),
+            TokenKind::StringLiteral(s) => Ok(Const::from(s.clone()).into()),
             TokenKind::NumericLiteral(Numeric::Integer(num)) => Ok(Const::from(*num).into()),
             TokenKind::NumericLiteral(Numeric::Rational(num)) => Ok(Const::from(*num).into()),
             TokenKind::NumericLiteral(Numeric::BigInt(num)) => Ok(Const::from(num.clone()).into()),
+            TokenKind::TemplateNoSubstitution(string) => {
+                let string = cooked(string, tok.span().start())?;
+                Ok(TemplateLit::new(vec![TemplateElement::String(string.clone())]).into())
+            }
+            TokenKind::TemplateMiddle(string) => TemplateLiteral::new(
+                self.allow_yield,
+                self.allow_await,
+                tok.span().start(),
+                string.clone(),
+            )
+            .parse(cursor)
+            .map(Node::from),
             TokenKind::RegularExpressionLiteral(body, flags) => {
                 Ok(Node::from(New::from(Call::new(
                     Identifier::from("RegExp"),
@@ -13,12 +13,12 @@ use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
         ast::{
-            node::{self, FunctionExpr, MethodDefinitionKind, Node, Object},
+            node::{self, Assign, FunctionExpr, MethodDefinitionKind, Node, Object},
             Punctuator,
         },
         parser::{
             expression::AssignmentExpression,
-            function::{FormalParameters, FunctionBody},
+            function::{check_parameter_names, FormalParameters, FunctionBody},
             AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
@@ -134,15 +134,54 @@ where
             return Ok(node::PropertyDefinition::SpreadObject(node));
         }
 
-        let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+        // https://tc39.es/ecma262/#prod-GeneratorMethod
+        if cursor.next_if(Punctuator::Mul)?.is_some() {
+            let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+            cursor.expect(Punctuator::OpenParen, "generator method definition")?;
+            let params_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let params = FormalParameters::new(true, false).parse(cursor)?;
+            cursor.expect(Punctuator::CloseParen, "generator method definition")?;
+            check_parameter_names(&params, true, params_start)?;
+            cursor.expect(Punctuator::OpenBlock, "generator method definition")?;
+            let body = FunctionBody::new(true, false).parse(cursor)?;
+            cursor.expect(Punctuator::CloseBlock, "generator method definition")?;
+
+            return Ok(node::PropertyDefinition::method_definition(
+                MethodDefinitionKind::Generator,
+                prop_name,
+                FunctionExpr::new(None, params, body),
+            ));
+        }
+
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let is_identifier = matches!(token.kind(), TokenKind::Identifier(_));
+        let prop_name = token.to_string();
         if cursor.next_if(Punctuator::Colon)?.is_some() {
             let val = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
             return Ok(node::PropertyDefinition::property(prop_name, val));
         }
 
-        // TODO GeneratorMethod
-        // https://tc39.es/ecma262/#prod-GeneratorMethod
+        if is_identifier {
+            match cursor.peek(0)?.map(|tok| tok.kind()) {
+                // https://tc39.es/ecma262/#prod-IdentifierReference
+                Some(TokenKind::Punctuator(Punctuator::Comma))
+                | Some(TokenKind::Punctuator(Punctuator::CloseBlock)) => {
+                    return Ok(node::PropertyDefinition::identifier_reference(prop_name));
+                }
+                // A `CoverInitializedName` is only valid as part of an assignment pattern.
+                // https://tc39.es/ecma262/#prod-CoverInitializedName
+                Some(TokenKind::Punctuator(Punctuator::Assign)) => {
+                    let init =
+                        Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    return Ok(node::PropertyDefinition::property(
+                        prop_name.clone(),
+                        Assign::new(prop_name.into_boxed_str(), init),
+                    ));
+                }
+                _ => {}
+            }
+        }
 
         if prop_name.as_str() == "async" {
             // TODO - AsyncMethod.
@@ -152,9 +191,8 @@ where
             // https://tc39.es/ecma262/#prod-AsyncGeneratorMethod
         }
 
-        if cursor
-            .next_if(TokenKind::Punctuator(Punctuator::OpenParen))?
-            .is_some()
+        if cursor.peek(0)?.map(|tok| tok.kind())
+            == Some(&TokenKind::Punctuator(Punctuator::OpenParen))
             || ["get", "set"].contains(&prop_name.as_str())
         {
             return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
@@ -174,7 +212,9 @@ where
 /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
 #[derive(Debug, Clone)]
 struct MethodDefinition {
+    #[allow(dead_code)]
     allow_yield: AllowYield,
+    #[allow(dead_code)]
     allow_await: AllowAwait,
     identifier: String,
 }
@@ -204,8 +244,11 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("MethodDefinition", "Parsing");
 
+        // A method can also be named `get` or `set`, as in `{ get() {} }`.
+        let is_accessor = cursor.peek(0)?.map(|tok| tok.kind())
+            != Some(&TokenKind::Punctuator(Punctuator::OpenParen));
         let (methodkind, prop_name, params) = match self.identifier.as_str() {
-            idn @ "get" | idn @ "set" => {
+            idn @ "get" | idn @ "set" if is_accessor => {
                 let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
                 cursor.expect(
                     TokenKind::Punctuator(Punctuator::OpenParen),
@@ -229,12 +272,19 @@ where
                             "setter functions must have one argument",
                         ));
                     }
+                    check_parameter_names(&params, true, first_param.span().start())?;
                     (MethodDefinitionKind::Set, prop_name, params)
                 }
             }
             prop_name => {
+                cursor.expect(
+                    TokenKind::Punctuator(Punctuator::OpenParen),
+                    "method definition",
+                )?;
+                let params_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
                 let params = FormalParameters::new(false, false).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "method definition")?;
+                check_parameter_names(&params, true, params_start)?;
                 (
                     MethodDefinitionKind::Ordinary,
                     prop_name.to_string(),
@@ -0,0 +1,134 @@
+//! Template literal parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+//! [spec]: https://tc39.es/ecma262/#sec-template-literals
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    profiler::BoaProfiler,
+    syntax::{
+        ast::{
+            node::{Node, TemplateElement, TemplateLit},
+            Position, Punctuator,
+        },
+        lexer::{TemplateString, TokenKind},
+        parser::{expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
+    },
+    value::RcString,
+};
+
+use std::io::Read;
+
+/// Parses a template literal with substitutions.
+///
+/// Expects the first part of the template literal, up to the `${` of its first substitution, to
+/// already be consumed.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+/// [spec]: https://tc39.es/ecma262/#prod-TemplateLiteral
+#[derive(Debug, Clone)]
+pub(super) struct TemplateLiteral {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    start: Position,
+    first: TemplateString,
+}
+
+impl TemplateLiteral {
+    /// Creates a new `TemplateLiteral` parser.
+    pub(super) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        start: Position,
+        first: TemplateString,
+    ) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            start,
+            first,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for TemplateLiteral
+where
+    R: Read,
+{
+    type Output = TemplateLit;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("TemplateLiteral", "Parsing");
+
+        let (strings, exprs) =
+            parse_substitutions(cursor, self.allow_yield, self.allow_await, self.first)?;
+
+        let mut elements = Vec::with_capacity(strings.len() + exprs.len());
+        let mut exprs = exprs.into_iter();
+        for string in strings {
+            elements.push(TemplateElement::String(
+                cooked(&string, self.start)?.clone(),
+            ));
+            if let Some(expr) = exprs.next() {
+                elements.push(TemplateElement::Expr(expr));
+            }
+        }
+        Ok(TemplateLit::new(elements))
+    }
+}
+
+/// Gets the cooked string of a part of an untagged template literal, in which invalid escape
+/// sequences are a syntax error.
+pub(super) fn cooked(string: &TemplateString, position: Position) -> Result<&RcString, ParseError> {
+    string
+        .cooked()
+        .ok_or_else(|| ParseError::general("invalid escape sequence in template literal", position))
+}
+
+/// Parses the substitutions of a template literal and the strings that follow them, until the
+/// end of the template literal.
+///
+/// Returns the strings of the template literal, starting with `first`, along with the
+/// expressions of its substitutions.
+pub(in crate::syntax::parser::expression) fn parse_substitutions<R>(
+    cursor: &mut Cursor<R>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    first: TemplateString,
+) -> Result<(Vec<TemplateString>, Vec<Node>), ParseError>
+where
+    R: Read,
+{
+    let mut strings = vec![first];
+    let mut exprs = Vec::new();
+    loop {
+        exprs.push(Expression::new(true, allow_yield, allow_await).parse(cursor)?);
+        let close = cursor.expect(Punctuator::CloseBlock, "template literal")?;
+
+        // The `}` ending a substitution starts the next part of the template literal, which
+        // must be lexed as such.
+        let token = cursor.lex_template(close.span().start())?;
+        match token.kind() {
+            TokenKind::TemplateMiddle(string) => strings.push(string.clone()),
+            TokenKind::TemplateNoSubstitution(string) => {
+                strings.push(string.clone());
+                return Ok((strings, exprs));
+            }
+            _ => return Err(ParseError::unexpected(token, "template literal")),
+        }
+    }
+}
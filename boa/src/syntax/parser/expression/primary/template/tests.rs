@@ -0,0 +1,99 @@
+use crate::syntax::{
+    ast::{
+        node::{BinOp, Identifier, TaggedTemplate, TemplateElement, TemplateLit},
+        op::NumOp,
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks a template literal without substitutions.
+#[test]
+fn check_no_substitution() {
+    check_parser(
+        r"`hello\nworld`",
+        vec![TemplateLit::new(vec![TemplateElement::String("hello\nworld".into())]).into()],
+    );
+}
+
+/// Checks a template literal with substitutions.
+#[test]
+fn check_substitutions() {
+    check_parser(
+        "`a${b}c${1 + 2}`",
+        vec![TemplateLit::new(vec![
+            TemplateElement::String("a".into()),
+            TemplateElement::Expr(Identifier::from("b").into()),
+            TemplateElement::String("c".into()),
+            TemplateElement::Expr(BinOp::new(NumOp::Add, Const::from(1), Const::from(2)).into()),
+            TemplateElement::String("".into()),
+        ])
+        .into()],
+    );
+}
+
+/// Checks a template literal nested in a substitution.
+#[test]
+fn check_nested() {
+    check_parser(
+        "`a${`b${c}`}`",
+        vec![TemplateLit::new(vec![
+            TemplateElement::String("a".into()),
+            TemplateElement::Expr(
+                TemplateLit::new(vec![
+                    TemplateElement::String("b".into()),
+                    TemplateElement::Expr(Identifier::from("c").into()),
+                    TemplateElement::String("".into()),
+                ])
+                .into(),
+            ),
+            TemplateElement::String("".into()),
+        ])
+        .into()],
+    );
+}
+
+/// Checks that invalid escape sequences are not allowed in untagged template literals.
+#[test]
+fn check_invalid_escape() {
+    check_invalid(r"`\unicode`");
+    check_invalid(r"`a${b}\x0g`");
+    check_invalid(r"`\01`");
+}
+
+/// Checks that a template literal must be terminated.
+#[test]
+fn check_unterminated() {
+    check_invalid("`a${b}");
+    check_invalid("`a${b");
+}
+
+/// Checks tagged templates, which allow invalid escape sequences.
+#[test]
+fn check_tagged() {
+    check_parser(
+        r"tag`a${b}\unicode`",
+        vec![TaggedTemplate::new(
+            Identifier::from("tag"),
+            vec!["a".into(), r"\unicode".into()],
+            vec![Some("a".into()), None],
+            vec![Identifier::from("b").into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks a tagged template on a new line, which continues the previous expression.
+#[test]
+fn check_tagged_new_line() {
+    check_parser(
+        "tag\n`a`",
+        vec![TaggedTemplate::new(
+            Identifier::from("tag"),
+            vec!["a".into()],
+            vec![Some("a".into())],
+            vec![],
+        )
+        .into()],
+    );
+}
@@ -1,10 +1,13 @@
 use crate::syntax::{
-    ast::op::{AssignOp, BitOp, CompOp, NumOp},
+    ast::op::{AssignOp, BitOp, CompOp, LogOp, NumOp},
     ast::{
-        node::{BinOp, Identifier},
+        node::{
+            AsyncArrowFunctionDecl, AwaitExpr, BinOp, Call, FormalParameter, GetConstField,
+            Identifier, Node, Optional, OptionalOperation, OptionalOperationKind, Return,
+        },
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks numeric operations
@@ -182,6 +185,33 @@ fn check_assign_operations() {
         "a >>>= b",
         vec![BinOp::new(AssignOp::Ushr, Identifier::from("a"), Identifier::from("b")).into()],
     );
+    check_parser(
+        "a &&= b",
+        vec![BinOp::new(
+            AssignOp::BoolAnd,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ||= b",
+        vec![BinOp::new(
+            AssignOp::BoolOr,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ??= b",
+        vec![BinOp::new(
+            AssignOp::Coalesce,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
     check_parser(
         "a %= 10 / 2",
         vec![BinOp::new(
@@ -193,6 +223,106 @@ fn check_assign_operations() {
     );
 }
 
+/// Checks the precedence of the nullish coalescing operator.
+#[test]
+fn check_coalesce_operations() {
+    check_parser(
+        "a ?? b | c ?? d",
+        vec![BinOp::new(
+            LogOp::Coalesce,
+            BinOp::new(
+                LogOp::Coalesce,
+                Identifier::from("a"),
+                BinOp::new(BitOp::Or, Identifier::from("b"), Identifier::from("c")),
+            ),
+            Identifier::from("d"),
+        )
+        .into()],
+    );
+    check_parser(
+        "(a || b) ?? (c && d)",
+        vec![BinOp::new(
+            LogOp::Coalesce,
+            BinOp::new(LogOp::Or, Identifier::from("a"), Identifier::from("b")),
+            BinOp::new(LogOp::And, Identifier::from("c"), Identifier::from("d")),
+        )
+        .into()],
+    );
+    check_parser(
+        "a && b || c && d",
+        vec![BinOp::new(
+            LogOp::Or,
+            BinOp::new(LogOp::And, Identifier::from("a"), Identifier::from("b")),
+            BinOp::new(LogOp::And, Identifier::from("c"), Identifier::from("d")),
+        )
+        .into()],
+    );
+}
+
+/// Checks that the nullish coalescing operator can't be mixed with logical operators without
+/// parentheses.
+#[test]
+fn check_coalesce_mixed_with_logical_operations() {
+    check_invalid("a ?? b || c");
+    check_invalid("a || b ?? c");
+    check_invalid("a && b ?? c");
+    check_invalid("a ?? b && c");
+}
+
+/// Checks optional chains.
+#[test]
+fn check_optional_chain() {
+    check_parser(
+        "a?.b.c(d)",
+        vec![Optional::new(
+            Identifier::from("a"),
+            vec![
+                OptionalOperation::new(OptionalOperationKind::GetConstField("b".into()), true),
+                OptionalOperation::new(OptionalOperationKind::GetConstField("c".into()), false),
+                OptionalOperation::new(
+                    OptionalOperationKind::Call(vec![Identifier::from("d").into()].into()),
+                    false,
+                ),
+            ],
+        )
+        .into()],
+    );
+    check_parser(
+        "a.b?.[c]?.()",
+        vec![Optional::new(
+            GetConstField::new(Identifier::from("a"), "b"),
+            vec![
+                OptionalOperation::new(
+                    OptionalOperationKind::GetField(Identifier::from("c").into()),
+                    true,
+                ),
+                OptionalOperation::new(OptionalOperationKind::Call(Box::new([])), true),
+            ],
+        )
+        .into()],
+    );
+    check_parser(
+        "f()?.a",
+        vec![Optional::new(
+            Call::new(Identifier::from("f"), vec![]),
+            vec![OptionalOperation::new(
+                OptionalOperationKind::GetConstField("a".into()),
+                true,
+            )],
+        )
+        .into()],
+    );
+}
+
+/// Checks optional chains that are not valid.
+#[test]
+fn check_invalid_optional_chain() {
+    check_invalid("a?.b = c");
+    check_invalid("a?.`b`");
+    check_invalid("a?.b`c`");
+    check_invalid("new a?.b()");
+}
+
 #[test]
 fn check_relational_operations() {
     check_parser(
@@ -236,3 +366,35 @@ fn check_relational_operations() {
         vec![BinOp::new(CompOp::In, Identifier::from("p"), Identifier::from("o")).into()],
     );
 }
+
+/// Checks async arrow function parsing.
+#[test]
+fn check_async_arrow_functions() {
+    check_parser(
+        "async x => await x",
+        vec![AsyncArrowFunctionDecl::new(
+            vec![FormalParameter::new("x", None, false)],
+            vec![Return::new::<_, _, Option<Box<str>>>(
+                AwaitExpr::from(Node::from(Identifier::from("x"))),
+                None,
+            )
+            .into()],
+        )
+        .into()],
+    );
+    check_parser(
+        "async (a, b) => { return a + b; }",
+        vec![AsyncArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new("a", None, false),
+                FormalParameter::new("b", None, false),
+            ],
+            vec![Return::new::<_, _, Option<Box<str>>>(
+                BinOp::new(NumOp::Add, Identifier::from("a"), Identifier::from("b")),
+                None,
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
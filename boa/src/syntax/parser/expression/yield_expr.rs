@@ -0,0 +1,88 @@
+//! Yield expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+//! [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+
+use super::AssignmentExpression;
+
+use crate::{
+    syntax::{
+        ast::{node::YieldExpr, Keyword, Punctuator},
+        lexer::TokenKind,
+        parser::{
+            cursor::{Cursor, SemicolonResult},
+            AllowAwait, AllowIn, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Parses a yield expression.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct YieldExpression {
+    allow_in: AllowIn,
+    allow_await: AllowAwait,
+}
+
+impl YieldExpression {
+    /// Creates a new `YieldExpression` parser.
+    pub(in crate::syntax::parser) fn new<I, A>(allow_in: I, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for YieldExpression
+where
+    R: Read,
+{
+    type Output = YieldExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("YieldExpression", "Parsing");
+        cursor.expect(TokenKind::Keyword(Keyword::Yield), "yield expression")?;
+
+        // The operand must be on the same line, and `yield` can also end the expression it is
+        // part of.
+        let (has_operand, delegate) = match cursor.peek_semicolon()? {
+            SemicolonResult::Found(_) => (false, false),
+            SemicolonResult::NotFound(tk) => match tk.kind() {
+                TokenKind::Punctuator(Punctuator::CloseParen)
+                | TokenKind::Punctuator(Punctuator::CloseBracket)
+                | TokenKind::Punctuator(Punctuator::Comma)
+                | TokenKind::Punctuator(Punctuator::Colon) => (false, false),
+                TokenKind::Punctuator(Punctuator::Mul) => (true, true),
+                _ => (true, false),
+            },
+        };
+        if delegate {
+            let _ = cursor.next()?.expect("* token vanished");
+        }
+
+        let expr = if has_operand {
+            Some(AssignmentExpression::new(self.allow_in, true, self.allow_await).parse(cursor)?)
+        } else {
+            None
+        };
+
+        Ok(YieldExpr::new(expr, delegate))
+    }
+}
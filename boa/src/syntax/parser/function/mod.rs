@@ -13,13 +13,12 @@ mod tests;
 use crate::{
     syntax::{
         ast::{
-            node::{self},
-            Punctuator,
+            node::{self, Target},
+            Position, Punctuator,
         },
         lexer::{InputElement, TokenKind},
         parser::{
-            expression::Initializer,
-            statement::{BindingIdentifier, StatementList},
+            statement::{BindingElement, BindingTarget, StatementList},
             AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
         },
     },
@@ -106,6 +105,40 @@ where
     }
 }
 
+/// Checks that the parameters of a function don't bind the same name twice, when they must be
+/// unique.
+///
+/// The names must be unique if `unique` is `true`, which is the case for strict mode functions,
+/// methods and arrow functions, or if the parameter list is not simple.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
+pub(in crate::syntax::parser) fn check_parameter_names(
+    params: &[node::FormalParameter],
+    unique: bool,
+    position: Position,
+) -> Result<(), ParseError> {
+    let simple = params.iter().all(|param| {
+        matches!(param.target(), Target::Identifier(_))
+            && param.init().is_none()
+            && !param.is_rest_param()
+    });
+    if !unique && simple {
+        return Ok(());
+    }
+
+    let mut names = Vec::new();
+    for name in params.iter().flat_map(|param| param.target().bound_names()) {
+        if names.contains(&name) {
+            return Err(ParseError::general("duplicate parameter name", position));
+        }
+        names.push(name);
+    }
+    Ok(())
+}
+
 /// Rest parameter parsing.
 ///
 /// More information:
@@ -154,10 +187,9 @@ where
         let _timer = BoaProfiler::global().start_event("BindingRestElement", "Parsing");
         cursor.expect(Punctuator::Spread, "rest parameter")?;
 
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        // TODO: BindingPattern
+        let target = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
-        Ok(Self::Output::new(param, None, true))
+        Ok(Self::Output::new(target, None, true))
     }
 }
 
@@ -198,22 +230,10 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("FormalParameter", "Parsing");
 
-        // TODO: BindingPattern
-
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-
-        let init = if let Some(t) = cursor.peek(0)? {
-            // Check that this is an initilizer before attempting parse.
-            if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
-                Some(Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?)
-            } else {
-                None
-            }
-        } else {
-            None
-        };
+        let (target, init) =
+            BindingElement::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
 
-        Ok(Self::Output::new(param, init, false))
+        Ok(Self::Output::new(target, init, false))
     }
 }
 
@@ -261,25 +281,32 @@ where
         let _timer = BoaProfiler::global().start_event("FunctionStatementList", "Parsing");
 
         let global_strict_mode = cursor.strict_mode();
+        let labels = cursor.take_labels();
+        let mut strict = false;
         if let Some(tk) = cursor.peek(0)? {
             match tk.kind() {
                 TokenKind::Punctuator(Punctuator::CloseBlock) => {
+                    cursor.restore_labels(labels);
                     return Ok(Vec::new().into());
                 }
-                TokenKind::StringLiteral(string) | TokenKind::TemplateLiteral(string) => {
-                    if string == &"use strict".into() {
-                        cursor.set_strict_mode(true);
-                    }
+                TokenKind::StringLiteral(string) if string == "use strict" => {
+                    cursor.set_strict_mode(true);
+                    strict = true;
                 }
                 _ => {}
             }
         }
 
-        let stmlist =
-            StatementList::new(self.allow_yield, self.allow_await, true, true, true).parse(cursor);
+        let stmlist = StatementList::new(self.allow_yield, self.allow_await, true, true, false)
+            .parse(cursor)
+            .map(|mut stmlist| {
+                stmlist.set_strict(strict);
+                stmlist
+            });
 
         // Reset strict mode back to the global scope.
         cursor.set_strict_mode(global_strict_mode);
+        cursor.restore_labels(labels);
         stmlist
     }
 }
@@ -3,7 +3,7 @@ use crate::syntax::{
         ArrowFunctionDecl, BinOp, FormalParameter, FunctionDecl, Identifier, Node, Return,
     },
     ast::op::NumOp,
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks basic function declaration parsing.
@@ -176,3 +176,27 @@ fn check_arrow_empty_return_semicolon_insertion() {
         .into()],
     );
 }
+
+/// Checks that duplicate parameter names are only allowed in non-strict functions with a simple
+/// parameter list.
+#[test]
+fn check_duplicate_parameters() {
+    check_parser(
+        "function foo(a, a) {}",
+        vec![FunctionDecl::new(
+            Box::from("foo"),
+            vec![
+                FormalParameter::new("a", None, false),
+                FormalParameter::new("a", None, false),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+
+    check_invalid("function foo(a, a) { 'use strict'; }");
+    check_invalid("'use strict'; function foo(a, a) {}");
+    check_invalid("function foo(a, ...a) {}");
+    check_invalid("function foo(a, a = 1) {}");
+    check_invalid("(a, a) => {}");
+}
@@ -1,5 +1,6 @@
 //! Boa parser implementation.
 
+mod class;
 mod cursor;
 pub mod error;
 mod expression;
@@ -15,6 +16,27 @@ use cursor::Cursor;
 
 use std::io::Read;
 
+/// Identifiers that can't be used as identifier references or binding identifiers in strict
+/// mode code.
+///
+/// They are only rejected by the parser, since they are still valid property names.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-identifiers-static-semantics-early-errors
+const STRICT_RESERVED_IDENTIFIERS: [&str; 9] = [
+    "implements",
+    "interface",
+    "let",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "static",
+    "yield",
+];
+
 /// Trait implemented by parsers.
 ///
 /// This makes it possible to abstract over the underlying implementation of a parser.
@@ -103,6 +125,24 @@ impl<R> Parser<R> {
     {
         Script.parse(&mut self.cursor)
     }
+
+    /// Parses the source as the code evaluated by a call to `eval`, which is strict mode code if
+    /// `strict` is `true`, or if it starts with a `"use strict"` directive.
+    pub fn parse_eval(&mut self, strict: bool) -> Result<StatementList, ParseError>
+    where
+        R: Read,
+    {
+        self.cursor.set_strict_mode(strict);
+        Script.parse(&mut self.cursor)
+    }
+
+    /// Parses the source as the code of a module, which is always strict mode code.
+    pub fn parse_module(&mut self) -> Result<StatementList, ParseError>
+    where
+        R: Read,
+    {
+        Module.parse(&mut self.cursor)
+    }
 }
 
 /// Parses a full script.
@@ -123,15 +163,16 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         match cursor.peek(0)? {
             Some(tok) => {
-                match tok.kind() {
-                    TokenKind::StringLiteral(string) | TokenKind::TemplateLiteral(string) => {
-                        if string.as_ref() == "use strict" {
-                            cursor.set_strict_mode(true);
-                        }
+                let strict = match tok.kind() {
+                    TokenKind::StringLiteral(string) if string.as_ref() == "use strict" => {
+                        cursor.set_strict_mode(true);
+                        true
                     }
-                    _ => {}
-                }
-                ScriptBody.parse(cursor)
+                    _ => false,
+                };
+                let mut statement_list = ScriptBody.parse(cursor)?;
+                statement_list.set_strict(strict);
+                Ok(statement_list)
             }
             None => Ok(StatementList::from(Vec::new())),
         }
@@ -154,6 +195,47 @@ where
     type Output = StatementList;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        self::statement::StatementList::new(false, false, false, false, true).parse(cursor)
+        statement::StatementList::new(false, false, false, false, false).parse(cursor)
+    }
+}
+
+/// Parses a full module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+#[derive(Debug, Clone, Copy)]
+pub struct Module;
+
+impl<R> TokenParser<R> for Module
+where
+    R: Read,
+{
+    type Output = StatementList;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.set_strict_mode(true);
+        ModuleBody.parse(cursor)
+    }
+}
+
+/// Parses a module body.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleBody
+#[derive(Debug, Clone, Copy)]
+pub struct ModuleBody;
+
+impl<R> TokenParser<R> for ModuleBody
+where
+    R: Read,
+{
+    type Output = StatementList;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        statement::ModuleItemList.parse(cursor)
     }
 }
@@ -0,0 +1,313 @@
+//! Binding pattern parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+//! [spec]: https://tc39.es/ecma262/#sec-destructuring-binding-patterns
+
+#[cfg(test)]
+mod tests;
+
+use super::BindingIdentifier;
+use crate::{
+    syntax::{
+        ast::{
+            node::{ArrayPattern, Node, ObjectPattern, PatternElement, PatternProperty, Target},
+            Keyword, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            class::ClassElementName, expression::Initializer, AllowAwait, AllowIn, AllowYield,
+            Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Binding target parsing.
+///
+/// This is either a binding identifier or a binding pattern.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForBinding
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingTarget {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingTarget {
+    /// Creates a new `BindingTarget` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingTarget
+where
+    R: Read,
+{
+    type Output = Target;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("BindingTarget", "Parsing");
+
+        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                ObjectBindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Target::from)
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                ArrayBindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Target::from)
+            }
+            _ => BindingIdentifier::new(self.allow_yield, self.allow_await)
+                .parse(cursor)
+                .map(Target::from),
+        }
+    }
+}
+
+/// Binding element parsing.
+///
+/// This is a binding target, optionally followed by an initializer.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct BindingElement {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingElement {
+    /// Creates a new `BindingElement` parser.
+    pub(in crate::syntax::parser) fn new<I, Y, A>(
+        allow_in: I,
+        allow_yield: Y,
+        allow_await: A,
+    ) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingElement
+where
+    R: Read,
+{
+    type Output = (Target, Option<Node>);
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("BindingElement", "Parsing");
+
+        let target = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let init = parse_initializer(self.allow_in, self.allow_yield, self.allow_await, cursor)?;
+        Ok((target, init))
+    }
+}
+
+/// Parses the initializer of a binding, if there is one.
+fn parse_initializer<R>(
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    cursor: &mut Cursor<R>,
+) -> Result<Option<Node>, ParseError>
+where
+    R: Read,
+{
+    match cursor.peek(0)? {
+        Some(tok) if tok.kind() == &TokenKind::Punctuator(Punctuator::Assign) => Ok(Some(
+            Initializer::new(allow_in, allow_yield, allow_await).parse(cursor)?,
+        )),
+        _ => Ok(None),
+    }
+}
+
+/// Object binding pattern parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment#object_destructuring
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ObjectBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ObjectBindingPattern {
+    /// Creates a new `ObjectBindingPattern` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ObjectBindingPattern
+where
+    R: Read,
+{
+    type Output = ObjectPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ObjectBindingPattern", "Parsing");
+        cursor.expect(Punctuator::OpenBlock, "object binding pattern")?;
+
+        let mut properties = Vec::new();
+        let mut rest = None;
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                let name =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                rest = Some(Target::from(name));
+                cursor.expect(Punctuator::CloseBlock, "object binding pattern")?;
+                break;
+            }
+
+            // A single name binding is an identifier that is not followed by a colon.
+            let is_single_name = matches!(
+                cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind(),
+                TokenKind::Identifier(_)
+                    | TokenKind::Keyword(Keyword::Yield)
+                    | TokenKind::Keyword(Keyword::Await)
+            ) && !matches!(
+                cursor.peek(1)?.map(|tok| tok.kind()),
+                Some(TokenKind::Punctuator(Punctuator::Colon))
+            );
+
+            let property = if is_single_name {
+                let name =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init =
+                    parse_initializer(AllowIn(true), self.allow_yield, self.allow_await, cursor)?;
+                PatternProperty::new(name.clone(), name, init)
+            } else {
+                // Property names have the same syntax as the names of class elements.
+                let name =
+                    ClassElementName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                cursor.expect(Punctuator::Colon, "object binding pattern")?;
+                let (target, init) =
+                    BindingElement::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                PatternProperty::new(name, target, init)
+            };
+            properties.push(property);
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBlock, "object binding pattern")?;
+                break;
+            }
+        }
+
+        Ok(ObjectPattern::new(properties, rest))
+    }
+}
+
+/// Array binding pattern parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment#array_destructuring
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ArrayBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ArrayBindingPattern {
+    /// Creates a new `ArrayBindingPattern` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ArrayBindingPattern
+where
+    R: Read,
+{
+    type Output = ArrayPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ArrayBindingPattern", "Parsing");
+        cursor.expect(Punctuator::OpenBracket, "array binding pattern")?;
+
+        let mut elements = Vec::new();
+        let mut rest = None;
+        loop {
+            if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
+                break;
+            }
+
+            // An elision is a hole in the pattern.
+            if cursor.next_if(Punctuator::Comma)?.is_some() {
+                elements.push(None);
+                continue;
+            }
+
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                rest = Some(BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?);
+                cursor.expect(Punctuator::CloseBracket, "array binding pattern")?;
+                break;
+            }
+
+            let (target, init) =
+                BindingElement::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+            elements.push(Some(PatternElement::new(target, init)));
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBracket, "array binding pattern")?;
+                break;
+            }
+        }
+
+        Ok(ArrayPattern::new(elements, rest))
+    }
+}
@@ -0,0 +1,72 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            ArrayPattern, ConstDecl, ConstDeclList, Identifier, LetDecl, LetDeclList,
+            ObjectPattern, PatternElement, PatternProperty, VarDecl, VarDeclList,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn check_array_pattern() {
+    check_parser(
+        "var [a, , b = 1, ...c] = d;",
+        vec![VarDeclList::from(vec![VarDecl::new(
+            ArrayPattern::new(
+                vec![
+                    Some(PatternElement::new("a", None)),
+                    None,
+                    Some(PatternElement::new("b", Some(Const::from(1).into()))),
+                ],
+                Some("c".into()),
+            ),
+            Some(Identifier::from("d").into()),
+        )])
+        .into()],
+    );
+}
+
+#[test]
+fn check_object_pattern() {
+    check_parser(
+        "let { a, b: c = 1, ...d } = e;",
+        vec![LetDeclList::from(vec![LetDecl::new(
+            ObjectPattern::new(
+                vec![
+                    PatternProperty::new("a", "a", None),
+                    PatternProperty::new("b", "c", Some(Const::from(1).into())),
+                ],
+                Some("d".into()),
+            ),
+            Some(Identifier::from("e").into()),
+        )])
+        .into()],
+    );
+}
+
+#[test]
+fn check_nested_pattern() {
+    check_parser(
+        "const { a: [b] } = c;",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            ObjectPattern::new(
+                vec![PatternProperty::new(
+                    "a",
+                    ArrayPattern::new(vec![Some(PatternElement::new("b", None))], None),
+                    None,
+                )],
+                None,
+            ),
+            Some(Identifier::from("c")),
+        )])
+        .into()],
+    );
+}
+
+#[test]
+fn check_rest_must_be_last() {
+    check_invalid("var [...a, b] = c;");
+    check_invalid("var { ...a, b } = c;");
+}
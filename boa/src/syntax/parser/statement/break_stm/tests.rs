@@ -1,9 +1,9 @@
 use crate::syntax::{
     ast::{
-        node::{Block, Break, Node, WhileLoop},
+        node::{Block, Break, Labelled, Node, WhileLoop},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 #[test]
@@ -42,14 +42,13 @@ fn inline_block_semicolon_insertion() {
 #[test]
 fn new_line_semicolon_insertion() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_loop(
+            "test",
             Block::from(vec![Break::new("test").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
@@ -68,39 +67,36 @@ fn inline_block() {
 #[test]
 fn new_line_block() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_loop(
+            "test",
             Block::from(vec![Break::new("test").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
 #[test]
 fn reserved_label() {
     check_parser(
-        "while (true) {
+        "await: while (true) {
             break await;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_loop(
+            "await",
             Block::from(vec![Break::new("await").into()]),
-        )
-        .into()],
+        )],
     );
 
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             break yield;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_loop(
+            "yield",
             Block::from(vec![Break::new("yield").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
@@ -131,3 +127,16 @@ fn new_line_block_empty_semicolon_insertion() {
         .into()],
     );
 }
+
+/// Creates a `while (true)` loop with the given label and body.
+fn labelled_loop(label: &str, body: Block) -> Node {
+    let mut while_loop = WhileLoop::new(Const::from(true), body);
+    while_loop.set_labels(vec![label.into()]);
+    Labelled::new(label, while_loop).into()
+}
+
+#[test]
+fn undefined_label() {
+    check_invalid("while (true) { break test; }");
+    check_invalid("test: { function f() { while (true) { break test; } } }");
+}
@@ -0,0 +1,91 @@
+//! Class declaration parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+//! [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{module::DEFAULT_BINDING, ClassDecl},
+            Keyword, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            class::ClassTail, statement::BindingIdentifier, AllowAwait, AllowDefault, AllowYield,
+            Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Class declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ClassDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl ClassDeclaration {
+    /// Creates a new `ClassDeclaration` parser.
+    pub(super) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassDeclaration
+where
+    R: Read,
+{
+    type Output = ClassDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassDeclaration", "Parsing");
+        cursor.expect(Keyword::Class, "class declaration")?;
+
+        // The name of a default export can be omitted, in which case the class is named
+        // `default`.
+        let name = match cursor.peek(0)? {
+            Some(tok)
+                if self.is_default.0
+                    && matches!(
+                        tok.kind(),
+                        TokenKind::Keyword(Keyword::Extends)
+                            | TokenKind::Punctuator(Punctuator::OpenBlock)
+                    ) =>
+            {
+                DEFAULT_BINDING.into()
+            }
+            _ => BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?,
+        };
+        let tail = ClassTail::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok(ClassDecl::new(
+            name,
+            tail.super_ref,
+            tail.constructor,
+            tail.elements,
+        ))
+    }
+}
@@ -0,0 +1,179 @@
+//! Export declaration parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+//! [spec]: https://tc39.es/ecma262/#sec-exports
+
+use super::{
+    import::{expect_contextual, next_is_contextual, IdentifierName, ModuleSpecifier},
+    ClassDeclaration, Declaration, HoistableDeclaration,
+};
+use crate::{
+    syntax::{
+        ast::{
+            node::{ExportDecl, ExportSpecifier, Node},
+            Keyword, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::AssignmentExpression, statement::VariableStatement, Cursor, ParseError,
+            TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Parses an export declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser::statement) struct ExportDeclaration;
+
+impl<R> TokenParser<R> for ExportDeclaration
+where
+    R: Read,
+{
+    type Output = ExportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ExportDeclaration", "Parsing");
+        cursor.expect(Keyword::Export, "export declaration")?;
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let decl = match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next()?;
+                let alias = if next_is_contextual(cursor, "as")? {
+                    cursor.next()?;
+                    Some(IdentifierName.parse(cursor)?)
+                } else {
+                    None
+                };
+                expect_contextual(cursor, "from", "export declaration")?;
+                let module = ModuleSpecifier.parse(cursor)?;
+                cursor.expect_semicolon("export declaration")?;
+                ExportDecl::All { alias, module }
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                cursor.next()?;
+                let specifiers = ExportsList.parse(cursor)?;
+                let module = if next_is_contextual(cursor, "from")? {
+                    cursor.next()?;
+                    Some(ModuleSpecifier.parse(cursor)?)
+                } else {
+                    None
+                };
+                cursor.expect_semicolon("export declaration")?;
+                ExportDecl::Named {
+                    specifiers: specifiers.into(),
+                    module,
+                }
+            }
+            TokenKind::Keyword(Keyword::Var) => ExportDecl::Declaration(Box::new(
+                VariableStatement::new(false, false).parse(cursor)?.into(),
+            )),
+            TokenKind::Keyword(Keyword::Default) => {
+                cursor.next()?;
+                ExportDefault.parse(cursor)?
+            }
+            _ => ExportDecl::Declaration(Box::new(
+                Declaration::new(false, false, true).parse(cursor)?,
+            )),
+        };
+
+        Ok(decl)
+    }
+}
+
+/// Parses the default export of an export declaration, after the `default` keyword.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDefault;
+
+impl<R> TokenParser<R> for ExportDefault
+where
+    R: Read,
+{
+    type Output = ExportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+        match tok.kind() {
+            TokenKind::Keyword(Keyword::Function) => Ok(ExportDecl::DefaultDeclaration(Box::new(
+                HoistableDeclaration::new(false, false, true).parse(cursor)?,
+            ))),
+            TokenKind::Keyword(Keyword::Async)
+                if matches!(
+                    cursor.peek(1)?.map(|tok| tok.kind()),
+                    Some(TokenKind::Keyword(Keyword::Function))
+                ) =>
+            {
+                Ok(ExportDecl::DefaultDeclaration(Box::new(
+                    HoistableDeclaration::new(false, false, true).parse(cursor)?,
+                )))
+            }
+            TokenKind::Keyword(Keyword::Class) => {
+                let class = ClassDeclaration::new(false, false, true).parse(cursor)?;
+                Ok(ExportDecl::DefaultDeclaration(Box::new(Node::from(class))))
+            }
+            _ => {
+                let expression = AssignmentExpression::new(true, false, false).parse(cursor)?;
+                cursor.expect_semicolon("export declaration")?;
+                Ok(ExportDecl::DefaultExpression(Box::new(expression)))
+            }
+        }
+    }
+}
+
+/// Parses the list of named exports of an export declaration, after its opening brace.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-NamedExports
+#[derive(Debug, Clone, Copy)]
+struct ExportsList;
+
+impl<R> TokenParser<R> for ExportsList
+where
+    R: Read,
+{
+    type Output = Vec<ExportSpecifier>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let mut specifiers = Vec::new();
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            let local = IdentifierName.parse(cursor)?;
+            let exported = if next_is_contextual(cursor, "as")? {
+                cursor.next()?;
+                IdentifierName.parse(cursor)?
+            } else {
+                local.clone()
+            };
+            specifiers.push(ExportSpecifier::new(local, exported));
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBlock, "export specifiers")?;
+                break;
+            }
+        }
+        Ok(specifiers)
+    }
+}
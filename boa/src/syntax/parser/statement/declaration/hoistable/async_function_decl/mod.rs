@@ -2,10 +2,14 @@
 mod tests;
 
 use crate::syntax::{
-    ast::{node::AsyncFunctionDecl, Keyword, Punctuator},
+    ast::{
+        node::{module::DEFAULT_BINDING, AsyncFunctionDecl},
+        Keyword, Punctuator,
+    },
     lexer::TokenKind,
     parser::{
-        function::FormalParameters, function::FunctionBody, statement::BindingIdentifier,
+        function::{check_parameter_names, FormalParameters, FunctionBody},
+        statement::BindingIdentifier,
         AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, TokenParser,
     },
 };
@@ -57,13 +61,15 @@ where
         let name = if let Some(token) = tok {
             match token.kind() {
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    // The name of a default export can be omitted, in which case the function
+                    // is named `default`.
                     if !self.is_default.0 {
                         return Err(ParseError::unexpected(
                             token.clone(),
                             " in async function declaration",
                         ));
                     }
-                    None
+                    Some(DEFAULT_BINDING.into())
                 }
                 _ => {
                     Some(BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?)
@@ -75,6 +81,8 @@ where
 
         cursor.expect(Punctuator::OpenParen, "async function declaration")?;
 
+        let params_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+
         let params = FormalParameters::new(false, true).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseParen, "async function declaration")?;
@@ -82,6 +90,8 @@ where
 
         let body = FunctionBody::new(false, true).parse(cursor)?;
 
+        check_parameter_names(&params, cursor.strict_mode() || body.strict(), params_start)?;
+
         cursor.expect(Punctuator::CloseBlock, "async function declaration")?;
 
         Ok(AsyncFunctionDecl::new(name, params, body))
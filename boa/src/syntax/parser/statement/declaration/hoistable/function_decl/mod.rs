@@ -2,9 +2,14 @@
 mod tests;
 
 use crate::syntax::{
-    ast::{node::FunctionDecl, Keyword, Punctuator},
+    ast::{
+        node::{module::DEFAULT_BINDING, FunctionDecl},
+        Keyword, Punctuator,
+    },
+    lexer::TokenKind,
     parser::{
-        function::FormalParameters, function::FunctionBody, statement::BindingIdentifier,
+        function::{check_parameter_names, FormalParameters, FunctionBody},
+        statement::BindingIdentifier,
         AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, TokenParser,
     },
 };
@@ -49,22 +54,37 @@ where
     type Output = FunctionDecl;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        cursor.expect(Keyword::Function, "function declaration")?;
+        let start = cursor
+            .expect(Keyword::Function, "function declaration")?
+            .start_offset();
 
-        // TODO: If self.is_default, then this can be empty.
-        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        // The name of a default export can be omitted, in which case the function is named
+        // `default`.
+        let name = match cursor.peek(0)? {
+            Some(tok)
+                if self.is_default.0
+                    && tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) =>
+            {
+                DEFAULT_BINDING.into()
+            }
+            _ => BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?,
+        };
 
         cursor.expect(Punctuator::OpenParen, "function declaration")?;
 
+        let params_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+
         let params = FormalParameters::new(false, false).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseParen, "function declaration")?;
         cursor.expect(Punctuator::OpenBlock, "function declaration")?;
 
-        let body = FunctionBody::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let body = FunctionBody::new(false, false).parse(cursor)?;
+
+        check_parameter_names(&params, cursor.strict_mode() || body.strict(), params_start)?;
 
         cursor.expect(Punctuator::CloseBlock, "function declaration")?;
 
-        Ok(FunctionDecl::new(name, params, body))
+        Ok(FunctionDecl::new(name, params, body).with_source_text(cursor.source_text(start)))
     }
 }
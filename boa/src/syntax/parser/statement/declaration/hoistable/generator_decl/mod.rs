@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests;
+
+use crate::syntax::{
+    ast::{
+        node::{module::DEFAULT_BINDING, GeneratorDecl},
+        Keyword, Punctuator,
+    },
+    lexer::TokenKind,
+    parser::{
+        function::{check_parameter_names, FormalParameters, FunctionBody},
+        statement::BindingIdentifier,
+        AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, TokenParser,
+    },
+};
+use std::io::Read;
+
+/// Generator declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function*
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(super) struct GeneratorDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl GeneratorDeclaration {
+    /// Creates a new `GeneratorDeclaration` parser.
+    pub(super) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for GeneratorDeclaration
+where
+    R: Read,
+{
+    type Output = GeneratorDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.expect(Keyword::Function, "generator declaration")?;
+        cursor.expect(Punctuator::Mul, "generator declaration")?;
+
+        // The name of a default export can be omitted, in which case the function is named
+        // `default`.
+        let name = match cursor.peek(0)? {
+            Some(tok)
+                if self.is_default.0
+                    && tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) =>
+            {
+                DEFAULT_BINDING.into()
+            }
+            _ => BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?,
+        };
+
+        cursor.expect(Punctuator::OpenParen, "generator declaration")?;
+
+        let params_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+
+        let params = FormalParameters::new(true, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "generator declaration")?;
+        cursor.expect(Punctuator::OpenBlock, "generator declaration")?;
+
+        let body = FunctionBody::new(true, false).parse(cursor)?;
+
+        check_parameter_names(&params, cursor.strict_mode() || body.strict(), params_start)?;
+
+        cursor.expect(Punctuator::CloseBlock, "generator declaration")?;
+
+        Ok(GeneratorDecl::new(name, params, body))
+    }
+}
@@ -0,0 +1,33 @@
+use crate::syntax::{
+    ast::node::{GeneratorDecl, YieldExpr},
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Generator declaration parsing.
+#[test]
+fn generator_declaration() {
+    check_parser(
+        "function* gen() {}",
+        vec![GeneratorDecl::new(Box::from("gen"), vec![], vec![]).into()],
+    );
+}
+
+/// Generator declaration parsing with a `yield` expression in its body.
+#[test]
+fn generator_declaration_yield() {
+    check_parser(
+        "function* gen() { yield; }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![YieldExpr::new(None, false).into()],
+        )
+        .into()],
+    );
+}
+
+/// `yield` can't be used as a parameter name of a generator.
+#[test]
+fn generator_declaration_yield_parameter() {
+    check_invalid("function* gen(yield) {}");
+}
@@ -10,13 +10,15 @@ mod tests;
 
 mod async_function_decl;
 mod function_decl;
+mod generator_decl;
 
 use async_function_decl::AsyncFunctionDeclaration;
 use function_decl::FunctionDeclaration;
+use generator_decl::GeneratorDeclaration;
 
 use crate::{
     syntax::{
-        ast::{Keyword, Node},
+        ast::{Keyword, Node, Punctuator},
         lexer::TokenKind,
         parser::{
             AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
@@ -63,16 +65,26 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("HoistableDeclaration", "Parsing");
-        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
 
         match tok.kind() {
+            TokenKind::Keyword(Keyword::Function)
+                if matches!(
+                    cursor.peek(1)?.map(|tok| tok.kind()),
+                    Some(TokenKind::Punctuator(Punctuator::Mul))
+                ) =>
+            {
+                GeneratorDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Function) => {
                 FunctionDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
                     .parse(cursor)
                     .map(Node::from)
             }
             TokenKind::Keyword(Keyword::Async) => {
-                AsyncFunctionDeclaration::new(self.allow_yield, self.allow_await, false)
+                AsyncFunctionDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
                     .parse(cursor)
                     .map(Node::from)
             }
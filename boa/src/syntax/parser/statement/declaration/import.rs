@@ -0,0 +1,237 @@
+//! Import declaration parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+//! [spec]: https://tc39.es/ecma262/#sec-imports
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{ImportDecl, ImportSpecifier},
+            Keyword, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{statement::BindingIdentifier, Cursor, ParseError, TokenParser},
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Parses an import declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser::statement) struct ImportDeclaration;
+
+impl<R> TokenParser<R> for ImportDeclaration
+where
+    R: Read,
+{
+    type Output = ImportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ImportDeclaration", "Parsing");
+        cursor.expect(Keyword::Import, "import declaration")?;
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        if let TokenKind::StringLiteral(_) = tok.kind() {
+            let module = ModuleSpecifier.parse(cursor)?;
+            cursor.expect_semicolon("import declaration")?;
+            return Ok(ImportDecl::new(None, None, Vec::new(), module));
+        }
+
+        let mut default = None;
+        let mut namespace = None;
+        let mut specifiers = Vec::new();
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let has_default = !matches!(
+            tok.kind(),
+            TokenKind::Punctuator(Punctuator::Mul) | TokenKind::Punctuator(Punctuator::OpenBlock)
+        );
+        if has_default {
+            default = Some(BindingIdentifier::new(false, false).parse(cursor)?);
+        }
+
+        if !has_default || cursor.next_if(Punctuator::Comma)?.is_some() {
+            let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Mul) => {
+                    expect_contextual(cursor, "as", "namespace import")?;
+                    namespace = Some(BindingIdentifier::new(false, false).parse(cursor)?);
+                }
+                TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                    specifiers = ImportsList.parse(cursor)?;
+                }
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![
+                            TokenKind::Punctuator(Punctuator::Mul),
+                            TokenKind::Punctuator(Punctuator::OpenBlock),
+                        ],
+                        tok,
+                        "import declaration",
+                    ))
+                }
+            }
+        }
+
+        expect_contextual(cursor, "from", "import declaration")?;
+        let module = ModuleSpecifier.parse(cursor)?;
+        cursor.expect_semicolon("import declaration")?;
+
+        Ok(ImportDecl::new(default, namespace, specifiers, module))
+    }
+}
+
+/// Parses the list of named imports of an import declaration, after its opening brace.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-NamedImports
+#[derive(Debug, Clone, Copy)]
+struct ImportsList;
+
+impl<R> TokenParser<R> for ImportsList
+where
+    R: Read,
+{
+    type Output = Vec<ImportSpecifier>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let mut specifiers = Vec::new();
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            // An imported name that isn't a valid binding identifier must be renamed.
+            let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            let specifier = if let TokenKind::Identifier(_) = tok.kind() {
+                let imported = BindingIdentifier::new(false, false).parse(cursor)?;
+                if next_is_contextual(cursor, "as")? {
+                    cursor.next()?;
+                    let local = BindingIdentifier::new(false, false).parse(cursor)?;
+                    ImportSpecifier::new(imported, local)
+                } else {
+                    ImportSpecifier::new(imported.clone(), imported)
+                }
+            } else {
+                let imported = IdentifierName.parse(cursor)?;
+                expect_contextual(cursor, "as", "import specifier")?;
+                let local = BindingIdentifier::new(false, false).parse(cursor)?;
+                ImportSpecifier::new(imported, local)
+            };
+            specifiers.push(specifier);
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBlock, "import specifiers")?;
+                break;
+            }
+        }
+        Ok(specifiers)
+    }
+}
+
+/// Parses the string literal of a module specifier, as in `from "module"`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleSpecifier
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser::statement) struct ModuleSpecifier;
+
+impl<R> TokenParser<R> for ModuleSpecifier
+where
+    R: Read,
+{
+    type Output = Box<str>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::StringLiteral(module) => Ok(module.as_str().into()),
+            _ => Err(ParseError::expected(
+                vec![TokenKind::string_literal("module specifier")],
+                tok,
+                "module specifier",
+            )),
+        }
+    }
+}
+
+/// Parses an identifier name, which, unlike a binding identifier, can be a reserved word.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierName
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser::statement) struct IdentifierName;
+
+impl<R> TokenParser<R> for IdentifierName
+where
+    R: Read,
+{
+    type Output = Box<str>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::Identifier(name) => Ok(name.clone()),
+            TokenKind::Keyword(keyword) => Ok(keyword.as_str().into()),
+            TokenKind::BooleanLiteral(true) => Ok("true".into()),
+            TokenKind::BooleanLiteral(false) => Ok("false".into()),
+            TokenKind::NullLiteral => Ok("null".into()),
+            _ => Err(ParseError::expected(
+                vec![TokenKind::identifier("identifier")],
+                tok,
+                "identifier name",
+            )),
+        }
+    }
+}
+
+/// Returns `true` if the next token is the given contextual keyword, such as `as` or `from`.
+pub(in crate::syntax::parser::statement) fn next_is_contextual<R>(
+    cursor: &mut Cursor<R>,
+    keyword: &str,
+) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    Ok(matches!(
+        cursor.peek(0)?.map(|tok| tok.kind()),
+        Some(TokenKind::Identifier(name)) if name.as_ref() == keyword
+    ))
+}
+
+/// Consumes the next token, which must be the given contextual keyword.
+pub(in crate::syntax::parser::statement) fn expect_contextual<R>(
+    cursor: &mut Cursor<R>,
+    keyword: &str,
+    context: &'static str,
+) -> Result<(), ParseError>
+where
+    R: Read,
+{
+    let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+    match tok.kind() {
+        TokenKind::Identifier(name) if name.as_ref() == keyword => Ok(()),
+        _ => Err(ParseError::expected(
+            vec![TokenKind::identifier(keyword)],
+            tok,
+            context,
+        )),
+    }
+}
@@ -11,13 +11,13 @@ use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
         ast::{
-            node::{ConstDecl, ConstDeclList, LetDecl, LetDeclList, Node},
+            node::{ConstDecl, ConstDeclList, LetDecl, LetDeclList, Node, Target},
             Keyword, Punctuator,
         },
         parser::{
             cursor::{Cursor, SemicolonResult},
             expression::Initializer,
-            statement::BindingIdentifier,
+            statement::BindingTarget,
             AllowAwait, AllowIn, AllowYield, ParseError, ParseResult, TokenParser,
         },
     },
@@ -151,14 +151,14 @@ where
         let mut const_decls = Vec::new();
 
         loop {
-            let (ident, init) =
+            let (target, init) =
                 LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
                     .parse(cursor)?;
 
             if self.is_const {
                 if self.const_init_required {
                     if init.is_some() {
-                        const_decls.push(ConstDecl::new(ident, init));
+                        const_decls.push(ConstDecl::new(target, init));
                     } else {
                         return Err(ParseError::expected(
                             vec![TokenKind::Punctuator(Punctuator::Assign)],
@@ -167,10 +167,10 @@ where
                         ));
                     }
                 } else {
-                    const_decls.push(ConstDecl::new(ident, init))
+                    const_decls.push(ConstDecl::new(target, init))
                 }
             } else {
-                let_decls.push(LetDecl::new(ident, init));
+                let_decls.push(LetDecl::new(target, init));
             }
 
             match cursor.peek_semicolon()? {
@@ -240,12 +240,12 @@ impl<R> TokenParser<R> for LexicalBinding
 where
     R: Read,
 {
-    type Output = (Box<str>, Option<Node>);
+    type Output = (Target, Option<Node>);
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("LexicalBinding", "Parsing");
 
-        let ident = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let target = BindingTarget::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = if let Some(t) = cursor.peek(0)? {
             if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
@@ -260,6 +260,6 @@ where
             None
         };
 
-        Ok((ident, init))
+        Ok((target, init))
     }
 }
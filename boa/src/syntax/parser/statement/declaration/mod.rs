@@ -7,12 +7,19 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements#Declarations
 //! [spec]:https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement
 
+mod class_decl;
+mod export;
 mod hoistable;
+mod import;
 mod lexical;
 #[cfg(test)]
 mod tests;
 
-use self::{hoistable::HoistableDeclaration, lexical::LexicalDeclaration};
+pub(super) use self::{export::ExportDeclaration, import::ImportDeclaration};
+
+use self::{
+    class_decl::ClassDeclaration, hoistable::HoistableDeclaration, lexical::LexicalDeclaration,
+};
 
 use crate::syntax::lexer::TokenKind;
 use crate::{
@@ -66,6 +73,11 @@ where
             TokenKind::Keyword(Keyword::Function) | TokenKind::Keyword(Keyword::Async) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false).parse(cursor)
             }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassDeclaration::new(self.allow_yield, self.allow_await, false)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
                 LexicalDeclaration::new(
                     true,
@@ -1,9 +1,14 @@
 use crate::syntax::{
     ast::{
-        node::{ConstDecl, ConstDeclList, LetDecl, LetDeclList, Node, VarDecl, VarDeclList},
+        node::{
+            BinOp, Call, ClassDecl, ConstDecl, ConstDeclList, ExportDecl, ExportSpecifier,
+            FunctionDecl, Identifier, ImportCall, ImportDecl, ImportSpecifier, LetDecl,
+            LetDeclList, Node, VarDecl, VarDeclList,
+        },
+        op::NumOp,
         Const,
     },
-    parser::tests::{check_invalid, check_parser},
+    parser::tests::{check_invalid, check_invalid_module, check_module_parser, check_parser},
 };
 
 /// Checks `var` declaration parsing.
@@ -166,3 +171,161 @@ fn multiple_const_declaration() {
         .into()],
     );
 }
+
+/// Checks the parsing of the forms of `import` declarations.
+#[test]
+fn import_declarations() {
+    check_module_parser(
+        r#"
+        import "side-effect";
+        import a from "a";
+        import * as ns from "ns";
+        import b, { c, d as e, default as f } from "b";
+        import {} from "empty";
+        "#,
+        vec![
+            ImportDecl::new(None, None, Vec::new(), "side-effect".into()).into(),
+            ImportDecl::new(Some("a".into()), None, Vec::new(), "a".into()).into(),
+            ImportDecl::new(None, Some("ns".into()), Vec::new(), "ns".into()).into(),
+            ImportDecl::new(
+                Some("b".into()),
+                None,
+                vec![
+                    ImportSpecifier::new("c", "c"),
+                    ImportSpecifier::new("d", "e"),
+                    ImportSpecifier::new("default", "f"),
+                ],
+                "b".into(),
+            )
+            .into(),
+            ImportDecl::new(None, None, Vec::new(), "empty".into()).into(),
+        ],
+    );
+}
+
+/// Checks that invalid `import` declarations are rejected.
+#[test]
+fn invalid_import_declarations() {
+    check_invalid_module(r#"import { default } from "a";"#);
+    check_invalid_module(r#"import a;"#);
+    check_invalid_module(r#"import * from "a";"#);
+    check_invalid_module(r#"import { a } "a";"#);
+}
+
+/// Checks the parsing of the forms of `export` declarations.
+#[test]
+fn export_declarations() {
+    check_module_parser(
+        r#"
+        export var a = 1;
+        export const b = 2;
+        export { a, b as c };
+        export { d as default, e } from "d";
+        export * from "f";
+        export * as g from "g";
+        export default a + 1;
+        "#,
+        vec![
+            ExportDecl::Declaration(Box::new(
+                VarDeclList::from(vec![VarDecl::new("a", Some(Const::from(1).into()))]).into(),
+            ))
+            .into(),
+            ExportDecl::Declaration(Box::new(
+                ConstDeclList::from(ConstDecl::new("b", Some(Const::from(2)))).into(),
+            ))
+            .into(),
+            ExportDecl::Named {
+                specifiers: vec![
+                    ExportSpecifier::new("a", "a"),
+                    ExportSpecifier::new("b", "c"),
+                ]
+                .into(),
+                module: None,
+            }
+            .into(),
+            ExportDecl::Named {
+                specifiers: vec![
+                    ExportSpecifier::new("d", "default"),
+                    ExportSpecifier::new("e", "e"),
+                ]
+                .into(),
+                module: Some("d".into()),
+            }
+            .into(),
+            ExportDecl::All {
+                alias: None,
+                module: "f".into(),
+            }
+            .into(),
+            ExportDecl::All {
+                alias: Some("g".into()),
+                module: "g".into(),
+            }
+            .into(),
+            ExportDecl::DefaultExpression(Box::new(
+                BinOp::new(NumOp::Add, Identifier::from("a"), Const::from(1)).into(),
+            ))
+            .into(),
+        ],
+    );
+}
+
+/// Checks that anonymous default function and class declarations are named `default`, and
+/// that the exported functions are hoisted.
+#[test]
+fn export_default_declarations() {
+    check_module_parser(
+        "export default class {}",
+        vec![ExportDecl::DefaultDeclaration(Box::new(
+            ClassDecl::new("default", None, None, Vec::new()).into(),
+        ))
+        .into()],
+    );
+
+    check_module_parser(
+        "let x = f(); export function f() {}",
+        vec![
+            ExportDecl::Declaration(Box::new(
+                FunctionDecl::new("f", Vec::new(), Vec::new()).into(),
+            ))
+            .into(),
+            LetDeclList::from(vec![LetDecl::new(
+                "x",
+                Node::from(Call::new(Identifier::from("f"), Vec::new())),
+            )])
+            .into(),
+        ],
+    );
+
+    check_module_parser(
+        "export default function () {}",
+        vec![ExportDecl::DefaultDeclaration(Box::new(
+            FunctionDecl::new("default", Vec::new(), Vec::new()).into(),
+        ))
+        .into()],
+    );
+}
+
+/// Checks that modules are strict mode code, and that `import` and `export` declarations are
+/// only allowed at the top level of modules.
+#[test]
+fn module_only_declarations() {
+    check_invalid_module("let eval = 1;");
+    check_invalid_module(r#"{ import a from "a"; }"#);
+    check_invalid_module("function f() { export const a = 1; }");
+    check_invalid(r#"import a from "a";"#);
+    check_invalid("export const a = 1;");
+}
+
+/// Checks the parsing of dynamic `import()` calls, in scripts and modules.
+#[test]
+fn import_calls() {
+    check_parser(
+        r#"import("a");"#,
+        vec![ImportCall::new(Const::from("a")).into()],
+    );
+    check_module_parser(
+        r#"import("a");"#,
+        vec![ImportCall::new(Const::from("a")).into()],
+    );
+}
@@ -11,11 +11,11 @@ use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
         ast::{
-            node::{ForLoop, ForOfLoop, Node},
-            Const, Keyword, Punctuator,
+            node::{ForInLoop, ForLoop, ForOfLoop, IterableLoopInitializer, Node},
+            Const, Keyword, Position, Punctuator,
         },
         parser::{
-            expression::Expression,
+            expression::{assignment_target, Expression},
             statement::declaration::Declaration,
             statement::{variable::VariableDeclarationList, Statement},
             AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
@@ -85,22 +85,41 @@ where
                 Some(Declaration::new(self.allow_yield, self.allow_await, false).parse(cursor)?)
             }
             TokenKind::Punctuator(Punctuator::Semicolon) => None,
-            _ => Some(Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?),
+            // The `in` operator is not allowed, so that the head of a `for...in` loop is not
+            // parsed as a relational expression.
+            _ => Some(Expression::new(false, self.allow_yield, self.allow_await).parse(cursor)?),
         };
 
-        // TODO: for..in, for..of
         match cursor.peek(0)? {
-            Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::In) => {
-                unimplemented!("for...in statement")
+            Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::In) && init.is_some() => {
+                let position = tok.span().start();
+                let variable = loop_initializer(
+                    init.as_ref().unwrap(),
+                    "invalid left-hand side in for...in loop",
+                    position,
+                )?;
+                let _ = cursor.next();
+                let expr =
+                    Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                cursor.expect(Punctuator::CloseParen, "for in statement")?;
+                let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)?;
+                return Ok(ForInLoop::new(variable, expr, body).into());
             }
             Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::Of) && init.is_some() => {
+                let position = tok.span().start();
+                let variable = loop_initializer(
+                    init.as_ref().unwrap(),
+                    "invalid left-hand side in for...of loop",
+                    position,
+                )?;
                 let _ = cursor.next();
                 let iterable =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "for of statement")?;
                 let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)?;
-                return Ok(ForOfLoop::new(init.unwrap(), iterable, body).into());
+                return Ok(ForOfLoop::new(variable, iterable, body).into());
             }
             _ => {}
         }
@@ -133,3 +152,36 @@ where
         Ok(ForLoop::new(init, cond, step, body).into())
     }
 }
+
+/// Converts the head of a `for...in` or `for...of` loop to the initializer of its variable.
+///
+/// Declarations must declare a single binding without an initializer, and expressions must be
+/// valid assignment targets.
+fn loop_initializer(
+    init: &Node,
+    message: &'static str,
+    position: Position,
+) -> Result<IterableLoopInitializer, ParseError> {
+    let initializer = match init {
+        Node::VarDeclList(list) => match list.as_ref() {
+            [decl] if decl.init().is_none() => {
+                Some(IterableLoopInitializer::Var(decl.target().clone()))
+            }
+            _ => None,
+        },
+        Node::LetDeclList(list) => match list.as_ref() {
+            [decl] if decl.init().is_none() => {
+                Some(IterableLoopInitializer::Let(decl.target().clone()))
+            }
+            _ => None,
+        },
+        Node::ConstDeclList(list) => match list.as_ref() {
+            [decl] if decl.init().is_none() => {
+                Some(IterableLoopInitializer::Const(decl.target().clone()))
+            }
+            _ => None,
+        },
+        node => assignment_target(node).map(IterableLoopInitializer::Assignment),
+    };
+    initializer.ok_or_else(|| ParseError::general(message, position))
+}
@@ -1,13 +1,13 @@
 use crate::syntax::{
     ast::{
         node::{
-            field::GetConstField, BinOp, Block, Break, Call, DoWhileLoop, Identifier, UnaryOp,
-            VarDecl, VarDeclList, WhileLoop,
+            field::GetConstField, BinOp, Block, Break, Call, DoWhileLoop, ForInLoop, Identifier,
+            IterableLoopInitializer, UnaryOp, VarDecl, VarDeclList, WhileLoop,
         },
         op::{self, AssignOp, CompOp},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks do-while statement parsing.
@@ -136,3 +136,34 @@ fn do_while_spaces() {
         .into()],
     );
 }
+
+/// Checks parsing of a `for...in` statement.
+#[test]
+fn check_for_in() {
+    check_parser(
+        "for (var key in obj) {}",
+        vec![ForInLoop::new(
+            IterableLoopInitializer::Var("key".into()),
+            Identifier::from("obj"),
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+    check_parser(
+        "for (key in obj) {}",
+        vec![ForInLoop::new(
+            IterableLoopInitializer::Assignment("key".into()),
+            Identifier::from("obj"),
+            Block::from(vec![]),
+        )
+        .into()],
+    );
+}
+
+/// Checks that the head of a `for...in` statement declares a single binding.
+#[test]
+fn check_invalid_for_in() {
+    check_invalid("for (var a, b in obj) {}");
+    check_invalid("for (let a = 1 in obj) {}");
+    check_invalid("for (1 in obj) {}");
+}
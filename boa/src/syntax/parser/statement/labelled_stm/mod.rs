@@ -2,15 +2,23 @@ use std::io::Read;
 
 use super::{LabelIdentifier, Statement};
 use crate::{
-    syntax::ast::Node,
+    syntax::ast::{
+        node::{Labelled, Node},
+        Keyword,
+    },
     syntax::{
         ast::Punctuator,
+        lexer::{InputElement, TokenKind},
         parser::{
             cursor::Cursor, error::ParseError, AllowAwait, AllowReturn, AllowYield, TokenParser,
         },
     },
     BoaProfiler,
 };
+
+#[cfg(test)]
+mod tests;
+
 /// Labelled Statement Parsing
 ///
 /// More information
@@ -49,18 +57,83 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("Label", "Parsing");
-        let name = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        cursor.expect(Punctuator::Colon, "Labelled Statement")?;
-        let mut stmt =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
 
-        set_label_for_node(&mut stmt, name);
-        Ok(stmt)
+        // All the labels of a statement are parsed at once, since whether they can be the target
+        // of a `continue` statement depends on the labelled statement.
+        let mut labels = Vec::new();
+        loop {
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let name = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            cursor.expect(Punctuator::Colon, "Labelled Statement")?;
+            if labels.contains(&name) || cursor.has_label(&name, false) {
+                return Err(ParseError::general("duplicate label", position));
+            }
+            labels.push(name);
+
+            if !is_label_start(cursor, self.allow_yield, self.allow_await)? {
+                break;
+            }
+        }
+
+        let iteration = matches!(
+            cursor.peek(0)?.map(|tok| tok.kind()),
+            Some(TokenKind::Keyword(Keyword::For))
+                | Some(TokenKind::Keyword(Keyword::While))
+                | Some(TokenKind::Keyword(Keyword::Do))
+        );
+        for label in &labels {
+            cursor.push_label(label.clone(), iteration);
+        }
+        let stmt =
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor);
+        for _ in &labels {
+            cursor.pop_label();
+        }
+
+        let mut stmt = stmt?;
+        set_labels_for_node(&mut stmt, &labels);
+        Ok(labels
+            .into_iter()
+            .rev()
+            .fold(stmt, |stmt, label| Labelled::new(label, stmt).into()))
     }
 }
 
-fn set_label_for_node(stmt: &mut Node, name: Box<str>) {
-    if let Node::ForLoop(ref mut for_loop) = stmt {
-        for_loop.set_label(name)
+/// Returns `true` if the next tokens are a label identifier followed by a colon, which start a
+/// labelled statement.
+pub(super) fn is_label_start<R>(
+    cursor: &mut Cursor<R>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    let identifier = match cursor.peek(0)?.map(|tok| tok.kind()) {
+        Some(TokenKind::Identifier(_)) => true,
+        Some(TokenKind::Keyword(Keyword::Yield)) => !allow_yield.0,
+        Some(TokenKind::Keyword(Keyword::Await)) => !allow_await.0,
+        _ => false,
+    };
+    if !identifier {
+        return Ok(false);
+    }
+
+    cursor.set_goal(InputElement::Div);
+    Ok(matches!(
+        cursor.peek(1)?.map(|tok| tok.kind()),
+        Some(TokenKind::Punctuator(Punctuator::Colon))
+    ))
+}
+
+/// Gives the labels of a loop to it, so that its `continue` statements can target them.
+fn set_labels_for_node(stmt: &mut Node, labels: &[Box<str>]) {
+    match stmt {
+        Node::ForLoop(ref mut for_loop) => for_loop.set_labels(labels),
+        Node::ForInLoop(ref mut for_in_loop) => for_in_loop.set_labels(labels),
+        Node::ForOfLoop(ref mut for_of_loop) => for_of_loop.set_labels(labels),
+        Node::WhileLoop(ref mut while_loop) => while_loop.set_labels(labels),
+        Node::DoWhileLoop(ref mut do_while) => do_while.set_labels(labels),
+        _ => {}
     }
 }
@@ -0,0 +1,79 @@
+use crate::syntax::{
+    ast::{
+        node::{Block, Break, Continue, ForLoop, If, Labelled, Node, WhileLoop},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn labelled_block() {
+    check_parser(
+        "a: { break a; }",
+        vec![Labelled::new("a", Block::from(vec![Break::new("a").into()])).into()],
+    );
+}
+
+#[test]
+fn labelled_if() {
+    check_parser(
+        "a: if (true) break a;",
+        vec![Labelled::new(
+            "a",
+            If::new::<_, _, Block, _>(Const::from(true), Break::new("a"), None),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn labelled_loop() {
+    let mut for_loop = ForLoop::new(
+        None,
+        Node::from(Const::from(true)),
+        None,
+        Continue::new("a"),
+    );
+    for_loop.set_labels(vec!["a".into()]);
+    check_parser(
+        "a: for (;;) continue a;",
+        vec![Labelled::new("a", for_loop).into()],
+    );
+}
+
+#[test]
+fn multiple_labels() {
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Continue::new("a").into(), Continue::new("b").into()]),
+    );
+    while_loop.set_labels(vec!["a".into(), "b".into()]);
+    check_parser(
+        "a: b: while (true) { continue a; continue b; }",
+        vec![Labelled::new("a", Labelled::new("b", while_loop)).into()],
+    );
+}
+
+#[test]
+fn duplicate_label() {
+    check_invalid("a: a: while (true) {}");
+    check_invalid("a: { a: {} }");
+    check_invalid("a: while (true) { if (true) { a: {} } }");
+}
+
+#[test]
+fn same_label_in_sibling_statements() {
+    check_parser(
+        "a: {} a: {}",
+        vec![
+            Labelled::new("a", Block::from(Vec::new())).into(),
+            Labelled::new("a", Block::from(Vec::new())).into(),
+        ],
+    );
+}
+
+#[test]
+fn label_outside_of_statement() {
+    check_invalid("a: {} break a;");
+    check_invalid("a: {} while (true) { continue a; }");
+}
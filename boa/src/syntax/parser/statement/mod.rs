@@ -7,6 +7,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 //! [spec]: https://tc39.es/ecma262/#sec-ecmascript-language-statements-and-declarations
 
+mod binding_pattern;
 mod block;
 mod break_stm;
 mod continue_stm;
@@ -20,12 +21,15 @@ mod switch;
 mod throw;
 mod try_stm;
 mod variable;
+mod with_stm;
+
+pub(in crate::syntax::parser) use self::binding_pattern::{BindingElement, BindingTarget};
 
 use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::Declaration,
+    declaration::{Declaration, ExportDeclaration, ImportDeclaration},
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -34,19 +38,22 @@ use self::{
     throw::ThrowStatement,
     try_stm::TryStatement,
     variable::VariableStatement,
+    with_stm::WithStatement,
 };
 
-use super::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser};
+use super::{
+    AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
+    STRICT_RESERVED_IDENTIFIERS,
+};
 
 use crate::{
     syntax::{
         ast::{node, Keyword, Node, Punctuator},
-        lexer::{Error as LexError, InputElement, TokenKind},
-        parser::expression::await_expr::AwaitExpression,
+        lexer::{Error as LexError, TokenKind},
     },
     BoaProfiler,
 };
-use labelled_stm::LabelledStatement;
+use labelled_stm::{is_label_start, LabelledStatement};
 
 use std::io::Read;
 
@@ -111,9 +118,6 @@ where
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind() {
-            TokenKind::Keyword(Keyword::Await) => AwaitExpression::new(self.allow_yield)
-                .parse(cursor)
-                .map(Node::from),
             TokenKind::Keyword(Keyword::If) => {
                 IfStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -137,7 +141,6 @@ where
             TokenKind::Keyword(Keyword::For) => {
                 ForStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
-                    .map(Node::from)
             }
             TokenKind::Keyword(Keyword::Return) => {
                 if self.allow_return.0 {
@@ -168,6 +171,11 @@ where
                     .parse(cursor)
                     .map(Node::from)
             }
+            TokenKind::Keyword(Keyword::With) => {
+                WithStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Switch) => {
                 SwitchStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -178,23 +186,17 @@ where
                     .parse(cursor)
                     .map(Node::from)
             }
-            TokenKind::Identifier(_) => {
+            TokenKind::Identifier(_)
+            | TokenKind::Keyword(Keyword::Yield)
+            | TokenKind::Keyword(Keyword::Await) => {
                 // Labelled Statement check
-                cursor.set_goal(InputElement::Div);
-                let tok = cursor.peek(1)?;
-                if tok.is_some()
-                    && matches!(
-                        tok.unwrap().kind(),
-                        TokenKind::Punctuator(Punctuator::Colon)
-                    )
-                {
+                if is_label_start(cursor, self.allow_yield, self.allow_await)? {
                     return LabelledStatement::new(
                         self.allow_yield,
                         self.allow_await,
                         self.allow_return,
                     )
-                    .parse(cursor)
-                    .map(Node::from);
+                    .parse(cursor);
                 }
 
                 ExpressionStatement::new(self.allow_yield, self.allow_await).parse(cursor)
@@ -387,6 +389,18 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementListItem", "Parsing");
         let strict_mode = cursor.strict_mode();
+
+        // `async` only starts a declaration when it is followed by `function`, otherwise it
+        // starts an async arrow function.
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        if tok.kind() == &TokenKind::Keyword(Keyword::Async) {
+            let next = cursor.peek(1)?.map(|tok| tok.kind().clone());
+            if next != Some(TokenKind::Keyword(Keyword::Function)) {
+                return Statement::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor);
+            }
+        }
+
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match *tok.kind() {
@@ -399,7 +413,9 @@ where
                 }
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
-            TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
+            TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Class) => {
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
             _ => {
@@ -409,6 +425,74 @@ where
     }
 }
 
+/// Reads the list of items of a module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ModuleItemList;
+
+impl<R> TokenParser<R> for ModuleItemList
+where
+    R: Read,
+{
+    type Output = node::StatementList;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ModuleItemList", "Parsing");
+        let mut items = Vec::new();
+
+        while cursor.peek(0)?.is_some() {
+            items.push(ModuleItem.parse(cursor)?);
+
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
+        }
+
+        items.sort_by(Node::hoistable_order);
+
+        Ok(items.into())
+    }
+}
+
+/// Module item parsing
+///
+/// A module item can either be an import declaration, an export declaration, or a statement
+/// list item.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[derive(Debug, Clone, Copy)]
+struct ModuleItem;
+
+impl<R> TokenParser<R> for ModuleItem
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ModuleItem", "Parsing");
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+
+        match tok.kind() {
+            // `import(` starts a dynamic import expression.
+            TokenKind::Keyword(Keyword::Import)
+                if cursor.peek(1)?.map(|tok| tok.kind())
+                    != Some(&TokenKind::Punctuator(Punctuator::OpenParen)) =>
+            {
+                ImportDeclaration.parse(cursor).map(Node::from)
+            }
+            TokenKind::Keyword(Keyword::Export) => ExportDeclaration.parse(cursor).map(Node::from),
+            _ => StatementListItem::new(false, false, false, false).parse(cursor),
+        }
+    }
+}
+
 /// Label identifier parsing.
 ///
 /// This seems to be the same as a `BindingIdentifier`.
@@ -458,6 +542,17 @@ where
         let next_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
 
         match next_token.kind() {
+            TokenKind::Identifier(ref s)
+                if cursor.strict_mode()
+                    && (STRICT_RESERVED_IDENTIFIERS.contains(&s.as_ref())
+                        || s.as_ref() == "eval"
+                        || s.as_ref() == "arguments") =>
+            {
+                Err(ParseError::lex(LexError::Syntax(
+                    format!("binding identifier '{}' not allowed in strict mode", s).into(),
+                    next_token.span().start(),
+                )))
+            }
             TokenKind::Identifier(ref s) => Ok(s.clone()),
             TokenKind::Keyword(k @ Keyword::Yield) if !self.allow_yield.0 => {
                 if cursor.strict_mode() {
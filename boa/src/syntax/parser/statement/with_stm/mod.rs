@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests;
+
+use super::Statement;
+
+use crate::{
+    syntax::{
+        ast::{node::With, Keyword, Punctuator},
+        parser::{
+            expression::Expression, AllowAwait, AllowReturn, AllowYield, Cursor, ParseError,
+            TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// With statement parsing.
+///
+/// The `with` statement is an early error in strict mode code.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/with
+/// [spec]: https://tc39.es/ecma262/#prod-WithStatement
+#[derive(Debug, Clone, Copy)]
+pub(super) struct WithStatement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    allow_return: AllowReturn,
+}
+
+impl WithStatement {
+    /// Creates a new `WithStatement` parser.
+    pub(super) fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        R: Into<AllowReturn>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            allow_return: allow_return.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for WithStatement
+where
+    R: Read,
+{
+    type Output = With;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("WithStatement", "Parsing");
+        let position = cursor
+            .expect(Keyword::With, "with statement")?
+            .span()
+            .start();
+        if cursor.strict_mode() {
+            return Err(ParseError::general(
+                "with statement not allowed in strict mode",
+                position,
+            ));
+        }
+
+        cursor.expect(Punctuator::OpenParen, "with statement")?;
+        let expr = Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+        cursor.expect(Punctuator::CloseParen, "with statement")?;
+
+        let statement =
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+
+        Ok(With::new(expr, statement))
+    }
+}
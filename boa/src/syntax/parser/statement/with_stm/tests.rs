@@ -0,0 +1,34 @@
+use crate::syntax::{
+    ast::node::{Block, Call, Identifier, With},
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn with_block() {
+    check_parser(
+        "with (obj) { f(); }",
+        vec![With::new(
+            Identifier::from("obj"),
+            Block::from(vec![Call::new(Identifier::from("f"), vec![]).into()]),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn with_statement() {
+    check_parser(
+        "with (obj) f();",
+        vec![With::new(
+            Identifier::from("obj"),
+            Call::new(Identifier::from("f"), vec![]),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn with_in_strict_mode() {
+    check_invalid("'use strict'; with (obj) {}");
+    check_invalid("function f() { 'use strict'; with (obj) {} }");
+}
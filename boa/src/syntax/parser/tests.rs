@@ -32,6 +32,26 @@ pub(super) fn check_invalid(js: &str) {
     assert!(Parser::new(js.as_bytes()).parse_all().is_err());
 }
 
+/// Checks that the given JavaScript module gives the expected items.
+#[track_caller]
+pub(super) fn check_module_parser<L>(js: &str, items: L)
+where
+    L: Into<Box<[Node]>>,
+{
+    assert_eq!(
+        Parser::new(js.as_bytes())
+            .parse_module()
+            .expect("failed to parse"),
+        StatementList::from(items)
+    );
+}
+
+/// Checks that the given JavaScript module creates a parse error.
+#[track_caller]
+pub(super) fn check_invalid_module(js: &str) {
+    assert!(Parser::new(js.as_bytes()).parse_module().is_err());
+}
+
 /// Should be parsed as `new Class().method()` instead of `new (Class().method())`
 #[test]
 fn check_construct_call_precedence() {
@@ -62,7 +62,7 @@ impl From<RcSymbol> for Value {
 pub struct TryFromCharError;
 
 impl Display for TryFromCharError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Could not convert value to a char type")
     }
 }
@@ -164,7 +164,7 @@ impl From<GcObject> for Value {
 pub struct TryFromObjectError;
 
 impl Display for TryFromObjectError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Could not convert value to an Object type")
     }
 }
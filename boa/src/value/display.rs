@@ -1,4 +1,5 @@
 use super::*;
+use crate::property::AccessorDescriptor;
 
 /// This object is used for displaying a `Value`.
 #[derive(Debug, Clone, Copy)]
@@ -13,7 +14,7 @@ pub struct ValueDisplay<'value> {
 /// - The function with which to print
 /// - The indentation for the current level (for nested objects)
 /// - A HashSet with the addresses of the already printed objects for the current branch
-///      (used to avoid infinite loops when there are cyclic deps)
+///   (used to avoid infinite loops when there are cyclic deps)
 macro_rules! print_obj_value {
     (all of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr) => {
         {
@@ -48,16 +49,17 @@ macro_rules! print_obj_value {
     };
     (props of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr, $print_internals:expr) => {
         print_obj_value!(impl $obj, |(key, val)| {
-            let v = &val
-                // FIXME: handle accessor descriptors
-                .as_data_descriptor()
-                .unwrap()
-                .value();
+            let v = match val {
+                PropertyDescriptor::Data(data) => {
+                    $display_fn(&data.value(), $encounters, $indent.wrapping_add(4), $print_internals)
+                }
+                PropertyDescriptor::Accessor(accessor) => display_accessor(accessor).to_string(),
+            };
 
             format!(
                 "{:>width$}: {}",
                 key,
-                $display_fn(v, $encounters, $indent.wrapping_add(4), $print_internals),
+                v,
                 width = $indent,
             )
         })
@@ -74,6 +76,17 @@ macro_rules! print_obj_value {
     };
 }
 
+/// Returns how an accessor property is displayed, as its value can't be computed without
+/// calling its getter.
+fn display_accessor(accessor: &AccessorDescriptor) -> &'static str {
+    match (accessor.getter(), accessor.setter()) {
+        (Some(_), Some(_)) => "[Getter/Setter]",
+        (Some(_), None) => "[Getter]",
+        (None, Some(_)) => "[Setter]",
+        (None, None) => "undefined",
+    }
+}
+
 pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children: bool) -> String {
     match x {
         // We don't want to print private (compiler) or prototype properties
@@ -93,7 +106,7 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
                 }
                 ObjectData::Array => {
                     let len = v
-                        .get_own_property(&PropertyKey::from("length"))
+                        .ordinary_get_own_property(&PropertyKey::from("length"))
                         // TODO: do this in a better way `unwrap`
                         .unwrap()
                         // FIXME: handle accessor descriptors
@@ -109,20 +122,16 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
                         }
 
                         let arr = (0..len)
-                            .map(|i| {
+                            .map(|i| match &v.ordinary_get_own_property(&i.into()) {
                                 // Introduce recursive call to stringify any objects
                                 // which are part of the Array
-                                log_string_from(
-                                    &v.get_own_property(&i.into())
-                                        // TODO: do this in a better way "unwrap"
-                                        .unwrap()
-                                        // FIXME: handle accessor descriptors
-                                        .as_data_descriptor()
-                                        .unwrap()
-                                        .value(),
-                                    print_internals,
-                                    false,
-                                )
+                                Some(PropertyDescriptor::Data(data)) => {
+                                    log_string_from(&data.value(), print_internals, false)
+                                }
+                                Some(PropertyDescriptor::Accessor(accessor)) => {
+                                    display_accessor(accessor).to_string()
+                                }
+                                None => String::from("<empty>"),
                             })
                             .collect::<Vec<String>>()
                             .join(", ");
@@ -134,7 +143,7 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
                 }
                 ObjectData::Map(ref map) => {
                     let size = v
-                        .get_own_property(&PropertyKey::from("size"))
+                        .ordinary_get_own_property(&PropertyKey::from("size"))
                         // TODO: do this in a better way "unwrap"
                         .unwrap()
                         // FIXME: handle accessor descriptors
@@ -162,7 +171,39 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
                         format!("Map({})", size)
                     }
                 }
-                _ => display_obj(&x, print_internals),
+                ObjectData::Set(ref set) => {
+                    if set.is_empty() {
+                        return String::from("Set(0)");
+                    }
+
+                    if print_children {
+                        let entries = set
+                            .iter()
+                            .map(|value| log_string_from(value, print_internals, false))
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        format!("Set {{ {} }}", entries)
+                    } else {
+                        format!("Set({})", set.len())
+                    }
+                }
+                ObjectData::ArrayBuffer(ref buffer) => {
+                    format!("ArrayBuffer({})", buffer.byte_length())
+                }
+                ObjectData::TypedArray(ref array) => {
+                    let name = array.kind().name();
+                    let len = array.length().unwrap_or(0);
+                    if print_children && len > 0 {
+                        let elements = (0..len)
+                            .map(|i| log_string_from(&array.get_element(i), print_internals, false))
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        format!("{}({}) [ {} ]", name, len, elements)
+                    } else {
+                        format!("{}({})", name, len)
+                    }
+                }
+                _ => display_obj(x, print_internals),
             }
         }
         Value::Symbol(ref symbol) => symbol.to_string(),
@@ -185,8 +226,8 @@ pub(crate) fn display_obj(v: &Value, print_internals: bool) -> String {
 
     if let Value::Object(object) = v {
         if object.borrow().is_error() {
-            let name = v.get_field("name");
-            let message = v.get_field("message");
+            let name = v.get_data_field("name");
+            let message = v.get_data_field("message");
             return format!("{}: {}", name.display(), message.display());
         }
     }
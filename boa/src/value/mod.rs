@@ -19,7 +19,6 @@ use serde_json::{Number as JSONNumber, Value as JSONValue};
 use std::{
     collections::HashSet,
     convert::TryFrom,
-    f64::NAN,
     fmt::{self, Display},
     str::FromStr,
 };
@@ -37,7 +36,6 @@ mod r#type;
 pub use conversions::*;
 pub use display::ValueDisplay;
 pub use equality::*;
-pub use hash::*;
 pub use operations::*;
 pub use r#type::Type;
 pub use rcbigint::RcBigInt;
@@ -45,11 +43,12 @@ pub use rcstring::RcString;
 pub use rcsymbol::RcSymbol;
 
 /// A Javascript value
-#[derive(Trace, Finalize, Debug, Clone)]
+#[derive(Trace, Finalize, Debug, Clone, Default)]
 pub enum Value {
     /// `null` - A null value, for when a value doesn't exist.
     Null,
     /// `undefined` - An undefined value, for when a field or index doesn't exist.
+    #[default]
     Undefined,
     /// `boolean` - A `true` / `false` value, for if a certain criteria is met.
     Boolean(bool),
@@ -83,7 +82,7 @@ impl Value {
     /// Creates a new number with `NaN` value.
     #[inline]
     pub fn nan() -> Self {
-        Self::number(NAN)
+        Self::number(f64::NAN)
     }
 
     /// Creates a new string value.
@@ -154,7 +153,7 @@ impl Value {
         let _timer = BoaProfiler::global().start_event("new_object", "value");
 
         if let Some(global) = global {
-            let object_prototype = global.get_field("Object").get_field(PROTOTYPE);
+            let object_prototype = global.get_data_field("Object").get_data_field(PROTOTYPE);
 
             let object = Object::create(object_prototype);
             Self::object(object)
@@ -216,7 +215,7 @@ impl Value {
 
     /// Converts the `Value` to `JSON`.
     pub fn to_json(&self, interpreter: &mut Context) -> Result<JSONValue> {
-        let to_json = self.get_field("toJSON");
+        let to_json = self.get_field("toJSON", interpreter)?;
         if to_json.is_function() {
             let json_value = interpreter.call(&to_json, self, &[])?;
             return json_value.to_json(interpreter);
@@ -434,7 +433,7 @@ impl Value {
         let _timer = BoaProfiler::global().start_event("Value::get_property", "value");
         match self {
             Self::Object(ref object) => {
-                let property = object.get_own_property(&key);
+                let property = object.ordinary_get_own_property(&key);
                 if property.is_some() {
                     return property;
                 }
@@ -445,24 +444,134 @@ impl Value {
         }
     }
 
-    /// Resolve the property in the object and get its value, or undefined if this is not an object or the field doesn't exist
-    /// get_field recieves a Property from get_prop(). It should then return the [[Get]] result value if that's set, otherwise fall back to [[Value]]
-    /// TODO: this function should use the get Value if its set
-    pub fn get_field<K>(&self, key: K) -> Self
+    /// Resolve the property in the object and get its value, calling its getter if it is an
+    /// accessor property.
+    ///
+    /// Returns undefined if this is not an object or the field doesn't exist.
+    pub fn get_field<K>(&self, key: K, context: &mut Context) -> Result<Self>
     where
         K: Into<PropertyKey>,
     {
         let _timer = BoaProfiler::global().start_event("Value::get_field", "value");
+        self.get_field_with_receiver(key, self, context)
+    }
+
+    /// Resolve the property in the object and get its value, calling the getter with `receiver`
+    /// as the `this` value if the property is an accessor property.
+    ///
+    /// Returns undefined if this is not an object or the field doesn't exist.
+    pub(crate) fn get_field_with_receiver<K>(
+        &self,
+        key: K,
+        receiver: &Value,
+        context: &mut Context,
+    ) -> Result<Self>
+    where
+        K: Into<PropertyKey>,
+    {
+        let _timer = BoaProfiler::global().start_event("Value::get_field_with_receiver", "value");
+        match self {
+            Self::Object(ref object) => object.get(&key.into(), receiver.clone(), context),
+            _ => Ok(Value::undefined()),
+        }
+    }
+
+    /// The abstract operation `GetMethod`, returning the function stored under `key` or `None`
+    /// if the property is `undefined` or `null`.
+    ///
+    /// Primitives are converted to objects for the lookup, so their prototype methods are found.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmethod
+    pub(crate) fn get_method<K>(&self, key: K, context: &mut Context) -> Result<Option<Value>>
+    where
+        K: Into<PropertyKey>,
+    {
         let key = key.into();
+        let function = Value::from(self.to_object(context)?).get_field_with_receiver(
+            key.clone(),
+            self,
+            context,
+        )?;
+        if function.is_null_or_undefined() {
+            return Ok(None);
+        }
+        if !function.is_function() {
+            return Err(context.construct_type_error(format!("{} is not a function", key)));
+        }
+        Ok(Some(function))
+    }
+
+    /// Resolve the property in the object and get its value if it is a data property, without
+    /// calling the getter of an accessor property.
+    ///
+    /// This is only meant for the lookups made where no JavaScript code can run. Returns
+    /// undefined if this is not an object, the field doesn't exist or it is an accessor property.
+    pub(crate) fn get_data_field<K>(&self, key: K) -> Self
+    where
+        K: Into<PropertyKey>,
+    {
         match self.get_property(key) {
-            Some(ref desc) => match desc {
-                PropertyDescriptor::Accessor(_) => todo!(),
-                PropertyDescriptor::Data(desc) => desc.value(),
-            },
-            None => Value::undefined(),
+            Some(PropertyDescriptor::Data(ref desc)) => desc.value(),
+            _ => Value::undefined(),
+        }
+    }
+
+    /// Set the field in the value, calling the setter with `receiver` as the `this` value if the
+    /// property is an accessor property.
+    ///
+    /// Otherwise the field is set on the `receiver`.
+    pub(crate) fn set_field_with_receiver<K, V>(
+        &self,
+        key: K,
+        value: V,
+        receiver: &Value,
+        context: &mut Context,
+    ) -> Result<Self>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        let value = value.into();
+        let _timer = BoaProfiler::global().start_event("Value::set_field_with_receiver", "value");
+        if let Self::Object(ref obj) = *self {
+            set_with_receiver(obj, key.into(), value.clone(), receiver, context)?;
         }
+        Ok(value)
     }
 
+    /// The abstract operation `PutValue` for a property reference, which sets the field like
+    /// [`Value::set_field_with_receiver`] after converting a primitive value to an object.
+    ///
+    /// In strict mode code, a `TypeError` is thrown if the property could not be set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-putvalue
+    pub(crate) fn put_field_with_receiver<K, V>(
+        &self,
+        key: K,
+        value: V,
+        receiver: &Value,
+        context: &mut Context,
+    ) -> Result<Self>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        let key = key.into();
+        let value = value.into();
+        let object = self.to_object(context)?;
+        let succeeded = set_with_receiver(&object, key.clone(), value.clone(), receiver, context)?;
+        if !succeeded && context.executor().strict() {
+            return context
+                .throw_type_error(format!("Cannot assign to read only property '{}'", key));
+        }
+        Ok(value)
+    }
     /// Check to see if the Value has the field, mainly used by environment records.
     #[inline]
     pub fn has_field<K>(&self, key: K) -> bool
@@ -470,33 +579,18 @@ impl Value {
         K: Into<PropertyKey>,
     {
         let _timer = BoaProfiler::global().start_event("Value::has_field", "value");
-        self.as_object()
-            .map(|object| object.has_property(&key.into()))
-            .unwrap_or(false)
+        self.get_property(key).is_some()
     }
 
-    /// Set the field in the value
+    /// Set the field in the value, calling its setter if it is an accessor property.
     #[inline]
-    pub fn set_field<K, V>(&self, key: K, value: V) -> Value
+    pub fn set_field<K, V>(&self, key: K, value: V, context: &mut Context) -> Result<Value>
     where
         K: Into<PropertyKey>,
         V: Into<Value>,
     {
-        let key = key.into();
-        let value = value.into();
         let _timer = BoaProfiler::global().start_event("Value::set_field", "value");
-        if let Self::Object(ref obj) = *self {
-            if let PropertyKey::Index(index) = key {
-                if obj.is_array() {
-                    let len = self.get_field("length").as_number().unwrap() as u32;
-                    if len < index + 1 {
-                        self.set_field("length", index + 1);
-                    }
-                }
-            }
-            obj.clone().set(key, value.clone());
-        }
-        value
+        self.set_field_with_receiver(key, value, self, context)
     }
 
     /// Set the kind of an object.
@@ -526,16 +620,33 @@ impl Value {
         // 1. Assert: input is an ECMAScript language value. (always a value not need to check)
         // 2. If Type(input) is Object, then
         if let Value::Object(obj) = self {
-            let mut hint = preferred_type;
+            // a. Let exoticToPrim be ? GetMethod(input, @@toPrimitive).
+            let to_primitive = ctx.well_known_symbols().to_primitive_symbol();
+            // b. If exoticToPrim is not undefined, then
+            if let Some(exotic_to_primitive) = self.get_method(to_primitive, ctx)? {
+                let hint = match preferred_type {
+                    PreferredType::Default => "default",
+                    PreferredType::String => "string",
+                    PreferredType::Number => "number",
+                };
+                // v. Let result be ? Call(exoticToPrim, input, « hint »).
+                let result = ctx.call(&exotic_to_primitive, self, &[hint.into()])?;
+                // vi. If Type(result) is not Object, return result.
+                // vii. Throw a TypeError exception.
+                return if result.is_object() {
+                    ctx.throw_type_error("Symbol.toPrimitive method returned an object")
+                } else {
+                    Ok(result)
+                };
+            }
 
-            // Skip d, e we don't support Symbols yet
-            // TODO: add when symbols are supported
-            // TODO: Add other steps.
-            if hint == PreferredType::Default {
-                hint = PreferredType::Number;
+            // c. If preferredType is not present, let preferredType be number.
+            let hint = match preferred_type {
+                PreferredType::Default => PreferredType::Number,
+                hint => hint,
             };
 
-            // g. Return ? OrdinaryToPrimitive(input, hint).
+            // d. Return ? OrdinaryToPrimitive(input, preferredType).
             obj.ordinary_to_primitive(ctx, hint)
         } else {
             // 3. Return input.
@@ -649,7 +760,11 @@ impl Value {
                     ObjectData::String(string.clone()),
                 ));
                 // Make sure the correct length is set on our new string object
-                object.set("length".into(), string.chars().count().into());
+                object.insert_property(
+                    "length",
+                    string.code_units().len(),
+                    Attribute::NON_ENUMERABLE,
+                );
                 Ok(object)
             }
             Value::Symbol(ref symbol) => {
@@ -687,6 +802,23 @@ impl Value {
         })
     }
 
+    /// Abstract operation `CreateListFromArrayLike ( obj )`
+    ///
+    /// Returns the elements of an array-like object, from index 0 to its `length`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createlistfromarraylike
+    pub(crate) fn create_list_from_array_like(&self, ctx: &mut Context) -> Result<Vec<Value>> {
+        if !self.is_object() {
+            return Err(ctx.construct_type_error("CreateListFromArrayLike called on non-object"));
+        }
+
+        let len = self.get_field("length", ctx)?.to_length(ctx)?;
+        (0..len).map(|index| self.get_field(index, ctx)).collect()
+    }
+
     /// It returns value converted to a numeric value of type `Number` or `BigInt`.
     ///
     /// See: <https://tc39.es/ecma262/#sec-tonumeric>
@@ -763,6 +895,20 @@ impl Value {
         Ok(len.min(Number::MAX_SAFE_INTEGER) as usize)
     }
 
+    /// Converts a relative index, like the `start` and `end` arguments of `slice`, to an index
+    /// in a list of length `len`.
+    ///
+    /// Negative indices are counted from the end of the list, and the result is clamped
+    /// between `0` and `len`.
+    pub(crate) fn to_relative_index(&self, len: usize, ctx: &mut Context) -> Result<usize> {
+        let relative = self.to_integer(ctx)?;
+        if relative < 0.0 {
+            Ok((len as f64 + relative).max(0.0) as usize)
+        } else {
+            Ok(relative.min(len as f64) as usize)
+        }
+    }
+
     /// Converts a value to an integral Number value.
     ///
     /// See: <https://tc39.es/ecma262/#sec-tointeger>
@@ -820,7 +966,7 @@ impl Value {
     /// See: <https://tc39.es/ecma262/#sec-tonumeric>
     pub fn to_numeric_number(&self, ctx: &mut Context) -> Result<f64> {
         let primitive = self.to_primitive(ctx, PreferredType::Number)?;
-        if let Some(ref bigint) = primitive.as_bigint() {
+        if let Some(bigint) = primitive.as_bigint() {
             return Ok(bigint.to_f64());
         }
         primitive.to_number(ctx)
@@ -856,10 +1002,24 @@ impl Value {
     }
 }
 
-impl Default for Value {
-    fn default() -> Self {
-        Self::Undefined
+/// Sets the property `key` of `object` with `receiver` as the `this` value of a setter, returning
+/// whether it could be set.
+fn set_with_receiver(
+    object: &GcObject,
+    key: PropertyKey,
+    value: Value,
+    receiver: &Value,
+    context: &mut Context,
+) -> Result<bool> {
+    if let (PropertyKey::Index(index), Value::Object(ref receiver_obj)) = (&key, receiver) {
+        if receiver_obj.is_array() {
+            let len = receiver.get_field("length", context)?.to_length(context)?;
+            if len < *index as usize + 1 {
+                receiver.set_field("length", *index + 1, context)?;
+            }
+        }
     }
+    object.clone().set(key, value, receiver.clone(), context)
 }
 
 /// The preffered type to convert an object to a primitive `Value`.
@@ -11,9 +11,11 @@ impl Value {
             (Self::Integer(x), Self::Rational(y)) => Self::rational(f64::from(*x) + y),
             (Self::Rational(x), Self::Integer(y)) => Self::rational(x + f64::from(*y)),
 
-            (Self::String(ref x), Self::String(ref y)) => Self::string(format!("{}{}", x, y)),
-            (Self::String(ref x), ref y) => Self::string(format!("{}{}", x, y.to_string(ctx)?)),
-            (ref x, Self::String(ref y)) => Self::string(format!("{}{}", x.to_string(ctx)?, y)),
+            (Self::String(ref x), Self::String(ref y)) => Self::string(x.concat(y)),
+            (Self::String(ref x), y) if !y.is_object() => {
+                Self::string(x.concat(&y.to_string(ctx)?))
+            }
+            (x, Self::String(ref y)) if !x.is_object() => Self::string(x.to_string(ctx)?.concat(y)),
             (Self::BigInt(ref n1), Self::BigInt(ref n2)) => {
                 Self::bigint(n1.as_inner().clone() + n2.as_inner().clone())
             }
@@ -23,8 +25,8 @@ impl Value {
                 self.to_primitive(ctx, PreferredType::Default)?,
                 other.to_primitive(ctx, PreferredType::Default)?,
             ) {
-                (Self::String(ref x), ref y) => Self::string(format!("{}{}", x, y.to_string(ctx)?)),
-                (ref x, Self::String(ref y)) => Self::string(format!("{}{}", x.to_string(ctx)?, y)),
+                (Self::String(ref x), ref y) => Self::string(x.concat(&y.to_string(ctx)?)),
+                (ref x, Self::String(ref y)) => Self::string(x.to_string(ctx)?.concat(y)),
                 (x, y) => match (x.to_numeric(ctx)?, y.to_numeric(ctx)?) {
                     (Numeric::Number(x), Numeric::Number(y)) => Self::rational(x + y),
                     (Numeric::BigInt(ref n1), Numeric::BigInt(ref n2)) => {
@@ -106,7 +108,7 @@ impl Value {
             (Self::Rational(x), Self::Integer(y)) => Self::rational(x / f64::from(*y)),
 
             (Self::BigInt(ref a), Self::BigInt(ref b)) => {
-                if *b.as_inner() == BigInt::from(0) {
+                if b.as_inner().is_zero() {
                     return ctx.throw_range_error("BigInt division by zero");
                 }
                 Self::bigint(a.as_inner().clone() / b.as_inner().clone())
@@ -116,7 +118,7 @@ impl Value {
             (_, _) => match (self.to_numeric(ctx)?, other.to_numeric(ctx)?) {
                 (Numeric::Number(a), Numeric::Number(b)) => Self::rational(a / b),
                 (Numeric::BigInt(ref a), Numeric::BigInt(ref b)) => {
-                    if *b.as_inner() == BigInt::from(0) {
+                    if b.as_inner().is_zero() {
                         return ctx.throw_range_error("BigInt division by zero");
                     }
                     Self::bigint(a.as_inner().clone() / b.as_inner().clone())
@@ -146,7 +148,7 @@ impl Value {
             (Self::Rational(x), Self::Integer(y)) => Self::rational(x % f64::from(*y)),
 
             (Self::BigInt(ref a), Self::BigInt(ref b)) => {
-                if *b.as_inner() == BigInt::from(0) {
+                if b.as_inner().is_zero() {
                     return ctx.throw_range_error("BigInt division by zero");
                 }
                 Self::bigint(a.as_inner().clone() % b.as_inner().clone())
@@ -419,14 +421,14 @@ impl Value {
     #[inline]
     pub fn neg(&self, interpreter: &mut Context) -> Result<Value> {
         Ok(match *self {
-            Self::Symbol(_) | Self::Undefined => Self::rational(NAN),
+            Self::Symbol(_) | Self::Undefined => Self::rational(f64::NAN),
             Self::Object(_) => Self::rational(match self.to_numeric_number(interpreter) {
                 Ok(num) => -num,
-                Err(_) => NAN,
+                Err(_) => f64::NAN,
             }),
             Self::String(ref str) => Self::rational(match f64::from_str(str) {
                 Ok(num) => -num,
-                Err(_) => NAN,
+                Err(_) => f64::NAN,
             }),
             Self::Rational(num) => Self::rational(-num),
             Self::Integer(num) => Self::rational(-f64::from(num)),
@@ -486,29 +488,17 @@ impl Value {
                 };
 
                 match (px, py) {
-                    (Self::String(ref x), Self::String(ref y)) => {
-                        if x.starts_with(y.as_str()) {
-                            return Ok(AbstractRelation::False);
-                        }
-                        if y.starts_with(x.as_str()) {
-                            return Ok(AbstractRelation::True);
-                        }
-                        for (x, y) in x.chars().zip(y.chars()) {
-                            if x != y {
-                                return Ok((x < y).into());
-                            }
-                        }
-                        unreachable!()
-                    }
+                    // Strings are compared by their UTF-16 code units.
+                    (Self::String(ref x), Self::String(ref y)) => (x < y).into(),
                     (Self::BigInt(ref x), Self::String(ref y)) => {
-                        if let Some(y) = string_to_bigint(&y) {
+                        if let Some(y) = string_to_bigint(y) {
                             (*x.as_inner() < y).into()
                         } else {
                             AbstractRelation::Undefined
                         }
                     }
                     (Self::String(ref x), Self::BigInt(ref y)) => {
-                        if let Some(x) = string_to_bigint(&x) {
+                        if let Some(x) = string_to_bigint(x) {
                             (x < *y.as_inner()).into()
                         } else {
                             AbstractRelation::Undefined
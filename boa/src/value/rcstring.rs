@@ -1,62 +1,149 @@
 use std::borrow::Borrow;
-use std::fmt::{self, Display};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display};
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::rc::Rc;
 
 use gc::{unsafe_empty_trace, Finalize, Trace};
 
-#[derive(Debug, Finalize, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct RcString(Rc<str>);
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A reference counted JavaScript string.
+///
+/// JavaScript strings are sequences of UTF-16 code units, which is what `length`, indexing and
+/// comparisons work on, and they can hold lone surrogates. The code units are the canonical
+/// content of the string.
+///
+/// A UTF-8 copy is kept next to them for the Rust API: the string derefs to it and displays it.
+/// In that copy lone surrogates are replaced by `U+FFFD`, which is also a single code unit, so
+/// the code unit indices of both versions match.
+#[derive(Finalize, Clone)]
+pub struct RcString(Rc<Inner>);
+
+struct Inner {
+    code_units: Box<[u16]>,
+    utf8: Box<str>,
+}
 
 unsafe impl Trace for RcString {
     unsafe_empty_trace!();
 }
 
 impl RcString {
+    /// Creates a string from UTF-16 code units, which may contain lone surrogates.
+    #[inline]
+    pub fn from_utf16(code_units: &[u16]) -> Self {
+        Self(Rc::new(Inner {
+            code_units: code_units.into(),
+            utf8: String::from_utf16_lossy(code_units).into_boxed_str(),
+        }))
+    }
+
+    /// The UTF-8 version of the string, with lone surrogates replaced by `U+FFFD`.
     #[inline]
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.0.utf8
+    }
+
+    /// The UTF-16 code units of the string.
+    #[inline]
+    pub fn code_units(&self) -> &[u16] {
+        &self.0.code_units
+    }
+
+    /// Returns the concatenation of this string and `other`.
+    pub fn concat(&self, other: &Self) -> Self {
+        if other.code_units().is_empty() {
+            return self.clone();
+        }
+        if self.code_units().is_empty() {
+            return other.clone();
+        }
+
+        let mut code_units = Vec::with_capacity(self.code_units().len() + other.code_units().len());
+        code_units.extend_from_slice(self.code_units());
+        code_units.extend_from_slice(other.code_units());
+        Self::from(code_units)
     }
 }
 
 impl Default for RcString {
     #[inline]
     fn default() -> Self {
-        Self(Rc::from(String::new()))
+        Self::from("")
+    }
+}
+
+impl Debug for RcString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
     }
 }
 
 impl Display for RcString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Display::fmt(&self.0, f)
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for RcString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0) || self.code_units() == other.code_units()
+    }
+}
+
+impl Eq for RcString {}
+
+impl PartialOrd for RcString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RcString {
+    /// Strings are ordered by their code units, like the JavaScript relational operators do.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.code_units().cmp(other.code_units())
+    }
+}
+
+impl Hash for RcString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code_units().hash(state)
     }
 }
 
 impl PartialEq<str> for RcString {
     #[inline]
     fn eq(&self, other: &str) -> bool {
-        self.as_str() == other
+        self.code_units().iter().copied().eq(other.encode_utf16())
     }
 }
 
 impl PartialEq<RcString> for str {
     #[inline]
     fn eq(&self, other: &RcString) -> bool {
-        self == other.as_str()
+        other == self
     }
 }
 
 impl PartialEq<&str> for RcString {
     #[inline]
     fn eq(&self, other: &&str) -> bool {
-        self.as_str() == *other
+        self == *other
     }
 }
 
 impl PartialEq<RcString> for &str {
     #[inline]
     fn eq(&self, other: &RcString) -> bool {
-        *self == other.as_str()
+        other == *self
     }
 }
 
@@ -65,28 +152,31 @@ impl Deref for RcString {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        &self.0
+        self.as_str()
     }
 }
 
-impl Borrow<str> for RcString {
+impl AsRef<str> for RcString {
     #[inline]
-    fn borrow(&self) -> &str {
-        self.0.borrow()
+    fn as_ref(&self) -> &str {
+        self.as_str()
     }
 }
 
-impl AsRef<str> for RcString {
+impl Borrow<[u16]> for RcString {
     #[inline]
-    fn as_ref(&self) -> &str {
-        self.as_str()
+    fn borrow(&self) -> &[u16] {
+        self.code_units()
     }
 }
 
 impl From<String> for RcString {
     #[inline]
     fn from(string: String) -> Self {
-        Self(Rc::from(string))
+        Self(Rc::new(Inner {
+            code_units: string.encode_utf16().collect(),
+            utf8: string.into_boxed_str(),
+        }))
     }
 }
 
@@ -100,13 +190,51 @@ impl From<&RcString> for String {
 impl From<Box<str>> for RcString {
     #[inline]
     fn from(string: Box<str>) -> Self {
-        Self(Rc::from(string))
+        Self::from(String::from(string))
     }
 }
 
 impl From<&str> for RcString {
     #[inline]
     fn from(string: &str) -> Self {
-        Self(Rc::from(string))
+        Self::from(String::from(string))
+    }
+}
+
+impl From<&[u16]> for RcString {
+    #[inline]
+    fn from(code_units: &[u16]) -> Self {
+        Self::from_utf16(code_units)
+    }
+}
+
+impl From<Vec<u16>> for RcString {
+    #[inline]
+    fn from(code_units: Vec<u16>) -> Self {
+        let utf8 = String::from_utf16_lossy(&code_units).into_boxed_str();
+        Self(Rc::new(Inner {
+            code_units: code_units.into_boxed_slice(),
+            utf8,
+        }))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for RcString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.code_units().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RcString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<u16>::deserialize(deserializer).map(Self::from)
     }
 }
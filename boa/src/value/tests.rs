@@ -9,15 +9,15 @@ use std::hash::{Hash, Hasher};
 #[test]
 fn is_object() {
     let val = Value::new_object(None);
-    assert_eq!(val.is_object(), true);
+    assert!(val.is_object());
 }
 
 #[test]
 fn string_to_value() {
     let s = String::from("Hello");
     let v = Value::from(s);
-    assert_eq!(v.is_string(), true);
-    assert_eq!(v.is_null(), false);
+    assert!(v.is_string());
+    assert!(!v.is_null());
 }
 
 #[test]
@@ -29,28 +29,35 @@ fn undefined() {
 
 #[test]
 fn get_set_field() {
+    let mut context = Context::new();
     let obj = Value::new_object(None);
     // Create string and convert it to a Value
     let s = Value::from("bar");
-    obj.set_field("foo", s);
-    assert_eq!(obj.get_field("foo").display().to_string(), "\"bar\"");
+    obj.set_field("foo", s, &mut context).unwrap();
+    assert_eq!(
+        obj.get_field("foo", &mut context)
+            .unwrap()
+            .display()
+            .to_string(),
+        "\"bar\""
+    );
 }
 
 #[test]
 fn integer_is_true() {
-    assert_eq!(Value::from(1).to_boolean(), true);
-    assert_eq!(Value::from(0).to_boolean(), false);
-    assert_eq!(Value::from(-1).to_boolean(), true);
+    assert!(Value::from(1).to_boolean());
+    assert!(!Value::from(0).to_boolean());
+    assert!(Value::from(-1).to_boolean());
 }
 
 #[test]
 fn number_is_true() {
-    assert_eq!(Value::from(1.0).to_boolean(), true);
-    assert_eq!(Value::from(0.1).to_boolean(), true);
-    assert_eq!(Value::from(0.0).to_boolean(), false);
-    assert_eq!(Value::from(-0.0).to_boolean(), false);
-    assert_eq!(Value::from(-1.0).to_boolean(), true);
-    assert_eq!(Value::from(NAN).to_boolean(), false);
+    assert!(Value::from(1.0).to_boolean());
+    assert!(Value::from(0.1).to_boolean());
+    assert!(!Value::from(0.0).to_boolean());
+    assert!(!Value::from(-0.0).to_boolean());
+    assert!(Value::from(-1.0).to_boolean());
+    assert!(!Value::from(f64::NAN).to_boolean());
 }
 
 // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Equality_comparisons_and_sameness
@@ -1236,3 +1243,47 @@ mod abstract_relational_comparison {
         check_comparison!(engine, "'InvalidBigInt' >= -100n" => false);
     }
 }
+
+#[test]
+fn to_primitive_uses_symbol_to_primitive() {
+    let mut engine = Context::new();
+    let init = r#"
+        var hints = [];
+        var object = {};
+        object[Symbol.toPrimitive] = function(hint) {
+            hints.push(hint);
+            return hint === "number" ? 42 : "string";
+        };
+        "#;
+    forward(&mut engine, init);
+
+    assert_eq!(forward(&mut engine, "+object"), "42");
+    assert_eq!(forward(&mut engine, "`${object}`"), "\"string\"");
+    assert_eq!(forward(&mut engine, "object + ''"), "\"string\"");
+    assert_eq!(forward(&mut engine, "object == 'string'"), "true");
+    assert_eq!(
+        forward(&mut engine, "hints.join()"),
+        "\"number,string,default,default\""
+    );
+
+    forward(
+        &mut engine,
+        "object[Symbol.toPrimitive] = function() { return {}; };",
+    );
+    assert_eq!(
+        forward(&mut engine, "try { +object } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+
+    forward(&mut engine, "object[Symbol.toPrimitive] = 1;");
+    assert_eq!(
+        forward(&mut engine, "try { +object } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+
+    forward(
+        &mut engine,
+        "object[Symbol.toPrimitive] = undefined; object.valueOf = function() { return 7; };",
+    );
+    assert_eq!(forward(&mut engine, "+object"), "7");
+}
@@ -1,12 +1,11 @@
 #![deny(
     unused_qualifications,
     clippy::all,
-    unused_qualifications,
     unused_import_braces,
     unused_lifetimes,
     unreachable_pub,
     trivial_numeric_casts,
-    rustdoc,
+    rustdoc::all,
     missing_debug_implementations,
     missing_copy_implementations,
     deprecated_in_future,
@@ -25,10 +24,14 @@
     clippy::as_conversions
 )]
 
-use boa::{syntax::ast::node::StatementList, Context};
+use boa::{module::FsModuleLoader, syntax::ast::node::StatementList, Context};
 use colored::*;
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
-use std::{fs::read_to_string, path::PathBuf};
+use std::{
+    env,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
 use structopt::{clap::arg_enum, StructOpt};
 
 mod helper;
@@ -66,6 +69,11 @@ struct Opt {
     )]
     dump_ast: Option<Option<DumpFormat>>,
 
+    /// Evaluate the file(s) as modules. Files with the `.mjs` extension are always evaluated
+    /// as modules.
+    #[structopt(long, short = "m")]
+    module: bool,
+
     /// Use vi mode in the REPL
     #[structopt(long = "vi")]
     vi_mode: bool,
@@ -76,6 +84,11 @@ impl Opt {
     fn has_dump_flag(&self) -> bool {
         self.dump_ast.is_some()
     }
+
+    /// Returns whether the given file is evaluated as a module.
+    fn is_module(&self, file: &Path) -> bool {
+        self.module || file.extension().is_some_and(|ext| ext == "mjs")
+    }
 }
 
 arg_enum! {
@@ -104,21 +117,25 @@ arg_enum! {
 ///
 /// Returns a error of type String with a message,
 /// if the token stream has a parsing error.
-fn parse_tokens(src: &str) -> Result<StatementList, String> {
+fn parse_tokens(src: &str, module: bool) -> Result<StatementList, String> {
     use boa::syntax::parser::Parser;
 
-    Parser::new(src.as_bytes())
-        .parse_all()
-        .map_err(|e| format!("ParsingError: {}", e))
+    let mut parser = Parser::new(src.as_bytes());
+    if module {
+        parser.parse_module()
+    } else {
+        parser.parse_all()
+    }
+    .map_err(|e| format!("ParsingError: {}", e))
 }
 
 /// Dumps the AST to stdout with format controlled by the given arguments.
 ///
 /// Returns a error of type String with a error message,
 /// if the source has a syntax or parsing error.
-fn dump(src: &str, args: &Opt) -> Result<(), String> {
+fn dump(src: &str, module: bool, args: &Opt) -> Result<(), String> {
     if let Some(ref arg) = args.dump_ast {
-        let ast = parse_tokens(src)?;
+        let ast = parse_tokens(src, module)?;
 
         match arg {
             Some(format) => match format {
@@ -140,14 +157,20 @@ pub fn main() -> Result<(), std::io::Error> {
     let args = Opt::from_args();
 
     let mut engine = Context::new();
+    engine.set_run_jobs_on_eval(true);
+    engine.set_module_loader(FsModuleLoader::new(env::current_dir()?));
 
     for file in &args.files {
         let buffer = read_to_string(file)?;
 
         if args.has_dump_flag() {
-            if let Err(e) = dump(&buffer, &args) {
+            if let Err(e) = dump(&buffer, args.is_module(file), &args) {
                 eprintln!("{}", e);
             }
+        } else if args.is_module(file) {
+            if let Err(v) = engine.import_module(&file.to_string_lossy()) {
+                eprintln!("Uncaught {}", v.display());
+            }
         } else {
             match engine.eval(&buffer) {
                 Ok(v) => println!("{}", v.display()),
@@ -181,7 +204,7 @@ pub fn main() -> Result<(), std::io::Error> {
                     editor.add_history_entry(&line);
 
                     if args.has_dump_flag() {
-                        if let Err(e) = dump(&line, &args) {
+                        if let Err(e) = dump(&line, false, &args) {
                             eprintln!("{}", e);
                         }
                     } else {
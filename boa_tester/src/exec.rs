@@ -96,20 +96,20 @@ impl Test {
                         let mut passed = true;
 
                         if self.flags.contains(TestFlags::RAW) {
-                            let mut engine = self.set_up_env(&harness, false);
+                            let mut engine = self.set_up_env(harness, false);
                             let res = engine.eval(&self.content);
 
                             passed = res.is_ok()
                         } else {
                             if self.flags.contains(TestFlags::STRICT) {
-                                let mut engine = self.set_up_env(&harness, true);
+                                let mut engine = self.set_up_env(harness, true);
                                 let res = engine.eval(&self.content);
 
                                 passed = res.is_ok()
                             }
 
                             if passed && self.flags.contains(TestFlags::NO_STRICT) {
-                                let mut engine = self.set_up_env(&harness, false);
+                                let mut engine = self.set_up_env(harness, false);
                                 let res = engine.eval(&self.content);
 
                                 passed = res.is_ok()
@@ -198,7 +198,7 @@ impl Test {
 
         self.includes.iter().for_each(|include| {
             let res = engine.eval(
-                &harness
+                harness
                     .includes
                     .get(include)
                     .expect("could not find include file"),
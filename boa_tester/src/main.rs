@@ -9,7 +9,6 @@
 #![deny(
     unused_qualifications,
     clippy::all,
-    unused_qualifications,
     unused_import_braces,
     unused_lifetimes,
     unreachable_pub,
@@ -208,6 +207,7 @@ enum TestOutcomeResult {
 
 /// Represents a test.
 #[derive(Debug, Clone)]
+#[allow(dead_code)]
 struct Test {
     name: Box<str>,
     description: Box<str>,
@@ -245,16 +245,14 @@ impl Test {
 }
 
 /// An outcome for a test.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 enum Outcome {
+    #[default]
     Positive,
-    Negative { phase: Phase, error_type: Box<str> },
-}
-
-impl Default for Outcome {
-    fn default() -> Self {
-        Self::Positive
-    }
+    Negative {
+        phase: Phase,
+        error_type: Box<str>,
+    },
 }
 
 impl From<Option<Negative>> for Outcome {
@@ -339,6 +337,7 @@ enum Phase {
 /// Locale information structure.
 #[derive(Debug, Default, Clone, Deserialize)]
 #[serde(transparent)]
+#[allow(dead_code)]
 struct Locale {
     locale: Box<[Box<str>]>,
 }
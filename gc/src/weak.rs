@@ -0,0 +1,297 @@
+//! Weak pointers and ephemerons.
+//!
+//! A `WeakGc<T>` points to a garbage-collected value without keeping it alive, and an
+//! `Ephemeron<K, V>` keeps its value alive only as long as its key is alive.
+
+use crate::gc::GcBox;
+use crate::trace::{Finalize, Trace};
+use crate::Gc;
+use std::cell::{Cell, RefCell};
+use std::fmt::{self, Debug};
+use std::mem;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+////////////
+// WeakGc //
+////////////
+
+/// A weak pointer to a garbage-collected value.
+///
+/// It doesn't keep the value alive: once the value is only reachable through weak pointers, it
+/// is collected, and [`WeakGc::upgrade`] returns `None`.
+pub struct WeakGc<T: Trace + ?Sized + 'static> {
+    ptr: NonNull<GcBox<T>>,
+    alive: Rc<Cell<bool>>,
+}
+
+impl<T: Trace + ?Sized> Gc<T> {
+    /// Creates a new weak pointer to the value of this `Gc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gc::{force_collect, Gc};
+    ///
+    /// let five = Gc::new(5);
+    /// let weak = Gc::downgrade(&five);
+    /// assert_eq!(weak.upgrade().map(|five| *five), Some(5));
+    ///
+    /// drop(five);
+    /// force_collect();
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn downgrade(this: &Gc<T>) -> WeakGc<T> {
+        WeakGc {
+            ptr: this.inner_ptr(),
+            alive: this.inner().alive_flag(),
+        }
+    }
+}
+
+impl<T: Trace + ?Sized> WeakGc<T> {
+    /// Returns a `Gc` to the value, or `None` if it was collected.
+    pub fn upgrade(&self) -> Option<Gc<T>> {
+        if self.is_alive() {
+            // The box is still allocated, so it can be rooted again.
+            unsafe { Some(Gc::from_inner_ptr(self.ptr)) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the value wasn't collected yet.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.alive.get()
+    }
+
+    /// Returns `true` if the two weak pointers point to the same allocation.
+    #[inline]
+    pub fn ptr_eq(this: &WeakGc<T>, other: &WeakGc<T>) -> bool {
+        Rc::ptr_eq(&this.alive, &other.alive)
+    }
+
+    /// Returns `true` if the value is alive, and was marked by the collection in progress.
+    fn is_marked(&self) -> bool {
+        self.is_alive() && unsafe { self.ptr.as_ref().is_marked() }
+    }
+}
+
+impl<T: Trace + ?Sized> Clone for WeakGc<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        WeakGc {
+            ptr: self.ptr,
+            alive: self.alive.clone(),
+        }
+    }
+}
+
+impl<T: Trace + ?Sized> Finalize for WeakGc<T> {}
+
+// A weak pointer doesn't keep its value alive, so there is nothing to trace.
+unsafe impl<T: Trace + ?Sized> Trace for WeakGc<T> {
+    crate::unsafe_empty_trace!();
+}
+
+impl<T: Trace + ?Sized + Debug> Debug for WeakGc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.upgrade() {
+            Some(value) => f.debug_tuple("WeakGc").field(&&*value).finish(),
+            None => f.write_str("WeakGc(<collected>)"),
+        }
+    }
+}
+
+///////////////
+// Ephemeron //
+///////////////
+
+/// A key-value pair whose value is kept alive only as long as its key is alive.
+///
+/// The key is held weakly, and the value is only traced when the key is reachable through
+/// other pointers than the ephemeron itself. In particular, a value that references its own
+/// key doesn't keep the pair alive, which is what weak maps need.
+///
+/// # Examples
+///
+/// ```
+/// use gc::{force_collect, Ephemeron, Gc};
+///
+/// let key = Gc::new(1);
+/// let ephemeron = Ephemeron::new(&key, Gc::new(2));
+/// assert_eq!(ephemeron.value().map(|value| *value), Some(2));
+///
+/// drop(key);
+/// force_collect();
+/// assert!(ephemeron.key().is_none());
+/// assert!(ephemeron.value().is_none());
+/// ```
+pub struct Ephemeron<K: Trace + ?Sized + 'static, V: Trace + 'static> {
+    inner: Gc<EphemeronBox<K, V>>,
+}
+
+impl<K: Trace + ?Sized, V: Trace> Ephemeron<K, V> {
+    /// Creates a new ephemeron, associating `value` to `key`.
+    ///
+    /// # Collection
+    ///
+    /// This method could trigger a garbage collection.
+    pub fn new(key: &Gc<K>, value: V) -> Self {
+        Ephemeron {
+            inner: Gc::new(EphemeronBox {
+                key: Gc::downgrade(key),
+                value,
+            }),
+        }
+    }
+
+    /// Returns the key, or `None` if it was collected.
+    #[inline]
+    pub fn key(&self) -> Option<Gc<K>> {
+        self.inner.key.upgrade()
+    }
+
+    /// Returns `true` if the key wasn't collected yet.
+    #[inline]
+    pub fn is_alive(&self) -> bool {
+        self.inner.key.is_alive()
+    }
+}
+
+impl<K: Trace + ?Sized, V: Trace + Clone> Ephemeron<K, V> {
+    /// Returns the value, or `None` if the key was collected.
+    #[inline]
+    pub fn value(&self) -> Option<V> {
+        if self.is_alive() {
+            Some(self.inner.value.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl<K: Trace + ?Sized, V: Trace> Clone for Ephemeron<K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Ephemeron {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<K: Trace + ?Sized, V: Trace> Finalize for Ephemeron<K, V> {}
+
+unsafe impl<K: Trace + ?Sized, V: Trace> Trace for Ephemeron<K, V> {
+    crate::custom_trace!(this, {
+        mark(&this.inner);
+    });
+}
+
+impl<K: Trace + ?Sized + Debug, V: Trace + Debug> Debug for Ephemeron<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut tuple = f.debug_tuple("Ephemeron");
+        match self.key() {
+            Some(key) => tuple.field(&&*key).field(&self.inner.value),
+            None => tuple.field(&"<collected>"),
+        };
+        tuple.finish()
+    }
+}
+
+/// The garbage-collected part of an ephemeron.
+///
+/// Once the key is collected, the value may contain dangling pointers, so it must not be
+/// accessed anymore. It is only dropped, which doesn't dereference its unrooted pointers.
+struct EphemeronBox<K: Trace + ?Sized + 'static, V: Trace + 'static> {
+    key: WeakGc<K>,
+    value: V,
+}
+
+impl<K: Trace + ?Sized, V: Trace> Finalize for EphemeronBox<K, V> {}
+
+unsafe impl<K: Trace + ?Sized, V: Trace> Trace for EphemeronBox<K, V> {
+    unsafe fn trace(&self) {
+        if self.key.is_marked() {
+            self.value.trace();
+        } else if self.key.is_alive() {
+            // The key may still be marked later in this collection.
+            let ephemeron: &dyn TraceEphemeron = self;
+            EPHEMERONS.with(|ephemerons| {
+                ephemerons
+                    .borrow_mut()
+                    .push(ephemeron as *const dyn TraceEphemeron)
+            });
+        }
+    }
+
+    unsafe fn root(&self) {
+        if self.key.is_alive() {
+            self.value.root();
+        }
+    }
+
+    unsafe fn unroot(&self) {
+        if self.key.is_alive() {
+            self.value.unroot();
+        }
+    }
+
+    fn finalize_glue(&self) {
+        Finalize::finalize(self);
+        if self.key.is_alive() {
+            self.value.finalize_glue();
+        }
+    }
+}
+
+/// The ephemerons whose values are traced once their keys are marked.
+trait TraceEphemeron {
+    /// Traces the value, and returns `true`, if the key is marked.
+    unsafe fn trace_if_key_marked(&self) -> bool;
+}
+
+impl<K: Trace + ?Sized, V: Trace> TraceEphemeron for EphemeronBox<K, V> {
+    unsafe fn trace_if_key_marked(&self) -> bool {
+        let marked = self.key.is_marked();
+        if marked {
+            self.value.trace();
+        }
+        marked
+    }
+}
+
+// The ephemerons traced by the collection in progress whose keys weren't marked yet.
+thread_local!(
+    static EPHEMERONS: RefCell<Vec<*const dyn TraceEphemeron>> = const { RefCell::new(Vec::new()) }
+);
+
+/// Traces the values of the pending ephemerons whose keys were marked, until no new key is
+/// marked by tracing them.
+///
+/// The values of the ephemerons that are still pending afterwards are only reachable through
+/// their keys, which are collected along with them.
+pub(crate) unsafe fn trace_ephemerons() {
+    loop {
+        let pending = EPHEMERONS.with(|ephemerons| mem::take(&mut *ephemerons.borrow_mut()));
+        if pending.is_empty() {
+            return;
+        }
+
+        let mut marked_any = false;
+        let mut unmarked = Vec::new();
+        for ephemeron in pending {
+            if (*ephemeron).trace_if_key_marked() {
+                marked_any = true;
+            } else {
+                unmarked.push(ephemeron);
+            }
+        }
+
+        if !marked_any {
+            return;
+        }
+        EPHEMERONS.with(|ephemerons| ephemerons.borrow_mut().extend(unmarked));
+    }
+}
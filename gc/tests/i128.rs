@@ -0,0 +1,11 @@
+use gc::Gc;
+
+#[test]
+fn i128() {
+    Gc::new(0i128);
+}
+
+#[test]
+fn u128() {
+    Gc::new(0u128);
+}
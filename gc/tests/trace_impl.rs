@@ -0,0 +1,68 @@
+#![cfg_attr(feature = "nightly", feature(specialization))]
+// The `Trace` derive wraps its impls in a `const` item that newer compilers warn about.
+#![allow(non_local_definitions)]
+
+use gc_derive::Finalize;
+// With the `derive` feature, `gc` exports the derive macros itself.
+#[cfg(not(feature = "derive"))]
+use gc_derive::Trace;
+use std::cell::RefCell;
+
+thread_local!(static X: RefCell<u8> = const { RefCell::new(0) });
+
+use gc::Trace;
+
+#[derive(Copy, Clone, Finalize)]
+struct Foo;
+
+unsafe impl Trace for Foo {
+    unsafe fn trace(&self) {
+        X.with(|x| {
+            let mut m = x.borrow_mut();
+            *m += 1;
+        })
+    }
+    unsafe fn root(&self) {}
+    unsafe fn unroot(&self) {}
+    fn finalize_glue(&self) {}
+}
+
+#[derive(Trace, Clone, Finalize)]
+struct Bar {
+    inner: Foo,
+}
+
+#[allow(dead_code)]
+#[derive(Trace, Clone, Finalize)]
+struct InnerBoxSlice {
+    inner: Box<[u32]>,
+}
+
+#[allow(dead_code)]
+#[derive(Trace, Clone, Finalize)]
+struct InnerBoxStr {
+    inner: Box<str>,
+}
+
+#[derive(Trace, Finalize)]
+struct Baz {
+    a: Bar,
+    b: Bar,
+}
+
+#[test]
+fn test() {
+    let bar = Bar { inner: Foo };
+    unsafe {
+        bar.trace();
+    }
+    X.with(|x| assert!(*x.borrow() == 1));
+    let baz = Baz {
+        a: bar.clone(),
+        b: bar.clone(),
+    };
+    unsafe {
+        baz.trace();
+    }
+    X.with(|x| assert!(*x.borrow() == 3));
+}
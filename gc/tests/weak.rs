@@ -0,0 +1,79 @@
+use gc::{force_collect, Ephemeron, Gc, GcCell, WeakGc};
+
+#[test]
+fn weak_pointer_does_not_keep_value_alive() {
+    let value = Gc::new(GcCell::new(1));
+    let weak = Gc::downgrade(&value);
+    let other = weak.clone();
+
+    force_collect();
+    assert!(weak.is_alive());
+    *weak.upgrade().unwrap().borrow_mut() = 2;
+    assert_eq!(*value.borrow(), 2);
+    assert!(WeakGc::ptr_eq(&weak, &Gc::downgrade(&value)));
+
+    drop(value);
+    force_collect();
+    assert!(!weak.is_alive());
+    assert!(weak.upgrade().is_none());
+    assert!(other.upgrade().is_none());
+}
+
+#[test]
+fn ephemeron_value_lives_with_key() {
+    let key = Gc::new(1);
+    let value = Gc::new(2);
+    let weak_value = Gc::downgrade(&value);
+    let ephemeron = Gc::new(Ephemeron::new(&key, value));
+
+    force_collect();
+    assert_eq!(ephemeron.value().map(|value| *value), Some(2));
+    assert_eq!(ephemeron.key().map(|key| *key), Some(1));
+
+    drop(key);
+    force_collect();
+    assert!(!ephemeron.is_alive());
+    assert!(ephemeron.value().is_none());
+    assert!(weak_value.upgrade().is_none());
+}
+
+#[test]
+fn ephemeron_value_referencing_its_key() {
+    let key = Gc::new(GcCell::new(Vec::<u8>::new()));
+    let ephemeron = Gc::new(Ephemeron::new(&key, key.clone()));
+    let weak_key = Gc::downgrade(&key);
+
+    drop(key);
+    force_collect();
+    assert!(weak_key.upgrade().is_none());
+    assert!(ephemeron.value().is_none());
+}
+
+#[test]
+fn ephemeron_chain() {
+    // The key of the second ephemeron is only reachable through the value of the first.
+    let first_key = Gc::new(1);
+    let second_key = Gc::new(2);
+    let ephemerons = Gc::new(GcCell::new(vec![
+        Ephemeron::new(&second_key, Gc::new(3)),
+        Ephemeron::new(&first_key, second_key),
+    ]));
+
+    force_collect();
+    assert!(ephemerons.borrow().iter().all(Ephemeron::is_alive));
+    assert_eq!(ephemerons.borrow()[0].value().map(|value| *value), Some(3));
+
+    drop(first_key);
+    force_collect();
+    assert!(ephemerons
+        .borrow()
+        .iter()
+        .all(|ephemeron| !ephemeron.is_alive()));
+}
+
+#[test]
+fn collections_are_counted() {
+    let collections = gc::collections();
+    force_collect();
+    assert_eq!(gc::collections(), collections + 1);
+}